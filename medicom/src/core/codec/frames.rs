@@ -0,0 +1,334 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Locating the frames of encapsulated `PixelData` among its fragments, Part 5 Section A.4, using
+//! the Basic Offset Table or the Extended Offset Table.
+
+use std::{borrow::Cow, ops::Range};
+
+use crate::core::codec::CodecError;
+
+/// The number of bytes of an Item tag and its value length, which precede each fragment.
+const ITEM_HEADER_LEN: usize = 8;
+
+/// The fragments holding a single frame of encapsulated `PixelData`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameLocation {
+    /// The indices of the fragments of the frame, not counting the Basic Offset Table item.
+    pub fragments: Range<usize>,
+    /// The number of bytes of the frame, if specified by the Extended Offset Table Lengths. The
+    /// fragment may be longer than this due to padding.
+    pub length: Option<usize>,
+}
+
+/// Maps the fragments of encapsulated `PixelData` to frames, so that the compressed bytes of a
+/// single frame can be retrieved without reading the other frames.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrameTable {
+    frames: Vec<FrameLocation>,
+}
+
+impl FrameTable {
+    /// Determines the frames of encapsulated `PixelData` from its fragments.
+    ///
+    /// `bot` - The value of the first item, the Basic Offset Table, which may be empty.
+    /// `eot` - The values of Extended Offset Table and Extended Offset Table Lengths, if present.
+    /// `fragments` - The values of the items following the Basic Offset Table.
+    /// `num_frames` - The Number of Frames of the dataset.
+    ///
+    /// The frames are located using the first of these which applies:
+    /// 1. The Extended Offset Table, where each frame is a single fragment.
+    /// 2. The Basic Offset Table, where each frame is one or more fragments.
+    /// 3. All fragments are a single frame if there is only one frame.
+    /// 4. Each fragment is a frame if there are as many fragments as frames.
+    /// 5. Each fragment which starts with a JPEG SOI or JPEG 2000 SOC marker starts a frame.
+    ///
+    /// # Errors
+    /// - `CodecError::InvalidData` if an offset table is malformed or does not refer to the start
+    ///   of a fragment, or if the frames cannot be determined.
+    pub fn new<F: AsRef<[u8]>>(
+        bot: &[u8],
+        eot: Option<(&[u8], &[u8])>,
+        fragments: &[F],
+        num_frames: usize,
+    ) -> Result<Self, CodecError> {
+        if fragments.is_empty() {
+            return Ok(Self::default());
+        }
+
+        // The offset of each fragment's Item tag relative to the first fragment's Item tag.
+        let mut item_offsets: Vec<usize> = Vec::with_capacity(fragments.len());
+        let mut item_pos = 0usize;
+        for fragment in fragments {
+            item_offsets.push(item_pos);
+            item_pos += ITEM_HEADER_LEN + fragment.as_ref().len();
+        }
+        let fragment_at = |offset: usize, table: &str| {
+            item_offsets.binary_search(&offset).map_err(|_| {
+                CodecError::InvalidData(format!(
+                    "{table} offset {offset} is not the start of a fragment"
+                ))
+            })
+        };
+
+        if let Some((offsets, lengths)) = eot {
+            let offsets = Self::read_offsets::<8>(offsets, "Extended Offset Table")?;
+            let lengths = Self::read_offsets::<8>(lengths, "Extended Offset Table Lengths")?;
+            if offsets.len() != lengths.len() {
+                return Err(CodecError::InvalidData(format!(
+                    "Extended Offset Table has {} offsets but {} lengths",
+                    offsets.len(),
+                    lengths.len()
+                )));
+            }
+            let frames = offsets
+                .into_iter()
+                .zip(lengths)
+                .map(|(offset, length)| {
+                    let idx = fragment_at(offset, "Extended Offset Table")?;
+                    Ok(FrameLocation {
+                        fragments: idx..idx + 1,
+                        length: Some(length),
+                    })
+                })
+                .collect::<Result<Vec<FrameLocation>, CodecError>>()?;
+            return Ok(Self { frames });
+        }
+
+        if !bot.is_empty() {
+            let offsets = Self::read_offsets::<4>(bot, "Basic Offset Table")?;
+            let starts = offsets
+                .into_iter()
+                .map(|offset| fragment_at(offset, "Basic Offset Table"))
+                .collect::<Result<Vec<usize>, CodecError>>()?;
+            return Self::from_starts(&starts, fragments.len());
+        }
+
+        if num_frames <= 1 {
+            return Ok(Self {
+                frames: vec![FrameLocation {
+                    fragments: 0..fragments.len(),
+                    length: None,
+                }],
+            });
+        }
+        if fragments.len() == num_frames {
+            return Self::from_starts(&(0..num_frames).collect::<Vec<usize>>(), num_frames);
+        }
+
+        let starts = fragments
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| Self::starts_frame(f.as_ref()))
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+        if starts.len() != num_frames || starts.first() != Some(&0) {
+            return Err(CodecError::InvalidData(format!(
+                "unable to determine {num_frames} frames from {} fragments without an offset table",
+                fragments.len()
+            )));
+        }
+        Self::from_starts(&starts, fragments.len())
+    }
+
+    /// The number of frames.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether there are no frames.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The location of the frame at the given index.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&FrameLocation> {
+        self.frames.get(index)
+    }
+
+    /// Iterates over the location of each frame.
+    pub fn iter(&self) -> std::slice::Iter<'_, FrameLocation> {
+        self.frames.iter()
+    }
+
+    /// The compressed bytes of the frame at the given index. The fragment is borrowed if the frame
+    /// is a single fragment, otherwise its fragments are concatenated. Returns `None` if the frame
+    /// does not exist or its fragments are missing.
+    ///
+    /// `fragments` - The values of the items following the Basic Offset Table.
+    #[must_use]
+    pub fn frame_data<'a>(&self, index: usize, fragments: &[&'a [u8]]) -> Option<Cow<'a, [u8]>> {
+        let location = self.frames.get(index)?;
+        let mut data = match fragments.get(location.fragments.clone())? {
+            [single] => Cow::Borrowed(*single),
+            parts => Cow::Owned(parts.concat()),
+        };
+        if let Some(length) = location.length {
+            if length > data.len() {
+                return None;
+            }
+            match &mut data {
+                Cow::Borrowed(d) => *d = &d[..length],
+                Cow::Owned(d) => d.truncate(length),
+            }
+        }
+        Some(data)
+    }
+
+    /// Creates frames from the index of the first fragment of each frame.
+    fn from_starts(starts: &[usize], num_fragments: usize) -> Result<Self, CodecError> {
+        let mut frames = Vec::with_capacity(starts.len());
+        for (i, &start) in starts.iter().enumerate() {
+            let end = starts.get(i + 1).copied().unwrap_or(num_fragments);
+            if end <= start {
+                return Err(CodecError::InvalidData(
+                    "Basic Offset Table offsets are not increasing".to_owned(),
+                ));
+            }
+            frames.push(FrameLocation {
+                fragments: start..end,
+                length: None,
+            });
+        }
+        Ok(Self { frames })
+    }
+
+    /// Reads the little-endian offsets of an offset table, each of which is `N` bytes.
+    fn read_offsets<const N: usize>(data: &[u8], table: &str) -> Result<Vec<usize>, CodecError> {
+        if data.len() % N != 0 {
+            return Err(CodecError::InvalidData(format!(
+                "{table} length {} is not a multiple of {N}",
+                data.len()
+            )));
+        }
+        data.chunks_exact(N)
+            .map(|chunk| {
+                let mut bytes = [0u8; 8];
+                bytes[..N].copy_from_slice(chunk);
+                usize::try_from(u64::from_le_bytes(bytes))
+                    .map_err(|_| CodecError::InvalidData(format!("{table} offset is too large")))
+            })
+            .collect()
+    }
+
+    /// Whether the fragment starts with a marker which begins a compressed frame: the JPEG Start
+    /// of Image marker, also used by JPEG-LS, or the JPEG 2000 Start of Codestream marker.
+    fn starts_frame(fragment: &[u8]) -> bool {
+        matches!(fragment, [0xFF, 0xD8, ..] | [0xFF, 0x4F, 0xFF, 0x51, ..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::core::codec::{
+        frames::{FrameLocation, FrameTable},
+        CodecError,
+    };
+
+    /// Three frames, the second of which is split across two fragments.
+    const FRAGMENTS: [&[u8]; 4] = [
+        &[0xFF, 0xD8, 1, 2],
+        &[0xFF, 0xD8, 3, 4],
+        &[5, 6],
+        &[0xFF, 0xD8, 7, 8, 9, 0],
+    ];
+
+    #[test]
+    pub fn test_basic_offset_table() {
+        let bot = [0u32, 12, 34]
+            .iter()
+            .flat_map(|o| o.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let fragments = FRAGMENTS;
+        let table = FrameTable::new(&bot, None, &fragments, 3).unwrap();
+        assert_eq!(3, table.len());
+        assert_eq!(
+            Some(&FrameLocation {
+                fragments: 1..3,
+                length: None
+            }),
+            table.get(1)
+        );
+        assert!(matches!(
+            table.frame_data(0, &fragments),
+            Some(Cow::Borrowed([0xFF, 0xD8, 1, 2]))
+        ));
+        assert_eq!(
+            vec![0xFF, 0xD8, 3, 4, 5, 6],
+            table.frame_data(1, &fragments).unwrap().into_owned()
+        );
+        assert!(table.frame_data(3, &fragments).is_none());
+
+        // Without the table the frames are found by their SOI markers.
+        assert_eq!(table, FrameTable::new(&[], None, &fragments, 3).unwrap());
+
+        let bad_bot = 7u32.to_le_bytes();
+        assert!(matches!(
+            FrameTable::new(&bad_bot, None, &fragments, 1),
+            Err(CodecError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_extended_offset_table() {
+        let fragments: [&[u8]; 2] = [&[1, 2, 3, 0], &[4, 5]];
+        let offsets = [0u64, 12]
+            .iter()
+            .flat_map(|o| o.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let lengths = [3u64, 2]
+            .iter()
+            .flat_map(|o| o.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let table = FrameTable::new(&[], Some((&offsets, &lengths)), &fragments, 2).unwrap();
+        assert_eq!(
+            vec![1, 2, 3],
+            table.frame_data(0, &fragments).unwrap().into_owned()
+        );
+        assert_eq!(
+            vec![4, 5],
+            table.frame_data(1, &fragments).unwrap().into_owned()
+        );
+
+        assert!(matches!(
+            FrameTable::new(&[], Some((&offsets, &lengths[..8])), &fragments, 2),
+            Err(CodecError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_without_offset_table() {
+        let fragments: [&[u8]; 2] = [&[1, 2], &[3, 4]];
+        let single = FrameTable::new(&[], None, &fragments, 1).unwrap();
+        assert_eq!(
+            vec![1, 2, 3, 4],
+            single.frame_data(0, &fragments).unwrap().into_owned()
+        );
+
+        let each = FrameTable::new(&[], None, &fragments, 2).unwrap();
+        assert_eq!(
+            vec![3, 4],
+            each.frame_data(1, &fragments).unwrap().into_owned()
+        );
+
+        assert!(FrameTable::new(&[], None, &fragments, 3).is_err());
+    }
+}
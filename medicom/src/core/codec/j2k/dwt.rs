@@ -0,0 +1,161 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The inverse discrete wavelet transforms, ISO/IEC 15444-1 Annex F: the reversible 5-3 filter
+//! using integer lifting and the irreversible 9-7 filter.
+
+use crate::core::codec::j2k::tile::Resolution;
+
+/// Lifting parameters of the irreversible 9-7 filter, Table F.4.
+const ALPHA: f32 = -1.586_134_3;
+const BETA: f32 = -0.052_980_117;
+const GAMMA: f32 = 0.882_911_1;
+const DELTA: f32 = 0.443_506_87;
+const K: f32 = 1.230_174_1;
+
+/// Reconstructs the samples of a resolution level from the samples of the next lower resolution
+/// level and the HL, LH and HH subbands of this level, using the reversible 5-3 filter.
+pub(super) fn inverse_53(
+    low: &[i32],
+    prev: &Resolution,
+    bands: &[Vec<i32>],
+    res: &Resolution,
+) -> Vec<i32> {
+    let mut out = interleave(low, prev, bands, res);
+    transform_2d(&mut out, res, lift_53);
+    out
+}
+
+/// Reconstructs the samples of a resolution level from the samples of the next lower resolution
+/// level and the HL, LH and HH subbands of this level, using the irreversible 9-7 filter.
+pub(super) fn inverse_97(
+    low: &[f32],
+    prev: &Resolution,
+    bands: &[Vec<f32>],
+    res: &Resolution,
+) -> Vec<f32> {
+    let mut out = interleave(low, prev, bands, res);
+    transform_2d(&mut out, res, lift_97);
+    out
+}
+
+/// Interleaves the lower resolution and the subbands into the positions of the resolution level
+/// which they cover, 2D_INTERLEAVE of Section F.3.3. Samples at even coordinates are low-pass
+/// and samples at odd coordinates are high-pass.
+fn interleave<T: Copy + Default>(
+    low: &[T],
+    prev: &Resolution,
+    bands: &[Vec<T>],
+    res: &Resolution,
+) -> Vec<T> {
+    let width = (res.x1 - res.x0) as usize;
+    let height = (res.y1 - res.y0) as usize;
+    let mut out = vec![T::default(); width * height];
+    for v in res.y0..res.y1 {
+        for u in res.x0..res.x1 {
+            let (src, src_w, sx, sy) = match (u % 2, v % 2) {
+                (0, 0) => (low, prev.x1 - prev.x0, u / 2 - prev.x0, v / 2 - prev.y0),
+                (hx, hy) => {
+                    let b = &res.bands[(hx + 2 * hy - 1) as usize];
+                    let data = &bands[(hx + 2 * hy - 1) as usize];
+                    (data.as_slice(), b.x1 - b.x0, u / 2 - b.x0, v / 2 - b.y0)
+                }
+            };
+            out[(v - res.y0) as usize * width + (u - res.x0) as usize] =
+                src[(sy * src_w + sx) as usize];
+        }
+    }
+    out
+}
+
+/// Applies a one-dimensional filter to each row and then to each column, HOR_SR and VER_SR of
+/// Section F.3.4. The filter is given the absolute coordinate of the first sample, as the parity
+/// of each sample's coordinate determines whether it is low-pass or high-pass.
+fn transform_2d<T: Copy + Default>(data: &mut [T], res: &Resolution, lift: fn(&mut [T], u32)) {
+    let width = (res.x1 - res.x0) as usize;
+    let height = (res.y1 - res.y0) as usize;
+    if width == 0 || height == 0 {
+        return;
+    }
+    for row in data.chunks_exact_mut(width) {
+        lift(row, res.x0);
+    }
+    let mut column = vec![T::default(); height];
+    for x in 0..width {
+        for (y, val) in column.iter_mut().enumerate() {
+            *val = data[y * width + x];
+        }
+        lift(&mut column, res.y0);
+        for (y, val) in column.iter().enumerate() {
+            data[y * width + x] = *val;
+        }
+    }
+}
+
+/// The index of the sample at `i` after periodic symmetric extension of a signal of length `n`,
+/// Section F.3.7.
+fn extend(i: isize, n: usize) -> usize {
+    let n = n as isize;
+    let period = 2 * (n - 1);
+    let mut i = i.rem_euclid(period);
+    if i >= n {
+        i = period - i;
+    }
+    i as usize
+}
+
+/// Applies a lifting step to the samples of one parity, adding the result of `step` given the
+/// two neighboring samples.
+fn lift_step<T: Copy>(x: &mut [T], first: usize, step: impl Fn(T, T, T) -> T) {
+    let n = x.len();
+    for i in (first..n).step_by(2) {
+        let left = x[extend(i as isize - 1, n)];
+        let right = x[extend(i as isize + 1, n)];
+        x[i] = step(x[i], left, right);
+    }
+}
+
+/// The one-dimensional reversible 5-3 synthesis, Section F.3.8.1.
+fn lift_53(x: &mut [i32], i0: u32) {
+    // The index of the first even (low-pass) sample.
+    let even = (i0 % 2) as usize;
+    if x.len() == 1 {
+        if even == 1 {
+            x[0] /= 2;
+        }
+        return;
+    }
+    lift_step(x, even, |v, l, r| v - ((l + r + 2) >> 2));
+    lift_step(x, 1 - even, |v, l, r| v + ((l + r) >> 1));
+}
+
+/// The one-dimensional irreversible 9-7 synthesis, Section F.3.8.2.
+fn lift_97(x: &mut [f32], i0: u32) {
+    let even = (i0 % 2) as usize;
+    if x.len() == 1 {
+        if even == 1 {
+            x[0] /= 2.0;
+        }
+        return;
+    }
+    for (i, val) in x.iter_mut().enumerate() {
+        *val *= if i % 2 == even { K } else { 1.0 / K };
+    }
+    lift_step(x, even, |v, l, r| v - DELTA * (l + r));
+    lift_step(x, 1 - even, |v, l, r| v - GAMMA * (l + r));
+    lift_step(x, even, |v, l, r| v - BETA * (l + r));
+    lift_step(x, 1 - even, |v, l, r| v - ALPHA * (l + r));
+}
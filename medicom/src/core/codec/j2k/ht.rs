@@ -0,0 +1,446 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The HT block decoder, ISO/IEC 15444-15 Clause 7.
+//!
+//! Only the HT cleanup pass is decoded. The HT refinement passes which may follow it add a
+//! single bit-plane of precision to lossy codestreams and are skipped, reconstructing those
+//! coefficients at the precision of the cleanup pass. Lossless codestreams code every bit-plane
+//! in the cleanup pass.
+
+use crate::core::codec::{
+    j2k::{tile::CodeBlock, vlc},
+    CodecError,
+};
+
+/// The exponents of the MEL decoder's run lengths for each state, Table 2.
+const MEL_EXP: [u32; 13] = [0, 0, 0, 1, 1, 1, 2, 2, 2, 3, 3, 4, 5];
+
+/// The largest valid length of the MEL and VLC segments, Section 7.1.
+const MAX_SCUP: usize = 4079;
+
+fn invalid(what: &str) -> CodecError {
+    CodecError::InvalidData(format!("HTJ2K {what}"))
+}
+
+/// Reads the MagSgn bit-stream forward, least significant bit first. A zero bit is stuffed after
+/// every 0xFF byte, and the stream is padded with 0xFF bytes.
+struct MagSgnBits<'a> {
+    data: &'a [u8],
+    pos: usize,
+    buf: u64,
+    bits: u32,
+    unstuff: bool,
+}
+
+impl<'a> MagSgnBits<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        MagSgnBits {
+            data,
+            pos: 0,
+            buf: 0,
+            bits: 0,
+            unstuff: false,
+        }
+    }
+
+    fn read(&mut self, count: u32) -> u32 {
+        while self.bits < count {
+            let byte = self.data.get(self.pos).copied().unwrap_or(0xFF);
+            self.pos += 1;
+            let (byte, len) = if self.unstuff {
+                (byte & 0x7F, 7)
+            } else {
+                (byte, 8)
+            };
+            self.buf |= u64::from(byte) << self.bits;
+            self.bits += len;
+            self.unstuff = byte == 0xFF;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let val = (self.buf & ((1u64 << count) - 1)) as u32;
+        self.buf >>= count;
+        self.bits -= count;
+        val
+    }
+}
+
+/// Decodes the MEL segment, an adaptive run-length code of the significance of quads with no
+/// significant neighbors, Section 7.3.3.
+struct Mel {
+    data: Vec<u8>,
+    pos: usize,
+    byte: u8,
+    bits: u32,
+    unstuff: bool,
+    state: usize,
+    /// The number of zero events remaining in the current run.
+    run: u32,
+    /// Whether the current run is terminated by a one event.
+    one: bool,
+}
+
+impl Mel {
+    /// The MEL segment shares its last byte with the VLC segment, whose four least significant
+    /// bits are read as ones, after which the stream is padded with 0xFF bytes.
+    fn new(data: &[u8]) -> Self {
+        let mut data = data.to_vec();
+        if let Some(last) = data.last_mut() {
+            *last |= 0x0F;
+        }
+        Mel {
+            data,
+            pos: 0,
+            byte: 0,
+            bits: 0,
+            unstuff: false,
+            state: 0,
+            run: 0,
+            one: false,
+        }
+    }
+
+    fn read_bit(&mut self) -> u32 {
+        if self.bits == 0 {
+            let byte = self.data.get(self.pos).copied().unwrap_or(0xFF);
+            self.pos += 1;
+            self.bits = if self.unstuff { 7 } else { 8 };
+            self.unstuff = byte == 0xFF;
+            self.byte = byte;
+        }
+        self.bits -= 1;
+        u32::from(self.byte >> self.bits) & 1
+    }
+
+    fn event(&mut self) -> bool {
+        loop {
+            if self.run > 0 {
+                self.run -= 1;
+                return false;
+            }
+            if self.one {
+                self.one = false;
+                return true;
+            }
+            let exp = MEL_EXP[self.state];
+            if self.read_bit() == 1 {
+                self.run = 1 << exp;
+                self.state = (self.state + 1).min(MEL_EXP.len() - 1);
+            } else {
+                let mut run = 0;
+                for _ in 0..exp {
+                    run = (run << 1) | self.read_bit();
+                }
+                self.run = run;
+                self.one = true;
+                self.state = self.state.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// Reads the VLC segment backward from the end of the cleanup segment, least significant bit
+/// first. A zero bit is stuffed in the most significant bit of a byte following a byte greater
+/// than 0x8F if its remaining bits are all ones.
+struct VlcBits<'a> {
+    data: &'a [u8],
+    /// The number of bytes of the segment not yet read.
+    remaining: usize,
+    buf: u64,
+    bits: u32,
+    unstuff: bool,
+}
+
+impl<'a> VlcBits<'a> {
+    /// The segment starts with the four most significant bits of the second to last byte, the
+    /// remaining bits of which give the segment's length.
+    fn new(data: &'a [u8], scup: usize) -> Self {
+        let last = data[data.len() - 2];
+        let nibble = last >> 4;
+        let bits = if nibble & 7 == 7 { 3 } else { 4 };
+        VlcBits {
+            data: &data[data.len() - scup..data.len() - 2],
+            remaining: scup - 2,
+            buf: u64::from(nibble & ((1 << bits) - 1)),
+            bits,
+            unstuff: last | 0x0F > 0x8F,
+        }
+    }
+
+    fn fill(&mut self) {
+        while self.bits <= 32 {
+            let byte = if self.remaining > 0 {
+                self.remaining -= 1;
+                self.data[self.remaining]
+            } else {
+                0
+            };
+            let len = if self.unstuff && byte & 0x7F == 0x7F {
+                7
+            } else {
+                8
+            };
+            self.buf |= (u64::from(byte) & ((1 << len) - 1)) << self.bits;
+            self.bits += len;
+            self.unstuff = byte > 0x8F;
+        }
+    }
+
+    fn peek(&mut self) -> usize {
+        self.fill();
+        (self.buf & 0x7F) as usize
+    }
+
+    fn read(&mut self, count: u32) -> u32 {
+        self.fill();
+        #[allow(clippy::cast_possible_truncation)]
+        let val = (self.buf & ((1u64 << count) - 1)) as u32;
+        self.buf >>= count;
+        self.bits -= count;
+        val
+    }
+
+    /// Reads the prefix of an unsigned residual, Table 3.
+    fn read_prefix(&mut self) -> u32 {
+        if self.read(1) == 1 {
+            1
+        } else if self.read(1) == 1 {
+            2
+        } else if self.read(1) == 1 {
+            3
+        } else {
+            5
+        }
+    }
+
+    /// Reads the suffix of an unsigned residual given its prefix, Table 3.
+    fn read_suffix(&mut self, prefix: u32) -> u32 {
+        match prefix {
+            3 => self.read(1),
+            5 => self.read(5),
+            _ => 0,
+        }
+    }
+
+    /// Reads the extension of an unsigned residual given its suffix.
+    fn read_ext(&mut self, prefix: u32, suffix: u32) -> u32 {
+        if prefix == 5 && suffix >= 28 {
+            4 * self.read(4)
+        } else {
+            0
+        }
+    }
+
+    /// Reads the unsigned residuals of a pair of quads, Section 7.3.6. For the initial row, a
+    /// MEL event indicates whether both residuals are at least two if both quads have them.
+    fn read_residuals(&mut self, u_off: [bool; 2], pair_event: Option<bool>) -> [u32; 2] {
+        let mut prefix = [0; 2];
+        let mut suffix = [0; 2];
+        match pair_event {
+            Some(true) => {
+                prefix = [self.read_prefix(), self.read_prefix()];
+                suffix = [self.read_suffix(prefix[0]), self.read_suffix(prefix[1])];
+                let ext = [
+                    self.read_ext(prefix[0], suffix[0]),
+                    self.read_ext(prefix[1], suffix[1]),
+                ];
+                return [
+                    2 + prefix[0] + suffix[0] + ext[0],
+                    2 + prefix[1] + suffix[1] + ext[1],
+                ];
+            }
+            Some(false) => {
+                prefix[0] = self.read_prefix();
+                if prefix[0] > 2 {
+                    let second = self.read(1) + 1;
+                    let suffix = self.read_suffix(prefix[0]);
+                    let ext = self.read_ext(prefix[0], suffix);
+                    return [prefix[0] + suffix + ext, second];
+                }
+                prefix[1] = self.read_prefix();
+            }
+            None => {
+                for (pfx, off) in prefix.iter_mut().zip(u_off) {
+                    if off {
+                        *pfx = self.read_prefix();
+                    }
+                }
+            }
+        }
+        for (sfx, pfx) in suffix.iter_mut().zip(prefix) {
+            *sfx = self.read_suffix(pfx);
+        }
+        let ext = [
+            self.read_ext(prefix[0], suffix[0]),
+            self.read_ext(prefix[1], suffix[1]),
+        ];
+        [
+            prefix[0] + suffix[0] + ext[0],
+            prefix[1] + suffix[1] + ext[1],
+        ]
+    }
+}
+
+/// The fields of a decoded CxtVLC codeword, as laid out by the lookup tables of `vlc`.
+#[derive(Debug, Clone, Copy, Default)]
+struct QuadInfo(u16);
+
+impl QuadInfo {
+    fn len(self) -> u32 {
+        u32::from(self.0 & 0x7)
+    }
+
+    fn u_off(self) -> bool {
+        self.0 & 0x8 != 0
+    }
+
+    /// The significance of the samples of the quad, in the order top-left, bottom-left,
+    /// top-right, bottom-right.
+    fn rho(self) -> u16 {
+        (self.0 >> 4) & 0xF
+    }
+
+    fn e_1(self) -> u16 {
+        (self.0 >> 8) & 0xF
+    }
+
+    fn e_k(self) -> u16 {
+        (self.0 >> 12) & 0xF
+    }
+}
+
+/// Decodes the HT cleanup pass of a code-block into its coefficients, given the number of
+/// magnitude bit-planes of its subband.
+pub(super) fn decode_block(cb: &CodeBlock, mb: u32, out: &mut [i32]) -> Result<(), CodecError> {
+    let Some(segment) = cb.segments.first() else {
+        return Ok(());
+    };
+    let data = segment.data.as_slice();
+    let len = data.len();
+    if len < 2 {
+        return Err(invalid("cleanup segment is truncated"));
+    }
+    let scup = (usize::from(data[len - 1]) << 4) | usize::from(data[len - 2] & 0x0F);
+    if !(2..=len.min(MAX_SCUP)).contains(&scup) {
+        return Err(invalid("cleanup segment has an invalid MEL and VLC length"));
+    }
+    let pcup = len - scup;
+    // The bit-plane of the cleanup pass, below which there is no data in the code-block.
+    let Some(plane) = mb.checked_sub(cb.zero_bitplanes + 1) else {
+        return Err(invalid(
+            "code-block has more missing bit-planes than its subband",
+        ));
+    };
+
+    let mut magsgn = MagSgnBits::new(&data[..pcup]);
+    let mut mel = Mel::new(&data[pcup..len - 1]);
+    let mut vlc = VlcBits::new(data, scup);
+
+    let (width, height) = (cb.width(), cb.height());
+    let quads_wide = width.div_ceil(2);
+    // The significance and exponents of the samples in the bottom row of the previous and
+    // current rows of quads, with a column either side.
+    let mut sig_above = vec![false; 2 * quads_wide + 3];
+    let mut exp_above = vec![0u32; 2 * quads_wide + 3];
+    let mut sig_row = sig_above.clone();
+    let mut exp_row = exp_above.clone();
+
+    for qy in 0..height.div_ceil(2) {
+        let initial = qy == 0;
+        let lut = if initial {
+            vlc::initial_row()
+        } else {
+            vlc::other_rows()
+        };
+        sig_row.fill(false);
+        exp_row.fill(0);
+        let mut prev = QuadInfo::default();
+        for pair in (0..quads_wide).step_by(2) {
+            let count = if pair + 1 < quads_wide { 2 } else { 1 };
+            let mut info = [QuadInfo::default(); 2];
+            for (i, quad) in info.iter_mut().enumerate().take(count) {
+                let col = 2 * (pair + i) + 1;
+                let rho = prev.rho();
+                let c_q = if initial {
+                    (rho & 1) | (rho >> 1)
+                } else {
+                    let west = u16::from(rho & 0xC != 0);
+                    let north = u16::from(sig_above[col - 1] || sig_above[col]);
+                    let east = u16::from(sig_above[col + 1] || sig_above[col + 2]);
+                    north | west << 1 | east << 2
+                };
+                *quad = QuadInfo(lut[usize::from(c_q) << 7 | vlc.peek()]);
+                if c_q == 0 && !mel.event() {
+                    *quad = QuadInfo::default();
+                }
+                vlc.read(quad.len());
+                prev = *quad;
+            }
+
+            let u_off = [info[0].u_off(), info[1].u_off()];
+            let pair_event = (initial && u_off == [true, true]).then(|| mel.event());
+            let residuals = vlc.read_residuals(u_off, pair_event);
+
+            for (i, quad) in info.iter().enumerate().take(count) {
+                let q = pair + i;
+                let rho = quad.rho();
+                let kappa = if initial || rho.count_ones() < 2 {
+                    1
+                } else {
+                    let max = exp_above[2 * q..2 * q + 4]
+                        .iter()
+                        .max()
+                        .copied()
+                        .unwrap_or(0);
+                    max.saturating_sub(1).max(1)
+                };
+                let exponent = residuals[i] + kappa;
+                if exponent > 31 {
+                    return Err(invalid("quad has an invalid exponent bound"));
+                }
+                for n in 0..4 {
+                    if (rho >> n) & 1 == 0 {
+                        continue;
+                    }
+                    let bits = exponent - u32::from((quad.e_k() >> n) & 1);
+                    let implicit = u64::from((quad.e_1() >> n) & 1) << bits;
+                    let val = u64::from(magsgn.read(bits)) | implicit;
+                    let magnitude = ((val >> 1) + 1) << plane;
+                    let magnitude = if plane > 0 {
+                        magnitude | (1 << (plane - 1))
+                    } else {
+                        magnitude
+                    };
+                    let Ok(magnitude) = i32::try_from(magnitude) else {
+                        return Err(invalid("coefficient exceeds the subband's bit-planes"));
+                    };
+
+                    let (x, y) = (2 * q + n / 2, 2 * qy + n % 2);
+                    if n % 2 == 1 {
+                        sig_row[x + 1] = true;
+                        exp_row[x + 1] = 64 - (val | 1).leading_zeros();
+                    }
+                    if x < width && y < height {
+                        out[y * width + x] = if val & 1 == 1 { -magnitude } else { magnitude };
+                    }
+                }
+            }
+        }
+        std::mem::swap(&mut sig_above, &mut sig_row);
+        std::mem::swap(&mut exp_above, &mut exp_row);
+    }
+    Ok(())
+}
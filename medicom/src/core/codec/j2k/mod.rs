@@ -0,0 +1,830 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! JPEG 2000, Part 5 Section 8.2.4 and ISO/IEC 15444-1 (ITU-T T.800), as well as High-Throughput
+//! JPEG 2000, Part 5 Section 8.2.15 and ISO/IEC 15444-15 (ITU-T T.814), which replaces the
+//! code-block coding of Part 1 but shares the same codestream structure.
+//!
+//! This module reads the codestream markers and tile-part headers. Tiles are decoded in the
+//! sub-modules: packets are read by `packet`, code-blocks are decoded by `t1` or `ht`, and the
+//! inverse wavelet transform is applied by `dwt`.
+
+use crate::core::codec::{CodecError, FrameLayout};
+
+mod dwt;
+mod ht;
+mod mq;
+mod packet;
+mod t1;
+mod tile;
+mod vlc;
+
+/// Start of Codestream.
+const SOC: u16 = 0xFF4F;
+/// Extended capabilities, used by HTJ2K.
+const CAP: u16 = 0xFF50;
+/// Image and tile size.
+const SIZ: u16 = 0xFF51;
+/// Coding style default.
+const COD: u16 = 0xFF52;
+/// Coding style component.
+const COC: u16 = 0xFF53;
+/// Quantization default.
+const QCD: u16 = 0xFF5C;
+/// Quantization component.
+const QCC: u16 = 0xFF5D;
+/// Region of interest.
+const RGN: u16 = 0xFF5E;
+/// Progression order change.
+const POC: u16 = 0xFF5F;
+/// Packed packet headers, main header.
+const PPM: u16 = 0xFF60;
+/// Packed packet headers, tile-part header.
+const PPT: u16 = 0xFF61;
+/// Start of Tile-part.
+const SOT: u16 = 0xFF90;
+/// Start of Data.
+const SOD: u16 = 0xFF93;
+/// End of Codestream.
+const EOC: u16 = 0xFFD9;
+
+/// Code-block style: selective arithmetic coding bypass.
+const CBS_BYPASS: u8 = 0x01;
+/// Code-block style: reset context probabilities on coding pass boundaries.
+const CBS_RESET: u8 = 0x02;
+/// Code-block style: termination on each coding pass.
+const CBS_TERMALL: u8 = 0x04;
+/// Code-block style: vertically causal context.
+const CBS_CAUSAL: u8 = 0x08;
+/// Code-block style: segmentation symbols are used.
+const CBS_SEGSYM: u8 = 0x20;
+/// Code-block style: code-blocks use the HT block coder.
+const CBS_HT: u8 = 0x40;
+/// Code-block style: code-blocks may mix the HT and Part 1 block coders.
+const CBS_HT_MIXED: u8 = 0x80;
+
+/// The signature box of the JP2 file format, for frames which are wrapped in a JP2 file rather
+/// than being a bare codestream.
+const JP2_SIGNATURE: [u8; 12] = [
+    0x00, 0x00, 0x00, 0x0C, 0x6A, 0x50, 0x20, 0x20, 0x0D, 0x0A, 0x87, 0x0A,
+];
+
+/// A component of the image, from the SIZ marker segment.
+#[derive(Debug, Clone)]
+struct SizComponent {
+    precision: u8,
+    signed: bool,
+    dx: u32,
+    dy: u32,
+}
+
+/// The image and tile size, from the SIZ marker segment.
+#[derive(Debug, Clone)]
+struct ImageSize {
+    x1: u32,
+    y1: u32,
+    x0: u32,
+    y0: u32,
+    tile_w: u32,
+    tile_h: u32,
+    tile_x0: u32,
+    tile_y0: u32,
+    components: Vec<SizComponent>,
+}
+
+impl ImageSize {
+    fn tiles_wide(&self) -> u32 {
+        (self.x1 - self.tile_x0).div_ceil(self.tile_w)
+    }
+
+    fn tiles_high(&self) -> u32 {
+        (self.y1 - self.tile_y0).div_ceil(self.tile_h)
+    }
+
+    fn width(&self) -> u32 {
+        self.x1 - self.x0
+    }
+
+    fn height(&self) -> u32 {
+        self.y1 - self.y0
+    }
+}
+
+/// The progression order of packets, from the COD marker segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Progression {
+    Lrcp,
+    Rlcp,
+    Rpcl,
+    Pcrl,
+    Cprl,
+}
+
+/// The coding parameters which apply to all components, from the COD marker segment.
+#[derive(Debug, Clone)]
+struct CodingDefaults {
+    sop: bool,
+    eph: bool,
+    progression: Progression,
+    layers: u16,
+    mct: bool,
+}
+
+/// The coding parameters of a component, from the COD or COC marker segments.
+#[derive(Debug, Clone)]
+struct CodingStyle {
+    levels: u8,
+    /// The code-block width and height exponents.
+    cb_w: u8,
+    cb_h: u8,
+    cb_style: u8,
+    reversible: bool,
+    /// The precinct width and height exponents of each resolution level.
+    precincts: Vec<(u8, u8)>,
+}
+
+/// The quantization parameters of a component, from the QCD or QCC marker segments.
+#[derive(Debug, Clone)]
+struct Quantization {
+    /// 0 for no quantization, 1 for scalar derived, and 2 for scalar expounded.
+    style: u8,
+    guard_bits: u8,
+    /// The exponent and mantissa of each subband, or only of the LL subband for scalar derived.
+    steps: Vec<(u8, u16)>,
+}
+
+impl Quantization {
+    /// The exponent and mantissa of the subband with the given index, in the order the subbands
+    /// are listed in the marker segment, where `level` is the decomposition level of the subband.
+    fn step(&self, band_idx: usize, level: u8, levels: u8) -> Result<(u8, u16), CodecError> {
+        if self.style == 1 {
+            let (exp, mant) = self.steps.first().copied().unwrap_or_default();
+            let exp = (i32::from(exp) - i32::from(levels) + i32::from(level)).max(0);
+            return Ok((u8::try_from(exp).unwrap_or(0), mant));
+        }
+        self.steps.get(band_idx).copied().ok_or_else(|| {
+            CodecError::InvalidData("JPEG 2000 quantization has too few subbands".to_owned())
+        })
+    }
+}
+
+/// The coding and quantization marker segments of a header, which are applied by precedence
+/// rather than by the order in which they appear.
+#[derive(Debug, Default)]
+struct HeaderMarkers {
+    cod: Option<(CodingDefaults, CodingStyle)>,
+    coc: Vec<(usize, CodingStyle)>,
+    qcd: Option<Quantization>,
+    qcc: Vec<(usize, Quantization)>,
+}
+
+/// The parameters used for decoding a tile, from the main header and the tile's first tile-part
+/// header.
+#[derive(Debug, Clone)]
+struct TileParams {
+    defaults: CodingDefaults,
+    styles: Vec<CodingStyle>,
+    quants: Vec<Quantization>,
+}
+
+impl TileParams {
+    /// Applies the markers of a header, in which COC and QCC marker segments take precedence over
+    /// COD and QCD marker segments.
+    fn apply(&mut self, markers: HeaderMarkers) -> Result<(), CodecError> {
+        if let Some((defaults, style)) = markers.cod {
+            self.defaults = defaults;
+            self.styles.fill(style);
+        }
+        for (comp, style) in markers.coc {
+            *self
+                .styles
+                .get_mut(comp)
+                .ok_or_else(|| invalid("COC component"))? = style;
+        }
+        if let Some(quant) = markers.qcd {
+            self.quants.fill(quant);
+        }
+        for (comp, quant) in markers.qcc {
+            *self
+                .quants
+                .get_mut(comp)
+                .ok_or_else(|| invalid("QCC component"))? = quant;
+        }
+        Ok(())
+    }
+}
+
+fn invalid(what: &str) -> CodecError {
+    CodecError::InvalidData(format!("JPEG 2000 codestream has an invalid {what}"))
+}
+
+/// Decodes a single frame of JPEG 2000 or HTJ2K compressed data into native samples.
+///
+/// The decoded samples are arranged as little-endian samples interleaved by pixel. When the
+/// codestream uses a multiple component transform the decoded samples are RGB.
+///
+/// # Errors
+/// - `CodecError::UnsupportedLayout` if the codestream uses subsampled components, regions of
+///   interest, progression order changes, packed packet headers, or mixed HT code-blocks, or if
+///   it does not match the given frame layout.
+/// - `CodecError::InvalidData` if the codestream is malformed or truncated.
+pub fn decode_frame(frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+    let mut reader = CodestreamReader::new(codestream(frame)?)?;
+    let (size, params) = reader.read_main_header()?;
+    check_layout(&size, layout)?;
+
+    let num_tiles = usize::try_from(size.tiles_wide() * size.tiles_high()).unwrap_or(0);
+    let mut tiles: Vec<Option<(TileParams, Vec<u8>)>> = vec![None; num_tiles];
+    while let Some((index, markers, data)) = reader.next_tile_part(size.components.len())? {
+        let tile = tiles.get_mut(index).ok_or_else(|| invalid("tile index"))?;
+        match tile {
+            Some((_, tile_data)) => tile_data.extend_from_slice(data),
+            None => {
+                let mut tile_params = params.clone();
+                tile_params.apply(markers)?;
+                *tile = Some((tile_params, data.to_vec()));
+            }
+        }
+    }
+
+    let pixels = usize::from(layout.cols) * usize::from(layout.rows);
+    let mut planes = vec![vec![0i32; pixels]; size.components.len()];
+    for (index, tile) in tiles.into_iter().enumerate() {
+        if let Some((tile_params, data)) = tile {
+            let index = u32::try_from(index).unwrap_or(u32::MAX);
+            tile::decode_tile(&size, index, &tile_params, &data, &mut planes)?;
+        }
+    }
+
+    let bytes_per_sample = layout.bytes_per_sample();
+    let mut native = Vec::with_capacity(layout.frame_len());
+    for pixel in 0..pixels {
+        for plane in &planes {
+            native.extend_from_slice(&plane[pixel].to_le_bytes()[..bytes_per_sample.min(4)]);
+        }
+    }
+    Ok(native)
+}
+
+/// The codestream within the frame, which is either a bare codestream or the Contiguous
+/// Codestream box of a JP2 file.
+fn codestream(frame: &[u8]) -> Result<&[u8], CodecError> {
+    if !frame.starts_with(&JP2_SIGNATURE) {
+        return Ok(frame);
+    }
+    let mut pos = 0usize;
+    while pos + 8 <= frame.len() {
+        let len = u32::from_be_bytes([frame[pos], frame[pos + 1], frame[pos + 2], frame[pos + 3]]);
+        let box_type = &frame[pos + 4..pos + 8];
+        let (header_len, box_len) = match len {
+            0 => (8, frame.len() - pos),
+            1 => {
+                let ext = frame
+                    .get(pos + 8..pos + 16)
+                    .and_then(|b| b.try_into().ok())
+                    .map(u64::from_be_bytes)
+                    .ok_or_else(|| invalid("JP2 box"))?;
+                (16, usize::try_from(ext).unwrap_or(usize::MAX))
+            }
+            len => (8, len as usize),
+        };
+        if box_len < header_len || pos + box_len > frame.len() {
+            return Err(invalid("JP2 box"));
+        }
+        if box_type == b"jp2c" {
+            return Ok(&frame[pos + header_len..pos + box_len]);
+        }
+        pos += box_len;
+    }
+    Err(invalid("JP2 file without a codestream"))
+}
+
+/// Checks that the image described by the codestream matches the expected frame layout.
+fn check_layout(size: &ImageSize, layout: &FrameLayout) -> Result<(), CodecError> {
+    if size.width() != u32::from(layout.cols)
+        || size.height() != u32::from(layout.rows)
+        || size.components.len() != usize::from(layout.samples_per_pixel)
+    {
+        return Err(CodecError::UnsupportedLayout(format!(
+            "JPEG 2000 image of {}x{} with {} components does not match {}x{} with {} samples",
+            size.width(),
+            size.height(),
+            size.components.len(),
+            layout.cols,
+            layout.rows,
+            layout.samples_per_pixel
+        )));
+    }
+    if size.components.iter().any(|c| c.dx != 1 || c.dy != 1) {
+        return Err(CodecError::UnsupportedLayout(
+            "JPEG 2000 with subsampled components".to_owned(),
+        ));
+    }
+    if size
+        .components
+        .iter()
+        .any(|c| u16::from(c.precision) > layout.bits_alloc || c.precision > 31)
+    {
+        return Err(CodecError::UnsupportedLayout(format!(
+            "JPEG 2000 component precision exceeds {} bits allocated",
+            layout.bits_alloc
+        )));
+    }
+    Ok(())
+}
+
+/// Reads the marker segments of a codestream.
+struct CodestreamReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> CodestreamReader<'a> {
+    /// Creates a reader over the codestream, which must start with the SOC marker.
+    fn new(data: &'a [u8]) -> Result<Self, CodecError> {
+        if data.len() < 2 || u16::from_be_bytes([data[0], data[1]]) != SOC {
+            return Err(CodecError::InvalidData(
+                "JPEG 2000 codestream does not start with SOC marker".to_owned(),
+            ));
+        }
+        Ok(CodestreamReader { data, pos: 2 })
+    }
+
+    fn read_marker(&mut self) -> Result<u16, CodecError> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + 2)
+            .ok_or_else(|| invalid("marker"))?;
+        self.pos += 2;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Reads the length-prefixed segment at the current position, returning its contents.
+    fn read_segment(&mut self) -> Result<&'a [u8], CodecError> {
+        let len = self
+            .data
+            .get(self.pos..self.pos + 2)
+            .map(|b| usize::from(u16::from_be_bytes([b[0], b[1]])))
+            .ok_or_else(|| invalid("marker segment"))?;
+        if len < 2 || self.pos + len > self.data.len() {
+            return Err(invalid("marker segment length"));
+        }
+        let segment = &self.data[self.pos + 2..self.pos + len];
+        self.pos += len;
+        Ok(segment)
+    }
+
+    /// Reads the main header, through the first SOT marker.
+    fn read_main_header(&mut self) -> Result<(ImageSize, TileParams), CodecError> {
+        if self.read_marker()? != SIZ {
+            return Err(invalid("main header without SIZ"));
+        }
+        let size = read_size(self.read_segment()?)?;
+        let num_comps = size.components.len();
+        let markers = self.read_header_markers(num_comps)?;
+        let Some((defaults, style)) = markers.cod.clone() else {
+            return Err(invalid("main header without COD"));
+        };
+        let Some(quant) = markers.qcd.clone() else {
+            return Err(invalid("main header without QCD"));
+        };
+        let mut params = TileParams {
+            defaults,
+            styles: vec![style; num_comps],
+            quants: vec![quant; num_comps],
+        };
+        params.apply(markers)?;
+        Ok((size, params))
+    }
+
+    /// Reads the marker segments of a header, up to the SOT or SOD marker which ends it. The
+    /// position is left at that marker.
+    fn read_header_markers(&mut self, num_comps: usize) -> Result<HeaderMarkers, CodecError> {
+        let mut markers = HeaderMarkers::default();
+        loop {
+            let marker = self.read_marker()?;
+            match marker {
+                SOT | SOD => {
+                    self.pos -= 2;
+                    return Ok(markers);
+                }
+                COD => markers.cod = Some(read_cod(self.read_segment()?)?),
+                COC => markers.coc.push(read_coc(self.read_segment()?, num_comps)?),
+                QCD => markers.qcd = Some(read_quant(self.read_segment()?)?),
+                QCC => markers.qcc.push(read_qcc(self.read_segment()?, num_comps)?),
+                RGN | POC | PPM | PPT => {
+                    return Err(CodecError::UnsupportedLayout(format!(
+                        "JPEG 2000 with marker {marker:#06X}"
+                    )));
+                }
+                CAP | 0xFF30..=0xFF3F | 0xFF55..=0xFF59 | 0xFF63 | 0xFF64 => {
+                    self.read_segment()?;
+                }
+                _ => return Err(invalid(&format!("marker {marker:#06X}"))),
+            }
+        }
+    }
+
+    /// Reads the next tile-part, returning the tile index, the markers of its header, and the
+    /// packet data. Returns `None` once the end of the codestream is reached.
+    #[allow(clippy::type_complexity)]
+    fn next_tile_part(
+        &mut self,
+        num_comps: usize,
+    ) -> Result<Option<(usize, HeaderMarkers, &'a [u8])>, CodecError> {
+        if self.pos + 2 > self.data.len() {
+            return Ok(None);
+        }
+        let start = self.pos;
+        match self.read_marker()? {
+            SOT => {}
+            EOC => return Ok(None),
+            marker => return Err(invalid(&format!("marker {marker:#06X} before SOT"))),
+        }
+        let segment = self.read_segment()?;
+        if segment.len() < 8 {
+            return Err(invalid("SOT marker segment"));
+        }
+        let index = usize::from(u16::from_be_bytes([segment[0], segment[1]]));
+        let psot = u32::from_be_bytes([segment[2], segment[3], segment[4], segment[5]]) as usize;
+        let markers = self.read_header_markers(num_comps)?;
+        self.read_marker()?;
+        let end = if psot == 0 {
+            // The last tile-part may extend to the EOC marker.
+            let end = self.data.len();
+            if self.data.ends_with(&EOC.to_be_bytes()) {
+                end - 2
+            } else {
+                end
+            }
+        } else {
+            (start + psot).min(self.data.len())
+        };
+        if end < self.pos {
+            return Err(invalid("tile-part length"));
+        }
+        let data = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(Some((index, markers, data)))
+    }
+}
+
+fn read_u16(segment: &[u8], pos: usize) -> Result<u16, CodecError> {
+    segment
+        .get(pos..pos + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or_else(|| invalid("marker segment length"))
+}
+
+fn read_u32(segment: &[u8], pos: usize) -> Result<u32, CodecError> {
+    segment
+        .get(pos..pos + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| invalid("marker segment length"))
+}
+
+fn read_size(segment: &[u8]) -> Result<ImageSize, CodecError> {
+    let num_comps = usize::from(read_u16(segment, 34)?);
+    let mut components = Vec::with_capacity(num_comps);
+    for i in 0..num_comps {
+        let comp = segment
+            .get(36 + i * 3..39 + i * 3)
+            .ok_or_else(|| invalid("SIZ marker segment"))?;
+        components.push(SizComponent {
+            precision: (comp[0] & 0x7F) + 1,
+            signed: comp[0] & 0x80 != 0,
+            dx: u32::from(comp[1]),
+            dy: u32::from(comp[2]),
+        });
+    }
+    let size = ImageSize {
+        x1: read_u32(segment, 2)?,
+        y1: read_u32(segment, 6)?,
+        x0: read_u32(segment, 10)?,
+        y0: read_u32(segment, 14)?,
+        tile_w: read_u32(segment, 18)?,
+        tile_h: read_u32(segment, 22)?,
+        tile_x0: read_u32(segment, 26)?,
+        tile_y0: read_u32(segment, 30)?,
+        components,
+    };
+    if size.x0 >= size.x1
+        || size.y0 >= size.y1
+        || size.tile_w == 0
+        || size.tile_h == 0
+        || size.tile_x0 > size.x0
+        || size.tile_y0 > size.y0
+        || size.components.is_empty()
+        || size.components.iter().any(|c| c.dx == 0 || c.dy == 0)
+    {
+        return Err(invalid("SIZ marker segment"));
+    }
+    Ok(size)
+}
+
+fn read_cod(segment: &[u8]) -> Result<(CodingDefaults, CodingStyle), CodecError> {
+    let scod = *segment
+        .first()
+        .ok_or_else(|| invalid("COD marker segment"))?;
+    let progression = match segment.get(1) {
+        Some(0) => Progression::Lrcp,
+        Some(1) => Progression::Rlcp,
+        Some(2) => Progression::Rpcl,
+        Some(3) => Progression::Pcrl,
+        Some(4) => Progression::Cprl,
+        _ => return Err(invalid("progression order")),
+    };
+    let defaults = CodingDefaults {
+        sop: scod & 0x02 != 0,
+        eph: scod & 0x04 != 0,
+        progression,
+        layers: read_u16(segment, 2)?,
+        mct: segment.get(4).is_some_and(|m| *m != 0),
+    };
+    let style = read_coding_style(scod, segment.get(5..).unwrap_or_default())?;
+    Ok((defaults, style))
+}
+
+fn read_coc(segment: &[u8], num_comps: usize) -> Result<(usize, CodingStyle), CodecError> {
+    let (comp, rest) = read_comp_index(segment, num_comps)?;
+    let scoc = *rest.first().ok_or_else(|| invalid("COC marker segment"))?;
+    Ok((comp, read_coding_style(scoc, &rest[1..])?))
+}
+
+/// Reads the component index of a COC or QCC marker segment, which is two bytes if there are
+/// more than 256 components.
+fn read_comp_index(segment: &[u8], num_comps: usize) -> Result<(usize, &[u8]), CodecError> {
+    if num_comps > 256 {
+        Ok((
+            usize::from(read_u16(segment, 0)?),
+            segment.get(2..).unwrap_or_default(),
+        ))
+    } else {
+        let comp = segment.first().ok_or_else(|| invalid("component index"))?;
+        Ok((usize::from(*comp), &segment[1..]))
+    }
+}
+
+/// Reads the SPcod or SPcoc parameters, given the Scod or Scoc value indicating whether precinct
+/// sizes are defined.
+fn read_coding_style(scod: u8, params: &[u8]) -> Result<CodingStyle, CodecError> {
+    if params.len() < 5 {
+        return Err(invalid("coding style parameters"));
+    }
+    let levels = params[0];
+    let cb_w = params[1] + 2;
+    let cb_h = params[2] + 2;
+    if levels > 32 || cb_w > 10 || cb_h > 10 || cb_w + cb_h > 12 {
+        return Err(invalid("coding style parameters"));
+    }
+    let cb_style = params[3];
+    if cb_style & CBS_HT_MIXED != 0 {
+        return Err(CodecError::UnsupportedLayout(
+            "JPEG 2000 with mixed HT and Part 1 code-blocks".to_owned(),
+        ));
+    }
+    let precincts = if scod & 0x01 != 0 {
+        let sizes = params
+            .get(5..6 + usize::from(levels))
+            .ok_or_else(|| invalid("precinct sizes"))?;
+        sizes.iter().map(|s| (s & 0x0F, s >> 4)).collect()
+    } else {
+        vec![(15, 15); usize::from(levels) + 1]
+    };
+    Ok(CodingStyle {
+        levels,
+        cb_w,
+        cb_h,
+        cb_style,
+        reversible: params[4] == 1,
+        precincts,
+    })
+}
+
+fn read_qcc(segment: &[u8], num_comps: usize) -> Result<(usize, Quantization), CodecError> {
+    let (comp, rest) = read_comp_index(segment, num_comps)?;
+    Ok((comp, read_quant(rest)?))
+}
+
+/// Reads the Sqcd and SPqcd parameters, or the Sqcc and SPqcc parameters.
+fn read_quant(params: &[u8]) -> Result<Quantization, CodecError> {
+    let sqcd = *params.first().ok_or_else(|| invalid("quantization"))?;
+    let style = sqcd & 0x1F;
+    let steps = match style {
+        0 => params[1..].iter().map(|b| (b >> 3, 0)).collect(),
+        1 | 2 => params[1..]
+            .chunks_exact(2)
+            .map(|b| {
+                let val = u16::from_be_bytes([b[0], b[1]]);
+                ((val >> 11) as u8, val & 0x7FF)
+            })
+            .collect(),
+        _ => return Err(invalid("quantization style")),
+    };
+    Ok(Quantization {
+        style,
+        guard_bits: sqcd >> 5,
+        steps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::codec::{j2k::decode_frame, CodecError, FrameLayout};
+
+    /// Decodes the frame and compares each sample against `expected`, given the sample's column,
+    /// row and component, allowing a difference of `tolerance`.
+    fn check_decode(
+        j2k: &[u8],
+        layout: &FrameLayout,
+        expected: impl Fn(usize, usize, usize) -> usize,
+        tolerance: u16,
+    ) {
+        let decoded = decode_frame(j2k, layout).unwrap();
+        assert_eq!(layout.frame_len(), decoded.len());
+        let spp = usize::from(layout.samples_per_pixel);
+        let bps = layout.bytes_per_sample();
+        for (i, sample) in decoded.chunks_exact(bps).enumerate() {
+            let val = if bps == 1 {
+                u16::from(sample[0])
+            } else {
+                u16::from_le_bytes([sample[0], sample[1]])
+            };
+            let (pixel, c) = (i / spp, i % spp);
+            let (x, y) = (
+                pixel % usize::from(layout.cols),
+                pixel / usize::from(layout.cols),
+            );
+            let want = u16::try_from(expected(x, y, c)).unwrap();
+            assert!(
+                val.abs_diff(want) <= tolerance,
+                "sample ({x}, {y}, {c}) is {val}, expected {want}"
+            );
+        }
+    }
+
+    fn gray12(rows: u16, cols: u16) -> FrameLayout {
+        FrameLayout {
+            rows,
+            cols,
+            samples_per_pixel: 1,
+            bits_alloc: 16,
+            bits_stored: 12,
+            pixel_rep: 0,
+        }
+    }
+
+    fn rgb8(rows: u16, cols: u16) -> FrameLayout {
+        FrameLayout {
+            rows,
+            cols,
+            samples_per_pixel: 3,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        }
+    }
+
+    #[test]
+    pub fn test_decode_reversible() {
+        // A 7x5 12-bit image with an image offset of (1, 1), two decomposition levels and 4x4
+        // code-blocks.
+        let j2k: [u8; 160] = [
+            0xFF, 0x4F, 0xFF, 0x51, 0x00, 0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+            0x00, 0x06, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08,
+            0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0x0B, 0x01, 0x01, 0xFF, 0x52, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x01, 0xFF, 0x5C, 0x00, 0x0A, 0x40, 0x60, 0x68, 0x68, 0x70, 0x68, 0x68,
+            0x70, 0xFF, 0x90, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x57, 0x00, 0x01, 0xFF,
+            0x93, 0xC3, 0xF3, 0x02, 0x09, 0xBB, 0xC3, 0xF6, 0x04, 0x87, 0xEC, 0x05, 0x01, 0xF8,
+            0x01, 0x80, 0x09, 0x73, 0x14, 0x67, 0x07, 0x3F, 0x0A, 0x47, 0xEC, 0xC3, 0xF6, 0x0C,
+            0x87, 0xEC, 0x1D, 0x07, 0xEC, 0x1C, 0x0C, 0x38, 0x86, 0xE5, 0x97, 0x71, 0xC6, 0xC5,
+            0xD7, 0xF2, 0xF7, 0xB5, 0x16, 0xBB, 0xE4, 0x86, 0x30, 0xEF, 0xBF, 0x31, 0x2D, 0x1F,
+            0x64, 0x56, 0x81, 0x3F, 0x14, 0x9D, 0xF8, 0x22, 0x84, 0xA2, 0xC0, 0x27, 0x27, 0xC4,
+            0xA6, 0x19, 0x4C, 0x7F, 0xFF, 0xD9,
+        ];
+        check_decode(
+            &j2k,
+            &gray12(5, 7),
+            |x, y, _| (x * 311 + y * 173 + (x * y) % 7 * 97) % 4096,
+            0,
+        );
+    }
+
+    #[test]
+    pub fn test_decode_irreversible_rgb() {
+        // A 6x6 RGB image using the irreversible component transform and 9-7 wavelet, with a
+        // quantization step size of 0.5.
+        let j2k: [u8; 186] = [
+            0xFF, 0x4F, 0xFF, 0x51, 0x00, 0x2F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+            0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06,
+            0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+            0x07, 0x01, 0x01, 0x07, 0x01, 0x01, 0x07, 0x01, 0x01, 0xFF, 0x52, 0x00, 0x0C, 0x00,
+            0x00, 0x00, 0x01, 0x01, 0x01, 0x04, 0x04, 0x00, 0x00, 0xFF, 0x5C, 0x00, 0x0B, 0x42,
+            0x48, 0x00, 0x50, 0x00, 0x50, 0x00, 0x58, 0x00, 0xFF, 0x90, 0x00, 0x0A, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x6A, 0x00, 0x01, 0xFF, 0x93, 0xCF, 0xC0, 0x2C, 0x08, 0x39, 0x88,
+            0x02, 0xB6, 0x99, 0x2B, 0xA6, 0x1A, 0xDC, 0xB3, 0xC1, 0xF3, 0x87, 0x15, 0x55, 0xB7,
+            0xC0, 0xC2, 0x34, 0x14, 0xCF, 0xC0, 0x2C, 0x15, 0xCB, 0x5F, 0xE7, 0x29, 0x7E, 0x9F,
+            0x8D, 0xEB, 0xB5, 0xBF, 0xC0, 0xF9, 0xC3, 0xC1, 0xF5, 0x03, 0x80, 0x15, 0x88, 0xC9,
+            0x5C, 0xDF, 0x00, 0x37, 0x0C, 0xBC, 0xC4, 0x06, 0xEA, 0xD1, 0x47, 0xC0, 0x3E, 0x11,
+            0x20, 0x04, 0x40, 0x15, 0x8F, 0x66, 0x1F, 0x0E, 0x3F, 0xC0, 0xF9, 0xC3, 0xC0, 0xF9,
+            0xC3, 0x80, 0x15, 0x88, 0x90, 0xD3, 0x79, 0x0A, 0xDD, 0x0E, 0x3D, 0xB3, 0x1D, 0xC6,
+            0x07, 0x4E, 0xFF, 0xD9,
+        ];
+        check_decode(
+            &j2k,
+            &rgb8(6, 6),
+            |x, y, c| [x * 40 + y * 4, y * 30 + 10, (x + y) * 20][c],
+            2,
+        );
+    }
+
+    #[test]
+    pub fn test_decode_ht() {
+        // An 8x6 12-bit HTJ2K image with two decomposition levels.
+        let j2k: [u8; 185] = [
+            0xFF, 0x4F, 0xFF, 0x51, 0x00, 0x29, 0x40, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+            0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+            0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0x0B, 0x01, 0x01, 0xFF, 0x50, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xFF,
+            0x52, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x01, 0x01, 0x40, 0x01, 0xFF,
+            0x5C, 0x00, 0x0A, 0x40, 0x60, 0x68, 0x68, 0x70, 0x68, 0x68, 0x70, 0xFF, 0x90, 0x00,
+            0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x66, 0x00, 0x01, 0xFF, 0x93, 0xC0, 0x02, 0xAC,
+            0x9F, 0xB0, 0xEB, 0x1B, 0xFB, 0x73, 0x7F, 0x01, 0xC3, 0x35, 0x00, 0xC0, 0x01, 0x55,
+            0x00, 0x04, 0xD0, 0x00, 0x25, 0xF7, 0xDC, 0xA3, 0x1A, 0xA9, 0x7F, 0x01, 0x42, 0xB5,
+            0x00, 0x6D, 0xFF, 0x7F, 0x32, 0x34, 0x00, 0xFF, 0x7F, 0x09, 0x34, 0x00, 0xC0, 0x01,
+            0x69, 0xC0, 0x01, 0x59, 0x00, 0x02, 0x50, 0xBD, 0xEF, 0x7B, 0x8F, 0x1E, 0x24, 0xB1,
+            0xEE, 0x7D, 0x87, 0xE4, 0x3F, 0x0C, 0xFE, 0x62, 0x08, 0xB1, 0xB8, 0x00, 0x70, 0xA4,
+            0xD8, 0x24, 0xFE, 0xDF, 0x00, 0x31, 0x01, 0xBE, 0xB7, 0x00, 0xF8, 0xE7, 0x60, 0x74,
+            0x00, 0xFF, 0xD9,
+        ];
+        check_decode(
+            &j2k,
+            &gray12(6, 8),
+            |x, y, _| (x * x * 31 + y * 57 + x * y * 13) % 4096,
+            0,
+        );
+    }
+
+    #[test]
+    pub fn test_decode_ht_rgb() {
+        // A 5x4 RGB HTJ2K image using the reversible component transform.
+        let j2k: [u8; 191] = [
+            0xFF, 0x4F, 0xFF, 0x51, 0x00, 0x2F, 0x40, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+            0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05,
+            0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+            0x07, 0x01, 0x01, 0x07, 0x01, 0x01, 0x07, 0x01, 0x01, 0xFF, 0x50, 0x00, 0x08, 0x00,
+            0x02, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x52, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x01,
+            0x01, 0x04, 0x04, 0x40, 0x01, 0xFF, 0x5C, 0x00, 0x07, 0x40, 0x40, 0x48, 0x48, 0x50,
+            0xFF, 0x90, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x69, 0x00, 0x01, 0xFF, 0x93,
+            0xC0, 0x2B, 0x40, 0xFF, 0x0B, 0xD5, 0x66, 0xAA, 0xF1, 0x3F, 0x00, 0x00, 0x01, 0x7F,
+            0x37, 0x00, 0xC0, 0x2A, 0xC0, 0xDF, 0x81, 0xC9, 0xA3, 0xF8, 0x3F, 0x20, 0x83, 0x7C,
+            0xB6, 0x00, 0xC0, 0x2B, 0x00, 0x53, 0xCD, 0x1B, 0x1B, 0xB1, 0x3F, 0x00, 0x20, 0x01,
+            0x7C, 0xB7, 0x00, 0xC0, 0x12, 0xC0, 0x15, 0x10, 0x02, 0x50, 0xFC, 0x7F, 0x01, 0xE4,
+            0x00, 0xB2, 0x06, 0x8C, 0x3F, 0xA4, 0xE7, 0x55, 0x00, 0xFC, 0x7F, 0x01, 0xE4, 0x00,
+            0xA0, 0x09, 0xC0, 0xE3, 0xBF, 0x3F, 0xD1, 0xDE, 0x75, 0x00, 0xA0, 0x0A, 0x80, 0xDD,
+            0xAE, 0xFB, 0x3F, 0xE4, 0x77, 0x55, 0x00, 0xFF, 0xD9,
+        ];
+        check_decode(
+            &j2k,
+            &rgb8(4, 5),
+            |x, y, c| [x * 40 + y * 3, y * 50 + x, x * y * 9][c],
+            0,
+        );
+    }
+
+    #[test]
+    pub fn test_decode_layout_mismatch() {
+        let j2k: [u8; 160] = [
+            0xFF, 0x4F, 0xFF, 0x51, 0x00, 0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+            0x00, 0x06, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08,
+            0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0x0B, 0x01, 0x01, 0xFF, 0x52, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x01, 0xFF, 0x5C, 0x00, 0x0A, 0x40, 0x60, 0x68, 0x68, 0x70, 0x68, 0x68,
+            0x70, 0xFF, 0x90, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x57, 0x00, 0x01, 0xFF,
+            0x93, 0xC3, 0xF3, 0x02, 0x09, 0xBB, 0xC3, 0xF6, 0x04, 0x87, 0xEC, 0x05, 0x01, 0xF8,
+            0x01, 0x80, 0x09, 0x73, 0x14, 0x67, 0x07, 0x3F, 0x0A, 0x47, 0xEC, 0xC3, 0xF6, 0x0C,
+            0x87, 0xEC, 0x1D, 0x07, 0xEC, 0x1C, 0x0C, 0x38, 0x86, 0xE5, 0x97, 0x71, 0xC6, 0xC5,
+            0xD7, 0xF2, 0xF7, 0xB5, 0x16, 0xBB, 0xE4, 0x86, 0x30, 0xEF, 0xBF, 0x31, 0x2D, 0x1F,
+            0x64, 0x56, 0x81, 0x3F, 0x14, 0x9D, 0xF8, 0x22, 0x84, 0xA2, 0xC0, 0x27, 0x27, 0xC4,
+            0xA6, 0x19, 0x4C, 0x7F, 0xFF, 0xD9,
+        ];
+        let result = decode_frame(&j2k, &gray12(6, 7));
+        assert!(matches!(result, Err(CodecError::UnsupportedLayout(_))));
+        let result = decode_frame(&j2k[..40], &gray12(5, 7));
+        assert!(matches!(result, Err(CodecError::InvalidData(_))));
+    }
+}
@@ -0,0 +1,248 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The MQ arithmetic decoder, ISO/IEC 15444-1 Annex C, and the raw (bypass) bit reader used by
+//! the selective arithmetic coding bypass mode, Annex D.6.
+
+/// The probability estimation state machine, Table C.2, as `(Qe, NMPS, NLPS, SWITCH)`.
+const QE_TABLE: [(u32, u8, u8, bool); 47] = [
+    (0x5601, 1, 1, true),
+    (0x3401, 2, 6, false),
+    (0x1801, 3, 9, false),
+    (0x0AC1, 4, 12, false),
+    (0x0521, 5, 29, false),
+    (0x0221, 38, 33, false),
+    (0x5601, 7, 6, true),
+    (0x5401, 8, 14, false),
+    (0x4801, 9, 14, false),
+    (0x3801, 10, 14, false),
+    (0x3001, 11, 17, false),
+    (0x2401, 12, 18, false),
+    (0x1C01, 13, 20, false),
+    (0x1601, 29, 21, false),
+    (0x5601, 15, 14, true),
+    (0x5401, 16, 14, false),
+    (0x5101, 17, 15, false),
+    (0x4801, 18, 16, false),
+    (0x3801, 19, 17, false),
+    (0x3401, 20, 18, false),
+    (0x3001, 21, 19, false),
+    (0x2801, 22, 19, false),
+    (0x2401, 23, 20, false),
+    (0x2201, 24, 21, false),
+    (0x1C01, 25, 22, false),
+    (0x1801, 26, 23, false),
+    (0x1601, 27, 24, false),
+    (0x1401, 28, 25, false),
+    (0x1201, 29, 26, false),
+    (0x1101, 30, 27, false),
+    (0x0AC1, 31, 28, false),
+    (0x09C1, 32, 29, false),
+    (0x08A1, 33, 30, false),
+    (0x0521, 34, 31, false),
+    (0x0441, 35, 32, false),
+    (0x02A1, 36, 33, false),
+    (0x0221, 37, 34, false),
+    (0x0141, 38, 35, false),
+    (0x0111, 39, 36, false),
+    (0x0085, 40, 37, false),
+    (0x0049, 41, 38, false),
+    (0x0025, 42, 39, false),
+    (0x0015, 43, 40, false),
+    (0x0009, 44, 41, false),
+    (0x0005, 45, 42, false),
+    (0x0001, 45, 43, false),
+    (0x5601, 46, 46, false),
+];
+
+/// The state of a single context: the index into `QE_TABLE` and the more probable symbol.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct Context {
+    index: u8,
+    mps: u8,
+}
+
+impl Context {
+    /// A context starting at the given state index with an MPS of zero.
+    pub(super) fn new(index: u8) -> Self {
+        Context { index, mps: 0 }
+    }
+}
+
+/// Decodes the arithmetic-coded segments of a code-block, using the software conventions of
+/// Section C.3. Reading past the end of the segment behaves as though it were followed by a
+/// marker, producing 1-bits.
+pub(super) struct MqDecoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+    a: u32,
+    c: u32,
+    ct: u32,
+}
+
+impl<'a> MqDecoder<'a> {
+    /// Initializes the decoder over a terminated segment, INITDEC.
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        let mut mq = MqDecoder {
+            data,
+            pos: 0,
+            a: 0x8000,
+            c: 0,
+            ct: 0,
+        };
+        mq.c = u32::from(mq.byte(0)) << 16;
+        mq.byte_in();
+        mq.c <<= 7;
+        mq.ct -= 7;
+        mq
+    }
+
+    fn byte(&self, pos: usize) -> u8 {
+        self.data.get(pos).copied().unwrap_or(0xFF)
+    }
+
+    /// BYTEIN, which also handles the stuffed bit following an 0xFF byte.
+    fn byte_in(&mut self) {
+        if self.byte(self.pos) == 0xFF {
+            if self.byte(self.pos + 1) > 0x8F {
+                self.c = self.c.wrapping_add(0xFF00);
+                self.ct = 8;
+            } else {
+                self.pos += 1;
+                self.c = self.c.wrapping_add(u32::from(self.byte(self.pos)) << 9);
+                self.ct = 7;
+            }
+        } else {
+            self.pos += 1;
+            self.c = self.c.wrapping_add(u32::from(self.byte(self.pos)) << 8);
+            self.ct = 8;
+        }
+    }
+
+    /// RENORMD.
+    fn renormalize(&mut self) {
+        loop {
+            if self.ct == 0 {
+                self.byte_in();
+            }
+            self.a <<= 1;
+            self.c <<= 1;
+            self.ct -= 1;
+            if self.a & 0x8000 != 0 {
+                break;
+            }
+        }
+    }
+
+    /// Decodes a single binary decision in the given context, DECODE.
+    pub(super) fn decode(&mut self, cx: &mut Context) -> u8 {
+        let (qe, nmps, nlps, switch) = QE_TABLE[usize::from(cx.index)];
+        self.a -= qe;
+        let d;
+        if (self.c >> 16) < qe {
+            // LPS_EXCHANGE.
+            if self.a < qe {
+                d = cx.mps;
+                cx.index = nmps;
+            } else {
+                d = 1 - cx.mps;
+                if switch {
+                    cx.mps = 1 - cx.mps;
+                }
+                cx.index = nlps;
+            }
+            self.a = qe;
+            self.renormalize();
+        } else {
+            self.c -= qe << 16;
+            if self.a & 0x8000 != 0 {
+                return cx.mps;
+            }
+            // MPS_EXCHANGE.
+            if self.a < qe {
+                d = 1 - cx.mps;
+                if switch {
+                    cx.mps = 1 - cx.mps;
+                }
+                cx.index = nlps;
+            } else {
+                d = cx.mps;
+                cx.index = nmps;
+            }
+            self.renormalize();
+        }
+        d
+    }
+}
+
+/// Reads the raw bits of a segment coded with the arithmetic coder bypassed. A zero bit is stuffed
+/// after every 0xFF byte, and reading past the end of the segment produces 1-bits.
+pub(super) struct RawReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    byte: u8,
+    bits: u8,
+}
+
+impl<'a> RawReader<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        RawReader {
+            data,
+            pos: 0,
+            byte: 0,
+            bits: 0,
+        }
+    }
+
+    pub(super) fn read_bit(&mut self) -> u8 {
+        if self.bits == 0 {
+            let stuffed = self.byte == 0xFF;
+            self.byte = self.data.get(self.pos).copied().unwrap_or(0xFF);
+            self.pos += 1;
+            self.bits = if stuffed { 7 } else { 8 };
+        }
+        self.bits -= 1;
+        (self.byte >> self.bits) & 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::codec::j2k::mq::{Context, MqDecoder};
+
+    #[test]
+    pub fn test_decode_reference_sequence() {
+        // The test sequence for the MQ coder from ITU-T T.88 Annex H.2, coded with one context.
+        let coded: [u8; 30] = [
+            0x84, 0xC7, 0x3B, 0xFC, 0xE1, 0xA1, 0x43, 0x04, 0x02, 0x20, 0x00, 0x00, 0x41, 0x0D,
+            0xBB, 0x86, 0xF4, 0x31, 0x7F, 0xFF, 0x88, 0xFF, 0x37, 0x47, 0x1A, 0xDB, 0x6A, 0xDF,
+            0xFF, 0xAC,
+        ];
+        let expected: [u8; 32] = [
+            0x00, 0x02, 0x00, 0x51, 0x00, 0x00, 0x00, 0xC0, 0x03, 0x52, 0x87, 0x2A, 0xAA, 0xAA,
+            0xAA, 0xAA, 0x82, 0xC0, 0x20, 0x00, 0xFC, 0xD7, 0x9E, 0xF6, 0xBF, 0x7F, 0xED, 0x90,
+            0x4F, 0x46, 0xA3, 0xBF,
+        ];
+
+        let mut mq = MqDecoder::new(&coded);
+        let mut cx = Context::new(0);
+        let decoded = expected
+            .iter()
+            .map(|_| (0..8).fold(0u8, |byte, _| (byte << 1) | mq.decode(&mut cx)))
+            .collect::<Vec<u8>>();
+        assert_eq!(expected.as_slice(), decoded.as_slice());
+    }
+}
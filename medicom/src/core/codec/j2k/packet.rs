@@ -0,0 +1,358 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Reading packets in progression order, ISO/IEC 15444-1 Annex B.9 through B.12, which
+//! distributes the coded data of each layer to the code-blocks of a precinct.
+
+use crate::core::codec::{
+    j2k::{
+        tile::{CodeBlock, Resolution, Segment, TileComponent},
+        Progression, TileParams, CBS_BYPASS, CBS_HT, CBS_TERMALL,
+    },
+    CodecError,
+};
+
+/// Start of Packet.
+const SOP: [u8; 2] = [0xFF, 0x91];
+/// End of Packet Header.
+const EPH: [u8; 2] = [0xFF, 0x92];
+
+/// A tag tree, Section B.10.2, coding a two-dimensional array of values such that each node
+/// holds the minimum of its children.
+#[derive(Debug)]
+pub(super) struct TagTree {
+    /// The width of each level, starting with the leaves.
+    widths: Vec<usize>,
+    /// The index into `nodes` of the first node of each level.
+    offsets: Vec<usize>,
+    /// The value of each node, or `u32::MAX` if it is not yet known.
+    values: Vec<u32>,
+    /// The lower bound of each node's value which has been established so far.
+    lows: Vec<u32>,
+}
+
+impl TagTree {
+    pub(super) fn new(width: usize, height: usize) -> Self {
+        let mut widths = Vec::new();
+        let mut offsets = Vec::new();
+        let (mut w, mut h) = (width, height);
+        let mut total = 0;
+        while w > 0 && h > 0 {
+            widths.push(w);
+            offsets.push(total);
+            total += w * h;
+            if w == 1 && h == 1 {
+                break;
+            }
+            (w, h) = (w.div_ceil(2), h.div_ceil(2));
+        }
+        TagTree {
+            widths,
+            offsets,
+            values: vec![u32::MAX; total],
+            lows: vec![0; total],
+        }
+    }
+
+    /// Decodes whether the value of the leaf at `(x, y)` is less than the threshold, reading as
+    /// many bits as needed to establish that.
+    fn decode(
+        &mut self,
+        bits: &mut HeaderBits,
+        x: usize,
+        y: usize,
+        threshold: u32,
+    ) -> Result<bool, CodecError> {
+        let mut low = 0;
+        for level in (0..self.widths.len()).rev() {
+            let node = self.offsets[level] + (y >> level) * self.widths[level] + (x >> level);
+            if low > self.lows[node] {
+                self.lows[node] = low;
+            } else {
+                low = self.lows[node];
+            }
+            while low < threshold && low < self.values[node] {
+                if bits.read_bit()? == 1 {
+                    self.values[node] = low;
+                } else {
+                    low += 1;
+                }
+            }
+            self.lows[node] = low;
+        }
+        Ok(self.values[self.offsets[0] + y * self.widths[0] + x] < threshold)
+    }
+
+    /// Decodes the value of the leaf at `(x, y)`.
+    fn decode_value(
+        &mut self,
+        bits: &mut HeaderBits,
+        x: usize,
+        y: usize,
+    ) -> Result<u32, CodecError> {
+        let mut threshold = 1;
+        while !self.decode(bits, x, y, threshold)? {
+            threshold += 1;
+        }
+        Ok(self.values[self.offsets[0] + y * self.widths[0] + x])
+    }
+}
+
+/// Reads the bits of a packet header, Section B.10.1. A zero bit is stuffed after every 0xFF
+/// byte.
+struct HeaderBits<'a> {
+    data: &'a [u8],
+    pos: usize,
+    buf: u32,
+    bits: u32,
+}
+
+impl<'a> HeaderBits<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        HeaderBits {
+            data,
+            pos: 0,
+            buf: 0,
+            bits: 0,
+        }
+    }
+
+    fn byte_in(&mut self) -> Result<(), CodecError> {
+        self.buf = (self.buf << 8) & 0xFFFF;
+        self.bits = if self.buf == 0xFF00 { 7 } else { 8 };
+        let byte = self.data.get(self.pos).ok_or_else(|| {
+            CodecError::InvalidData("JPEG 2000 packet header is truncated".to_owned())
+        })?;
+        self.buf |= u32::from(*byte);
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn read_bit(&mut self) -> Result<u32, CodecError> {
+        if self.bits == 0 {
+            self.byte_in()?;
+        }
+        self.bits -= 1;
+        Ok((self.buf >> self.bits) & 1)
+    }
+
+    fn read_bits(&mut self, count: u32) -> Result<u32, CodecError> {
+        let mut val = 0;
+        for _ in 0..count {
+            val = (val << 1) | self.read_bit()?;
+        }
+        Ok(val)
+    }
+
+    /// Ends the packet header, skipping the stuffed bits of a final 0xFF byte, and returns the
+    /// number of bytes read.
+    fn finish(&mut self) -> Result<usize, CodecError> {
+        if self.buf & 0xFF == 0xFF {
+            self.byte_in()?;
+        }
+        Ok(self.pos)
+    }
+
+    /// Reads the number of coding passes, Table B.4.
+    fn read_num_passes(&mut self) -> Result<u32, CodecError> {
+        if self.read_bit()? == 0 {
+            return Ok(1);
+        }
+        if self.read_bit()? == 0 {
+            return Ok(2);
+        }
+        let val = self.read_bits(2)?;
+        if val != 3 {
+            return Ok(3 + val);
+        }
+        let val = self.read_bits(5)?;
+        if val != 31 {
+            return Ok(6 + val);
+        }
+        Ok(37 + self.read_bits(7)?)
+    }
+}
+
+/// Reads the packets of a tile in its progression order, accumulating the coded data of each
+/// code-block. Reading stops early if the tile's data is exhausted, as a truncated codestream
+/// still decodes to a lower quality image.
+pub(super) fn read_packets(
+    comps: &mut [TileComponent],
+    params: &TileParams,
+    data: &[u8],
+) -> Result<(), CodecError> {
+    let layers = u32::from(params.defaults.layers);
+    let mut order = Vec::new();
+    for (c, comp) in comps.iter().enumerate() {
+        for (r, res) in comp.resolutions.iter().enumerate() {
+            for (p, precinct) in res.precincts.iter().enumerate() {
+                let (y, x) = (precinct.ref_y as usize, precinct.ref_x as usize);
+                for l in 0..layers as usize {
+                    let key = match params.defaults.progression {
+                        Progression::Lrcp => [l, r, c, p, 0],
+                        Progression::Rlcp => [r, l, c, p, 0],
+                        Progression::Rpcl => [r, y, x, c, l],
+                        Progression::Pcrl => [y, x, c, r, l],
+                        Progression::Cprl => [c, y, x, r, l],
+                    };
+                    order.push((key, c, r, p, l));
+                }
+            }
+        }
+    }
+    order.sort_unstable_by_key(|(key, ..)| *key);
+
+    let mut pos = 0usize;
+    for (_, c, r, p, l) in order {
+        if pos >= data.len() {
+            break;
+        }
+        let style = params.styles[c].cb_style;
+        let layer = u32::try_from(l).unwrap_or(u32::MAX);
+        pos += read_packet(
+            &mut comps[c].resolutions[r],
+            p,
+            layer,
+            style,
+            params,
+            &data[pos..],
+        )?;
+    }
+    Ok(())
+}
+
+/// The contribution of a packet to a code-block: the band and code-block indices and, for each
+/// codeword segment, the segment index and length of the data.
+type Contribution = (usize, usize, Vec<(usize, usize)>);
+
+/// Reads a single packet of a precinct, returning the number of bytes read.
+fn read_packet(
+    res: &mut Resolution,
+    precinct_idx: usize,
+    layer: u32,
+    style: u8,
+    params: &TileParams,
+    data: &[u8],
+) -> Result<usize, CodecError> {
+    let mut pos = 0usize;
+    if params.defaults.sop && data.starts_with(&SOP) {
+        pos += 6;
+    }
+    let mut bits = HeaderBits::new(data.get(pos..).unwrap_or_default());
+    let mut contributions: Vec<Contribution> = Vec::new();
+    if bits.read_bit()? == 1 {
+        let precinct = &mut res.precincts[precinct_idx];
+        for pband in &mut precinct.bands {
+            let wide = pband.wide;
+            for (k, &cb_idx) in pband.codeblocks.iter().enumerate() {
+                let (x, y) = (k % wide, k / wide);
+                let cb = &mut res.bands[pband.band].codeblocks[cb_idx];
+                let included = if cb.included {
+                    bits.read_bit()? == 1
+                } else {
+                    pband.inclusion.decode(&mut bits, x, y, layer + 1)?
+                };
+                if !included {
+                    continue;
+                }
+                if !cb.included {
+                    cb.zero_bitplanes = pband.zero_bitplanes.decode_value(&mut bits, x, y)?;
+                    cb.included = true;
+                }
+                let passes = bits.read_num_passes()?;
+                while bits.read_bit()? == 1 {
+                    cb.lblock += 1;
+                }
+                let lengths = read_segment_lengths(cb, passes, style, &mut bits)?;
+                contributions.push((pband.band, cb_idx, lengths));
+            }
+        }
+    }
+    pos += bits.finish()?;
+    if params.defaults.eph && data.get(pos..).is_some_and(|d| d.starts_with(&EPH)) {
+        pos += 2;
+    }
+
+    for (band, cb_idx, lengths) in contributions {
+        let cb = &mut res.bands[band].codeblocks[cb_idx];
+        for (seg, len) in lengths {
+            let end = (pos + len).min(data.len());
+            cb.segments[seg]
+                .data
+                .extend_from_slice(data.get(pos..end).unwrap_or_default());
+            pos = end;
+        }
+    }
+    Ok(pos)
+}
+
+/// Assigns the new coding passes of a code-block to codeword segments, reading the length of the
+/// data contributed to each segment, Section B.10.7.
+fn read_segment_lengths(
+    cb: &mut CodeBlock,
+    passes: u32,
+    style: u8,
+    bits: &mut HeaderBits,
+) -> Result<Vec<(usize, usize)>, CodecError> {
+    let mut lengths = Vec::new();
+    let mut remaining = passes;
+    while remaining > 0 {
+        if cb.segments.last().is_none_or(|s| s.passes == s.max_passes) {
+            cb.segments.push(Segment {
+                data: Vec::new(),
+                passes: 0,
+                max_passes: max_passes(cb.passes, cb.segments.len(), style),
+            });
+        }
+        let seg_idx = cb.segments.len() - 1;
+        let seg = &mut cb.segments[seg_idx];
+        let count = remaining.min(seg.max_passes - seg.passes);
+        let len = bits.read_bits(cb.lblock + count.ilog2())?;
+        lengths.push((seg_idx, len as usize));
+        seg.passes += count;
+        cb.passes += count;
+        remaining -= count;
+    }
+    if style & CBS_HT != 0 && cb.passes > 3 {
+        return Err(CodecError::UnsupportedLayout(
+            "HTJ2K code-blocks with more than one HT set".to_owned(),
+        ));
+    }
+    Ok(lengths)
+}
+
+/// The number of coding passes in a codeword segment starting with the given pass, which depends
+/// on the code-block style.
+fn max_passes(first_pass: u32, segment_idx: usize, style: u8) -> u32 {
+    if style & CBS_HT != 0 {
+        // The HT cleanup pass is followed by a segment with the refinement passes.
+        return if segment_idx == 0 { 1 } else { 2 };
+    }
+    if style & CBS_TERMALL != 0 {
+        return 1;
+    }
+    if style & CBS_BYPASS != 0 {
+        // The first four bit-planes are arithmetic coded, followed by alternating raw segments of
+        // the significance propagation and magnitude refinement passes, and arithmetic coded
+        // cleanup passes.
+        return match first_pass {
+            0..10 => 10 - first_pass,
+            _ if (first_pass - 10) % 3 == 0 => 2,
+            _ => 1,
+        };
+    }
+    u32::MAX
+}
@@ -0,0 +1,424 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The EBCOT code-block decoder, ISO/IEC 15444-1 Annex D, which decodes the significance
+//! propagation, magnitude refinement and cleanup passes of each bit-plane.
+
+use crate::core::codec::{
+    j2k::{
+        mq::{Context, MqDecoder, RawReader},
+        tile::{BandKind, CodeBlock},
+        CBS_BYPASS, CBS_CAUSAL, CBS_RESET, CBS_SEGSYM,
+    },
+    CodecError,
+};
+
+const SIG: u8 = 0x01;
+const NEG: u8 = 0x02;
+const VISITED: u8 = 0x04;
+const REFINED: u8 = 0x08;
+
+/// The first sign coding context.
+const CTX_SC: usize = 9;
+/// The first magnitude refinement context.
+const CTX_MR: usize = 14;
+const CTX_RUN: usize = 17;
+const CTX_UNIFORM: usize = 18;
+const NUM_CTX: usize = 19;
+
+/// The kind of coding pass, in the order they occur within a bit-plane after the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pass {
+    SigProp,
+    MagRef,
+    Cleanup,
+}
+
+/// Either decoder used for a segment of coded data.
+enum SegmentDecoder<'a> {
+    Mq(MqDecoder<'a>),
+    Raw(RawReader<'a>),
+}
+
+/// Decodes the coding passes of a code-block into quantized coefficients, stored with the sign
+/// applied in `out`, which has a row stride of the code-block's width.
+///
+/// Coefficients whose least significant bit-planes were not coded are reconstructed at the
+/// midpoint of the remaining uncertainty.
+pub(super) fn decode_block(
+    cb: &CodeBlock,
+    kind: BandKind,
+    mb: u32,
+    style: u8,
+    out: &mut [i32],
+) -> Result<(), CodecError> {
+    let width = cb.width();
+    let height = cb.height();
+    let Some(mut plane) = (mb - 1).checked_sub(cb.zero_bitplanes) else {
+        return Err(CodecError::InvalidData(
+            "JPEG 2000 code-block has more missing bit-planes than magnitude bit-planes".to_owned(),
+        ));
+    };
+
+    let mut block = BlockState::new(width, height, kind, style & CBS_CAUSAL != 0);
+    let mut last_plane = plane;
+    let mut pass_idx = 0u32;
+    for segment in &cb.segments {
+        let mut decoder: Option<SegmentDecoder> = None;
+        for _ in 0..segment.passes {
+            let pass = match pass_idx {
+                0 => Pass::Cleanup,
+                n => match (n - 1) % 3 {
+                    0 => Pass::SigProp,
+                    1 => Pass::MagRef,
+                    _ => Pass::Cleanup,
+                },
+            };
+            if pass_idx > 0 && pass == Pass::SigProp {
+                let Some(next) = plane.checked_sub(1) else {
+                    block.finish(last_plane, out);
+                    return Ok(());
+                };
+                plane = next;
+            }
+            let raw = style & CBS_BYPASS != 0 && pass_idx >= 10 && pass != Pass::Cleanup;
+            let decoder = decoder.get_or_insert_with(|| {
+                if raw {
+                    SegmentDecoder::Raw(RawReader::new(&segment.data))
+                } else {
+                    SegmentDecoder::Mq(MqDecoder::new(&segment.data))
+                }
+            });
+            match (pass, decoder) {
+                (Pass::SigProp, SegmentDecoder::Mq(mq)) => block.sig_prop(plane, mq),
+                (Pass::SigProp, SegmentDecoder::Raw(raw)) => block.sig_prop_raw(plane, raw),
+                (Pass::MagRef, SegmentDecoder::Mq(mq)) => block.mag_ref(plane, mq),
+                (Pass::MagRef, SegmentDecoder::Raw(raw)) => block.mag_ref_raw(plane, raw),
+                (Pass::Cleanup, SegmentDecoder::Mq(mq)) => {
+                    block.cleanup(plane, mq);
+                    if style & CBS_SEGSYM != 0 {
+                        for _ in 0..4 {
+                            mq.decode(&mut block.contexts[CTX_UNIFORM]);
+                        }
+                    }
+                }
+                (Pass::Cleanup, SegmentDecoder::Raw(_)) => {
+                    return Err(CodecError::InvalidData(
+                        "JPEG 2000 cleanup pass in a bypassed segment".to_owned(),
+                    ));
+                }
+            }
+            if style & CBS_RESET != 0 {
+                block.reset_contexts();
+            }
+            last_plane = plane;
+            pass_idx += 1;
+        }
+    }
+    block.finish(last_plane, out);
+    Ok(())
+}
+
+/// The state of the coefficients of a code-block while decoding. The flags have a border of one
+/// sample on each side so neighbors can be read without bounds checks.
+struct BlockState {
+    width: usize,
+    height: usize,
+    kind: BandKind,
+    causal: bool,
+    flags: Vec<u8>,
+    mags: Vec<u32>,
+    contexts: [Context; NUM_CTX],
+}
+
+impl BlockState {
+    fn new(width: usize, height: usize, kind: BandKind, causal: bool) -> Self {
+        let mut block = BlockState {
+            width,
+            height,
+            kind,
+            causal,
+            flags: vec![0u8; (width + 2) * (height + 2)],
+            mags: vec![0u32; width * height],
+            contexts: [Context::default(); NUM_CTX],
+        };
+        block.reset_contexts();
+        block
+    }
+
+    fn reset_contexts(&mut self) {
+        self.contexts = [Context::default(); NUM_CTX];
+        self.contexts[0] = Context::new(4);
+        self.contexts[CTX_RUN] = Context::new(3);
+        self.contexts[CTX_UNIFORM] = Context::new(46);
+    }
+
+    /// The index into `flags` of the sample at the given position.
+    fn fidx(&self, x: usize, y: usize) -> usize {
+        (y + 1) * (self.width + 2) + x + 1
+    }
+
+    /// Whether the neighbors below the sample should be ignored, which is the case for the last
+    /// row of a stripe in vertically causal mode.
+    fn below_ignored(&self, y: usize) -> bool {
+        self.causal && y % 4 == 3
+    }
+
+    /// The zero coding context of a sample, Table D.1.
+    fn zc_context(&self, x: usize, y: usize) -> usize {
+        let i = self.fidx(x, y);
+        let stride = self.width + 2;
+        let sig = |j: usize| usize::from(self.flags[j] & SIG);
+        let below = !self.below_ignored(y);
+        let h = sig(i - 1) + sig(i + 1);
+        let mut v = sig(i - stride);
+        let mut d = sig(i - stride - 1) + sig(i - stride + 1);
+        if below {
+            v += sig(i + stride);
+            d += sig(i + stride - 1) + sig(i + stride + 1);
+        }
+        let (h, v) = match self.kind {
+            BandKind::HL => (v, h),
+            _ => (h, v),
+        };
+        if self.kind == BandKind::HH {
+            let hv = h + v;
+            return match (d, hv) {
+                (3.., _) => 8,
+                (2, 1..) => 7,
+                (2, _) => 6,
+                (1, 2..) => 5,
+                (1, 1) => 4,
+                (1, _) => 3,
+                (_, 2..) => 2,
+                (_, 1) => 1,
+                _ => 0,
+            };
+        }
+        match (h, v, d) {
+            (2, _, _) => 8,
+            (1, 1.., _) => 7,
+            (1, _, 1..) => 6,
+            (1, _, _) => 5,
+            (_, 2, _) => 4,
+            (_, 1, _) => 3,
+            (_, _, 2..) => 2,
+            (_, _, 1) => 1,
+            _ => 0,
+        }
+    }
+
+    /// The sign coding context and the bit to XOR with the decoded sign, Table D.3.
+    fn sc_context(&self, x: usize, y: usize) -> (usize, u8) {
+        let i = self.fidx(x, y);
+        let stride = self.width + 2;
+        let contrib = |j: usize| -> i32 {
+            match self.flags[j] & (SIG | NEG) {
+                SIG => 1,
+                f if f == SIG | NEG => -1,
+                _ => 0,
+            }
+        };
+        let h = (contrib(i - 1) + contrib(i + 1)).clamp(-1, 1);
+        let below = if self.below_ignored(y) {
+            0
+        } else {
+            contrib(i + stride)
+        };
+        let v = (contrib(i - stride) + below).clamp(-1, 1);
+        match (h, v) {
+            (1, 1) => (CTX_SC + 4, 0),
+            (1, 0) => (CTX_SC + 3, 0),
+            (1, _) => (CTX_SC + 2, 0),
+            (0, 1) => (CTX_SC + 1, 0),
+            (0, 0) => (CTX_SC, 0),
+            (0, _) => (CTX_SC + 1, 1),
+            (_, 1) => (CTX_SC + 2, 1),
+            (_, 0) => (CTX_SC + 3, 1),
+            _ => (CTX_SC + 4, 1),
+        }
+    }
+
+    /// Marks the sample as significant with the given sign at the bit-plane.
+    fn set_significant(&mut self, x: usize, y: usize, negative: bool, plane: u32) {
+        let i = self.fidx(x, y);
+        self.flags[i] |= SIG;
+        if negative {
+            self.flags[i] |= NEG;
+        }
+        self.mags[y * self.width + x] |= 1 << plane;
+    }
+
+    fn decode_sign(&mut self, x: usize, y: usize, mq: &mut MqDecoder) -> bool {
+        let (ctx, xor) = self.sc_context(x, y);
+        mq.decode(&mut self.contexts[ctx]) ^ xor == 1
+    }
+
+    /// Visits each sample in stripe order: stripes of four rows, each scanned column by column.
+    fn for_each_sample(&mut self, mut visit: impl FnMut(&mut Self, usize, usize)) {
+        for y0 in (0..self.height).step_by(4) {
+            for x in 0..self.width {
+                for y in y0..(y0 + 4).min(self.height) {
+                    visit(self, x, y);
+                }
+            }
+        }
+    }
+
+    /// The significance propagation pass, Section D.3.1.
+    fn sig_prop(&mut self, plane: u32, mq: &mut MqDecoder) {
+        self.for_each_sample(|block, x, y| {
+            let i = block.fidx(x, y);
+            if block.flags[i] & SIG != 0 {
+                return;
+            }
+            let ctx = block.zc_context(x, y);
+            if ctx == 0 {
+                return;
+            }
+            block.flags[i] |= VISITED;
+            if mq.decode(&mut block.contexts[ctx]) == 1 {
+                let negative = block.decode_sign(x, y, mq);
+                block.set_significant(x, y, negative, plane);
+            }
+        });
+    }
+
+    /// The significance propagation pass with the arithmetic coder bypassed, Section D.6.
+    fn sig_prop_raw(&mut self, plane: u32, raw: &mut RawReader) {
+        self.for_each_sample(|block, x, y| {
+            let i = block.fidx(x, y);
+            if block.flags[i] & SIG != 0 || block.zc_context(x, y) == 0 {
+                return;
+            }
+            block.flags[i] |= VISITED;
+            if raw.read_bit() == 1 {
+                let negative = raw.read_bit() == 1;
+                block.set_significant(x, y, negative, plane);
+            }
+        });
+    }
+
+    /// The magnitude refinement context of a significant sample, Table D.4.
+    fn mr_context(&self, x: usize, y: usize) -> usize {
+        let i = self.fidx(x, y);
+        if self.flags[i] & REFINED != 0 {
+            return CTX_MR + 2;
+        }
+        let stride = self.width + 2;
+        let sig = |j: usize| self.flags[j] & SIG != 0;
+        let above = sig(i - 1) || sig(i + 1) || (i - stride - 1..=i - stride + 1).any(sig);
+        let below = !self.below_ignored(y) && (i + stride - 1..=i + stride + 1).any(sig);
+        if above || below {
+            CTX_MR + 1
+        } else {
+            CTX_MR
+        }
+    }
+
+    /// The magnitude refinement pass, Section D.3.3.
+    fn mag_ref(&mut self, plane: u32, mq: &mut MqDecoder) {
+        self.for_each_sample(|block, x, y| {
+            let i = block.fidx(x, y);
+            if block.flags[i] & (SIG | VISITED) != SIG {
+                return;
+            }
+            let ctx = block.mr_context(x, y);
+            let bit = mq.decode(&mut block.contexts[ctx]);
+            block.mags[y * block.width + x] |= u32::from(bit) << plane;
+            block.flags[i] |= REFINED;
+        });
+    }
+
+    /// The magnitude refinement pass with the arithmetic coder bypassed, Section D.6.
+    fn mag_ref_raw(&mut self, plane: u32, raw: &mut RawReader) {
+        self.for_each_sample(|block, x, y| {
+            let i = block.fidx(x, y);
+            if block.flags[i] & (SIG | VISITED) != SIG {
+                return;
+            }
+            let bit = raw.read_bit();
+            block.mags[y * block.width + x] |= u32::from(bit) << plane;
+            block.flags[i] |= REFINED;
+        });
+    }
+
+    /// The cleanup pass, Section D.3.4, which also clears the samples visited by the
+    /// significance propagation pass of the bit-plane.
+    fn cleanup(&mut self, plane: u32, mq: &mut MqDecoder) {
+        for y0 in (0..self.height).step_by(4) {
+            let rows = (self.height - y0).min(4);
+            for x in 0..self.width {
+                let mut start = 0;
+                let run_mode = rows == 4
+                    && (y0..y0 + 4).all(|y| {
+                        self.flags[self.fidx(x, y)] & (SIG | VISITED) == 0
+                            && self.zc_context(x, y) == 0
+                    });
+                if run_mode {
+                    if mq.decode(&mut self.contexts[CTX_RUN]) == 0 {
+                        continue;
+                    }
+                    let hi = mq.decode(&mut self.contexts[CTX_UNIFORM]);
+                    let lo = mq.decode(&mut self.contexts[CTX_UNIFORM]);
+                    let y = y0 + usize::from(hi << 1 | lo);
+                    let negative = self.decode_sign(x, y, mq);
+                    self.set_significant(x, y, negative, plane);
+                    start = y - y0 + 1;
+                }
+                for y in y0 + start..y0 + rows {
+                    let i = self.fidx(x, y);
+                    if self.flags[i] & (SIG | VISITED) != 0 {
+                        continue;
+                    }
+                    let ctx = self.zc_context(x, y);
+                    if mq.decode(&mut self.contexts[ctx]) == 1 {
+                        let negative = self.decode_sign(x, y, mq);
+                        self.set_significant(x, y, negative, plane);
+                    }
+                }
+            }
+        }
+        for flag in &mut self.flags {
+            *flag &= !VISITED;
+        }
+    }
+
+    /// Writes the signed coefficients, reconstructed at the midpoint of the bit-planes that were
+    /// not decoded.
+    fn finish(&self, last_plane: u32, out: &mut [i32]) {
+        let half = if last_plane > 0 {
+            1u32 << (last_plane - 1)
+        } else {
+            0
+        };
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let mag = self.mags[y * self.width + x];
+                let val = if mag == 0 {
+                    0
+                } else {
+                    i32::try_from(mag | half).unwrap_or(i32::MAX)
+                };
+                out[y * self.width + x] = if self.flags[self.fidx(x, y)] & NEG != 0 {
+                    -val
+                } else {
+                    val
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,538 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The division of a tile into tile-components, resolution levels, subbands, precincts and
+//! code-blocks, ISO/IEC 15444-1 Annex B, and the reconstruction of the tile's samples.
+
+use crate::core::codec::{
+    j2k::{
+        dwt, ht,
+        packet::{self, TagTree},
+        t1, CodingStyle, ImageSize, Quantization, TileParams, CBS_HT,
+    },
+    CodecError,
+};
+
+/// The orientation of a subband, indicating which directions were high-pass filtered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BandKind {
+    LL,
+    HL,
+    LH,
+    HH,
+}
+
+impl BandKind {
+    /// The horizontal and vertical offsets of the subband, Table B.1.
+    fn offsets(self) -> (u32, u32) {
+        match self {
+            BandKind::LL => (0, 0),
+            BandKind::HL => (1, 0),
+            BandKind::LH => (0, 1),
+            BandKind::HH => (1, 1),
+        }
+    }
+
+    /// The base 2 logarithm of the subband's gain, Table E.1.
+    fn log_gain(self) -> i32 {
+        match self {
+            BandKind::LL => 0,
+            BandKind::HL | BandKind::LH => 1,
+            BandKind::HH => 2,
+        }
+    }
+}
+
+/// A codeword segment of a code-block, holding the data of one or more coding passes which were
+/// terminated together.
+#[derive(Debug, Clone, Default)]
+pub(super) struct Segment {
+    pub data: Vec<u8>,
+    pub passes: u32,
+    /// The number of passes after which the segment is terminated.
+    pub max_passes: u32,
+}
+
+/// A code-block of a subband, with the coded data accumulated from each packet.
+#[derive(Debug, Clone)]
+pub(super) struct CodeBlock {
+    pub x0: u32,
+    pub y0: u32,
+    pub x1: u32,
+    pub y1: u32,
+    pub included: bool,
+    /// The number of missing most significant bit-planes.
+    pub zero_bitplanes: u32,
+    pub lblock: u32,
+    pub passes: u32,
+    pub segments: Vec<Segment>,
+}
+
+impl CodeBlock {
+    pub(super) fn width(&self) -> usize {
+        (self.x1 - self.x0) as usize
+    }
+
+    pub(super) fn height(&self) -> usize {
+        (self.y1 - self.y0) as usize
+    }
+}
+
+/// A subband of a resolution level.
+#[derive(Debug)]
+pub(super) struct Band {
+    pub kind: BandKind,
+    pub x0: u32,
+    pub y0: u32,
+    pub x1: u32,
+    pub y1: u32,
+    /// The number of magnitude bit-planes, `Mb` of Equation E-2.
+    pub mb: u32,
+    /// The quantization step size, which is one for reversible transforms.
+    pub step: f32,
+    pub codeblocks: Vec<CodeBlock>,
+}
+
+impl Band {
+    fn width(&self) -> usize {
+        (self.x1 - self.x0) as usize
+    }
+
+    fn height(&self) -> usize {
+        (self.y1 - self.y0) as usize
+    }
+}
+
+/// The code-blocks of a subband which belong to a precinct, with the tag trees used to code
+/// their inclusion and missing bit-planes in packet headers.
+#[derive(Debug)]
+pub(super) struct PrecinctBand {
+    pub band: usize,
+    /// Indices into the subband's code-blocks, in raster order.
+    pub codeblocks: Vec<usize>,
+    /// The number of code-blocks in each row of the precinct.
+    pub wide: usize,
+    pub inclusion: TagTree,
+    pub zero_bitplanes: TagTree,
+}
+
+/// A precinct of a resolution level.
+#[derive(Debug)]
+pub(super) struct Precinct {
+    pub bands: Vec<PrecinctBand>,
+    /// The position on the reference grid at which the precinct occurs for position-driven
+    /// progression orders, Section B.12.1.3.
+    pub ref_x: u32,
+    pub ref_y: u32,
+}
+
+/// A resolution level of a tile-component.
+#[derive(Debug)]
+pub(super) struct Resolution {
+    pub x0: u32,
+    pub y0: u32,
+    pub x1: u32,
+    pub y1: u32,
+    pub bands: Vec<Band>,
+    pub precincts: Vec<Precinct>,
+}
+
+/// A component of a tile.
+#[derive(Debug)]
+pub(super) struct TileComponent {
+    pub x0: u32,
+    pub y0: u32,
+    pub x1: u32,
+    pub resolutions: Vec<Resolution>,
+}
+
+/// The reconstructed samples of a tile-component, which are integers for the reversible
+/// transform.
+enum Samples {
+    Int(Vec<i32>),
+    Float(Vec<f32>),
+}
+
+/// Decodes the packet data of a tile, writing the reconstructed samples of each component into
+/// `planes`, which cover the image area.
+pub(super) fn decode_tile(
+    size: &ImageSize,
+    index: u32,
+    params: &TileParams,
+    data: &[u8],
+    planes: &mut [Vec<i32>],
+) -> Result<(), CodecError> {
+    let p = index % size.tiles_wide();
+    let q = index / size.tiles_wide();
+    let tx0 = (size.tile_x0 + p * size.tile_w).max(size.x0);
+    let ty0 = (size.tile_y0 + q * size.tile_h).max(size.y0);
+    let tx1 = (size.tile_x0 + (p + 1) * size.tile_w).min(size.x1);
+    let ty1 = (size.tile_y0 + (q + 1) * size.tile_h).min(size.y1);
+
+    let mut comps = Vec::with_capacity(size.components.len());
+    for (c, comp) in size.components.iter().enumerate() {
+        comps.push(build_component(
+            [
+                tx0.div_ceil(comp.dx),
+                ty0.div_ceil(comp.dy),
+                tx1.div_ceil(comp.dx),
+                ty1.div_ceil(comp.dy),
+            ],
+            [tx0, ty0, comp.dx, comp.dy],
+            &params.styles[c],
+            &params.quants[c],
+            comp.precision,
+        )?);
+    }
+
+    packet::read_packets(&mut comps, params, data)?;
+
+    let mut samples = Vec::with_capacity(comps.len());
+    for (comp, style) in comps.iter().zip(&params.styles) {
+        samples.push(reconstruct(comp, style)?);
+    }
+    if params.defaults.mct && samples.len() >= 3 {
+        inverse_mct(&mut samples)?;
+    }
+
+    for (c, (comp, samples)) in comps.iter().zip(samples).enumerate() {
+        let siz = &size.components[c];
+        let (min, max, shift) = if siz.signed {
+            (
+                -(1i64 << (siz.precision - 1)),
+                (1i64 << (siz.precision - 1)) - 1,
+                0,
+            )
+        } else {
+            (0, (1i64 << siz.precision) - 1, 1i64 << (siz.precision - 1))
+        };
+        let width = (comp.x1 - comp.x0) as usize;
+        let image_w = size.width() as usize;
+        let (off_x, off_y) = ((comp.x0 - size.x0) as usize, (comp.y0 - size.y0) as usize);
+        for (i, val) in samples_iter(&samples).enumerate() {
+            let (x, y) = (i % width, i / width);
+            #[allow(clippy::cast_possible_truncation)]
+            let val = (val + shift).clamp(min, max) as i32;
+            planes[c][(off_y + y) * image_w + off_x + x] = val;
+        }
+    }
+    Ok(())
+}
+
+/// The samples rounded to integers.
+fn samples_iter(samples: &Samples) -> Box<dyn Iterator<Item = i64> + '_> {
+    match samples {
+        Samples::Int(vals) => Box::new(vals.iter().map(|v| i64::from(*v))),
+        #[allow(clippy::cast_possible_truncation)]
+        Samples::Float(vals) => Box::new(vals.iter().map(|v| v.round() as i64)),
+    }
+}
+
+/// Divides a tile-component into its resolution levels, subbands, precincts and code-blocks.
+/// The bounds are given as `[x0, y0, x1, y1]` in the component's coordinates, and the reference
+/// as the tile's origin on the reference grid and the component's subsampling factors.
+fn build_component(
+    bounds: [u32; 4],
+    reference: [u32; 4],
+    style: &CodingStyle,
+    quant: &Quantization,
+    precision: u8,
+) -> Result<TileComponent, CodecError> {
+    let [x0, y0, x1, y1] = bounds;
+    let [tx0, ty0, dx, dy] = reference;
+    let levels = u32::from(style.levels);
+    let mut resolutions = Vec::with_capacity(usize::from(style.levels) + 1);
+    let mut band_idx = 0usize;
+    for r in 0..=levels {
+        let scale = levels - r;
+        let (rx0, ry0) = (ceil_shr(x0, scale), ceil_shr(y0, scale));
+        let (rx1, ry1) = (ceil_shr(x1, scale), ceil_shr(y1, scale));
+        let (ppx, ppy) = style.precincts[r as usize];
+        let (ppx, ppy) = (u32::from(ppx), u32::from(ppy));
+        if r > 0 && (ppx == 0 || ppy == 0) {
+            return Err(CodecError::InvalidData(
+                "JPEG 2000 precinct size of one for a resolution with high-pass subbands"
+                    .to_owned(),
+            ));
+        }
+
+        let kinds: &[BandKind] = if r == 0 {
+            &[BandKind::LL]
+        } else {
+            &[BandKind::HL, BandKind::LH, BandKind::HH]
+        };
+        // The precinct and code-block sizes are halved in the subbands of higher resolutions.
+        let band_shift = u32::from(r > 0);
+        let cb_w = u32::from(style.cb_w).min(ppx - band_shift);
+        let cb_h = u32::from(style.cb_h).min(ppy - band_shift);
+        let level = if r == 0 { levels } else { levels - r + 1 };
+        let mut bands = Vec::with_capacity(kinds.len());
+        for kind in kinds {
+            let (xo, yo) = kind.offsets();
+            let (bx0, by0) = (band_coord(x0, xo, level), band_coord(y0, yo, level));
+            let (bx1, by1) = (band_coord(x1, xo, level), band_coord(y1, yo, level));
+            let (exp, mant) =
+                quant.step(band_idx, u8::try_from(level).unwrap_or(0), style.levels)?;
+            band_idx += 1;
+            let mb = u32::from(quant.guard_bits) + u32::from(exp);
+            let Some(mb) = mb.checked_sub(1).filter(|mb| (1..=30).contains(mb)) else {
+                return Err(CodecError::UnsupportedLayout(format!(
+                    "JPEG 2000 subband with {mb} magnitude bit-planes"
+                )));
+            };
+            let range = i32::from(precision) + kind.log_gain();
+            let step = if style.reversible {
+                1.0
+            } else {
+                2f32.powi(range - i32::from(exp)) * (1.0 + f32::from(mant) / 2048.0)
+            };
+            let mut codeblocks = Vec::new();
+            if bx0 < bx1 && by0 < by1 {
+                for j in (by0 >> cb_h)..by1.div_ceil(1 << cb_h) {
+                    for i in (bx0 >> cb_w)..bx1.div_ceil(1 << cb_w) {
+                        codeblocks.push(CodeBlock {
+                            x0: (i << cb_w).max(bx0),
+                            y0: (j << cb_h).max(by0),
+                            x1: ((i + 1) << cb_w).min(bx1),
+                            y1: ((j + 1) << cb_h).min(by1),
+                            included: false,
+                            zero_bitplanes: 0,
+                            lblock: 3,
+                            passes: 0,
+                            segments: Vec::new(),
+                        });
+                    }
+                }
+            }
+            bands.push(Band {
+                kind: *kind,
+                x0: bx0,
+                y0: by0,
+                x1: bx1,
+                y1: by1,
+                mb,
+                step,
+                codeblocks,
+            });
+        }
+
+        let mut precincts = Vec::new();
+        if rx0 < rx1 && ry0 < ry1 {
+            let (px0, py0) = (rx0 >> ppx, ry0 >> ppy);
+            let (px1, py1) = (rx1.div_ceil(1 << ppx), ry1.div_ceil(1 << ppy));
+            for py in py0..py1 {
+                for px in px0..px1 {
+                    let mut precinct_bands = Vec::with_capacity(bands.len());
+                    for (b, band) in bands.iter().enumerate() {
+                        precinct_bands.push(precinct_band(
+                            b,
+                            band,
+                            [px, py],
+                            [ppx - band_shift, ppy - band_shift],
+                            [cb_w, cb_h],
+                        ));
+                    }
+                    // Precincts are visited on the reference grid at multiples of their size,
+                    // or at the tile's origin for the first precinct if it is not aligned.
+                    let ref_x = if px == px0 && (rx0 << scale) % (1 << (ppx + scale)) != 0 {
+                        tx0
+                    } else {
+                        (px << (ppx + scale)) * dx
+                    };
+                    let ref_y = if py == py0 && (ry0 << scale) % (1 << (ppy + scale)) != 0 {
+                        ty0
+                    } else {
+                        (py << (ppy + scale)) * dy
+                    };
+                    precincts.push(Precinct {
+                        bands: precinct_bands,
+                        ref_x,
+                        ref_y,
+                    });
+                }
+            }
+        }
+        resolutions.push(Resolution {
+            x0: rx0,
+            y0: ry0,
+            x1: rx1,
+            y1: ry1,
+            bands,
+            precincts,
+        });
+    }
+    Ok(TileComponent {
+        x0,
+        y0,
+        x1,
+        resolutions,
+    })
+}
+
+/// The code-blocks of a subband within the precinct at `[px, py]`, given the precinct and
+/// code-block size exponents within the subband.
+fn precinct_band(
+    band_idx: usize,
+    band: &Band,
+    [px, py]: [u32; 2],
+    [ppx, ppy]: [u32; 2],
+    [cb_w, cb_h]: [u32; 2],
+) -> PrecinctBand {
+    let mut codeblocks = Vec::new();
+    let (mut wide, mut high) = (0, 0);
+    if band.x0 < band.x1 && band.y0 < band.y1 {
+        let band_cbx0 = band.x0 >> cb_w;
+        let band_cby0 = band.y0 >> cb_h;
+        let band_cbs_wide = band.x1.div_ceil(1 << cb_w) - band_cbx0;
+        let cbx0 = ((px << ppx) >> cb_w).max(band_cbx0);
+        let cby0 = ((py << ppy) >> cb_h).max(band_cby0);
+        let cbx1 = (((px + 1) << ppx) >> cb_w).min(band.x1.div_ceil(1 << cb_w));
+        let cby1 = (((py + 1) << ppy) >> cb_h).min(band.y1.div_ceil(1 << cb_h));
+        if cbx0 < cbx1 && cby0 < cby1 {
+            wide = (cbx1 - cbx0) as usize;
+            high = (cby1 - cby0) as usize;
+            for j in cby0..cby1 {
+                for i in cbx0..cbx1 {
+                    let idx = (j - band_cby0) * band_cbs_wide + (i - band_cbx0);
+                    codeblocks.push(idx as usize);
+                }
+            }
+        }
+    }
+    PrecinctBand {
+        band: band_idx,
+        codeblocks,
+        wide,
+        inclusion: TagTree::new(wide, high),
+        zero_bitplanes: TagTree::new(wide, high),
+    }
+}
+
+/// `ceil(val / 2^shift)`.
+fn ceil_shr(val: u32, shift: u32) -> u32 {
+    u32::try_from((u64::from(val) + (1u64 << shift) - 1) >> shift).unwrap_or(u32::MAX)
+}
+
+/// The coordinate of a subband, given a coordinate of the tile-component, the subband's offset
+/// and its decomposition level, Equation B-15.
+fn band_coord(val: u32, offset: u32, level: u32) -> u32 {
+    if level == 0 {
+        return val;
+    }
+    let shifted = i64::from(val) - (i64::from(offset) << (level - 1));
+    let div = 1i64 << level;
+    u32::try_from((shifted + div - 1).div_euclid(div)).unwrap_or(0)
+}
+
+/// Decodes the code-blocks of each subband and applies the inverse wavelet transform to
+/// reconstruct the samples of the tile-component.
+fn reconstruct(comp: &TileComponent, style: &CodingStyle) -> Result<Samples, CodecError> {
+    let mut levels = comp.resolutions.iter();
+    let Some(res0) = levels.next() else {
+        return Ok(Samples::Int(Vec::new()));
+    };
+    let ll = decode_band(&res0.bands[0], style)?;
+    if style.reversible {
+        let mut cur = ll;
+        let mut prev = res0;
+        for res in levels {
+            let bands = res
+                .bands
+                .iter()
+                .map(|b| decode_band(b, style))
+                .collect::<Result<Vec<_>, _>>()?;
+            cur = dwt::inverse_53(&cur, prev, &bands, res);
+            prev = res;
+        }
+        Ok(Samples::Int(cur))
+    } else {
+        let to_float = |band: &Band, coeffs: Vec<i32>| -> Vec<f32> {
+            #[allow(clippy::cast_precision_loss)]
+            coeffs.into_iter().map(|q| q as f32 * band.step).collect()
+        };
+        let mut cur = to_float(&res0.bands[0], ll);
+        let mut prev = res0;
+        for res in levels {
+            let bands = res
+                .bands
+                .iter()
+                .map(|b| Ok(to_float(b, decode_band(b, style)?)))
+                .collect::<Result<Vec<_>, CodecError>>()?;
+            cur = dwt::inverse_97(&cur, prev, &bands, res);
+            prev = res;
+        }
+        Ok(Samples::Float(cur))
+    }
+}
+
+/// Decodes the code-blocks of a subband into its quantized coefficients.
+fn decode_band(band: &Band, style: &CodingStyle) -> Result<Vec<i32>, CodecError> {
+    let width = band.width();
+    let mut coeffs = vec![0i32; width * band.height()];
+    let mut block = Vec::new();
+    for cb in &band.codeblocks {
+        if cb.passes == 0 {
+            continue;
+        }
+        block.clear();
+        block.resize(cb.width() * cb.height(), 0);
+        if style.cb_style & CBS_HT != 0 {
+            ht::decode_block(cb, band.mb, &mut block)?;
+        } else {
+            t1::decode_block(cb, band.kind, band.mb, style.cb_style, &mut block)?;
+        }
+        let off_x = (cb.x0 - band.x0) as usize;
+        let off_y = (cb.y0 - band.y0) as usize;
+        for (y, row) in block.chunks_exact(cb.width()).enumerate() {
+            let start = (off_y + y) * width + off_x;
+            coeffs[start..start + row.len()].copy_from_slice(row);
+        }
+    }
+    Ok(coeffs)
+}
+
+/// Applies the inverse multiple component transform to the first three components, using the
+/// reversible component transform for integer samples or the irreversible component transform
+/// otherwise, Annex G.
+fn inverse_mct(samples: &mut [Samples]) -> Result<(), CodecError> {
+    match samples {
+        [Samples::Int(y0), Samples::Int(y1), Samples::Int(y2), ..]
+            if y0.len() == y1.len() && y0.len() == y2.len() =>
+        {
+            for i in 0..y0.len() {
+                let g = y0[i] - ((y1[i] + y2[i]) >> 2);
+                let r = y2[i] + g;
+                let b = y1[i] + g;
+                (y0[i], y1[i], y2[i]) = (r, g, b);
+            }
+            Ok(())
+        }
+        [Samples::Float(y), Samples::Float(cb), Samples::Float(cr), ..]
+            if y.len() == cb.len() && y.len() == cr.len() =>
+        {
+            for i in 0..y.len() {
+                let r = y[i] + 1.402 * cr[i];
+                let g = y[i] - 0.344_136 * cb[i] - 0.714_136 * cr[i];
+                let b = y[i] + 1.772 * cb[i];
+                (y[i], cb[i], cr[i]) = (r, g, b);
+            }
+            Ok(())
+        }
+        _ => Err(CodecError::UnsupportedLayout(
+            "JPEG 2000 multiple component transform of mismatched components".to_owned(),
+        )),
+    }
+}
@@ -0,0 +1,866 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The variable-length codes of the HT cleanup pass, ISO/IEC 15444-15 Annex C.
+//!
+//! The codes are listed as `[c_q, rho, u_off, e_k, e_1, codeword, length]`, where `c_q` is the
+//! quad context, `rho` the significance pattern of the quad, and `e_k`/`e_1` the patterns of
+//! samples whose exponent bound is reduced or whose most significant bit is implicit. The
+//! codewords are read least-significant bit first.
+
+use std::sync::OnceLock;
+
+/// The codes used for the initial row of quads.
+const TABLE0: [[u8; 7]; 444] = [
+    [0, 2, 0, 0, 0, 0, 3],
+    [0, 10, 0, 0, 0, 1, 5],
+    [0, 4, 0, 0, 0, 2, 3],
+    [0, 6, 0, 0, 0, 3, 6],
+    [0, 8, 0, 0, 0, 4, 3],
+    [0, 14, 1, 10, 8, 5, 6],
+    [0, 1, 0, 0, 0, 6, 4],
+    [0, 13, 1, 13, 8, 7, 7],
+    [0, 11, 1, 1, 0, 9, 6],
+    [0, 15, 1, 15, 5, 11, 7],
+    [0, 7, 1, 2, 0, 13, 6],
+    [0, 5, 0, 0, 0, 14, 5],
+    [0, 5, 1, 5, 1, 15, 7],
+    [0, 3, 0, 0, 0, 17, 5],
+    [0, 4, 1, 4, 4, 19, 6],
+    [0, 12, 1, 12, 4, 21, 6],
+    [0, 12, 1, 12, 12, 23, 7],
+    [0, 15, 1, 14, 2, 25, 6],
+    [0, 15, 1, 9, 9, 27, 7],
+    [0, 9, 0, 0, 0, 29, 6],
+    [0, 12, 0, 0, 0, 30, 5],
+    [0, 3, 1, 3, 1, 31, 7],
+    [0, 5, 1, 4, 4, 35, 6],
+    [0, 12, 1, 12, 8, 37, 6],
+    [0, 13, 1, 1, 1, 39, 7],
+    [0, 15, 1, 15, 4, 41, 6],
+    [0, 15, 1, 15, 12, 43, 7],
+    [0, 9, 1, 0, 0, 45, 6],
+    [0, 7, 0, 0, 0, 47, 7],
+    [0, 15, 1, 15, 1, 51, 7],
+    [0, 10, 1, 8, 8, 53, 6],
+    [0, 11, 0, 0, 0, 55, 7],
+    [0, 15, 1, 15, 8, 57, 6],
+    [0, 14, 1, 14, 4, 59, 7],
+    [0, 8, 1, 8, 8, 61, 6],
+    [0, 1, 1, 1, 1, 63, 7],
+    [0, 13, 1, 5, 4, 71, 7],
+    [0, 14, 1, 2, 2, 75, 7],
+    [0, 7, 1, 2, 2, 79, 7],
+    [0, 11, 1, 1, 1, 87, 7],
+    [0, 15, 0, 0, 0, 91, 7],
+    [0, 3, 1, 2, 2, 95, 7],
+    [0, 13, 0, 0, 0, 103, 7],
+    [0, 15, 1, 11, 10, 107, 7],
+    [0, 6, 1, 0, 0, 111, 7],
+    [0, 15, 1, 14, 6, 115, 7],
+    [0, 10, 1, 10, 2, 119, 7],
+    [0, 14, 0, 0, 0, 123, 7],
+    [0, 2, 1, 2, 2, 127, 7],
+    [1, 0, 0, 0, 0, 0, 2],
+    [1, 12, 0, 0, 0, 1, 5],
+    [1, 8, 0, 0, 0, 2, 4],
+    [1, 7, 1, 2, 0, 3, 6],
+    [1, 12, 1, 12, 4, 5, 6],
+    [1, 2, 0, 0, 0, 6, 4],
+    [1, 15, 0, 0, 0, 7, 7],
+    [1, 15, 1, 10, 2, 9, 6],
+    [1, 4, 0, 0, 0, 10, 4],
+    [1, 5, 0, 0, 0, 11, 6],
+    [1, 9, 1, 0, 0, 13, 6],
+    [1, 1, 0, 0, 0, 14, 4],
+    [1, 13, 0, 0, 0, 15, 7],
+    [1, 11, 1, 1, 0, 17, 6],
+    [1, 6, 0, 0, 0, 19, 6],
+    [1, 10, 1, 8, 8, 21, 6],
+    [1, 13, 1, 1, 1, 23, 7],
+    [1, 14, 1, 10, 8, 25, 6],
+    [1, 3, 0, 0, 0, 27, 6],
+    [1, 8, 1, 8, 8, 29, 6],
+    [1, 1, 1, 1, 1, 31, 7],
+    [1, 6, 1, 0, 0, 35, 6],
+    [1, 12, 1, 8, 8, 37, 6],
+    [1, 15, 1, 8, 8, 39, 7],
+    [1, 15, 1, 11, 8, 41, 6],
+    [1, 4, 1, 4, 4, 43, 6],
+    [1, 9, 0, 0, 0, 45, 6],
+    [1, 11, 0, 0, 0, 47, 7],
+    [1, 15, 1, 14, 4, 49, 6],
+    [1, 5, 1, 4, 4, 51, 6],
+    [1, 10, 0, 0, 0, 53, 6],
+    [1, 14, 0, 0, 0, 55, 7],
+    [1, 13, 1, 5, 4, 57, 6],
+    [1, 2, 1, 2, 2, 59, 6],
+    [1, 3, 1, 0, 0, 61, 6],
+    [1, 7, 0, 0, 0, 63, 7],
+    [1, 15, 1, 15, 1, 71, 7],
+    [1, 11, 1, 1, 1, 79, 7],
+    [1, 14, 1, 2, 2, 87, 7],
+    [1, 7, 1, 2, 2, 95, 7],
+    [1, 14, 1, 14, 4, 103, 7],
+    [1, 10, 1, 10, 2, 111, 7],
+    [1, 13, 1, 13, 8, 119, 7],
+    [1, 5, 1, 5, 1, 127, 7],
+    [2, 0, 0, 0, 0, 0, 2],
+    [2, 5, 0, 0, 0, 1, 5],
+    [2, 8, 0, 0, 0, 2, 4],
+    [2, 6, 0, 0, 0, 3, 6],
+    [2, 13, 1, 8, 8, 5, 6],
+    [2, 2, 0, 0, 0, 6, 4],
+    [2, 15, 1, 3, 2, 7, 7],
+    [2, 15, 1, 1, 1, 9, 6],
+    [2, 4, 0, 0, 0, 10, 4],
+    [2, 4, 1, 4, 4, 11, 6],
+    [2, 10, 1, 0, 0, 13, 6],
+    [2, 1, 0, 0, 0, 14, 4],
+    [2, 11, 0, 0, 0, 15, 7],
+    [2, 7, 1, 3, 1, 17, 6],
+    [2, 5, 1, 5, 1, 19, 6],
+    [2, 12, 0, 0, 0, 21, 6],
+    [2, 13, 0, 0, 0, 23, 7],
+    [2, 14, 1, 2, 0, 25, 6],
+    [2, 1, 1, 1, 1, 27, 6],
+    [2, 9, 1, 0, 0, 29, 6],
+    [2, 7, 0, 0, 0, 31, 7],
+    [2, 5, 1, 5, 4, 35, 6],
+    [2, 12, 1, 4, 4, 37, 6],
+    [2, 14, 0, 0, 0, 39, 7],
+    [2, 15, 1, 15, 1, 41, 6],
+    [2, 3, 0, 0, 0, 43, 6],
+    [2, 10, 0, 0, 0, 45, 6],
+    [2, 5, 1, 5, 5, 47, 7],
+    [2, 15, 1, 7, 4, 49, 6],
+    [2, 3, 1, 1, 1, 51, 6],
+    [2, 11, 1, 2, 0, 53, 6],
+    [2, 7, 1, 7, 4, 55, 7],
+    [2, 13, 1, 12, 4, 57, 6],
+    [2, 15, 1, 15, 8, 59, 7],
+    [2, 9, 0, 0, 0, 61, 6],
+    [2, 2, 1, 2, 2, 63, 7],
+    [2, 15, 0, 0, 0, 71, 7],
+    [2, 8, 1, 8, 8, 79, 7],
+    [2, 12, 1, 12, 8, 87, 7],
+    [2, 6, 1, 0, 0, 95, 7],
+    [2, 13, 1, 13, 1, 103, 7],
+    [2, 7, 1, 2, 2, 111, 7],
+    [2, 11, 1, 2, 2, 119, 7],
+    [2, 14, 1, 2, 2, 123, 7],
+    [2, 3, 1, 3, 2, 127, 7],
+    [3, 0, 0, 0, 0, 0, 3],
+    [3, 13, 1, 13, 4, 1, 6],
+    [3, 15, 1, 15, 4, 2, 5],
+    [3, 15, 1, 15, 12, 3, 7],
+    [3, 1, 0, 0, 0, 4, 4],
+    [3, 3, 1, 1, 1, 5, 6],
+    [3, 5, 0, 0, 0, 6, 5],
+    [3, 8, 1, 8, 8, 7, 7],
+    [3, 11, 1, 3, 2, 9, 6],
+    [3, 8, 0, 0, 0, 10, 5],
+    [3, 14, 0, 0, 0, 11, 7],
+    [3, 2, 0, 0, 0, 12, 5],
+    [3, 5, 1, 5, 1, 13, 6],
+    [3, 15, 1, 10, 10, 14, 6],
+    [3, 7, 1, 7, 2, 15, 7],
+    [3, 12, 1, 4, 4, 17, 6],
+    [3, 15, 1, 15, 8, 18, 5],
+    [3, 14, 1, 4, 4, 19, 7],
+    [3, 7, 1, 6, 4, 21, 6],
+    [3, 4, 0, 0, 0, 22, 5],
+    [3, 10, 1, 10, 2, 23, 7],
+    [3, 10, 0, 0, 0, 25, 6],
+    [3, 5, 1, 5, 5, 26, 5],
+    [3, 13, 1, 9, 9, 27, 7],
+    [3, 15, 1, 15, 1, 28, 5],
+    [3, 3, 0, 0, 0, 29, 6],
+    [3, 15, 1, 15, 5, 30, 6],
+    [3, 6, 1, 6, 2, 31, 7],
+    [3, 13, 1, 13, 1, 33, 6],
+    [3, 15, 1, 14, 6, 35, 7],
+    [3, 7, 1, 7, 1, 37, 6],
+    [3, 11, 1, 11, 1, 39, 7],
+    [3, 10, 1, 8, 8, 41, 6],
+    [3, 13, 1, 13, 5, 43, 7],
+    [3, 4, 1, 4, 4, 45, 6],
+    [3, 15, 1, 15, 2, 46, 6],
+    [3, 7, 1, 6, 6, 47, 7],
+    [3, 12, 0, 0, 0, 49, 6],
+    [3, 14, 1, 14, 2, 51, 7],
+    [3, 5, 1, 5, 4, 53, 6],
+    [3, 9, 1, 1, 1, 55, 7],
+    [3, 9, 0, 0, 0, 57, 6],
+    [3, 12, 1, 12, 8, 59, 7],
+    [3, 1, 1, 1, 1, 61, 6],
+    [3, 14, 1, 12, 8, 62, 6],
+    [3, 6, 0, 0, 0, 63, 7],
+    [3, 15, 1, 15, 3, 67, 7],
+    [3, 11, 1, 1, 1, 71, 7],
+    [3, 13, 1, 13, 8, 75, 7],
+    [3, 2, 1, 2, 2, 79, 7],
+    [3, 15, 0, 0, 0, 83, 7],
+    [3, 9, 1, 9, 8, 87, 7],
+    [3, 13, 0, 0, 0, 91, 7],
+    [3, 6, 1, 4, 4, 95, 7],
+    [3, 15, 1, 11, 9, 99, 7],
+    [3, 11, 0, 0, 0, 103, 7],
+    [3, 13, 1, 13, 12, 107, 7],
+    [3, 7, 0, 0, 0, 111, 7],
+    [3, 14, 1, 14, 4, 115, 7],
+    [3, 7, 1, 7, 3, 119, 7],
+    [3, 11, 1, 11, 8, 123, 7],
+    [3, 3, 1, 3, 2, 127, 7],
+    [4, 0, 0, 0, 0, 0, 2],
+    [4, 10, 0, 0, 0, 1, 5],
+    [4, 8, 0, 0, 0, 2, 4],
+    [4, 7, 1, 4, 0, 3, 6],
+    [4, 13, 1, 1, 0, 5, 6],
+    [4, 2, 0, 0, 0, 6, 4],
+    [4, 13, 1, 1, 1, 7, 7],
+    [4, 15, 1, 7, 2, 9, 6],
+    [4, 4, 0, 0, 0, 10, 4],
+    [4, 5, 0, 0, 0, 11, 6],
+    [4, 10, 1, 10, 8, 13, 6],
+    [4, 1, 0, 0, 0, 14, 4],
+    [4, 11, 1, 11, 2, 15, 7],
+    [4, 9, 0, 0, 0, 17, 6],
+    [4, 6, 0, 0, 0, 19, 6],
+    [4, 12, 0, 0, 0, 21, 6],
+    [4, 14, 0, 0, 0, 23, 7],
+    [4, 14, 1, 12, 8, 25, 6],
+    [4, 2, 1, 2, 2, 27, 6],
+    [4, 8, 1, 8, 8, 29, 6],
+    [4, 7, 0, 0, 0, 31, 7],
+    [4, 6, 1, 0, 0, 35, 6],
+    [4, 12, 1, 8, 8, 37, 6],
+    [4, 15, 0, 0, 0, 39, 7],
+    [4, 15, 1, 9, 1, 41, 6],
+    [4, 3, 0, 0, 0, 43, 6],
+    [4, 10, 1, 10, 2, 45, 6],
+    [4, 10, 1, 10, 10, 47, 7],
+    [4, 15, 1, 11, 8, 49, 6],
+    [4, 5, 1, 0, 0, 51, 6],
+    [4, 11, 1, 0, 0, 53, 6],
+    [4, 12, 1, 12, 4, 55, 7],
+    [4, 14, 1, 4, 4, 57, 6],
+    [4, 15, 1, 15, 4, 59, 7],
+    [4, 3, 1, 2, 2, 61, 6],
+    [4, 1, 1, 1, 1, 63, 7],
+    [4, 15, 1, 9, 9, 71, 7],
+    [4, 11, 0, 0, 0, 79, 7],
+    [4, 13, 0, 0, 0, 87, 7],
+    [4, 4, 1, 4, 4, 95, 7],
+    [4, 14, 1, 14, 2, 103, 7],
+    [4, 7, 1, 4, 4, 111, 7],
+    [4, 9, 1, 0, 0, 119, 7],
+    [4, 15, 1, 7, 6, 123, 7],
+    [4, 3, 1, 3, 1, 127, 7],
+    [5, 0, 0, 0, 0, 0, 3],
+    [5, 12, 0, 0, 0, 1, 6],
+    [5, 8, 0, 0, 0, 2, 5],
+    [5, 15, 1, 13, 9, 3, 7],
+    [5, 15, 1, 15, 2, 4, 5],
+    [5, 7, 1, 6, 4, 5, 6],
+    [5, 15, 1, 15, 1, 6, 6],
+    [5, 11, 1, 9, 9, 7, 7],
+    [5, 10, 1, 10, 2, 9, 6],
+    [5, 2, 0, 0, 0, 10, 5],
+    [5, 14, 1, 6, 6, 11, 7],
+    [5, 15, 1, 15, 4, 12, 5],
+    [5, 5, 0, 0, 0, 13, 6],
+    [5, 14, 1, 14, 2, 14, 6],
+    [5, 7, 1, 7, 3, 15, 7],
+    [5, 11, 1, 9, 8, 17, 6],
+    [5, 4, 0, 0, 0, 18, 5],
+    [5, 15, 0, 0, 0, 19, 7],
+    [5, 15, 1, 15, 8, 20, 5],
+    [5, 6, 0, 0, 0, 21, 6],
+    [5, 15, 1, 7, 6, 22, 6],
+    [5, 9, 1, 8, 8, 23, 7],
+    [5, 8, 1, 8, 8, 25, 6],
+    [5, 1, 0, 0, 0, 26, 5],
+    [5, 13, 1, 13, 5, 27, 7],
+    [5, 10, 0, 0, 0, 28, 5],
+    [5, 2, 1, 2, 2, 29, 6],
+    [5, 13, 1, 9, 8, 30, 6],
+    [5, 4, 1, 4, 4, 31, 7],
+    [5, 11, 1, 11, 2, 33, 6],
+    [5, 15, 1, 15, 12, 35, 7],
+    [5, 6, 1, 2, 2, 37, 6],
+    [5, 9, 0, 0, 0, 38, 6],
+    [5, 11, 0, 0, 0, 39, 7],
+    [5, 10, 1, 10, 10, 41, 6],
+    [5, 13, 1, 13, 1, 43, 7],
+    [5, 3, 0, 0, 0, 45, 6],
+    [5, 13, 1, 13, 4, 46, 6],
+    [5, 6, 1, 6, 4, 47, 7],
+    [5, 10, 1, 10, 8, 49, 6],
+    [5, 14, 1, 14, 10, 51, 7],
+    [5, 5, 1, 4, 4, 53, 6],
+    [5, 14, 1, 14, 8, 54, 6],
+    [5, 7, 1, 7, 1, 55, 7],
+    [5, 3, 1, 3, 2, 57, 6],
+    [5, 12, 1, 12, 4, 59, 7],
+    [5, 15, 1, 15, 10, 61, 7],
+    [5, 12, 1, 8, 8, 62, 6],
+    [5, 3, 1, 3, 1, 63, 7],
+    [5, 15, 1, 7, 7, 67, 7],
+    [5, 11, 1, 11, 3, 71, 7],
+    [5, 14, 0, 0, 0, 75, 7],
+    [5, 7, 0, 0, 0, 79, 7],
+    [5, 14, 1, 14, 4, 83, 7],
+    [5, 7, 1, 6, 6, 87, 7],
+    [5, 13, 0, 0, 0, 91, 7],
+    [5, 3, 1, 3, 3, 95, 7],
+    [5, 15, 1, 7, 5, 99, 7],
+    [5, 9, 1, 9, 1, 103, 7],
+    [5, 13, 1, 9, 9, 107, 7],
+    [5, 5, 1, 5, 1, 111, 7],
+    [5, 14, 1, 14, 12, 115, 7],
+    [5, 7, 1, 7, 2, 119, 7],
+    [5, 11, 1, 11, 1, 123, 7],
+    [5, 15, 1, 15, 3, 125, 7],
+    [5, 1, 1, 1, 1, 127, 7],
+    [6, 0, 0, 0, 0, 0, 3],
+    [6, 13, 1, 5, 4, 1, 6],
+    [6, 15, 1, 15, 2, 2, 5],
+    [6, 1, 1, 1, 1, 3, 6],
+    [6, 1, 0, 0, 0, 4, 4],
+    [6, 7, 1, 6, 4, 5, 6],
+    [6, 15, 1, 15, 8, 6, 6],
+    [6, 12, 1, 12, 12, 7, 7],
+    [6, 11, 1, 9, 1, 9, 6],
+    [6, 4, 0, 0, 0, 10, 5],
+    [6, 14, 1, 2, 2, 11, 7],
+    [6, 2, 0, 0, 0, 12, 5],
+    [6, 2, 1, 2, 2, 13, 6],
+    [6, 15, 1, 15, 3, 14, 6],
+    [6, 8, 1, 8, 8, 15, 7],
+    [6, 12, 0, 0, 0, 17, 6],
+    [6, 8, 0, 0, 0, 18, 5],
+    [6, 15, 0, 0, 0, 19, 7],
+    [6, 5, 1, 1, 1, 21, 6],
+    [6, 15, 1, 15, 12, 22, 6],
+    [6, 10, 1, 10, 8, 23, 7],
+    [6, 10, 0, 0, 0, 25, 6],
+    [6, 3, 0, 0, 0, 26, 5],
+    [6, 13, 1, 12, 8, 27, 7],
+    [6, 15, 1, 15, 1, 28, 5],
+    [6, 3, 1, 3, 1, 29, 6],
+    [6, 15, 1, 6, 6, 30, 6],
+    [6, 6, 1, 6, 4, 31, 7],
+    [6, 12, 1, 12, 8, 33, 6],
+    [6, 15, 1, 15, 5, 35, 7],
+    [6, 6, 0, 0, 0, 37, 6],
+    [6, 15, 1, 15, 4, 38, 6],
+    [6, 11, 1, 11, 10, 39, 7],
+    [6, 10, 1, 2, 2, 41, 6],
+    [6, 14, 0, 0, 0, 43, 7],
+    [6, 3, 1, 3, 2, 45, 6],
+    [6, 15, 1, 14, 10, 46, 6],
+    [6, 7, 1, 7, 3, 47, 7],
+    [6, 11, 1, 11, 2, 49, 6],
+    [6, 13, 1, 5, 5, 51, 7],
+    [6, 5, 0, 0, 0, 53, 6],
+    [6, 7, 1, 7, 1, 54, 6],
+    [6, 9, 1, 1, 1, 55, 7],
+    [6, 9, 0, 0, 0, 57, 6],
+    [6, 12, 1, 12, 4, 59, 7],
+    [6, 3, 1, 3, 3, 61, 6],
+    [6, 14, 1, 14, 8, 62, 6],
+    [6, 4, 1, 4, 4, 63, 7],
+    [6, 11, 1, 9, 9, 71, 7],
+    [6, 14, 1, 14, 2, 75, 7],
+    [6, 7, 1, 6, 6, 79, 7],
+    [6, 14, 1, 14, 4, 83, 7],
+    [6, 9, 1, 9, 8, 87, 7],
+    [6, 13, 0, 0, 0, 91, 7],
+    [6, 6, 1, 2, 2, 95, 7],
+    [6, 15, 1, 11, 9, 99, 7],
+    [6, 11, 0, 0, 0, 103, 7],
+    [6, 13, 1, 13, 1, 107, 7],
+    [6, 7, 0, 0, 0, 111, 7],
+    [6, 14, 1, 14, 12, 115, 7],
+    [6, 7, 1, 7, 2, 119, 7],
+    [6, 11, 1, 11, 8, 123, 7],
+    [6, 5, 1, 5, 4, 127, 7],
+    [7, 15, 1, 15, 1, 0, 4],
+    [7, 13, 1, 13, 1, 1, 6],
+    [7, 15, 1, 15, 10, 2, 5],
+    [7, 13, 0, 0, 0, 3, 7],
+    [7, 15, 1, 15, 8, 4, 4],
+    [7, 1, 0, 0, 0, 5, 6],
+    [7, 15, 1, 15, 15, 6, 5],
+    [7, 7, 1, 7, 4, 7, 7],
+    [7, 15, 1, 15, 4, 8, 4],
+    [7, 7, 1, 7, 1, 9, 6],
+    [7, 15, 1, 15, 3, 10, 5],
+    [7, 11, 1, 11, 3, 11, 7],
+    [7, 15, 1, 15, 2, 12, 4],
+    [7, 14, 1, 14, 10, 13, 7],
+    [7, 14, 1, 14, 8, 14, 6],
+    [7, 4, 1, 4, 4, 15, 7],
+    [7, 10, 1, 2, 2, 17, 6],
+    [7, 0, 0, 0, 0, 18, 5],
+    [7, 11, 1, 11, 8, 19, 7],
+    [7, 15, 0, 0, 0, 21, 7],
+    [7, 15, 1, 15, 12, 22, 6],
+    [7, 7, 0, 0, 0, 23, 7],
+    [7, 5, 1, 1, 1, 25, 6],
+    [7, 15, 1, 15, 5, 26, 5],
+    [7, 7, 1, 7, 5, 27, 7],
+    [7, 13, 1, 13, 8, 29, 7],
+    [7, 14, 1, 14, 2, 30, 6],
+    [7, 3, 1, 3, 3, 31, 7],
+    [7, 11, 1, 11, 2, 33, 6],
+    [7, 12, 1, 8, 8, 35, 7],
+    [7, 15, 1, 15, 11, 37, 7],
+    [7, 7, 1, 7, 3, 39, 7],
+    [7, 6, 1, 0, 0, 41, 6],
+    [7, 11, 0, 0, 0, 43, 7],
+    [7, 14, 1, 6, 6, 45, 7],
+    [7, 15, 1, 15, 9, 46, 6],
+    [7, 3, 1, 3, 1, 47, 7],
+    [7, 9, 1, 0, 0, 49, 6],
+    [7, 11, 1, 11, 10, 51, 7],
+    [7, 15, 1, 15, 13, 53, 7],
+    [7, 15, 1, 15, 6, 54, 6],
+    [7, 6, 0, 0, 0, 55, 7],
+    [7, 2, 0, 0, 0, 57, 6],
+    [7, 8, 1, 8, 8, 59, 7],
+    [7, 13, 1, 13, 12, 61, 7],
+    [7, 13, 1, 13, 4, 62, 6],
+    [7, 2, 1, 2, 2, 63, 7],
+    [7, 12, 1, 12, 4, 67, 7],
+    [7, 7, 1, 7, 2, 71, 7],
+    [7, 11, 1, 9, 9, 75, 7],
+    [7, 14, 1, 14, 12, 77, 7],
+    [7, 4, 0, 0, 0, 79, 7],
+    [7, 10, 0, 0, 0, 83, 7],
+    [7, 15, 1, 15, 7, 85, 7],
+    [7, 5, 0, 0, 0, 87, 7],
+    [7, 9, 0, 0, 0, 91, 7],
+    [7, 13, 1, 13, 5, 93, 7],
+    [7, 3, 0, 0, 0, 95, 7],
+    [7, 12, 0, 0, 0, 99, 7],
+    [7, 15, 1, 15, 14, 101, 7],
+    [7, 7, 1, 6, 6, 103, 7],
+    [7, 10, 1, 10, 8, 107, 7],
+    [7, 14, 0, 0, 0, 109, 7],
+    [7, 3, 1, 3, 2, 111, 7],
+    [7, 11, 1, 11, 1, 115, 7],
+    [7, 14, 1, 14, 4, 117, 7],
+    [7, 5, 1, 5, 4, 119, 7],
+    [7, 8, 0, 0, 0, 123, 7],
+    [7, 13, 1, 9, 9, 125, 7],
+    [7, 1, 1, 1, 1, 127, 7],
+];
+
+/// The codes used for non-initial rows of quads.
+const TABLE1: [[u8; 7]; 358] = [
+    [0, 1, 0, 0, 0, 0, 3],
+    [0, 6, 0, 0, 0, 1, 5],
+    [0, 4, 0, 0, 0, 2, 3],
+    [0, 13, 1, 0, 0, 3, 6],
+    [0, 8, 0, 0, 0, 4, 3],
+    [0, 8, 1, 8, 8, 5, 5],
+    [0, 2, 0, 0, 0, 6, 3],
+    [0, 4, 1, 4, 4, 7, 6],
+    [0, 10, 0, 0, 0, 9, 5],
+    [0, 10, 1, 8, 8, 11, 6],
+    [0, 3, 0, 0, 0, 13, 5],
+    [0, 13, 0, 0, 0, 15, 7],
+    [0, 12, 0, 0, 0, 17, 5],
+    [0, 9, 1, 0, 0, 19, 6],
+    [0, 5, 0, 0, 0, 21, 5],
+    [0, 2, 1, 2, 2, 23, 6],
+    [0, 9, 0, 0, 0, 25, 5],
+    [0, 7, 1, 0, 0, 27, 6],
+    [0, 15, 1, 1, 0, 29, 6],
+    [0, 7, 0, 0, 0, 31, 7],
+    [0, 12, 1, 8, 8, 35, 6],
+    [0, 1, 1, 1, 1, 39, 6],
+    [0, 5, 1, 0, 0, 43, 6],
+    [0, 14, 0, 0, 0, 47, 7],
+    [0, 11, 1, 0, 0, 51, 6],
+    [0, 15, 1, 1, 1, 55, 7],
+    [0, 3, 1, 0, 0, 59, 6],
+    [0, 14, 1, 4, 0, 61, 6],
+    [0, 10, 1, 10, 2, 63, 7],
+    [0, 14, 1, 4, 4, 79, 7],
+    [0, 11, 0, 0, 0, 95, 7],
+    [0, 12, 1, 12, 4, 111, 7],
+    [0, 15, 0, 0, 0, 119, 7],
+    [0, 6, 1, 0, 0, 127, 7],
+    [1, 0, 0, 0, 0, 0, 1],
+    [1, 8, 0, 0, 0, 1, 4],
+    [1, 5, 0, 0, 0, 3, 6],
+    [1, 1, 0, 0, 0, 5, 4],
+    [1, 13, 0, 0, 0, 7, 7],
+    [1, 2, 0, 0, 0, 9, 4],
+    [1, 9, 0, 0, 0, 11, 6],
+    [1, 4, 0, 0, 0, 13, 5],
+    [1, 7, 0, 0, 0, 15, 7],
+    [1, 12, 0, 0, 0, 19, 6],
+    [1, 8, 1, 8, 8, 23, 7],
+    [1, 15, 0, 0, 0, 27, 7],
+    [1, 3, 0, 0, 0, 29, 5],
+    [1, 2, 1, 2, 2, 31, 7],
+    [1, 15, 1, 4, 0, 35, 6],
+    [1, 11, 0, 0, 0, 39, 7],
+    [1, 11, 1, 0, 0, 43, 7],
+    [1, 6, 0, 0, 0, 47, 7],
+    [1, 10, 0, 0, 0, 51, 6],
+    [1, 4, 1, 4, 4, 55, 7],
+    [1, 14, 0, 0, 0, 59, 7],
+    [1, 3, 1, 1, 1, 63, 7],
+    [1, 12, 1, 0, 0, 71, 7],
+    [1, 6, 1, 0, 0, 79, 7],
+    [1, 9, 1, 0, 0, 87, 7],
+    [1, 14, 1, 0, 0, 91, 7],
+    [1, 3, 1, 3, 2, 95, 7],
+    [1, 10, 1, 0, 0, 103, 7],
+    [1, 15, 1, 4, 4, 107, 7],
+    [1, 5, 1, 0, 0, 111, 7],
+    [1, 7, 1, 0, 0, 119, 7],
+    [1, 13, 1, 0, 0, 123, 7],
+    [1, 1, 1, 1, 1, 127, 7],
+    [2, 0, 0, 0, 0, 0, 1],
+    [2, 2, 0, 0, 0, 1, 4],
+    [2, 5, 0, 0, 0, 3, 6],
+    [2, 8, 0, 0, 0, 5, 5],
+    [2, 11, 1, 0, 0, 7, 7],
+    [2, 1, 0, 0, 0, 9, 4],
+    [2, 15, 0, 0, 0, 11, 7],
+    [2, 12, 0, 0, 0, 13, 6],
+    [2, 7, 0, 0, 0, 15, 7],
+    [2, 15, 1, 15, 8, 19, 7],
+    [2, 4, 0, 0, 0, 21, 5],
+    [2, 7, 1, 0, 0, 23, 7],
+    [2, 13, 1, 0, 0, 27, 7],
+    [2, 10, 0, 0, 0, 29, 6],
+    [2, 3, 1, 3, 1, 31, 7],
+    [2, 2, 1, 2, 2, 35, 6],
+    [2, 11, 0, 0, 0, 39, 7],
+    [2, 14, 1, 4, 4, 43, 7],
+    [2, 10, 1, 10, 2, 45, 6],
+    [2, 6, 0, 0, 0, 47, 7],
+    [2, 15, 1, 5, 1, 51, 7],
+    [2, 9, 0, 0, 0, 55, 7],
+    [2, 12, 1, 0, 0, 59, 7],
+    [2, 3, 0, 0, 0, 61, 6],
+    [2, 3, 1, 2, 2, 63, 7],
+    [2, 11, 1, 11, 2, 71, 7],
+    [2, 14, 1, 4, 0, 75, 7],
+    [2, 6, 1, 0, 0, 79, 7],
+    [2, 15, 1, 7, 2, 83, 7],
+    [2, 9, 1, 0, 0, 87, 7],
+    [2, 13, 0, 0, 0, 91, 7],
+    [2, 4, 1, 4, 4, 95, 7],
+    [2, 10, 1, 10, 8, 103, 7],
+    [2, 14, 0, 0, 0, 107, 7],
+    [2, 5, 1, 0, 0, 111, 7],
+    [2, 15, 1, 4, 4, 115, 7],
+    [2, 8, 1, 8, 8, 119, 7],
+    [2, 10, 1, 10, 10, 123, 7],
+    [2, 1, 1, 1, 1, 127, 7],
+    [3, 0, 0, 0, 0, 0, 2],
+    [3, 15, 1, 15, 8, 1, 6],
+    [3, 2, 0, 0, 0, 2, 4],
+    [3, 5, 0, 0, 0, 3, 6],
+    [3, 11, 0, 0, 0, 5, 6],
+    [3, 8, 0, 0, 0, 6, 5],
+    [3, 15, 1, 6, 6, 7, 7],
+    [3, 12, 1, 0, 0, 9, 6],
+    [3, 1, 0, 0, 0, 10, 4],
+    [3, 1, 1, 1, 1, 11, 6],
+    [3, 9, 0, 0, 0, 13, 6],
+    [3, 3, 0, 0, 0, 14, 5],
+    [3, 10, 1, 10, 8, 15, 7],
+    [3, 15, 0, 0, 0, 17, 6],
+    [3, 3, 1, 3, 1, 19, 6],
+    [3, 10, 0, 0, 0, 21, 6],
+    [3, 4, 0, 0, 0, 22, 5],
+    [3, 11, 1, 11, 3, 23, 7],
+    [3, 11, 1, 11, 2, 25, 6],
+    [3, 15, 1, 15, 5, 27, 7],
+    [3, 6, 0, 0, 0, 29, 6],
+    [3, 7, 1, 5, 1, 30, 6],
+    [3, 5, 1, 5, 4, 31, 7],
+    [3, 15, 1, 15, 2, 33, 6],
+    [3, 2, 1, 2, 2, 35, 6],
+    [3, 10, 1, 2, 2, 37, 6],
+    [3, 14, 1, 4, 4, 39, 7],
+    [3, 12, 0, 0, 0, 41, 6],
+    [3, 15, 1, 15, 4, 43, 7],
+    [3, 7, 0, 0, 0, 45, 6],
+    [3, 7, 1, 4, 4, 47, 7],
+    [3, 13, 1, 4, 0, 49, 6],
+    [3, 3, 1, 3, 2, 51, 6],
+    [3, 9, 1, 0, 0, 53, 6],
+    [3, 13, 0, 0, 0, 55, 7],
+    [3, 11, 1, 8, 8, 57, 6],
+    [3, 15, 1, 15, 10, 59, 7],
+    [3, 5, 1, 1, 1, 61, 6],
+    [3, 15, 1, 15, 1, 62, 6],
+    [3, 4, 1, 4, 4, 63, 7],
+    [3, 14, 1, 12, 8, 71, 7],
+    [3, 8, 1, 8, 8, 79, 7],
+    [3, 13, 1, 4, 4, 87, 7],
+    [3, 15, 1, 10, 8, 91, 7],
+    [3, 6, 1, 0, 0, 95, 7],
+    [3, 14, 0, 0, 0, 103, 7],
+    [3, 14, 1, 14, 2, 107, 7],
+    [3, 7, 1, 7, 2, 111, 7],
+    [3, 11, 1, 11, 1, 119, 7],
+    [3, 15, 1, 7, 3, 123, 7],
+    [3, 3, 1, 3, 3, 127, 7],
+    [4, 0, 0, 0, 0, 0, 1],
+    [4, 8, 0, 0, 0, 1, 4],
+    [4, 14, 1, 2, 0, 3, 6],
+    [4, 12, 0, 0, 0, 5, 5],
+    [4, 12, 1, 12, 4, 7, 7],
+    [4, 4, 0, 0, 0, 9, 4],
+    [4, 15, 1, 3, 2, 11, 7],
+    [4, 1, 0, 0, 0, 13, 5],
+    [4, 8, 1, 8, 8, 15, 7],
+    [4, 6, 0, 0, 0, 19, 6],
+    [4, 2, 0, 0, 0, 21, 5],
+    [4, 10, 1, 0, 0, 23, 7],
+    [4, 14, 1, 2, 2, 27, 7],
+    [4, 10, 0, 0, 0, 29, 6],
+    [4, 5, 1, 0, 0, 31, 7],
+    [4, 4, 1, 4, 4, 35, 6],
+    [4, 12, 1, 12, 12, 39, 7],
+    [4, 15, 0, 0, 0, 43, 7],
+    [4, 6, 1, 0, 0, 47, 7],
+    [4, 5, 0, 0, 0, 51, 6],
+    [4, 9, 1, 0, 0, 55, 7],
+    [4, 13, 1, 0, 0, 59, 7],
+    [4, 15, 1, 3, 0, 61, 6],
+    [4, 2, 1, 2, 2, 63, 7],
+    [4, 12, 1, 12, 8, 71, 7],
+    [4, 15, 1, 1, 1, 75, 7],
+    [4, 7, 0, 0, 0, 79, 7],
+    [4, 7, 1, 0, 0, 87, 7],
+    [4, 14, 0, 0, 0, 91, 7],
+    [4, 3, 0, 0, 0, 95, 7],
+    [4, 11, 0, 0, 0, 103, 7],
+    [4, 11, 1, 0, 0, 107, 7],
+    [4, 3, 1, 0, 0, 111, 7],
+    [4, 9, 0, 0, 0, 119, 7],
+    [4, 13, 0, 0, 0, 123, 7],
+    [4, 1, 1, 1, 1, 127, 7],
+    [5, 0, 0, 0, 0, 0, 2],
+    [5, 14, 1, 10, 8, 1, 6],
+    [5, 4, 0, 0, 0, 2, 4],
+    [5, 8, 1, 8, 8, 3, 6],
+    [5, 13, 0, 0, 0, 5, 6],
+    [5, 12, 0, 0, 0, 6, 5],
+    [5, 15, 1, 15, 3, 7, 7],
+    [5, 15, 1, 15, 12, 9, 6],
+    [5, 2, 0, 0, 0, 10, 5],
+    [5, 3, 1, 0, 0, 11, 6],
+    [5, 11, 0, 0, 0, 13, 6],
+    [5, 5, 0, 0, 0, 14, 5],
+    [5, 13, 1, 13, 8, 15, 7],
+    [5, 15, 1, 15, 8, 17, 6],
+    [5, 6, 0, 0, 0, 19, 6],
+    [5, 7, 1, 2, 0, 21, 6],
+    [5, 8, 0, 0, 0, 22, 5],
+    [5, 15, 1, 9, 9, 23, 7],
+    [5, 14, 0, 0, 0, 25, 6],
+    [5, 15, 0, 0, 0, 26, 5],
+    [5, 3, 0, 0, 0, 27, 6],
+    [5, 10, 0, 0, 0, 29, 6],
+    [5, 1, 0, 0, 0, 30, 5],
+    [5, 9, 1, 0, 0, 31, 7],
+    [5, 15, 1, 15, 2, 33, 6],
+    [5, 7, 0, 0, 0, 35, 6],
+    [5, 12, 1, 4, 4, 37, 6],
+    [5, 15, 1, 7, 6, 39, 7],
+    [5, 15, 1, 15, 1, 41, 6],
+    [5, 4, 1, 4, 4, 43, 6],
+    [5, 10, 1, 0, 0, 45, 6],
+    [5, 12, 1, 12, 8, 47, 7],
+    [5, 15, 1, 15, 4, 49, 6],
+    [5, 5, 1, 4, 4, 51, 6],
+    [5, 11, 1, 1, 0, 53, 6],
+    [5, 14, 1, 14, 4, 55, 7],
+    [5, 13, 1, 5, 4, 57, 6],
+    [5, 1, 1, 1, 1, 59, 6],
+    [5, 9, 0, 0, 0, 61, 6],
+    [5, 2, 1, 2, 2, 63, 7],
+    [5, 15, 1, 15, 10, 71, 7],
+    [5, 11, 1, 1, 1, 79, 7],
+    [5, 14, 1, 2, 2, 87, 7],
+    [5, 7, 1, 2, 2, 95, 7],
+    [5, 15, 1, 13, 5, 103, 7],
+    [5, 6, 1, 0, 0, 111, 7],
+    [5, 13, 1, 1, 1, 119, 7],
+    [5, 5, 1, 5, 1, 127, 7],
+    [6, 0, 0, 0, 0, 0, 3],
+    [6, 15, 0, 0, 0, 1, 6],
+    [6, 1, 0, 0, 0, 2, 4],
+    [6, 1, 1, 1, 1, 3, 6],
+    [6, 4, 0, 0, 0, 4, 4],
+    [6, 8, 1, 8, 8, 5, 6],
+    [6, 10, 0, 0, 0, 6, 5],
+    [6, 13, 1, 13, 4, 7, 7],
+    [6, 10, 1, 10, 2, 9, 6],
+    [6, 5, 0, 0, 0, 10, 5],
+    [6, 15, 1, 7, 6, 11, 7],
+    [6, 2, 0, 0, 0, 12, 4],
+    [6, 3, 1, 2, 2, 13, 6],
+    [6, 11, 0, 0, 0, 14, 6],
+    [6, 10, 1, 10, 10, 15, 7],
+    [6, 14, 0, 0, 0, 17, 6],
+    [6, 15, 1, 15, 5, 19, 7],
+    [6, 6, 0, 0, 0, 21, 6],
+    [6, 8, 0, 0, 0, 22, 5],
+    [6, 12, 1, 12, 12, 23, 7],
+    [6, 9, 1, 0, 0, 25, 6],
+    [6, 12, 0, 0, 0, 26, 5],
+    [6, 14, 1, 4, 4, 27, 7],
+    [6, 3, 0, 0, 0, 29, 6],
+    [6, 15, 1, 15, 8, 30, 6],
+    [6, 7, 1, 3, 1, 31, 7],
+    [6, 14, 1, 14, 8, 33, 6],
+    [6, 15, 1, 15, 1, 35, 7],
+    [6, 7, 0, 0, 0, 37, 6],
+    [6, 12, 1, 12, 4, 39, 7],
+    [6, 7, 1, 2, 2, 41, 6],
+    [6, 13, 1, 13, 1, 43, 7],
+    [6, 4, 1, 4, 4, 45, 6],
+    [6, 15, 1, 15, 4, 46, 6],
+    [6, 5, 1, 5, 1, 47, 7],
+    [6, 13, 0, 0, 0, 49, 6],
+    [6, 14, 1, 14, 2, 51, 7],
+    [6, 5, 1, 4, 4, 53, 6],
+    [6, 11, 1, 2, 2, 55, 7],
+    [6, 9, 0, 0, 0, 57, 6],
+    [6, 13, 1, 12, 8, 59, 7],
+    [6, 2, 1, 2, 2, 61, 6],
+    [6, 15, 1, 15, 2, 62, 6],
+    [6, 6, 1, 2, 2, 63, 7],
+    [6, 11, 1, 11, 1, 71, 7],
+    [6, 15, 1, 3, 3, 75, 7],
+    [6, 10, 1, 10, 8, 79, 7],
+    [6, 15, 1, 11, 9, 83, 7],
+    [6, 11, 1, 10, 8, 87, 7],
+    [6, 14, 1, 14, 4, 91, 7],
+    [6, 6, 1, 6, 4, 95, 7],
+    [6, 15, 1, 15, 12, 99, 7],
+    [6, 12, 1, 12, 8, 103, 7],
+    [6, 14, 1, 14, 10, 107, 7],
+    [6, 7, 1, 7, 4, 111, 7],
+    [6, 15, 1, 15, 10, 115, 7],
+    [6, 11, 1, 11, 2, 119, 7],
+    [6, 13, 1, 4, 4, 123, 7],
+    [6, 3, 1, 3, 1, 127, 7],
+    [7, 15, 0, 0, 0, 0, 3],
+    [7, 15, 1, 15, 10, 1, 6],
+    [7, 15, 1, 15, 1, 2, 5],
+    [7, 3, 0, 0, 0, 3, 6],
+    [7, 0, 0, 0, 0, 4, 4],
+    [7, 11, 1, 3, 2, 5, 6],
+    [7, 7, 0, 0, 0, 6, 5],
+    [7, 13, 1, 1, 1, 7, 7],
+    [7, 13, 1, 5, 4, 9, 6],
+    [7, 15, 1, 15, 2, 10, 5],
+    [7, 14, 1, 14, 4, 11, 7],
+    [7, 13, 0, 0, 0, 12, 5],
+    [7, 7, 1, 7, 1, 13, 6],
+    [7, 15, 1, 15, 12, 14, 6],
+    [7, 7, 1, 7, 3, 15, 7],
+    [7, 15, 1, 15, 3, 17, 6],
+    [7, 15, 1, 15, 8, 18, 5],
+    [7, 1, 1, 1, 1, 19, 6],
+    [7, 9, 0, 0, 0, 21, 6],
+    [7, 5, 0, 0, 0, 22, 5],
+    [7, 11, 1, 11, 1, 23, 7],
+    [7, 12, 1, 0, 0, 25, 6],
+    [7, 11, 0, 0, 0, 26, 5],
+    [7, 13, 1, 13, 8, 27, 7],
+    [7, 15, 1, 15, 4, 28, 5],
+    [7, 6, 0, 0, 0, 29, 6],
+    [7, 15, 1, 11, 9, 30, 6],
+    [7, 5, 1, 5, 4, 31, 7],
+    [7, 15, 1, 7, 6, 33, 6],
+    [7, 2, 0, 0, 0, 35, 6],
+    [7, 10, 0, 0, 0, 37, 6],
+    [7, 9, 1, 0, 0, 39, 7],
+    [7, 10, 1, 0, 0, 41, 6],
+    [7, 15, 1, 15, 7, 43, 7],
+    [7, 4, 0, 0, 0, 45, 6],
+    [7, 15, 1, 15, 5, 46, 6],
+    [7, 7, 1, 7, 4, 47, 7],
+    [7, 14, 0, 0, 0, 49, 6],
+    [7, 1, 0, 0, 0, 51, 6],
+    [7, 8, 0, 0, 0, 53, 6],
+    [7, 8, 1, 8, 8, 55, 7],
+    [7, 12, 0, 0, 0, 57, 6],
+    [7, 14, 1, 14, 2, 59, 7],
+    [7, 5, 1, 1, 1, 61, 6],
+    [7, 14, 1, 10, 8, 62, 6],
+    [7, 3, 1, 1, 1, 63, 7],
+    [7, 13, 1, 13, 1, 71, 7],
+    [7, 15, 1, 15, 11, 75, 7],
+    [7, 7, 1, 4, 4, 79, 7],
+    [7, 7, 1, 7, 2, 87, 7],
+    [7, 14, 1, 2, 2, 91, 7],
+    [7, 4, 1, 4, 4, 95, 7],
+    [7, 11, 1, 1, 1, 103, 7],
+    [7, 15, 1, 15, 15, 107, 7],
+    [7, 3, 1, 3, 2, 111, 7],
+    [7, 6, 1, 0, 0, 119, 7],
+    [7, 11, 1, 11, 8, 123, 7],
+    [7, 2, 1, 2, 2, 127, 7],
+];
+
+/// Expands a table of codes into a lookup table indexed by `c_q << 7 | next 7 bits`. Each entry
+/// holds `length | u_off << 3 | rho << 4 | e_1 << 8 | e_k << 12`.
+fn expand(table: &[[u8; 7]]) -> Vec<u16> {
+    let mut lut = vec![0u16; 8 << 7];
+    for &[c_q, rho, u_off, e_k, e_1, cwd, len] in table {
+        let mask = (1usize << len) - 1;
+        let entry = u16::from(len)
+            | u16::from(u_off) << 3
+            | u16::from(rho) << 4
+            | u16::from(e_1) << 8
+            | u16::from(e_k) << 12;
+        for bits in 0..128usize {
+            if bits & mask == usize::from(cwd) {
+                lut[usize::from(c_q) << 7 | bits] = entry;
+            }
+        }
+    }
+    lut
+}
+
+/// The lookup table for the initial row of quads.
+pub(super) fn initial_row() -> &'static [u16] {
+    static LUT: OnceLock<Vec<u16>> = OnceLock::new();
+    LUT.get_or_init(|| expand(&TABLE0))
+}
+
+/// The lookup table for non-initial rows of quads.
+pub(super) fn other_rows() -> &'static [u16] {
+    static LUT: OnceLock<Vec<u16>> = OnceLock::new();
+    LUT.get_or_init(|| expand(&TABLE1))
+}
@@ -0,0 +1,570 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! JPEG Baseline (Process 1) and Extended (Process 2 & 4), the sequential DCT-based processes
+//! using Huffman coding, Annex F.
+
+use std::sync::OnceLock;
+
+use crate::core::codec::{
+    jpeg::{
+        push_sample, BitReader, FrameHeader, HuffmanTable, JpegReader, ScanHeader, SOF0, SOF1,
+        SOF2, SOF3,
+    },
+    CodecError, FrameLayout,
+};
+
+/// The natural-order index of each coefficient in zig-zag order, Figure A.6.
+const ZIGZAG: [usize; 64] = [
+    0, 1, 8, 16, 9, 2, 3, 10, 17, 24, 32, 25, 18, 11, 4, 5, 12, 19, 26, 33, 40, 48, 41, 34, 27, 20,
+    13, 6, 7, 14, 21, 28, 35, 42, 49, 56, 57, 50, 43, 36, 29, 22, 15, 23, 30, 37, 44, 51, 58, 59,
+    52, 45, 38, 31, 39, 46, 53, 60, 61, 54, 47, 55, 62, 63,
+];
+
+/// The decoded samples of a single component, padded to a whole number of MCUs.
+struct ComponentPlane {
+    /// Width of the plane, in samples.
+    width: usize,
+    samples: Vec<u16>,
+    /// The DC prediction, reset at the start of each scan and restart interval.
+    dc_pred: i32,
+}
+
+/// Decodes a single frame of JPEG Baseline or Extended compressed data into native samples.
+///
+/// Frames with three components are converted from YCbCr to RGB unless the JPEG stream indicates
+/// the components are not color-transformed, either through an Adobe APP14 segment or by using
+/// the component identifiers `R`, `G`, `B`. The decoded samples are arranged as little-endian
+/// samples interleaved by pixel.
+///
+/// # Errors
+/// - `CodecError::UnsupportedLayout` if the JPEG stream uses a process other than the sequential
+///   DCT-based processes, or does not match the given frame layout.
+/// - `CodecError::InvalidData` if the JPEG stream is malformed or truncated.
+pub fn decode_frame(frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+    let mut reader = JpegReader::new(frame)?;
+    let mut planes: Vec<ComponentPlane> = Vec::new();
+    while let Some(scan) = reader.next_scan()? {
+        let header = reader.frame()?;
+        match header.sof {
+            SOF0 | SOF1 => {}
+            SOF2 => {
+                return Err(CodecError::UnsupportedLayout(
+                    "JPEG progressive DCT".to_owned(),
+                ))
+            }
+            SOF3 => return Err(CodecError::UnsupportedLayout("JPEG lossless".to_owned())),
+            sof => {
+                return Err(CodecError::UnsupportedLayout(format!(
+                    "JPEG process with SOF marker {sof:#04X}"
+                )))
+            }
+        }
+        if header.precision != 8 && header.precision != 12 {
+            return Err(CodecError::UnsupportedLayout(format!(
+                "JPEG DCT with {} bit precision",
+                header.precision
+            )));
+        }
+        // Sequential processes always code all coefficients in a single scan, Table B.3.
+        if scan.ss != 0 || scan.se != 63 || scan.al != 0 {
+            return Err(CodecError::InvalidData(format!(
+                "invalid sequential DCT scan parameters: Ss={}, Se={}, Al={}",
+                scan.ss, scan.se, scan.al
+            )));
+        }
+        header.check_layout(layout)?;
+        if planes.is_empty() {
+            planes = allocate_planes(header);
+        }
+        decode_scan(&mut reader, &scan, &mut planes)?;
+    }
+
+    let header = reader.frame()?;
+    if planes.is_empty() {
+        return Err(CodecError::InvalidData(
+            "JPEG stream has no scans".to_owned(),
+        ));
+    }
+
+    let color_transform = header.components.len() == 3
+        && match reader.adobe_transform {
+            Some(transform) => transform != 0,
+            None => header.components.iter().map(|c| c.id).collect::<Vec<u8>>() != b"RGB",
+        };
+    Ok(to_native(header, &planes, color_transform, layout))
+}
+
+/// Allocates the planes for each component of the frame, padded to a whole number of MCUs.
+fn allocate_planes(header: &FrameHeader) -> Vec<ComponentPlane> {
+    let max_h = usize::from(header.max_h());
+    let max_v = usize::from(header.max_v());
+    let mcus_x = usize::from(header.width).div_ceil(8 * max_h);
+    let mcus_y = usize::from(header.height).div_ceil(8 * max_v);
+    header
+        .components
+        .iter()
+        .map(|c| {
+            let width = mcus_x * usize::from(c.h) * 8;
+            let height = mcus_y * usize::from(c.v) * 8;
+            ComponentPlane {
+                width,
+                samples: vec![0u16; width * height],
+                dc_pred: 0,
+            }
+        })
+        .collect()
+}
+
+/// Decodes the entropy-coded data of a single scan into the component planes.
+fn decode_scan(
+    reader: &mut JpegReader,
+    scan: &ScanHeader,
+    planes: &mut [ComponentPlane],
+) -> Result<(), CodecError> {
+    let header = reader.frame()?;
+    let max_h = usize::from(header.max_h());
+    let max_v = usize::from(header.max_v());
+    let precision = header.precision;
+
+    // Gather the tables for each component up-front.
+    let mut tables = Vec::with_capacity(scan.components.len());
+    for sc in &scan.components {
+        if sc.index >= planes.len() {
+            return Err(CodecError::InvalidData(format!(
+                "scan component {} has no decoded plane",
+                sc.index
+            )));
+        }
+        let fc = &header.components[sc.index];
+        let dc = reader.dc_tables[usize::from(sc.td)].as_ref();
+        let ac = reader.ac_tables[usize::from(sc.ta)].as_ref();
+        let q = reader.qtables[usize::from(fc.tq)].as_ref();
+        let (Some(dc), Some(ac), Some(q)) = (dc, ac, q) else {
+            return Err(CodecError::InvalidData(format!(
+                "missing tables for component {}",
+                fc.id
+            )));
+        };
+        tables.push((dc, ac, q));
+    }
+
+    // A scan with a single component is not interleaved, and each MCU is a single block of the
+    // component's own (unpadded) block grid, Section A.2.2.
+    let (mcus_x, mcus_y) = if scan.components.len() == 1 {
+        let fc = &header.components[scan.components[0].index];
+        let comp_w = (usize::from(header.width) * usize::from(fc.h)).div_ceil(max_h);
+        let comp_h = (usize::from(header.height) * usize::from(fc.v)).div_ceil(max_v);
+        (comp_w.div_ceil(8), comp_h.div_ceil(8))
+    } else {
+        (
+            usize::from(header.width).div_ceil(8 * max_h),
+            usize::from(header.height).div_ceil(8 * max_v),
+        )
+    };
+
+    let mut bits = reader.scan_data();
+    let restart_interval = usize::from(reader.restart_interval);
+    for plane in planes.iter_mut() {
+        plane.dc_pred = 0;
+    }
+    let mut coefs = [0i32; 64];
+    let mut block = [0u16; 64];
+    for mcu in 0..mcus_x * mcus_y {
+        if restart_interval > 0 && mcu > 0 && mcu % restart_interval == 0 {
+            bits.restart();
+            for plane in planes.iter_mut() {
+                plane.dc_pred = 0;
+            }
+        }
+
+        let mcu_x = mcu % mcus_x;
+        let mcu_y = mcu / mcus_x;
+        for (sc, (dc, ac, q)) in scan.components.iter().zip(&tables) {
+            let fc = &header.components[sc.index];
+            let (blocks_h, blocks_v) = if scan.components.len() == 1 {
+                (1, 1)
+            } else {
+                (usize::from(fc.h), usize::from(fc.v))
+            };
+            let plane = &mut planes[sc.index];
+            for by in 0..blocks_v {
+                for bx in 0..blocks_h {
+                    decode_block(&mut bits, dc, ac, q, &mut plane.dc_pred, &mut coefs)?;
+                    idct(&coefs, precision, &mut block);
+                    let x0 = (mcu_x * blocks_h + bx) * 8;
+                    let y0 = (mcu_y * blocks_v + by) * 8;
+                    for (row, chunk) in block.chunks_exact(8).enumerate() {
+                        let start = (y0 + row) * plane.width + x0;
+                        if let Some(dst) = plane.samples.get_mut(start..start + 8) {
+                            dst.copy_from_slice(chunk);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    reader.end_scan(bits.position());
+    Ok(())
+}
+
+/// Decodes the Huffman-coded coefficients of a single block, Section F.2.2, de-quantizing them
+/// into natural order.
+fn decode_block(
+    bits: &mut BitReader,
+    dc: &HuffmanTable,
+    ac: &HuffmanTable,
+    q: &[u16; 64],
+    dc_pred: &mut i32,
+    coefs: &mut [i32; 64],
+) -> Result<(), CodecError> {
+    coefs.fill(0);
+    let size = bits.decode(dc)?;
+    let dc_coef = dc_pred
+        .checked_add(bits.receive_extend(size))
+        .and_then(|pred| {
+            *dc_pred = pred;
+            pred.checked_mul(i32::from(q[0]))
+        })
+        .ok_or_else(|| CodecError::InvalidData("DC coefficient out of range".to_owned()))?;
+    coefs[0] = dc_coef;
+
+    let mut k = 1usize;
+    while k < 64 {
+        let rs = bits.decode(ac)?;
+        let run = usize::from(rs >> 4);
+        let size = rs & 0x0F;
+        if size == 0 {
+            if run == 15 {
+                // ZRL, a run of 16 zero coefficients.
+                k += 16;
+                continue;
+            }
+            // EOB, the remaining coefficients are zero.
+            break;
+        }
+        k += run;
+        if k > 63 {
+            return Err(CodecError::InvalidData(
+                "coefficient index out of range".to_owned(),
+            ));
+        }
+        coefs[ZIGZAG[k]] = bits.receive_extend(size) * i32::from(q[k]);
+        k += 1;
+    }
+    Ok(())
+}
+
+/// The cosine basis used by the inverse DCT, indexed by `[x][u]` as `C(u)/2 * cos((2x+1)uπ/16)`.
+fn idct_basis() -> &'static [[f32; 8]; 8] {
+    static BASIS: OnceLock<[[f32; 8]; 8]> = OnceLock::new();
+    BASIS.get_or_init(|| {
+        let mut basis = [[0f32; 8]; 8];
+        for (x, row) in basis.iter_mut().enumerate() {
+            for (u, val) in row.iter_mut().enumerate() {
+                let cu = if u == 0 {
+                    std::f32::consts::FRAC_1_SQRT_2
+                } else {
+                    1.0
+                };
+                #[allow(clippy::cast_precision_loss)]
+                let angle = ((2 * x + 1) * u) as f32 * std::f32::consts::PI / 16.0;
+                *val = cu / 2.0 * angle.cos();
+            }
+        }
+        basis
+    })
+}
+
+/// Computes the inverse DCT of the de-quantized coefficients, Section A.3.3, level-shifting and
+/// clamping the result to the sample precision.
+fn idct(coefs: &[i32; 64], precision: u8, out: &mut [u16; 64]) {
+    let basis = idct_basis();
+    let mut tmp = [0f32; 64];
+    // Rows: transform along u for each v.
+    for v in 0..8 {
+        for x in 0..8 {
+            let mut sum = 0f32;
+            for u in 0..8 {
+                let coef = coefs[v * 8 + u];
+                if coef != 0 {
+                    #[allow(clippy::cast_precision_loss)]
+                    let coef = coef as f32;
+                    sum += basis[x][u] * coef;
+                }
+            }
+            tmp[v * 8 + x] = sum;
+        }
+    }
+
+    let shift = f32::from(1u16 << (precision - 1));
+    let max = f32::from(u16::MAX >> (16 - precision));
+    // Columns: transform along v for each x.
+    for y in 0..8 {
+        for x in 0..8 {
+            let mut sum = 0f32;
+            for v in 0..8 {
+                sum += basis[y][v] * tmp[v * 8 + x];
+            }
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let sample = (sum + shift).round().clamp(0.0, max) as u16;
+            out[y * 8 + x] = sample;
+        }
+    }
+}
+
+/// Upsamples the component planes to the frame size, applies the color transform, and arranges
+/// the samples as little-endian and interleaved by pixel.
+fn to_native(
+    header: &FrameHeader,
+    planes: &[ComponentPlane],
+    color_transform: bool,
+    layout: &FrameLayout,
+) -> Vec<u8> {
+    let width = usize::from(header.width);
+    let height = usize::from(header.height);
+    let max_h = usize::from(header.max_h());
+    let max_v = usize::from(header.max_v());
+    let num_comps = planes.len();
+    let bytes_per_sample = layout.bytes_per_sample();
+    let center = f32::from(1u16 << (header.precision - 1));
+    let max = f32::from(u16::MAX >> (16 - header.precision));
+
+    let mut native = Vec::with_capacity(layout.frame_len());
+    let mut pixel = [0u16; 3];
+    for y in 0..height {
+        for x in 0..width {
+            for (c, plane) in planes.iter().enumerate() {
+                let fc = &header.components[c];
+                let px = x * usize::from(fc.h) / max_h;
+                let py = y * usize::from(fc.v) / max_v;
+                let sample = plane.samples[py * plane.width + px];
+                if num_comps == 3 {
+                    pixel[c] = sample;
+                } else {
+                    push_sample(&mut native, sample, bytes_per_sample);
+                }
+            }
+            if num_comps != 3 {
+                continue;
+            }
+
+            if color_transform {
+                let luma = f32::from(pixel[0]);
+                let cb = f32::from(pixel[1]) - center;
+                let cr = f32::from(pixel[2]) - center;
+                let rgb = [
+                    luma + 1.402 * cr,
+                    luma - 0.344_136 * cb - 0.714_136 * cr,
+                    luma + 1.772 * cb,
+                ];
+                for val in rgb {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let val = val.round().clamp(0.0, max) as u16;
+                    push_sample(&mut native, val, bytes_per_sample);
+                }
+            } else {
+                for val in pixel {
+                    push_sample(&mut native, val, bytes_per_sample);
+                }
+            }
+        }
+    }
+    native
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::codec::{jpeg::dct::decode_frame, CodecError, FrameLayout};
+
+    /// Builds a minimal baseline JPEG stream for an 8x8 grayscale image of a single value, using
+    /// a quantization table of all ones and Huffman tables with a single code each.
+    fn single_block_jpeg(dc_size: u8, dc_bits: u8) -> Vec<u8> {
+        let mut jpeg = vec![0xFF, 0xD8];
+        // DQT, 8-bit precision, table 0, all ones.
+        jpeg.extend_from_slice(&[0xFF, 0xDB, 0x00, 0x43, 0x00]);
+        jpeg.extend_from_slice(&[1u8; 64]);
+        // SOF0, 8-bit, 8x8, 1 component with id 1, 1x1 sampling, table 0.
+        jpeg.extend_from_slice(&[
+            0xFF, 0xC0, 0x00, 0x0B, 0x08, 0x00, 0x08, 0x00, 0x08, 0x01, 0x01, 0x11, 0x00,
+        ]);
+        // DHT, DC table 0 with a single 1-bit code for `dc_size`.
+        jpeg.extend_from_slice(&[0xFF, 0xC4, 0x00, 0x14, 0x00, 0x01]);
+        jpeg.extend_from_slice(&[0u8; 15]);
+        jpeg.push(dc_size);
+        // DHT, AC table 0 with a single 1-bit code for EOB.
+        jpeg.extend_from_slice(&[0xFF, 0xC4, 0x00, 0x14, 0x10, 0x01]);
+        jpeg.extend_from_slice(&[0u8; 15]);
+        jpeg.push(0x00);
+        // SOS, 1 component, tables 0/0, spectral selection 0-63.
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x08, 0x01, 0x01, 0x00, 0x00, 0x3F, 0x00]);
+        // DC code (0), DC value bits, AC EOB code (0), padded with ones.
+        let bits = u16::from(dc_bits) << (15 - dc_size);
+        let used = 1 + dc_size + 1;
+        let padded = bits | ((1u16 << (16 - used)) - 1);
+        jpeg.extend_from_slice(&padded.to_be_bytes());
+        jpeg.extend_from_slice(&[0xFF, 0xD9]);
+        jpeg
+    }
+
+    #[test]
+    pub fn test_decode_flat_block() {
+        let layout = FrameLayout {
+            rows: 8,
+            cols: 8,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        // A DC difference of 80 is encoded with size 7 as 1010000. The DC coefficient is 8 times
+        // the average sample value, offset by the level shift of 128.
+        let jpeg = single_block_jpeg(7, 0b101_0000);
+        let decoded = decode_frame(&jpeg, &layout).unwrap();
+        assert_eq!(vec![138u8; 64], decoded);
+    }
+
+    /// A baseline JPEG of a 16x8 grayscale image of two blocks, with a quantization table of all
+    /// twos. The first block has a quantized DC of 20 and AC coefficients of -15 and 7 at zig-zag
+    /// indices 1 and 2. The second has a quantized DC of 8, predicted from the first, and AC
+    /// coefficients of 10 and -3 at zig-zag indices 4 and 5.
+    const TWO_BLOCK_JPEG: [u8; 168] = [
+        0xFF, 0xD8, 0xFF, 0xDB, 0x00, 0x43, 0x00, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
+        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
+        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
+        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
+        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0xFF, 0xC0, 0x00, 0x0B,
+        0x08, 0x00, 0x08, 0x00, 0x10, 0x01, 0x01, 0x11, 0x00, 0xFF, 0xC4, 0x00, 0x1F, 0x00, 0x00,
+        0x00, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0xFF, 0xC4, 0x00,
+        0x1E, 0x10, 0x00, 0x00, 0x00, 0x0B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x11, 0x12, 0x13, 0x14, 0x34, 0xFF,
+        0xDA, 0x00, 0x08, 0x01, 0x01, 0x00, 0x00, 0x3F, 0x00, 0x5A, 0x20, 0x1F, 0x04, 0x3A, 0xA2,
+        0x03, 0xFF, 0xD9,
+    ];
+
+    /// The samples of `TWO_BLOCK_JPEG`, computed from the definition of the inverse DCT.
+    #[rustfmt::skip]
+    const TWO_BLOCK_SAMPLES: [u8; 128] = [
+        130, 131, 132, 134, 136, 138, 140, 141, 134, 134, 133, 132, 130, 128, 126, 124,
+        130, 131, 132, 134, 136, 138, 139, 140, 133, 133, 133, 132, 130, 128, 126, 125,
+        129, 130, 131, 133, 135, 137, 139, 140, 132, 132, 132, 132, 130, 129, 127, 126,
+        128, 129, 131, 132, 135, 136, 138, 139, 130, 130, 131, 131, 131, 130, 129, 128,
+        127, 128, 130, 131, 134, 135, 137, 138, 128, 129, 130, 131, 131, 131, 130, 130,
+        126, 127, 129, 131, 133, 135, 136, 137, 126, 127, 129, 130, 132, 132, 132, 132,
+        126, 127, 128, 130, 132, 134, 135, 136, 125, 126, 128, 130, 132, 133, 133, 133,
+        125, 126, 128, 130, 132, 134, 135, 136, 124, 126, 128, 130, 132, 133, 134, 134,
+    ];
+
+    #[test]
+    pub fn test_decode_ac_coefficients() {
+        let layout = FrameLayout {
+            rows: 8,
+            cols: 16,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        let decoded = decode_frame(&TWO_BLOCK_JPEG, &layout).unwrap();
+        assert_eq!(TWO_BLOCK_SAMPLES.to_vec(), decoded);
+    }
+
+    #[test]
+    pub fn test_decode_dc_overflow() {
+        let layout = FrameLayout {
+            rows: 8,
+            cols: 16,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        let mut jpeg = vec![0xFF, 0xD8];
+        // DQT, 16-bit precision, table 0, all 65535.
+        jpeg.extend_from_slice(&[0xFF, 0xDB, 0x00, 0x83, 0x10]);
+        jpeg.extend_from_slice(&[0xFF; 128]);
+        // SOF0, 8-bit, 16x8, 1 component with id 1, 1x1 sampling, table 0.
+        jpeg.extend_from_slice(&[
+            0xFF, 0xC0, 0x00, 0x0B, 0x08, 0x00, 0x08, 0x00, 0x10, 0x01, 0x01, 0x11, 0x00,
+        ]);
+        // DHT, DC table 0 with a single 1-bit code for size 15.
+        jpeg.extend_from_slice(&[0xFF, 0xC4, 0x00, 0x14, 0x00, 0x01]);
+        jpeg.extend_from_slice(&[0u8; 15]);
+        jpeg.push(15);
+        // DHT, AC table 0 with a single 1-bit code for EOB.
+        jpeg.extend_from_slice(&[0xFF, 0xC4, 0x00, 0x14, 0x10, 0x01]);
+        jpeg.extend_from_slice(&[0u8; 15]);
+        jpeg.push(0x00);
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x08, 0x01, 0x01, 0x00, 0x00, 0x3F, 0x00]);
+        // Two blocks each with a DC difference of 32767, whose de-quantized DC overflows in the
+        // second block.
+        jpeg.extend_from_slice(&[0x7F, 0xFF, 0x00, 0x3F, 0xFF, 0x00, 0xBF]);
+        jpeg.extend_from_slice(&[0xFF, 0xD9]);
+        assert!(matches!(
+            decode_frame(&jpeg, &layout),
+            Err(CodecError::InvalidData(msg)) if msg.contains("DC coefficient")
+        ));
+    }
+
+    #[test]
+    pub fn test_decode_mismatched_layout() {
+        let layout = FrameLayout {
+            rows: 16,
+            cols: 8,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        let jpeg = single_block_jpeg(7, 0b101_0000);
+        assert!(decode_frame(&jpeg, &layout).is_err());
+    }
+
+    #[test]
+    pub fn test_decode_oversized_frame_header() {
+        let layout = FrameLayout {
+            rows: 8,
+            cols: 16,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        // A frame header of 65535x65535 is rejected before allocating planes for it.
+        let mut jpeg = TWO_BLOCK_JPEG.to_vec();
+        let sof = jpeg.windows(2).position(|m| m == [0xFF, 0xC0]).unwrap();
+        jpeg[sof + 5..sof + 9].copy_from_slice(&[0xFF; 4]);
+        assert!(matches!(
+            decode_frame(&jpeg, &layout),
+            Err(CodecError::UnsupportedLayout(_))
+        ));
+
+        // A second frame header with three components, followed by a scan of the third.
+        let mut jpeg = TWO_BLOCK_JPEG[..TWO_BLOCK_JPEG.len() - 2].to_vec();
+        jpeg.extend_from_slice(&[
+            0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00, 0x08, 0x00, 0x10, 0x03, 0x01, 0x11, 0x00, 0x02,
+            0x11, 0x00, 0x03, 0x11, 0x00,
+        ]);
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x08, 0x01, 0x03, 0x00, 0x00, 0x3F, 0x00]);
+        jpeg.extend_from_slice(&[0x00, 0xFF, 0xD9]);
+        assert!(matches!(
+            decode_frame(&jpeg, &layout),
+            Err(CodecError::UnsupportedLayout(_))
+        ));
+    }
+}
@@ -0,0 +1,558 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! JPEG Lossless, Non-Hierarchical (Process 14), the predictive lossless process using Huffman
+//! coding, Annex H. Decoding supports all predictors while encoding uses Selection Value 1.
+
+use crate::core::codec::{
+    jpeg::{planes_to_native, JpegReader, ScanHeader, DHT, EOI, MARKER, SOF3, SOI, SOS},
+    CodecError, FrameLayout,
+};
+
+/// The number of difference categories, Table H.2. Category 16 is only used for a difference of
+/// 32768 and has no additional bits.
+const NUM_CATEGORIES: usize = 17;
+
+/// Decodes a single frame of JPEG Lossless compressed data into native samples.
+///
+/// The decoded samples are arranged as little-endian samples interleaved by pixel. Signed samples
+/// are sign-extended from Bits Stored.
+///
+/// # Errors
+/// - `CodecError::UnsupportedLayout` if the JPEG stream uses a process other than the lossless
+///   process, uses subsampled components, or does not match the given frame layout.
+/// - `CodecError::InvalidData` if the JPEG stream is malformed or truncated.
+pub fn decode_frame(frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+    let mut reader = JpegReader::new(frame)?;
+    let mut planes: Vec<Vec<u16>> = Vec::new();
+    while let Some(scan) = reader.next_scan()? {
+        let header = reader.frame()?;
+        if header.sof != SOF3 {
+            return Err(CodecError::UnsupportedLayout(format!(
+                "JPEG process with SOF marker {:#04X}",
+                header.sof
+            )));
+        }
+        if header.precision < 2 || header.precision > 16 {
+            return Err(CodecError::InvalidData(format!(
+                "JPEG lossless with {} bit precision",
+                header.precision
+            )));
+        }
+        if header.components.iter().any(|c| c.h != 1 || c.v != 1) {
+            return Err(CodecError::UnsupportedLayout(
+                "JPEG lossless with subsampled components".to_owned(),
+            ));
+        }
+        header.check_layout(layout)?;
+        if planes.is_empty() {
+            let pixels = usize::from(header.width) * usize::from(header.height);
+            planes = vec![vec![0u16; pixels]; header.components.len()];
+        }
+        decode_scan(&mut reader, &scan, &mut planes)?;
+    }
+
+    planes_to_native(reader.frame()?, &planes, layout)
+}
+
+/// Decodes the entropy-coded data of a single scan into the component planes, Section H.2.
+fn decode_scan(
+    reader: &mut JpegReader,
+    scan: &ScanHeader,
+    planes: &mut [Vec<u16>],
+) -> Result<(), CodecError> {
+    let header = reader.frame()?;
+    let width = usize::from(header.width);
+    let pixels = width * usize::from(header.height);
+    let predictor = scan.ss;
+    if !(1..=7).contains(&predictor) {
+        return Err(CodecError::InvalidData(format!(
+            "invalid lossless predictor: {predictor}"
+        )));
+    }
+    let point_transform = scan.al;
+    if point_transform >= header.precision {
+        return Err(CodecError::InvalidData(format!(
+            "invalid point transform: {point_transform}"
+        )));
+    }
+    let precision = header.precision - point_transform;
+    let mask = (1i32 << precision) - 1;
+    let default_pred = 1i32 << (precision - 1);
+
+    let mut tables = Vec::with_capacity(scan.components.len());
+    for sc in &scan.components {
+        if sc.index >= planes.len() {
+            return Err(CodecError::InvalidData(format!(
+                "scan component {} has no decoded plane",
+                sc.index
+            )));
+        }
+        let Some(table) = reader.dc_tables[usize::from(sc.td)].as_ref() else {
+            return Err(CodecError::InvalidData(format!(
+                "missing Huffman table for component {}",
+                header.components[sc.index].id
+            )));
+        };
+        tables.push(table);
+    }
+
+    // The reconstructed values before applying the point transform, per component.
+    let mut recon = vec![vec![0i32; pixels]; scan.components.len()];
+    let mut bits = reader.scan_data();
+    let restart_interval = usize::from(reader.restart_interval);
+    let mut restart_at = 0usize;
+    for index in 0..pixels {
+        if restart_interval > 0 && index > 0 && index % restart_interval == 0 {
+            bits.restart();
+            restart_at = index;
+        }
+        let x = index % width;
+        for (values, table) in recon.iter_mut().zip(&tables) {
+            // Prediction per Section H.1.2.1. The first sample of the scan or restart interval
+            // uses a default value, the rest of its line uses the sample to the left, and the
+            // first sample of each line uses the sample above.
+            let pred = if index == restart_at {
+                default_pred
+            } else if index - restart_at < width - (restart_at % width) && x > 0 {
+                values[index - 1]
+            } else if x == 0 {
+                values[index - width]
+            } else {
+                let ra = values[index - 1];
+                let rb = values[index - width];
+                let rc = values[index - width - 1];
+                match predictor {
+                    1 => ra,
+                    2 => rb,
+                    3 => rc,
+                    4 => ra + rb - rc,
+                    5 => ra + ((rb - rc) >> 1),
+                    6 => rb + ((ra - rc) >> 1),
+                    _ => (ra + rb) >> 1,
+                }
+            };
+            let size = bits.decode(table)?;
+            let diff = bits.receive_extend(size);
+            values[index] = (pred + diff) & mask;
+        }
+    }
+
+    for (sc, values) in scan.components.iter().zip(recon) {
+        for (sample, value) in planes[sc.index].iter_mut().zip(values) {
+            *sample = u16::try_from(value << point_transform).unwrap_or(u16::MAX);
+        }
+    }
+
+    reader.end_scan(bits.position());
+    Ok(())
+}
+
+/// Encodes a single frame of native samples into JPEG Lossless compressed data, using the first
+/// order prediction of Selection Value 1 with no point transform.
+///
+/// The native samples are expected to be little-endian and interleaved by pixel. The precision of
+/// the encoded samples is Bits Stored, and any bits of the native samples above it are discarded.
+/// An optimal Huffman table is generated for each frame.
+///
+/// # Errors
+/// - `CodecError::UnsupportedLayout` if the samples are not 8 or 16 bits, Bits Stored is not
+///   within 2 to 16, or the frame has no rows or columns.
+/// - `CodecError::InvalidData` if `native` does not match the length of a frame in the layout.
+pub fn encode_frame(native: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+    let bytes_per_sample = layout.bytes_per_sample();
+    let samples = usize::from(layout.samples_per_pixel);
+    let precision = layout.bits_stored;
+    if (bytes_per_sample != 1 && bytes_per_sample != 2)
+        || !(2..=16).contains(&precision)
+        || usize::from(precision) > bytes_per_sample * 8
+        || samples == 0
+        || samples > 4
+    {
+        return Err(CodecError::UnsupportedLayout(format!(
+            "{samples} samples of {} bits with {precision} bits stored",
+            layout.bits_alloc
+        )));
+    }
+    // A frame without samples has no symbol frequencies to build a Huffman table from.
+    if layout.rows == 0 || layout.cols == 0 {
+        return Err(CodecError::UnsupportedLayout(format!(
+            "empty frame of {}x{}",
+            layout.cols, layout.rows
+        )));
+    }
+    if native.len() != layout.frame_len() {
+        return Err(CodecError::InvalidData(format!(
+            "expected {} bytes for frame but got {}",
+            layout.frame_len(),
+            native.len()
+        )));
+    }
+
+    // Compute the differences for every sample, Section H.1.2.1 with Selection Value 1.
+    let width = usize::from(layout.cols);
+    let mask = (1i32 << precision) - 1;
+    let values = native
+        .chunks_exact(bytes_per_sample)
+        .map(|b| {
+            let val = if bytes_per_sample == 1 {
+                i32::from(b[0])
+            } else {
+                i32::from(u16::from_le_bytes([b[0], b[1]]))
+            };
+            val & mask
+        })
+        .collect::<Vec<i32>>();
+    let mut diffs = Vec::with_capacity(values.len());
+    for (i, val) in values.iter().enumerate() {
+        let pixel = i / samples;
+        let x = pixel % width;
+        let pred = if pixel == 0 {
+            1i32 << (precision - 1)
+        } else if x == 0 {
+            values[i - width * samples]
+        } else {
+            values[i - samples]
+        };
+        // Differences are computed modulo 2^16, Section H.1.2.1.
+        let diff = (val - pred) & 0xFFFF;
+        diffs.push(if diff >= 0x8000 {
+            diff - 0x1_0000
+        } else {
+            diff
+        });
+    }
+
+    let mut freqs = [0u32; NUM_CATEGORIES];
+    for diff in &diffs {
+        freqs[usize::from(category(*diff))] += 1;
+    }
+    let (counts, huffvals) = optimal_table(&freqs);
+    let codes = code_table(&counts, &huffvals);
+
+    let mut jpeg = Vec::with_capacity(native.len() / 2);
+    jpeg.extend_from_slice(&[MARKER, SOI]);
+
+    // SOF3, with component identifiers from 1 and no subsampling.
+    let num_comps = u8::try_from(samples).unwrap_or_default();
+    jpeg.extend_from_slice(&[MARKER, SOF3]);
+    jpeg.extend_from_slice(&(8 + 3 * u16::from(num_comps)).to_be_bytes());
+    jpeg.push(u8::try_from(precision).unwrap_or_default());
+    jpeg.extend_from_slice(&layout.rows.to_be_bytes());
+    jpeg.extend_from_slice(&layout.cols.to_be_bytes());
+    jpeg.push(num_comps);
+    for id in 1..=num_comps {
+        jpeg.extend_from_slice(&[id, 0x11, 0x00]);
+    }
+
+    // DHT, a single table shared by all components.
+    jpeg.extend_from_slice(&[MARKER, DHT]);
+    let dht_len = 2 + 1 + 16 + u16::try_from(huffvals.len()).unwrap_or_default();
+    jpeg.extend_from_slice(&dht_len.to_be_bytes());
+    jpeg.push(0x00);
+    jpeg.extend_from_slice(&counts);
+    jpeg.extend_from_slice(&huffvals);
+
+    // SOS, with predictor 1 and no point transform.
+    jpeg.extend_from_slice(&[MARKER, SOS]);
+    jpeg.extend_from_slice(&(6 + 2 * u16::from(num_comps)).to_be_bytes());
+    jpeg.push(num_comps);
+    for id in 1..=num_comps {
+        jpeg.extend_from_slice(&[id, 0x00]);
+    }
+    jpeg.extend_from_slice(&[0x01, 0x00, 0x00]);
+
+    let mut writer = BitWriter::new(&mut jpeg);
+    for diff in diffs {
+        let size = category(diff);
+        let (code, len) = codes[usize::from(size)];
+        writer.write_bits(u32::from(code), len);
+        if size > 0 && size < 16 {
+            // Negative differences are encoded as the low bits of diff - 1, Section F.1.2.1.
+            let extra = if diff < 0 { diff - 1 } else { diff };
+            writer.write_bits(
+                u32::try_from(extra & ((1 << size) - 1)).unwrap_or_default(),
+                size,
+            );
+        }
+    }
+    writer.flush();
+
+    jpeg.extend_from_slice(&[MARKER, EOI]);
+    Ok(jpeg)
+}
+
+/// The difference category, the number of bits needed for the magnitude of the difference.
+fn category(diff: i32) -> u8 {
+    u8::try_from(32 - diff.unsigned_abs().leading_zeros()).unwrap_or(16)
+}
+
+/// Generates an optimal Huffman table for the given symbol frequencies, limited to codes of 16
+/// bits, per Section K.2. Returns the number of codes of each length and the symbols ordered by
+/// code length.
+fn optimal_table(freqs: &[u32; NUM_CATEGORIES]) -> ([u8; 16], Vec<u8>) {
+    // One reserved symbol with a frequency of 1 ensures no code is all ones.
+    let reserved = NUM_CATEGORIES;
+    let mut freq = freqs.iter().map(|f| u64::from(*f)).collect::<Vec<u64>>();
+    freq.push(1);
+    let mut codesize = vec![0usize; NUM_CATEGORIES + 1];
+    let mut others: Vec<Option<usize>> = vec![None; NUM_CATEGORIES + 1];
+
+    loop {
+        // Find the least frequent symbol, and the next least frequent, preferring larger indices
+        // on ties, Figure K.1.
+        let mut v1: Option<usize> = None;
+        let mut v2: Option<usize> = None;
+        for (i, f) in freq.iter().enumerate() {
+            if *f == 0 {
+                continue;
+            }
+            if v1.is_none_or(|v| *f <= freq[v]) {
+                v2 = v1;
+                v1 = Some(i);
+            } else if v2.is_none_or(|v| *f <= freq[v]) {
+                v2 = Some(i);
+            }
+        }
+        let (Some(mut v1), Some(mut v2)) = (v1, v2) else {
+            break;
+        };
+
+        freq[v1] += freq[v2];
+        freq[v2] = 0;
+        codesize[v1] += 1;
+        while let Some(next) = others[v1] {
+            v1 = next;
+            codesize[v1] += 1;
+        }
+        others[v1] = Some(v2);
+        codesize[v2] += 1;
+        while let Some(next) = others[v2] {
+            v2 = next;
+            codesize[v2] += 1;
+        }
+    }
+
+    let mut bits = [0usize; 33];
+    for size in &codesize {
+        if *size > 0 {
+            bits[*size] += 1;
+        }
+    }
+    // Limit code lengths to 16 bits, Figure K.3.
+    let mut i = 32;
+    while i > 16 {
+        while bits[i] > 0 {
+            let mut j = i - 2;
+            while bits[j] == 0 {
+                j -= 1;
+            }
+            bits[i] -= 2;
+            bits[i - 1] += 1;
+            bits[j + 1] += 2;
+            bits[j] -= 1;
+        }
+        i -= 1;
+    }
+    // Remove the reserved code from the longest length.
+    while i > 0 && bits[i] == 0 {
+        i -= 1;
+    }
+    bits[i] -= 1;
+
+    let mut counts = [0u8; 16];
+    for (count, num) in counts.iter_mut().zip(&bits[1..=16]) {
+        *count = u8::try_from(*num).unwrap_or_default();
+    }
+    let mut huffvals = Vec::with_capacity(NUM_CATEGORIES);
+    for size in 1..=32 {
+        for (symbol, symbol_size) in codesize.iter().enumerate() {
+            if *symbol_size == size && symbol != reserved {
+                huffvals.push(u8::try_from(symbol).unwrap_or_default());
+            }
+        }
+    }
+    (counts, huffvals)
+}
+
+/// Generates the code and code length for each symbol, Section C.2.
+fn code_table(counts: &[u8; 16], huffvals: &[u8]) -> [(u16, u8); NUM_CATEGORIES] {
+    let mut codes = [(0u16, 0u8); NUM_CATEGORIES];
+    let mut code = 0u16;
+    let mut k = 0usize;
+    for (len, count) in (1u8..=16).zip(counts) {
+        for _ in 0..*count {
+            codes[usize::from(huffvals[k])] = (code, len);
+            code = code.wrapping_add(1);
+            k += 1;
+        }
+        code = code.wrapping_shl(1);
+    }
+    codes
+}
+
+/// Writes entropy-coded data bit by bit, stuffing a zero byte after any 0xFF byte.
+struct BitWriter<'a> {
+    out: &'a mut Vec<u8>,
+    bits: u32,
+    nbits: u8,
+}
+
+impl<'a> BitWriter<'a> {
+    fn new(out: &'a mut Vec<u8>) -> Self {
+        Self {
+            out,
+            bits: 0,
+            nbits: 0,
+        }
+    }
+
+    /// Writes the low `count` bits of `value`, up to 16.
+    fn write_bits(&mut self, value: u32, count: u8) {
+        if count == 0 {
+            return;
+        }
+        self.bits = (self.bits << count) | (value & ((1 << count) - 1));
+        self.nbits += count;
+        while self.nbits >= 8 {
+            let byte = u8::try_from((self.bits >> (self.nbits - 8)) & 0xFF).unwrap_or_default();
+            self.out.push(byte);
+            if byte == MARKER {
+                self.out.push(0x00);
+            }
+            self.nbits -= 8;
+        }
+    }
+
+    /// Pads the final byte with one bits.
+    fn flush(&mut self) {
+        if self.nbits > 0 {
+            let pad = 8 - self.nbits;
+            self.write_bits((1 << pad) - 1, pad);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::codec::{
+        jpeg::lossless::{decode_frame, encode_frame},
+        CodecError, FrameLayout,
+    };
+
+    #[test]
+    pub fn test_roundtrip_16bit_signed() {
+        let layout = FrameLayout {
+            rows: 7,
+            cols: 9,
+            samples_per_pixel: 1,
+            bits_alloc: 16,
+            bits_stored: 16,
+            pixel_rep: 1,
+        };
+        // Values spanning the full signed range, including the extremes which result in a
+        // difference of 32768.
+        let values = (0..layout.pixel_count())
+            .map(|i| match i % 5 {
+                0 => i16::MIN,
+                1 => i16::MAX,
+                _ => i16::try_from(i * 37).unwrap() - 1024,
+            })
+            .collect::<Vec<i16>>();
+        let native = values
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect::<Vec<u8>>();
+
+        let encoded = encode_frame(&native, &layout).unwrap();
+        assert_eq!(native, decode_frame(&encoded, &layout).unwrap());
+    }
+
+    #[test]
+    pub fn test_roundtrip_8bit_rgb() {
+        let layout = FrameLayout {
+            rows: 5,
+            cols: 11,
+            samples_per_pixel: 3,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        let native = (0..layout.frame_len())
+            .map(|i| u8::try_from(i * 13 % 256).unwrap())
+            .collect::<Vec<u8>>();
+
+        let encoded = encode_frame(&native, &layout).unwrap();
+        assert_eq!(native, decode_frame(&encoded, &layout).unwrap());
+    }
+
+    #[test]
+    pub fn test_roundtrip_12bit_signed_flat() {
+        let layout = FrameLayout {
+            rows: 16,
+            cols: 16,
+            samples_per_pixel: 1,
+            bits_alloc: 16,
+            bits_stored: 12,
+            pixel_rep: 1,
+        };
+        // A single value results in a table with a single symbol. The value is only encoded with
+        // 12 bits and should be sign-extended when decoded.
+        let native = std::iter::repeat_n((-1234i16).to_le_bytes(), layout.pixel_count())
+            .flatten()
+            .collect::<Vec<u8>>();
+
+        let encoded = encode_frame(&native, &layout).unwrap();
+        assert_eq!(native, decode_frame(&encoded, &layout).unwrap());
+    }
+
+    #[test]
+    pub fn test_encode_empty_frame() {
+        let layout = FrameLayout {
+            rows: 0,
+            cols: 4,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        assert!(matches!(
+            encode_frame(&[], &layout),
+            Err(CodecError::UnsupportedLayout(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_decode_oversized_frame_header() {
+        let layout = FrameLayout {
+            rows: 1,
+            cols: 1,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        // A frame header of 65535x65535 is rejected before allocating planes for it.
+        let jpeg: [u8; 25] = [
+            0xFF, 0xD8, 0xFF, 0xC3, 0x00, 0x0B, 0x08, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x01, 0x11,
+            0x00, 0xFF, 0xDA, 0x00, 0x08, 0x01, 0x01, 0x00, 0x01, 0x00, 0x00,
+        ];
+        assert!(matches!(
+            decode_frame(&jpeg, &layout),
+            Err(CodecError::UnsupportedLayout(_))
+        ));
+    }
+}
@@ -0,0 +1,835 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! JPEG-LS Lossless and Near-Lossless, ITU-T T.87. Samples are predicted from their neighbors
+//! and the prediction errors are Golomb coded using adaptive context modelling (LOCO-I), with a
+//! run mode for flat regions of the image.
+
+use crate::core::codec::{
+    jpeg::{
+        planes_to_native, FrameHeader, JpegReader, LsPresets, ScanHeader, MARKER, RST0, RST7, SOF55,
+    },
+    CodecError, FrameLayout,
+};
+
+/// The basic thresholds for quantizing local gradients, Section C.2.4.1.1.1.
+const BASIC_T1: i32 = 3;
+const BASIC_T2: i32 = 7;
+const BASIC_T3: i32 = 21;
+/// The default value of the counter at which context statistics are halved.
+const DEFAULT_RESET: i32 = 64;
+/// The number of contexts for regular mode, Section A.3.3.
+const NUM_CONTEXTS: usize = 365;
+/// The bounds of the bias correction of each context, Section A.6.2.
+const MIN_C: i32 = -128;
+const MAX_C: i32 = 127;
+/// The order of run length codes for run mode, Section A.7.1.2.
+const J: [u8; 32] = [
+    0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 9, 10, 11, 12, 13,
+    14, 15,
+];
+/// The maximum number of components supported for sample-interleaved scans.
+const MAX_SAMPLE_INTERLEAVED: usize = 4;
+
+/// Decodes a single frame of JPEG-LS compressed data into native samples.
+///
+/// The decoded samples are arranged as little-endian samples interleaved by pixel. Signed samples
+/// are sign-extended from Bits Stored.
+///
+/// # Errors
+/// - `CodecError::UnsupportedLayout` if the JPEG stream is not JPEG-LS, uses subsampled components,
+///   mapping tables, or a point transform, or does not match the given frame layout.
+/// - `CodecError::InvalidData` if the JPEG stream is malformed or truncated.
+pub fn decode_frame(frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+    let mut reader = JpegReader::new(frame)?;
+    let mut planes: Vec<Vec<u16>> = Vec::new();
+    while let Some(scan) = reader.next_scan()? {
+        let header = reader.frame()?.clone();
+        if header.sof != SOF55 {
+            return Err(CodecError::UnsupportedLayout(format!(
+                "JPEG process with SOF marker {:#04X}",
+                header.sof
+            )));
+        }
+        if header.precision < 2 || header.precision > 16 {
+            return Err(CodecError::InvalidData(format!(
+                "JPEG-LS with {} bit precision",
+                header.precision
+            )));
+        }
+        if header.components.iter().any(|c| c.h != 1 || c.v != 1) {
+            return Err(CodecError::UnsupportedLayout(
+                "JPEG-LS with subsampled components".to_owned(),
+            ));
+        }
+        if scan.components.iter().any(|c| c.td != 0 || c.ta != 0) {
+            return Err(CodecError::UnsupportedLayout(
+                "JPEG-LS with mapping tables".to_owned(),
+            ));
+        }
+        if scan.al != 0 {
+            return Err(CodecError::UnsupportedLayout(
+                "JPEG-LS with point transform".to_owned(),
+            ));
+        }
+        header.check_layout(layout)?;
+        if planes.is_empty() {
+            let pixels = usize::from(header.width) * usize::from(header.height);
+            planes = vec![vec![0u16; pixels]; header.components.len()];
+        }
+        let params = CodingParams::new(header.precision, scan.ss, reader.ls_presets.as_ref())?;
+        let len = decode_scan(&reader, &header, &scan, params, &mut planes)?;
+        reader.end_scan(len);
+    }
+
+    planes_to_native(reader.frame()?, &planes, layout)
+}
+
+/// Decodes the entropy-coded data of a single scan into the component planes, returning the
+/// number of bytes of entropy-coded data.
+fn decode_scan(
+    reader: &JpegReader,
+    header: &FrameHeader,
+    scan: &ScanHeader,
+    params: CodingParams,
+    planes: &mut [Vec<u16>],
+) -> Result<usize, CodecError> {
+    if let Some(sc) = scan.components.iter().find(|sc| sc.index >= planes.len()) {
+        return Err(CodecError::InvalidData(format!(
+            "scan component {} has no decoded plane",
+            sc.index
+        )));
+    }
+    let num_comps = scan.components.len();
+    let interleave = scan.se;
+    match interleave {
+        0 if num_comps == 1 => {}
+        1 => {}
+        2 if num_comps <= MAX_SAMPLE_INTERLEAVED => {}
+        _ => {
+            return Err(CodecError::UnsupportedLayout(format!(
+                "JPEG-LS scan with {num_comps} components and interleave mode {interleave}"
+            )))
+        }
+    }
+
+    // Each line is stored with an extra sample on both ends, which hold the neighbors used for
+    // predicting the first and last samples of the next line, Section A.2.1.
+    let width = usize::from(header.width);
+    let height = usize::from(header.height);
+    let mut prev_lines = vec![vec![0i32; width + 2]; num_comps];
+    let mut cur_lines = vec![vec![0i32; width + 2]; num_comps];
+    // Line-interleaved scans track the run index separately for each component.
+    let mut run_indices = vec![0usize; num_comps];
+
+    let restart_interval = usize::from(reader.restart_interval);
+    let mut decoder = ScanDecoder::new(params, reader.scan_bytes());
+    for y in 0..height {
+        if restart_interval > 0 && y > 0 && y % restart_interval == 0 {
+            decoder.restart();
+            prev_lines.iter_mut().flatten().for_each(|v| *v = 0);
+            cur_lines.iter_mut().flatten().for_each(|v| *v = 0);
+            run_indices.fill(0);
+        }
+
+        std::mem::swap(&mut prev_lines, &mut cur_lines);
+        for (prev, cur) in prev_lines.iter_mut().zip(cur_lines.iter_mut()) {
+            prev[width + 1] = prev[width];
+            cur[0] = prev[1];
+        }
+
+        if interleave == 2 {
+            decoder.decode_line(&prev_lines, &mut cur_lines, width)?;
+        } else {
+            for (c, run_index) in run_indices.iter_mut().enumerate() {
+                decoder.run_index = *run_index;
+                decoder.decode_line(&prev_lines[c..=c], &mut cur_lines[c..=c], width)?;
+                *run_index = decoder.run_index;
+            }
+        }
+
+        for (sc, cur) in scan.components.iter().zip(&cur_lines) {
+            let plane = &mut planes[sc.index][y * width..(y + 1) * width];
+            for (sample, value) in plane.iter_mut().zip(&cur[1..=width]) {
+                *sample = u16::try_from(*value).unwrap_or_default();
+            }
+        }
+    }
+
+    Ok(decoder.bits.position())
+}
+
+/// The coding parameters of a scan, Section A.2.
+#[derive(Debug, Clone)]
+struct CodingParams {
+    maxval: i32,
+    near: i32,
+    range: i32,
+    /// The number of bits needed to represent a mapped error value.
+    qbpp: u32,
+    /// The maximum length of a Golomb code.
+    limit: u32,
+    t1: i32,
+    t2: i32,
+    t3: i32,
+    reset: i32,
+}
+
+impl CodingParams {
+    fn new(precision: u8, near: u8, presets: Option<&LsPresets>) -> Result<Self, CodecError> {
+        let presets = presets.cloned().unwrap_or_default();
+        let full = (1i32 << precision) - 1;
+        let maxval = if presets.maxval == 0 {
+            full
+        } else {
+            i32::from(presets.maxval)
+        };
+        let near = i32::from(near);
+        if maxval > full || near > (maxval / 2).min(255) {
+            return Err(CodecError::InvalidData(format!(
+                "invalid JPEG-LS parameters, MAXVAL: {maxval}, NEAR: {near}"
+            )));
+        }
+
+        let range = (maxval + 2 * near) / (2 * near + 1) + 1;
+        let qbpp = bit_len(range - 1);
+        let bpp = bit_len(maxval).max(2);
+        let limit = 2 * (bpp + bpp.max(8));
+
+        // The default thresholds, Section C.2.4.1.1.1.
+        let clamp = |val: i32, min: i32| {
+            if val > maxval || val < min {
+                min
+            } else {
+                val
+            }
+        };
+        let (t1, t2, t3) = if maxval >= 128 {
+            let factor = (maxval.min(4095) + 128) / 256;
+            let t1 = clamp(factor * (BASIC_T1 - 2) + 2 + 3 * near, near + 1);
+            let t2 = clamp(factor * (BASIC_T2 - 3) + 3 + 5 * near, t1);
+            let t3 = clamp(factor * (BASIC_T3 - 4) + 4 + 7 * near, t2);
+            (t1, t2, t3)
+        } else {
+            let factor = 256 / (maxval + 1);
+            let t1 = clamp((BASIC_T1 / factor + 3 * near).max(2), near + 1);
+            let t2 = clamp((BASIC_T2 / factor + 5 * near).max(3), t1);
+            let t3 = clamp((BASIC_T3 / factor + 7 * near).max(4), t2);
+            (t1, t2, t3)
+        };
+        let preset_or = |preset: u16, default: i32| {
+            if preset == 0 {
+                default
+            } else {
+                i32::from(preset)
+            }
+        };
+
+        Ok(Self {
+            maxval,
+            near,
+            range,
+            qbpp,
+            limit,
+            t1: preset_or(presets.t1, t1),
+            t2: preset_or(presets.t2, t2),
+            t3: preset_or(presets.t3, t3),
+            reset: preset_or(presets.reset, DEFAULT_RESET),
+        })
+    }
+
+    /// Quantizes a local gradient into one of nine regions, Section A.3.3.
+    fn quantize(&self, d: i32) -> i32 {
+        if d <= -self.t3 {
+            -4
+        } else if d <= -self.t2 {
+            -3
+        } else if d <= -self.t1 {
+            -2
+        } else if d < -self.near {
+            -1
+        } else if d <= self.near {
+            0
+        } else if d < self.t1 {
+            1
+        } else if d < self.t2 {
+            2
+        } else if d < self.t3 {
+            3
+        } else {
+            4
+        }
+    }
+
+    /// The signed context index of the quantized gradients. Zero indicates run mode.
+    fn context_id(&self, ra: i32, rb: i32, rc: i32, rd: i32) -> i32 {
+        (self.quantize(rd - rb) * 9 + self.quantize(rb - rc)) * 9 + self.quantize(rc - ra)
+    }
+
+    /// Reconstructs a sample from its prediction and the decoded error, Section A.4.5.
+    fn reconstruct(&self, predicted: i32, error: i32) -> i32 {
+        let scale = 2 * self.near + 1;
+        let mut val = predicted + error * scale;
+        if val < -self.near {
+            val += self.range * scale;
+        } else if val > self.maxval + self.near {
+            val -= self.range * scale;
+        }
+        val.clamp(0, self.maxval)
+    }
+}
+
+/// The statistics of a regular mode context, Section A.2.2.
+#[derive(Debug, Clone, Copy)]
+struct RegularContext {
+    /// Accumulated magnitude of prediction errors.
+    a: i32,
+    /// Accumulated bias of prediction errors.
+    b: i32,
+    /// The bias correction applied to predictions.
+    c: i32,
+    /// The number of occurrences of the context.
+    n: i32,
+}
+
+impl RegularContext {
+    fn new(range: i32) -> Self {
+        Self {
+            a: ((range + 32) / 64).max(2),
+            b: 0,
+            c: 0,
+            n: 1,
+        }
+    }
+
+    /// The Golomb coding parameter, Section A.5.1.
+    fn golomb_k(&self) -> Result<u32, CodecError> {
+        golomb_k(self.n, self.a)
+    }
+
+    /// Updates the statistics after decoding an error, Sections A.6.1 and A.6.2.
+    fn update(&mut self, error: i32, near: i32, reset: i32) -> Result<(), CodecError> {
+        self.a += error.abs();
+        self.b += error * (2 * near + 1);
+        if self.a >= 1 << 24 || self.b.abs() >= 1 << 24 {
+            return Err(CodecError::InvalidData(
+                "JPEG-LS context statistics overflow".to_owned(),
+            ));
+        }
+        if self.n == reset {
+            self.a >>= 1;
+            self.b >>= 1;
+            self.n >>= 1;
+        }
+        self.n += 1;
+
+        if self.b + self.n <= 0 {
+            self.b += self.n;
+            if self.b <= -self.n {
+                self.b = -self.n + 1;
+            }
+            if self.c > MIN_C {
+                self.c -= 1;
+            }
+        } else if self.b > 0 {
+            self.b -= self.n;
+            if self.b > 0 {
+                self.b = 0;
+            }
+            if self.c < MAX_C {
+                self.c += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The statistics of a run interruption context, Section A.7.2.
+#[derive(Debug, Clone, Copy)]
+struct RunContext {
+    /// Whether the interrupting sample is predicted from the sample to its left (1) or above (0).
+    ri_type: i32,
+    a: i32,
+    n: i32,
+    /// The number of negative errors.
+    nn: i32,
+}
+
+impl RunContext {
+    fn new(ri_type: i32, range: i32) -> Self {
+        Self {
+            ri_type,
+            a: ((range + 32) / 64).max(2),
+            n: 1,
+            nn: 0,
+        }
+    }
+
+    fn golomb_k(&self) -> Result<u32, CodecError> {
+        golomb_k(self.n, self.a + (self.n >> 1) * self.ri_type)
+    }
+
+    /// Recovers the error from its mapped value, Section A.7.2.2.
+    fn unmap(&self, mapped: i32, k: u32) -> i32 {
+        let temp = mapped + self.ri_type;
+        let map = temp & 1;
+        let abs = (temp + map) / 2;
+        if (k != 0 || 2 * self.nn >= self.n) == (map == 1) {
+            -abs
+        } else {
+            abs
+        }
+    }
+
+    /// Updates the statistics after decoding an error, Section A.7.2.3.
+    fn update(&mut self, error: i32, mapped: i32, reset: i32) {
+        if error < 0 {
+            self.nn += 1;
+        }
+        self.a += (mapped + 1 - self.ri_type) >> 1;
+        if self.n == reset {
+            self.a >>= 1;
+            self.n >>= 1;
+            self.nn >>= 1;
+        }
+        self.n += 1;
+    }
+}
+
+/// The state of decoding a single scan.
+struct ScanDecoder<'a> {
+    params: CodingParams,
+    bits: BitReader<'a>,
+    contexts: [RegularContext; NUM_CONTEXTS],
+    run_contexts: [RunContext; 2],
+    run_index: usize,
+}
+
+impl<'a> ScanDecoder<'a> {
+    fn new(params: CodingParams, data: &'a [u8]) -> Self {
+        let range = params.range;
+        Self {
+            params,
+            bits: BitReader::new(data),
+            contexts: [RegularContext::new(range); NUM_CONTEXTS],
+            run_contexts: [RunContext::new(0, range), RunContext::new(1, range)],
+            run_index: 0,
+        }
+    }
+
+    /// Resets the statistics and moves past the restart marker at the end of a restart interval.
+    fn restart(&mut self) {
+        let range = self.params.range;
+        self.contexts = [RegularContext::new(range); NUM_CONTEXTS];
+        self.run_contexts = [RunContext::new(0, range), RunContext::new(1, range)];
+        self.run_index = 0;
+        self.bits.restart();
+    }
+
+    /// Decodes a single line of samples. Multiple components are decoded for sample-interleaved
+    /// scans, otherwise there is a single component.
+    fn decode_line(
+        &mut self,
+        prev: &[Vec<i32>],
+        cur: &mut [Vec<i32>],
+        width: usize,
+    ) -> Result<(), CodecError> {
+        let mut ids = [0i32; MAX_SAMPLE_INTERLEAVED];
+        let mut x = 1;
+        while x <= width {
+            for (id, (p, c)) in ids.iter_mut().zip(prev.iter().zip(cur.iter())) {
+                *id = self.params.context_id(c[x - 1], p[x], p[x - 1], p[x + 1]);
+            }
+            if ids[..prev.len()].iter().all(|id| *id == 0) {
+                x += self.decode_run(prev, cur, x, width)?;
+            } else {
+                for (id, (p, c)) in ids.iter().zip(prev.iter().zip(cur.iter_mut())) {
+                    c[x] = self.decode_regular(*id, predict(c[x - 1], p[x], p[x - 1]))?;
+                }
+                x += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Decodes a sample in regular mode, Sections A.4 through A.6.
+    fn decode_regular(&mut self, id: i32, predicted: i32) -> Result<i32, CodecError> {
+        let sign = if id < 0 { -1 } else { 1 };
+        let ctx = &mut self.contexts[id.unsigned_abs() as usize];
+        let k = ctx.golomb_k()?;
+        let predicted = (predicted + sign * ctx.c).clamp(0, self.params.maxval);
+
+        let mapped = self
+            .bits
+            .decode_value(k, self.params.limit, self.params.qbpp)?;
+        let mut error = if mapped & 1 == 0 {
+            mapped >> 1
+        } else {
+            -((mapped + 1) >> 1)
+        };
+        if k == 0 && self.params.near == 0 && 2 * ctx.b + ctx.n - 1 < 0 {
+            error = -error - 1;
+        }
+        ctx.update(error, self.params.near, self.params.reset)?;
+
+        Ok(self.params.reconstruct(predicted, sign * error))
+    }
+
+    /// Decodes a run of samples equal to the sample to the left, followed by the sample which
+    /// interrupts the run unless the run reaches the end of the line, Section A.7. Returns the
+    /// number of samples decoded.
+    fn decode_run(
+        &mut self,
+        prev: &[Vec<i32>],
+        cur: &mut [Vec<i32>],
+        x: usize,
+        width: usize,
+    ) -> Result<usize, CodecError> {
+        let remaining = width + 1 - x;
+        let mut len = 0usize;
+        while self.bits.read_bit() {
+            let run = 1usize << J[self.run_index];
+            let count = run.min(remaining - len);
+            len += count;
+            if count == run {
+                self.run_index = (self.run_index + 1).min(J.len() - 1);
+            }
+            if len == remaining {
+                break;
+            }
+        }
+        if len != remaining {
+            len += self.bits.read_bits(J[self.run_index]) as usize;
+        }
+        if len > remaining {
+            return Err(CodecError::InvalidData(
+                "JPEG-LS run exceeds the line".to_owned(),
+            ));
+        }
+
+        for c in cur.iter_mut() {
+            let ra = c[x - 1];
+            c[x..x + len].fill(ra);
+        }
+        if len == remaining {
+            return Ok(len);
+        }
+
+        let end = x + len;
+        if cur.len() == 1 {
+            let ra = cur[0][end - 1];
+            let rb = prev[0][end];
+            cur[0][end] = if (ra - rb).abs() <= self.params.near {
+                let error = self.decode_run_error(1)?;
+                self.params.reconstruct(ra, error)
+            } else {
+                let error = self.decode_run_error(0)?;
+                self.params.reconstruct(rb, error * sign(rb - ra))
+            };
+        } else {
+            // Sample-interleaved components are each predicted from the sample above.
+            for (p, c) in prev.iter().zip(cur.iter_mut()) {
+                let ra = c[end - 1];
+                let rb = p[end];
+                let error = self.decode_run_error(0)?;
+                c[end] = self.params.reconstruct(rb, error * sign(rb - ra));
+            }
+        }
+        self.run_index = self.run_index.saturating_sub(1);
+        Ok(len + 1)
+    }
+
+    /// Decodes the error of a run interruption sample, Section A.7.2.
+    fn decode_run_error(&mut self, ri_type: usize) -> Result<i32, CodecError> {
+        let ctx = &mut self.run_contexts[ri_type];
+        let k = ctx.golomb_k()?;
+        let limit = self.params.limit - u32::from(J[self.run_index]) - 1;
+        let mapped = self.bits.decode_value(k, limit, self.params.qbpp)?;
+        let error = ctx.unmap(mapped, k);
+        ctx.update(error, mapped, self.params.reset);
+        Ok(error)
+    }
+}
+
+/// The median edge detecting predictor, Section A.4.1.
+fn predict(ra: i32, rb: i32, rc: i32) -> i32 {
+    if rc >= ra.max(rb) {
+        ra.min(rb)
+    } else if rc <= ra.min(rb) {
+        ra.max(rb)
+    } else {
+        ra + rb - rc
+    }
+}
+
+fn sign(val: i32) -> i32 {
+    if val < 0 {
+        -1
+    } else {
+        1
+    }
+}
+
+/// The number of bits needed to represent the given non-negative value.
+fn bit_len(val: i32) -> u32 {
+    32 - val.leading_zeros()
+}
+
+/// Computes the Golomb coding parameter, the smallest `k` such that `n * 2^k >= a`.
+fn golomb_k(n: i32, a: i32) -> Result<u32, CodecError> {
+    let mut k = 0u32;
+    while (i64::from(n) << k) < i64::from(a) {
+        k += 1;
+        if k > 24 {
+            return Err(CodecError::InvalidData(
+                "invalid JPEG-LS context statistics".to_owned(),
+            ));
+        }
+    }
+    Ok(k)
+}
+
+/// Reads JPEG-LS entropy-coded data bit by bit. Following a 0xFF byte, the high bit of the next
+/// byte is a stuffed zero bit, and a set high bit indicates a marker, Section A.1.
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    bits: u64,
+    nbits: u8,
+    /// Whether the previous byte was 0xFF, so the next byte only holds 7 bits.
+    stuffed: bool,
+    /// Whether a marker or the end of the data has been reached, after which zero bits are read.
+    at_marker: bool,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            bits: 0,
+            nbits: 0,
+            stuffed: false,
+            at_marker: false,
+        }
+    }
+
+    /// The position of the next unread byte.
+    fn position(&self) -> usize {
+        self.pos
+    }
+
+    fn load_byte(&mut self) {
+        let byte = match self.data.get(self.pos) {
+            Some(byte) if !(self.at_marker || (self.stuffed && byte & 0x80 != 0)) => *byte,
+            _ => {
+                self.at_marker = true;
+                self.bits <<= 8;
+                self.nbits += 8;
+                return;
+            }
+        };
+        let byte = u64::from(byte);
+        if self.stuffed {
+            self.bits = (self.bits << 7) | byte;
+            self.nbits += 7;
+        } else {
+            self.bits = (self.bits << 8) | byte;
+            self.nbits += 8;
+        }
+        self.stuffed = byte == u64::from(MARKER);
+        self.pos += 1;
+    }
+
+    /// Reads the given number of bits, up to 32.
+    fn read_bits(&mut self, count: u8) -> u32 {
+        if count == 0 {
+            return 0;
+        }
+        while self.nbits < count {
+            self.load_byte();
+        }
+        self.nbits -= count;
+        let val = (self.bits >> self.nbits) & ((1u64 << count) - 1);
+        u32::try_from(val).unwrap_or_default()
+    }
+
+    fn read_bit(&mut self) -> bool {
+        self.read_bits(1) == 1
+    }
+
+    /// Decodes a length-limited Golomb code, Section A.5.3.
+    fn decode_value(&mut self, k: u32, limit: u32, qbpp: u32) -> Result<i32, CodecError> {
+        let escape = limit.saturating_sub(qbpp + 1);
+        let mut high = 0u32;
+        while !self.read_bit() {
+            high += 1;
+            if high > escape {
+                return Err(CodecError::InvalidData(
+                    "JPEG-LS code exceeds the length limit".to_owned(),
+                ));
+            }
+        }
+        let val = if high == escape {
+            self.read_bits(u8::try_from(qbpp).unwrap_or_default()) + 1
+        } else {
+            (high << k) | self.read_bits(u8::try_from(k).unwrap_or_default())
+        };
+        i32::try_from(val).map_err(|_| {
+            CodecError::InvalidData("JPEG-LS code exceeds the length limit".to_owned())
+        })
+    }
+
+    /// Discards the remaining bits of the current byte and moves past a restart marker.
+    fn restart(&mut self) {
+        self.bits = 0;
+        self.nbits = 0;
+        if self.stuffed && self.data.get(self.pos).is_some_and(|b| b & 0x80 == 0) {
+            self.pos += 1;
+        }
+        while self.data.get(self.pos) == Some(&MARKER)
+            && self.data.get(self.pos + 1) == Some(&MARKER)
+        {
+            self.pos += 1;
+        }
+        if self.data.get(self.pos) == Some(&MARKER)
+            && self
+                .data
+                .get(self.pos + 1)
+                .is_some_and(|b| (RST0..=RST7).contains(b))
+        {
+            self.pos += 2;
+        }
+        self.stuffed = false;
+        self.at_marker = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::codec::{jpeg::ls::decode_frame, CodecError, FrameLayout};
+
+    /// An 8x4 image where the left half is flat, exercising both run mode and regular mode.
+    const LOSSLESS_JPEG: [u8; 50] = [
+        0xFF, 0xD8, 0xFF, 0xF7, 0x00, 0x0B, 0x08, 0x00, 0x04, 0x00, 0x08, 0x01, 0x01, 0x11, 0x00,
+        0xFF, 0xDA, 0x00, 0x08, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xC6, 0x94,
+        0x00, 0x00, 0x02, 0xBC, 0x17, 0x0A, 0x91, 0xCE, 0x06, 0x06, 0x05, 0x9C, 0x38, 0xC2, 0xEC,
+        0x59, 0x24, 0x60, 0xFF, 0xD9,
+    ];
+
+    #[test]
+    pub fn test_decode_lossless() {
+        let expected: [u8; 32] = [
+            100, 100, 100, 100, 148, 185, 222, 3, 100, 100, 100, 100, 159, 196, 233, 14, 100, 100,
+            100, 100, 170, 207, 244, 25, 100, 100, 100, 100, 181, 218, 255, 36,
+        ];
+        let layout = FrameLayout {
+            rows: 4,
+            cols: 8,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+
+        let decoded = decode_frame(&LOSSLESS_JPEG, &layout).unwrap();
+        assert_eq!(expected.as_slice(), decoded.as_slice());
+    }
+
+    #[test]
+    pub fn test_decode_near_lossless_sample_interleaved() {
+        // A 6x3 RGB image encoded with a NEAR of 2 and sample-interleaved components.
+        let jpeg: [u8; 74] = [
+            0xFF, 0xD8, 0xFF, 0xF7, 0x00, 0x11, 0x08, 0x00, 0x03, 0x00, 0x06, 0x03, 0x01, 0x11,
+            0x00, 0x02, 0x11, 0x00, 0x03, 0x11, 0x00, 0xFF, 0xDA, 0x00, 0x0C, 0x03, 0x01, 0x00,
+            0x02, 0x00, 0x03, 0x00, 0x02, 0x02, 0x00, 0x40, 0x00, 0x00, 0x20, 0x40, 0x04, 0x03,
+            0x7B, 0xDA, 0xD6, 0xB5, 0xAB, 0x5A, 0xD6, 0xD0, 0x18, 0x41, 0x10, 0x22, 0x33, 0x11,
+            0x91, 0x86, 0x13, 0x80, 0x41, 0x44, 0x08, 0x8C, 0x88, 0x89, 0x9D, 0x35, 0x9D, 0x18,
+            0x06, 0x40, 0xFF, 0xD9,
+        ];
+        let original: [u8; 54] = [
+            0, 60, 120, 40, 100, 160, 80, 140, 200, 120, 180, 240, 160, 220, 24, 200, 4, 64, 20,
+            80, 140, 60, 120, 180, 100, 160, 220, 140, 200, 4, 180, 240, 44, 220, 24, 84, 40, 100,
+            160, 80, 140, 200, 120, 180, 240, 160, 220, 24, 200, 4, 64, 240, 44, 104,
+        ];
+        let layout = FrameLayout {
+            rows: 3,
+            cols: 6,
+            samples_per_pixel: 3,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+
+        let decoded = decode_frame(&jpeg, &layout).unwrap();
+        assert_eq!(original.len(), decoded.len());
+        for (orig, val) in original.iter().zip(decoded) {
+            assert!(orig.abs_diff(val) <= 2, "{val} is not within 2 of {orig}");
+        }
+    }
+
+    #[test]
+    pub fn test_decode_mismatched_frame_header() {
+        let layout = FrameLayout {
+            rows: 4,
+            cols: 8,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        // A frame header of 65535x65535 is rejected before allocating planes for it.
+        let mut jpeg = LOSSLESS_JPEG.to_vec();
+        jpeg[7..11].copy_from_slice(&[0xFF; 4]);
+        assert!(matches!(
+            decode_frame(&jpeg, &layout),
+            Err(CodecError::UnsupportedLayout(_))
+        ));
+
+        // A second frame header with three components, followed by a scan of the third.
+        let mut jpeg = LOSSLESS_JPEG[..LOSSLESS_JPEG.len() - 2].to_vec();
+        jpeg.extend_from_slice(&[
+            0xFF, 0xF7, 0x00, 0x11, 0x08, 0x00, 0x04, 0x00, 0x08, 0x03, 0x01, 0x11, 0x00, 0x02,
+            0x11, 0x00, 0x03, 0x11, 0x00,
+        ]);
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x08, 0x01, 0x03, 0x00, 0x00, 0x00, 0x00]);
+        jpeg.extend_from_slice(&[0x00, 0xFF, 0xD9]);
+        assert!(matches!(
+            decode_frame(&jpeg, &layout),
+            Err(CodecError::UnsupportedLayout(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_decode_not_jpegls() {
+        // A JPEG Lossless frame header.
+        let jpeg: [u8; 25] = [
+            0xFF, 0xD8, 0xFF, 0xC3, 0x00, 0x0B, 0x08, 0x00, 0x01, 0x00, 0x01, 0x01, 0x01, 0x11,
+            0x00, 0xFF, 0xDA, 0x00, 0x08, 0x01, 0x01, 0x00, 0x01, 0x00, 0x00,
+        ];
+        let layout = FrameLayout {
+            rows: 1,
+            cols: 1,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+
+        let result = decode_frame(&jpeg, &layout);
+        assert!(matches!(result, Err(CodecError::UnsupportedLayout(_))));
+    }
+}
@@ -0,0 +1,735 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! JPEG, Part 5 Section 8.2.1 and ISO/IEC 10918-1 (ITU-T T.81), as well as JPEG-LS, Part 5
+//! Section 8.2.3 and ISO/IEC 14495-1 (ITU-T T.87), which shares the same stream structure.
+//!
+//! This module handles the structure shared by the JPEG processes: markers, table segments, frame
+//! and scan headers, and reading Huffman-coded entropy data. The processes themselves are
+//! implemented in the sub-modules.
+
+use crate::core::codec::{CodecError, FrameLayout};
+
+pub mod dct;
+pub mod lossless;
+pub mod ls;
+
+/// Marker prefix byte.
+pub(crate) const MARKER: u8 = 0xFF;
+/// Start of Frame, Baseline DCT.
+pub(crate) const SOF0: u8 = 0xC0;
+/// Start of Frame, Extended sequential DCT, Huffman coding.
+pub(crate) const SOF1: u8 = 0xC1;
+/// Start of Frame, Progressive DCT, Huffman coding.
+pub(crate) const SOF2: u8 = 0xC2;
+/// Start of Frame, Lossless (sequential), Huffman coding.
+pub(crate) const SOF3: u8 = 0xC3;
+/// Define Huffman Tables.
+pub(crate) const DHT: u8 = 0xC4;
+/// Define Arithmetic Coding conditioning.
+pub(crate) const DAC: u8 = 0xCC;
+/// Restart markers, RST0 through RST7.
+pub(crate) const RST0: u8 = 0xD0;
+pub(crate) const RST7: u8 = 0xD7;
+/// Start of Image.
+pub(crate) const SOI: u8 = 0xD8;
+/// End of Image.
+pub(crate) const EOI: u8 = 0xD9;
+/// Start of Scan.
+pub(crate) const SOS: u8 = 0xDA;
+/// Define Quantization Tables.
+pub(crate) const DQT: u8 = 0xDB;
+/// Define Restart Interval.
+pub(crate) const DRI: u8 = 0xDD;
+/// Application segment used by Adobe to indicate the color transform.
+pub(crate) const APP14: u8 = 0xEE;
+/// Start of Frame, JPEG-LS.
+pub(crate) const SOF55: u8 = 0xF7;
+/// JPEG-LS preset parameters.
+pub(crate) const LSE: u8 = 0xF8;
+
+/// The number of bits in a code resolved by a single lookup into a `HuffmanTable`.
+const LOOKUP_BITS: u8 = 9;
+
+/// A component of the frame, from the Frame Header.
+#[derive(Debug, Clone)]
+pub(crate) struct FrameComponent {
+    pub id: u8,
+    /// Horizontal sampling factor.
+    pub h: u8,
+    /// Vertical sampling factor.
+    pub v: u8,
+    /// Quantization table destination selector.
+    pub tq: u8,
+}
+
+/// The Frame Header, from the SOFn marker segment.
+#[derive(Debug, Clone)]
+pub(crate) struct FrameHeader {
+    /// The SOFn marker, indicating the process used.
+    pub sof: u8,
+    /// Sample precision, in bits.
+    pub precision: u8,
+    pub height: u16,
+    pub width: u16,
+    pub components: Vec<FrameComponent>,
+}
+
+impl FrameHeader {
+    pub(crate) fn max_h(&self) -> u8 {
+        self.components.iter().map(|c| c.h).max().unwrap_or(1)
+    }
+
+    pub(crate) fn max_v(&self) -> u8 {
+        self.components.iter().map(|c| c.v).max().unwrap_or(1)
+    }
+
+    /// Checks that the frame matches the expected layout. This is checked for each scan before
+    /// allocating or decoding into planes, as the dimensions and components of the frame header are
+    /// not otherwise limited, and a later frame header may replace the first.
+    pub(crate) fn check_layout(&self, layout: &FrameLayout) -> Result<(), CodecError> {
+        if self.width != layout.cols
+            || self.height != layout.rows
+            || self.components.len() != usize::from(layout.samples_per_pixel)
+        {
+            return Err(CodecError::UnsupportedLayout(format!(
+                "JPEG frame of {}x{} with {} components does not match {}x{} with {} samples",
+                self.width,
+                self.height,
+                self.components.len(),
+                layout.cols,
+                layout.rows,
+                layout.samples_per_pixel
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A component of a scan, from the Scan Header.
+#[derive(Debug, Clone)]
+pub(crate) struct ScanComponent {
+    /// Index of the component within the Frame Header's components.
+    pub index: usize,
+    /// DC entropy coding table destination selector. For lossless this is the only table.
+    pub td: u8,
+    /// AC entropy coding table destination selector.
+    pub ta: u8,
+}
+
+/// The Scan Header, from the SOS marker segment.
+#[derive(Debug, Clone)]
+pub(crate) struct ScanHeader {
+    pub components: Vec<ScanComponent>,
+    /// Start of spectral selection, the predictor selection for lossless, or the near-lossless
+    /// error bound for JPEG-LS.
+    pub ss: u8,
+    /// End of spectral selection, or the interleave mode for JPEG-LS.
+    pub se: u8,
+    /// Successive approximation bit position low, or the point transform for lossless.
+    pub al: u8,
+}
+
+/// The JPEG-LS preset coding parameters, from the LSE marker segment, ITU-T T.87 Section C.2.4.1.1.
+/// A value of zero indicates the default value should be used.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LsPresets {
+    pub maxval: u16,
+    pub t1: u16,
+    pub t2: u16,
+    pub t3: u16,
+    pub reset: u16,
+}
+
+/// A Huffman table, from the DHT marker segment, Annex C.
+#[derive(Debug, Clone)]
+pub(crate) struct HuffmanTable {
+    /// Lookup by the next `LOOKUP_BITS` bits, giving the code length and value. A code length of
+    /// zero indicates the code is longer than `LOOKUP_BITS`.
+    lookup: Vec<(u8, u8)>,
+    /// The largest code of each length, or -1 if there are no codes of that length.
+    maxcode: [i32; 17],
+    /// The smallest code of each length.
+    mincode: [i32; 17],
+    /// The index into `values` of the first value with a code of each length.
+    valptr: [usize; 17],
+    values: Vec<u8>,
+}
+
+impl HuffmanTable {
+    /// Generates the table from the number of codes of each length and the values associated with
+    /// the codes, per Annex C.
+    pub(crate) fn new(counts: &[u8; 16], values: Vec<u8>) -> Result<Self, CodecError> {
+        let total = counts.iter().map(|c| usize::from(*c)).sum::<usize>();
+        if total != values.len() || total > 256 {
+            return Err(CodecError::InvalidData(
+                "Huffman table has mismatched code counts".to_owned(),
+            ));
+        }
+
+        let mut table = HuffmanTable {
+            lookup: vec![(0, 0); 1 << LOOKUP_BITS],
+            maxcode: [-1; 17],
+            mincode: [0; 17],
+            valptr: [0; 17],
+            values,
+        };
+        let mut code = 0u32;
+        let mut k = 0usize;
+        for len in 1..=16u8 {
+            let len_idx = usize::from(len);
+            table.mincode[len_idx] = i32::try_from(code).unwrap_or(i32::MAX);
+            table.valptr[len_idx] = k;
+            for _ in 0..counts[len_idx - 1] {
+                if code >= (1 << len) {
+                    return Err(CodecError::InvalidData(
+                        "Huffman table has too many codes".to_owned(),
+                    ));
+                }
+                if len <= LOOKUP_BITS {
+                    let shift = LOOKUP_BITS - len;
+                    let start = (code << shift) as usize;
+                    for entry in &mut table.lookup[start..start + (1 << shift)] {
+                        *entry = (len, table.values[k]);
+                    }
+                }
+                code += 1;
+                k += 1;
+            }
+            if counts[len_idx - 1] > 0 {
+                table.maxcode[len_idx] = i32::try_from(code).unwrap_or(i32::MAX) - 1;
+            }
+            code <<= 1;
+        }
+        Ok(table)
+    }
+}
+
+/// Reads entropy-coded data bit by bit, removing stuffed zero bytes. Once a marker is reached no
+/// further bytes are consumed and zero bits are returned, which allows the caller to detect the
+/// marker position after decoding a scan or restart interval.
+pub(crate) struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    /// Buffered bits, aligned to the most significant bit.
+    bits: u64,
+    nbits: u8,
+    at_marker: bool,
+}
+
+impl<'a> BitReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            bits: 0,
+            nbits: 0,
+            at_marker: false,
+        }
+    }
+
+    /// The position of the next unread byte.
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+
+    fn fill(&mut self) {
+        while self.nbits <= 56 {
+            let byte = if self.at_marker {
+                0
+            } else {
+                match self.data.get(self.pos) {
+                    None => {
+                        self.at_marker = true;
+                        0
+                    }
+                    // A stuffed zero byte following 0xFF indicates the 0xFF is data.
+                    Some(&MARKER) if self.data.get(self.pos + 1) == Some(&0x00) => {
+                        self.pos += 2;
+                        MARKER
+                    }
+                    Some(&MARKER) => {
+                        self.at_marker = true;
+                        0
+                    }
+                    Some(byte) => {
+                        self.pos += 1;
+                        *byte
+                    }
+                }
+            };
+            self.bits |= u64::from(byte) << (56 - self.nbits);
+            self.nbits += 8;
+        }
+    }
+
+    fn peek(&mut self, count: u8) -> u32 {
+        if self.nbits < count {
+            self.fill();
+        }
+        u32::try_from(self.bits >> (64 - u32::from(count))).unwrap_or_default()
+    }
+
+    fn consume(&mut self, count: u8) {
+        self.bits <<= count;
+        self.nbits -= count;
+    }
+
+    /// Reads `count` bits, up to 16, as an unsigned value.
+    pub(crate) fn read_bits(&mut self, count: u8) -> u32 {
+        if count == 0 {
+            return 0;
+        }
+        let val = self.peek(count);
+        self.consume(count);
+        val
+    }
+
+    /// Decodes the next Huffman-coded value using the given table.
+    pub(crate) fn decode(&mut self, table: &HuffmanTable) -> Result<u8, CodecError> {
+        let (len, val) = table.lookup[self.peek(LOOKUP_BITS) as usize];
+        if len > 0 {
+            self.consume(len);
+            return Ok(val);
+        }
+
+        for len in (LOOKUP_BITS + 1)..=16 {
+            let code = i32::try_from(self.peek(len)).unwrap_or(i32::MAX);
+            let len_idx = usize::from(len);
+            if code <= table.maxcode[len_idx] {
+                self.consume(len);
+                let index = table.valptr[len_idx]
+                    + usize::try_from(code - table.mincode[len_idx]).unwrap_or_default();
+                return table.values.get(index).copied().ok_or_else(|| {
+                    CodecError::InvalidData("Huffman code out of range".to_owned())
+                });
+            }
+        }
+        Err(CodecError::InvalidData("invalid Huffman code".to_owned()))
+    }
+
+    /// Reads `size` bits and extends them into a signed value, per the RECEIVE and EXTEND
+    /// procedures of Section F.2.2.1.
+    pub(crate) fn receive_extend(&mut self, size: u8) -> i32 {
+        if size == 0 {
+            return 0;
+        }
+        if size >= 16 {
+            // Only used by lossless for a difference of 32768, which has no additional bits.
+            return 32768;
+        }
+        let val = i32::try_from(self.read_bits(size)).unwrap_or_default();
+        if val < (1 << (size - 1)) {
+            val - (1 << size) + 1
+        } else {
+            val
+        }
+    }
+
+    /// Handles the end of a restart interval: remaining bits in the current byte are discarded and
+    /// the RSTn marker that should follow is skipped.
+    pub(crate) fn restart(&mut self) {
+        self.bits = 0;
+        self.nbits = 0;
+        // Skip any fill bytes before the marker.
+        while self.data.get(self.pos) == Some(&MARKER)
+            && self.data.get(self.pos + 1) == Some(&MARKER)
+        {
+            self.pos += 1;
+        }
+        if self.data.get(self.pos) == Some(&MARKER)
+            && self
+                .data
+                .get(self.pos + 1)
+                .is_some_and(|m| (RST0..=RST7).contains(m))
+        {
+            self.pos += 2;
+        }
+        self.at_marker = false;
+    }
+}
+
+/// Reads the marker segments of a JPEG stream, collecting the tables and headers needed by the
+/// decoding processes. The entropy-coded data of each scan is read separately with a `BitReader`.
+pub(crate) struct JpegReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    pub frame: Option<FrameHeader>,
+    pub dc_tables: [Option<HuffmanTable>; 4],
+    pub ac_tables: [Option<HuffmanTable>; 4],
+    /// Quantization tables, in zig-zag order.
+    pub qtables: [Option<[u16; 64]>; 4],
+    pub restart_interval: u16,
+    /// The transform flag from an Adobe APP14 segment, if present.
+    pub adobe_transform: Option<u8>,
+    /// The JPEG-LS preset coding parameters from an LSE segment, if present.
+    pub ls_presets: Option<LsPresets>,
+}
+
+impl<'a> JpegReader<'a> {
+    /// Creates a reader over the JPEG stream, which must start with the SOI marker.
+    pub(crate) fn new(data: &'a [u8]) -> Result<Self, CodecError> {
+        if data.len() < 2 || data[0] != MARKER || data[1] != SOI {
+            return Err(CodecError::InvalidData(
+                "JPEG stream does not start with SOI marker".to_owned(),
+            ));
+        }
+        Ok(Self {
+            data,
+            pos: 2,
+            frame: None,
+            dc_tables: [None, None, None, None],
+            ac_tables: [None, None, None, None],
+            qtables: [None; 4],
+            restart_interval: 0,
+            adobe_transform: None,
+            ls_presets: None,
+        })
+    }
+
+    /// Creates a `BitReader` over the entropy-coded data of the scan most recently returned by
+    /// `next_scan()`.
+    pub(crate) fn scan_data(&self) -> BitReader<'a> {
+        BitReader::new(&self.data[self.pos..])
+    }
+
+    /// The entropy-coded data of the scan most recently returned by `next_scan()`, through the end
+    /// of the stream.
+    pub(crate) fn scan_bytes(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+
+    /// Updates the position of this reader to the end of the scan, given the number of bytes of
+    /// entropy-coded data that were read.
+    pub(crate) fn end_scan(&mut self, len: usize) {
+        self.pos += len;
+    }
+
+    /// The Frame Header, which must have been read prior to any scan.
+    pub(crate) fn frame(&self) -> Result<&FrameHeader, CodecError> {
+        self.frame
+            .as_ref()
+            .ok_or_else(|| CodecError::InvalidData("JPEG stream is missing SOF marker".to_owned()))
+    }
+
+    /// Reads marker segments until the next Start of Scan, returning its header, or `None` once the
+    /// End of Image is reached.
+    pub(crate) fn next_scan(&mut self) -> Result<Option<ScanHeader>, CodecError> {
+        loop {
+            // Find the next marker, skipping any entropy-coded data not consumed by the previous
+            // scan as well as fill bytes.
+            while self.pos + 1 < self.data.len()
+                && (self.data[self.pos] != MARKER
+                    || self.data[self.pos + 1] == 0x00
+                    || self.data[self.pos + 1] == MARKER
+                    || (RST0..=RST7).contains(&self.data[self.pos + 1]))
+            {
+                self.pos += 1;
+            }
+            if self.pos + 1 >= self.data.len() {
+                // Tolerate a missing EOI.
+                return Ok(None);
+            }
+
+            let marker = self.data[self.pos + 1];
+            self.pos += 2;
+            if marker == EOI {
+                return Ok(None);
+            }
+
+            let segment = self.read_segment()?;
+            match marker {
+                SOS => return self.read_scan_header(segment).map(Some),
+                DHT => self.read_huffman_tables(segment)?,
+                DQT => self.read_quant_tables(segment)?,
+                DRI => {
+                    if segment.len() < 2 {
+                        return Err(CodecError::InvalidData(
+                            "DRI segment is truncated".to_owned(),
+                        ));
+                    }
+                    self.restart_interval = u16::from_be_bytes([segment[0], segment[1]]);
+                }
+                APP14 if segment.len() >= 12 && segment.starts_with(b"Adobe") => {
+                    self.adobe_transform = Some(segment[11]);
+                }
+                DAC => {
+                    return Err(CodecError::UnsupportedLayout(
+                        "JPEG arithmetic coding".to_owned(),
+                    ))
+                }
+                // SOFn, excluding DHT, JPG, and DAC which share the range.
+                0xC0..=0xCF | SOF55 => self.read_frame_header(marker, segment)?,
+                LSE => self.read_ls_presets(segment)?,
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads the length-prefixed segment at the current position, returning its contents.
+    fn read_segment(&mut self) -> Result<&'a [u8], CodecError> {
+        let data: &'a [u8] = self.data;
+        let Some(len_bytes) = data.get(self.pos..self.pos + 2) else {
+            return Err(CodecError::InvalidData(
+                "JPEG segment is truncated".to_owned(),
+            ));
+        };
+        let len = usize::from(u16::from_be_bytes([len_bytes[0], len_bytes[1]]));
+        let Some(segment) = data.get(self.pos + 2..self.pos + len.max(2)) else {
+            return Err(CodecError::InvalidData(
+                "JPEG segment is truncated".to_owned(),
+            ));
+        };
+        self.pos += len.max(2);
+        Ok(segment)
+    }
+
+    fn read_frame_header(&mut self, sof: u8, segment: &[u8]) -> Result<(), CodecError> {
+        if segment.len() < 6 {
+            return Err(CodecError::InvalidData(
+                "SOF segment is truncated".to_owned(),
+            ));
+        }
+        let num_comps = usize::from(segment[5]);
+        if num_comps == 0 || segment.len() < 6 + num_comps * 3 {
+            return Err(CodecError::InvalidData(
+                "SOF segment is truncated".to_owned(),
+            ));
+        }
+        let components = segment[6..6 + num_comps * 3]
+            .chunks_exact(3)
+            .map(|c| FrameComponent {
+                id: c[0],
+                h: (c[1] >> 4).max(1),
+                v: (c[1] & 0x0F).max(1),
+                tq: c[2] & 0x03,
+            })
+            .collect::<Vec<FrameComponent>>();
+        let frame = FrameHeader {
+            sof,
+            precision: segment[0],
+            height: u16::from_be_bytes([segment[1], segment[2]]),
+            width: u16::from_be_bytes([segment[3], segment[4]]),
+            components,
+        };
+        if frame.height == 0 || frame.width == 0 {
+            return Err(CodecError::UnsupportedLayout(format!(
+                "JPEG frame of {}x{}",
+                frame.width, frame.height
+            )));
+        }
+        self.frame = Some(frame);
+        Ok(())
+    }
+
+    fn read_scan_header(&self, segment: &[u8]) -> Result<ScanHeader, CodecError> {
+        let frame = self.frame()?;
+        let num_comps = usize::from(*segment.first().unwrap_or(&0));
+        if num_comps == 0 || segment.len() < 4 + num_comps * 2 {
+            return Err(CodecError::InvalidData(
+                "SOS segment is truncated".to_owned(),
+            ));
+        }
+        let mut components = Vec::with_capacity(num_comps);
+        for c in segment[1..1 + num_comps * 2].chunks_exact(2) {
+            let Some(index) = frame.components.iter().position(|fc| fc.id == c[0]) else {
+                return Err(CodecError::InvalidData(format!(
+                    "scan references unknown component {}",
+                    c[0]
+                )));
+            };
+            components.push(ScanComponent {
+                index,
+                td: (c[1] >> 4) & 0x03,
+                ta: c[1] & 0x03,
+            });
+        }
+        let params = &segment[1 + num_comps * 2..];
+        Ok(ScanHeader {
+            components,
+            ss: params[0],
+            se: params[1],
+            al: params[2] & 0x0F,
+        })
+    }
+
+    fn read_ls_presets(&mut self, segment: &[u8]) -> Result<(), CodecError> {
+        // Only the coding parameters are used. Mapping tables are rejected when referenced by a
+        // scan, and oversize dimensions cannot apply to DICOM images.
+        if segment.first() != Some(&1) {
+            return Ok(());
+        }
+        if segment.len() < 11 {
+            return Err(CodecError::InvalidData(
+                "LSE segment is truncated".to_owned(),
+            ));
+        }
+        let param = |i: usize| u16::from_be_bytes([segment[1 + i * 2], segment[2 + i * 2]]);
+        self.ls_presets = Some(LsPresets {
+            maxval: param(0),
+            t1: param(1),
+            t2: param(2),
+            t3: param(3),
+            reset: param(4),
+        });
+        Ok(())
+    }
+
+    fn read_huffman_tables(&mut self, mut segment: &[u8]) -> Result<(), CodecError> {
+        while !segment.is_empty() {
+            if segment.len() < 17 {
+                return Err(CodecError::InvalidData(
+                    "DHT segment is truncated".to_owned(),
+                ));
+            }
+            let class = segment[0] >> 4;
+            let dest = usize::from(segment[0] & 0x03);
+            let mut counts = [0u8; 16];
+            counts.copy_from_slice(&segment[1..17]);
+            let total = counts.iter().map(|c| usize::from(*c)).sum::<usize>();
+            let Some(values) = segment.get(17..17 + total) else {
+                return Err(CodecError::InvalidData(
+                    "DHT segment is truncated".to_owned(),
+                ));
+            };
+            let table = HuffmanTable::new(&counts, values.to_vec())?;
+            if class == 0 {
+                self.dc_tables[dest] = Some(table);
+            } else {
+                self.ac_tables[dest] = Some(table);
+            }
+            segment = &segment[17 + total..];
+        }
+        Ok(())
+    }
+
+    fn read_quant_tables(&mut self, mut segment: &[u8]) -> Result<(), CodecError> {
+        while !segment.is_empty() {
+            let precision = segment[0] >> 4;
+            let dest = usize::from(segment[0] & 0x03);
+            let len = if precision == 0 { 64 } else { 128 };
+            let Some(values) = segment.get(1..1 + len) else {
+                return Err(CodecError::InvalidData(
+                    "DQT segment is truncated".to_owned(),
+                ));
+            };
+            let mut table = [0u16; 64];
+            for (i, q) in table.iter_mut().enumerate() {
+                *q = if precision == 0 {
+                    u16::from(values[i])
+                } else {
+                    u16::from_be_bytes([values[i * 2], values[i * 2 + 1]])
+                };
+            }
+            self.qtables[dest] = Some(table);
+            segment = &segment[1 + len..];
+        }
+        Ok(())
+    }
+}
+
+/// Converts the decoded component planes of a frame into native samples interleaved by pixel,
+/// checking that the frame matches the expected layout. Signed samples are sign-extended from
+/// Bits Stored.
+pub(crate) fn planes_to_native(
+    frame: &FrameHeader,
+    planes: &[Vec<u16>],
+    layout: &FrameLayout,
+) -> Result<Vec<u8>, CodecError> {
+    if planes.is_empty() {
+        return Err(CodecError::InvalidData(
+            "JPEG stream has no scans".to_owned(),
+        ));
+    }
+    frame.check_layout(layout)?;
+
+    let bytes_per_sample = layout.bytes_per_sample();
+    let mut native = Vec::with_capacity(layout.frame_len());
+    for pixel in 0..layout.pixel_count() {
+        for plane in planes {
+            push_sample(
+                &mut native,
+                sign_extend(plane[pixel], layout),
+                bytes_per_sample,
+            );
+        }
+    }
+    Ok(native)
+}
+
+/// Sign-extends a decoded sample from Bits Stored if the samples are signed, so the sample can be
+/// read as a native value. The result is limited to the size of a native sample.
+fn sign_extend(sample: u16, layout: &FrameLayout) -> u16 {
+    if !layout.is_signed() {
+        return sample;
+    }
+    let shift = 16u16.saturating_sub(layout.bits_stored.clamp(1, 16));
+    let extended = (sample << shift)
+        .cast_signed()
+        .wrapping_shr(u32::from(shift))
+        .cast_unsigned();
+    if layout.bytes_per_sample() == 1 {
+        extended & 0xFF
+    } else {
+        extended
+    }
+}
+
+/// Appends a decoded sample to the native pixel data as a little-endian value of the given size.
+pub(crate) fn push_sample(native: &mut Vec<u8>, sample: u16, bytes_per_sample: usize) {
+    if bytes_per_sample == 1 {
+        native.push(u8::try_from(sample).unwrap_or(u8::MAX));
+    } else {
+        native.extend_from_slice(&sample.to_le_bytes());
+        native.extend(std::iter::repeat_n(0u8, bytes_per_sample.saturating_sub(2)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::codec::jpeg::{BitReader, HuffmanTable};
+
+    #[test]
+    pub fn test_huffman_decode() {
+        // Codes: 0 => 00, 1 => 010, 2 => 011, 3 => 100, and a 10-bit code of all ones but the
+        // last for 4, exercising the path beyond the lookup table.
+        let mut counts = [0u8; 16];
+        counts[1] = 1;
+        counts[2] = 3;
+        counts[9] = 1;
+        let table = HuffmanTable::new(&counts, vec![0, 1, 2, 3, 4]).unwrap();
+
+        // 00 011 100 1010000000 010, followed by padding. The 10-bit code is the 3-bit code 101,
+        // which is the next code after 100, shifted to 10 bits.
+        let bits = 0b0001_1100_1010_0000_0001_0000u32;
+        let data = bits.to_be_bytes();
+        let mut reader = BitReader::new(&data[1..]);
+        assert_eq!(0, reader.decode(&table).unwrap());
+        assert_eq!(2, reader.decode(&table).unwrap());
+        assert_eq!(3, reader.decode(&table).unwrap());
+        assert_eq!(4, reader.decode(&table).unwrap());
+        assert_eq!(1, reader.decode(&table).unwrap());
+    }
+
+    #[test]
+    pub fn test_receive_extend() {
+        let data = [0b0111_0000u8];
+        let mut reader = BitReader::new(&data);
+        // 01 of size 2 is -2, 110 of size 3 is 6.
+        assert_eq!(-2, reader.receive_extend(2));
+        assert_eq!(6, reader.receive_extend(3));
+    }
+}
@@ -0,0 +1,120 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Codecs for the value of encapsulated `PixelData`, converting between compressed frames and
+//! native pixel samples.
+
+use thiserror::Error;
+
+pub mod frames;
+#[cfg(feature = "j2k")]
+pub mod j2k;
+pub mod jpeg;
+pub mod registry;
+pub mod rle;
+
+#[derive(Error, Debug)]
+/// Errors that can occur while encoding or decoding encapsulated pixel data.
+pub enum CodecError {
+    /// The frame layout is not supported by the codec.
+    #[error("unsupported frame layout: {0}")]
+    UnsupportedLayout(String),
+
+    /// The compressed data is malformed or truncated.
+    #[error("invalid compressed data: {0}")]
+    InvalidData(String),
+}
+
+/// The layout of a single frame of native pixel data. Codecs use this to interpret the compressed
+/// data and to produce native samples, which are always little-endian and interleaved by pixel
+/// (Planar Configuration of 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameLayout {
+    pub rows: u16,
+    pub cols: u16,
+    pub samples_per_pixel: u16,
+    pub bits_alloc: u16,
+    pub bits_stored: u16,
+    pub pixel_rep: u16,
+}
+
+impl FrameLayout {
+    /// The number of pixels in a single frame.
+    #[must_use]
+    pub fn pixel_count(&self) -> usize {
+        usize::from(self.rows) * usize::from(self.cols)
+    }
+
+    /// The number of bytes used by a single sample.
+    #[must_use]
+    pub fn bytes_per_sample(&self) -> usize {
+        usize::from(self.bits_alloc).div_ceil(8)
+    }
+
+    /// The number of bytes of a single frame of native pixel data.
+    #[must_use]
+    pub fn frame_len(&self) -> usize {
+        self.pixel_count() * usize::from(self.samples_per_pixel) * self.bytes_per_sample()
+    }
+
+    /// Whether the samples are signed values.
+    #[must_use]
+    pub fn is_signed(&self) -> bool {
+        self.pixel_rep != 0
+    }
+}
+
+/// A codec for the frames of encapsulated `PixelData` in a particular transfer syntax. Codecs are
+/// looked up by transfer syntax through a `CodecRegistry`, which allows codecs to be provided
+/// outside of this crate.
+pub trait PixelDataCodec: Send + Sync {
+    /// Decodes the compressed bytes of a single frame into native samples of the given layout.
+    ///
+    /// # Errors
+    /// - `CodecError::UnsupportedLayout` if the codec cannot produce samples of the given layout.
+    /// - `CodecError::InvalidData` if the compressed data is malformed or truncated.
+    fn decode_frame(&self, frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError>;
+
+    /// Whether this codec can encode native frames. Codecs which only decode do not need to
+    /// implement this or `encode_frame`.
+    fn can_encode(&self) -> bool {
+        false
+    }
+
+    /// Encodes a single frame of native samples of the given layout into compressed bytes.
+    ///
+    /// # Errors
+    /// - `CodecError::UnsupportedLayout` if the codec cannot encode the given layout, or if the
+    ///   codec does not support encoding.
+    fn encode_frame(&self, native: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+        let _ = (native, layout);
+        Err(CodecError::UnsupportedLayout(
+            "encoding is not supported by this codec".to_owned(),
+        ))
+    }
+
+    /// The Photometric Interpretation of decoded frames, if decoding converts the samples from
+    /// the given Photometric Interpretation into a different color space. Returns `None` if the
+    /// decoded samples are in the same color space as the compressed samples.
+    fn decoded_photo_interp(
+        &self,
+        photo_interp: &str,
+        layout: &FrameLayout,
+    ) -> Option<&'static str> {
+        let _ = (photo_interp, layout);
+        None
+    }
+}
@@ -0,0 +1,278 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The registry of `PixelDataCodec`s by transfer syntax, and the codecs built in to this crate.
+
+use std::{collections::HashMap, sync::Arc};
+
+#[cfg(feature = "j2k")]
+use crate::core::codec::j2k;
+use crate::core::{
+    codec::{
+        jpeg::{dct, lossless, ls},
+        rle, CodecError, FrameLayout, PixelDataCodec,
+    },
+    defn::{constants::ts, ts::TSRef},
+};
+
+/// Maps transfer syntaxes to the `PixelDataCodec` used to decode and encode their `PixelData`.
+///
+/// The default registry contains the codecs built in to this crate. Codecs may be registered for
+/// additional transfer syntaxes, or to replace a built-in codec.
+#[derive(Clone)]
+pub struct CodecRegistry {
+    codecs: HashMap<TSRef, Arc<dyn PixelDataCodec>>,
+}
+
+impl Default for CodecRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register(&ts::RLELossless, Arc::new(RleCodec));
+        for ts in [&ts::JPEGBaselineProcess1, &ts::JPEGExtendedProcess2_and_4] {
+            registry.register(ts, Arc::new(JpegDctCodec));
+        }
+        for ts in [
+            &ts::JPEGLosslessNonHierarchicalProcess14,
+            &ts::JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1,
+        ] {
+            registry.register(ts, Arc::new(JpegLosslessCodec));
+        }
+        for ts in [
+            &ts::JPEGLSLosslessImageCompression,
+            &ts::JPEGLSLossyNearLosslessImageCompression,
+        ] {
+            registry.register(ts, Arc::new(JpegLsCodec));
+        }
+        #[cfg(feature = "j2k")]
+        for ts in [
+            &ts::JPEG2000ImageCompressionLosslessOnly,
+            &ts::JPEG2000ImageCompression,
+            &ts::HighThroughputJPEG2000ImageCompressionLosslessOnly,
+            &ts::HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly,
+            &ts::HighThroughputJPEG2000ImageCompression,
+        ] {
+            registry.register(ts, Arc::new(J2kCodec));
+        }
+        registry
+    }
+}
+
+impl std::fmt::Debug for CodecRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names = self
+            .codecs
+            .keys()
+            .map(|ts| ts.uid().name())
+            .collect::<Vec<&str>>();
+        names.sort_unstable();
+        f.debug_struct("CodecRegistry")
+            .field("codecs", &names)
+            .finish()
+    }
+}
+
+impl CodecRegistry {
+    /// Creates a registry without any codecs.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            codecs: HashMap::new(),
+        }
+    }
+
+    /// Registers the codec for the given transfer syntax, returning the codec it replaces.
+    pub fn register(
+        &mut self,
+        ts: TSRef,
+        codec: Arc<dyn PixelDataCodec>,
+    ) -> Option<Arc<dyn PixelDataCodec>> {
+        self.codecs.insert(ts, codec)
+    }
+
+    /// Removes the codec for the given transfer syntax, returning it if one was registered.
+    pub fn unregister(&mut self, ts: TSRef) -> Option<Arc<dyn PixelDataCodec>> {
+        self.codecs.remove(ts)
+    }
+
+    /// Gets the codec for the given transfer syntax.
+    #[must_use]
+    pub fn get(&self, ts: TSRef) -> Option<&dyn PixelDataCodec> {
+        self.codecs.get(ts).map(AsRef::as_ref)
+    }
+
+    /// Whether a codec is registered for the given transfer syntax.
+    #[must_use]
+    pub fn contains(&self, ts: TSRef) -> bool {
+        self.codecs.contains_key(ts)
+    }
+}
+
+/// The RLE Lossless codec, Part 5 Annex G.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RleCodec;
+
+impl PixelDataCodec for RleCodec {
+    fn decode_frame(&self, frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+        rle::decode_frame(frame, layout)
+    }
+
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    fn encode_frame(&self, native: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+        rle::encode_frame(native, layout)
+    }
+}
+
+/// The JPEG Baseline and Extended decoder. Color images are converted to RGB.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JpegDctCodec;
+
+impl PixelDataCodec for JpegDctCodec {
+    fn decode_frame(&self, frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+        dct::decode_frame(frame, layout)
+    }
+
+    fn decoded_photo_interp(
+        &self,
+        _photo_interp: &str,
+        layout: &FrameLayout,
+    ) -> Option<&'static str> {
+        (layout.samples_per_pixel == 3).then_some("RGB")
+    }
+}
+
+/// The JPEG Lossless Process 14 codec.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JpegLosslessCodec;
+
+impl PixelDataCodec for JpegLosslessCodec {
+    fn decode_frame(&self, frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+        lossless::decode_frame(frame, layout)
+    }
+
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    fn encode_frame(&self, native: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+        lossless::encode_frame(native, layout)
+    }
+}
+
+/// The JPEG-LS lossless and near-lossless decoder.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JpegLsCodec;
+
+impl PixelDataCodec for JpegLsCodec {
+    fn decode_frame(&self, frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+        ls::decode_frame(frame, layout)
+    }
+}
+
+/// The JPEG 2000 and HTJ2K decoder. Images using the reversible or irreversible color transform
+/// are converted to RGB.
+#[cfg(feature = "j2k")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct J2kCodec;
+
+#[cfg(feature = "j2k")]
+impl PixelDataCodec for J2kCodec {
+    fn decode_frame(&self, frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+        j2k::decode_frame(frame, layout)
+    }
+
+    fn decoded_photo_interp(
+        &self,
+        photo_interp: &str,
+        _layout: &FrameLayout,
+    ) -> Option<&'static str> {
+        (photo_interp == "YBR_RCT" || photo_interp == "YBR_ICT").then_some("RGB")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::core::{
+        codec::{registry::CodecRegistry, CodecError, FrameLayout, PixelDataCodec},
+        defn::constants::ts,
+    };
+
+    /// A codec whose compressed frames are the native samples with each byte inverted.
+    struct InvertCodec;
+
+    impl PixelDataCodec for InvertCodec {
+        fn decode_frame(&self, frame: &[u8], _layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+            Ok(frame.iter().map(|b| !b).collect())
+        }
+    }
+
+    const LAYOUT: FrameLayout = FrameLayout {
+        rows: 1,
+        cols: 2,
+        samples_per_pixel: 1,
+        bits_alloc: 8,
+        bits_stored: 8,
+        pixel_rep: 0,
+    };
+
+    #[test]
+    pub fn test_default_codecs() {
+        let registry = CodecRegistry::default();
+        assert!(registry.contains(&ts::RLELossless));
+        assert!(registry.contains(&ts::JPEGBaselineProcess1));
+        assert!(registry.contains(&ts::JPEGLSLosslessImageCompression));
+        assert!(!registry.contains(&ts::ExplicitVRLittleEndian));
+        assert_eq!(
+            cfg!(feature = "j2k"),
+            registry.contains(&ts::JPEG2000ImageCompression)
+        );
+
+        let rle = registry.get(&ts::RLELossless).unwrap();
+        assert!(rle.can_encode());
+        let encoded = rle.encode_frame(&[1, 2], &LAYOUT).unwrap();
+        assert_eq!(vec![1, 2], rle.decode_frame(&encoded, &LAYOUT).unwrap());
+
+        let ls = registry.get(&ts::JPEGLSLosslessImageCompression).unwrap();
+        assert!(!ls.can_encode());
+        assert!(matches!(
+            ls.encode_frame(&[1, 2], &LAYOUT),
+            Err(CodecError::UnsupportedLayout(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_register_codec() {
+        let mut registry = CodecRegistry::empty();
+        assert!(registry.get(&ts::RLELossless).is_none());
+
+        assert!(registry
+            .register(&ts::RLELossless, Arc::new(InvertCodec))
+            .is_none());
+        let codec = registry.get(&ts::RLELossless).unwrap();
+        assert_eq!(
+            vec![0xFE, 0x0F],
+            codec.decode_frame(&[1, 0xF0], &LAYOUT).unwrap()
+        );
+        assert!(codec.decoded_photo_interp("MONOCHROME2", &LAYOUT).is_none());
+
+        assert!(registry.unregister(&ts::RLELossless).is_some());
+        assert!(!registry.contains(&ts::RLELossless));
+    }
+}
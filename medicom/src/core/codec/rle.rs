@@ -0,0 +1,348 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! RLE Lossless, Part 5 Annex G.
+
+use crate::core::codec::{CodecError, FrameLayout};
+
+/// The length of the RLE Header which starts each frame.
+pub const RLE_HEADER_LEN: usize = 64;
+
+/// The maximum number of segments an RLE Header can reference.
+pub const RLE_MAX_SEGMENTS: usize = 15;
+
+/// Decodes a single frame of RLE Lossless compressed data into native samples.
+///
+/// Each segment of the frame holds one byte-plane of one sample, ordered by sample and then from
+/// the most significant byte to the least, per Part 5 Section G.2. The decoded bytes are arranged
+/// as little-endian samples interleaved by pixel.
+///
+/// # Errors
+/// - `CodecError::UnsupportedLayout` if the frame layout would require more than 15 segments.
+/// - `CodecError::InvalidData` if the RLE Header or any segment is malformed or truncated.
+pub fn decode_frame(frame: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+    let bytes_per_sample = layout.bytes_per_sample();
+    let samples = usize::from(layout.samples_per_pixel);
+    let expected_segments = bytes_per_sample * samples;
+    if expected_segments == 0 || expected_segments > RLE_MAX_SEGMENTS {
+        return Err(CodecError::UnsupportedLayout(format!(
+            "{samples} samples of {} bits",
+            layout.bits_alloc
+        )));
+    }
+
+    let offsets = read_header(frame)?;
+    if offsets.len() != expected_segments {
+        return Err(CodecError::InvalidData(format!(
+            "expected {expected_segments} segments but RLE Header specifies {}",
+            offsets.len()
+        )));
+    }
+
+    let pixels = layout.pixel_count();
+    let mut decoded = vec![0u8; layout.frame_len()];
+    let mut plane: Vec<u8> = Vec::with_capacity(pixels);
+    for (seg_index, start) in offsets.iter().enumerate() {
+        let end = offsets.get(seg_index + 1).copied().unwrap_or(frame.len());
+        if *start < RLE_HEADER_LEN || *start > end || end > frame.len() {
+            return Err(CodecError::InvalidData(format!(
+                "segment {seg_index} has invalid bounds: {start}..{end}"
+            )));
+        }
+
+        plane.clear();
+        decode_segment(&frame[*start..end], pixels, &mut plane)?;
+
+        // Segments are ordered most significant byte first, while the native output is
+        // little-endian.
+        let sample = seg_index / bytes_per_sample;
+        let byte_pos = bytes_per_sample - 1 - (seg_index % bytes_per_sample);
+        for (pixel, byte) in plane.iter().enumerate() {
+            decoded[(pixel * samples + sample) * bytes_per_sample + byte_pos] = *byte;
+        }
+    }
+
+    Ok(decoded)
+}
+
+/// Encodes a single frame of native samples into RLE Lossless compressed data.
+///
+/// The native samples are expected to be little-endian and interleaved by pixel. Each byte-plane
+/// of each sample is encoded as a separate segment, with each row of the plane encoded
+/// separately, per Part 5 Section G.3. Segments are padded to an even length.
+///
+/// # Errors
+/// - `CodecError::UnsupportedLayout` if the frame layout would require more than 15 segments.
+/// - `CodecError::InvalidData` if `native` does not match the length of a frame in the layout.
+pub fn encode_frame(native: &[u8], layout: &FrameLayout) -> Result<Vec<u8>, CodecError> {
+    let bytes_per_sample = layout.bytes_per_sample();
+    let samples = usize::from(layout.samples_per_pixel);
+    let num_segments = bytes_per_sample * samples;
+    if num_segments == 0 || num_segments > RLE_MAX_SEGMENTS {
+        return Err(CodecError::UnsupportedLayout(format!(
+            "{samples} samples of {} bits",
+            layout.bits_alloc
+        )));
+    }
+    if native.len() != layout.frame_len() {
+        return Err(CodecError::InvalidData(format!(
+            "expected {} bytes for frame but got {}",
+            layout.frame_len(),
+            native.len()
+        )));
+    }
+
+    let cols = usize::from(layout.cols);
+    let mut header = vec![0u8; RLE_HEADER_LEN];
+    header[0..4].copy_from_slice(
+        &u32::try_from(num_segments)
+            .unwrap_or_default()
+            .to_le_bytes(),
+    );
+    let mut segments: Vec<u8> = Vec::with_capacity(native.len());
+    let mut plane: Vec<u8> = Vec::with_capacity(layout.pixel_count());
+    for seg_index in 0..num_segments {
+        let offset = u32::try_from(RLE_HEADER_LEN + segments.len()).map_err(|_| {
+            CodecError::InvalidData("frame is too large for RLE Header offsets".to_owned())
+        })?;
+        header[4 + seg_index * 4..8 + seg_index * 4].copy_from_slice(&offset.to_le_bytes());
+
+        // Gather the byte-plane for this segment, most significant byte first.
+        let sample = seg_index / bytes_per_sample;
+        let byte_pos = bytes_per_sample - 1 - (seg_index % bytes_per_sample);
+        plane.clear();
+        plane.extend(
+            native
+                .iter()
+                .skip(sample * bytes_per_sample + byte_pos)
+                .step_by(samples * bytes_per_sample),
+        );
+
+        for row in plane.chunks(cols.max(1)) {
+            encode_row(row, &mut segments);
+        }
+        if segments.len() % 2 != 0 {
+            segments.push(0);
+        }
+    }
+
+    header.append(&mut segments);
+    Ok(header)
+}
+
+/// Encodes a single row of a byte-plane with PackBits, appending the result to `segment`.
+fn encode_row(row: &[u8], segment: &mut Vec<u8>) {
+    let mut pos = 0usize;
+    while pos < row.len() {
+        let run = row[pos..]
+            .iter()
+            .take(128)
+            .take_while(|b| **b == row[pos])
+            .count();
+        if run > 1 {
+            // Replicate run, encoded as -n+1.
+            segment.push(u8::try_from(257 - run).unwrap_or_default());
+            segment.push(row[pos]);
+            pos += run;
+            continue;
+        }
+
+        // Literal run, ending where a replicate run starts.
+        let start = pos;
+        pos += 1;
+        while pos < row.len() && pos - start < 128 {
+            if pos + 1 < row.len() && row[pos] == row[pos + 1] {
+                break;
+            }
+            pos += 1;
+        }
+        segment.push(u8::try_from(pos - start - 1).unwrap_or_default());
+        segment.extend_from_slice(&row[start..pos]);
+    }
+}
+
+/// Reads the RLE Header, returning the byte offset of each segment.
+fn read_header(frame: &[u8]) -> Result<Vec<usize>, CodecError> {
+    if frame.len() < RLE_HEADER_LEN {
+        return Err(CodecError::InvalidData(format!(
+            "RLE Header is truncated, frame is {} bytes",
+            frame.len()
+        )));
+    }
+
+    let header = frame[..RLE_HEADER_LEN]
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+        .collect::<Vec<usize>>();
+    let num_segments = header[0];
+    if num_segments == 0 || num_segments > RLE_MAX_SEGMENTS {
+        return Err(CodecError::InvalidData(format!(
+            "invalid number of segments: {num_segments}"
+        )));
+    }
+    Ok(header[1..=num_segments].to_vec())
+}
+
+/// Decodes a PackBits segment into `plane`, stopping once `len` bytes have been produced. Any
+/// trailing bytes in the segment, such as padding, are ignored.
+fn decode_segment(segment: &[u8], len: usize, plane: &mut Vec<u8>) -> Result<(), CodecError> {
+    let mut pos = 0usize;
+    while pos < segment.len() && plane.len() < len {
+        let header = segment[pos];
+        pos += 1;
+        match header {
+            // Literal run, the next n+1 bytes are copied.
+            0..=127 => {
+                let count = usize::from(header) + 1;
+                let Some(literal) = segment.get(pos..pos + count) else {
+                    return Err(CodecError::InvalidData(
+                        "literal run extends beyond segment".to_owned(),
+                    ));
+                };
+                plane.extend_from_slice(literal);
+                pos += count;
+            }
+            // No-op.
+            128 => {}
+            // Replicate run, the next byte is repeated -n+1 times.
+            129..=255 => {
+                let count = 257 - usize::from(header);
+                let Some(byte) = segment.get(pos) else {
+                    return Err(CodecError::InvalidData(
+                        "replicate run extends beyond segment".to_owned(),
+                    ));
+                };
+                plane.extend(std::iter::repeat_n(*byte, count));
+                pos += 1;
+            }
+        }
+    }
+
+    if plane.len() < len {
+        return Err(CodecError::InvalidData(format!(
+            "segment decoded to {} bytes, expected {len}",
+            plane.len()
+        )));
+    }
+    plane.truncate(len);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::codec::{
+        rle::{decode_frame, encode_frame, RLE_HEADER_LEN},
+        FrameLayout,
+    };
+
+    fn header(offsets: &[u32]) -> Vec<u8> {
+        let mut header = vec![0u8; RLE_HEADER_LEN];
+        header[0..4].copy_from_slice(&u32::try_from(offsets.len()).unwrap().to_le_bytes());
+        for (i, offset) in offsets.iter().enumerate() {
+            header[4 + i * 4..8 + i * 4].copy_from_slice(&offset.to_le_bytes());
+        }
+        header
+    }
+
+    #[test]
+    pub fn test_decode_8bit_mono() {
+        let layout = FrameLayout {
+            rows: 2,
+            cols: 3,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        let mut frame = header(&[64]);
+        // Replicate 0x05 three times, then a literal run of 3 bytes.
+        frame.extend_from_slice(&[0xFE, 0x05, 0x02, 0x01, 0x02, 0x03]);
+
+        let decoded = decode_frame(&frame, &layout).unwrap();
+        assert_eq!(vec![5, 5, 5, 1, 2, 3], decoded);
+    }
+
+    #[test]
+    pub fn test_decode_16bit_rgb() {
+        let layout = FrameLayout {
+            rows: 1,
+            cols: 2,
+            samples_per_pixel: 3,
+            bits_alloc: 16,
+            bits_stored: 16,
+            pixel_rep: 0,
+        };
+        // Six segments, each a literal run of two bytes padded to an even length.
+        let mut frame = header(&[64, 68, 72, 76, 80, 84]);
+        for (msb, lsb) in [(0x01, 0x02), (0x03, 0x04), (0x05, 0x06)] {
+            frame.extend_from_slice(&[0x01, msb, msb + 0x10, 0x00]);
+            frame.extend_from_slice(&[0x01, lsb, lsb + 0x10, 0x00]);
+        }
+
+        let decoded = decode_frame(&frame, &layout).unwrap();
+        assert_eq!(
+            vec![
+                0x02, 0x01, 0x04, 0x03, 0x06, 0x05, // First pixel
+                0x12, 0x11, 0x14, 0x13, 0x16, 0x15, // Second pixel
+            ],
+            decoded
+        );
+    }
+
+    #[test]
+    pub fn test_decode_truncated() {
+        let layout = FrameLayout {
+            rows: 2,
+            cols: 2,
+            samples_per_pixel: 1,
+            bits_alloc: 8,
+            bits_stored: 8,
+            pixel_rep: 0,
+        };
+        let mut frame = header(&[64]);
+        frame.extend_from_slice(&[0xFF, 0x05]);
+        assert!(decode_frame(&frame, &layout).is_err());
+    }
+
+    #[test]
+    pub fn test_encode_roundtrip() {
+        let layout = FrameLayout {
+            rows: 3,
+            cols: 130,
+            samples_per_pixel: 3,
+            bits_alloc: 16,
+            bits_stored: 12,
+            pixel_rep: 0,
+        };
+        // Mix of long runs, which exceed the maximum run length of 128, and literal values.
+        let native = (0..layout.pixel_count() * 3)
+            .flat_map(|i| {
+                let val = if i % 390 < 300 {
+                    7u16
+                } else {
+                    (i * 31 % 4096) as u16
+                };
+                val.to_le_bytes()
+            })
+            .collect::<Vec<u8>>();
+
+        let encoded = encode_frame(&native, &layout).unwrap();
+        assert_eq!(6, encoded[0]);
+        assert!(encoded.len() % 2 == 0);
+        assert!(encoded.len() < native.len());
+        assert_eq!(native, decode_frame(&encoded, &layout).unwrap());
+    }
+}
@@ -17,6 +17,7 @@
 //! DICOM Object, an in-memory tree-like structure representing a DICOM dataset.
 
 use std::{
+    borrow::Cow,
     collections::{btree_map, BTreeMap},
     fmt,
     io::{stdout, Error, Read, Write},
@@ -24,6 +25,7 @@ use std::{
 
 use crate::core::{
     charset::CSRef,
+    codec::{frames::FrameTable, CodecError},
     dcmelement::DicomElement,
     defn::{
         constants::tags,
@@ -141,6 +143,14 @@ impl DicomRoot {
         self.sentinel.get_child_by_tag_mut(tag)
     }
 
+    /// Removes a child node by tag number, returning it if it was present.
+    pub fn remove_child_by_tag<T>(&mut self, tag: T) -> Option<DicomObject>
+    where
+        u32: From<T>,
+    {
+        self.sentinel.remove_child_by_tag(tag)
+    }
+
     /// Returns an iterator for the child nodes in this `DicomRoot`.
     pub fn iter_child_nodes(&self) -> btree_map::Iter<'_, u32, DicomObject> {
         self.sentinel.iter_child_nodes()
@@ -246,6 +256,32 @@ impl DicomRoot {
         self.sentinel.flatten()
     }
 
+    /// Locates the frames of the encapsulated `PixelData`. See `DicomObject::pixel_data_frames`.
+    ///
+    /// # Errors
+    /// - `CodecError::InvalidData` if the frames cannot be determined from the offset tables or
+    ///   the fragments.
+    pub fn pixel_data_frames(&self) -> Result<Option<FrameTable>, CodecError> {
+        self.sentinel.pixel_data_frames()
+    }
+
+    /// The compressed bytes of a single frame of the encapsulated `PixelData`. See
+    /// `DicomObject::pixel_data_frame`.
+    #[must_use]
+    pub fn pixel_data_frame(&self, table: &FrameTable, index: usize) -> Option<Cow<'_, [u8]>> {
+        self.sentinel.pixel_data_frame(table, index)
+    }
+
+    /// The compressed bytes of each frame of the encapsulated `PixelData`. See
+    /// `DicomObject::pixel_data_frames_iter`.
+    #[must_use]
+    pub fn pixel_data_frames_iter<'a>(
+        &'a self,
+        table: &'a FrameTable,
+    ) -> Option<impl Iterator<Item = Option<Cow<'a, [u8]>>> + 'a> {
+        self.sentinel.pixel_data_frames_iter(table)
+    }
+
     /// Creates a new `DicomElement` from the given `Tag`, using this `DicomRoot`'s transfer syntax
     /// and the tag's implicit VR if present, or `UN` if not.
     #[must_use]
@@ -363,9 +399,12 @@ impl DicomRoot {
             let mut possible_next_elem: Option<Result<DicomElement, ParseError>> = None;
             // Checking sequence or item tag should match dcmparser.read_dicom_element() which
             // does not read a value for those elements but lets the parser read its value as
-            // separate elements which we're considering child elements.
-            let dcmobj: DicomObject = if element.is_sq_like()
-                || (tag == tags::ITEM && element.vl() != ValueLength::Explicit(0))
+            // separate elements which we're considering child elements. Items within encapsulated
+            // pixel data hold their fragment as the value and have no child elements. Sequences and
+            // items with a length of zero have no child elements, and recursing would take the
+            // elements which follow as their children.
+            let dcmobj: DicomObject = if element.vl() != ValueLength::Explicit(0)
+                && (element.is_sq_like() || (tag == tags::ITEM && !element.is_within_pixel_data()))
             {
                 let mut child_nodes: BTreeMap<u32, DicomObject> = BTreeMap::new();
                 let mut items: Vec<DicomObject> = Vec::new();
@@ -525,6 +564,14 @@ impl DicomObject {
         self.child_nodes.get_mut(&u32::from(tag))
     }
 
+    /// Removes a child node by tag number, returning it if it was present.
+    pub fn remove_child_by_tag<T>(&mut self, tag: T) -> Option<DicomObject>
+    where
+        u32: From<T>,
+    {
+        self.child_nodes.remove(&u32::from(tag))
+    }
+
     /// Returns an iterator for the child nodes in this `DicomObject`.
     pub fn iter_child_nodes(&self) -> btree_map::Iter<'_, u32, DicomObject> {
         self.child_nodes.iter()
@@ -680,6 +727,81 @@ impl DicomObject {
         let obj = DicomObject::new(elem);
         self.child_nodes.entry(tag).or_insert(obj)
     }
+
+    /// The fragments of this object's encapsulated `PixelData`, not including the Basic Offset
+    /// Table item. Returns `None` if this object does not have encapsulated `PixelData`.
+    #[must_use]
+    pub fn pixel_data_fragments(&self) -> Option<Vec<&[u8]>> {
+        let pixel_data = self
+            .get_child_by_tag(tags::PIXEL_DATA)
+            .filter(|o| o.element().has_fragments())?;
+        Some(
+            pixel_data
+                .iter_items()
+                .skip(1)
+                .map(|item| item.element().data().as_slice())
+                .collect(),
+        )
+    }
+
+    /// Locates the frames of this object's encapsulated `PixelData` among its fragments, using
+    /// the Extended Offset Table and Extended Offset Table Lengths elements if present, otherwise
+    /// the Basic Offset Table item. Returns `None` if this object does not have encapsulated
+    /// `PixelData`.
+    ///
+    /// # Errors
+    /// - `CodecError::InvalidData` if the frames cannot be determined from the offset tables or
+    ///   the fragments.
+    pub fn pixel_data_frames(&self) -> Result<Option<FrameTable>, CodecError> {
+        let Some(fragments) = self.pixel_data_fragments() else {
+            return Ok(None);
+        };
+        let bot = self
+            .get_child_by_tag(tags::PIXEL_DATA)
+            .and_then(|o| o.get_item_by_index(1))
+            .map(|item| item.element().data().as_slice())
+            .unwrap_or_default();
+        let eot = self
+            .get_child_by_tag(tags::EXTENDED_OFFSET_TABLE)
+            .zip(self.get_child_by_tag(tags::EXTENDED_OFFSET_TABLE_LENGTHS))
+            .map(|(offsets, lengths)| {
+                (
+                    offsets.element().data().as_slice(),
+                    lengths.element().data().as_slice(),
+                )
+            });
+        let num_frames = self
+            .get_value_by_tag(tags::NUMBER_OF_FRAMES)
+            .and_then(|v| v.int())
+            .and_then(|v| usize::try_from(v).ok())
+            .unwrap_or(1);
+        FrameTable::new(bot, eot, &fragments, num_frames).map(Some)
+    }
+
+    /// The compressed bytes of a single frame of this object's encapsulated `PixelData`, located
+    /// using the given table from `pixel_data_frames`. The fragment is borrowed if the frame is a
+    /// single fragment, so only the requested frame is copied, if at all. Returns `None` if the
+    /// frame does not exist.
+    ///
+    /// The fragments are gathered on each call, so use `pixel_data_frames_iter` to read every
+    /// frame.
+    #[must_use]
+    pub fn pixel_data_frame(&self, table: &FrameTable, index: usize) -> Option<Cow<'_, [u8]>> {
+        table.frame_data(index, &self.pixel_data_fragments()?)
+    }
+
+    /// The compressed bytes of each frame of this object's encapsulated `PixelData` in order,
+    /// located using the given table from `pixel_data_frames`. The fragments are gathered once for
+    /// all frames. Each item is `None` if the frame's fragments do not exist. Returns `None` if
+    /// this object does not have encapsulated `PixelData`.
+    #[must_use]
+    pub fn pixel_data_frames_iter<'a>(
+        &'a self,
+        table: &'a FrameTable,
+    ) -> Option<impl Iterator<Item = Option<Cow<'a, [u8]>>> + 'a> {
+        let fragments = self.pixel_data_fragments()?;
+        Some((0..table.len()).map(move |index| table.frame_data(index, &fragments)))
+    }
 }
 
 impl fmt::Debug for DicomObject {
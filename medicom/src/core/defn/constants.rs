@@ -27,6 +27,17 @@ pub mod tags {
 
     pub const SERIES_INSTANCE_UID: u32 = 0x0020_000E;
 
+    pub const SAMPLES_PER_PIXEL: u32 = 0x0028_0002;
+    pub const PLANAR_CONFIGURATION: u32 = 0x0028_0006;
+    pub const NUMBER_OF_FRAMES: u32 = 0x0028_0008;
+    pub const ROWS: u32 = 0x0028_0010;
+    pub const COLUMNS: u32 = 0x0028_0011;
+    pub const BITS_ALLOCATED: u32 = 0x0028_0100;
+    pub const BITS_STORED: u32 = 0x0028_0101;
+    pub const PIXEL_REPRESENTATION: u32 = 0x0028_0103;
+
+    pub const EXTENDED_OFFSET_TABLE: u32 = 0x7FE0_0001;
+    pub const EXTENDED_OFFSET_TABLE_LENGTHS: u32 = 0x7FE0_0002;
     pub const FLOAT_PIXEL_DATA: u32 = 0x7FE0_0008;
     pub const DOUBLE_PIXEL_DATA: u32 = 0x7FE0_0009;
     pub const PIXEL_DATA: u32 = 0x7FE0_0010;
@@ -88,6 +99,131 @@ pub mod ts {
         true,
         false,
     );
+
+    /// RLE Lossless
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.5
+    pub static RLELossless: TransferSyntax =
+        TransferSyntax::new(&super::uids::RLELossless, true, false, false, true);
+
+    /// JPEG Lossless, Non-Hierarchical (Process 14)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.57
+    pub static JPEGLosslessNonHierarchicalProcess14: TransferSyntax = TransferSyntax::new(
+        &super::uids::JPEGLosslessNonHierarchicalProcess14,
+        true,
+        false,
+        false,
+        true,
+    );
+
+    /// JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14 [Selection Value 1])
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.70
+    pub static JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1:
+        TransferSyntax = TransferSyntax::new(
+        &super::uids::JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1,
+        true,
+        false,
+        false,
+        true,
+    );
+
+    /// JPEG Baseline (Process 1): Default Transfer Syntax for Lossy JPEG 8 Bit Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.50
+    pub static JPEGBaselineProcess1: TransferSyntax =
+        TransferSyntax::new(&super::uids::JPEGBaselineProcess1, true, false, false, true);
+
+    /// JPEG Extended (Process 2&4): Default Transfer Syntax for Lossy JPEG 12 Bit Image Compression (Process 4 only)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.51
+    pub static JPEGExtendedProcess2_and_4: TransferSyntax = TransferSyntax::new(
+        &super::uids::JPEGExtendedProcess2_and_4,
+        true,
+        false,
+        false,
+        true,
+    );
+
+    /// JPEG-LS Lossless Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.80
+    pub static JPEGLSLosslessImageCompression: TransferSyntax = TransferSyntax::new(
+        &super::uids::JPEGLSLosslessImageCompression,
+        true,
+        false,
+        false,
+        true,
+    );
+
+    /// JPEG-LS Lossy (Near-Lossless) Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.81
+    pub static JPEGLSLossyNearLosslessImageCompression: TransferSyntax = TransferSyntax::new(
+        &super::uids::JPEGLSLossyNearLosslessImageCompression,
+        true,
+        false,
+        false,
+        true,
+    );
+
+    /// JPEG 2000 Image Compression (Lossless Only)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.90
+    pub static JPEG2000ImageCompressionLosslessOnly: TransferSyntax = TransferSyntax::new(
+        &super::uids::JPEG2000ImageCompressionLosslessOnly,
+        true,
+        false,
+        false,
+        true,
+    );
+
+    /// JPEG 2000 Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.91
+    pub static JPEG2000ImageCompression: TransferSyntax = TransferSyntax::new(
+        &super::uids::JPEG2000ImageCompression,
+        true,
+        false,
+        false,
+        true,
+    );
+
+    /// High-Throughput JPEG 2000 Image Compression (Lossless Only)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.201
+    pub static HighThroughputJPEG2000ImageCompressionLosslessOnly: TransferSyntax =
+        TransferSyntax::new(
+            &super::uids::HighThroughputJPEG2000ImageCompressionLosslessOnly,
+            true,
+            false,
+            false,
+            true,
+        );
+
+    /// High-Throughput JPEG 2000 with RPCL Options Image Compression (Lossless Only)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.202
+    pub static HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly: TransferSyntax =
+        TransferSyntax::new(
+            &super::uids::HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly,
+            true,
+            false,
+            false,
+            true,
+        );
+
+    /// High-Throughput JPEG 2000 Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.203
+    pub static HighThroughputJPEG2000ImageCompression: TransferSyntax = TransferSyntax::new(
+        &super::uids::HighThroughputJPEG2000ImageCompression,
+        true,
+        false,
+        false,
+        true,
+    );
 }
 
 /// The minimal set of UIDs needed when parsing a DICOM dataset.
@@ -145,6 +281,123 @@ pub mod uids {
         "",
         "Implicit VR Big Endian (Virtual)",
     );
+
+    /// RLE Lossless
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.5
+    /// - **UID Type:** Transfer Syntax
+    pub static RLELossless: UID = UID::new("RLELossless", "1.2.840.10008.1.2.5", "RLE Lossless");
+
+    /// JPEG Lossless, Non-Hierarchical (Process 14)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.57
+    /// - **UID Type:** Transfer Syntax
+    pub static JPEGLosslessNonHierarchicalProcess14: UID = UID::new(
+        "JPEGLosslessNonHierarchicalProcess14",
+        "1.2.840.10008.1.2.4.57",
+        "JPEG Lossless, Non-Hierarchical (Process 14)",
+    );
+
+    /// JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14 [Selection Value 1])
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.70
+    /// - **UID Type:** Transfer Syntax
+    pub static JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1: UID =
+        UID::new(
+            "JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1",
+            "1.2.840.10008.1.2.4.70",
+            "JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14 [Selection Value 1])",
+        );
+
+    /// JPEG Baseline (Process 1): Default Transfer Syntax for Lossy JPEG 8 Bit Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.50
+    /// - **UID Type:** Transfer Syntax
+    pub static JPEGBaselineProcess1: UID = UID::new(
+        "JPEGBaselineProcess1",
+        "1.2.840.10008.1.2.4.50",
+        "JPEG Baseline (Process 1): Default Transfer Syntax for Lossy JPEG 8 Bit Image Compression",
+    );
+
+    /// JPEG Extended (Process 2&4): Default Transfer Syntax for Lossy JPEG 12 Bit Image Compression (Process 4 only)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.51
+    /// - **UID Type:** Transfer Syntax
+    pub static JPEGExtendedProcess2_and_4: UID = UID::new(
+        "JPEGExtendedProcess2_and_4",
+        "1.2.840.10008.1.2.4.51",
+        "JPEG Extended (Process 2&4): Default Transfer Syntax for Lossy JPEG 12 Bit Image Compression (Process 4 only)",
+    );
+
+    /// JPEG-LS Lossless Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.80
+    /// - **UID Type:** Transfer Syntax
+    pub static JPEGLSLosslessImageCompression: UID = UID::new(
+        "JPEGLSLosslessImageCompression",
+        "1.2.840.10008.1.2.4.80",
+        "JPEG-LS Lossless Image Compression",
+    );
+
+    /// JPEG-LS Lossy (Near-Lossless) Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.81
+    /// - **UID Type:** Transfer Syntax
+    pub static JPEGLSLossyNearLosslessImageCompression: UID = UID::new(
+        "JPEGLSLossyNearLosslessImageCompression",
+        "1.2.840.10008.1.2.4.81",
+        "JPEG-LS Lossy (Near-Lossless) Image Compression",
+    );
+
+    /// JPEG 2000 Image Compression (Lossless Only)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.90
+    /// - **UID Type:** Transfer Syntax
+    pub static JPEG2000ImageCompressionLosslessOnly: UID = UID::new(
+        "JPEG2000ImageCompressionLosslessOnly",
+        "1.2.840.10008.1.2.4.90",
+        "JPEG 2000 Image Compression (Lossless Only)",
+    );
+
+    /// JPEG 2000 Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.91
+    /// - **UID Type:** Transfer Syntax
+    pub static JPEG2000ImageCompression: UID = UID::new(
+        "JPEG2000ImageCompression",
+        "1.2.840.10008.1.2.4.91",
+        "JPEG 2000 Image Compression",
+    );
+
+    /// High-Throughput JPEG 2000 Image Compression (Lossless Only)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.201
+    /// - **UID Type:** Transfer Syntax
+    pub static HighThroughputJPEG2000ImageCompressionLosslessOnly: UID = UID::new(
+        "HighThroughputJPEG2000ImageCompressionLosslessOnly",
+        "1.2.840.10008.1.2.4.201",
+        "High-Throughput JPEG 2000 Image Compression (Lossless Only)",
+    );
+
+    /// High-Throughput JPEG 2000 with RPCL Options Image Compression (Lossless Only)
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.202
+    /// - **UID Type:** Transfer Syntax
+    pub static HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly: UID = UID::new(
+        "HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly",
+        "1.2.840.10008.1.2.4.202",
+        "High-Throughput JPEG 2000 with RPCL Options Image Compression (Lossless Only)",
+    );
+
+    /// High-Throughput JPEG 2000 Image Compression
+    ///
+    /// - **UID:** 1.2.840.10008.1.2.4.203
+    /// - **UID Type:** Transfer Syntax
+    pub static HighThroughputJPEG2000ImageCompression: UID = UID::new(
+        "HighThroughputJPEG2000ImageCompression",
+        "1.2.840.10008.1.2.4.203",
+        "High-Throughput JPEG 2000 Image Compression",
+    );
 }
 
 /// The transfer syntax lookup for parsing a DICOM dataset.
@@ -178,6 +431,35 @@ pub mod lookup {
                 Some(&ts::DeflatedExplicitVRLittleEndian)
             } else if uid == uids::ExplicitVRBigEndian.uid() {
                 Some(&ts::ExplicitVRBigEndian)
+            } else if uid == uids::RLELossless.uid() {
+                Some(&ts::RLELossless)
+            } else if uid == uids::JPEGLosslessNonHierarchicalProcess14.uid() {
+                Some(&ts::JPEGLosslessNonHierarchicalProcess14)
+            } else if uid
+                == uids::JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1
+                    .uid()
+            {
+                Some(&ts::JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1)
+            } else if uid == uids::JPEGBaselineProcess1.uid() {
+                Some(&ts::JPEGBaselineProcess1)
+            } else if uid == uids::JPEGExtendedProcess2_and_4.uid() {
+                Some(&ts::JPEGExtendedProcess2_and_4)
+            } else if uid == uids::JPEGLSLosslessImageCompression.uid() {
+                Some(&ts::JPEGLSLosslessImageCompression)
+            } else if uid == uids::JPEGLSLossyNearLosslessImageCompression.uid() {
+                Some(&ts::JPEGLSLossyNearLosslessImageCompression)
+            } else if uid == uids::JPEG2000ImageCompressionLosslessOnly.uid() {
+                Some(&ts::JPEG2000ImageCompressionLosslessOnly)
+            } else if uid == uids::JPEG2000ImageCompression.uid() {
+                Some(&ts::JPEG2000ImageCompression)
+            } else if uid == uids::HighThroughputJPEG2000ImageCompressionLosslessOnly.uid() {
+                Some(&ts::HighThroughputJPEG2000ImageCompressionLosslessOnly)
+            } else if uid
+                == uids::HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly.uid()
+            {
+                Some(&ts::HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly)
+            } else if uid == uids::HighThroughputJPEG2000ImageCompression.uid() {
+                Some(&ts::HighThroughputJPEG2000ImageCompression)
             } else {
                 None
             }
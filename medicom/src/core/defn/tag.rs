@@ -40,9 +40,14 @@ pub struct Tag {
     /// Identifier or name which can be used with a `DicomDictionary`.
     ident: &'static str,
 
-    /// The tag number.
+    /// The tag number. For tags in a repeating group this is the number with the repeating bits
+    /// cleared, e.g. `0x6000_3000` for Overlay Data `(60xx,3000)`.
     num: u32,
 
+    /// The bits of a tag number which identify this tag. This is `0xFFFF_FFFF` except for tags
+    /// defined in a repeating group, such as `0xFF00_FFFF` for the Overlay group `(60xx,eeee)`.
+    mask: u32,
+
     /// The default value representation which should be used to read this tag when parsing
     /// `ImplicitVR` transfer syntaxes. Some tags may support multiple possible implicit VRs,
     /// however this is not currently supported.
@@ -68,6 +73,28 @@ impl Tag {
         Self {
             ident,
             num: tag,
+            mask: u32::MAX,
+            implicit_vr,
+            vm,
+            desc,
+        }
+    }
+
+    /// Create a new tag defined in a repeating group, where the bits of the tag number not set in
+    /// `mask` may vary, such as the Curve `(50xx,eeee)` and Overlay `(60xx,eeee)` groups.
+    #[must_use]
+    pub const fn new_masked(
+        ident: &'static str,
+        tag: u32,
+        mask: u32,
+        implicit_vr: Option<VRRef>,
+        vm: VMRef,
+        desc: &'static str,
+    ) -> Self {
+        Self {
+            ident,
+            num: tag & mask,
+            mask,
             implicit_vr,
             vm,
             desc,
@@ -86,6 +113,28 @@ impl Tag {
         self.num
     }
 
+    /// Get the mask of the bits of a tag number which identify this tag.
+    #[must_use]
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    /// Whether this tag is defined in a repeating group, and so matches multiple tag numbers.
+    #[must_use]
+    pub fn is_repeating(&self) -> bool {
+        self.mask != u32::MAX
+    }
+
+    /// Whether the given tag number is an instance of this tag. For tags in a repeating group this
+    /// is any tag number in the group, e.g. Overlay Data matches both `(6000,3000)` and
+    /// `(6002,3000)`.
+    pub fn matches<T>(&self, tag: T) -> bool
+    where
+        u32: From<T>,
+    {
+        u32::from(tag) & self.mask == self.num
+    }
+
     /// Get the tag's implicit value representation, if it has one.
     #[must_use]
     pub fn implicit_vr(&self) -> Option<VRRef> {
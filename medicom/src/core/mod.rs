@@ -21,6 +21,7 @@
 #![allow(clippy::module_name_repetitions)]
 
 pub mod charset;
+pub mod codec;
 pub mod dcmelement;
 pub mod dcmobject;
 pub mod dcmsqelem;
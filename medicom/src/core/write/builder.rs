@@ -18,6 +18,7 @@ use std::io::Write;
 
 use crate::core::{
     charset::CSRef,
+    codec::registry::CodecRegistry,
     defn::{constants::ts, ts::TSRef},
     write::{
         ds::dataset::Dataset,
@@ -40,6 +41,9 @@ pub struct WriterBuilder {
     /// If a file preamble is specified then the `"DICM"` prefix will be written immediately
     /// after the file preamble is written.
     file_preamble: Option<[u8; FILE_PREAMBLE_LENGTH]>,
+
+    /// The codecs used to encode native `PixelData`. Defaults to the codecs built in to this crate.
+    codecs: Option<CodecRegistry>,
 }
 
 impl WriterBuilder {
@@ -79,6 +83,14 @@ impl WriterBuilder {
         self
     }
 
+    /// Sets the codecs to use for encoding native `PixelData` when writing with an encapsulated
+    /// transfer syntax.
+    #[must_use]
+    pub fn codecs(mut self, codecs: CodecRegistry) -> Self {
+        self.codecs = Some(codecs);
+        self
+    }
+
     /// Constructs a `Writer` from this builder.
     pub fn build<DatasetType: Write>(&self, dataset: DatasetType) -> Writer<DatasetType> {
         Writer {
@@ -88,6 +100,7 @@ impl WriterBuilder {
             ts: self.ts.unwrap_or(&ts::ExplicitVRLittleEndian),
             cs: self.cs.unwrap_or_default(),
             file_preamble: self.file_preamble,
+            codecs: self.codecs.clone().unwrap_or_default(),
         }
     }
 }
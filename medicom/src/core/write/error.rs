@@ -16,7 +16,7 @@
 
 use thiserror::Error;
 
-use crate::core::read::ParseError;
+use crate::core::{codec::CodecError, read::ParseError};
 
 #[derive(Error, Debug)]
 /// Errors that can occur during writing of a DICOM dataset.
@@ -27,6 +27,15 @@ pub enum WriteError {
     #[error("value length of undefined cannot be used with implicit VR")]
     InvalidValueLength,
 
+    #[error("failed encoding pixel data")]
+    EncodePixelDataError(#[from] CodecError),
+
+    #[error("unsupported pixel data encoding: {0}")]
+    UnsupportedPixelDataEncoding(String),
+
+    #[error("invalid pixel data: {0}")]
+    InvalidPixelData(String),
+
     /// Wrapper around `std::io::Error`.
     #[error("i/o error writing to stream")]
     IOError {
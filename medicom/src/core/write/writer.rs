@@ -14,15 +14,22 @@
    limitations under the License.
 */
 
-use std::io::Write;
+use std::{io::Write, iter::once};
 
 use crate::core::{
     charset::CSRef,
+    codec::{registry::CodecRegistry, FrameLayout},
     dcmelement::DicomElement,
     dcmobject::DicomRoot,
+    dcmsqelem::SequenceElement,
     defn::{
         constants::{
-            tags::{FILE_META_GROUP_END, FILE_META_INFORMATION_GROUP_LENGTH},
+            tags::{
+                BITS_ALLOCATED, BITS_STORED, COLUMNS, FILE_META_GROUP_END,
+                FILE_META_INFORMATION_GROUP_LENGTH, ITEM, NUMBER_OF_FRAMES, PIXEL_DATA,
+                PIXEL_REPRESENTATION, PLANAR_CONFIGURATION, ROWS, SAMPLES_PER_PIXEL,
+                SEQUENCE_DELIMITATION_ITEM, TRANSFER_SYNTAX_UID,
+            },
             ts::{ExplicitVRLittleEndian, ImplicitVRBigEndian, ImplicitVRLittleEndian},
         },
         is_parent_priv_sq, is_sq_delim,
@@ -62,6 +69,10 @@ pub struct Writer<W: Write> {
     /// If a file preamble is specified then the `"DICM"` prefix will be written immediately
     /// after the file preamble is written.
     pub(crate) file_preamble: Option<[u8; FILE_PREAMBLE_LENGTH]>,
+
+    /// The codecs used to encode native `PixelData` when writing with an encapsulated transfer
+    /// syntax.
+    pub(crate) codecs: CodecRegistry,
 }
 
 impl<W: Write> Writer<W> {
@@ -85,6 +96,11 @@ impl<W: Write> Writer<W> {
         self.cs
     }
 
+    /// Get the codecs used to encode native `PixelData`.
+    pub fn codecs(&self) -> &CodecRegistry {
+        &self.codecs
+    }
+
     /// Creates a new `DicomElement` with the given value encoded with the given VR.
     ///
     /// # Errors
@@ -107,13 +123,199 @@ impl<W: Write> Writer<W> {
     /// Flattens the given `DicomRoot` elements into a stream of `DicomElement` and writes the
     /// resulting elements into the dataset.
     ///
+    /// If the writer's transfer syntax is encapsulated and the `DicomRoot` holds native pixel data,
+    /// the pixel data will be encoded into fragments and the `TransferSyntaxUID` of the File Meta
+    /// will be updated to match the writer's transfer syntax.
+    ///
     /// # Errors
-    /// Errors may occur writing to the dataset.
+    /// Errors may occur writing to the dataset, or if the pixel data cannot be encoded with the
+    /// writer's transfer syntax, including if no codec able to encode is registered for it.
     pub fn write_dcmroot(&mut self, dcmroot: &DicomRoot) -> WriteResult<usize> {
-        let elements = dcmroot.flatten();
+        let encapsulated = self.encapsulate_pixel_data(dcmroot)?;
+        let ts_uid = if encapsulated.is_some() {
+            Some(Writer::<W>::new_fme(
+                TRANSFER_SYNTAX_UID,
+                &vr::UI,
+                RawValue::of_uid(self.ts.uid().uid()),
+            )?)
+        } else {
+            None
+        };
+
+        let mut elements = dcmroot.flatten();
+        if let Some(pd_elements) = &encapsulated {
+            if let Some(pos) = elements
+                .iter()
+                .position(|e| e.tag() == PIXEL_DATA && e.sq_path().is_empty())
+            {
+                elements.splice(pos..=pos, pd_elements.iter());
+            }
+        }
+        if let Some(ts_uid) = &ts_uid {
+            if let Some(elem) = elements.iter_mut().find(|e| e.tag() == TRANSFER_SYNTAX_UID) {
+                *elem = ts_uid;
+            }
+        }
+
         self.write_elements(elements.into_iter())
     }
 
+    /// Encodes the native `PixelData` of the given `DicomRoot` into the elements for encapsulated
+    /// pixel data, using the writer's transfer syntax: the `PixelData` element, the Basic Offset
+    /// Table item, one fragment item per frame, and the sequence delimiter.
+    ///
+    /// Returns `None` if there is no `PixelData`, if the writer's transfer syntax is not
+    /// encapsulated, or if the pixel data is already encoded in the writer's transfer syntax.
+    fn encapsulate_pixel_data(
+        &self,
+        dcmroot: &DicomRoot,
+    ) -> WriteResult<Option<Vec<DicomElement>>> {
+        if !self.ts.encapsulated() || dcmroot.ts() == self.ts {
+            return Ok(None);
+        }
+        let Some(pd_obj) = dcmroot.get_child_by_tag(PIXEL_DATA) else {
+            return Ok(None);
+        };
+        if dcmroot.ts().encapsulated() || pd_obj.element().has_fragments() {
+            return Err(WriteError::UnsupportedPixelDataEncoding(format!(
+                "{} to {}",
+                dcmroot.ts().uid().name(),
+                self.ts.uid().name()
+            )));
+        }
+        let Some(codec) = self.codecs.get(self.ts).filter(|c| c.can_encode()) else {
+            return Err(WriteError::UnsupportedPixelDataEncoding(
+                self.ts.uid().name().to_owned(),
+            ));
+        };
+
+        let get_ushort = |tag: u32, name: &str| {
+            dcmroot
+                .get_value_by_tag(tag)
+                .and_then(|v| v.ushort())
+                .ok_or_else(|| WriteError::InvalidPixelData(format!("missing {name}")))
+        };
+        let layout = FrameLayout {
+            rows: get_ushort(ROWS, "Rows")?,
+            cols: get_ushort(COLUMNS, "Columns")?,
+            samples_per_pixel: get_ushort(SAMPLES_PER_PIXEL, "SamplesperPixel")?,
+            bits_alloc: get_ushort(BITS_ALLOCATED, "BitsAllocated")?,
+            bits_stored: get_ushort(BITS_STORED, "BitsStored")?,
+            pixel_rep: get_ushort(PIXEL_REPRESENTATION, "PixelRepresentation")?,
+        };
+        let planar_config = dcmroot
+            .get_value_by_tag(PLANAR_CONFIGURATION)
+            .and_then(|v| v.ushort())
+            .unwrap_or_default();
+        let num_frames = dcmroot
+            .get_value_by_tag(NUMBER_OF_FRAMES)
+            .and_then(|v| v.int())
+            .and_then(|v| usize::try_from(v).ok())
+            .unwrap_or(1)
+            .max(1);
+
+        let frame_len = layout.frame_len();
+        let mut native = pd_obj.element().data().clone();
+        if native.len() < frame_len * num_frames {
+            return Err(WriteError::InvalidPixelData(format!(
+                "expected {} bytes for {num_frames} frames but got {}",
+                frame_len * num_frames,
+                native.len()
+            )));
+        }
+        native.truncate(frame_len * num_frames);
+        // The codecs work with little-endian samples.
+        let bytes_per_sample = layout.bytes_per_sample();
+        if dcmroot.ts().big_endian() && bytes_per_sample > 1 {
+            for sample in native.chunks_exact_mut(bytes_per_sample) {
+                sample.reverse();
+            }
+        }
+
+        let mut fragments: Vec<Vec<u8>> = Vec::with_capacity(num_frames);
+        for frame in native.chunks_exact(frame_len) {
+            let fragment = if planar_config == 1 && layout.samples_per_pixel > 1 {
+                let interleaved = Writer::<W>::interleave_planes(frame, &layout);
+                codec.encode_frame(&interleaved, &layout)?
+            } else {
+                codec.encode_frame(frame, &layout)?
+            };
+            fragments.push(fragment);
+        }
+
+        // The Basic Offset Table lists the offset of each frame's fragment relative to the first
+        // byte of the first fragment's Item tag. Each Item tag and length is 8 bytes.
+        let mut bot: Vec<u8> = Vec::with_capacity(num_frames * 4);
+        let mut offset = 0usize;
+        for fragment in &fragments {
+            let item_offset = u32::try_from(offset).map_err(|_| {
+                WriteError::InvalidPixelData("encoded pixel data is too large".to_owned())
+            })?;
+            bot.extend_from_slice(&item_offset.to_le_bytes());
+            offset += 8 + fragment.len();
+        }
+
+        let pd_sq = vec![SequenceElement::new(
+            PIXEL_DATA,
+            None,
+            &OB,
+            ValueLength::UndefinedLength,
+            self.cs,
+        )];
+        let mut elements: Vec<DicomElement> = Vec::with_capacity(fragments.len() + 3);
+        elements.push(DicomElement::new(
+            PIXEL_DATA,
+            &OB,
+            ValueLength::UndefinedLength,
+            self.ts,
+            self.cs,
+            Vec::with_capacity(0),
+            Vec::with_capacity(0),
+        ));
+        for data in once(bot).chain(fragments) {
+            let vl = u32::try_from(data.len()).map_err(|_| {
+                WriteError::InvalidPixelData("encoded frame is too large".to_owned())
+            })?;
+            elements.push(DicomElement::new(
+                ITEM,
+                &vr::UN,
+                ValueLength::Explicit(vl),
+                self.ts,
+                self.cs,
+                data,
+                pd_sq.clone(),
+            ));
+        }
+        elements.push(DicomElement::new(
+            SEQUENCE_DELIMITATION_ITEM,
+            &vr::UN,
+            ValueLength::Explicit(0),
+            self.ts,
+            self.cs,
+            Vec::with_capacity(0),
+            pd_sq,
+        ));
+
+        Ok(Some(elements))
+    }
+
+    /// Converts a frame of native pixel data encoded color-by-plane (Planar Configuration of 1)
+    /// into color-by-pixel (Planar Configuration of 0).
+    fn interleave_planes(frame: &[u8], layout: &FrameLayout) -> Vec<u8> {
+        let pixels = layout.pixel_count();
+        let samples = usize::from(layout.samples_per_pixel);
+        let bps = layout.bytes_per_sample();
+        let mut interleaved = vec![0u8; frame.len()];
+        for sample in 0..samples {
+            for pixel in 0..pixels {
+                let src = (sample * pixels + pixel) * bps;
+                let dst = (pixel * samples + sample) * bps;
+                interleaved[dst..dst + bps].copy_from_slice(&frame[src..src + bps]);
+            }
+        }
+        interleaved
+    }
+
     /// Write the iterator of `DicomElement` to the dataset. If the `WriteState` is set to any
     /// valid state for file media, this will handle appropriate encoding for file meta group.
     ///
@@ -21,6 +21,9 @@
 //! on the name, with spaces removed and all letters lower-cased, to allow for case-insensitive
 //! look-ups. The associated value is the common value for the item, such as tag number for DICOM
 //! Elements, and the stringified UID for Transfer Syntaxes and DICOM UIDs.
+//!
+//! DICOM Elements defined in repeating groups, such as Overlay Data (60xx,3000), are looked up by
+//! their tag number with one of `TAG_MASKS` applied, in `TAG_BY_MASKED_VALUE`.
 
 #![allow(clippy::unreadable_literal, clippy::doc_markdown)]
 
@@ -30,6056 +33,6162 @@ use crate::dict::{tags, transfer_syntaxes as ts, uids};
 pub static TAG_BY_IDENT: phf::Map<&'static str, TagRef> = ::phf::Map {
     key: 16287231350648472473,
     disps: &[
-        (0, 9),
-        (0, 30),
-        (0, 482),
-        (0, 86),
-        (0, 1823),
-        (0, 246),
-        (0, 444),
-        (0, 13),
-        (0, 0),
-        (0, 17),
+        (0, 112),
+        (0, 31),
+        (0, 234),
+        (0, 96),
+        (0, 41),
+        (0, 146),
+        (0, 1),
+        (0, 231),
+        (0, 155),
+        (0, 161),
+        (0, 145),
+        (0, 73),
+        (0, 64),
+        (0, 59),
+        (0, 16),
+        (0, 2),
+        (0, 618),
+        (0, 581),
+        (0, 42),
+        (0, 2),
+        (0, 15),
+        (0, 26),
+        (0, 107),
         (0, 4),
-        (0, 526),
-        (0, 274),
-        (0, 9),
-        (0, 27),
+        (0, 150),
+        (0, 0),
         (0, 10),
+        (0, 312),
+        (0, 1),
+        (0, 1),
+        (0, 76),
+        (0, 967),
+        (0, 196),
+        (0, 23),
         (0, 0),
-        (0, 74),
-        (0, 9),
-        (0, 8),
-        (0, 79),
-        (0, 717),
         (0, 0),
+        (0, 10),
+        (0, 56),
+        (0, 72),
         (0, 3),
-        (0, 63),
-        (0, 1),
-        (0, 323),
-        (0, 4),
-        (0, 97),
-        (0, 238),
-        (0, 415),
+        (0, 23),
         (0, 0),
+        (0, 158),
+        (0, 92),
+        (0, 2),
+        (0, 128),
+        (0, 10),
+        (0, 103),
+        (0, 2),
+        (0, 3),
         (0, 0),
-        (0, 35),
-        (0, 51),
-        (0, 5),
-        (0, 8),
-        (0, 61),
-        (0, 266),
-        (0, 56),
-        (0, 37),
-        (0, 25),
-        (0, 1),
-        (0, 4),
-        (0, 54),
         (0, 20),
-        (0, 5),
-        (0, 900),
-        (0, 37),
-        (0, 151),
+        (0, 31),
+        (0, 146),
+        (0, 193),
+        (0, 167),
+        (0, 256),
+        (0, 17),
+        (0, 93),
+        (0, 1),
+        (0, 395),
+        (0, 41),
+        (0, 46),
+        (0, 1),
+        (0, 861),
+        (0, 334),
+        (0, 0),
+        (0, 8),
         (0, 0),
+        (0, 2),
         (0, 11),
-        (0, 5),
-        (0, 58),
-        (0, 239),
-        (0, 451),
-        (0, 1159),
-        (0, 132),
-        (0, 981),
-        (0, 7),
-        (0, 766),
-        (0, 35),
-        (0, 964),
-        (0, 693),
+        (0, 1),
+        (0, 87),
         (0, 0),
-        (0, 44),
-        (0, 6),
+        (0, 29),
+        (0, 7),
+        (0, 41),
         (0, 0),
-        (0, 134),
-        (0, 327),
+        (0, 444),
         (0, 2),
+        (0, 13),
+        (0, 317),
+        (0, 35),
+        (0, 104),
+        (0, 34),
         (0, 25),
+        (0, 2),
+        (0, 1076),
+        (0, 0),
+        (0, 3),
+        (0, 884),
+        (0, 7),
+        (0, 7),
+        (0, 0),
+        (0, 5),
+        (0, 3),
+        (0, 200),
+        (0, 4),
+        (0, 291),
         (0, 9),
-        (0, 14),
-        (0, 6),
-        (0, 62),
-        (0, 66),
-        (0, 531),
-        (0, 35),
         (0, 0),
+        (0, 30),
+        (0, 90),
+        (0, 755),
         (0, 2),
-        (0, 690),
-        (0, 176),
-        (0, 2),
-        (0, 700),
-        (0, 12),
-        (0, 255),
-        (0, 83),
-        (0, 8),
-        (0, 139),
-        (0, 1719),
-        (0, 59),
-        (0, 2),
-        (0, 205),
         (0, 8),
-        (0, 69),
-        (0, 100),
         (0, 3),
-        (0, 27),
-        (0, 156),
-        (0, 331),
-        (0, 14),
+        (0, 21),
+        (0, 358),
         (0, 0),
-        (0, 16),
-        (0, 1988),
-        (0, 325),
-        (0, 371),
-        (0, 183),
-        (0, 546),
-        (0, 4),
+        (0, 212),
+        (0, 155),
+        (0, 49),
+        (0, 411),
         (0, 4),
+        (0, 797),
         (0, 2),
-        (0, 243),
-        (0, 160),
-        (0, 268),
-        (0, 0),
+        (0, 66),
+        (0, 690),
+        (0, 30),
+        (0, 97),
+        (0, 1),
+        (0, 353),
+        (0, 85),
+        (0, 712),
+        (0, 81),
+        (0, 152),
+        (0, 180),
+        (0, 26),
+        (0, 238),
+        (0, 89),
+        (0, 19),
+        (0, 292),
         (0, 0),
-        (0, 103),
-        (0, 348),
+        (0, 12),
+        (0, 22),
+        (0, 20),
+        (0, 88),
+        (0, 498),
+        (0, 3),
+        (0, 12),
+        (0, 7),
+        (0, 137),
+        (0, 315),
+        (0, 229),
+        (0, 4),
         (0, 2),
-        (0, 1403),
-        (0, 243),
-        (0, 15),
-        (0, 1120),
+        (0, 924),
+        (0, 162),
+        (0, 14),
+        (0, 42),
+        (0, 3),
+        (0, 1191),
+        (0, 383),
+        (0, 4),
+        (0, 30),
+        (0, 58),
+        (0, 1),
+        (0, 28),
         (0, 10),
+        (0, 23),
+        (0, 232),
+        (0, 492),
+        (0, 9),
+        (0, 13),
+        (0, 951),
         (0, 45),
+        (0, 17),
+        (0, 858),
+        (0, 256),
         (0, 1),
-        (0, 261),
-        (0, 46),
-        (0, 89),
+        (0, 11),
+        (0, 14),
+        (0, 718),
+        (0, 236),
+        (0, 349),
+        (0, 23),
+        (0, 5),
         (0, 4),
+        (0, 649),
+        (0, 88),
+        (0, 3),
         (0, 0),
-        (0, 1),
-        (0, 20),
-        (0, 189),
-        (0, 7),
-        (0, 288),
-        (0, 661),
+        (0, 24),
+        (0, 861),
+        (0, 802),
+        (0, 2),
+        (0, 56),
+        (0, 184),
+        (0, 3),
         (0, 0),
+        (0, 820),
+        (0, 139),
+        (0, 57),
+        (0, 354),
+        (0, 611),
         (0, 13),
+        (0, 26),
+        (0, 14),
+        (0, 117),
+        (0, 570),
+        (0, 9),
+        (0, 26),
+        (0, 263),
+        (0, 7),
+        (0, 12),
+        (0, 48),
+        (0, 1729),
         (0, 3),
         (0, 0),
-        (0, 0),
-        (0, 75),
-        (0, 1350),
-        (0, 1),
-        (0, 5),
-        (0, 0),
+        (0, 3),
+        (0, 472),
+        (0, 6),
         (0, 91),
-        (0, 16),
-        (0, 80),
-        (0, 2),
-        (0, 120),
-        (0, 423),
         (0, 49),
-        (0, 0),
-        (0, 0),
-        (0, 1),
-        (0, 1),
+        (0, 209),
+        (0, 382),
+        (0, 16),
+        (0, 249),
+        (0, 240),
+        (0, 15),
+        (0, 7),
+        (0, 34),
         (0, 28),
-        (0, 60),
-        (0, 2),
-        (0, 265),
-        (0, 117),
-        (0, 197),
-        (0, 2),
-        (0, 76),
-        (0, 198),
-        (0, 50),
-        (0, 146),
-        (0, 1350),
+        (0, 29),
+        (0, 537),
+        (0, 1657),
+        (0, 421),
+        (0, 194),
+        (0, 218),
+        (0, 52),
+        (0, 3),
+        (0, 21),
+        (0, 1490),
         (0, 0),
+        (0, 10),
+        (0, 13),
+        (0, 13),
+        (0, 155),
+        (0, 420),
+        (0, 2627),
+        (0, 796),
+        (0, 121),
+        (0, 552),
+        (0, 679),
+        (0, 189),
+        (0, 21),
+        (0, 1044),
+        (0, 92),
+        (0, 149),
+        (0, 11),
+        (0, 2095),
+        (0, 7),
+        (0, 564),
         (0, 1),
-        (0, 76),
-        (0, 1179),
-        (0, 239),
-        (0, 0),
-        (0, 89),
+        (0, 8),
+        (0, 1465),
         (0, 9),
-        (0, 5),
-        (0, 70),
-        (0, 50),
-        (0, 2),
-        (0, 616),
-        (0, 61),
-        (0, 213),
-        (0, 0),
-        (0, 877),
-        (0, 104),
-        (0, 50),
-        (0, 1),
-        (0, 4),
-        (0, 170),
-        (0, 293),
-        (0, 5),
-        (0, 17),
-        (0, 1859),
-        (0, 1),
+        (0, 101),
+        (0, 1458),
+        (0, 1120),
+        (0, 275),
+        (0, 176),
+        (0, 427),
         (0, 34),
-        (0, 0),
-        (0, 40),
-        (0, 340),
-        (0, 92),
-        (0, 9),
-        (0, 398),
-        (0, 17),
-        (0, 32),
-        (0, 24),
-        (0, 970),
-        (0, 1065),
-        (0, 198),
-        (0, 117),
-        (0, 859),
-        (0, 0),
-        (0, 37),
-        (0, 643),
-        (0, 245),
-        (0, 1501),
         (0, 1),
-        (0, 41),
-        (0, 342),
-        (0, 333),
-        (0, 1175),
-        (0, 137),
+        (0, 1322),
+        (0, 7),
+        (0, 112),
+        (0, 142),
+        (0, 868),
         (0, 0),
-        (0, 35),
-        (0, 162),
-        (0, 90),
-        (0, 102),
-        (0, 107),
-        (0, 36),
-        (0, 1),
-        (0, 15),
-        (0, 21),
+        (0, 16),
+        (0, 87),
         (0, 0),
-        (0, 24),
-        (0, 316),
-        (0, 960),
+        (0, 226),
+        (0, 1417),
+        (0, 167),
+        (0, 149),
+        (0, 1466),
         (0, 0),
-        (0, 89),
-        (0, 838),
+        (0, 55),
         (0, 5),
-        (0, 160),
-        (0, 145),
-        (0, 428),
-        (0, 1262),
-        (0, 121),
-        (0, 21),
-        (0, 666),
-        (0, 12),
-        (0, 89),
-        (0, 66),
-        (0, 2955),
-        (0, 62),
-        (0, 48),
-        (0, 22),
+        (0, 419),
+        (0, 1),
+        (0, 225),
+        (0, 59),
+        (0, 7),
+        (0, 605),
+        (0, 280),
+        (0, 182),
+        (0, 251),
+        (0, 51),
+        (0, 769),
+        (0, 100),
+        (0, 1159),
+        (0, 171),
+        (0, 397),
+        (0, 586),
+        (0, 9),
+        (0, 417),
+        (0, 56),
+        (0, 500),
+        (0, 1349),
+        (0, 32),
+        (0, 324),
+        (0, 177),
+        (0, 560),
         (0, 0),
-        (0, 38),
         (0, 0),
+        (0, 34),
         (0, 5),
-        (0, 345),
-        (0, 368),
-        (0, 0),
-        (0, 18),
-        (0, 0),
-        (0, 604),
+        (0, 716),
+        (0, 59),
+        (0, 15),
+        (0, 1631),
+        (0, 143),
+        (0, 147),
+        (0, 763),
+        (0, 586),
+        (0, 8),
+        (0, 16),
+        (0, 4799),
         (0, 6),
-        (0, 1),
-        (0, 57),
-        (0, 39),
-        (0, 530),
-        (0, 34),
-        (0, 374),
-        (0, 19),
-        (0, 89),
-        (0, 89),
-        (0, 577),
-        (0, 304),
-        (0, 145),
-        (0, 183),
-        (0, 1),
-        (0, 403),
-        (0, 2137),
-        (0, 193),
-        (0, 11),
-        (0, 43),
-        (0, 859),
-        (0, 9),
-        (0, 23),
-        (0, 243),
-        (0, 537),
-        (0, 62),
         (0, 4),
-        (0, 0),
-        (0, 2342),
-        (0, 760),
-        (0, 8),
-        (0, 1096),
-        (0, 516),
-        (0, 1795),
-        (0, 1192),
-        (0, 0),
-        (0, 2),
-        (0, 155),
         (0, 3),
-        (0, 463),
-        (0, 186),
-        (0, 32),
-        (0, 15),
-        (0, 3477),
-        (0, 2),
-        (0, 836),
-        (0, 44),
+        (0, 22),
         (0, 9),
-        (0, 1),
+        (0, 165),
+        (0, 79),
+        (0, 491),
+        (0, 82),
+        (0, 60),
+        (0, 1369),
+        (0, 10),
+        (0, 13),
+        (0, 712),
         (0, 0),
-        (0, 853),
-        (0, 18),
-        (0, 81),
-        (0, 1650),
-        (0, 1202),
+        (0, 267),
+        (0, 779),
+        (0, 246),
+        (0, 1569),
+        (0, 42),
+        (0, 746),
+        (0, 16),
         (0, 1),
-        (0, 2),
-        (0, 423),
-        (0, 17),
+        (0, 28),
         (0, 0),
-        (0, 782),
-        (0, 79),
-        (0, 6),
-        (0, 29),
-        (0, 27),
-        (0, 833),
-        (0, 1),
-        (0, 55),
-        (0, 14),
+        (0, 9),
+        (0, 412),
         (0, 68),
+        (0, 546),
+        (0, 54),
+        (0, 215),
+        (0, 1141),
+        (0, 3543),
+        (0, 4),
+        (0, 757),
+        (0, 6),
+        (0, 533),
         (0, 2),
-        (0, 1932),
-        (0, 2),
-        (0, 39),
-        (0, 5),
-        (0, 1478),
-        (0, 695),
-        (0, 469),
+        (0, 294),
+        (0, 479),
+        (0, 1441),
+        (0, 31),
+        (0, 1),
+        (0, 16),
+        (0, 34),
+        (0, 27),
+        (0, 117),
+        (0, 1307),
+        (0, 21),
         (0, 1),
-        (0, 1060),
+        (1, 90),
+        (0, 507),
+        (0, 6),
         (0, 0),
+        (0, 13),
+        (0, 3),
         (0, 25),
-        (0, 6),
-        (0, 2),
-        (0, 76),
-        (0, 30),
+        (0, 3),
+        (0, 722),
+        (0, 7),
+        (0, 288),
+        (0, 265),
         (0, 0),
-        (0, 677),
-        (0, 43),
-        (0, 6),
-        (0, 29),
-        (0, 374),
-        (0, 178),
-        (0, 56),
+        (0, 24),
+        (0, 66),
+        (0, 270),
+        (0, 213),
+        (0, 205),
+        (0, 9),
+        (0, 1782),
+        (0, 16),
+        (0, 122),
+        (0, 20),
+        (0, 70),
+        (0, 223),
+        (0, 2102),
+        (0, 7),
+        (0, 550),
+        (0, 77),
+        (0, 1019),
+        (0, 477),
+        (0, 2),
         (0, 3),
-        (0, 5),
-        (0, 65),
-        (0, 247),
+        (0, 73),
+        (0, 2),
         (0, 1),
-        (0, 3132),
-        (0, 344),
-        (0, 128),
-        (0, 11),
-        (0, 51),
         (0, 0),
+        (0, 3250),
         (0, 5),
-        (0, 1148),
-        (0, 811),
-        (0, 6),
-        (0, 1013),
         (0, 2),
-        (0, 0),
-        (0, 146),
-        (0, 56),
-        (0, 21),
-        (0, 9),
-        (0, 259),
-        (0, 5),
-        (0, 399),
-        (0, 69),
-        (0, 591),
-        (0, 190),
-        (0, 22),
-        (0, 156),
-        (0, 1044),
+        (0, 27),
+        (0, 335),
+        (0, 14),
+        (0, 7),
+        (0, 0),
+        (0, 663),
+        (0, 1803),
+        (0, 217),
+        (0, 187),
+        (0, 1783),
+        (0, 269),
+        (0, 0),
+        (0, 1810),
+        (1, 1471),
+        (0, 771),
+        (0, 4235),
+        (0, 11),
+        (0, 1589),
         (0, 2),
-        (0, 35),
-        (0, 2154),
-        (0, 367),
-        (0, 10),
-        (0, 16),
-        (0, 372),
-        (0, 66),
-        (0, 85),
-        (0, 10),
-        (0, 1550),
-        (0, 392),
-        (0, 215),
-        (0, 24),
-        (0, 979),
-        (0, 422),
-        (0, 21),
-        (0, 312),
-        (0, 545),
-        (0, 1068),
-        (0, 306),
-        (0, 2127),
-        (0, 3633),
-        (0, 1949),
-        (0, 593),
+        (0, 2345),
+        (0, 821),
+        (0, 11),
+        (0, 170),
+        (0, 213),
+        (0, 159),
         (0, 1),
-        (0, 26),
-        (0, 18),
-        (0, 1612),
-        (0, 1165),
-        (0, 126),
-        (0, 32),
-        (0, 28),
-        (0, 52),
-        (0, 491),
-        (0, 66),
-        (0, 595),
+        (0, 13),
+        (1, 204),
+        (0, 36),
+        (0, 1),
+        (0, 14),
+        (0, 329),
+        (0, 3183),
+        (0, 41),
+        (0, 34),
+        (0, 463),
+        (0, 4),
+        (1, 1403),
+        (0, 13),
+        (0, 41),
+        (0, 264),
+        (0, 8),
+        (0, 4),
+        (0, 1221),
+        (0, 241),
+        (0, 119),
+        (0, 37),
+        (0, 51),
+        (0, 200),
+        (0, 528),
+        (0, 1),
+        (0, 1255),
+        (0, 762),
         (0, 9),
+        (0, 763),
+        (0, 966),
+        (0, 341),
+        (0, 64),
+        (0, 365),
         (0, 5),
-        (0, 35),
-        (0, 10),
-        (0, 1723),
-        (0, 3189),
-        (0, 115),
-        (0, 15),
-        (0, 9),
-        (0, 6),
-        (0, 50),
-        (0, 1068),
-        (0, 1022),
-        (0, 719),
-        (0, 121),
-        (0, 22),
-        (0, 43),
-        (0, 9),
-        (0, 3),
-        (0, 23),
-        (0, 332),
+        (0, 4218),
+        (0, 331),
+        (0, 4),
+        (0, 456),
+        (0, 228),
+        (0, 49),
+        (0, 143),
+        (1, 1071),
+        (0, 296),
+        (1, 725),
+        (0, 905),
         (0, 0),
-        (0, 1938),
-        (0, 344),
         (0, 0),
-        (0, 29),
-        (0, 25),
+        (0, 3321),
+        (0, 2932),
+        (0, 433),
+        (0, 780),
         (0, 1),
-        (0, 1213),
-        (0, 610),
-        (0, 96),
-        (0, 0),
-        (0, 94),
-        (0, 69),
-        (0, 62),
-        (0, 3598),
-        (0, 3),
+        (0, 2376),
+        (0, 11),
+        (0, 28),
+        (0, 15),
+        (0, 1602),
+        (0, 27),
+        (0, 3888),
+        (0, 124),
+        (0, 351),
+        (0, 884),
+        (0, 1727),
+        (0, 22),
+        (0, 268),
+        (0, 40),
         (0, 6),
-        (0, 2734),
-        (0, 764),
-        (0, 0),
-        (0, 380),
-        (0, 205),
-        (0, 17),
-        (0, 547),
-        (0, 108),
-        (0, 219),
-        (0, 44),
-        (0, 101),
-        (0, 0),
-        (0, 675),
-        (0, 984),
-        (0, 2006),
-        (0, 362),
-        (0, 107),
-        (0, 35),
-        (0, 408),
+        (0, 2),
+        (0, 271),
+        (0, 45),
+        (0, 237),
+        (0, 127),
+        (0, 1230),
         (0, 1),
-        (0, 107),
-        (0, 83),
-        (0, 2059),
-        (0, 292),
-        (0, 22),
+        (0, 0),
+        (0, 18),
+        (0, 26),
         (0, 1),
-        (0, 21),
-        (0, 736),
-        (0, 3),
-        (0, 7),
-        (0, 5),
-        (0, 88),
-        (0, 311),
-        (0, 11),
+        (0, 1763),
+        (0, 1028),
+        (0, 1071),
+        (0, 341),
+        (0, 52),
+        (0, 4),
         (0, 1),
-        (0, 5),
-        (0, 1158),
-        (0, 2556),
-        (0, 1036),
-        (0, 5),
-        (0, 72),
+        (0, 44),
+        (0, 18),
+        (0, 399),
+        (0, 57),
         (0, 11),
-        (0, 39),
-        (0, 12),
-        (0, 2634),
-        (0, 1743),
-        (0, 32),
-        (0, 19),
-        (0, 1),
-        (0, 695),
-        (0, 3),
+        (0, 199),
+        (0, 331),
+        (0, 17),
         (0, 7),
-        (0, 278),
-        (0, 570),
-        (0, 11),
-        (0, 713),
-        (0, 687),
-        (0, 12),
+        (0, 146),
         (0, 3),
-        (0, 7),
-        (0, 9),
-        (0, 131),
-        (0, 74),
-        (0, 24),
-        (0, 648),
-        (0, 6),
-        (0, 13),
-        (0, 396),
-        (0, 283),
-        (0, 723),
-        (0, 0),
-        (0, 34),
-        (0, 4),
-        (0, 1310),
         (0, 0),
-        (0, 152),
-        (0, 1169),
-        (0, 218),
-        (0, 982),
-        (0, 132),
+        (0, 339),
+        (0, 238),
+        (0, 43),
+        (0, 8),
+        (0, 4080),
+        (0, 2),
         (0, 0),
-        (0, 2627),
-        (0, 123),
-        (0, 13),
-        (0, 48),
-        (0, 61),
-        (0, 151),
-        (0, 242),
-        (0, 535),
-        (0, 182),
-        (0, 41),
-        (0, 172),
-        (0, 97),
-        (0, 243),
+        (0, 561),
         (0, 1),
+        (0, 295),
+        (0, 74),
         (0, 1),
-        (0, 23),
-        (0, 119),
-        (0, 98),
+        (0, 185),
+        (0, 5),
         (0, 1),
-        (0, 2823),
-        (0, 575),
+        (0, 108),
+        (0, 3),
         (0, 4),
-        (1, 1999),
-        (0, 93),
-        (0, 24),
-        (0, 145),
+        (0, 7),
+        (0, 458),
+        (0, 722),
+        (0, 267),
+        (0, 66),
+        (0, 36),
+        (0, 287),
+        (0, 129),
+        (0, 287),
+        (0, 277),
+        (0, 254),
+        (0, 641),
+        (0, 812),
+        (0, 349),
+        (0, 21),
+        (0, 48),
+        (0, 7),
+        (0, 2171),
         (0, 3),
-        (0, 59),
-        (0, 46),
-        (0, 178),
-        (0, 2),
-        (0, 517),
-        (0, 38),
+        (0, 48),
+        (0, 15),
+        (0, 1983),
+        (0, 37),
+        (0, 4070),
+        (0, 1014),
+        (0, 0),
+        (0, 135),
+        (0, 7),
+        (0, 91),
         (0, 127),
-        (0, 31),
+        (0, 4),
         (0, 2),
-        (0, 330),
-        (0, 18),
-        (0, 2087),
-        (0, 3566),
-        (0, 169),
-        (0, 160),
-        (0, 267),
-        (0, 4885),
-        (0, 3),
-        (0, 555),
-        (0, 13),
-        (0, 353),
-        (0, 388),
+        (0, 16),
         (0, 0),
         (0, 0),
-        (0, 291),
-        (0, 3),
-        (0, 3833),
-        (0, 402),
-        (0, 42),
-        (0, 177),
-        (0, 891),
-        (0, 600),
-        (0, 596),
-        (0, 28),
-        (0, 654),
-        (0, 776),
-        (0, 354),
+        (0, 1),
+        (0, 0),
+        (0, 334),
+        (0, 21),
+        (0, 875),
+        (0, 1679),
         (0, 4),
-        (0, 180),
-        (0, 59),
-        (0, 665),
-        (0, 125),
-        (0, 94),
-        (0, 121),
+        (0, 33),
+        (0, 73),
+        (1, 673),
         (0, 0),
-        (0, 55),
-        (0, 4077),
-        (0, 686),
-        (0, 71),
-        (0, 5),
-        (0, 197),
-        (0, 170),
-        (0, 183),
-        (0, 512),
-        (0, 558),
-        (0, 16),
-        (0, 8),
-        (0, 300),
-        (0, 25),
-        (0, 661),
-        (0, 30),
-        (0, 0),
-        (0, 412),
-        (0, 526),
-        (0, 544),
         (0, 5),
-        (0, 9),
-        (0, 238),
-        (0, 22),
+        (0, 372),
         (0, 1),
-        (0, 997),
-        (0, 3080),
-        (0, 42),
-        (0, 2464),
         (0, 0),
         (0, 0),
-        (0, 1079),
-        (0, 962),
+        (0, 400),
+        (0, 92),
+        (0, 392),
         (0, 7),
+        (0, 934),
+        (0, 49),
+        (0, 20),
+        (1, 460),
+        (0, 0),
+        (0, 28),
+        (0, 402),
+        (0, 0),
+        (0, 347),
         (0, 1),
-        (0, 242),
-        (0, 11),
-        (0, 352),
-        (0, 2272),
+        (0, 651),
+        (0, 2),
+        (0, 100),
+        (0, 130),
+        (0, 813),
+        (0, 6),
+        (0, 393),
+        (0, 474),
+        (0, 662),
+        (0, 2),
+        (0, 344),
+        (0, 124),
+        (0, 49),
+        (0, 7),
+        (0, 1),
+        (0, 10),
+        (0, 114),
+        (0, 422),
+        (0, 576),
+        (0, 4),
+        (0, 1078),
+        (0, 8),
+        (0, 1925),
+        (0, 31),
+        (0, 25),
+        (0, 84),
+        (1, 4819),
+        (0, 54),
+        (0, 20),
+        (0, 0),
+        (0, 232),
+        (0, 204),
+        (0, 1686),
+        (0, 12),
+        (0, 5),
+        (0, 29),
+        (0, 698),
+        (0, 19),
+        (0, 291),
+        (0, 102),
+        (0, 198),
+        (0, 23),
+        (0, 20),
         (0, 1),
+        (0, 10),
+        (0, 926),
+        (1, 959),
+        (0, 72),
+        (0, 298),
+        (0, 1081),
+        (0, 677),
+        (0, 160),
+        (0, 0),
+        (0, 1905),
         (0, 16),
+        (0, 3974),
+        (0, 92),
+        (0, 638),
+        (1, 205),
+        (0, 59),
+        (0, 122),
+        (0, 0),
+        (1, 366),
+        (0, 14),
+        (0, 1597),
+        (0, 3),
+        (0, 5),
+        (0, 10),
+        (0, 10),
+        (0, 3),
+        (0, 102),
+        (0, 3304),
+        (0, 46),
+        (0, 32),
+        (0, 1943),
+        (0, 25),
+        (0, 1979),
+        (0, 865),
+        (0, 294),
+        (0, 44),
+        (0, 181),
+        (0, 310),
+        (0, 2761),
         (0, 48),
-        (0, 780),
-        (0, 607),
-        (0, 47),
-        (0, 189),
-        (0, 135),
-        (0, 6),
+        (0, 36),
+        (0, 76),
+        (0, 7),
+        (0, 523),
         (0, 6),
-        (0, 113),
-        (0, 732),
-        (0, 598),
-        (0, 272),
-        (0, 2),
-        (0, 4715),
-        (0, 50),
-        (0, 625),
-        (0, 16),
-        (0, 2241),
-        (0, 2002),
-        (0, 1811),
-        (0, 657),
-        (0, 2),
+        (0, 1911),
+        (0, 1096),
+        (0, 345),
         (0, 4),
-        (0, 148),
-        (0, 13),
-        (0, 254),
-        (0, 2009),
-        (0, 4150),
-        (0, 3541),
-        (0, 58),
-        (0, 89),
+        (0, 1511),
         (0, 16),
-        (0, 3784),
-        (0, 24),
-        (0, 994),
-        (0, 19),
-        (0, 652),
+        (0, 1921),
         (0, 2),
-        (0, 2306),
-        (0, 1323),
-        (0, 89),
+        (0, 56),
         (0, 0),
+        (0, 220),
+        (0, 552),
+        (0, 59),
+        (0, 384),
         (0, 194),
-        (0, 15),
-        (0, 0),
-        (0, 40),
-        (0, 667),
-        (0, 2658),
-        (0, 292),
-        (0, 11),
-        (0, 236),
-        (1, 362),
-        (0, 773),
-        (0, 1),
-        (0, 0),
-        (0, 76),
-        (0, 545),
-        (0, 652),
-        (0, 1189),
-        (0, 27),
-        (0, 937),
-        (0, 101),
-        (0, 2393),
-        (0, 66),
-        (0, 2),
-        (0, 48),
-        (0, 30),
-        (0, 4869),
-        (0, 123),
-        (0, 83),
+        (0, 3978),
+        (0, 126),
         (0, 0),
-        (1, 2),
-        (0, 2),
-        (0, 88),
-        (0, 2183),
-        (0, 3685),
-        (0, 63),
-        (0, 373),
+        (1, 3633),
+        (0, 1828),
         (0, 0),
-        (0, 18),
-        (0, 510),
-        (2, 4047),
         (0, 62),
-        (0, 338),
-        (0, 20),
-        (0, 26),
         (0, 2),
-        (0, 304),
-        (0, 346),
-        (0, 2945),
-        (0, 24),
-        (0, 685),
-        (0, 4),
-        (0, 47),
-        (0, 31),
-        (2, 483),
-        (0, 5000),
-        (0, 105),
+        (0, 524),
+        (0, 33),
+        (0, 663),
+        (0, 352),
+        (1, 553),
+        (0, 126),
+        (0, 561),
+        (0, 1249),
+        (0, 77),
         (0, 0),
-        (0, 240),
-        (0, 14),
-        (0, 66),
-        (0, 6),
-        (0, 2030),
-        (0, 16),
-        (0, 190),
+        (0, 1395),
+        (0, 2096),
+        (0, 450),
+        (0, 54),
+        (0, 2403),
+        (0, 22),
+        (0, 1495),
+        (0, 4236),
+        (0, 35),
+        (0, 112),
         (0, 0),
-        (0, 43),
-        (0, 174),
-        (1, 4),
-        (1, 140),
-        (0, 10),
-        (0, 20),
-        (3, 2072),
+        (0, 4753),
+        (0, 159),
+        (0, 56),
+        (0, 1361),
+        (0, 1651),
+        (0, 3477),
+        (0, 1054),
+        (0, 280),
+        (0, 3),
+        (0, 9),
+        (0, 34),
+        (0, 181),
+        (0, 278),
+        (0, 2880),
+        (0, 2731),
+        (0, 24),
+        (0, 2138),
+        (0, 90),
+        (0, 898),
         (0, 13),
-        (0, 823),
-        (0, 60),
-        (0, 2412),
-        (0, 1947),
+        (0, 2160),
+        (0, 71),
+        (0, 793),
+        (0, 73),
+        (0, 2000),
+        (0, 12),
+        (0, 140),
+        (0, 32),
+        (0, 106),
+        (0, 2509),
+        (0, 1885),
         (0, 0),
-        (1, 1420),
-        (0, 1235),
-        (0, 3991),
-        (0, 1033),
-        (0, 162),
-        (0, 369),
+        (0, 156),
+        (0, 4578),
+        (0, 2515),
+        (0, 71),
+        (0, 142),
+        (0, 212),
+        (0, 2140),
         (0, 52),
-        (0, 1),
-        (0, 2944),
-        (0, 2447),
-        (0, 1),
-        (0, 217),
-        (0, 62),
-        (0, 1948),
-        (0, 2956),
-        (0, 1102),
-        (0, 383),
-        (0, 2405),
-        (0, 82),
-        (0, 83),
+        (0, 337),
+        (0, 70),
+        (0, 532),
+        (0, 6),
+        (0, 339),
+        (0, 15),
+        (0, 28),
+        (0, 2142),
+        (0, 927),
+        (0, 640),
+        (0, 20),
+        (0, 45),
+        (1, 656),
         (0, 148),
-        (0, 460),
-        (1, 45),
-        (0, 3),
-        (0, 1208),
-        (0, 71),
-        (0, 1433),
-        (0, 2),
-        (0, 4),
-        (0, 97),
-        (0, 414),
+        (0, 8),
+        (0, 737),
+        (0, 1294),
+        (0, 184),
+        (0, 184),
+        (2, 4378),
+        (0, 184),
+        (0, 357),
+        (2, 2360),
+        (0, 34),
+        (0, 196),
+        (0, 1713),
+        (0, 3513),
+        (0, 249),
+        (0, 1021),
+        (0, 52),
+        (0, 5),
         (0, 20),
-        (0, 1558),
-        (0, 48),
-        (0, 1364),
-        (0, 489),
-        (0, 2930),
-        (1, 936),
-        (0, 470),
-        (0, 2851),
-        (0, 345),
-        (0, 1109),
-        (0, 824),
-        (0, 337),
-        (0, 3448),
-        (0, 192),
-        (0, 14),
-        (0, 19),
-        (0, 4957),
-        (0, 1446),
+        (0, 1325),
+        (0, 95),
         (0, 1),
-        (0, 4),
-        (0, 2191),
         (0, 12),
+        (0, 714),
+        (0, 200),
+        (0, 407),
+        (0, 1515),
         (0, 0),
-        (0, 3770),
-        (0, 12),
-        (0, 2972),
-        (0, 3),
-        (0, 10),
-        (0, 542),
-        (0, 71),
-        (0, 586),
-        (0, 2479),
-        (0, 336),
-        (0, 4518),
-        (0, 264),
+        (0, 8),
+        (0, 204),
+        (0, 1304),
+        (1, 2578),
+        (0, 188),
+        (0, 6),
+        (0, 3563),
+        (0, 101),
+        (0, 2222),
         (0, 0),
-        (1, 2011),
-        (0, 25),
+        (0, 49),
+        (0, 3088),
+        (1, 2401),
+        (0, 0),
+        (0, 654),
+        (0, 24),
+        (0, 199),
+        (1, 428),
+        (0, 0),
+        (0, 4358),
+        (0, 1231),
+        (0, 33),
+        (0, 457),
+        (0, 3940),
+        (2, 3853),
+        (0, 0),
+        (1, 973),
+        (0, 38),
+        (0, 14),
+        (0, 53),
+        (3, 3285),
+        (0, 439),
+        (0, 713),
+        (0, 803),
+        (0, 250),
+        (0, 723),
+        (0, 2373),
+        (0, 112),
         (0, 5),
-        (0, 2329),
-        (0, 2),
-        (1, 4595),
-        (0, 937),
-        (0, 7),
         (0, 30),
-        (0, 142),
-        (0, 11),
-        (0, 886),
-        (0, 123),
-        (0, 20),
-        (1, 2441),
-        (0, 11),
-        (0, 2049),
-        (0, 1228),
-        (0, 62),
+        (0, 135),
         (0, 17),
-        (0, 119),
-        (0, 609),
-        (0, 2458),
-        (0, 3191),
-        (0, 8),
-        (0, 8),
-        (0, 400),
-        (0, 755),
-        (4, 3989),
-        (2, 382),
-        (0, 280),
-        (0, 716),
-        (0, 27),
-        (0, 25),
-        (0, 18),
+        (0, 79),
+        (1, 2181),
         (0, 18),
-        (0, 521),
-        (0, 5),
-        (0, 307),
-        (0, 89),
-        (0, 488),
-        (0, 2),
+        (0, 694),
+        (1, 2585),
+        (0, 407),
         (0, 0),
-        (0, 3013),
-        (0, 2353),
-        (0, 190),
-        (0, 317),
-        (0, 28),
-        (2, 2334),
-        (0, 12),
-        (0, 52),
-        (0, 6),
-        (2, 3857),
-        (0, 593),
-        (0, 7),
-        (2, 4034),
-        (1, 1844),
-        (0, 90),
-        (0, 157),
-        (0, 867),
-        (0, 118),
-        (0, 3076),
-        (0, 6),
-        (0, 1300),
-        (0, 7),
-        (1, 4049),
-        (0, 1720),
-        (0, 221),
-        (3, 2113),
-        (0, 328),
+        (0, 1704),
         (0, 1),
-        (0, 3),
-        (0, 465),
-        (0, 106),
-        (0, 537),
-        (0, 786),
-        (0, 8),
-        (0, 560),
+        (0, 27),
+        (1, 919),
+        (0, 451),
+        (0, 0),
+        (0, 1),
+        (0, 325),
         (0, 8),
-        (2, 102),
-        (0, 740),
+        (0, 2),
+        (3, 3220),
+        (0, 162),
+        (0, 391),
+        (0, 267),
         (0, 30),
-        (0, 236),
-        (0, 8),
-        (0, 328),
-        (0, 86),
+        (0, 5043),
+        (0, 0),
+        (0, 3802),
+        (0, 269),
+        (0, 278),
+        (0, 147),
+        (0, 384),
+        (0, 1980),
+        (0, 1428),
+        (0, 5),
         (0, 1),
-        (0, 12),
-        (1, 502),
-        (0, 14),
+        (2, 419),
+        (0, 246),
+        (0, 226),
+        (0, 3634),
+        (1, 1078),
+        (0, 85),
+        (1, 4058),
+        (0, 0),
+        (0, 1704),
+        (0, 658),
+        (0, 874),
+        (0, 131),
+        (0, 333),
         (0, 41),
-        (0, 685),
-        (0, 277),
-        (0, 83),
-        (0, 4614),
-        (0, 2406),
-        (0, 4775),
-        (6, 4028),
-        (0, 668),
+        (0, 3226),
+        (2, 3030),
+        (0, 542),
+        (0, 755),
+        (0, 8),
+        (0, 1348),
+        (0, 1658),
+        (0, 79),
+        (0, 22),
+        (0, 1209),
+        (2, 1173),
+        (0, 2),
+        (0, 2),
+        (0, 146),
+        (0, 958),
+        (1, 4570),
+        (0, 931),
+        (0, 348),
+        (0, 0),
+        (0, 2626),
+        (0, 1024),
+        (0, 3426),
+        (0, 2471),
+        (0, 3824),
+        (0, 3426),
+        (0, 74),
+        (0, 3702),
+        (0, 71),
+        (0, 93),
+        (0, 3503),
+        (0, 4),
+        (0, 30),
+        (0, 3639),
+        (0, 458),
+        (0, 2983),
+        (0, 127),
+        (0, 42),
+        (0, 344),
+        (0, 4184),
         (0, 16),
-        (0, 29),
-        (2, 2018),
-        (0, 628),
-        (1, 1602),
-        (0, 353),
         (0, 1),
-        (1, 1948),
-        (0, 2872),
-        (0, 261),
-        (0, 54),
-        (0, 125),
-        (0, 35),
-        (0, 1631),
-        (4, 1675),
-        (0, 3),
-        (1, 22),
-        (0, 14),
-        (0, 339),
-        (0, 514),
-        (0, 690),
+        (0, 42),
+        (0, 421),
+        (0, 11),
+        (0, 47),
+        (2, 1797),
+        (0, 4982),
+        (0, 4899),
+        (0, 39),
+        (0, 1066),
         (0, 0),
-        (0, 107),
-        (0, 245),
-        (0, 4519),
-        (0, 18),
-        (0, 60),
+        (0, 719),
+        (0, 63),
+        (0, 1),
+        (0, 37),
+        (0, 257),
+        (0, 4640),
+        (0, 396),
+        (0, 247),
+        (0, 2149),
+        (0, 24),
+        (0, 887),
+        (0, 8),
+        (3, 4701),
+        (0, 337),
         (0, 2),
+        (0, 311),
         (0, 0),
-        (0, 3928),
-        (2, 3091),
-        (0, 670),
-        (0, 2997),
-        (0, 2864),
-        (1, 1219),
-        (0, 2573),
-        (1, 4089),
-        (0, 718),
-        (0, 26),
-        (2, 894),
-        (1, 743),
-        (0, 8),
-        (1, 2210),
-        (2, 1456),
-        (0, 616),
-        (4, 880),
-        (5, 646),
-        (0, 717),
-        (0, 980),
-        (0, 57),
-        (1, 1639),
-        (0, 158),
-        (0, 2861),
-        (0, 1),
-        (0, 1038),
-        (2, 2386),
-        (0, 2362),
-        (0, 323),
-        (0, 2140),
-        (0, 1236),
-        (2, 1604),
+        (0, 1026),
+        (0, 2169),
+        (2, 209),
+        (0, 0),
+        (0, 1713),
+        (0, 108),
+        (0, 1146),
+        (0, 110),
+        (0, 3281),
+        (0, 760),
+        (0, 1077),
+        (0, 4602),
+        (0, 830),
+        (0, 29),
+        (0, 780),
+        (0, 4062),
+        (0, 61),
+        (0, 19),
+        (1, 3455),
+        (0, 379),
+        (0, 4682),
+        (1, 374),
+        (1, 1239),
+        (0, 23),
+        (4, 2222),
+        (0, 0),
+        (0, 1246),
+        (0, 2005),
+        (0, 2),
+        (1, 3877),
+        (0, 2172),
+        (0, 1298),
+        (0, 2310),
+        (0, 812),
+        (0, 2661),
+        (0, 237),
     ],
     entries: &[
-        ("conceptualvolumeidentificationsequence", &tags::ConceptualVolumeIdentificationSequence),
-        ("slicesensitivityfactor", &tags::SliceSensitivityFactor),
-        ("tomolayerheight", &tags::TomoLayerHeight),
-        ("volumelocalizationtechnique", &tags::VolumeLocalizationTechnique),
-        ("radialposition", &tags::RadialPosition),
-        ("xraygridsequence", &tags::XRayGridSequence),
-        ("shadowoffsetx", &tags::ShadowOffsetX),
-        ("patientreliabilityindicator", &tags::PatientReliabilityIndicator),
-        ("tolerancetablelabel", &tags::ToleranceTableLabel),
-        ("radiationdosevaluesparameterssequence", &tags::RadiationDoseValuesParametersSequence),
-        ("shotdurationtime", &tags::ShotDurationTime),
-        ("ophthalmicthicknessmaptypecodesequence", &tags::OphthalmicThicknessMapTypeCodeSequence),
-        ("component2referencedmatingfeatureid", &tags::Component2ReferencedMatingFeatureID),
-        ("decayfactor", &tags::DecayFactor),
-        ("referencedoverlayplanesequence", &tags::ReferencedOverlayPlaneSequence),
-        ("storageprotocolelementsequence", &tags::StorageProtocolElementSequence),
-        ("obliquecroppingplanesequence", &tags::ObliqueCroppingPlaneSequence),
-        ("illumination", &tags::Illumination),
-        ("selectorattributeprivatecreator", &tags::SelectorAttributePrivateCreator),
-        ("commentsonpatientsperformanceofvisualfield", &tags::CommentsonPatientsPerformanceofVisualField),
-        ("modifiedimagetime", &tags::ModifiedImageTime),
-        ("otherpatientnames", &tags::OtherPatientNames),
-        ("dvhmaximumdose", &tags::DVHMaximumDose),
-        ("reconstructionangle", &tags::ReconstructionAngle),
-        ("numberofboli", &tags::NumberofBoli),
-        ("radiationchargestate", &tags::RadiationChargeState),
-        ("mappingresource", &tags::MappingResource),
-        ("bitsstored", &tags::BitsStored),
-        ("sourceencapsulationnominalthickness", &tags::SourceEncapsulationNominalThickness),
-        ("secondarypositionerincrementsign", &tags::SecondaryPositionerIncrementSign),
-        ("excitationfrequency", &tags::ExcitationFrequency),
-        ("treatmentsitecodesequence", &tags::TreatmentSiteCodeSequence),
-        ("stimuluspresentationtime", &tags::StimulusPresentationTime),
-        ("applicationmaximumrepainttime", &tags::ApplicationMaximumRepaintTime),
-        ("angularstep", &tags::AngularStep),
-        ("zerovelocitypixelvalue", &tags::ZeroVelocityPixelValue),
-        ("dvhreferencedroisequence", &tags::DVHReferencedROISequence),
-        ("anglenumber", &tags::AngleNumber),
-        ("conceptualvolumesequence", &tags::ConceptualVolumeSequence),
-        ("patientbreeddescription", &tags::PatientBreedDescription),
-        ("radiationdosecentralaxisdisplacement", &tags::RadiationDoseCentralAxisDisplacement),
-        ("nonconformingmodifiedattributessequence", &tags::NonconformingModifiedAttributesSequence),
-        ("routesegmentid", &tags::RouteSegmentID),
-        ("showticklabel", &tags::ShowTickLabel),
-        ("referenceddosesequence", &tags::ReferencedDoseSequence),
-        ("phasedescription", &tags::PhaseDescription),
-        ("displayedareaselectionsequence", &tags::DisplayedAreaSelectionSequence),
-        ("targetminimumdose", &tags::TargetMinimumDose),
-        ("interpolationtype", &tags::InterpolationType),
-        ("functionalsettlingphaseframespresent", &tags::FunctionalSettlingPhaseFramesPresent),
-        ("lowrrvalue", &tags::LowRRValue),
-        ("calculatedframelist", &tags::CalculatedFrameList),
-        ("numberofwaveformchannels", &tags::NumberofWaveformChannels),
-        ("dvhnumberofbins", &tags::DVHNumberofBins),
-        ("targetlabel", &tags::TargetLabel),
-        ("metersetrateset", &tags::MetersetRateSet),
-        ("graylookuptabledata", &tags::GrayLookupTableData),
-        ("procedureexpirationdate", &tags::ProcedureExpirationDate),
-        ("selectorltvalue", &tags::SelectorLTValue),
-        ("tabletoprollangle", &tags::TableTopRollAngle),
-        ("predeformationmatrixregistrationsequence", &tags::PreDeformationMatrixRegistrationSequence),
-        ("imagedvolumedepth", &tags::ImagedVolumeDepth),
-        ("maxaperturevalue", &tags::MaxApertureValue),
-        ("maskoperationexplanation", &tags::MaskOperationExplanation),
-        ("productparametersequence", &tags::ProductParameterSequence),
-        ("codingschemeurltype", &tags::CodingSchemeURLType),
-        ("scheduledstepattributessequence", &tags::ScheduledStepAttributesSequence),
-        ("deviceindex", &tags::DeviceIndex),
-        ("curvedate", &tags::CurveDate),
-        ("interlocksequence", &tags::InterlockSequence),
-        ("filterthicknessusedingaincalibration", &tags::FilterThicknessUsedinGainCalibration),
-        ("studycompletiondate", &tags::StudyCompletionDate),
-        ("rangemodulatordescription", &tags::RangeModulatorDescription),
-        ("referencedsopinstanceuid", &tags::ReferencedSOPInstanceUID),
-        ("flashenergy", &tags::FlashEnergy),
-        ("beamdosepointsourcetoexternalcontourdistance", &tags::BeamDosePointSourcetoExternalContourDistance),
-        ("referencedtimeoffsets", &tags::ReferencedTimeOffsets),
-        ("datacollectioncenterpatient", &tags::DataCollectionCenterPatient),
-        ("verticalprismbase", &tags::VerticalPrismBase),
-        ("implanttemplategroupname", &tags::ImplantTemplateGroupName),
-        ("listofmimetypes", &tags::ListofMIMETypes),
-        ("dopplersamplevolumeyposition_retired", &tags::DopplerSampleVolumeYPosition_Retired),
-        ("xrayfiltersequence", &tags::XRayFilterSequence),
-        ("visualfieldshape", &tags::VisualFieldShape),
-        ("detectortimesincelastexposure", &tags::DetectorTimeSinceLastExposure),
-        ("sourceapplicationentitytitle", &tags::SourceApplicationEntityTitle),
-        ("contrastbolusingredientopaque", &tags::ContrastBolusIngredientOpaque),
-        ("hpgldocumentsequence", &tags::HPGLDocumentSequence),
-        ("timeofgaincalibration", &tags::TimeofGainCalibration),
-        ("printqueueid", &tags::PrintQueueID),
-        ("retestsensitivityvalue", &tags::RetestSensitivityValue),
-        ("flashfiringstatus", &tags::FlashFiringStatus),
-        ("distancesourcetodetector", &tags::DistanceSourcetoDetector),
-        ("displaysethorizontaljustification", &tags::DisplaySetHorizontalJustification),
-        ("slabthickness", &tags::SlabThickness),
-        ("coordinatesystemaxisunits", &tags::CoordinateSystemAxisUnits),
-        ("configurationid", &tags::ConfigurationID),
-        ("doublefloatpixeldata", &tags::DoubleFloatPixelData),
-        ("localizeddeviationprobabilitysequence", &tags::LocalizedDeviationProbabilitySequence),
-        ("anatomicstructure", &tags::AnatomicStructure),
-        ("identifiertypecode", &tags::IdentifierTypeCode),
-        ("mediastoragesopclassuid", &tags::MediaStorageSOPClassUID),
-        ("recordedchannelsequence", &tags::RecordedChannelSequence),
-        ("interlockorigindescription", &tags::InterlockOriginDescription),
-        ("matingfeaturedegreeoffreedomsequence", &tags::MatingFeatureDegreeofFreedomSequence),
-        ("deviationindex", &tags::DeviationIndex),
-        ("rtacquisitionpatientpositionsequence", &tags::RTAcquisitionPatientPositionSequence),
-        ("gpsprocessingmethod", &tags::GPSProcessingMethod),
-        ("hl7instanceidentifier", &tags::HL7InstanceIdentifier),
-        ("segmentsurfacegenerationalgorithmidentificationsequence", &tags::SegmentSurfaceGenerationAlgorithmIdentificationSequence),
-        ("deidentificationactionsequence", &tags::DeidentificationActionSequence),
-        ("brachytreatmenttechnique", &tags::BrachyTreatmentTechnique),
+        ("annotationappliestoallzplanes", &tags::AnnotationAppliestoAllZPlanes),
+        ("framenumbersofinterestfoi", &tags::FrameNumbersofInterestFOI),
+        ("totalpixelmatrixrows", &tags::TotalPixelMatrixRows),
+        ("compensatorposition", &tags::CompensatorPosition),
         ("numberofframesusedforintegration", &tags::NumberofFramesUsedforIntegration),
-        ("linacoutput", &tags::LINACOutput),
-        ("sortbycategory", &tags::SortbyCategory),
-        ("typeofinstances", &tags::TypeofInstances),
-        ("referencedbeamsequence", &tags::ReferencedBeamSequence),
-        ("tmlinepositiony1_retired", &tags::TMLinePositionY1_Retired),
-        ("pulsersettingssequence", &tags::PulserSettingsSequence),
-        ("channellabel", &tags::ChannelLabel),
-        ("printerstatus", &tags::PrinterStatus),
-        ("referenceddeviceindex", &tags::ReferencedDeviceIndex),
-        ("bodypartexamined", &tags::BodyPartExamined),
-        ("iolformulacodesequence", &tags::IOLFormulaCodeSequence),
-        ("optotype", &tags::Optotype),
-        ("containercomponentlength", &tags::ContainerComponentLength),
-        ("instackpositionnumber", &tags::InStackPositionNumber),
-        ("operatingmode", &tags::OperatingMode),
-        ("sourceimagesequence", &tags::SourceImageSequence),
-        ("usedsegmentssequence", &tags::UsedSegmentsSequence),
-        ("modulationtype", &tags::ModulationType),
+        ("sequenceofcompresseddata", &tags::SequenceofCompressedData),
+        ("contrastflowrate", &tags::ContrastFlowRate),
+        ("attenuationcorrectionsource", &tags::AttenuationCorrectionSource),
+        ("graphicannotationunits", &tags::GraphicAnnotationUnits),
+        ("rtreferencedstudysequence", &tags::RTReferencedStudySequence),
+        ("reformattinginterval", &tags::ReformattingInterval),
+        ("componenttypessequence", &tags::ComponentTypesSequence),
+        ("screeningbaselinetype", &tags::ScreeningBaselineType),
         ("cumulativetimeweight", &tags::CumulativeTimeWeight),
-        ("presentationpixelaspectratio", &tags::PresentationPixelAspectRatio),
-        ("dactimepoints", &tags::DACTimePoints),
-        ("highbit", &tags::HighBit),
-        ("ctadditionalxraysourcesequence", &tags::CTAdditionalXRaySourceSequence),
-        ("numberofradiationgenerationmodes", &tags::NumberofRadiationGenerationModes),
-        ("radiationgenerationmodeindex", &tags::RadiationGenerationModeIndex),
-        ("resonantnucleus", &tags::ResonantNucleus),
-        ("transformnumberofaxes", &tags::TransformNumberofAxes),
-        ("lengthtoend", &tags::LengthtoEnd),
-        ("referencednonimagecompositesopinstancesequence", &tags::ReferencedNonImageCompositeSOPInstanceSequence),
-        ("multiplecopiesflag", &tags::MultipleCopiesFlag),
-        ("channelsampleskew", &tags::ChannelSampleSkew),
-        ("radiopharmaceuticalinformationsequence", &tags::RadiopharmaceuticalInformationSequence),
-        ("collationflag", &tags::CollationFlag),
-        ("interpretationid", &tags::InterpretationID),
-        ("setuptechniquedescription", &tags::SetupTechniqueDescription),
-        ("radiationgenerationmodesequence", &tags::RadiationGenerationModeSequence),
-        ("component1referencedmatingfeaturesetid", &tags::Component1ReferencedMatingFeatureSetID),
-        ("rangemodulatorid", &tags::RangeModulatorID),
-        ("rtbeammodifierdefinitiondistance", &tags::RTBeamModifierDefinitionDistance),
-        ("qualitycontrolimage", &tags::QualityControlImage),
-        ("testpatterncodesequence", &tags::TestPatternCodeSequence),
-        ("overlaysmoothingtype", &tags::OverlaySmoothingType),
-        ("timedistributionprotocol", &tags::TimeDistributionProtocol),
-        ("issuerofpatientidqualifierssequence", &tags::IssuerofPatientIDQualifiersSequence),
-        ("serviceepisodedescription", &tags::ServiceEpisodeDescription),
-        ("phototimersetting", &tags::PhototimerSetting),
-        ("defaultsmoothingtype", &tags::DefaultSmoothingType),
-        ("grayscale", &tags::GrayScale),
-        ("referencedimagerealworldvaluemappingsequence", &tags::ReferencedImageRealWorldValueMappingSequence),
-        ("slideidentifier", &tags::SlideIdentifier),
-        ("numberofbeams", &tags::NumberofBeams),
-        ("channelidentificationcode", &tags::ChannelIdentificationCode),
-        ("sourceofanteriorchamberdepthdatacodesequence", &tags::SourceofAnteriorChamberDepthDataCodeSequence),
-        ("volumetricproperties", &tags::VolumetricProperties),
-        ("acquisitionmethodalgorithmsequence", &tags::AcquisitionMethodAlgorithmSequence),
-        ("detectoractivationoffsetfromexposure", &tags::DetectorActivationOffsetFromExposure),
-        ("preamplifiersettingssequence", &tags::PreAmplifierSettingsSequence),
-        ("tmlinepositiony0_retired", &tags::TMLinePositionY0_Retired),
-        ("analyzedarea", &tags::AnalyzedArea),
-        ("ophthalmicaxiallengthmeasurementstotallengthsequence", &tags::OphthalmicAxialLengthMeasurementsTotalLengthSequence),
-        ("randomscorrectionmethod", &tags::RandomsCorrectionMethod),
-        ("energywindowcenterline", &tags::EnergyWindowCenterline),
-        ("extendedmatchingmechanisms", &tags::ExtendedMatchingMechanisms),
-        ("doseratedelivered", &tags::DoseRateDelivered),
-        ("interpretationapprovaldate", &tags::InterpretationApprovalDate),
-        ("recordedsnoutsequence", &tags::RecordedSnoutSequence),
-        ("offsetofthelastdirectoryrecordoftherootdirectoryentity", &tags::OffsetoftheLastDirectoryRecordoftheRootDirectoryEntity),
-        ("imagedatatypecodesequence", &tags::ImageDataTypeCodeSequence),
-        ("distancereceptorplanetodetectorhousing", &tags::DistanceReceptorPlanetoDetectorHousing),
-        ("implanttemplategroupmemberssequence", &tags::ImplantTemplateGroupMembersSequence),
-        ("overlaymode", &tags::OverlayMode),
-        ("filmsessionlabel", &tags::FilmSessionLabel),
-        ("attributeoccurrenceprivatecreator", &tags::AttributeOccurrencePrivateCreator),
-        ("displayfunctiontype", &tags::DisplayFunctionType),
-        ("sensorname", &tags::SensorName),
-        ("compensatorcolumns", &tags::CompensatorColumns),
-        ("connectortype", &tags::ConnectorType),
+        ("stereobaselinedisplacement", &tags::StereoBaselineDisplacement),
+        ("referencedimageboxsequence_retired", &tags::ReferencedImageBoxSequence_Retired),
+        ("switchingphasetransitionduration", &tags::SwitchingPhaseTransitionDuration),
+        ("fractiongroupdescription", &tags::FractionGroupDescription),
+        ("parallelacquisitiontechnique", &tags::ParallelAcquisitionTechnique),
+        ("recommendedlinethickness", &tags::RecommendedLineThickness),
+        ("geometryofkspacetraversal", &tags::GeometryofkSpaceTraversal),
+        ("fractionnumber", &tags::FractionNumber),
+        ("windowcenter_and_widthexplanation", &tags::WindowCenter_and_WidthExplanation),
+        ("mainroofangle", &tags::MainRoofAngle),
+        ("synchronizedimageboxlist", &tags::SynchronizedImageBoxList),
+        ("annotationgroupsequence", &tags::AnnotationGroupSequence),
+        ("sourcestrengthreferencedate", &tags::SourceStrengthReferenceDate),
+        ("intraocularpressure", &tags::IntraOcularPressure),
+        ("distancepupillarydistance", &tags::DistancePupillaryDistance),
+        ("histogramsequence", &tags::HistogramSequence),
+        ("numberofleafjawpairs", &tags::NumberofLeafJawPairs),
+        ("threatroiextents", &tags::ThreatROIExtents),
+        ("surfacepointsnormalssequence", &tags::SurfacePointsNormalsSequence),
+        ("referencegeometrycodesequence", &tags::ReferenceGeometryCodeSequence),
+        ("centerofrotationoffset", &tags::CenterofRotationOffset),
+        ("interpretationauthor", &tags::InterpretationAuthor),
+        ("targetprescriptiondose", &tags::TargetPrescriptionDose),
+        ("radiationgenerationmodedescription", &tags::RadiationGenerationModeDescription),
         ("uppercutofffrequency", &tags::UpperCutoffFrequency),
-        ("anatomicregionsequence", &tags::AnatomicRegionSequence),
-        ("softcopyvoilutsequence", &tags::SoftcopyVOILUTSequence),
-        ("distanceobjecttotabletop", &tags::DistanceObjecttoTableTop),
-        ("attributetolerancevaluessequence", &tags::AttributeToleranceValuesSequence),
-        ("gpslatitude", &tags::GPSLatitude),
-        ("archiverequested", &tags::ArchiveRequested),
-        ("tidoffset", &tags::TIDOffset),
-        ("triggertime", &tags::TriggerTime),
-        ("imageandfluoroscopyareadoseproduct", &tags::ImageandFluoroscopyAreaDoseProduct),
-        ("selectordsvalue", &tags::SelectorDSValue),
-        ("shadowopacity", &tags::ShadowOpacity),
-        ("deadtimecorrected", &tags::DeadTimeCorrected),
-        ("firsttreatmentdate", &tags::FirstTreatmentDate),
-        ("selectorisvalue", &tags::SelectorISValue),
-        ("visualfieldtestreliabilityglobalindexsequence", &tags::VisualFieldTestReliabilityGlobalIndexSequence),
-        ("componentreferencesystem", &tags::ComponentReferenceSystem),
-        ("ddlvalue", &tags::DDLValue),
-        ("dateoflastcalibration", &tags::DateofLastCalibration),
-        ("mappingresourcename", &tags::MappingResourceName),
-        ("globaldeviationprobabilitynormalsflag", &tags::GlobalDeviationProbabilityNormalsFlag),
-        ("aslslabsequence", &tags::ASLSlabSequence),
-        ("proposedstudysequence", &tags::ProposedStudySequence),
-        ("referencepixelx0", &tags::ReferencePixelX0),
-        ("tablespeed", &tags::TableSpeed),
-        ("devicesequence", &tags::DeviceSequence),
-        ("acquisitionsubtasksequence", &tags::AcquisitionSubtaskSequence),
-        ("rtprotocolcodesequence", &tags::RTProtocolCodeSequence),
-        ("radiationorderindex", &tags::RadiationOrderIndex),
-        ("cornealtopographymaptypecodesequence", &tags::CornealTopographyMapTypeCodeSequence),
-        ("sopclassesinstudy", &tags::SOPClassesinStudy),
-        ("imagefilterdetailssequence", &tags::ImageFilterDetailsSequence),
-        ("component1referencedmatingfeatureid", &tags::Component1ReferencedMatingFeatureID),
-        ("delivereddistaldepthfraction", &tags::DeliveredDistalDepthFraction),
-        ("falsepositivesquantity", &tags::FalsePositivesQuantity),
-        ("parallelrtbeamdelimiterpositions", &tags::ParallelRTBeamDelimiterPositions),
-        ("radionuclidecodesequence", &tags::RadionuclideCodeSequence),
-        ("numberofsurfaces", &tags::NumberofSurfaces),
-        ("segmentannotationtypemodifiercodesequence", &tags::SegmentAnnotationTypeModifierCodeSequence),
-        ("referencedbrachyapplicationsetupnumber", &tags::ReferencedBrachyApplicationSetupNumber),
-        ("pointsboundingboxcoordinates", &tags::PointsBoundingBoxCoordinates),
-        ("requestedimagesize", &tags::RequestedImageSize),
-        ("ionwedgesequence", &tags::IonWedgeSequence),
-        ("modifiedattributessequence", &tags::ModifiedAttributesSequence),
-        ("typeofpatientid", &tags::TypeofPatientID),
-        ("channellength", &tags::ChannelLength),
-        ("collimatorlowerhorizontaledge", &tags::CollimatorLowerHorizontalEdge),
-        ("isospeed", &tags::ISOSpeed),
-        ("referencedrttreatmentpreparationsequence", &tags::ReferencedRTTreatmentPreparationSequence),
-        ("dctlabel", &tags::DCTLabel),
-        ("numberofcompensators", &tags::NumberofCompensators),
-        ("ophthalmicpatientclinicalinformationlefteyesequence", &tags::OphthalmicPatientClinicalInformationLeftEyeSequence),
-        ("shieldingdevicetype", &tags::ShieldingDeviceType),
-        ("surfacemodelscalingfactor", &tags::SurfaceModelScalingFactor),
-        ("overlaypixeldatasequence", &tags::OverlayPixelDataSequence),
-        ("constrainttype", &tags::ConstraintType),
-        ("decompositionmaterialsequence", &tags::DecompositionMaterialSequence),
+        ("imagetype", &tags::ImageType),
+        ("flowidentifier", &tags::FlowIdentifier),
+        ("cornealtopographysurface", &tags::CornealTopographySurface),
+        ("filmdestination", &tags::FilmDestination),
+        ("performedprocedurestepstartdatetime", &tags::PerformedProcedureStepStartDateTime),
+        ("potentialreasonsforprocedurecodesequence", &tags::PotentialReasonsforProcedureCodeSequence),
+        ("contextidentifier", &tags::ContextIdentifier),
+        ("referencedwedgenumber", &tags::ReferencedWedgeNumber),
+        ("xraymassattenuationcoefficient", &tags::XRayMassAttenuationCoefficient),
+        ("acquisitionmethodcodesequence", &tags::AcquisitionMethodCodeSequence),
+        ("totalaperture", &tags::TotalAperture),
+        ("beamdosepointdepth", &tags::BeamDosePointDepth),
+        ("printerstatusinfo", &tags::PrinterStatusInfo),
+        ("leftimagesequence", &tags::LeftImageSequence),
+        ("sortingdirection", &tags::SortingDirection),
+        ("patientsupportpositionparametersequence", &tags::PatientSupportPositionParameterSequence),
+        ("opticalmagnificationfactor", &tags::OpticalMagnificationFactor),
+        ("rotationpoint", &tags::RotationPoint),
+        ("referencepixelphysicalvaluex", &tags::ReferencePixelPhysicalValueX),
+        ("opticaltransmittance", &tags::OpticalTransmittance),
+        ("referencedpatientsetupprocedureindex", &tags::ReferencedPatientSetupProcedureIndex),
+        ("delineatedradiationfieldsize", &tags::DelineatedRadiationFieldSize),
+        ("velocityencodingacquisitionsequence", &tags::VelocityEncodingAcquisitionSequence),
+        ("objectbinaryidentifier_trial", &tags::ObjectBinaryIdentifier_Trial),
+        ("implanttemplategroupvariationdimensionname", &tags::ImplantTemplateGroupVariationDimensionName),
+        ("implanttypecodesequence", &tags::ImplantTypeCodeSequence),
+        ("overlaysgreen", &tags::OverlaysGreen),
+        ("iolmanufacturer", &tags::IOLManufacturer),
+        ("pixeldata", &tags::PixelData),
+        ("croppingspecificationnumber", &tags::CroppingSpecificationNumber),
+        ("mausedingaincalibration", &tags::MAUsedinGainCalibration),
+        ("chairheadframepositiontolerance", &tags::ChairHeadFramePositionTolerance),
+        ("pupildilated", &tags::PupilDilated),
+        ("overlaymagnificationtype", &tags::OverlayMagnificationType),
+        ("waveformoriginality", &tags::WaveformOriginality),
+        ("recommendeddefaultvaluesequence", &tags::RecommendedDefaultValueSequence),
+        ("requestingae", &tags::RequestingAE),
+        ("blockslabnumber", &tags::BlockSlabNumber),
+        ("channelthreshold", &tags::ChannelThreshold),
+        ("referencedverificationimagesequence", &tags::ReferencedVerificationImageSequence),
+        ("hl7documenttypecodesequence", &tags::HL7DocumentTypeCodeSequence),
+        ("referencedbaselineparametersrtradiationinstancesequence", &tags::ReferencedBaselineParametersRTRadiationInstanceSequence),
+        ("beamname", &tags::BeamName),
+        ("snoutsequence", &tags::SnoutSequence),
+        ("qualitycontrolsubject", &tags::QualityControlSubject),
+        ("variablecoefficientssdhn", &tags::VariableCoefficientsSDHN),
+        ("stereobaselineangle", &tags::StereoBaselineAngle),
+        ("impressions", &tags::Impressions),
         ("tabletopeccentricangletolerance", &tags::TableTopEccentricAngleTolerance),
-        ("sourcestrengthreferencetime", &tags::SourceStrengthReferenceTime),
-        ("multienergyctxraydetectorsequence", &tags::MultienergyCTXRayDetectorSequence),
-        ("nominalcardiactriggertimepriortorpeak", &tags::NominalCardiacTriggerTimePriorToRPeak),
-        ("decimatecropresult", &tags::DecimateCropResult),
-        ("imagednucleus", &tags::ImagedNucleus),
-        ("uid", &tags::UID),
-        ("compensatordefinitionsequence", &tags::CompensatorDefinitionSequence),
-        ("inventoriedinstancessequence", &tags::InventoriedInstancesSequence),
-        ("detectorzpositiontoisocenter", &tags::DetectorZPositiontoIsocenter),
-        ("beamlimitingdevicesequence", &tags::BeamLimitingDeviceSequence),
-        ("brachyaccessorydeviceid", &tags::BrachyAccessoryDeviceID),
-        ("intraocularpressure", &tags::IntraOcularPressure),
-        ("rtimageframeimagingdevicepositionsequence", &tags::RTImageFrameImagingDevicePositionSequence),
-        ("xcoordinatescenterpixelviewangle", &tags::XCoordinatesCenterPixelViewAngle),
-        ("receivetransducersettingssequence", &tags::ReceiveTransducerSettingsSequence),
-        ("focusmethod", &tags::FocusMethod),
-        ("imagegeometrytype", &tags::ImageGeometryType),
-        ("assessedattributevaluesequence", &tags::AssessedAttributeValueSequence),
-        ("studytime", &tags::StudyTime),
-        ("abortreason", &tags::AbortReason),
-        ("dicosversion", &tags::DICOSVersion),
-        ("tag_2ddegreeoffreedomsequence", &tags::Tag_2DDegreeofFreedomSequence),
-        ("interlockcodesequence", &tags::InterlockCodeSequence),
-        ("xraydetectorindex", &tags::XRayDetectorIndex),
-        ("registrationmethodcodesequence", &tags::RegistrationMethodCodeSequence),
-        ("privateinformation", &tags::PrivateInformation),
-        ("scheduleddischargetime", &tags::ScheduledDischargeTime),
-        ("graphiclayerrecommendeddisplaygrayscalevalue", &tags::GraphicLayerRecommendedDisplayGrayscaleValue),
-        ("selectorslvalue", &tags::SelectorSLValue),
+        ("floatpixelpaddingvalue", &tags::FloatPixelPaddingValue),
+        ("numberofrangeshifters", &tags::NumberofRangeShifters),
+        ("referencedfractionnumber", &tags::ReferencedFractionNumber),
+        ("objectivelenspower", &tags::ObjectiveLensPower),
+        ("derivationimagesequence", &tags::DerivationImageSequence),
+        ("polarity", &tags::Polarity),
+        ("implantsize", &tags::ImplantSize),
+        ("volumetriccurvepoints", &tags::VolumetricCurvePoints),
+        ("imagingaperturespecificationtype", &tags::ImagingApertureSpecificationType),
+        ("numberofradiationgenerationmodes", &tags::NumberofRadiationGenerationModes),
+        ("tmlinepositionx1_retired", &tags::TMLinePositionX1_Retired),
+        ("vieworientationmodifiercodesequence", &tags::ViewOrientationModifierCodeSequence),
+        ("implanttemplategroupvariationdimensionranksequence", &tags::ImplantTemplateGroupVariationDimensionRankSequence),
+        ("patientpositioninginstructionsequence", &tags::PatientPositioningInstructionSequence),
+        ("frameacquisitionduration", &tags::FrameAcquisitionDuration),
+        ("filterlookuptabledescription", &tags::FilterLookupTableDescription),
+        ("templatetype", &tags::TemplateType),
+        ("specifiedchanneltotaltime", &tags::SpecifiedChannelTotalTime),
+        ("multienergyacquisitiondescription", &tags::MultienergyAcquisitionDescription),
+        ("contenttemplatesequence", &tags::ContentTemplateSequence),
+        ("showticklabel", &tags::ShowTickLabel),
+        ("procedurestepcommunicationsurisequence", &tags::ProcedureStepCommunicationsURISequence),
+        ("rtvflowactualframeduration", &tags::RTVFlowActualFrameDuration),
+        ("hl7documenteffectivetime", &tags::HL7DocumentEffectiveTime),
+        ("datapathassignment", &tags::DataPathAssignment),
         ("patientbreedcodesequence", &tags::PatientBreedCodeSequence),
-        ("wedgeroofangle", &tags::WedgeRoofAngle),
-        ("acquisitioninitiationsequence", &tags::AcquisitionInitiationSequence),
-        ("imagetransformationmatrix", &tags::ImageTransformationMatrix),
-        ("clinicaltrialtimepointtypecodesequence", &tags::ClinicalTrialTimePointTypeCodeSequence),
-        ("displayedareatoplefthandcorner_trial", &tags::DisplayedAreaTopLeftHandCorner_Trial),
-        ("repeatfractioncyclelength", &tags::RepeatFractionCycleLength),
-        ("visualacuitybotheyesopensequence", &tags::VisualAcuityBothEyesOpenSequence),
-        ("wedgethinedgeposition", &tags::WedgeThinEdgePosition),
-        ("exposuresondetectorsincemanufactured", &tags::ExposuresonDetectorSinceManufactured),
-        ("secondarycapturedeviceid", &tags::SecondaryCaptureDeviceID),
-        ("generalpurposescheduledprocedurestepstatus", &tags::GeneralPurposeScheduledProcedureStepStatus),
-        ("radiopharmaceuticalagentnumber", &tags::RadiopharmaceuticalAgentNumber),
-        ("observationnumber", &tags::ObservationNumber),
-        ("gridid", &tags::GridID),
-        ("referencedrtradiationrecordsequence", &tags::ReferencedRTRadiationRecordSequence),
-        ("seriesdescriptioncodesequence", &tags::SeriesDescriptionCodeSequence),
-        ("midslabposition", &tags::MidSlabPosition),
-        ("indexprobability", &tags::IndexProbability),
-        ("memoryallocation", &tags::MemoryAllocation),
-        ("positionmeasuringdeviceused", &tags::PositionMeasuringDeviceUsed),
-        ("estimateddosesaving", &tags::EstimatedDoseSaving),
-        ("bscancycletimevector", &tags::BscanCycleTimeVector),
-        ("conceptualvolumeconstituentsequence", &tags::ConceptualVolumeConstituentSequence),
-        ("parallelacquisition", &tags::ParallelAcquisition),
-        ("channelsourcesequence", &tags::ChannelSourceSequence),
-        ("patientsupportpositionparameterorderindex", &tags::PatientSupportPositionParameterOrderIndex),
-        ("rtrelatedroisequence", &tags::RTRelatedROISequence),
-        ("transportclassification", &tags::TransportClassification),
-        ("displayedareabottomrighthandcorner", &tags::DisplayedAreaBottomRightHandCorner),
-        ("energyderivationcodesequence", &tags::EnergyDerivationCodeSequence),
-        ("gridabsorbingmaterial", &tags::GridAbsorbingMaterial),
-        ("completionflagdescription", &tags::CompletionFlagDescription),
-        ("imageoverlayboxcontentsequence", &tags::ImageOverlayBoxContentSequence),
-        ("skipframerangeflag", &tags::SkipFrameRangeFlag),
-        ("imagedvolumewidth", &tags::ImagedVolumeWidth),
-        ("selectorodvalue", &tags::SelectorODValue),
-        ("imagecenterpointcoordinatessequence", &tags::ImageCenterPointCoordinatesSequence),
-        ("scanstoppositionsequence", &tags::ScanStopPositionSequence),
-        ("conceptualvolumederivationalgorithmsequence", &tags::ConceptualVolumeDerivationAlgorithmSequence),
-        ("excludedintervalssequence", &tags::ExcludedIntervalsSequence),
-        ("acousticcouplingmediumtemperature", &tags::AcousticCouplingMediumTemperature),
-        ("largestimagepixelvalue", &tags::LargestImagePixelValue),
-        ("isocentertowedgetraydistance", &tags::IsocentertoWedgeTrayDistance),
-        ("imagefilter", &tags::ImageFilter),
-        ("threatsequence", &tags::ThreatSequence),
-        ("transmittransducersettingssequence", &tags::TransmitTransducerSettingsSequence),
-        ("contourdata", &tags::ContourData),
-        ("dateoflastdetectorcalibration", &tags::DateofLastDetectorCalibration),
-        ("dopplersamplevolumexposition_retired", &tags::DopplerSampleVolumeXPosition_Retired),
-        ("brachyaccessorydevicenominaltransmission", &tags::BrachyAccessoryDeviceNominalTransmission),
-        ("humidity", &tags::Humidity),
-        ("referencedfilmboxsequence", &tags::ReferencedFilmBoxSequence),
-        ("indicationphysicalpropertysequence", &tags::IndicationPhysicalPropertySequence),
-        ("totalblocktrayfactor", &tags::TotalBlockTrayFactor),
-        ("wedgefrontheight", &tags::WedgeFrontHeight),
-        ("additionalinspectionmethodsequence", &tags::AdditionalInspectionMethodSequence),
-        ("producttypecodesequence", &tags::ProductTypeCodeSequence),
-        ("histogramdata", &tags::HistogramData),
-        ("commentsontheperformedprocedurestep", &tags::CommentsonthePerformedProcedureStep),
-        ("pyramiddescription", &tags::PyramidDescription),
-        ("rtvflowidentifier", &tags::RTVFlowIdentifier),
-        ("temporalrelationshipintervalanchor", &tags::TemporalRelationshipIntervalAnchor),
-        ("setuptechnique", &tags::SetupTechnique),
-        ("otherpatientids", &tags::OtherPatientIDs),
-        ("cadfileformat", &tags::CADFileFormat),
-        ("beamholdtransition", &tags::BeamHoldTransition),
-        ("shutterleftverticaledge", &tags::ShutterLeftVerticalEdge),
-        ("segmentationtype", &tags::SegmentationType),
-        ("specimensequence", &tags::SpecimenSequence),
+        ("secondarycountsaccumulated", &tags::SecondaryCountsAccumulated),
+        ("phaseinformationsequence", &tags::PhaseInformationSequence),
+        ("reviewdate", &tags::ReviewDate),
+        ("procedurestepdiscontinuationreasoncodesequence", &tags::ProcedureStepDiscontinuationReasonCodeSequence),
+        ("bottomrighthandcorneroflocalizerarea", &tags::BottomRightHandCornerofLocalizerArea),
+        ("imagedimensions", &tags::ImageDimensions),
+        ("acquisitionstatus", &tags::AcquisitionStatus),
+        ("taganglefirstaxis", &tags::TagAngleFirstAxis),
+        ("referencedsetupimagesequence", &tags::ReferencedSetupImageSequence),
+        ("compensatornumber", &tags::CompensatorNumber),
+        ("transducerdata", &tags::TransducerData),
+        ("specularreflectionintensity", &tags::SpecularReflectionIntensity),
+        ("numberoffractionpatterndigitsperday", &tags::NumberofFractionPatternDigitsPerDay),
+        ("beammodifierorientationangle", &tags::BeamModifierOrientationAngle),
+        ("dvhdosescaling", &tags::DVHDoseScaling),
+        ("blocksequence", &tags::BlockSequence),
+        ("digitalsignaturessequence", &tags::DigitalSignaturesSequence),
+        ("overlaydata", &tags::OverlayData),
+        ("summarizedfilterlookuptablesequence", &tags::SummarizedFilterLookupTableSequence),
+        ("maskselectionmode", &tags::MaskSelectionMode),
+        ("deviceserialnumber", &tags::DeviceSerialNumber),
+        ("gatingbeamholdtransitionsequence", &tags::GatingBeamHoldTransitionSequence),
+        ("patienttreatmentpreparationdevicesequence", &tags::PatientTreatmentPreparationDeviceSequence),
+        ("keratometryrighteyesequence", &tags::KeratometryRightEyeSequence),
+        ("itineraryidtype", &tags::ItineraryIDType),
+        ("secondaryreviewtime", &tags::SecondaryReviewTime),
+        ("channelposition", &tags::ChannelPosition),
+        ("interlocksequence", &tags::InterlockSequence),
+        ("taggingdelay", &tags::TaggingDelay),
+        ("sourceacquisitionprotocolelementnumber", &tags::SourceAcquisitionProtocolElementNumber),
+        ("fillerordernumberimagingservicerequest_retired", &tags::FillerOrderNumberImagingServiceRequest_Retired),
+        ("blockedgedatasequence", &tags::BlockEdgeDataSequence),
+        ("excessivefalsenegativesdataflag", &tags::ExcessiveFalseNegativesDataFlag),
+        ("confidentialityconstraintonpatientdatadescription", &tags::ConfidentialityConstraintonPatientDataDescription),
+        ("specificabsorptionratesequence", &tags::SpecificAbsorptionRateSequence),
+        ("floatingpointvalue", &tags::FloatingPointValue),
+        ("subjectiverefractionlefteyesequence", &tags::SubjectiveRefractionLeftEyeSequence),
+        ("ctimagingacquisitionparametersequence", &tags::CTImagingAcquisitionParameterSequence),
+        ("subscriptionliststatus", &tags::SubscriptionListStatus),
+        ("dosimetricobjectiveparametersequence", &tags::DosimetricObjectiveParameterSequence),
+        ("mappingresourceuid", &tags::MappingResourceUID),
+        ("ophthalmicaxiallengthmeasurementssequence", &tags::OphthalmicAxialLengthMeasurementsSequence),
+        ("conceptualvolumeconstituentsegmentationreferencesequence", &tags::ConceptualVolumeConstituentSegmentationReferenceSequence),
+        ("rtimagename", &tags::RTImageName),
+        ("equivalentconceptualvolumessequence", &tags::EquivalentConceptualVolumesSequence),
+        ("recordedblockslabsequence", &tags::RecordedBlockSlabSequence),
+        ("doublefloatrealworldvaluefirstvaluemapped", &tags::DoubleFloatRealWorldValueFirstValueMapped),
+        ("deviceindex", &tags::DeviceIndex),
+        ("filteroperationssequence", &tags::FilterOperationsSequence),
+        ("actiontypeid", &tags::ActionTypeID),
+        ("beamdose", &tags::BeamDose),
+        ("lutlabel", &tags::LUTLabel),
+        ("detectorbinning", &tags::DetectorBinning),
+        ("additionalpatienthistory", &tags::AdditionalPatientHistory),
+        ("ctacquisitiontypesequence", &tags::CTAcquisitionTypeSequence),
+        ("verticesoftheregion", &tags::VerticesoftheRegion),
+        ("lutdata", &tags::LUTData),
+        ("octopticalcenterwavelength", &tags::OCTOpticalCenterWavelength),
+        ("normalizationfactorformat", &tags::NormalizationFactorFormat),
+        ("observationbasiscodesequence", &tags::ObservationBasisCodeSequence),
+        ("correctedlocalizeddeviationfromnormalprobability", &tags::CorrectedLocalizedDeviationFromNormalProbability),
+        ("annotationflag", &tags::AnnotationFlag),
+        ("respiratorymotioncompensationtechnique", &tags::RespiratoryMotionCompensationTechnique),
+        ("tmlinepositiony0", &tags::TMLinePositionY0),
+        ("treatmentstatuscomment", &tags::TreatmentStatusComment),
+        ("overlaysgray", &tags::OverlaysGray),
+        ("filterlowfrequency", &tags::FilterLowFrequency),
+        ("objectthicknesssequence", &tags::ObjectThicknessSequence),
+        ("waveformdatadisplayscale", &tags::WaveformDataDisplayScale),
+        ("referencedframeofreferenceuid", &tags::ReferencedFrameofReferenceUID),
+        ("frameofinterestdescription", &tags::FrameofInterestDescription),
+        ("primaryfluencemodesequence", &tags::PrimaryFluenceModeSequence),
+        ("singlecollimationwidth", &tags::SingleCollimationWidth),
+        ("acquisitiondeviceprocessingcode", &tags::AcquisitionDeviceProcessingCode),
+        ("generalpurposescheduledproceduresteppriority", &tags::GeneralPurposeScheduledProcedureStepPriority),
+        ("ctreconstructionsequence", &tags::CTReconstructionSequence),
+        ("controlpoint3dposition", &tags::ControlPoint3DPosition),
+        ("quantitydefinitionsequence", &tags::QuantityDefinitionSequence),
+        ("maximumalongscandistortion", &tags::MaximumAlongscanDistortion),
+        ("reconstructiontype", &tags::ReconstructionType),
+        ("brachycontrolpointdeliveredsequence", &tags::BrachyControlPointDeliveredSequence),
+        ("algorithmnamecodesequence", &tags::AlgorithmNameCodeSequence),
+        ("recommendeddisplayframerateinfloat", &tags::RecommendedDisplayFrameRateinFloat),
+        ("certificateofsigner", &tags::CertificateofSigner),
+        ("patientsmothersbirthname", &tags::PatientsMothersBirthName),
+        ("preliminaryflag", &tags::PreliminaryFlag),
+        ("surfaceprocessingalgorithmidentificationsequence", &tags::SurfaceProcessingAlgorithmIdentificationSequence),
         ("instanceoriginstatus", &tags::InstanceOriginStatus),
-        ("apertureelevation", &tags::ApertureElevation),
-        ("instanceavailability", &tags::InstanceAvailability),
-        ("initialcinerunstate", &tags::InitialCineRunState),
-        ("lensmodel", &tags::LensModel),
-        ("internationalroutesegment", &tags::InternationalRouteSegment),
-        ("rttreatmenttechniquecodesequence", &tags::RTTreatmentTechniqueCodeSequence),
-        ("shadingstyle", &tags::ShadingStyle),
-        ("patientsupportpositiontolerancesequence", &tags::PatientSupportPositionToleranceSequence),
-        ("interpretationrecordedtime", &tags::InterpretationRecordedTime),
-        ("dosevaluessequence", &tags::DoseValuesSequence),
-        ("kspacefiltering", &tags::KspaceFiltering),
-        ("patienttreatmentorientationsequence", &tags::PatientTreatmentOrientationSequence),
-        ("rtpatientpositionsequence", &tags::RTPatientPositionSequence),
-        ("referenceddefinedprotocolsequence", &tags::ReferencedDefinedProtocolSequence),
-        ("sourcetopatientsurfacedistance", &tags::SourcetoPatientSurfaceDistance),
-        ("worklistlabel", &tags::WorklistLabel),
-        ("tablepositionsequence", &tags::TablePositionSequence),
-        ("contentlabel", &tags::ContentLabel),
-        ("verticesofthepolygonaloutline", &tags::VerticesofthePolygonalOutline),
-        ("observationdescription", &tags::ObservationDescription),
-        ("preselectedforimplantation", &tags::PreSelectedforImplantation),
-        ("sourceapplicatorname", &tags::SourceApplicatorName),
-        ("studycomponentstatusid", &tags::StudyComponentStatusID),
-        ("requestedresolutionid", &tags::RequestedResolutionID),
-        ("channelshieldnominaltransmission", &tags::ChannelShieldNominalTransmission),
-        ("failedsopsequence", &tags::FailedSOPSequence),
-        ("hangingprotocoldescription", &tags::HangingProtocolDescription),
-        ("seriesinstudy", &tags::SeriesinStudy),
-        ("yfocuscenter", &tags::YFocusCenter),
-        ("rtprescriptionindex", &tags::RTPrescriptionIndex),
-        ("temporalpositionidentifier", &tags::TemporalPositionIdentifier),
-        ("floatpixelpaddingvalue", &tags::FloatPixelPaddingValue),
-        ("mediadisposition", &tags::MediaDisposition),
-        ("voilutsequence", &tags::VOILUTSequence),
-        ("referencedfindingsgroupuid_trial", &tags::ReferencedFindingsGroupUID_Trial),
-        ("referencedstructuredcontextsequence", &tags::ReferencedStructuredContextSequence),
-        ("devicesettingdescription", &tags::DeviceSettingDescription),
-        ("decompositionalgorithmidentificationsequence", &tags::DecompositionAlgorithmIdentificationSequence),
-        ("institutionaldepartmentname", &tags::InstitutionalDepartmentName),
-        ("pixeldataarearotationanglerelativetofov", &tags::PixelDataAreaRotationAngleRelativeToFOV),
-        ("fileaccesssequence", &tags::FileAccessSequence),
-        ("studiescontainingotherreferencedinstancessequence", &tags::StudiesContainingOtherReferencedInstancesSequence),
-        ("reprojectionmethod", &tags::ReprojectionMethod),
-        ("pressure", &tags::Pressure),
-        ("recommendedpointradius", &tags::RecommendedPointRadius),
-        ("rtdoseroisequence", &tags::RTDoseROISequence),
-        ("imageorientationslide", &tags::ImageOrientationSlide),
-        ("instancecreationdate", &tags::InstanceCreationDate),
-        ("greenpalettecolorlookuptabledescriptor", &tags::GreenPaletteColorLookupTableDescriptor),
-        ("rtimageframecontextsequence", &tags::RTImageFrameContextSequence),
-        ("tag_3dplaneorigin", &tags::Tag_3DPlaneOrigin),
-        ("modelspecificationsequence", &tags::ModelSpecificationSequence),
-        ("patientlocationcoordinatessequence", &tags::PatientLocationCoordinatesSequence),
-        ("protocolelementpurpose", &tags::ProtocolElementPurpose),
-        ("materialpropertiesfileformat", &tags::MaterialPropertiesFileFormat),
-        ("destinationae", &tags::DestinationAE),
-        ("clinicaltrialcoordinatingcentername", &tags::ClinicalTrialCoordinatingCenterName),
-        ("doubleexposurefielddelta", &tags::DoubleExposureFieldDelta),
-        ("routeofadmissions", &tags::RouteofAdmissions),
-        ("reconstructiontargetcenterlocationsequence", &tags::ReconstructionTargetCenterLocationSequence),
-        ("tabletoprollangletolerance", &tags::TableTopRollAngleTolerance),
+        ("rtphysicianintentinputinstancesequence", &tags::RTPhysicianIntentInputInstanceSequence),
+        ("cumulativemeterset", &tags::CumulativeMeterset),
+        ("blendinglut1sequence", &tags::BlendingLUT1Sequence),
+        ("organatrisklimitdose", &tags::OrganatRiskLimitDose),
+        ("issuerofpatientid", &tags::IssuerofPatientID),
+        ("registeredlocalizertoplefthandcorner", &tags::RegisteredLocalizerTopLeftHandCorner),
+        ("velocityencodingmaximumvalue", &tags::VelocityEncodingMaximumValue),
+        ("maximumstimulusluminance", &tags::MaximumStimulusLuminance),
+        ("implantassemblytemplateissuer", &tags::ImplantAssemblyTemplateIssuer),
+        ("fontname", &tags::FontName),
+        ("functionalsettlingphaseframespresent", &tags::FunctionalSettlingPhaseFramesPresent),
+        ("referencedrtradiationsetsequence", &tags::ReferencedRTRadiationSetSequence),
+        ("acquisitiontaskindex", &tags::AcquisitionTaskIndex),
+        ("pixelmeasuressequence", &tags::PixelMeasuresSequence),
+        ("primarypositionerscanstartangle", &tags::PrimaryPositionerScanStartAngle),
+        ("datablock", &tags::DataBlock),
+        ("cylindersequence", &tags::CylinderSequence),
+        ("recommendedrotationpoint", &tags::RecommendedRotationPoint),
+        ("reasonforomission", &tags::ReasonforOmission),
+        ("nondicomoutputcodesequence", &tags::NonDICOMOutputCodeSequence),
+        ("imagingaperturesequence", &tags::ImagingApertureSequence),
+        ("pixelvaluetransformationsequence", &tags::PixelValueTransformationSequence),
+        ("planninglandmarkpointsequence", &tags::PlanningLandmarkPointSequence),
+        ("performedlocation", &tags::PerformedLocation),
+        ("primarychromaticities", &tags::PrimaryChromaticities),
+        ("certificatetype", &tags::CertificateType),
         ("delivereddepthdoseparameterssequence", &tags::DeliveredDepthDoseParametersSequence),
-        ("rtaccessoryholderdefinitionsequence", &tags::RTAccessoryHolderDefinitionSequence),
-        ("implanttemplategroupvariationdimensionsequence", &tags::ImplantTemplateGroupVariationDimensionSequence),
-        ("timeslottime", &tags::TimeSlotTime),
-        ("targetmaximumdose", &tags::TargetMaximumDose),
-        ("contrastbolustotaldose", &tags::ContrastBolusTotalDose),
-        ("biplaneacquisitionsequence", &tags::BiPlaneAcquisitionSequence),
-        ("dicommediaretrievalsequence", &tags::DICOMMediaRetrievalSequence),
-        ("radiationbeamblockthickness", &tags::RadiationBeamBlockThickness),
-        ("digitalzoomratio", &tags::DigitalZoomRatio),
-        ("numberofcopies", &tags::NumberofCopies),
-        ("recordedblocksequence", &tags::RecordedBlockSequence),
-        ("outlineupperhorizontaledge", &tags::OutlineUpperHorizontalEdge),
-        ("channelthreshold", &tags::ChannelThreshold),
-        ("positionerprimaryangle", &tags::PositionerPrimaryAngle),
-        ("transducerpositionmodifiersequence", &tags::TransducerPositionModifierSequence),
-        ("tagging", &tags::Tagging),
-        ("terminationrelativedensitythreshold", &tags::TerminationRelativeDensityThreshold),
-        ("matingfeaturesequence", &tags::MatingFeatureSequence),
-        ("wedgepositionsequence", &tags::WedgePositionSequence),
-        ("targetluminancecharacteristicssequence", &tags::TargetLuminanceCharacteristicsSequence),
-        ("effectivebinenergy", &tags::EffectiveBinEnergy),
-        ("reteststimulusseen", &tags::RetestStimulusSeen),
-        ("referencedtexturesequence", &tags::ReferencedTextureSequence),
-        ("gantryrotationdirection", &tags::GantryRotationDirection),
-        ("lossyimagecompressionmethod", &tags::LossyImageCompressionMethod),
-        ("privateinformationcreatoruid", &tags::PrivateInformationCreatorUID),
-        ("longitudinaltemporaleventtype", &tags::LongitudinalTemporalEventType),
-        ("dataelementssigned", &tags::DataElementsSigned),
-        ("maxdensity", &tags::MaxDensity),
-        ("storedvaluecolorrangesequence", &tags::StoredValueColorRangeSequence),
-        ("framenumbersofinterestfoi", &tags::FrameNumbersofInterestFOI),
-        ("blendingdisplaysequence", &tags::BlendingDisplaySequence),
-        ("displacementreferencelabel", &tags::DisplacementReferenceLabel),
-        ("totalnumberofexposures", &tags::TotalNumberofExposures),
-        ("affectedsopclassuid", &tags::AffectedSOPClassUID),
-        ("fractionpattern", &tags::FractionPattern),
+        ("printername", &tags::PrinterName),
+        ("locationofmeasuredbeamdiameter", &tags::LocationofMeasuredBeamDiameter),
+        ("exposureprogram", &tags::ExposureProgram),
+        ("fixationdevicedescription", &tags::FixationDeviceDescription),
+        ("dataelementmaximumcharacters", &tags::DataElementMaximumCharacters),
+        ("regionspatialformat", &tags::RegionSpatialFormat),
+        ("largegreenpalettecolorlookuptabledescriptor", &tags::LargeGreenPaletteColorLookupTableDescriptor),
+        ("multienergyctacquisition", &tags::MultienergyCTAcquisition),
+        ("blendinglut2transferfunction", &tags::BlendingLUT2TransferFunction),
+        ("component1referencedmatingfeaturesetid", &tags::Component1ReferencedMatingFeatureSetID),
+        ("geneticmodificationscodesequence", &tags::GeneticModificationsCodeSequence),
+        ("scheduledstudystartdate", &tags::ScheduledStudyStartDate),
+        ("hpgldocumentscaling", &tags::HPGLDocumentScaling),
+        ("shadingstyle", &tags::ShadingStyle),
+        ("photoacousticexcitationcharacteristicssequence", &tags::PhotoacousticExcitationCharacteristicsSequence),
+        ("radiationsetting", &tags::RadiationSetting),
+        ("commonzcoordinatevalue", &tags::CommonZCoordinateValue),
+        ("ioncontrolpointverificationsequence", &tags::IonControlPointVerificationSequence),
+        ("ultrasoundselectedophthalmicaxiallengthsequence", &tags::UltrasoundSelectedOphthalmicAxialLengthSequence),
+        ("ptorepresentationsequence", &tags::PTORepresentationSequence),
+        ("sourcetoreferenceobjectdistance", &tags::SourcetoReferenceObjectDistance),
+        ("compensatorrelativestoppingpowerratio", &tags::CompensatorRelativeStoppingPowerRatio),
+        ("visualacuitymodifiers", &tags::VisualAcuityModifiers),
+        ("positionacquisitiontemplatename", &tags::PositionAcquisitionTemplateName),
+        ("indexprobability", &tags::IndexProbability),
+        ("diffusiongradientdirectionsequence", &tags::DiffusionGradientDirectionSequence),
+        ("referencedisplaysets", &tags::ReferenceDisplaySets),
+        ("tolerancetablesequence", &tags::ToleranceTableSequence),
+        ("matingfeatureid", &tags::MatingFeatureID),
+        ("scheduledprocedurestependtime", &tags::ScheduledProcedureStepEndTime),
+        ("numberoftomosynthesissourceimages", &tags::NumberofTomosynthesisSourceImages),
+        ("evaluatornumber", &tags::EvaluatorNumber),
+        ("recordedrangemodulatorsequence", &tags::RecordedRangeModulatorSequence),
+        ("transmitterfrequency", &tags::TransmitterFrequency),
+        ("strainadditionalinformation", &tags::StrainAdditionalInformation),
+        ("scanprocedure", &tags::ScanProcedure),
+        ("pettabledynamicssequence", &tags::PETTableDynamicsSequence),
+        ("graphiclayerrecommendeddisplayrgbvalue", &tags::GraphicLayerRecommendedDisplayRGBValue),
+        ("dopplersamplevolumeyposition", &tags::DopplerSampleVolumeYPosition),
+        ("anodetargetmaterial", &tags::AnodeTargetMaterial),
+        ("interpretationapprovaltime", &tags::InterpretationApprovalTime),
+        ("scanningspotsize", &tags::ScanningSpotSize),
+        ("rtreferencedseriessequence", &tags::RTReferencedSeriesSequence),
+        ("tablerowdefinitionsequence", &tags::TableRowDefinitionSequence),
+        ("ophthalmicaxiallengthsequence", &tags::OphthalmicAxialLengthSequence),
+        ("componentmanufacturer", &tags::ComponentManufacturer),
+        ("decouplingmethod", &tags::DecouplingMethod),
+        ("selectorsequencepointerprivatecreator", &tags::SelectorSequencePointerPrivateCreator),
+        ("ophthalmicaxiallengthqualitymetricsequence", &tags::OphthalmicAxialLengthQualityMetricSequence),
+        ("degreeoffreedomtype", &tags::DegreeofFreedomType),
+        ("devicemotionparametercodesequence", &tags::DeviceMotionParameterCodeSequence),
+        ("softtissuefocusthermalindex", &tags::SoftTissuefocusThermalIndex),
+        ("nuclearmedicineseriestype", &tags::NuclearMedicineSeriesType),
+        ("interventiondrugstarttime", &tags::InterventionDrugStartTime),
+        ("storageprotocolelementsequence", &tags::StorageProtocolElementSequence),
+        ("assignedlocation", &tags::AssignedLocation),
+        ("driveprobesequence", &tags::DriveProbeSequence),
+        ("printjobdescriptionsequence", &tags::PrintJobDescriptionSequence),
+        ("dopplersamplevolumexposition_retired", &tags::DopplerSampleVolumeXPosition_Retired),
+        ("xrayimagereceptorangle", &tags::XRayImageReceptorAngle),
+        ("upperlimitnumberofpersistentfluoroscopyframes", &tags::UpperLimitNumberOfPersistentFluoroscopyFrames),
+        ("dosereferencesequence", &tags::DoseReferenceSequence),
+        ("cardiaccycleposition", &tags::CardiacCyclePosition),
+        ("constrainttype", &tags::ConstraintType),
+        ("tablefeedperrotation", &tags::TableFeedperRotation),
+        ("attributeoccurrencepointer", &tags::AttributeOccurrencePointer),
+        ("patientphysiologicalstatesequence", &tags::PatientPhysiologicalStateSequence),
+        ("radiationdosecentralaxisdisplacement", &tags::RadiationDoseCentralAxisDisplacement),
+        ("reasonforvisit", &tags::ReasonforVisit),
+        ("rttolerancesetlabel", &tags::RTToleranceSetLabel),
+        ("redpalettecolorlookuptabledata", &tags::RedPaletteColorLookupTableData),
+        ("transducertype", &tags::TransducerType),
+        ("clinicaltrialprotocolname", &tags::ClinicalTrialProtocolName),
+        ("algorithmdescription_trial", &tags::AlgorithmDescription_Trial),
+        ("aliaseddatatype", &tags::AliasedDataType),
+        ("patientsetupnumber", &tags::PatientSetupNumber),
+        ("frequencycorrection", &tags::FrequencyCorrection),
+        ("patienttreatmentorientationsequence", &tags::PatientTreatmentOrientationSequence),
+        ("collimatortype", &tags::CollimatorType),
+        ("imagingdevicelocationparametersequence", &tags::ImagingDeviceLocationParameterSequence),
+        ("timezoneoffsetfromutc", &tags::TimezoneOffsetFromUTC),
+        ("beamlimitingdevicetolerancesequence", &tags::BeamLimitingDeviceToleranceSequence),
+        ("serviceepisodeid", &tags::ServiceEpisodeID),
+        ("premedication", &tags::PreMedication),
+        ("dosecalibrationconditionsverifiedflag", &tags::DoseCalibrationConditionsVerifiedFlag),
+        ("waveformamplifiertype", &tags::WaveformAmplifierType),
+        ("dateofdocumentorverbaltransaction_trial", &tags::DateofDocumentorVerbalTransaction_Trial),
+        ("isocentertoblocktraydistance", &tags::IsocentertoBlockTrayDistance),
+        ("systemstatus", &tags::SystemStatus),
+        ("iconimagesequence", &tags::IconImageSequence),
+        ("crop", &tags::Crop),
+        ("modifiedimagedate", &tags::ModifiedImageDate),
+        ("shutterupperhorizontaledge", &tags::ShutterUpperHorizontalEdge),
+        ("rttreatmentphaseintervalsequence", &tags::RTTreatmentPhaseIntervalSequence),
+        ("planninginputinformationsequence", &tags::PlanningInputInformationSequence),
+        ("mraveragessequence", &tags::MRAveragesSequence),
+        ("sourcepatientgroupidentificationsequence", &tags::SourcePatientGroupIdentificationSequence),
+        ("acquisitiontype", &tags::AcquisitionType),
+        ("animationstepsize", &tags::AnimationStepSize),
+        ("detectormanufacturersmodelname", &tags::DetectorManufacturersModelName),
+        ("frameofreferencetransformationmatrix", &tags::FrameofReferenceTransformationMatrix),
+        ("distaldepthfraction", &tags::DistalDepthFraction),
+        ("elementdimensiona", &tags::ElementDimensionA),
+        ("matingfeaturesetid", &tags::MatingFeatureSetID),
+        ("residualsyringecounts", &tags::ResidualSyringeCounts),
+        ("motionsynchronizationsequence", &tags::MotionSynchronizationSequence),
+        ("containertypecodesequence", &tags::ContainerTypeCodeSequence),
+        ("examinedbodythickness", &tags::ExaminedBodyThickness),
+        ("channelshieldsequence", &tags::ChannelShieldSequence),
+        ("planepositionsequence", &tags::PlanePositionSequence),
+        ("channelidentificationcode", &tags::ChannelIdentificationCode),
+        ("lateralspreadingdeviceid", &tags::LateralSpreadingDeviceID),
+        ("frametype", &tags::FrameType),
+        ("perimetertable", &tags::PerimeterTable),
+        ("tag_3dmatingpoint", &tags::Tag_3DMatingPoint),
+        ("allergies", &tags::Allergies),
+        ("measuredmetersettodosemappingsequence", &tags::MeasuredMetersettoDoseMappingSequence),
+        ("specifiedsecondarymeterset", &tags::SpecifiedSecondaryMeterset),
+        ("contrastbolusautoinjectiontriggerflag", &tags::ContrastBolusAutoInjectionTriggerFlag),
+        ("seriesnumber", &tags::SeriesNumber),
+        ("receivetransducersequence", &tags::ReceiveTransducerSequence),
+        ("dischargediagnosiscodesequence", &tags::DischargeDiagnosisCodeSequence),
+        ("referencedsurfacemeshidentificationsequence", &tags::ReferencedSurfaceMeshIdentificationSequence),
+        ("retrieveaetitle", &tags::RetrieveAETitle),
+        ("rrintervalvector", &tags::RRIntervalVector),
+        ("studypriorityid", &tags::StudyPriorityID),
+        ("highrrvalue", &tags::HighRRValue),
+        ("deidentificationactionsequence", &tags::DeidentificationActionSequence),
+        ("randomscorrectionmethod", &tags::RandomsCorrectionMethod),
+        ("patientsbirthtime", &tags::PatientsBirthTime),
+        ("availabletransfersyntaxuid", &tags::AvailableTransferSyntaxUID),
+        ("fixationdevicesequence", &tags::FixationDeviceSequence),
+        ("annotationcoordinatetype", &tags::AnnotationCoordinateType),
+        ("fixationdevicepitchangle", &tags::FixationDevicePitchAngle),
+        ("fieldofviewdimensions", &tags::FieldofViewDimensions),
+        ("digitalsignatureuid", &tags::DigitalSignatureUID),
+        ("linedashingstyle", &tags::LineDashingStyle),
+        ("blockthickness", &tags::BlockThickness),
+        ("iolpowerforexactemmetropia", &tags::IOLPowerForExactEmmetropia),
+        ("relevantinformationsequence", &tags::RelevantInformationSequence),
+        ("diffusionbmatrixsequence", &tags::DiffusionbmatrixSequence),
+        ("materialcodesequence", &tags::MaterialCodeSequence),
+        ("eventtimeoffset", &tags::EventTimeOffset),
+        ("privatedataelement", &tags::PrivateDataElement),
+        ("volumetricannotationsequence", &tags::VolumetricAnnotationSequence),
+        ("requestingservice", &tags::RequestingService),
         ("ooitype", &tags::OOIType),
-        ("acquisitionmode", &tags::AcquisitionMode),
-        ("laterality", &tags::Laterality),
-        ("rtvcommunicationsopinstanceuid", &tags::RTVCommunicationSOPInstanceUID),
-        ("exposuremodulationtype", &tags::ExposureModulationType),
-        ("referencedbrachyaccessorydevicenumber", &tags::ReferencedBrachyAccessoryDeviceNumber),
-        ("surfaceprocessing", &tags::SurfaceProcessing),
-        ("cranialthermalindex", &tags::CranialThermalIndex),
-        ("tolerancetablenumber", &tags::ToleranceTableNumber),
-        ("cylindersequence", &tags::CylinderSequence),
-        ("placerordernumberprocedure", &tags::PlacerOrderNumberProcedure),
-        ("topicsubject", &tags::TopicSubject),
-        ("effectiverefractiveindex", &tags::EffectiveRefractiveIndex),
+        ("datasetsubtype", &tags::DataSetSubtype),
+        ("referencepixelx0", &tags::ReferencePixelX0),
+        ("finalcumulativetimeweight", &tags::FinalCumulativeTimeWeight),
+        ("patientphysiologicalstatecodesequence", &tags::PatientPhysiologicalStateCodeSequence),
+        ("mass", &tags::Mass),
+        ("ctdiphantomtypecodesequence", &tags::CTDIPhantomTypeCodeSequence),
+        ("camerapositiongroupsequence", &tags::CameraPositionGroupSequence),
+        ("measureddosereferencesequence", &tags::MeasuredDoseReferenceSequence),
+        ("referencedfilmsessionsequence", &tags::ReferencedFilmSessionSequence),
+        ("rtradiationtasksequence", &tags::RTRadiationTaskSequence),
+        ("rowsfornthordercoefficients", &tags::RowsForNthOrderCoefficients),
+        ("visitcomments", &tags::VisitComments),
+        ("detectorconfiguration", &tags::DetectorConfiguration),
+        ("presentationintenttype", &tags::PresentationIntentType),
+        ("filmorientation", &tags::FilmOrientation),
+        ("offsetofthenextdirectoryrecord", &tags::OffsetoftheNextDirectoryRecord),
+        ("receivingapplicationentitytitle", &tags::ReceivingApplicationEntityTitle),
+        ("ophthalmicaxiallength", &tags::OphthalmicAxialLength),
+        ("contrastadministrationprofilesequence", &tags::ContrastAdministrationProfileSequence),
+        ("cellvaluessequence", &tags::CellValuesSequence),
+        ("verificationdatetime", &tags::VerificationDateTime),
+        ("dvhnormalizationpoint", &tags::DVHNormalizationPoint),
+        ("applicationsetupnumber", &tags::ApplicationSetupNumber),
+        ("overrideparameterpointer", &tags::OverrideParameterPointer),
+        ("roigenerationalgorithm", &tags::ROIGenerationAlgorithm),
+        ("ownerid", &tags::OwnerID),
+        ("kvusedingaincalibration", &tags::KVUsedinGainCalibration),
+        ("coefficientssddn", &tags::CoefficientsSDDN),
+        ("secondarycountstype", &tags::SecondaryCountsType),
+        ("stagecodesequence", &tags::StageCodeSequence),
+        ("xaacquisitionframerate", &tags::XAAcquisitionFrameRate),
+        ("referencedfiducialuid", &tags::ReferencedFiducialUID),
+        ("strainstocknumber", &tags::StrainStockNumber),
+        ("indicationphysicalpropertysequence", &tags::IndicationPhysicalPropertySequence),
+        ("acquisitioncontextdescription", &tags::AcquisitionContextDescription),
+        ("printercharacteristicssequence", &tags::PrinterCharacteristicsSequence),
+        ("detectorelementspacing", &tags::DetectorElementSpacing),
+        ("creatorversionuid", &tags::CreatorVersionUID),
+        ("studycomments", &tags::StudyComments),
+        ("standardoutputsensitivity", &tags::StandardOutputSensitivity),
+        ("rtimageframekvradiationacquisitionsequence", &tags::RTImageFramekVRadiationAcquisitionSequence),
+        ("overlaysred", &tags::OverlaysRed),
+        ("datarepresentation", &tags::DataRepresentation),
+        ("referenceddosesequence", &tags::ReferencedDoseSequence),
+        ("surfacepointcolorcielabvaluedata", &tags::SurfacePointColorCIELabValueData),
+        ("algorithmparameters", &tags::AlgorithmParameters),
+        ("itineraryid", &tags::ItineraryID),
+        ("longitudinaltemporalinformationmodified", &tags::LongitudinalTemporalInformationModified),
+        ("globalcrop", &tags::GlobalCrop),
+        ("customrendered", &tags::CustomRendered),
         ("agecorrectedsensitivitydeviationalgorithmsequence", &tags::AgeCorrectedSensitivityDeviationAlgorithmSequence),
-        ("fiducialsetsequence", &tags::FiducialSetSequence),
-        ("rtaccessoryholderwaterequivalentthickness", &tags::RTAccessoryHolderWaterEquivalentThickness),
-        ("pausebetweenframes", &tags::PauseBetweenFrames),
-        ("ophthalmicaxiallengthdatasourcedescription", &tags::OphthalmicAxialLengthDataSourceDescription),
-        ("excessivefalsenegativesdataflag", &tags::ExcessiveFalseNegativesDataFlag),
-        ("gpssatellites", &tags::GPSSatellites),
-        ("acquisitionsignaltype", &tags::AcquisitionSignalType),
-        ("radiopharmaceuticalstoptime", &tags::RadiopharmaceuticalStopTime),
-        ("positionerpositionsequence", &tags::PositionerPositionSequence),
-        ("attachedcontours", &tags::AttachedContours),
+        ("beamhardeningcorrectiontechnique", &tags::BeamHardeningCorrectionTechnique),
+        ("sourceapplicatortiplength", &tags::SourceApplicatorTipLength),
+        ("gridpitch", &tags::GridPitch),
+        ("referencedhpgldocumentid", &tags::ReferencedHPGLDocumentID),
         ("specimendescriptionsequence", &tags::SpecimenDescriptionSequence),
-        ("currentconfigurationid", &tags::CurrentConfigurationID),
-        ("addnearsequence", &tags::AddNearSequence),
-        ("halfvaluelayer", &tags::HalfValueLayer),
-        ("wedgecurve", &tags::WedgeCurve),
-        ("gantryangle", &tags::GantryAngle),
-        ("cumulativemetersetweight", &tags::CumulativeMetersetWeight),
-        ("tomotherapeuticleafopendurations", &tags::TomotherapeuticLeafOpenDurations),
-        ("branchofservice", &tags::BranchofService),
-        ("visualacuitymeasurementsequence", &tags::VisualAcuityMeasurementSequence),
-        ("displaysetlabel", &tags::DisplaySetLabel),
-        ("patienteyemovementcommanded", &tags::PatientEyeMovementCommanded),
-        ("cardiacsynchronizationsequence", &tags::CardiacSynchronizationSequence),
-        ("receiversettingssequence", &tags::ReceiverSettingsSequence),
-        ("convolutionkernel", &tags::ConvolutionKernel),
-        ("rtplanrelationship", &tags::RTPlanRelationship),
-        ("selectorshvalue", &tags::SelectorSHValue),
-        ("vectoraccuracy", &tags::VectorAccuracy),
-        ("visualacuitylefteyesequence", &tags::VisualAcuityLeftEyeSequence),
-        ("overlaydate", &tags::OverlayDate),
-        ("temporalpositionindex", &tags::TemporalPositionIndex),
-        ("numberofphaseencodingsteps", &tags::NumberofPhaseEncodingSteps),
-        ("detectorelementspacing", &tags::DetectorElementSpacing),
-        ("structuresetroisequence", &tags::StructureSetROISequence),
-        ("radiationatomicnumber", &tags::RadiationAtomicNumber),
-        ("containerfiletype", &tags::ContainerFileType),
-        ("radiotherapytreatmenttype", &tags::RadiotherapyTreatmentType),
-        ("bitsallocated", &tags::BitsAllocated),
-        ("framecontentsequence", &tags::FrameContentSequence),
-        ("iontolerancetablesequence", &tags::IonToleranceTableSequence),
-        ("dataelementmaximumcharacters", &tags::DataElementMaximumCharacters),
-        ("scheduledprocedurestepstartdate", &tags::ScheduledProcedureStepStartDate),
-        ("totalaperture", &tags::TotalAperture),
-        ("sourceimagecornealprocesseddatasequence", &tags::SourceImageCornealProcessedDataSequence),
-        ("segmentcharacteristicsprecedence", &tags::SegmentCharacteristicsPrecedence),
-        ("blendinglut1transferfunction", &tags::BlendingLUT1TransferFunction),
-        ("isocentertocompensatordistances", &tags::IsocentertoCompensatorDistances),
-        ("agecorrectedsensitivitydeviationprobabilityvalue", &tags::AgeCorrectedSensitivityDeviationProbabilityValue),
-        ("clinicaltrialprotocolethicscommitteeapprovalnumber", &tags::ClinicalTrialProtocolEthicsCommitteeApprovalNumber),
-        ("volumetricpresentationinputnumber", &tags::VolumetricPresentationInputNumber),
-        ("rtprescriptionsequence", &tags::RTPrescriptionSequence),
-        ("framepixelshiftsequence", &tags::FramePixelShiftSequence),
-        ("emptyimageboxcielabvalue", &tags::EmptyImageBoxCIELabValue),
-        ("failureattributes", &tags::FailureAttributes),
-        ("ctacquisitiondetailssequence", &tags::CTAcquisitionDetailsSequence),
-        ("rtanatomicprescriptionsequence", &tags::RTAnatomicPrescriptionSequence),
-        ("planninglandmarkplanesequence", &tags::PlanningLandmarkPlaneSequence),
-        ("calibrationimage", &tags::CalibrationImage),
-        ("tabletoplongitudinaladjustedposition", &tags::TableTopLongitudinalAdjustedPosition),
-        ("numberoftablecolumns", &tags::NumberofTableColumns),
-        ("detectoractivedimensions", &tags::DetectorActiveDimensions),
-        ("performedstationgeographiclocationcodesequence", &tags::PerformedStationGeographicLocationCodeSequence),
-        ("graphiclayerrecommendeddisplayrgbvalue", &tags::GraphicLayerRecommendedDisplayRGBValue),
-        ("gpsmapdatum", &tags::GPSMapDatum),
-        ("wedgetotallength", &tags::WedgeTotalLength),
-        ("adaptivemapformat", &tags::AdaptiveMapFormat),
-        ("wedgesequence", &tags::WedgeSequence),
-        ("applicatoropeningy", &tags::ApplicatorOpeningY),
-        ("primarypromptscountsaccumulated", &tags::PrimaryPromptsCountsAccumulated),
-        ("referringphysiciansaddress", &tags::ReferringPhysiciansAddress),
-        ("brachycontrolpointsequence", &tags::BrachyControlPointSequence),
-        ("selectorolvalue", &tags::SelectorOLValue),
-        ("keratometrylefteyesequence", &tags::KeratometryLeftEyeSequence),
-        ("referencedcolorpaletteinstanceuid", &tags::ReferencedColorPaletteInstanceUID),
-        ("probeinductance", &tags::ProbeInductance),
-        ("hpglpennumber", &tags::HPGLPenNumber),
-        ("terminationdensitythreshold", &tags::TerminationDensityThreshold),
-        ("rangeshiftersettingssequence", &tags::RangeShifterSettingsSequence),
-        ("bulkmotioncompensationtechnique", &tags::BulkMotionCompensationTechnique),
-        ("attributemodificationdatetime", &tags::AttributeModificationDateTime),
-        ("exposurecontrolsensingregionrightverticaledge", &tags::ExposureControlSensingRegionRightVerticalEdge),
-        ("implanttemplategroupvariationdimensionrank", &tags::ImplantTemplateGroupVariationDimensionRank),
-        ("dosimetricobjectivepurpose", &tags::DosimetricObjectivePurpose),
-        ("roicontoursequence", &tags::ROIContourSequence),
-        ("numberofpatientrelatedinstances", &tags::NumberofPatientRelatedInstances),
-        ("numberofassessmentobservations", &tags::NumberofAssessmentObservations),
-        ("multiplexgroupuid", &tags::MultiplexGroupUID),
-        ("procedurestepcommunicationsurisequence", &tags::ProcedureStepCommunicationsURISequence),
-        ("containercomponentdiameter", &tags::ContainerComponentDiameter),
-        ("filterlookuptablesequence", &tags::FilterLookupTableSequence),
-        ("seamlinelocation", &tags::SeamLineLocation),
-        ("roielementalcompositionatomicnumber", &tags::ROIElementalCompositionAtomicNumber),
-        ("protocolname", &tags::ProtocolName),
-        ("referencedtdrinstancesequence", &tags::ReferencedTDRInstanceSequence),
-        ("realworldvalueslope", &tags::RealWorldValueSlope),
-        ("ioncontrolpointsequence", &tags::IonControlPointSequence),
-        ("sourceofrefractivemeasurementssequence", &tags::SourceofRefractiveMeasurementsSequence),
-        ("datapointcolumns", &tags::DataPointColumns),
-        ("numberofpatientsupportdevices", &tags::NumberofPatientSupportDevices),
-        ("referencedgeneralpurposescheduledproceduresteptransactionuid", &tags::ReferencedGeneralPurposeScheduledProcedureStepTransactionUID),
-        ("labeltext", &tags::LabelText),
-        ("languagecodesequence", &tags::LanguageCodeSequence),
+        ("slicesensitivityfactor", &tags::SliceSensitivityFactor),
+        ("protocolelementpurpose", &tags::ProtocolElementPurpose),
+        ("specimenpreparationstepcontentitemsequence", &tags::SpecimenPreparationStepContentItemSequence),
+        ("runlengthtriplet", &tags::RunLengthTriplet),
+        ("failurereason", &tags::FailureReason),
+        ("fovealpointnormativedataflag", &tags::FovealPointNormativeDataFlag),
+        ("deliveredprimarymeterset", &tags::DeliveredPrimaryMeterset),
+        ("referencedrttreatmentphaseindex", &tags::ReferencedRTTreatmentPhaseIndex),
+        ("priority", &tags::Priority),
+        ("activesourcelength", &tags::ActiveSourceLength),
+        ("vertexpointindexlist", &tags::VertexPointIndexList),
+        ("listofmimetypes", &tags::ListofMIMETypes),
+        ("rtroiidentificationcodesequence", &tags::RTROIIdentificationCodeSequence),
+        ("shininess", &tags::Shininess),
+        ("scanspotmetersetweights", &tags::ScanSpotMetersetWeights),
+        ("numberoftablerows", &tags::NumberofTableRows),
+        ("huffmantabletriplet", &tags::HuffmanTableTriplet),
+        ("verticalprismpower", &tags::VerticalPrismPower),
+        ("rightimagesequence", &tags::RightImageSequence),
+        ("xrayacquisitiondosesequence", &tags::XRayAcquisitionDoseSequence),
+        ("assertioncomments", &tags::AssertionComments),
+        ("stoptrim", &tags::StopTrim),
+        ("channeldeliverycontinuationsequence", &tags::ChannelDeliveryContinuationSequence),
+        ("framelabel", &tags::FrameLabel),
+        ("excessivefalsepositives", &tags::ExcessiveFalsePositives),
+        ("tolerancevalue", &tags::ToleranceValue),
+        ("retrievelocationuid", &tags::RetrieveLocationUID),
+        ("substanceadministrationdatetime", &tags::SubstanceAdministrationDateTime),
+        ("treatmenttoleranceviolationdatetime", &tags::TreatmentToleranceViolationDateTime),
+        ("multiframepresentationsequence", &tags::MultiframePresentationSequence),
         ("detectorelementsize", &tags::DetectorElementSize),
-        ("accessorycode", &tags::AccessoryCode),
-        ("coverageofkspace", &tags::CoverageofkSpace),
-        ("tag_3dmatingaxes", &tags::Tag_3DMatingAxes),
-        ("uvmappingsequence", &tags::UVMappingSequence),
-        ("tableofybreakpoints", &tags::TableofYBreakPoints),
-        ("approvalstatusdatetime", &tags::ApprovalStatusDateTime),
-        ("oecfrows", &tags::OECFRows),
-        ("rangeshifterwaterequivalentthickness", &tags::RangeShifterWaterEquivalentThickness),
-        ("performingphysiciansname", &tags::PerformingPhysiciansName),
-        ("referencedimagenavigationsequence", &tags::ReferencedImageNavigationSequence),
-        ("iolpowerforexactemmetropia", &tags::IOLPowerForExactEmmetropia),
-        ("rtplanlabel", &tags::RTPlanLabel),
-        ("numberofwarningsuboperations", &tags::NumberofWarningSuboperations),
-        ("channeldeliveryordersequence", &tags::ChannelDeliveryOrderSequence),
-        ("screenminimumgrayscalebitdepth", &tags::ScreenMinimumGrayscaleBitDepth),
-        ("screeningbaselinevalue", &tags::ScreeningBaselineValue),
-        ("numberofdetectors", &tags::NumberofDetectors),
-        ("authoridentificationsequence", &tags::AuthorIdentificationSequence),
-        ("studyupdatedatetime", &tags::StudyUpdateDateTime),
-        ("fractionbasedrelationshipsequence", &tags::FractionBasedRelationshipSequence),
-        ("devicemotionobservationmode", &tags::DeviceMotionObservationMode),
-        ("patienttreatmentpreparationprocedurecodesequence", &tags::PatientTreatmentPreparationProcedureCodeSequence),
-        ("materialisolationdiameter", &tags::MaterialIsolationDiameter),
-        ("exposuremode", &tags::ExposureMode),
-        ("brachyaccessorydevicetype", &tags::BrachyAccessoryDeviceType),
-        ("trianglestripsequence", &tags::TriangleStripSequence),
-        ("excitationpulseduration", &tags::ExcitationPulseDuration),
-        ("recommendeddisplayframerate", &tags::RecommendedDisplayFrameRate),
-        ("acquisitioncontextdescription", &tags::AcquisitionContextDescription),
-        ("rtroiobservationssequence", &tags::RTROIObservationsSequence),
-        ("probedrivesettingssequence", &tags::ProbeDriveSettingsSequence),
-        ("othersecondaryapprovalstatus", &tags::OtherSecondaryApprovalStatus),
-        ("ophthalmicimagetypedescription", &tags::OphthalmicImageTypeDescription),
-        ("flashredeyemode", &tags::FlashRedEyeMode),
-        ("selectoratvalue", &tags::SelectorATValue),
-        ("deliveredreferencedosedefinition", &tags::DeliveredReferenceDoseDefinition),
-        ("triggerwindow", &tags::TriggerWindow),
-        ("visualfieldtestpointnormalssequence", &tags::VisualFieldTestPointNormalsSequence),
-        ("plane", &tags::Plane),
-        ("largestvalidpixelvalue", &tags::LargestValidPixelValue),
-        ("volumetotablemappingmatrix", &tags::VolumetoTableMappingMatrix),
-        ("scheduledproceduresteppriority", &tags::ScheduledProcedureStepPriority),
-        ("photoacousticimageframetypesequence", &tags::PhotoacousticImageFrameTypeSequence),
-        ("decimalpotentialvisualacuity", &tags::DecimalPotentialVisualAcuity),
-        ("bscanslabthickness", &tags::BscanSlabThickness),
-        ("dataelement", &tags::DataElement),
-        ("filtermaterialusedingaincalibration", &tags::FilterMaterialUsedinGainCalibration),
-        ("segmentationtemplateuid", &tags::SegmentationTemplateUID),
+        ("rtaccessorydeviceslotid", &tags::RTAccessoryDeviceSlotID),
+        ("pixelpaddingrangelimit", &tags::PixelPaddingRangeLimit),
+        ("containercomponentthickness", &tags::ContainerComponentThickness),
         ("flashreturnstatus", &tags::FlashReturnStatus),
-        ("fieldofviewdescription", &tags::FieldofViewDescription),
-        ("transmittransducersequence", &tags::TransmitTransducerSequence),
-        ("shutterlowerhorizontaledge", &tags::ShutterLowerHorizontalEdge),
-        ("secondarypositionerscanstartangle", &tags::SecondaryPositionerScanStartAngle),
-        ("referencedpathindex", &tags::ReferencedPathIndex),
-        ("lightsource", &tags::LightSource),
-        ("roivolume", &tags::ROIVolume),
-        ("containercomponentwidth", &tags::ContainerComponentWidth),
-        ("stationname", &tags::StationName),
-        ("respiratorycycleposition", &tags::RespiratoryCyclePosition),
-        ("selectorutvalue", &tags::SelectorUTValue),
-        ("zonalmapnumberformat", &tags::ZonalMapNumberFormat),
-        ("impedancemeasurementfrequency", &tags::ImpedanceMeasurementFrequency),
-        ("subjectiverefractionrighteyesequence", &tags::SubjectiveRefractionRightEyeSequence),
-        ("extendedoffsettable", &tags::ExtendedOffsetTable),
-        ("ioncontrolpointdeliverysequence", &tags::IonControlPointDeliverySequence),
-        ("contrastbolusagent", &tags::ContrastBolusAgent),
-        ("stationaetitle", &tags::StationAETitle),
-        ("xdsstoragesequence", &tags::XDSStorageSequence),
-        ("containercomponentmaterial", &tags::ContainerComponentMaterial),
-        ("refractiveparametersusedonpatientsequence", &tags::RefractiveParametersUsedonPatientSequence),
-        ("channelsensitivity", &tags::ChannelSensitivity),
-        ("patienttreatmentpreparationprocedureparametersequence", &tags::PatientTreatmentPreparationProcedureParameterSequence),
-        ("horizontalalignment", &tags::HorizontalAlignment),
-        ("referencedrefractivemeasurementssequence", &tags::ReferencedRefractiveMeasurementsSequence),
-        ("mprviewwidth", &tags::MPRViewWidth),
-        ("dosesummationtype", &tags::DoseSummationType),
-        ("specimenidentifier", &tags::SpecimenIdentifier),
-        ("patientsupportdevicessequence", &tags::PatientSupportDevicesSequence),
-        ("generalizeddefectsensitivitydeviationalgorithmsequence", &tags::GeneralizedDefectSensitivityDeviationAlgorithmSequence),
-        ("hl7structureddocumentreferencesequence", &tags::HL7StructuredDocumentReferenceSequence),
-        ("keratometricaxis", &tags::KeratometricAxis),
+        ("selectorsequencepointeritems", &tags::SelectorSequencePointerItems),
+        ("objectsoundspeed", &tags::ObjectSoundSpeed),
+        ("probecenterlocationx", &tags::ProbeCenterLocationX),
+        ("chemicalshiftmaximumintegrationlimitinhz", &tags::ChemicalShiftMaximumIntegrationLimitinHz),
+        ("lateralspreadingdevicesequence", &tags::LateralSpreadingDeviceSequence),
+        ("linesequence", &tags::LineSequence),
+        ("variablecoefficientssddn", &tags::VariableCoefficientsSDDN),
+        ("evaluatorname", &tags::EvaluatorName),
+        ("issuerofaccessionnumbersequence", &tags::IssuerofAccessionNumberSequence),
+        ("compensatortransmissiondata", &tags::CompensatorTransmissionData),
+        ("physicianapprovinginterpretation", &tags::PhysicianApprovingInterpretation),
+        ("patientsinsuranceplancodesequence", &tags::PatientsInsurancePlanCodeSequence),
+        ("zonalmapformat", &tags::ZonalMapFormat),
+        ("chemicalshiftreference", &tags::ChemicalShiftReference),
+        ("patientstelecominformation", &tags::PatientsTelecomInformation),
+        ("referencedsourcenumber", &tags::ReferencedSourceNumber),
+        ("isocentertolateralspreadingdevicedistance", &tags::IsocentertoLateralSpreadingDeviceDistance),
+        ("treatmentterminationdescription", &tags::TreatmentTerminationDescription),
+        ("coefficientssdhn", &tags::CoefficientsSDHN),
+        ("shutterleftverticaledge", &tags::ShutterLeftVerticalEdge),
+        ("numberoftablecolumns", &tags::NumberofTableColumns),
+        ("intervalsacquired", &tags::IntervalsAcquired),
+        ("outlineupperhorizontaledge", &tags::OutlineUpperHorizontalEdge),
+        ("systemstatuscomment", &tags::SystemStatusComment),
+        ("imagehorizontalflip", &tags::ImageHorizontalFlip),
+        ("protocolelementnumber", &tags::ProtocolElementNumber),
+        ("referencedlateralspreadingdevicenumber", &tags::ReferencedLateralSpreadingDeviceNumber),
+        ("spectrallyselectedsuppression", &tags::SpectrallySelectedSuppression),
+        ("recommendedanimationrate", &tags::RecommendedAnimationRate),
+        ("referencedtargetluminancecharacteristicsid", &tags::ReferencedTargetLuminanceCharacteristicsID),
+        ("applicatoropening", &tags::ApplicatorOpening),
+        ("recordedlateralspreadingdevicesequence", &tags::RecordedLateralSpreadingDeviceSequence),
+        ("breastimplantpresent", &tags::BreastImplantPresent),
+        ("shieldingdevicetype", &tags::ShieldingDeviceType),
+        ("referencedradiationgenerationmodeindex", &tags::ReferencedRadiationGenerationModeIndex),
+        ("devicealternateidentifiertype", &tags::DeviceAlternateIdentifierType),
+        ("textcomments", &tags::TextComments),
+        ("localizeddeviationprobabilitysequence", &tags::LocalizedDeviationProbabilitySequence),
+        ("referencedsopinstanceuidinfile", &tags::ReferencedSOPInstanceUIDinFile),
+        ("pixelorigininterpretation", &tags::PixelOriginInterpretation),
+        ("mrspectroscopyacquisitiontype", &tags::MRSpectroscopyAcquisitionType),
+        ("waveformbitsallocated", &tags::WaveformBitsAllocated),
+        ("imagequalityindicatortype", &tags::ImageQualityIndicatorType),
+        ("radiationdosevaluesparameterssequence", &tags::RadiationDoseValuesParametersSequence),
+        ("volumetotransducerrelationship", &tags::VolumetoTransducerRelationship),
+        ("blockedgedata", &tags::BlockEdgeData),
+        ("intendedrecipientsofresultsidentificationsequence", &tags::IntendedRecipientsofResultsIdentificationSequence),
+        ("humanperformersname", &tags::HumanPerformersName),
+        ("reportingpriority", &tags::ReportingPriority),
         ("equivalentcdadocumentsequence", &tags::EquivalentCDADocumentSequence),
-        ("abortflag", &tags::AbortFlag),
-        ("evaluatorsequence", &tags::EvaluatorSequence),
-        ("rtradiationsalvagerecordcontrolpointsequence", &tags::RTRadiationSalvageRecordControlPointSequence),
-        ("devicevolume", &tags::DeviceVolume),
-        ("repairid", &tags::RepairID),
-        ("procedurestepprogressdescription", &tags::ProcedureStepProgressDescription),
-        ("secondaryreviewdate", &tags::SecondaryReviewDate),
-        ("qaresultssequence", &tags::QAResultsSequence),
-        ("organatriskfullvolumedose", &tags::OrganatRiskFullvolumeDose),
-        ("whitepointflag", &tags::WhitePointFlag),
-        ("scanspottuneid", &tags::ScanSpotTuneID),
-        ("histogrambinwidth", &tags::HistogramBinWidth),
-        ("rtradiationusage", &tags::RTRadiationUsage),
-        ("subtaskworkitemcodesequence", &tags::SubtaskWorkitemCodeSequence),
-        ("detectormanufacturername", &tags::DetectorManufacturerName),
-        ("deliveredsecondarymeterset", &tags::DeliveredSecondaryMeterset),
-        ("leafjawpositions", &tags::LeafJawPositions),
-        ("insuranceplanidentification", &tags::InsurancePlanIdentification),
-        ("rtbeamlimitingdeviceoffset", &tags::RTBeamLimitingDeviceOffset),
-        ("wedgetimedelay", &tags::WedgeTimeDelay),
-        ("hangingprotocoldefinitionsequence", &tags::HangingProtocolDefinitionSequence),
-        ("maskframenumbers", &tags::MaskFrameNumbers),
-        ("flipangle", &tags::FlipAngle),
-        ("dimensionindexsequence", &tags::DimensionIndexSequence),
-        ("participationdatetime", &tags::ParticipationDateTime),
-        ("synchronizationtrigger", &tags::SynchronizationTrigger),
-        ("cornealsizesequence", &tags::CornealSizeSequence),
-        ("distributionname", &tags::DistributionName),
-        ("columnangulation", &tags::ColumnAngulation),
-        ("ordercallbacktelecominformation", &tags::OrderCallbackTelecomInformation),
-        ("parametricmapframetypesequence", &tags::ParametricMapFrameTypeSequence),
-        ("tmlinepositiony0", &tags::TMLinePositionY0),
-        ("numberofhorizontalpixels", &tags::NumberofHorizontalPixels),
-        ("segmentedgreenpalettecolorlookuptabledata", &tags::SegmentedGreenPaletteColorLookupTableData),
-        ("numberoffractionsdelivered", &tags::NumberofFractionsDelivered),
-        ("documentclasscodesequence", &tags::DocumentClassCodeSequence),
-        ("doubleexposuremeterset_trial", &tags::DoubleExposureMeterset_Trial),
-        ("shadowoffsety", &tags::ShadowOffsetY),
-        ("assertiondatetime", &tags::AssertionDateTime),
-        ("transformationmethodcodesequence", &tags::TransformationMethodCodeSequence),
-        ("patientequipmentrelationshipcodesequence", &tags::PatientEquipmentRelationshipCodeSequence),
-        ("visualfieldtestpointsequence", &tags::VisualFieldTestPointSequence),
-        ("rtvmetainformationversion", &tags::RTVMetaInformationVersion),
-        ("surfaceprocessingratio", &tags::SurfaceProcessingRatio),
-        ("numberofverticalpixels", &tags::NumberofVerticalPixels),
-        ("fontnametype", &tags::FontNameType),
-        ("scheduleddischargedate", &tags::ScheduledDischargeDate),
-        ("relativeimagepositioncodesequence", &tags::RelativeImagePositionCodeSequence),
-        ("angularposition", &tags::AngularPosition),
-        ("brachytreatmenttype", &tags::BrachyTreatmentType),
-        ("intensifiersize", &tags::IntensifierSize),
-        ("subscriptionliststatus", &tags::SubscriptionListStatus),
-        ("performedstationaetitle", &tags::PerformedStationAETitle),
-        ("roboticpathnodesetcodesequence", &tags::RoboticPathNodeSetCodeSequence),
-        ("specimendescriptionsequence_trial", &tags::SpecimenDescriptionSequence_Trial),
-        ("angularviewvector", &tags::AngularViewVector),
-        ("countssource", &tags::CountsSource),
-        ("ownerid", &tags::OwnerID),
-        ("scanspotpositionmap", &tags::ScanSpotPositionMap),
+        ("sourceapplicatortype", &tags::SourceApplicatorType),
+        ("geneticmodificationssequence", &tags::GeneticModificationsSequence),
+        ("physicalunitsydirection", &tags::PhysicalUnitsYDirection),
+        ("derivationimplanttemplatesequence", &tags::DerivationImplantTemplateSequence),
+        ("startacquisitiondatetime", &tags::StartAcquisitionDateTime),
+        ("interpretationstatusid", &tags::InterpretationStatusID),
+        ("setupreferencedescription", &tags::SetupReferenceDescription),
+        ("volumebasedcalculationtechnique", &tags::VolumeBasedCalculationTechnique),
+        ("lateralspreadingdevicetype", &tags::LateralSpreadingDeviceType),
+        ("depthspatialresolution", &tags::DepthSpatialResolution),
+        ("tableofpixelvalues", &tags::TableofPixelValues),
+        ("beamdosepointsourcetoexternalcontourdistance", &tags::BeamDosePointSourcetoExternalContourDistance),
         ("beamholdtransitiontriggersource", &tags::BeamHoldTransitionTriggerSource),
-        ("referencedpresentationstatesequence", &tags::ReferencedPresentationStateSequence),
-        ("dopplercorrectionangle", &tags::DopplerCorrectionAngle),
-        ("zonalmaplocation", &tags::ZonalMapLocation),
-        ("displayedzvalue", &tags::DisplayedZValue),
-        ("channeloffset", &tags::ChannelOffset),
-        ("includesinformation", &tags::IncludesInformation),
-        ("selectorasvalue", &tags::SelectorASValue),
-        ("imagevolumegeometry", &tags::ImageVolumeGeometry),
-        ("rangemodulatornumber", &tags::RangeModulatorNumber),
-        ("taganglefirstaxis", &tags::TagAngleFirstAxis),
-        ("treatmentdate", &tags::TreatmentDate),
-        ("fractiongroupnumber", &tags::FractionGroupNumber),
-        ("horizontalprismpower", &tags::HorizontalPrismPower),
-        ("referencedobjectobservationclass_trial", &tags::ReferencedObjectObservationClass_Trial),
-        ("doseunits", &tags::DoseUnits),
-        ("trackingalgorithmidentificationsequence", &tags::TrackingAlgorithmIdentificationSequence),
-        ("rectificationtype", &tags::RectificationType),
-        ("radiationsourcecoordinatesystemyawangle", &tags::RadiationSourceCoordinateSystemYawAngle),
-        ("primitivepointindexlist", &tags::PrimitivePointIndexList),
-        ("registrationsequence", &tags::RegistrationSequence),
-        ("operatingmodetype", &tags::OperatingModeType),
-        ("contrastframeaveraging", &tags::ContrastFrameAveraging),
-        ("detailsofcoefficients", &tags::DetailsofCoefficients),
-        ("procedurestepcancellationdatetime", &tags::ProcedureStepCancellationDateTime),
-        ("frameofreferencetodisplayedcoordinatesystemtransformationmatrix", &tags::FrameofReferencetoDisplayedCoordinateSystemTransformationMatrix),
-        ("mrimageframetypesequence", &tags::MRImageFrameTypeSequence),
-        ("sourceenddatetime", &tags::SourceEndDateTime),
-        ("rectilinearphaseencodereordering", &tags::RectilinearPhaseEncodeReordering),
-        ("graphiclayersequence", &tags::GraphicLayerSequence),
-        ("referenceddosereferenceuid", &tags::ReferencedDoseReferenceUID),
-        ("configurationname", &tags::ConfigurationName),
-        ("isocenterreferencesystemsequence", &tags::IsocenterReferenceSystemSequence),
-        ("patientsetuplabel", &tags::PatientSetupLabel),
-        ("studyreaddate", &tags::StudyReadDate),
-        ("referencedfirstframesequence", &tags::ReferencedFirstFrameSequence),
-        ("scenetype", &tags::SceneType),
-        ("moveoriginatorapplicationentitytitle", &tags::MoveOriginatorApplicationEntityTitle),
-        ("maximumcumulativemetersetexposure", &tags::MaximumCumulativeMetersetExposure),
-        ("compensatorsequence", &tags::CompensatorSequence),
-        ("treatmentpositiongroupsequence", &tags::TreatmentPositionGroupSequence),
-        ("componentmanufacturingprocedure", &tags::ComponentManufacturingProcedure),
-        ("scheduledprocessingparameterssequence", &tags::ScheduledProcessingParametersSequence),
-        ("dvhroicontributiontype", &tags::DVHROIContributionType),
-        ("referringphysicianidentificationsequence", &tags::ReferringPhysicianIdentificationSequence),
-        ("dvhsequence", &tags::DVHSequence),
-        ("excitationspectralwidth", &tags::ExcitationSpectralWidth),
-        ("velocityencodingmaximumvalue", &tags::VelocityEncodingMaximumValue),
-        ("cassetteid", &tags::CassetteID),
-        ("otherpatientidssequence", &tags::OtherPatientIDsSequence),
-        ("contourslabthickness", &tags::ContourSlabThickness),
-        ("addothersequence", &tags::AddOtherSequence),
-        ("bolusdescription", &tags::BolusDescription),
-        ("lateralspreadingdevicesequence", &tags::LateralSpreadingDeviceSequence),
-        ("rttreatmentphaseuid", &tags::RTTreatmentPhaseUID),
-        ("mprtoplefthandcorner", &tags::MPRTopLeftHandCorner),
-        ("correctedlocalizeddeviationfromnormalcalculated", &tags::CorrectedLocalizedDeviationFromNormalCalculated),
-        ("activeaperture", &tags::ActiveAperture),
-        ("blockorientation", &tags::BlockOrientation),
-        ("anatomicstructurespaceorregionmodifiercodesequence_trial", &tags::AnatomicStructureSpaceOrRegionModifierCodeSequence_Trial),
-        ("distancesourcetoisocenter", &tags::DistanceSourcetoIsocenter),
-        ("detectorpositionsequence", &tags::DetectorPositionSequence),
-        ("framelabel", &tags::FrameLabel),
-        ("couplingtechnique", &tags::CouplingTechnique),
-        ("xrayimagereceptorangle", &tags::XRayImageReceptorAngle),
-        ("commentsonthescheduledprocedurestep", &tags::CommentsontheScheduledProcedureStep),
-        ("imagesetnumber", &tags::ImageSetNumber),
-        ("regionlocationmaxy1", &tags::RegionLocationMaxY1),
-        ("scheduledworkitemcodesequence", &tags::ScheduledWorkitemCodeSequence),
-        ("manufacturer", &tags::Manufacturer),
-        ("scheduledadmissiondate", &tags::ScheduledAdmissionDate),
-        ("devicediameterunits", &tags::DeviceDiameterUnits),
-        ("graphictype", &tags::GraphicType),
-        ("ascanrate", &tags::AscanRate),
-        ("snoutsequence", &tags::SnoutSequence),
-        ("patientsreligiouspreference", &tags::PatientsReligiousPreference),
-        ("calculateddosereferencesequence", &tags::CalculatedDoseReferenceSequence),
-        ("conceptualvolumecombinationdescription", &tags::ConceptualVolumeCombinationDescription),
-        ("measuredbeamdimensionb", &tags::MeasuredBeamDimensionB),
-        ("detectorgeometry", &tags::DetectorGeometry),
-        ("calculateddosereferencenumber", &tags::CalculatedDoseReferenceNumber),
-        ("sourcewaveformsequence", &tags::SourceWaveformSequence),
-        ("predecessorprotocolsequence", &tags::PredecessorProtocolSequence),
-        ("rectifiersmoothing", &tags::RectifierSmoothing),
-        ("responsiblegroupcodesequence", &tags::ResponsibleGroupCodeSequence),
-        ("minimumstoredvaluemapped", &tags::MinimumStoredValueMapped),
-        ("location", &tags::Location),
+        ("imagereceptorpositionsequence", &tags::ImageReceptorPositionSequence),
+        ("reformattingoperationinitialviewdirection", &tags::ReformattingOperationInitialViewDirection),
+        ("tag_2ddegreeoffreedomsequence", &tags::Tag_2DDegreeofFreedomSequence),
+        ("actualhumanperformerssequence", &tags::ActualHumanPerformersSequence),
+        ("presentationcreationtime", &tags::PresentationCreationTime),
+        ("sourcereconstructionprotocolelementnumber", &tags::SourceReconstructionProtocolElementNumber),
+        ("numberofviewsinstage", &tags::NumberofViewsinStage),
+        ("fiducialsequence", &tags::FiducialSequence),
+        ("defaultprinterresolutionid", &tags::DefaultPrinterResolutionID),
+        ("imageboxsynchronizationsequence", &tags::ImageBoxSynchronizationSequence),
+        ("abortflag", &tags::AbortFlag),
+        ("contrastbolusagentnumber", &tags::ContrastBolusAgentNumber),
+        ("frameextractionsequence", &tags::FrameExtractionSequence),
+        ("timeofflightinformationused", &tags::TimeofFlightInformationUsed),
+        ("inplanephaseencodingdirection", &tags::InplanePhaseEncodingDirection),
+        ("deliverymaximumdose", &tags::DeliveryMaximumDose),
+        ("blocknumberofpoints", &tags::BlockNumberofPoints),
+        ("resultsnormalssequence", &tags::ResultsNormalsSequence),
+        ("dactype", &tags::DACType),
+        ("targetuid", &tags::TargetUID),
+        ("samplerate", &tags::SampleRate),
+        ("shieldingdevicelabel", &tags::ShieldingDeviceLabel),
         ("modifiedimagedescription", &tags::ModifiedImageDescription),
-        ("rtbeamlimitingdevicedistaldistance", &tags::RTBeamLimitingDeviceDistalDistance),
-        ("performedprocedurestependtime", &tags::PerformedProcedureStepEndTime),
-        ("degreeoffreedomtype", &tags::DegreeofFreedomType),
-        ("ctdiphantomtypecodesequence", &tags::CTDIPhantomTypeCodeSequence),
-        ("purposeofreferencecodesequence", &tags::PurposeofReferenceCodeSequence),
-        ("sourceconceptualvolumeuid", &tags::SourceConceptualVolumeUID),
-        ("gridaspectratio", &tags::GridAspectRatio),
+        ("flashmode", &tags::FlashMode),
+        ("filterbeampathlengthminimum", &tags::FilterBeamPathLengthMinimum),
+        ("referencedprintjobsequencepullstoredprint", &tags::ReferencedPrintJobSequencePullStoredPrint),
+        ("receivingae", &tags::ReceivingAE),
+        ("tabletopverticaladjustedposition", &tags::TableTopVerticalAdjustedPosition),
         ("patientsupportpositiontoleranceorderindex", &tags::PatientSupportPositionToleranceOrderIndex),
-        ("xrayreceptortype", &tags::XRayReceptorType),
-        ("conceptcodesequence", &tags::ConceptCodeSequence),
-        ("componentshape", &tags::ComponentShape),
-        ("respiratorytriggertype", &tags::RespiratoryTriggerType),
-        ("coordinatesystemaxisnumber", &tags::CoordinateSystemAxisNumber),
-        ("renderedimagereferencesequence", &tags::RenderedImageReferenceSequence),
-        ("seriesinstanceuid", &tags::SeriesInstanceUID),
-        ("blockname", &tags::BlockName),
-        ("radiusofcircularshutter", &tags::RadiusofCircularShutter),
-        ("simpleframelist", &tags::SimpleFrameList),
-        ("pertinentsopclassesinseries", &tags::PertinentSOPClassesinSeries),
-        ("falsepositivesestimateflag", &tags::FalsePositivesEstimateFlag),
-        ("boundingboxcrop", &tags::BoundingBoxCrop),
-        ("referencedimplanttemplategroupmemberid", &tags::ReferencedImplantTemplateGroupMemberID),
-        ("asltechniquedescription", &tags::ASLTechniqueDescription),
-        ("applicatorshapereferencedroinumber", &tags::ApplicatorShapeReferencedROINumber),
-        ("equipmentmodality", &tags::EquipmentModality),
-        ("plannedverificationimagesequence", &tags::PlannedVerificationImageSequence),
-        ("numberoffractionsplanned", &tags::NumberofFractionsPlanned),
-        ("numberofboluses", &tags::NumberofBoluses),
-        ("patientsupportpositiondeviceparametersequence", &tags::PatientSupportPositionDeviceParameterSequence),
-        ("stereopairssequence", &tags::StereoPairsSequence),
-        ("performedprocedurecodesequence", &tags::PerformedProcedureCodeSequence),
-        ("cassetteorientation", &tags::CassetteOrientation),
-        ("patientstelecominformation", &tags::PatientsTelecomInformation),
-        ("specifiedpulserepetitioninterval", &tags::SpecifiedPulseRepetitionInterval),
-        ("onaxisbackgroundanatomicstructurecodesequence_trial", &tags::OnAxisBackgroundAnatomicStructureCodeSequence_Trial),
-        ("lateralspreadingdevicetype", &tags::LateralSpreadingDeviceType),
-        ("octbscananalysisacquisitionparameterssequence", &tags::OCTBscanAnalysisAcquisitionParametersSequence),
-        ("thresholdvalue", &tags::ThresholdValue),
-        ("radiationsourcecoordinatesystempitchangle", &tags::RadiationSourceCoordinateSystemPitchAngle),
-        ("inputsequencepositionindex", &tags::InputSequencePositionIndex),
-        ("deviceid", &tags::DeviceID),
-        ("velocityencodingminimumvalue", &tags::VelocityEncodingMinimumValue),
-        ("threatcategory", &tags::ThreatCategory),
-        ("omittedbeamtasksequence", &tags::OmittedBeamTaskSequence),
-        ("applicationversion", &tags::ApplicationVersion),
-        ("subjectlocation", &tags::SubjectLocation),
-        ("requestedmediaapplicationprofile", &tags::RequestedMediaApplicationProfile),
-        ("decimalvisualacuity", &tags::DecimalVisualAcuity),
-        ("protocolelementnumber", &tags::ProtocolElementNumber),
-        ("tracksetsequence", &tags::TrackSetSequence),
-        ("colorimageprintingflag", &tags::ColorImagePrintingFlag),
-        ("applicatorgeometrysequence", &tags::ApplicatorGeometrySequence),
-        ("relationshiptypecodesequence_trial", &tags::RelationshipTypeCodeSequence_Trial),
-        ("sensortemperature", &tags::SensorTemperature),
-        ("referencelocationlabel", &tags::ReferenceLocationLabel),
-        ("compensatorproximalthicknessmap", &tags::CompensatorProximalThicknessMap),
-        ("sourcereconstructionprotocolelementnumber", &tags::SourceReconstructionProtocolElementNumber),
-        ("devicemotioncontrolsequence", &tags::DeviceMotionControlSequence),
-        ("intermarkerdistance", &tags::InterMarkerDistance),
-        ("testpointnormalssequence", &tags::TestPointNormalsSequence),
-        ("opticalpathsequence", &tags::OpticalPathSequence),
-        ("aslcrusherflag", &tags::ASLCrusherFlag),
-        ("waveformpaddingvalue", &tags::WaveformPaddingValue),
-        ("luminancecharacteristicsid", &tags::LuminanceCharacteristicsID),
-        ("surgicallyinducedastigmatismsequence", &tags::SurgicallyInducedAstigmatismSequence),
-        ("rtroiidentificationcodesequence", &tags::RTROIIdentificationCodeSequence),
-        ("recordedrangeshiftersequence", &tags::RecordedRangeShifterSequence),
-        ("radiusofcurvature", &tags::RadiusofCurvature),
-        ("conceptualvolumecombinationexpression", &tags::ConceptualVolumeCombinationExpression),
-        ("pagenumbervector", &tags::PageNumberVector),
-        ("mediastoragesopinstanceuid", &tags::MediaStorageSOPInstanceUID),
-        ("temporalrangetype", &tags::TemporalRangeType),
-        ("referencedrealworldvaluemappinginstancesequence", &tags::ReferencedRealWorldValueMappingInstanceSequence),
-        ("maximumcornealcurvaturesequence", &tags::MaximumCornealCurvatureSequence),
-        ("structureddisplaybackgroundcielabvalue", &tags::StructuredDisplayBackgroundCIELabValue),
-        ("manifold", &tags::Manifold),
-        ("deliverednumberofpulses", &tags::DeliveredNumberofPulses),
-        ("radiationtypecodesequence", &tags::RadiationTypeCodeSequence),
-        ("residualsyringecounts", &tags::ResidualSyringeCounts),
-        ("imageboxscrolldirection", &tags::ImageBoxScrollDirection),
-        ("minimumsensitivityvalue", &tags::MinimumSensitivityValue),
-        ("compressionsequence", &tags::CompressionSequence),
-        ("patientssexneutered", &tags::PatientsSexNeutered),
-        ("selectoruvvalue", &tags::SelectorUVValue),
-        ("startcumulativetimeweight", &tags::StartCumulativeTimeWeight),
-        ("aliaseddatatype", &tags::AliasedDataType),
-        ("spectroscopydata", &tags::SpectroscopyData),
-        ("octzoffsetcorrection", &tags::OCTZOffsetCorrection),
-        ("measureddosetype", &tags::MeasuredDoseType),
-        ("nominalrangemodulatedregiondepths", &tags::NominalRangeModulatedRegionDepths),
-        ("excitationenergy", &tags::ExcitationEnergy),
-        ("boundingpolygon", &tags::BoundingPolygon),
-        ("anteriorchamberdepth", &tags::AnteriorChamberDepth),
-        ("startcumulativemeterset", &tags::StartCumulativeMeterset),
-        ("gatesettingssequence", &tags::GateSettingsSequence),
-        ("tracksequence", &tags::TrackSequence),
-        ("interlockoriginatingdevicesequence", &tags::InterlockOriginatingDeviceSequence),
-        ("ophthalmicaxiallengthvelocity", &tags::OphthalmicAxialLengthVelocity),
-        ("croppingspecificationnumber", &tags::CroppingSpecificationNumber),
-        ("beamname", &tags::BeamName),
-        ("verticalprismpower", &tags::VerticalPrismPower),
-        ("requestedprocedurecomments", &tags::RequestedProcedureComments),
-        ("allergies", &tags::Allergies),
-        ("scheduledstudystopdate", &tags::ScheduledStudyStopDate),
-        ("numberofmappoints", &tags::NumberofMapPoints),
-        ("timeslotnumber", &tags::TimeSlotNumber),
-        ("sensingmethod", &tags::SensingMethod),
-        ("pixelvaluemappingcodesequence", &tags::PixelValueMappingCodeSequence),
-        ("lensserialnumber", &tags::LensSerialNumber),
-        ("personname", &tags::PersonName),
+        ("modifiedattributessequence", &tags::ModifiedAttributesSequence),
+        ("exposedarea", &tags::ExposedArea),
+        ("referenceddefineddeviceindex", &tags::ReferencedDefinedDeviceIndex),
+        ("continuationstartmeterset", &tags::ContinuationStartMeterset),
+        ("detectorgeometrysequence", &tags::DetectorGeometrySequence),
+        ("targetexposureindex", &tags::TargetExposureIndex),
+        ("timeseriesblending", &tags::TimeSeriesBlending),
+        ("zonalmapnumberformat", &tags::ZonalMapNumberFormat),
+        ("energyweightingfactor", &tags::EnergyWeightingFactor),
+        ("observercontextflag_trial", &tags::ObserverContextFlag_Trial),
+        ("averagebeamdosepointdepth", &tags::AverageBeamDosePointDepth),
+        ("numberofpriorsreferenced", &tags::NumberofPriorsReferenced),
+        ("mrmetabolitemapsequence", &tags::MRMetaboliteMapSequence),
+        ("zoomfactor", &tags::ZoomFactor),
+        ("attributeoccurrenceprivatecreator", &tags::AttributeOccurrencePrivateCreator),
+        ("colorspace", &tags::ColorSpace),
+        ("gpstrack", &tags::GPSTrack),
+        ("referencedcolorpaletteinstanceuid", &tags::ReferencedColorPaletteInstanceUID),
+        ("tableofparametervalues", &tags::TableofParameterValues),
+        ("selectorobvalue", &tags::SelectorOBValue),
+        ("compensatorrows", &tags::CompensatorRows),
+        ("anteriorchamberdepthsequence", &tags::AnteriorChamberDepthSequence),
+        ("recordedchannelsequence", &tags::RecordedChannelSequence),
+        ("multienergyctcharacteristicssequence", &tags::MultienergyCTCharacteristicsSequence),
+        ("totalgain", &tags::TotalGain),
+        ("encapsulateddocument", &tags::EncapsulatedDocument),
+        ("doublefloatpixelpaddingrangelimit", &tags::DoubleFloatPixelPaddingRangeLimit),
+        ("wholebodytechnique", &tags::WholeBodyTechnique),
+        ("respiratoryintervaltime", &tags::RespiratoryIntervalTime),
+        ("referencedfindingsgroupuid_trial", &tags::ReferencedFindingsGroupUID_Trial),
+        ("inversiontimes", &tags::InversionTimes),
+        ("studytime", &tags::StudyTime),
         ("specificcharactersetoffilesetdescriptorfile", &tags::SpecificCharacterSetofFilesetDescriptorFile),
-        ("findingsgrouprecordingdate_trial", &tags::FindingsGroupRecordingDate_Trial),
-        ("dimensionorganizationsequence", &tags::DimensionOrganizationSequence),
-        ("volumetriccurvepoints", &tags::VolumetricCurvePoints),
-        ("planninglandmarkidentificationcodesequence", &tags::PlanningLandmarkIdentificationCodeSequence),
-        ("zeffective", &tags::ZEffective),
-        ("imageboxtilehorizontaldimension", &tags::ImageBoxTileHorizontalDimension),
-        ("materialthickness", &tags::MaterialThickness),
-        ("hardcopycreationdeviceid", &tags::HardcopyCreationDeviceID),
-        ("projectionimagingacquisitionparametersequence", &tags::ProjectionImagingAcquisitionParameterSequence),
+        ("acquisitioninitiationsequence", &tags::AcquisitionInitiationSequence),
+        ("wedgematerial", &tags::WedgeMaterial),
+        ("interventiondescription", &tags::InterventionDescription),
+        ("sourceframeofreferenceuid", &tags::SourceFrameofReferenceUID),
+        ("viewposition", &tags::ViewPosition),
+        ("selectorattributename", &tags::SelectorAttributeName),
+        ("spherepower", &tags::SpherePower),
+        ("modelspecificationsequence", &tags::ModelSpecificationSequence),
+        ("dosimetricobjectiveweight", &tags::DosimetricObjectiveWeight),
+        ("measurementpatterncodesequence", &tags::MeasurementPatternCodeSequence),
+        ("segmentationtype", &tags::SegmentationType),
+        ("segmentedrtaccessorydevicesequence", &tags::SegmentedRTAccessoryDeviceSequence),
+        ("blockdata", &tags::BlockData),
+        ("cardiacframingtype", &tags::CardiacFramingType),
+        ("applicatorsequence", &tags::ApplicatorSequence),
+        ("rangemodulatorgatingstopwaterequivalentthickness", &tags::RangeModulatorGatingStopWaterEquivalentThickness),
+        ("wedgeoffsetz", &tags::WedgeOffsetZ),
+        ("tablelateralincrement", &tags::TableLateralIncrement),
+        ("treatmentsessionapplicationsetupsequence", &tags::TreatmentSessionApplicationSetupSequence),
+        ("pixelbandwidth", &tags::PixelBandwidth),
+        ("channelsourcesequence", &tags::ChannelSourceSequence),
+        ("tableposition", &tags::TablePosition),
+        ("tabletype", &tags::TableType),
+        ("dvhmaximumdose", &tags::DVHMaximumDose),
+        ("iolpowerforexacttargetrefraction", &tags::IOLPowerForExactTargetRefraction),
+        ("lutfunction", &tags::LUTFunction),
+        ("platetype", &tags::PlateType),
+        ("tablerownumber", &tags::TableRowNumber),
+        ("requestedsopinstanceuid", &tags::RequestedSOPInstanceUID),
+        ("attributeitemselector", &tags::AttributeItemSelector),
+        ("substanceadministrationnotes", &tags::SubstanceAdministrationNotes),
+        ("address_trial", &tags::Address_Trial),
+        ("multienergyctprocessingsequence", &tags::MultienergyCTProcessingSequence),
+        ("physiciansreadingstudyidentificationsequence", &tags::PhysiciansReadingStudyIdentificationSequence),
+        ("imageboxcontentsequence", &tags::ImageBoxContentSequence),
+        ("parametersspecificationsequence", &tags::ParametersSpecificationSequence),
+        ("shutteroverlaygroup", &tags::ShutterOverlayGroup),
+        ("detectorelementphysicalsize", &tags::DetectorElementPhysicalSize),
+        ("surfacemodeldescriptionsequence", &tags::SurfaceModelDescriptionSequence),
+        ("widefieldophthalmicphotographythresholdqualityrating", &tags::WideFieldOphthalmicPhotographyThresholdQualityRating),
         ("scheduledproceduresteplocation", &tags::ScheduledProcedureStepLocation),
-        ("tabletoppitchangletolerance", &tags::TableTopPitchAngleTolerance),
-        ("procedurecodesequence", &tags::ProcedureCodeSequence),
-        ("spectrallyselectedsuppression", &tags::SpectrallySelectedSuppression),
-        ("referencedframeofreferencesequence", &tags::ReferencedFrameofReferenceSequence),
-        ("bold", &tags::Bold),
-        ("fixationlightpolarangletolerance", &tags::FixationLightPolarAngleTolerance),
-        ("acquisitiondeviceprocessingcode", &tags::AcquisitionDeviceProcessingCode),
-        ("reconstructionendlocationsequence", &tags::ReconstructionEndLocationSequence),
-        ("fractionpatternsequence", &tags::FractionPatternSequence),
-        ("deliveredchanneltotaltime", &tags::DeliveredChannelTotalTime),
-        ("anchorpointannotationunits", &tags::AnchorPointAnnotationUnits),
-        ("exposuretimeinseconds", &tags::ExposureTimeinSeconds),
-        ("treatmentsummarymeasureddosereferencesequence", &tags::TreatmentSummaryMeasuredDoseReferenceSequence),
-        ("planninglandmarkpointsequence", &tags::PlanningLandmarkPointSequence),
-        ("referencedptosequence", &tags::ReferencedPTOSequence),
-        ("customrendered", &tags::CustomRendered),
-        ("rtvsourceidentifier", &tags::RTVSourceIdentifier),
-        ("spectroscopyacquisitionphaserows", &tags::SpectroscopyAcquisitionPhaseRows),
-        ("inputinformationsequence", &tags::InputInformationSequence),
-        ("annotationpropertycategorycodesequence", &tags::AnnotationPropertyCategoryCodeSequence),
-        ("modalitylutsequence", &tags::ModalityLUTSequence),
-        ("offendingelement", &tags::OffendingElement),
-        ("histogramfirstbinvalue", &tags::HistogramFirstBinValue),
-        ("pixelmeasuressequence", &tags::PixelMeasuresSequence),
-        ("sopauthorizationdatetime", &tags::SOPAuthorizationDateTime),
-        ("exposurecontrolmode", &tags::ExposureControlMode),
-        ("referencedparentrtprescriptionindex", &tags::ReferencedParentRTPrescriptionIndex),
-        ("admittingtime", &tags::AdmittingTime),
-        ("blendinginputnumber", &tags::BlendingInputNumber),
-        ("multicoilconfiguration", &tags::MultiCoilConfiguration),
-        ("containercomponenttypecodesequence", &tags::ContainerComponentTypeCodeSequence),
-        ("imagingaperturespecificationtype", &tags::ImagingApertureSpecificationType),
-        ("collimatorrightverticaledge", &tags::CollimatorRightVerticalEdge),
-        ("largeredpalettecolorlookuptabledata", &tags::LargeRedPaletteColorLookupTableData),
-        ("controlpointindex", &tags::ControlPointIndex),
-        ("overridereason", &tags::OverrideReason),
-        ("sourcetobeamlimitingdevicedistance", &tags::SourcetoBeamLimitingDeviceDistance),
-        ("channelshieldnominalthickness", &tags::ChannelShieldNominalThickness),
-        ("transducerresponsesequence", &tags::TransducerResponseSequence),
-        ("totalcollimationwidth", &tags::TotalCollimationWidth),
-        ("requestpriority", &tags::RequestPriority),
-        ("tomoangle", &tags::TomoAngle),
-        ("enhancedrtbeamlimitingdevicesequence", &tags::EnhancedRTBeamLimitingDeviceSequence),
-        ("containerdescription", &tags::ContainerDescription),
-        ("recordinuseflag", &tags::RecordInuseFlag),
-        ("waveformpresentationgroupsequence", &tags::WaveformPresentationGroupSequence),
-        ("cornealvertexlocation", &tags::CornealVertexLocation),
-        ("reportstatuscomment_trial", &tags::ReportStatusComment_Trial),
-        ("tablezpositiontoisocenter", &tags::TableZPositiontoIsocenter),
-        ("receivecoiltype", &tags::ReceiveCoilType),
-        ("cornealpointestimated", &tags::CornealPointEstimated),
-        ("pointcoordinatesdata", &tags::PointCoordinatesData),
-        ("dacsequence", &tags::DACSequence),
-        ("ctgeometrysequence", &tags::CTGeometrySequence),
-        ("acquisitionstartcondition", &tags::AcquisitionStartCondition),
-        ("instancecoerciondatetime", &tags::InstanceCoercionDateTime),
+        ("referencedprocedurestepsequence", &tags::ReferencedProcedureStepSequence),
+        ("referencedpatientsetupphotosequence", &tags::ReferencedPatientSetupPhotoSequence),
+        ("selectorshvalue", &tags::SelectorSHValue),
+        ("entitylabel", &tags::EntityLabel),
+        ("tag_2dmatingpoint", &tags::Tag_2DMatingPoint),
+        ("placerordernumberprocedure", &tags::PlacerOrderNumberProcedure),
+        ("luminanceresponsedescription", &tags::LuminanceResponseDescription),
+        ("segmentsequence", &tags::SegmentSequence),
+        ("rttreatmentphaseintentpresenceflag", &tags::RTTreatmentPhaseIntentPresenceFlag),
+        ("typeofinstances", &tags::TypeofInstances),
+        ("opticalpathidentificationsequence", &tags::OpticalPathIdentificationSequence),
+        ("gaplength", &tags::GapLength),
+        ("compensatorthicknessdata", &tags::CompensatorThicknessData),
+        ("ultrasoundcolordatapresent", &tags::UltrasoundColorDataPresent),
+        ("pointsboundingboxcoordinates", &tags::PointsBoundingBoxCoordinates),
+        ("routesegmentid", &tags::RouteSegmentID),
+        ("dischargetime", &tags::DischargeTime),
+        ("selectoruvvalue", &tags::SelectorUVValue),
+        ("radiotherapytreatmenttype", &tags::RadiotherapyTreatmentType),
+        ("axialacceptance", &tags::AxialAcceptance),
+        ("assigningfacilitysequence", &tags::AssigningFacilitySequence),
+        ("tabletoplateralpositiontolerance", &tags::TableTopLateralPositionTolerance),
+        ("modifyingdevicemanufacturer", &tags::ModifyingDeviceManufacturer),
+        ("channelshieldnominaltransmission", &tags::ChannelShieldNominalTransmission),
+        ("referencedvisitsequence", &tags::ReferencedVisitSequence),
+        ("minimumhoursbetweenfractions", &tags::MinimumHoursbetweenFractions),
+        ("printerstatus", &tags::PrinterStatus),
+        ("dataelementconditionality", &tags::DataElementConditionality),
+        ("imagedatatypecodesequence", &tags::ImageDataTypeCodeSequence),
+        ("numberofmappoints", &tags::NumberofMapPoints),
+        ("stereopairspresent", &tags::StereoPairsPresent),
+        ("transmitcoilmanufacturername", &tags::TransmitCoilManufacturerName),
+        ("codemeaning", &tags::CodeMeaning),
+        ("userselectedgainy", &tags::UserSelectedGainY),
+        ("gammavalue", &tags::GammaValue),
+        ("fluencedatasource", &tags::FluenceDataSource),
+        ("spoiling", &tags::Spoiling),
+        ("opticalpathsequence", &tags::OpticalPathSequence),
+        ("mydriaticagentconcentrationunitssequence", &tags::MydriaticAgentConcentrationUnitsSequence),
+        ("privatedataelementdefinitionsequence", &tags::PrivateDataElementDefinitionSequence),
+        ("imagetoequipmentmappingmatrix", &tags::ImagetoEquipmentMappingMatrix),
+        ("rotationinformationsequence", &tags::RotationInformationSequence),
+        ("scheduledprocedurestepid", &tags::ScheduledProcedureStepID),
+        ("contactmethod", &tags::ContactMethod),
+        ("issuerofadmissionidsequence", &tags::IssuerofAdmissionIDSequence),
+        ("rtbeamlimitingdeviceangle", &tags::RTBeamLimitingDeviceAngle),
+        ("targetmaximumdose", &tags::TargetMaximumDose),
+        ("channelsourcemodifierssequence", &tags::ChannelSourceModifiersSequence),
+        ("cornealtopographymapqualityevaluation", &tags::CornealTopographyMapQualityEvaluation),
+        ("segmentnumber", &tags::SegmentNumber),
+        ("xoffsetinslidecoordinatesystem", &tags::XOffsetinSlideCoordinateSystem),
         ("componentsequence", &tags::ComponentSequence),
-        ("trianglepointindexlist", &tags::TrianglePointIndexList),
-        ("patienttreatmentpreparationprocedureparameterdescription", &tags::PatientTreatmentPreparationProcedureParameterDescription),
-        ("referencedtreatmentpositiongroupuid", &tags::ReferencedTreatmentPositionGroupUID),
-        ("patientsinsuranceplancodesequence", &tags::PatientsInsurancePlanCodeSequence),
-        ("measuredvaluesequence", &tags::MeasuredValueSequence),
-        ("interventionstatus", &tags::InterventionStatus),
-        ("queryretrieveview", &tags::QueryRetrieveView),
-        ("scanprogressiondirection", &tags::ScanProgressionDirection),
-        ("energywindowlowerlimit", &tags::EnergyWindowLowerLimit),
-        ("visualfieldtestduration", &tags::VisualFieldTestDuration),
-        ("dosereferencepointcoordinates", &tags::DoseReferencePointCoordinates),
-        ("frametime", &tags::FrameTime),
-        ("segmentannotationtypecodesequence", &tags::SegmentAnnotationTypeCodeSequence),
-        ("numberofwaveformsamples", &tags::NumberofWaveformSamples),
-        ("patientsupportdisplacementsequence", &tags::PatientSupportDisplacementSequence),
-        ("planoverviewsequence", &tags::PlanOverviewSequence),
-        ("annotationgroupsequence", &tags::AnnotationGroupSequence),
-        ("displaycalibrationresultsequence", &tags::DisplayCalibrationResultSequence),
-        ("coordinatesystemaxisvalues", &tags::CoordinateSystemAxisValues),
-        ("concatenationframeoffsetnumber", &tags::ConcatenationFrameOffsetNumber),
-        ("xrayoutput", &tags::XRayOutput),
-        ("nominalbeamenergy", &tags::NominalBeamEnergy),
-        ("controlpointsequence", &tags::ControlPointSequence),
-        ("signaldomainrows", &tags::SignalDomainRows),
-        ("measuredcenterfrequency", &tags::MeasuredCenterFrequency),
-        ("lutdescriptor", &tags::LUTDescriptor),
-        ("requestingphysician", &tags::RequestingPhysician),
-        ("deliverywarningdose", &tags::DeliveryWarningDose),
-        ("samplingfrequency", &tags::SamplingFrequency),
-        ("shieldingdevicedescription", &tags::ShieldingDeviceDescription),
-        ("compleximagecomponent", &tags::ComplexImageComponent),
-        ("scanstartpositionsequence", &tags::ScanStartPositionSequence),
-        ("saturationrecovery", &tags::SaturationRecovery),
-        ("waterequivalentdiametercalculationmethodcodesequence", &tags::WaterEquivalentDiameterCalculationMethodCodeSequence),
-        ("reasonforstudy", &tags::ReasonforStudy),
-        ("scopeofinventorysequence", &tags::ScopeofInventorySequence),
-        ("interpretationtranscriptiondate", &tags::InterpretationTranscriptionDate),
-        ("redpalettecolorlookuptabledata", &tags::RedPaletteColorLookupTableData),
-        ("countsincluded", &tags::CountsIncluded),
-        ("tabletoppitchrotationdirection", &tags::TableTopPitchRotationDirection),
-        ("fixationcheckedquantity", &tags::FixationCheckedQuantity),
-        ("displaysubsystemname", &tags::DisplaySubsystemName),
-        ("highrrvalue", &tags::HighRRValue),
-        ("diffusereflectionintensity", &tags::DiffuseReflectionIntensity),
-        ("magnificationtype", &tags::MagnificationType),
-        ("atdassessmentflag", &tags::ATDAssessmentFlag),
-        ("nearpupillarydistance", &tags::NearPupillaryDistance),
-        ("compensatorshapefabricationcodesequence", &tags::CompensatorShapeFabricationCodeSequence),
+        ("xraysourceindex", &tags::XRaySourceIndex),
+        ("subjectiverefractionrighteyesequence", &tags::SubjectiveRefractionRightEyeSequence),
         ("blindspotlocalized", &tags::BlindSpotLocalized),
-        ("numberofrtaccessoryholders", &tags::NumberofRTAccessoryHolders),
-        ("distancesourcetosupport", &tags::DistanceSourcetoSupport),
-        ("dactype", &tags::DACType),
-        ("modifiableconstraintflag", &tags::ModifiableConstraintFlag),
-        ("photographicexposureindex", &tags::PhotographicExposureIndex),
-        ("patientorientationcodesequence", &tags::PatientOrientationCodeSequence),
-        ("sourceimageevidencesequence", &tags::SourceImageEvidenceSequence),
-        ("timebasedimagesetssequence", &tags::TimeBasedImageSetsSequence),
-        ("coordinatesystemtransformtranslationmatrix", &tags::CoordinateSystemTransformTranslationMatrix),
-        ("triggersampleposition", &tags::TriggerSamplePosition),
-        ("radiopharmaceuticalcodesequence", &tags::RadiopharmaceuticalCodeSequence),
-        ("selectorsequencepointerprivatecreator", &tags::SelectorSequencePointerPrivateCreator),
-        ("scanoptions", &tags::ScanOptions),
-        ("ophthalmicultrasoundmethodcodesequence", &tags::OphthalmicUltrasoundMethodCodeSequence),
-        ("referencedimageoverlayboxsequence", &tags::ReferencedImageOverlayBoxSequence),
-        ("frequencyencodingcodesequence", &tags::FrequencyEncodingCodeSequence),
-        ("referencedpositionreferenceinstancesequence", &tags::ReferencedPositionReferenceInstanceSequence),
-        ("luminancevalue", &tags::LuminanceValue),
-        ("segmentedpropertycategorycodesequence", &tags::SegmentedPropertyCategoryCodeSequence),
-        ("referencedcontentitemidentifier", &tags::ReferencedContentItemIdentifier),
-        ("effectivedosecalculationmethoddescription", &tags::EffectiveDoseCalculationMethodDescription),
-        ("imagingservicerequestcomments", &tags::ImagingServiceRequestComments),
-        ("pixelvaluemappingtocodedconceptsequence", &tags::PixelValueMappingtoCodedConceptSequence),
-        ("volumetotransducermappingmatrix", &tags::VolumetoTransducerMappingMatrix),
-        ("beamlimitingdeviceangle", &tags::BeamLimitingDeviceAngle),
-        ("diffusionacquisitioncodesequence", &tags::DiffusionAcquisitionCodeSequence),
-        ("breedregistrationnumber", &tags::BreedRegistrationNumber),
-        ("inventorylevel", &tags::InventoryLevel),
-        ("depthdoseparameterssequence", &tags::DepthDoseParametersSequence),
+        ("printerresolutionid", &tags::PrinterResolutionID),
+        ("tableofxbreakpoints", &tags::TableofXBreakPoints),
+        ("trayaccessorycode", &tags::TrayAccessoryCode),
+        ("modelmirroring", &tags::ModelMirroring),
+        ("interpretationdiagnosisdescription", &tags::InterpretationDiagnosisDescription),
+        ("unassignedsharedconvertedattributessequence", &tags::UnassignedSharedConvertedAttributesSequence),
         ("dosimetricobjectivesequence", &tags::DosimetricObjectiveSequence),
-        ("assessmentrequestersequence", &tags::AssessmentRequesterSequence),
-        ("specifiedchanneltotaltime", &tags::SpecifiedChannelTotalTime),
-        ("surfaceprocessingdescription", &tags::SurfaceProcessingDescription),
-        ("confidentialitycode", &tags::ConfidentialityCode),
-        ("contenttime", &tags::ContentTime),
-        ("recordedcompensatorsequence", &tags::RecordedCompensatorSequence),
-        ("compensatorbaseplaneoffset", &tags::CompensatorBasePlaneOffset),
-        ("dosecomment", &tags::DoseComment),
-        ("rtaccessoryholderslotexistenceflag", &tags::RTAccessoryHolderSlotExistenceFlag),
-        ("rescaleintercept", &tags::RescaleIntercept),
-        ("acquisitionmotion", &tags::AcquisitionMotion),
-        ("affectedsopinstanceuid", &tags::AffectedSOPInstanceUID),
-        ("numberofblocks", &tags::NumberofBlocks),
-        ("containercomponentthickness", &tags::ContainerComponentThickness),
-        ("tomotherapeuticleafinitialcloseddurations", &tags::TomotherapeuticLeafInitialClosedDurations),
-        ("totalprescriptiondose", &tags::TotalPrescriptionDose),
-        ("rotationdirection", &tags::RotationDirection),
-        ("observationsubjectuid_trial", &tags::ObservationSubjectUID_Trial),
-        ("xray3dreconstructionsequence", &tags::XRay3DReconstructionSequence),
-        ("octacquisitiondomain", &tags::OCTAcquisitionDomain),
-        ("rtradiationsetintent", &tags::RTRadiationSetIntent),
-        ("issuerofserviceepisodeidsequence", &tags::IssuerofServiceEpisodeIDSequence),
-        ("itineraryid", &tags::ItineraryID),
-        ("additionalpatienthistory", &tags::AdditionalPatientHistory),
-        ("boundingboxtexthorizontaljustification", &tags::BoundingBoxTextHorizontalJustification),
-        ("setupimagecomment", &tags::SetupImageComment),
-        ("detectortemperaturesequence", &tags::DetectorTemperatureSequence),
-        ("calibrationreferencepointdepth", &tags::CalibrationReferencePointDepth),
-        ("averagebeamdosepointequivalentdepth", &tags::AverageBeamDosePointEquivalentDepth),
-        ("recommendedabsentpixelcielabvalue", &tags::RecommendedAbsentPixelCIELabValue),
-        ("baselinecorrection", &tags::BaselineCorrection),
-        ("procedurecontextflag_trial", &tags::ProcedureContextFlag_Trial),
-        ("referencedpatientsequence", &tags::ReferencedPatientSequence),
-        ("imageboxlayouttype", &tags::ImageBoxLayoutType),
-        ("multiframesourcesopinstanceuid", &tags::MultiframeSourceSOPInstanceUID),
-        ("containertypecodesequence", &tags::ContainerTypeCodeSequence),
-        ("diffusionbmatrixsequence", &tags::DiffusionbmatrixSequence),
-        ("transformdescription", &tags::TransformDescription),
-        ("organexposed", &tags::OrganExposed),
-        ("cumulativedosereferencecoefficient", &tags::CumulativeDoseReferenceCoefficient),
-        ("segmentsoverlap", &tags::SegmentsOverlap),
-        ("stereorotation", &tags::StereoRotation),
-        ("diffusiongradientorientation", &tags::DiffusionGradientOrientation),
-        ("planesinacquisition", &tags::PlanesinAcquisition),
-        ("windowwidth", &tags::WindowWidth),
-        ("containercomponentdescription", &tags::ContainerComponentDescription),
-        ("reconstructionpixelspacing", &tags::ReconstructionPixelSpacing),
-        ("tablecradletiltangle", &tags::TableCradleTiltAngle),
-        ("patientsupportpositionsequence", &tags::PatientSupportPositionSequence),
-        ("detectorconditionsnominalflag", &tags::DetectorConditionsNominalFlag),
-        ("parallelreductionfactorinplane", &tags::ParallelReductionFactorInplane),
-        ("relatedrtroiobservationssequence", &tags::RelatedRTROIObservationsSequence),
-        ("resultsdistributionlistsequence", &tags::ResultsDistributionListSequence),
-        ("observationbasiscodesequence", &tags::ObservationBasisCodeSequence),
-        ("printjobdescriptionsequence", &tags::PrintJobDescriptionSequence),
-        ("exposureindex", &tags::ExposureIndex),
-        ("illuminationcolorcodesequence", &tags::IlluminationColorCodeSequence),
-        ("ooiownertype", &tags::OOIOwnerType),
-        ("radiopharmaceuticalusagesequence", &tags::RadiopharmaceuticalUsageSequence),
-        ("productname", &tags::ProductName),
-        ("prescriptionnotessequence", &tags::PrescriptionNotesSequence),
-        ("starttrim", &tags::StartTrim),
-        ("referenceddigitalsignaturesequence", &tags::ReferencedDigitalSignatureSequence),
-        ("encryptedattributessequence", &tags::EncryptedAttributesSequence),
-        ("wedgeanglefloat", &tags::WedgeAngleFloat),
-        ("startingmetersetvalueknownflag", &tags::StartingMetersetValueKnownFlag),
-        ("referencedxraysourceindex", &tags::ReferencedXRaySourceIndex),
-        ("pixelvaluemappingexplanation", &tags::PixelValueMappingExplanation),
-        ("slaborientation", &tags::SlabOrientation),
-        ("averagecornealpower", &tags::AverageCornealPower),
-        ("reasonforvisit", &tags::ReasonforVisit),
-        ("wedgeoffsetx", &tags::WedgeOffsetX),
-        ("rtimageframekvradiationacquisitionsequence", &tags::RTImageFramekVRadiationAcquisitionSequence),
-        ("compressiondescription", &tags::CompressionDescription),
-        ("relatedassertionsequence", &tags::RelatedAssertionSequence),
-        ("gpsversionid", &tags::GPSVersionID),
-        ("mediumtype", &tags::MediumType),
-        ("transducerscanpatterncodesequence", &tags::TransducerScanPatternCodeSequence),
-        ("parallelrtbeamdelimiteropeningmode", &tags::ParallelRTBeamDelimiterOpeningMode),
-        ("itineraryidtype", &tags::ItineraryIDType),
-        ("contenttemplatesequence", &tags::ContentTemplateSequence),
-        ("positioningmethodcodesequence", &tags::PositioningMethodCodeSequence),
-        ("framedisplayshuttersequence", &tags::FrameDisplayShutterSequence),
-        ("coefficientssdhn", &tags::CoefficientsSDHN),
-        ("requesteddecimatecropbehavior", &tags::RequestedDecimateCropBehavior),
-        ("imageoverlayflag", &tags::ImageOverlayFlag),
-        ("sortingoperationssequence", &tags::SortingOperationsSequence),
-        ("fixationdevicedescription", &tags::FixationDeviceDescription),
-        ("itineraryidassigningauthority", &tags::ItineraryIDAssigningAuthority),
-        ("reportedvaluesorigin", &tags::ReportedValuesOrigin),
-        ("detectordescription", &tags::DetectorDescription),
-        ("cinerelativetorealtime", &tags::CineRelativetoRealTime),
-        ("recommendeddisplaygrayscalevalue", &tags::RecommendedDisplayGrayscaleValue),
-        ("numberoftableentries", &tags::NumberofTableEntries),
-        ("intravascularoctframecontentsequence", &tags::IntravascularOCTFrameContentSequence),
-        ("datasetsubtype", &tags::DataSetSubtype),
-        ("synchronizationchannel", &tags::SynchronizationChannel),
-        ("roidisplaycolor", &tags::ROIDisplayColor),
-        ("pyramidlabel", &tags::PyramidLabel),
-        ("supportedimagedisplayformatssequence", &tags::SupportedImageDisplayFormatsSequence),
-        ("tabletopverticalpositiontolerance", &tags::TableTopVerticalPositionTolerance),
-        ("imagereceptorpositionsequence", &tags::ImageReceptorPositionSequence),
-        ("effectivedosecalculationmethodcodesequence", &tags::EffectiveDoseCalculationMethodCodeSequence),
-        ("referencedbasicannotationboxsequence", &tags::ReferencedBasicAnnotationBoxSequence),
-        ("measurementssequence", &tags::MeasurementsSequence),
-        ("scheduledhumanperformerssequence", &tags::ScheduledHumanPerformersSequence),
-        ("wedgeoriginoffsetx", &tags::WedgeOriginOffsetX),
-        ("opticalpathidentifier", &tags::OpticalPathIdentifier),
-        ("intendednumberoffractions", &tags::IntendedNumberofFractions),
-        ("detectorconfiguration", &tags::DetectorConfiguration),
+        ("mrspectroscopyframetypesequence", &tags::MRSpectroscopyFrameTypeSequence),
+        ("regionpixelshiftsequence", &tags::RegionPixelShiftSequence),
+        ("radiationgenerationmodesequence", &tags::RadiationGenerationModeSequence),
+        ("spatialfrequencyresponsevalues", &tags::SpatialFrequencyResponseValues),
+        ("pulsenumber", &tags::PulseNumber),
+        ("zoffsetinslidecoordinatesystem", &tags::ZOffsetinSlideCoordinateSystem),
+        ("largegreenpalettecolorlookuptabledata", &tags::LargeGreenPaletteColorLookupTableData),
+        ("interpretationrecordedtime", &tags::InterpretationRecordedTime),
         ("generalpurposeperformedprocedurestepstatus", &tags::GeneralPurposePerformedProcedureStepStatus),
-        ("contextidentifier", &tags::ContextIdentifier),
-        ("informationfrommanufacturersequence", &tags::InformationFromManufacturerSequence),
-        ("imagepathfiltertypestackcodesequence", &tags::ImagePathFilterTypeStackCodeSequence),
-        ("omittedapplicationsetupsequence", &tags::OmittedApplicationSetupSequence),
-        ("longedgepointindexlist", &tags::LongEdgePointIndexList),
-        ("floatpixeldata", &tags::FloatPixelData),
-        ("rtimageorientation", &tags::RTImageOrientation),
-        ("geometricalproperties", &tags::GeometricalProperties),
-        ("accessionnumber", &tags::AccessionNumber),
+        ("referringphysicianidentificationsequence", &tags::ReferringPhysicianIdentificationSequence),
+        ("presentationgroupnumber", &tags::PresentationGroupNumber),
+        ("audiocomments", &tags::AudioComments),
+        ("wedgeroofangle", &tags::WedgeRoofAngle),
+        ("contrastbolusroute", &tags::ContrastBolusRoute),
+        ("visualfieldverticalextent", &tags::VisualFieldVerticalExtent),
+        ("overlaymode", &tags::OverlayMode),
+        ("interoperabilityindex", &tags::InteroperabilityIndex),
+        ("viewnumber", &tags::ViewNumber),
+        ("mrdiffusionsequence", &tags::MRDiffusionSequence),
+        ("ctimageframetypesequence", &tags::CTImageFrameTypeSequence),
+        ("secondaryapprovalstatus", &tags::SecondaryApprovalStatus),
+        ("displayedzvalue", &tags::DisplayedZValue),
+        ("gaincorrectionreferencesequence", &tags::GainCorrectionReferenceSequence),
+        ("pixelshiftsequence", &tags::PixelShiftSequence),
+        ("radiationgenerationmodelabel", &tags::RadiationGenerationModeLabel),
+        ("transversemash", &tags::TransverseMash),
+        ("clinicaltrialsiteid", &tags::ClinicalTrialSiteID),
+        ("barcodesymbology", &tags::BarcodeSymbology),
+        ("paddledescription", &tags::PaddleDescription),
+        ("illuminationpower", &tags::IlluminationPower),
+        ("implanttemplategroupmemberssequence", &tags::ImplantTemplateGroupMembersSequence),
+        ("detectorypositiontoisocenter", &tags::DetectorYPositiontoIsocenter),
+        ("observationsubjectcontextflag_trial", &tags::ObservationSubjectContextFlag_Trial),
+        ("evaluatorsequence", &tags::EvaluatorSequence),
+        ("transfertubenumber", &tags::TransferTubeNumber),
+        ("imagedatatypesequence", &tags::ImageDataTypeSequence),
+        ("referencedrelatedgeneralsopclassuidinfile", &tags::ReferencedRelatedGeneralSOPClassUIDinFile),
+        ("tablemotion", &tags::TableMotion),
+        ("aslcrusherdescription", &tags::ASLCrusherDescription),
+        ("resonantnucleus", &tags::ResonantNucleus),
+        ("filmconsumptionsequence", &tags::FilmConsumptionSequence),
+        ("focallength", &tags::FocalLength),
+        ("overlaylabel", &tags::OverlayLabel),
+        ("compensatorpixelspacing", &tags::CompensatorPixelSpacing),
+        ("rationaldenominatorvalue", &tags::RationalDenominatorValue),
+        ("rtvflowrtpsamplingrate", &tags::RTVFlowRTPSamplingRate),
+        ("rtaccessoryholderslotid", &tags::RTAccessoryHolderSlotID),
+        ("phototimersetting", &tags::PhototimerSetting),
+        ("deformableregistrationgridsequence", &tags::DeformableRegistrationGridSequence),
+        ("scheduledstudystoptime", &tags::ScheduledStudyStopTime),
+        ("specifiednumberofpulses", &tags::SpecifiedNumberofPulses),
+        ("reasonforchannelomission", &tags::ReasonforChannelOmission),
+        ("advancedblendingsequence", &tags::AdvancedBlendingSequence),
+        ("operatorsname", &tags::OperatorsName),
+        ("channelsensitivityunitssequence", &tags::ChannelSensitivityUnitsSequence),
+        ("shutterpresentationvalue", &tags::ShutterPresentationValue),
+        ("referencedrefractivemeasurementssequence", &tags::ReferencedRefractiveMeasurementsSequence),
+        ("acquisitionterminationconditiondata", &tags::AcquisitionTerminationConditionData),
+        ("lateralspreadingdevicesettingssequence", &tags::LateralSpreadingDeviceSettingsSequence),
+        ("rtplandescription", &tags::RTPlanDescription),
+        ("blendinglookuptabledescriptor", &tags::BlendingLookupTableDescriptor),
+        ("detectormanufacturername", &tags::DetectorManufacturerName),
+        ("filtertype", &tags::FilterType),
+        ("referencedpathindex", &tags::ReferencedPathIndex),
+        ("shutterrightverticaledge", &tags::ShutterRightVerticalEdge),
+        ("segmentedgreenpalettecolorlookuptabledata", &tags::SegmentedGreenPaletteColorLookupTableData),
+        ("octbscananalysisacquisitionparameterssequence", &tags::OCTBscanAnalysisAcquisitionParametersSequence),
+        ("softtissuesurfacethermalindex", &tags::SoftTissuesurfaceThermalIndex),
+        ("gridresolution", &tags::GridResolution),
+        ("compensatorproximalthicknessmap", &tags::CompensatorProximalThicknessMap),
+        ("percentsampling", &tags::PercentSampling),
+        ("indicationdescription", &tags::IndicationDescription),
+        ("pulsersettingssequence", &tags::PulserSettingsSequence),
+        ("multienergyctacquisitionsequence", &tags::MultienergyCTAcquisitionSequence),
+        ("spectroscopyacquisitionphasecolumns", &tags::SpectroscopyAcquisitionPhaseColumns),
+        ("virtualsourceaxisdistances", &tags::VirtualSourceAxisDistances),
+        ("toplefthandcorneroflocalizerarea", &tags::TopLeftHandCornerofLocalizerArea),
+        ("findingssourcecategorycodesequence_trial", &tags::FindingsSourceCategoryCodeSequence_Trial),
+        ("gpslatitude", &tags::GPSLatitude),
+        ("texturelabel", &tags::TextureLabel),
+        ("wedgeanglefloat", &tags::WedgeAngleFloat),
+        ("xcoordinatescenterpixelviewangle", &tags::XCoordinatesCenterPixelViewAngle),
+        ("radiationdoseidentificationsequence", &tags::RadiationDoseIdentificationSequence),
+        ("channelshieldnumber", &tags::ChannelShieldNumber),
+        ("subjectlocation", &tags::SubjectLocation),
+        ("fiducialdescription", &tags::FiducialDescription),
+        ("topictitle", &tags::TopicTitle),
+        ("conceptualvolumeconstituentsequence", &tags::ConceptualVolumeConstituentSequence),
+        ("reconstructionpipelinetype", &tags::ReconstructionPipelineType),
+        ("acquisitionindex", &tags::AcquisitionIndex),
+        ("verbalsource_trial", &tags::VerbalSource_Trial),
+        ("floatpixelpaddingrangelimit", &tags::FloatPixelPaddingRangeLimit),
+        ("topickeywords", &tags::TopicKeywords),
+        ("compressioncontactarea", &tags::CompressionContactArea),
+        ("rangeshiftertype", &tags::RangeShifterType),
+        ("pixelrepresentation", &tags::PixelRepresentation),
+        ("requestattributessequence", &tags::RequestAttributesSequence),
+        ("ordercallbacktelecominformation", &tags::OrderCallbackTelecomInformation),
+        ("taganglesecondaxis", &tags::TagAngleSecondAxis),
+        ("extendedoffsettable", &tags::ExtendedOffsetTable),
+        ("treatmentsessionuid", &tags::TreatmentSessionUID),
+        ("predecessorprotocolsequence", &tags::PredecessorProtocolSequence),
+        ("generatorpower", &tags::GeneratorPower),
         ("privatedataelementnumberofitems", &tags::PrivateDataElementNumberofItems),
-        ("scheduledperformingphysicianidentificationsequence", &tags::ScheduledPerformingPhysicianIdentificationSequence),
-        ("manufacturersmodelversion", &tags::ManufacturersModelVersion),
-        ("entitydescription", &tags::EntityDescription),
-        ("surfacescanmodecodesequence", &tags::SurfaceScanModeCodeSequence),
-        ("positionacquisitiontemplateid", &tags::PositionAcquisitionTemplateID),
-        ("selectorvaluenumber", &tags::SelectorValueNumber),
-        ("seamlineindex", &tags::SeamLineIndex),
-        ("patientsupportangletolerance", &tags::PatientSupportAngleTolerance),
-        ("assessmentlabel", &tags::AssessmentLabel),
-        ("tabletopverticalposition", &tags::TableTopVerticalPosition),
-        ("admissionid", &tags::AdmissionID),
-        ("scheduledperformingphysiciansname", &tags::ScheduledPerformingPhysiciansName),
-        ("anomalylocatorindicatorsequence", &tags::AnomalyLocatorIndicatorSequence),
-        ("printername", &tags::PrinterName),
-        ("selectortmvalue", &tags::SelectorTMValue),
-        ("generatorpower", &tags::GeneratorPower),
-        ("delineatedradiationfieldsize", &tags::DelineatedRadiationFieldSize),
-        ("reconstructiondiameter", &tags::ReconstructionDiameter),
-        ("dateofmanufacture", &tags::DateofManufacture),
-        ("intravascularoctframetypesequence", &tags::IntravascularOCTFrameTypeSequence),
-        ("partialdatadisplayhandling", &tags::PartialDataDisplayHandling),
-        ("excessivefalsepositives", &tags::ExcessiveFalsePositives),
-        ("dosevaluepurpose", &tags::DoseValuePurpose),
-        ("overriddenattributessequence", &tags::OverriddenAttributesSequence),
-        ("largegreenpalettecolorlookuptabledescriptor", &tags::LargeGreenPaletteColorLookupTableDescriptor),
-        ("alternatecontentdescriptionsequence", &tags::AlternateContentDescriptionSequence),
-        ("referencedconceptualvolumeuid", &tags::ReferencedConceptualVolumeUID),
-        ("patientsupportpositionparametersequence", &tags::PatientSupportPositionParameterSequence),
-        ("safepositionexitdate", &tags::SafePositionExitDate),
-        ("compoundgraphicinstanceid", &tags::CompoundGraphicInstanceID),
-        ("snoutid", &tags::SnoutID),
-        ("sequencingindicator_trial", &tags::SequencingIndicator_Trial),
-        ("substanceadministrationparametersequence", &tags::SubstanceAdministrationParameterSequence),
-        ("dateofinstallation", &tags::DateofInstallation),
-        ("regionlocationminy0", &tags::RegionLocationMinY0),
-        ("rtreferencedseriessequence", &tags::RTReferencedSeriesSequence),
-        ("dlpnotificationtrigger", &tags::DLPNotificationTrigger),
-        ("generalaccessorynumber", &tags::GeneralAccessoryNumber),
-        ("potentialdiagnostictasks", &tags::PotentialDiagnosticTasks),
-        ("fiducialsequence", &tags::FiducialSequence),
-        ("referencedreferenceimagesequence", &tags::ReferencedReferenceImageSequence),
-        ("emptyimagedensity", &tags::EmptyImageDensity),
-        ("specificabsorptionratesequence", &tags::SpecificAbsorptionRateSequence),
-        ("exposuretimeinmicros", &tags::ExposureTimeinmicroS),
-        ("vitalstaincodesequence_trial", &tags::VitalStainCodeSequence_Trial),
-        ("spatialfrequencyresponsevalues", &tags::SpatialFrequencyResponseValues),
-        ("ptoregionsequence", &tags::PTORegionSequence),
-        ("timeoflastdetectorcalibration", &tags::TimeofLastDetectorCalibration),
-        ("revolutiontime", &tags::RevolutionTime),
-        ("rotationofscannedfilm", &tags::RotationofScannedFilm),
-        ("offsetofreferencedlowerleveldirectoryentity", &tags::OffsetofReferencedLowerLevelDirectoryEntity),
-        ("segmentedkspacetraversal", &tags::SegmentedkSpaceTraversal),
-        ("patientspeciescodesequence", &tags::PatientSpeciesCodeSequence),
-        ("weightinglookuptabledata", &tags::WeightingLookupTableData),
-        ("storageprotocolelementspecificationsequence", &tags::StorageProtocolElementSpecificationSequence),
-        ("aslslaborientation", &tags::ASLSlabOrientation),
-        ("numberofwedgepositions", &tags::NumberofWedgePositions),
-        ("routeidassigningauthority", &tags::RouteIDAssigningAuthority),
-        ("sequenceofcompresseddata", &tags::SequenceofCompressedData),
-        ("dimensionorganizationtype", &tags::DimensionOrganizationType),
-        ("recommendedpresentationopacity", &tags::RecommendedPresentationOpacity),
-        ("failedattributessequence", &tags::FailedAttributesSequence),
-        ("imagesetlabel", &tags::ImageSetLabel),
-        ("correctedlocalizeddeviationfromnormalprobability", &tags::CorrectedLocalizedDeviationFromNormalProbability),
-        ("filterbeampathlengthminimum", &tags::FilterBeamPathLengthMinimum),
-        ("mrimagingmodifiersequence", &tags::MRImagingModifierSequence),
-        ("decouplingchemicalshiftreference", &tags::DecouplingChemicalShiftReference),
-        ("conceptualvolumecategorycodesequence", &tags::ConceptualVolumeCategoryCodeSequence),
-        ("trackingid", &tags::TrackingID),
-        ("presentationlutflag", &tags::PresentationLUTFlag),
-        ("implantassemblytemplatename", &tags::ImplantAssemblyTemplateName),
-        ("gpslongituderef", &tags::GPSLongitudeRef),
-        ("anatomicstructurespaceorregioncodesequence_trial", &tags::AnatomicStructureSpaceOrRegionCodeSequence_Trial),
-        ("surfacenumber", &tags::SurfaceNumber),
-        ("nominalbeamangle", &tags::NominalBeamAngle),
-        ("kvp", &tags::KVP),
-        ("xraytubecurrent", &tags::XRayTubeCurrent),
-        ("intendedfractionstarttime", &tags::IntendedFractionStartTime),
-        ("overlaymagnificationtype", &tags::OverlayMagnificationType),
-        ("diffusionbvaluexx", &tags::DiffusionbvalueXX),
-        ("rtplangeometry", &tags::RTPlanGeometry),
-        ("presentationinputtype", &tags::PresentationInputType),
-        ("referencedophthalmicaxiallengthmeasurementqcimagesequence", &tags::ReferencedOphthalmicAxialLengthMeasurementQCImageSequence),
-        ("compensatormountingposition", &tags::CompensatorMountingPosition),
-        ("objectdirectorybinaryidentifier_trial", &tags::ObjectDirectoryBinaryIdentifier_Trial),
-        ("typeoffilters", &tags::TypeofFilters),
-        ("maximumnumberofrecords", &tags::MaximumNumberofRecords),
-        ("implantassemblytemplateissuer", &tags::ImplantAssemblyTemplateIssuer),
-        ("numericvalue", &tags::NumericValue),
-        ("codingschemeversion", &tags::CodingSchemeVersion),
-        ("inventoryinstancedescription", &tags::InventoryInstanceDescription),
-        ("ultrasoundcolordatapresent", &tags::UltrasoundColorDataPresent),
-        ("secondarycountstype", &tags::SecondaryCountsType),
-        ("rtphysicianintentsequence", &tags::RTPhysicianIntentSequence),
-        ("regionofresidence", &tags::RegionofResidence),
-        ("compressionoriginator", &tags::CompressionOriginator),
-        ("rrintervalvector", &tags::RRIntervalVector),
-        ("patienttreatmentpreparationprocedureindex", &tags::PatientTreatmentPreparationProcedureIndex),
-        ("beamlimitingdevicerotationdirection", &tags::BeamLimitingDeviceRotationDirection),
-        ("gatethreshold", &tags::GateThreshold),
-        ("emptyvaluematchingsequence", &tags::EmptyValueMatchingSequence),
-        ("inconcatenationnumber", &tags::InconcatenationNumber),
-        ("tiptype", &tags::TIPType),
-        ("generalmachineverificationsequence", &tags::GeneralMachineVerificationSequence),
-        ("effectiveechotime", &tags::EffectiveEchoTime),
-        ("countlossnormalizationcorrected", &tags::CountLossNormalizationCorrected),
-        ("deliveredmeterset", &tags::DeliveredMeterset),
-        ("referencedtransfersyntaxuidinfile", &tags::ReferencedTransferSyntaxUIDinFile),
-        ("timedomainfiltering", &tags::TimeDomainFiltering),
-        ("maximumfractionalvalue", &tags::MaximumFractionalValue),
-        ("ophthalmicanatomicreferencepointycoordinate", &tags::OphthalmicAnatomicReferencePointYCoordinate),
-        ("boundingboxannotationunits", &tags::BoundingBoxAnnotationUnits),
-        ("flowcompensation", &tags::FlowCompensation),
-        ("visitstatusid", &tags::VisitStatusID),
-        ("cameraelevationangle", &tags::CameraElevationAngle),
-        ("acquisitiondate", &tags::AcquisitionDate),
-        ("beamlimitingdevicetolerancesequence", &tags::BeamLimitingDeviceToleranceSequence),
-        ("certificateofsigner", &tags::CertificateofSigner),
-        ("parametervaluenumber", &tags::ParameterValueNumber),
-        ("referencedsamplepositions", &tags::ReferencedSamplePositions),
-        ("alinepixelspacing", &tags::AlinePixelSpacing),
-        ("overlaybackgrounddensity", &tags::OverlayBackgroundDensity),
-        ("stowrsstoragesequence", &tags::STOWRSStorageSequence),
-        ("beamorderindex_trial", &tags::BeamOrderIndex_Trial),
-        ("sendingapplicationentitytitle", &tags::SendingApplicationEntityTitle),
-        ("patienttreatmentpreparationmethodcodesequence", &tags::PatientTreatmentPreparationMethodCodeSequence),
-        ("clinicaltrialsiteid", &tags::ClinicalTrialSiteID),
-        ("threatroibase", &tags::ThreatROIBase),
-        ("blockedpixels", &tags::BlockedPixels),
-        ("componentwelderids", &tags::ComponentWelderIDs),
-        ("equivalentpupilradius", &tags::EquivalentPupilRadius),
-        ("detectoractiveorigin", &tags::DetectorActiveOrigin),
-        ("phasenumber", &tags::PhaseNumber),
-        ("visualacuityrighteyesequence", &tags::VisualAcuityRightEyeSequence),
-        ("storagemediafilesetuid", &tags::StorageMediaFilesetUID),
-        ("scheduledstationname", &tags::ScheduledStationName),
-        ("metabolitemapdescription", &tags::MetaboliteMapDescription),
-        ("studydate", &tags::StudyDate),
-        ("dosimetricobjectiveuid", &tags::DosimetricObjectiveUID),
-        ("targetuid", &tags::TargetUID),
-        ("wedgematerial", &tags::WedgeMaterial),
-        ("channelmaximumvalue", &tags::ChannelMaximumValue),
-        ("requestedprocedureid", &tags::RequestedProcedureID),
-        ("dataelementconditionality", &tags::DataElementConditionality),
-        ("gantrypitchrotationdirection", &tags::GantryPitchRotationDirection),
-        ("abstractpriorcodesequence", &tags::AbstractPriorCodeSequence),
-        ("gantryid", &tags::GantryID),
-        ("detectormanufacturersmodelname", &tags::DetectorManufacturersModelName),
-        ("scanvelocity", &tags::ScanVelocity),
-        ("instancecreationtime", &tags::InstanceCreationTime),
-        ("intravascularframecontentsequence", &tags::IntravascularFrameContentSequence),
-        ("parallelrtbeamdelimiterboundaries", &tags::ParallelRTBeamDelimiterBoundaries),
-        ("cameraangleofview", &tags::CameraAngleofView),
-        ("contributiondescription", &tags::ContributionDescription),
-        ("smallestvalidpixelvalue", &tags::SmallestValidPixelValue),
-        ("equipmentreferencepointcodesequence", &tags::EquipmentReferencePointCodeSequence),
-        ("calciumscoringmassfactorpatient", &tags::CalciumScoringMassFactorPatient),
-        ("showacquisitiontechniquesflag", &tags::ShowAcquisitionTechniquesFlag),
-        ("intermediatepupillarydistance", &tags::IntermediatePupillaryDistance),
-        ("homecommunityid", &tags::HomeCommunityID),
-        ("threatroibitmap", &tags::ThreatROIBitmap),
-        ("scanlength", &tags::ScanLength),
-        ("observationdate_trial", &tags::ObservationDate_Trial),
-        ("unassignedsharedconvertedattributessequence", &tags::UnassignedSharedConvertedAttributesSequence),
-        ("tag_2dimplanttemplategroupmembermatchingaxes", &tags::Tag_2DImplantTemplateGroupMemberMatchingAxes),
-        ("telephonenumber_trial", &tags::TelephoneNumber_Trial),
-        ("patientsupportangle", &tags::PatientSupportAngle),
-        ("displayenvironmentspatialposition", &tags::DisplayEnvironmentSpatialPosition),
-        ("scheduledstudystartdate", &tags::ScheduledStudyStartDate),
-        ("indicationnumber", &tags::IndicationNumber),
-        ("imagehorizontalflip", &tags::ImageHorizontalFlip),
-        ("scheduledprocedurestepmodificationdatetime", &tags::ScheduledProcedureStepModificationDateTime),
-        ("xaacquisitionframerate", &tags::XAAcquisitionFrameRate),
-        ("ophthalmicaxiallengthmeasurementssegmentallengthsequence", &tags::OphthalmicAxialLengthMeasurementsSegmentalLengthSequence),
-        ("acquisitiontime", &tags::AcquisitionTime),
-        ("patientframeofreferencesource", &tags::PatientFrameofReferenceSource),
-        ("shorttermfluctuationprobabilitycalculated", &tags::ShortTermFluctuationProbabilityCalculated),
-        ("referencedrttreatmentphasesequence", &tags::ReferencedRTTreatmentPhaseSequence),
-        ("exposuredosesequence", &tags::ExposureDoseSequence),
-        ("referencedreferenceimagenumber", &tags::ReferencedReferenceImageNumber),
-        ("octzoffsetapplied", &tags::OCTZOffsetApplied),
-        ("intensifieractiveshape", &tags::IntensifierActiveShape),
-        ("negativecatchtrialsquantity", &tags::NegativeCatchTrialsQuantity),
-        ("rttreatmentpreparationpatientpositionsequence", &tags::RTTreatmentPreparationPatientPositionSequence),
-        ("acquisitionmethod", &tags::AcquisitionMethod),
-        ("urgencyorpriorityalerts_trial", &tags::UrgencyorPriorityAlerts_Trial),
-        ("transversemash", &tags::TransverseMash),
-        ("referencedbaselineparametersrtradiationinstancesequence", &tags::ReferencedBaselineParametersRTRadiationInstanceSequence),
-        ("referencedwaveformchannels", &tags::ReferencedWaveformChannels),
-        ("numberofpaddedalines", &tags::NumberofPaddedAlines),
-        ("relationshipsequence_trial", &tags::RelationshipSequence_Trial),
-        ("referencedimageboxsequence_retired", &tags::ReferencedImageBoxSequence_Retired),
-        ("linedashingstyle", &tags::LineDashingStyle),
-        ("observationuid", &tags::ObservationUID),
-        ("rtbeamlimitingdeviceangle", &tags::RTBeamLimitingDeviceAngle),
-        ("visualfieldtestnormalsflag", &tags::VisualFieldTestNormalsFlag),
-        ("setupdeviceparameter", &tags::SetupDeviceParameter),
-        ("aslboluscutofftechnique", &tags::ASLBolusCutoffTechnique),
-        ("referencedxraydetectorindex", &tags::ReferencedXRayDetectorIndex),
-        ("gridspacingmaterial", &tags::GridSpacingMaterial),
-        ("focallength", &tags::FocalLength),
-        ("beamlimitingdevicepositionsequence", &tags::BeamLimitingDevicePositionSequence),
-        ("recognizablevisualfeatures", &tags::RecognizableVisualFeatures),
-        ("clinicaltrialseriesid", &tags::ClinicalTrialSeriesID),
-        ("rtradiationsetusage", &tags::RTRadiationSetUsage),
-        ("findingsgrouprecordingtime_trial", &tags::FindingsGroupRecordingTime_Trial),
-        ("segmentlabel", &tags::SegmentLabel),
-        ("hpgldocument", &tags::HPGLDocument),
-        ("rotationinformationsequence", &tags::RotationInformationSequence),
-        ("verticesoftheregion", &tags::VerticesoftheRegion),
-        ("visualevaluationmethodcodesequence", &tags::VisualEvaluationMethodCodeSequence),
-        ("frametype", &tags::FrameType),
-        ("clinicaltrialtimepointdescription", &tags::ClinicalTrialTimePointDescription),
-        ("scanspotreordered", &tags::ScanSpotReordered),
-        ("decouplednucleus", &tags::DecoupledNucleus),
-        ("partialviewcodesequence", &tags::PartialViewCodeSequence),
-        ("specimenshortdescription", &tags::SpecimenShortDescription),
-        ("algorithmsource", &tags::AlgorithmSource),
-        ("pulserepetitioninterval", &tags::PulseRepetitionInterval),
-        ("referencetorecordedsound", &tags::ReferencetoRecordedSound),
-        ("containeridentifier", &tags::ContainerIdentifier),
-        ("imagingdevicelocationmatrixsequence", &tags::ImagingDeviceLocationMatrixSequence),
-        ("contentcreatorsidentificationcodesequence", &tags::ContentCreatorsIdentificationCodeSequence),
-        ("referencedpatientsetupphotosequence", &tags::ReferencedPatientSetupPhotoSequence),
-        ("selectorofvalue", &tags::SelectorOFValue),
-        ("verticesofthepolygonalshutter", &tags::VerticesofthePolygonalShutter),
-        ("inputreadinessstate", &tags::InputReadinessState),
-        ("referencedchannelnumber", &tags::ReferencedChannelNumber),
-        ("commanddatasettype", &tags::CommandDataSetType),
-        ("scanmode", &tags::ScanMode),
-        ("rightimagesequence", &tags::RightImageSequence),
-        ("totalgain", &tags::TotalGain),
-        ("surfacepointpresentationvaluedata", &tags::SurfacePointPresentationValueData),
-        ("transducerorientationmodifiersequence", &tags::TransducerOrientationModifierSequence),
-        ("tabletoplongitudinalposition", &tags::TableTopLongitudinalPosition),
-        ("interventiondrugdose", &tags::InterventionDrugDose),
-        ("contrastbolusvolume", &tags::ContrastBolusVolume),
-        ("referencedpatientaliassequence", &tags::ReferencedPatientAliasSequence),
-        ("interpretationtranscriber", &tags::InterpretationTranscriber),
-        ("physicaldeltay", &tags::PhysicalDeltaY),
-        ("totalpixelmatrixcolumns", &tags::TotalPixelMatrixColumns),
-        ("printingbitdepth", &tags::PrintingBitDepth),
-        ("conceptualvolumeoptimizationprecedence", &tags::ConceptualVolumeOptimizationPrecedence),
-        ("kvimaginggenerationparameterssequence", &tags::KVImagingGenerationParametersSequence),
-        ("outlinelowerhorizontaledge", &tags::OutlineLowerHorizontalEdge),
-        ("numberofpatientrelatedstudies", &tags::NumberofPatientRelatedStudies),
-        ("maximumstoredvaluemapped", &tags::MaximumStoredValueMapped),
-        ("spatialfrequencyresponserows", &tags::SpatialFrequencyResponseRows),
-        ("potentialscheduledprotocolcodesequence", &tags::PotentialScheduledProtocolCodeSequence),
-        ("volumetricpresentationinputsetsequence", &tags::VolumetricPresentationInputSetSequence),
-        ("reasonforremovalcodesequence", &tags::ReasonforRemovalCodeSequence),
-        ("fiducialidentifier", &tags::FiducialIdentifier),
-        ("referencedresultssequence", &tags::ReferencedResultsSequence),
-        ("cylinderlenspower", &tags::CylinderLensPower),
-        ("devicealternateidentifierformat", &tags::DeviceAlternateIdentifierFormat),
-        ("interpretationapprovaltime", &tags::InterpretationApprovalTime),
-        ("applicablesafetystandardagency", &tags::ApplicableSafetyStandardAgency),
-        ("xaxrfframecharacteristicssequence", &tags::XAXRFFrameCharacteristicsSequence),
-        ("placerordernumberimagingservicerequest_retired", &tags::PlacerOrderNumberImagingServiceRequest_Retired),
-        ("objectivelensnumericalaperture", &tags::ObjectiveLensNumericalAperture),
-        ("transformversionnumber", &tags::TransformVersionNumber),
-        ("shieldingdevicesequence", &tags::ShieldingDeviceSequence),
-        ("treatmentprotocols", &tags::TreatmentProtocols),
-        ("mrmodifiersequence", &tags::MRModifierSequence),
-        ("reformattingoperationinitialviewdirection", &tags::ReformattingOperationInitialViewDirection),
-        ("petreconstructionsequence", &tags::PETReconstructionSequence),
-        ("maximumalongscandistortion", &tags::MaximumAlongscanDistortion),
-        ("assignedlocation", &tags::AssignedLocation),
-        ("gpsaltituderef", &tags::GPSAltitudeRef),
-        ("localizingcursorposition", &tags::LocalizingCursorPosition),
-        ("parallelacquisitiontechnique", &tags::ParallelAcquisitionTechnique),
-        ("imagerpixelspacing", &tags::ImagerPixelSpacing),
-        ("selectorattributevr", &tags::SelectorAttributeVR),
-        ("referencedrtaccessoryholderdeviceindex", &tags::ReferencedRTAccessoryHolderDeviceIndex),
-        ("codingschemeurl", &tags::CodingSchemeURL),
-        ("patientssize", &tags::PatientsSize),
-        ("velocityencodingdirection", &tags::VelocityEncodingDirection),
-        ("snrthreshold", &tags::SNRThreshold),
-        ("slicelocation", &tags::SliceLocation),
-        ("displaysubsystemid", &tags::DisplaySubsystemID),
-        ("reconstructionfieldofview", &tags::ReconstructionFieldofView),
-        ("segmentannotationcategorycodesequence", &tags::SegmentAnnotationCategoryCodeSequence),
-        ("datasetdescription", &tags::DataSetDescription),
-        ("dischargediagnosisdescription", &tags::DischargeDiagnosisDescription),
-        ("configurationdescription", &tags::ConfigurationDescription),
-        ("pixelintensityrelationshiplutsequence", &tags::PixelIntensityRelationshipLUTSequence),
-        ("annotationgrouplabel", &tags::AnnotationGroupLabel),
-        ("imageorientationpatient", &tags::ImageOrientationPatient),
-        ("gpsstatus", &tags::GPSStatus),
-        ("institutioncodesequence", &tags::InstitutionCodeSequence),
-        ("presentationanimationstyle", &tags::PresentationAnimationStyle),
-        ("indexprobabilitysequence", &tags::IndexProbabilitySequence),
-        ("viewmodifiercodesequence", &tags::ViewModifierCodeSequence),
-        ("radiationbeameffectivewedgeangle", &tags::RadiationBeamEffectiveWedgeAngle),
-        ("segmentalgorithmtype", &tags::SegmentAlgorithmType),
-        ("acquisitioncontrast", &tags::AcquisitionContrast),
-        ("blendinglookuptabledescriptor", &tags::BlendingLookupTableDescriptor),
-        ("gpsspeedref", &tags::GPSSpeedRef),
-        ("requestingphysicianidentificationsequence", &tags::RequestingPhysicianIdentificationSequence),
-        ("wedgedefinitionsequence", &tags::WedgeDefinitionSequence),
-        ("mrtransmitcoilsequence", &tags::MRTransmitCoilSequence),
-        ("mprviewwidthdirection", &tags::MPRViewWidthDirection),
-        ("detectorprimaryangle", &tags::DetectorPrimaryAngle),
-        ("manufacturersdeviceidentifier", &tags::ManufacturersDeviceIdentifier),
-        ("referencedsopsequence", &tags::ReferencedSOPSequence),
-        ("repeatinterval", &tags::RepeatInterval),
-        ("diffusionbvalue", &tags::Diffusionbvalue),
-        ("viewposition", &tags::ViewPosition),
-        ("patientsprimarylanguagecodesequence", &tags::PatientsPrimaryLanguageCodeSequence),
-        ("multiplexgrouptimeoffset", &tags::MultiplexGroupTimeOffset),
-        ("positivecatchtrialsquantity", &tags::PositiveCatchTrialsQuantity),
-        ("decouplingfrequency", &tags::DecouplingFrequency),
-        ("observationsubjectclass_trial", &tags::ObservationSubjectClass_Trial),
-        ("assertionexpirationdatetime", &tags::AssertionExpirationDateTime),
-        ("expirydate", &tags::ExpiryDate),
-        ("listofuidmatchingsequence", &tags::ListofUIDMatchingSequence),
-        ("participationtype", &tags::ParticipationType),
-        ("scheduledadmissiontime", &tags::ScheduledAdmissionTime),
-        ("dateofdocumentorverbaltransaction_trial", &tags::DateofDocumentorVerbalTransaction_Trial),
-        ("imageacquisitiondepth", &tags::ImageAcquisitionDepth),
-        ("productpackageidentifier", &tags::ProductPackageIdentifier),
-        ("globaldeviationfromnormal", &tags::GlobalDeviationFromNormal),
-        ("chairheadframepositiontolerance", &tags::ChairHeadFramePositionTolerance),
-        ("numberofrangeshifters", &tags::NumberofRangeShifters),
-        ("priortreatmentreferencesequence", &tags::PriorTreatmentReferenceSequence),
-        ("viewcodesequence", &tags::ViewCodeSequence),
-        ("plateid", &tags::PlateID),
-        ("diffusionbvaluezz", &tags::DiffusionbvalueZZ),
-        ("blockedgedata", &tags::BlockEdgeData),
-        ("wadoretrievalsequence", &tags::WADORetrievalSequence),
-        ("graphicgroupdescription", &tags::GraphicGroupDescription),
-        ("intervalnumber", &tags::IntervalNumber),
-        ("receivingapplicationentitytitle", &tags::ReceivingApplicationEntityTitle),
-        ("requestedproceduredescription_trial", &tags::RequestedProcedureDescription_Trial),
-        ("printjobid", &tags::PrintJobID),
-        ("brachyreferenceddosereferencesequence", &tags::BrachyReferencedDoseReferenceSequence),
-        ("ethicscommitteeapprovaleffectivenessstartdate", &tags::EthicsCommitteeApprovalEffectivenessStartDate),
-        ("tabletoplateralsetupdisplacement", &tags::TableTopLateralSetupDisplacement),
-        ("componentid", &tags::ComponentID),
-        ("targetunderdosevolumefraction", &tags::TargetUnderdoseVolumeFraction),
-        ("datacollectiondiameter", &tags::DataCollectionDiameter),
-        ("blocktransmission", &tags::BlockTransmission),
-        ("transfertubelength", &tags::TransferTubeLength),
-        ("displaydevicetypecodesequence", &tags::DisplayDeviceTypeCodeSequence),
-        ("aslcontext", &tags::ASLContext),
-        ("movedestination", &tags::MoveDestination),
-        ("gridpitch", &tags::GridPitch),
-        ("radiationdoseidentificationindex", &tags::RadiationDoseIdentificationIndex),
-        ("impressions", &tags::Impressions),
-        ("sensitivitycalibrated", &tags::SensitivityCalibrated),
-        ("selectorowvalue", &tags::SelectorOWValue),
-        ("carrieridassigningauthority", &tags::CarrierIDAssigningAuthority),
-        ("inconcatenationtotalnumber", &tags::InconcatenationTotalNumber),
-        ("numberofstudyrelatedseries", &tags::NumberofStudyRelatedSeries),
-        ("imagingdevicespecificacquisitionparameters", &tags::ImagingDeviceSpecificAcquisitionParameters),
-        ("displayfilterpercentage", &tags::DisplayFilterPercentage),
-        ("tmlinepositionx0_retired", &tags::TMLinePositionX0_Retired),
-        ("rangemodulatorsequence", &tags::RangeModulatorSequence),
-        ("measureddosedescription", &tags::MeasuredDoseDescription),
-        ("opticalmagnificationfactor", &tags::OpticalMagnificationFactor),
-        ("printerpixelspacing", &tags::PrinterPixelSpacing),
-        ("blockslabnumber", &tags::BlockSlabNumber),
-        ("anatomicregionmodifiersequence", &tags::AnatomicRegionModifierSequence),
-        ("selectorurvalue", &tags::SelectorURValue),
-        ("cornealpower", &tags::CornealPower),
-        ("deidentificationmethod", &tags::DeidentificationMethod),
-        ("corneameasurementssequence", &tags::CorneaMeasurementsSequence),
-        ("multienergyctprocessingsequence", &tags::MultienergyCTProcessingSequence),
-        ("basismaterialscodesequence", &tags::BasisMaterialsCodeSequence),
-        ("dosecalibrationfactor", &tags::DoseCalibrationFactor),
-        ("screeningbaselinetype", &tags::ScreeningBaselineType),
-        ("incidentangle", &tags::IncidentAngle),
-        ("overlaytime", &tags::OverlayTime),
-        ("ophthalmicframelocationsequence", &tags::OphthalmicFrameLocationSequence),
-        ("treatmentdeliverycontinuationflag", &tags::TreatmentDeliveryContinuationFlag),
-        ("correctedlocalizeddeviationfromnormal", &tags::CorrectedLocalizedDeviationFromNormal),
-        ("threatcategorydescription", &tags::ThreatCategoryDescription),
-        ("graphicobjectsequence", &tags::GraphicObjectSequence),
-        ("preamplifierequipmentsequence", &tags::PreAmplifierEquipmentSequence),
-        ("sourceisotopehalflife", &tags::SourceIsotopeHalfLife),
-        ("documenttitle", &tags::DocumentTitle),
-        ("sourceapplicatorstepsize", &tags::SourceApplicatorStepSize),
-        ("lossyimagecompression_retired", &tags::LossyImageCompression_Retired),
-        ("filteroperationssequence", &tags::FilterOperationsSequence),
-        ("numberoffocalplanes", &tags::NumberofFocalPlanes),
-        ("fractionbasedrelationshipintervalanchor", &tags::FractionBasedRelationshipIntervalAnchor),
-        ("distancebetweenfocalplanes", &tags::DistanceBetweenFocalPlanes),
-        ("sourcepixelplanescharacteristicssequence", &tags::SourcePixelPlanesCharacteristicsSequence),
-        ("reasonforomission", &tags::ReasonforOmission),
-        ("ophthalmicimageorientation", &tags::OphthalmicImageOrientation),
-        ("parallelrtbeamdelimiteropeningextents", &tags::ParallelRTBeamDelimiterOpeningExtents),
-        ("digitalsignaturedatetime", &tags::DigitalSignatureDateTime),
-        ("responsibleorganization", &tags::ResponsibleOrganization),
-        ("rtdevicedistancereferencelocationcodesequence", &tags::RTDeviceDistanceReferenceLocationCodeSequence),
-        ("histogramexplanation", &tags::HistogramExplanation),
-        ("blockslabsequence", &tags::BlockSlabSequence),
-        ("ioncontrolpointverificationsequence", &tags::IonControlPointVerificationSequence),
-        ("devicemotionexecutionmode", &tags::DeviceMotionExecutionMode),
-        ("tolerancetablesequence", &tags::ToleranceTableSequence),
-        ("rangematchingsequence", &tags::RangeMatchingSequence),
-        ("mindensity", &tags::MinDensity),
-        ("zoomcenter", &tags::ZoomCenter),
-        ("attributeoccurrencesequence", &tags::AttributeOccurrenceSequence),
-        ("datatype", &tags::DataType),
-        ("contactdisplayname", &tags::ContactDisplayName),
-        ("tabletopeccentricangle", &tags::TableTopEccentricAngle),
-        ("imageprocessingapplied", &tags::ImageProcessingApplied),
-        ("ophthalmicaxiallength", &tags::OphthalmicAxialLength),
-        ("rtphysicianintentpredecessorsequence", &tags::RTPhysicianIntentPredecessorSequence),
-        ("scatterfractionfactor", &tags::ScatterFractionFactor),
-        ("indicationtype", &tags::IndicationType),
-        ("tableofparametervalues", &tags::TableofParameterValues),
-        ("conceptualvolumetypecodesequence", &tags::ConceptualVolumeTypeCodeSequence),
-        ("entityname", &tags::EntityName),
-        ("fieldofviewhorizontalflip", &tags::FieldofViewHorizontalFlip),
-        ("protocolelementname", &tags::ProtocolElementName),
-        ("evaluatornumber", &tags::EvaluatorNumber),
-        ("mydriaticagentconcentration", &tags::MydriaticAgentConcentration),
-        ("opticaltransmittance", &tags::OpticalTransmittance),
-        ("identicaldocumentssequence", &tags::IdenticalDocumentsSequence),
-        ("fractiongroupsummarysequence", &tags::FractionGroupSummarySequence),
-        ("implantregulatorydisapprovalcodesequence", &tags::ImplantRegulatoryDisapprovalCodeSequence),
-        ("boundingboxbottomrighthandcorner", &tags::BoundingBoxBottomRightHandCorner),
-        ("rtsegmentannotationsequence", &tags::RTSegmentAnnotationSequence),
-        ("lateralspreadingdevicenumber", &tags::LateralSpreadingDeviceNumber),
-        ("brachyaccessorydevicenumber", &tags::BrachyAccessoryDeviceNumber),
-        ("zonalmapformat", &tags::ZonalMapFormat),
-        ("fluencemode", &tags::FluenceMode),
-        ("dataobservationsequence", &tags::DataObservationSequence),
-        ("rotationpoint", &tags::RotationPoint),
-        ("replacedimplanttemplategroupsequence", &tags::ReplacedImplantTemplateGroupSequence),
-        ("dicomstoragesequence", &tags::DICOMStorageSequence),
-        ("tubeangle", &tags::TubeAngle),
-        ("endingrespiratoryphase", &tags::EndingRespiratoryPhase),
-        ("positionacquisitiontemplatecodesequence", &tags::PositionAcquisitionTemplateCodeSequence),
-        ("patienttreatmentpreparationdevicesequence", &tags::PatientTreatmentPreparationDeviceSequence),
-        ("verbalsourceidentifiercodesequence_trial", &tags::VerbalSourceIdentifierCodeSequence_Trial),
-        ("probecenterlocationx", &tags::ProbeCenterLocationX),
-        ("waveformfilterdescription", &tags::WaveformFilterDescription),
-        ("maccalculationtransfersyntaxuid", &tags::MACCalculationTransferSyntaxUID),
-        ("largeredpalettecolorlookuptabledescriptor", &tags::LargeRedPaletteColorLookupTableDescriptor),
-        ("lateralspreadingdevicesettingssequence", &tags::LateralSpreadingDeviceSettingsSequence),
-        ("normalizationfactorformat", &tags::NormalizationFactorFormat),
-        ("cameraownername", &tags::CameraOwnerName),
-        ("smallestpixelvalueinseries", &tags::SmallestPixelValueinSeries),
-        ("namesofintendedrecipientsofresults", &tags::NamesofIntendedRecipientsofResults),
-        ("keratometricpower", &tags::KeratometricPower),
-        ("waveformsequence", &tags::WaveformSequence),
-        ("selectoruivalue", &tags::SelectorUIValue),
-        ("sequencedelimitationitem", &tags::SequenceDelimitationItem),
-        ("xraytubecurrentinmicroa", &tags::XRayTubeCurrentinmicroA),
-        ("fontname", &tags::FontName),
-        ("waveformannotationsequence", &tags::WaveformAnnotationSequence),
-        ("derivationimplantassemblytemplatesequence", &tags::DerivationImplantAssemblyTemplateSequence),
-        ("directsegmentreferencesequence", &tags::DirectSegmentReferenceSequence),
-        ("conceptualvolumesegmentationdefinedflag", &tags::ConceptualVolumeSegmentationDefinedFlag),
-        ("partialfourier", &tags::PartialFourier),
-        ("filterlookuptabledata", &tags::FilterLookupTableData),
-        ("referencedrtradiationsequence", &tags::ReferencedRTRadiationSequence),
-        ("tag_2dlinecoordinatessequence", &tags::Tag_2DLineCoordinatesSequence),
-        ("priortreatmentdosedescription", &tags::PriorTreatmentDoseDescription),
-        ("compensatornumber", &tags::CompensatorNumber),
-        ("refractiveindexofaqueoushumor", &tags::RefractiveIndexofAqueousHumor),
-        ("fillpattern", &tags::FillPattern),
-        ("privatedataelementvaluerepresentation", &tags::PrivateDataElementValueRepresentation),
-        ("acquiredsoundpathlength", &tags::AcquiredSoundpathLength),
-        ("mainlobeangle", &tags::MainLobeAngle),
-        ("confocalmode", &tags::ConfocalMode),
-        ("maximumcollatedfilms", &tags::MaximumCollatedFilms),
-        ("dosevalue", &tags::DoseValue),
-        ("personsaddress", &tags::PersonsAddress),
-        ("doublefloatpixelpaddingvalue", &tags::DoubleFloatPixelPaddingValue),
-        ("frameofreferencetransformationtype", &tags::FrameofReferenceTransformationType),
-        ("hardcopydevicesoftwareversion", &tags::HardcopyDeviceSoftwareVersion),
-        ("overlaynumber", &tags::OverlayNumber),
-        ("rangemodulatortype", &tags::RangeModulatorType),
-        ("constraintviolationsignificance", &tags::ConstraintViolationSignificance),
-        ("requestattributessequence", &tags::RequestAttributesSequence),
-        ("beatrejectionflag", &tags::BeatRejectionFlag),
-        ("sourcetogeneralaccessorydistance", &tags::SourcetoGeneralAccessoryDistance),
-        ("petframecorrectionfactorssequence", &tags::PETFrameCorrectionFactorsSequence),
-        ("transversedetectorseparation", &tags::TransverseDetectorSeparation),
-        ("radiationsetting", &tags::RadiationSetting),
-        ("boardingpassid", &tags::BoardingPassID),
-        ("observationtime_trial", &tags::ObservationTime_Trial),
-        ("certifiedtimestamp", &tags::CertifiedTimestamp),
-        ("tag_3dmatingpoint", &tags::Tag_3DMatingPoint),
-        ("longdevicedescription", &tags::LongDeviceDescription),
-        ("centerofpto", &tags::CenterofPTO),
-        ("equipmentframeofreferencedescription", &tags::EquipmentFrameofReferenceDescription),
-        ("imageplanepixelspacing", &tags::ImagePlanePixelSpacing),
-        ("specifiedmeterset", &tags::SpecifiedMeterset),
-        ("channelrecommendeddisplaycielabvalue", &tags::ChannelRecommendedDisplayCIELabValue),
-        ("patientsprimarylanguagemodifiercodesequence", &tags::PatientsPrimaryLanguageModifierCodeSequence),
-        ("startacquisitiondatetime", &tags::StartAcquisitionDateTime),
-        ("groupofpatientsidentificationsequence", &tags::GroupofPatientsIdentificationSequence),
-        ("maskoperation", &tags::MaskOperation),
-        ("tableypositiontoisocenter", &tags::TableYPositiontoIsocenter),
-        ("tag_3drenderingtype", &tags::Tag_3DRenderingType),
-        ("indicationsequence", &tags::IndicationSequence),
-        ("scheduledprocedurestepstartdatetime", &tags::ScheduledProcedureStepStartDateTime),
-        ("shuttershape", &tags::ShutterShape),
-        ("scanspotmetersetweights", &tags::ScanSpotMetersetWeights),
-        ("referencedmeasureddosereferencesequence", &tags::ReferencedMeasuredDoseReferenceSequence),
-        ("unifiedprocedurestepliststatus", &tags::UnifiedProcedureStepListStatus),
-        ("segmentalgorithmname", &tags::SegmentAlgorithmName),
-        ("setupreferencedescription", &tags::SetupReferenceDescription),
-        ("scheduledstudystarttime", &tags::ScheduledStudyStartTime),
-        ("referencedchannelshieldnumber", &tags::ReferencedChannelShieldNumber),
-        ("fixationsequence", &tags::FixationSequence),
-        ("referencedstereometricinstancesequence", &tags::ReferencedStereometricInstanceSequence),
-        ("falsepositivesestimate", &tags::FalsePositivesEstimate),
-        ("controlpointrelativeposition", &tags::ControlPointRelativePosition),
-        ("implanttemplategroupmembermatching2dcoordinatessequence", &tags::ImplantTemplateGroupMemberMatching2DCoordinatesSequence),
-        ("simulatedkeratometriccylindersequence", &tags::SimulatedKeratometricCylinderSequence),
-        ("reconstructionalgorithm", &tags::ReconstructionAlgorithm),
-        ("issuerofthecontaineridentifiersequence", &tags::IssueroftheContainerIdentifierSequence),
-        ("t2preparation", &tags::T2Preparation),
-        ("calibrationdate", &tags::CalibrationDate),
-        ("datasetname", &tags::DataSetName),
-        ("afterloaderchannelid", &tags::AfterloaderChannelID),
-        ("intravascularlongitudinaldistance", &tags::IntravascularLongitudinalDistance),
-        ("extendedoffsettablelengths", &tags::ExtendedOffsetTableLengths),
-        ("waveformfiltertype", &tags::WaveformFilterType),
-        ("xray3dacquisitionsequence", &tags::XRay3DAcquisitionSequence),
-        ("stereopairspresent", &tags::StereoPairsPresent),
-        ("filterhighfrequency", &tags::FilterHighFrequency),
-        ("certifiedtimestamptype", &tags::CertifiedTimestampType),
-        ("texturelabel", &tags::TextureLabel),
-        ("componenttypessequence", &tags::ComponentTypesSequence),
-        ("sourcetocompensatordistance", &tags::SourcetoCompensatorDistance),
-        ("radiopharmaceutical", &tags::Radiopharmaceutical),
-        ("referencedsourcenumber", &tags::ReferencedSourceNumber),
-        ("tangentialpower", &tags::TangentialPower),
-        ("relativeurireferencewithinencapsulateddocument", &tags::RelativeURIReferenceWithinEncapsulatedDocument),
-        ("beamlimitingdevicepositiontolerance", &tags::BeamLimitingDevicePositionTolerance),
-        ("filmorientation", &tags::FilmOrientation),
-        ("procedurecontextsequence_trial", &tags::ProcedureContextSequence_Trial),
-        ("anchorpointvisibility", &tags::AnchorPointVisibility),
-        ("orderenteredby", &tags::OrderEnteredBy),
-        ("arbitrary", &tags::Arbitrary),
-        ("studyreadtime", &tags::StudyReadTime),
-        ("performedprocedurestependdate", &tags::PerformedProcedureStepEndDate),
-        ("dataelementlabelsequence", &tags::DataElementLabelSequence),
-        ("acquisitionprotocoldescription", &tags::AcquisitionProtocolDescription),
-        ("datasetsource", &tags::DataSetSource),
-        ("backgroundilluminationcolorcodesequence", &tags::BackgroundIlluminationColorCodeSequence),
-        ("implementationversionname", &tags::ImplementationVersionName),
-        ("detectorgeometrysequence", &tags::DetectorGeometrySequence),
-        ("phaseinformationsequence", &tags::PhaseInformationSequence),
-        ("materialgrade", &tags::MaterialGrade),
-        ("dvhnormalizationpoint", &tags::DVHNormalizationPoint),
-        ("devicemotionparametercodesequence", &tags::DeviceMotionParameterCodeSequence),
-        ("mechanicalindex", &tags::MechanicalIndex),
-        ("frameusefulnessgroupsequence", &tags::FrameUsefulnessGroupSequence),
-        ("notchfilterbandwidth", &tags::NotchFilterBandwidth),
-        ("ophthalmicaxiallengthmeasurementstype", &tags::OphthalmicAxialLengthMeasurementsType),
-        ("decompositionmethod", &tags::DecompositionMethod),
-        ("coordinatesystemtransformsequence", &tags::CoordinateSystemTransformSequence),
-        ("echoplanarpulsesequence", &tags::EchoPlanarPulseSequence),
-        ("sourceofpreviousvalues", &tags::SourceofPreviousValues),
-        ("deliverednominalrangemodulatedregiondepths", &tags::DeliveredNominalRangeModulatedRegionDepths),
-        ("displaysubsystemsequence", &tags::DisplaySubsystemSequence),
-        ("terminationcountsthreshold", &tags::TerminationCountsThreshold),
-        ("fluoroscopypersistenceflag", &tags::FluoroscopyPersistenceFlag),
-        ("manufacturersdeviceclassuid", &tags::ManufacturersDeviceClassUID),
-        ("blockdefinitionsequence", &tags::BlockDefinitionSequence),
-        ("deidentificationaction", &tags::DeidentificationAction),
-        ("dateofgaincalibration", &tags::DateofGainCalibration),
-        ("scheduledprocedurestepid", &tags::ScheduledProcedureStepID),
-        ("requestedsubsequentworkitemcodesequence", &tags::RequestedSubsequentWorkitemCodeSequence),
-        ("referencedcalculateddosereferencesequence", &tags::ReferencedCalculatedDoseReferenceSequence),
-        ("tabletopeccentricrotationdirection", &tags::TableTopEccentricRotationDirection),
-        ("tabletoplateralpositiontolerance", &tags::TableTopLateralPositionTolerance),
-        ("contrastbolusinjectiondelay", &tags::ContrastBolusInjectionDelay),
-        ("lateralspreadingdevicewaterequivalentthickness", &tags::LateralSpreadingDeviceWaterEquivalentThickness),
-        ("opticalselectedophthalmicaxiallengthsequence", &tags::OpticalSelectedOphthalmicAxialLengthSequence),
-        ("curvenumber", &tags::CurveNumber),
-        ("reformattingthickness", &tags::ReformattingThickness),
-        ("doubleexposureflag", &tags::DoubleExposureFlag),
-        ("imagetriggerdelay", &tags::ImageTriggerDelay),
-        ("ophthalmicanatomicreferencepointxcoordinate", &tags::OphthalmicAnatomicReferencePointXCoordinate),
-        ("quadraturereceivecoil", &tags::QuadratureReceiveCoil),
-        ("conceptualvolumetypemodifiercodesequence", &tags::ConceptualVolumeTypeModifierCodeSequence),
-        ("dischargedate", &tags::DischargeDate),
-        ("sourcehangingprotocolsequence", &tags::SourceHangingProtocolSequence),
-        ("tag_3dimplanttemplategroupmembermatchingaxes", &tags::Tag_3DImplantTemplateGroupMemberMatchingAxes),
-        ("referencedrangeshifternumber", &tags::ReferencedRangeShifterNumber),
-        ("routesegmentendlocationid", &tags::RouteSegmentEndLocationID),
-        ("pyramiduid", &tags::PyramidUID),
-        ("interoperabilityindex", &tags::InteroperabilityIndex),
-        ("configurationqaresultssequence", &tags::ConfigurationQAResultsSequence),
-        ("detectorisocentersecondaryangle", &tags::DetectorIsocenterSecondaryAngle),
-        ("hangingprotocolusergroupname", &tags::HangingProtocolUserGroupName),
-        ("medicalrecordlocator", &tags::MedicalRecordLocator),
-        ("sourcetosurfacedistance", &tags::SourcetoSurfaceDistance),
-        ("couplingvelocity", &tags::CouplingVelocity),
-        ("referencedsurfacesequence", &tags::ReferencedSurfaceSequence),
-        ("ivusacquisition", &tags::IVUSAcquisition),
-        ("exposuresequence", &tags::ExposureSequence),
-        ("patientsbodymassindex", &tags::PatientsBodyMassIndex),
-        ("upperlowerpixelvalues", &tags::UpperLowerPixelValues),
-        ("ophthalmicaxiallengthmeasurementssequence", &tags::OphthalmicAxialLengthMeasurementsSequence),
-        ("codingschemeidentificationsequence", &tags::CodingSchemeIdentificationSequence),
-        ("predictedtoricerrorsequence", &tags::PredictedToricErrorSequence),
-        ("ophthalmicaxialmeasurementsdevicetype", &tags::OphthalmicAxialMeasurementsDeviceType),
-        ("twodimensionaltothreedimensionalmapsequence", &tags::TwoDimensionaltoThreeDimensionalMapSequence),
-        ("qrmeasurementssequence", &tags::QRMeasurementsSequence),
-        ("photographicsensitivity", &tags::PhotographicSensitivity),
-        ("xraysourceisocenterprimaryangle", &tags::XRaySourceIsocenterPrimaryAngle),
-        ("displaysubsystemdescription", &tags::DisplaySubsystemDescription),
-        ("transducerfrequency", &tags::TransducerFrequency),
-        ("referencedmeasureddosereferencenumber", &tags::ReferencedMeasuredDoseReferenceNumber),
-        ("numberofframes", &tags::NumberofFrames),
-        ("objectsoundspeed", &tags::ObjectSoundSpeed),
-        ("tablerownumber", &tags::TableRowNumber),
-        ("thresholdtype", &tags::ThresholdType),
-        ("patientclinicaltrialparticipationsequence", &tags::PatientClinicalTrialParticipationSequence),
-        ("preamplifiernotes", &tags::PreAmplifierNotes),
-        ("effectivedosecalculationmethodcategorycodesequence", &tags::EffectiveDoseCalculationMethodCategoryCodeSequence),
-        ("minimumnumberofintervaldays", &tags::MinimumNumberofIntervalDays),
-        ("selectorattribute", &tags::SelectorAttribute),
-        ("cardiacsynchronizationtechnique", &tags::CardiacSynchronizationTechnique),
-        ("imagepathfilterpassthroughwavelength", &tags::ImagePathFilterPassThroughWavelength),
-        ("privategroupreference", &tags::PrivateGroupReference),
-        ("textvalue", &tags::TextValue),
-        ("parallelrtbeamdelimiterdevicesequence", &tags::ParallelRTBeamDelimiterDeviceSequence),
-        ("referencedtreatmentpositionindex", &tags::ReferencedTreatmentPositionIndex),
-        ("aslpulsetrainduration", &tags::ASLPulseTrainDuration),
-        ("contourimagesequence", &tags::ContourImageSequence),
-        ("codingschemeresponsibleorganization", &tags::CodingSchemeResponsibleOrganization),
-        ("coefficientssdvn", &tags::CoefficientsSDVN),
-        ("selectedframenumber", &tags::SelectedFrameNumber),
-        ("calculationcommenttype", &tags::CalculationCommentType),
-        ("smallestimagepixelvalue", &tags::SmallestImagePixelValue),
-        ("controlpointorientation", &tags::ControlPointOrientation),
-        ("inputavailabilityflag", &tags::InputAvailabilityFlag),
-        ("planninginputinformationsequence", &tags::PlanningInputInformationSequence),
-        ("routesegmentlocationidtype", &tags::RouteSegmentLocationIDType),
-        ("performedseriessequence", &tags::PerformedSeriesSequence),
-        ("syringecounts", &tags::SyringeCounts),
-        ("distaldepth", &tags::DistalDepth),
-        ("storedinstancetransfersyntaxuid", &tags::StoredInstanceTransferSyntaxUID),
-        ("bridgeresistors", &tags::BridgeResistors),
-        ("weightinglookuptabledescriptor", &tags::WeightingLookupTableDescriptor),
-        ("scanspotmetersetsdelivered", &tags::ScanSpotMetersetsDelivered),
-        ("performedstationname", &tags::PerformedStationName),
-        ("implanttype", &tags::ImplantType),
-        ("referencedrtphysicianintentindex", &tags::ReferencedRTPhysicianIntentIndex),
-        ("referencedrequestsequence", &tags::ReferencedRequestSequence),
-        ("projectionpixelcalibrationsequence", &tags::ProjectionPixelCalibrationSequence),
-        ("typeofdetectormotion", &tags::TypeofDetectorMotion),
-        ("performingphysicianidentificationsequence", &tags::PerformingPhysicianIdentificationSequence),
-        ("verifyingorganization", &tags::VerifyingOrganization),
-        ("echopulsesequence", &tags::EchoPulseSequence),
-        ("numberoffractions", &tags::NumberofFractions),
-        ("softwareversions", &tags::SoftwareVersions),
-        ("participantsequence", &tags::ParticipantSequence),
-        ("tag_2dplaneintersection", &tags::Tag_2DPlaneIntersection),
-        ("qualitycontrolsubject", &tags::QualityControlSubject),
-        ("wedgeangle", &tags::WedgeAngle),
-        ("annotationgroupgenerationtype", &tags::AnnotationGroupGenerationType),
-        ("wedgeincontactwidth", &tags::WedgeInContactWidth),
-        ("reportingpriority", &tags::ReportingPriority),
-        ("therapytype", &tags::TherapyType),
-        ("waveformdata", &tags::WaveformData),
-        ("ionbeamsequence", &tags::IonBeamSequence),
-        ("orderentererslocation", &tags::OrderEnterersLocation),
-        ("volumeofpto", &tags::VolumeofPTO),
-        ("uniquedeviceidentifier", &tags::UniqueDeviceIdentifier),
-        ("macidnumber", &tags::MACIDNumber),
-        ("originalspecializedsopclassuid", &tags::OriginalSpecializedSOPClassUID),
-        ("cardiacrrintervalspecified", &tags::CardiacRRIntervalSpecified),
-        ("photometricinterpretation", &tags::PhotometricInterpretation),
-        ("labelstyleselection", &tags::LabelStyleSelection),
-        ("studyid", &tags::StudyID),
-        ("sharedfunctionalgroupssequence", &tags::SharedFunctionalGroupsSequence),
-        ("virtualsourceaxisdistances", &tags::VirtualSourceAxisDistances),
-        ("stereobaselinedisplacement", &tags::StereoBaselineDisplacement),
-        ("exclusivecomponenttype", &tags::ExclusiveComponentType),
-        ("detectorinformationsequence", &tags::DetectorInformationSequence),
-        ("pixelaspectratio", &tags::PixelAspectRatio),
-        ("referencedstudysequence", &tags::ReferencedStudySequence),
-        ("surfacecomments", &tags::SurfaceComments),
-        ("blockmountingposition", &tags::BlockMountingPosition),
-        ("radionuclidehalflife", &tags::RadionuclideHalfLife),
-        ("referencedradiationgenerationmodeindex", &tags::ReferencedRadiationGenerationModeIndex),
-        ("twodimensionaltothreedimensionalmapdata", &tags::TwoDimensionaltoThreeDimensionalMapData),
-        ("sourcenumber", &tags::SourceNumber),
-        ("numberoftimeslices", &tags::NumberofTimeSlices),
-        ("anatomiclocationofexamininginstrumentcodesequence_trial", &tags::AnatomicLocationOfExaminingInstrumentCodeSequence_Trial),
-        ("volumelocalizationsequence", &tags::VolumeLocalizationSequence),
-        ("reflectedambientlight", &tags::ReflectedAmbientLight),
-        ("detectorid", &tags::DetectorID),
-        ("mprthicknesstype", &tags::MPRThicknessType),
-        ("rows", &tags::Rows),
-        ("entrancedoseinmgy", &tags::EntranceDoseinmGy),
-        ("modulatedscanmodetype", &tags::ModulatedScanModeType),
-        ("flowrtpsamplingrate", &tags::FlowRTPSamplingRate),
-        ("requestingservicecodesequence", &tags::RequestingServiceCodeSequence),
-        ("receivetransducersequence", &tags::ReceiveTransducerSequence),
-        ("floatingpointvalue", &tags::FloatingPointValue),
-        ("anteriorchamberdepthsequence", &tags::AnteriorChamberDepthSequence),
-        ("organatrisklimitdose", &tags::OrganatRiskLimitDose),
-        ("wedgechamferheight", &tags::WedgeChamferHeight),
-        ("referencedexpectedinvivomeasurementvalueindex", &tags::ReferencedExpectedInVivoMeasurementValueIndex),
-        ("linearitycorrectiontechnique", &tags::LinearityCorrectionTechnique),
-        ("consentforclinicaltrialusesequence", &tags::ConsentforClinicalTrialUseSequence),
-        ("graylookuptabledescriptor", &tags::GrayLookupTableDescriptor),
-        ("wedgetotalheight", &tags::WedgeTotalHeight),
-        ("positionerisocenterdetectorrotationangle", &tags::PositionerIsocenterDetectorRotationAngle),
-        ("contouroffsetvector", &tags::ContourOffsetVector),
-        ("interventiondrugcodesequence", &tags::InterventionDrugCodeSequence),
-        ("patientssex", &tags::PatientsSex),
-        ("specifiedprimarymeterset", &tags::SpecifiedPrimaryMeterset),
-        ("pixeldata", &tags::PixelData),
-        ("mvimaginggenerationparameterssequence", &tags::MVImagingGenerationParametersSequence),
-        ("lensstatusdescription", &tags::LensStatusDescription),
-        ("trackpointindexlist", &tags::TrackPointIndexList),
-        ("wadorsretrievalsequence", &tags::WADORSRetrievalSequence),
-        ("digitalfiltertypecodesequence", &tags::DigitalFilterTypeCodeSequence),
-        ("waterequivalentdiameter", &tags::WaterEquivalentDiameter),
-        ("longitudinaltemporaloffsetfromevent", &tags::LongitudinalTemporalOffsetfromEvent),
-        ("componentinputsequence", &tags::ComponentInputSequence),
-        ("cornealtopographymapqualityevaluation", &tags::CornealTopographyMapQualityEvaluation),
-        ("mracquisitionphaseencodingstepsoutofplane", &tags::MRAcquisitionPhaseEncodingStepsoutofplane),
-        ("lossyimagecompression", &tags::LossyImageCompression),
-        ("militaryrank", &tags::MilitaryRank),
-        ("referenceddosimetricobjectivessequence", &tags::ReferencedDosimetricObjectivesSequence),
-        ("effectivedatetime", &tags::EffectiveDateTime),
-        ("frameofreferencetransformationmatrix", &tags::FrameofReferenceTransformationMatrix),
-        ("tableverticalincrement", &tags::TableVerticalIncrement),
-        ("tmlinepositionx0", &tags::TMLinePositionX0),
-        ("selectorfdvalue", &tags::SelectorFDValue),
-        ("transducerposition", &tags::TransducerPosition),
-        ("devicetypecodesequence", &tags::DeviceTypeCodeSequence),
-        ("angioflag", &tags::AngioFlag),
-        ("lateralspreadingdevicedescription", &tags::LateralSpreadingDeviceDescription),
-        ("gantrymotioncorrected", &tags::GantryMotionCorrected),
-        ("selectorattributename", &tags::SelectorAttributeName),
-        ("graphicgroupid", &tags::GraphicGroupID),
-        ("xraytubecurrentinma", &tags::XRayTubeCurrentinmA),
-        ("queryretrievelevel", &tags::QueryRetrieveLevel),
-        ("assessmenttypecodesequence", &tags::AssessmentTypeCodeSequence),
-        ("interpretationtext", &tags::InterpretationText),
-        ("volumetriccurveupdirections", &tags::VolumetricCurveUpDirections),
-        ("implantsize", &tags::ImplantSize),
-        ("radiationbeamwedgeangle", &tags::RadiationBeamWedgeAngle),
-        ("exposuretime", &tags::ExposureTime),
-        ("executionstatus", &tags::ExecutionStatus),
-        ("cttabledynamicssequence", &tags::CTTableDynamicsSequence),
-        ("treatmenttoleranceviolationsequence", &tags::TreatmentToleranceViolationSequence),
-        ("metersetrate", &tags::MetersetRate),
-        ("switchingphasenumber", &tags::SwitchingPhaseNumber),
-        ("autokvplowerbound", &tags::AutoKVPLowerBound),
-        ("imagequalityindicatortype", &tags::ImageQualityIndicatorType),
-        ("roiphysicalpropertiessequence", &tags::ROIPhysicalPropertiesSequence),
-        ("interventiondruginformationsequence", &tags::InterventionDrugInformationSequence),
-        ("performedprocedurestependdatetime", &tags::PerformedProcedureStepEndDateTime),
-        ("aslboluscutoffdelaytime", &tags::ASLBolusCutoffDelayTime),
-        ("propertylabel", &tags::PropertyLabel),
-        ("countsaccumulated", &tags::CountsAccumulated),
-        ("anteriorchamberdepthdefinitioncodesequence", &tags::AnteriorChamberDepthDefinitionCodeSequence),
-        ("rowoverlap", &tags::RowOverlap),
-        ("scheduledstationnamecodesequence", &tags::ScheduledStationNameCodeSequence),
-        ("compressionlabel", &tags::CompressionLabel),
-        ("labelusinginformationextractedfrominstances", &tags::LabelUsingInformationExtractedFromInstances),
-        ("pulsenumber", &tags::PulseNumber),
-        ("tabletoplongitudinalsetupdisplacement", &tags::TableTopLongitudinalSetupDisplacement),
-        ("specificationselectionguidance", &tags::SpecificationSelectionGuidance),
-        ("requestedlateralitycodesequence", &tags::RequestedLateralityCodeSequence),
-        ("consentfordistributionflag", &tags::ConsentforDistributionFlag),
-        ("textcomments", &tags::TextComments),
-        ("xraymassattenuationcoefficient", &tags::XRayMassAttenuationCoefficient),
-        ("deliveryrate", &tags::DeliveryRate),
-        ("rwavetimevector", &tags::RWaveTimeVector),
-        ("breedregistrationsequence", &tags::BreedRegistrationSequence),
-        ("referencedradiationrtcontrolpointindex", &tags::ReferencedRadiationRTControlPointIndex),
-        ("isospeedlatitudezzz", &tags::ISOSpeedLatitudezzz),
-        ("treatmenttoleranceviolationdescription", &tags::TreatmentToleranceViolationDescription),
-        ("stimuluscolorcodesequence", &tags::StimulusColorCodeSequence),
-        ("findingssequence_trial", &tags::FindingsSequence_Trial),
-        ("fixationlightazimuthalangletolerance", &tags::FixationLightAzimuthalAngleTolerance),
-        ("implanttemplategroupversion", &tags::ImplantTemplateGroupVersion),
-        ("radiationmode", &tags::RadiationMode),
-        ("mrvelocityencodingsequence", &tags::MRVelocityEncodingSequence),
-        ("conceptualvolumedescription", &tags::ConceptualVolumeDescription),
-        ("multiplespinecho", &tags::MultipleSpinEcho),
-        ("referencedblocknumber", &tags::ReferencedBlockNumber),
-        ("numberofcontourpoints", &tags::NumberofContourPoints),
-        ("studyverifieddate", &tags::StudyVerifiedDate),
-        ("colorfilterarraypatternvalues", &tags::ColorFilterArrayPatternValues),
-        ("ptorepresentationsequence", &tags::PTORepresentationSequence),
-        ("rtbeamlimitingdeviceopeningsequence", &tags::RTBeamLimitingDeviceOpeningSequence),
-        ("numberoffailedsuboperations", &tags::NumberofFailedSuboperations),
-        ("identifyingcomments", &tags::IdentifyingComments),
-        ("blockrows", &tags::BlockRows),
-        ("surfaceprocessingalgorithmidentificationsequence", &tags::SurfaceProcessingAlgorithmIdentificationSequence),
-        ("radiobiologicaldoseeffectsequence", &tags::RadiobiologicalDoseEffectSequence),
-        ("rtaccessoryholderslotdistance", &tags::RTAccessoryHolderSlotDistance),
-        ("geneticmodificationsnomenclature", &tags::GeneticModificationsNomenclature),
-        ("codingschemeregistry", &tags::CodingSchemeRegistry),
-        ("approvalstatus", &tags::ApprovalStatus),
-        ("performedprocedurestepstartdatetime", &tags::PerformedProcedureStepStartDateTime),
-        ("encryptedcontenttransfersyntaxuid", &tags::EncryptedContentTransferSyntaxUID),
-        ("pseudocolorpaletteinstancereferencesequence", &tags::PseudoColorPaletteInstanceReferenceSequence),
-        ("channelsettingssequence", &tags::ChannelSettingsSequence),
-        ("annotationpropertytypecodesequence", &tags::AnnotationPropertyTypeCodeSequence),
-        ("alinerate", &tags::AlineRate),
-        ("recommendedlinethickness", &tags::RecommendedLineThickness),
-        ("spectroscopyacquisitiondatacolumns", &tags::SpectroscopyAcquisitionDataColumns),
-        ("recommendedanimationrate", &tags::RecommendedAnimationRate),
-        ("brachycontrolpointdeliveredsequence", &tags::BrachyControlPointDeliveredSequence),
-        ("testresultcomment", &tags::TestResultComment),
-        ("framelaterality", &tags::FrameLaterality),
-        ("reformattinginterval", &tags::ReformattingInterval),
-        ("startdensitythreshold", &tags::StartDensityThreshold),
-        ("reasonfortherequestedprocedure", &tags::ReasonfortheRequestedProcedure),
-        ("isotopenumber", &tags::IsotopeNumber),
-        ("segmentreferenceindex", &tags::SegmentReferenceIndex),
-        ("linacenergy", &tags::LINACEnergy),
-        ("synchronizedimageboxlist", &tags::SynchronizedImageBoxList),
-        ("referencedfractionnumber", &tags::ReferencedFractionNumber),
-        ("referencedbeamnumber", &tags::ReferencedBeamNumber),
-        ("receivecoilname", &tags::ReceiveCoilName),
-        ("slicethickness", &tags::SliceThickness),
-        ("threatroivoxelsequence", &tags::ThreatROIVoxelSequence),
-        ("otherpupillarydistance", &tags::OtherPupillaryDistance),
-        ("measurementequipmenttype", &tags::MeasurementEquipmentType),
-        ("derivationcodesequence", &tags::DerivationCodeSequence),
-        ("treatmentterminationcode", &tags::TreatmentTerminationCode),
-        ("interpretationauthor", &tags::InterpretationAuthor),
-        ("retinalthicknessdefinitioncodesequence", &tags::RetinalThicknessDefinitionCodeSequence),
-        ("isocentertocompensatortraydistance", &tags::IsocentertoCompensatorTrayDistance),
-        ("autokvpselectiontype", &tags::AutoKVPSelectionType),
-        ("lutfunction", &tags::LUTFunction),
-        ("interventionsequence", &tags::InterventionSequence),
-        ("visualevaluationresultsequence", &tags::VisualEvaluationResultSequence),
-        ("correctedparametersequence", &tags::CorrectedParameterSequence),
-        ("tableangle", &tags::TableAngle),
-        ("rtsegmentannotationindex", &tags::RTSegmentAnnotationIndex),
-        ("referencedrelatedgeneralsopclassuidinfile", &tags::ReferencedRelatedGeneralSOPClassUIDinFile),
-        ("routesegmentsequence", &tags::RouteSegmentSequence),
-        ("maximumdepthdistortion", &tags::MaximumDepthDistortion),
-        ("postprocessingfunction", &tags::PostprocessingFunction),
-        ("assertionuid", &tags::AssertionUID),
-        ("wedgefactor", &tags::WedgeFactor),
-        ("contrastbolusstarttime", &tags::ContrastBolusStartTime),
-        ("roiphysicalproperty", &tags::ROIPhysicalProperty),
-        ("numberofgeneralaccessories", &tags::NumberofGeneralAccessories),
-        ("structuresetdate", &tags::StructureSetDate),
-        ("displaysubsystemconfigurationsequence", &tags::DisplaySubsystemConfigurationSequence),
-        ("sourcepatientgroupidentificationsequence", &tags::SourcePatientGroupIdentificationSequence),
-        ("channelmode", &tags::ChannelMode),
-        ("flowtransfersyntaxuid", &tags::FlowTransferSyntaxUID),
-        ("referencedpresentationlutsequence", &tags::ReferencedPresentationLUTSequence),
-        ("algorithmroutingcodesequence", &tags::AlgorithmRoutingCodeSequence),
-        ("referencedhpgldocumentid", &tags::ReferencedHPGLDocumentID),
-        ("compressionsteppointers", &tags::CompressionStepPointers),
-        ("framelabelvector", &tags::FrameLabelVector),
-        ("trackinguid", &tags::TrackingUID),
-        ("beamnumber", &tags::BeamNumber),
-        ("lensescodesequence", &tags::LensesCodeSequence),
-        ("roiname", &tags::ROIName),
-        ("transducergeometrycodesequence", &tags::TransducerGeometryCodeSequence),
-        ("rightlenssequence", &tags::RightLensSequence),
-        ("effectiveduration", &tags::EffectiveDuration),
-        ("elementdimensiona", &tags::ElementDimensionA),
-        ("breastsupportxpositiontoisocenter", &tags::BreastSupportXPositiontoIsocenter),
-        ("safepositionreturndate", &tags::SafePositionReturnDate),
-        ("performedprocedurestepid", &tags::PerformedProcedureStepID),
-        ("falsenegativesestimateflag", &tags::FalseNegativesEstimateFlag),
-        ("fovealsensitivity", &tags::FovealSensitivity),
-        ("beamdosepointdepth", &tags::BeamDosePointDepth),
-        ("sopclassessupported", &tags::SOPClassesSupported),
-        ("testimagevalidation", &tags::TestImageValidation),
-        ("roboticpathcontrolpointsequence", &tags::RoboticPathControlPointSequence),
-        ("patientsupportrotationdirection", &tags::PatientSupportRotationDirection),
-        ("issuerofaccessionnumbersequence", &tags::IssuerofAccessionNumberSequence),
-        ("frameacquisitionsequence", &tags::FrameAcquisitionSequence),
-        ("occupation", &tags::Occupation),
-        ("pixelcoordinatesset_trial", &tags::PixelCoordinatesSet_Trial),
-        ("instructionindex", &tags::InstructionIndex),
-        ("totaltimeoffluoroscopy", &tags::TotalTimeofFluoroscopy),
-        ("selectorsvvalue", &tags::SelectorSVValue),
-        ("ivuspullbackstartframenumber", &tags::IVUSPullbackStartFrameNumber),
-        ("eventcodesequence", &tags::EventCodeSequence),
-        ("measurementunitscodesequence", &tags::MeasurementUnitsCodeSequence),
-        ("tabletopverticalsetupdisplacement", &tags::TableTopVerticalSetupDisplacement),
-        ("incorporatedinventoryinstancesequence", &tags::IncorporatedInventoryInstanceSequence),
-        ("sourcemodelid", &tags::SourceModelID),
-        ("calibrationdatetime", &tags::CalibrationDateTime),
-        ("wedgemanufacturername", &tags::WedgeManufacturerName),
-        ("numberofluminancepoints", &tags::NumberofLuminancePoints),
-        ("pixelintensityrelationshipsign", &tags::PixelIntensityRelationshipSign),
-        ("fixationdevicetype", &tags::FixationDeviceType),
-        ("conceptualvolumeconstituentsegmentationreferencesequence", &tags::ConceptualVolumeConstituentSegmentationReferenceSequence),
-        ("timeofflightinformationused", &tags::TimeofFlightInformationUsed),
-        ("printpriority", &tags::PrintPriority),
-        ("defaultprinterresolutionid", &tags::DefaultPrinterResolutionID),
-        ("dateofsecondarycapture", &tags::DateofSecondaryCapture),
-        ("compensatortransmissiondata", &tags::CompensatorTransmissionData),
-        ("anomalylocatorindicator", &tags::AnomalyLocatorIndicator),
-        ("graphicdimensions", &tags::GraphicDimensions),
-        ("recordedblockslabsequence", &tags::RecordedBlockSlabSequence),
-        ("rtaccessoryholderslotsequence", &tags::RTAccessoryHolderSlotSequence),
-        ("indicationlabel", &tags::IndicationLabel),
-        ("strainsource", &tags::StrainSource),
-        ("tag_3dimplanttemplategroupmembermatchingpoint", &tags::Tag_3DImplantTemplateGroupMemberMatchingPoint),
-        ("valuetype", &tags::ValueType),
-        ("requestedstatusinterval", &tags::RequestedStatusInterval),
-        ("exposedarea", &tags::ExposedArea),
-        ("structureddisplaytextboxsequence", &tags::StructuredDisplayTextBoxSequence),
-        ("refractivepower", &tags::RefractivePower),
-        ("networkid", &tags::NetworkID),
-        ("clinicaltrialsponsorname", &tags::ClinicalTrialSponsorName),
-        ("cardiacframingtype", &tags::CardiacFramingType),
-        ("informationsummary", &tags::InformationSummary),
-        ("overlayforegrounddensity", &tags::OverlayForegroundDensity),
-        ("templateextensionorganizationuid", &tags::TemplateExtensionOrganizationUID),
-        ("numberofannotations", &tags::NumberofAnnotations),
-        ("radiusalongthewedge", &tags::RadiusAlongtheWedge),
-        ("wedgeposition", &tags::WedgePosition),
-        ("beamtype", &tags::BeamType),
-        ("inversiontime", &tags::InversionTime),
-        ("waterreferenceacquisition", &tags::WaterReferenceAcquisition),
-        ("referencedsurfacedatasequence", &tags::ReferencedSurfaceDataSequence),
-        ("centerofcircularoutline", &tags::CenterofCircularOutline),
-        ("pertinentresourcessequence", &tags::PertinentResourcesSequence),
-        ("isocenterposition", &tags::IsocenterPosition),
-        ("focusdepth", &tags::FocusDepth),
-        ("algorithmdescription", &tags::AlgorithmDescription),
-        ("channelshieldid", &tags::ChannelShieldID),
-        ("rescaletype", &tags::RescaleType),
-        ("specimenlocalizationcontentitemsequence", &tags::SpecimenLocalizationContentItemSequence),
-        ("numberofviewsinstage", &tags::NumberofViewsinStage),
-        ("originalimageidentification", &tags::OriginalImageIdentification),
-        ("xraydetectorlabel", &tags::XRayDetectorLabel),
-        ("resourcedescription", &tags::ResourceDescription),
-        ("studycomments", &tags::StudyComments),
-        ("digitalfiltercharacteristicssequence", &tags::DigitalFilterCharacteristicsSequence),
-        ("imagerotation_retired", &tags::ImageRotation_Retired),
-        ("ctpositionsequence", &tags::CTPositionSequence),
-        ("clinicaltrialsitename", &tags::ClinicalTrialSiteName),
-        ("dosereferencesequence", &tags::DoseReferenceSequence),
-        ("urncodevalue", &tags::URNCodeValue),
-        ("metabolitemapcodesequence", &tags::MetaboliteMapCodeSequence),
-        ("issuetimeofimagingservicerequest", &tags::IssueTimeofImagingServiceRequest),
-        ("numberofstages", &tags::NumberofStages),
-        ("imagerotation", &tags::ImageRotation),
-        ("mrspectroscopyframetypesequence", &tags::MRSpectroscopyFrameTypeSequence),
-        ("beamdoseverificationcontrolpointsequence", &tags::BeamDoseVerificationControlPointSequence),
-        ("numberofvisualstimuli", &tags::NumberofVisualStimuli),
-        ("dacamplitude", &tags::DACAmplitude),
-        ("stimuliretestingquantity", &tags::StimuliRetestingQuantity),
-        ("cumulativedosetodosereference", &tags::CumulativeDosetoDoseReference),
-        ("encapsulatedpixeldatavaluetotallength", &tags::EncapsulatedPixelDataValueTotalLength),
-        ("procedurestepstate", &tags::ProcedureStepState),
-        ("ooisize", &tags::OOISize),
-        ("degreeofdilation", &tags::DegreeofDilation),
-        ("admittingdiagnosesdescription", &tags::AdmittingDiagnosesDescription),
-        ("originalimagesequence", &tags::OriginalImageSequence),
-        ("maximumpointdistance", &tags::MaximumPointDistance),
-        ("macalgorithm", &tags::MACAlgorithm),
-        ("subjectarea", &tags::SubjectArea),
-        ("channelwidth", &tags::ChannelWidth),
-        ("referencedotherplanesequence", &tags::ReferencedOtherPlaneSequence),
-        ("applicationsetupnumber", &tags::ApplicationSetupNumber),
-        ("annotationdisplayformatid", &tags::AnnotationDisplayFormatID),
-        ("segmentedpropertytypemodifiercodesequence", &tags::SegmentedPropertyTypeModifierCodeSequence),
-        ("ticklabelalignment", &tags::TickLabelAlignment),
-        ("rtrecordflag", &tags::RTRecordFlag),
-        ("visualacuitytypecodesequence", &tags::VisualAcuityTypeCodeSequence),
-        ("procedureversion", &tags::ProcedureVersion),
-        ("filesetconsistencyflag", &tags::FilesetConsistencyFlag),
-        ("identifyingprivateelements", &tags::IdentifyingPrivateElements),
-        ("brightnessvalue", &tags::BrightnessValue),
-        ("dataelementminimumcharacters", &tags::DataElementMinimumCharacters),
-        ("transformorderofaxes", &tags::TransformOrderofAxes),
-        ("threatdetectionalgorithmandversion", &tags::ThreatDetectionAlgorithmandVersion),
-        ("scanspotprescribedindices", &tags::ScanSpotPrescribedIndices),
-        ("constraintweight", &tags::ConstraintWeight),
-        ("ophthalmicaxiallengthmeasurementslengthsummationsequence", &tags::OphthalmicAxialLengthMeasurementsLengthSummationSequence),
-        ("memorybitdepth", &tags::MemoryBitDepth),
-        ("bitsmappedtocolorlookuptable", &tags::BitsMappedtoColorLookupTable),
-        ("bulkmotionsignalsource", &tags::BulkMotionSignalSource),
-        ("numberofrotations", &tags::NumberofRotations),
-        ("xaplanedetailssequence", &tags::XAPlaneDetailsSequence),
-        ("strainstocknumber", &tags::StrainStockNumber),
-        ("modelmodification", &tags::ModelModification),
-        ("imagesinstudy", &tags::ImagesinStudy),
-        ("mydriaticagentconcentrationunitssequence", &tags::MydriaticAgentConcentrationUnitsSequence),
-        ("multiframepresentationsequence", &tags::MultiframePresentationSequence),
-        ("originalimplanttemplatesequence", &tags::OriginalImplantTemplateSequence),
-        ("viewnumber", &tags::ViewNumber),
-        ("attenuationcorrectionsource", &tags::AttenuationCorrectionSource),
-        ("imagepositionvolume", &tags::ImagePositionVolume),
-        ("mostrecenttreatmentdate", &tags::MostRecentTreatmentDate),
-        ("personidentificationcodesequence", &tags::PersonIdentificationCodeSequence),
-        ("originalimageidentificationnomenclature", &tags::OriginalImageIdentificationNomenclature),
-        ("rttreatmentfractioncompletionstatus", &tags::RTTreatmentFractionCompletionStatus),
-        ("radiationdosesourcetoexternalcontourdistance", &tags::RadiationDoseSourcetoExternalContourDistance),
-        ("depthofscanfield", &tags::DepthofScanField),
-        ("deliveredtreatmenttime", &tags::DeliveredTreatmentTime),
-        ("documentingobserveridentifiercodesequence_trial", &tags::DocumentingObserverIdentifierCodeSequence_Trial),
-        ("componentmanufacturer", &tags::ComponentManufacturer),
-        ("spectrallyselectedexcitation", &tags::SpectrallySelectedExcitation),
-        ("referenceddatetime", &tags::ReferencedDateTime),
-        ("repositoryuniqueid", &tags::RepositoryUniqueID),
-        ("imageboxsmallscrolltype", &tags::ImageBoxSmallScrollType),
-        ("protocolelementcharacteristicssummary", &tags::ProtocolElementCharacteristicsSummary),
-        ("hardcopydevicemanufacturer", &tags::HardcopyDeviceManufacturer),
-        ("postdeformationmatrixregistrationsequence", &tags::PostDeformationMatrixRegistrationSequence),
-        ("actualenvironmentalconditions", &tags::ActualEnvironmentalConditions),
-        ("rescaleslope", &tags::RescaleSlope),
-        ("detectorxpositiontoisocenter", &tags::DetectorXPositiontoIsocenter),
-        ("channeldefinitionsequence", &tags::ChannelDefinitionSequence),
-        ("additionalrtroiidentificationcodesequence", &tags::AdditionalRTROIIdentificationCodeSequence),
-        ("elementpitchb", &tags::ElementPitchB),
-        ("modelusagecodesequence", &tags::ModelUsageCodeSequence),
-        ("conventionalcontrolpointverificationsequence", &tags::ConventionalControlPointVerificationSequence),
-        ("ultrasoundophthalmicaxiallengthmeasurementssequence", &tags::UltrasoundOphthalmicAxialLengthMeasurementsSequence),
-        ("brachytherapysourcetype", &tags::BrachytherapySourceType),
-        ("contentitemmodifiersequence", &tags::ContentItemModifierSequence),
-        ("numberofwedges", &tags::NumberofWedges),
-        ("imageboxtileverticaldimension", &tags::ImageBoxTileVerticalDimension),
-        ("tickposition", &tags::TickPosition),
-        ("transmitcoiltype", &tags::TransmitCoilType),
-        ("lutnumber", &tags::LUTNumber),
-        ("tracksetlabel", &tags::TrackSetLabel),
-        ("priority", &tags::Priority),
-        ("referenceddosereferencesequence", &tags::ReferencedDoseReferenceSequence),
-        ("coatingmaterialscodesequence", &tags::CoatingMaterialsCodeSequence),
-        ("acquisitionendlocationsequence", &tags::AcquisitionEndLocationSequence),
-        ("eventtimersequence", &tags::EventTimerSequence),
-        ("beamangle", &tags::BeamAngle),
-        ("luminanceresponsedescription", &tags::LuminanceResponseDescription),
-        ("sendingpresentationaddress", &tags::SendingPresentationAddress),
-        ("othermagnificationtypesavailable", &tags::OtherMagnificationTypesAvailable),
-        ("polarity", &tags::Polarity),
-        ("thresholddensity", &tags::ThresholdDensity),
-        ("measuredlateraldimension", &tags::MeasuredLateralDimension),
-        ("acquisitionsamplesize", &tags::AcquisitionSampleSize),
-        ("viewname", &tags::ViewName),
-        ("implanttemplategroupvariationdimensionname", &tags::ImplantTemplateGroupVariationDimensionName),
-        ("selectoraevalue", &tags::SelectorAEValue),
-        ("referencedstoredprintsequence", &tags::ReferencedStoredPrintSequence),
-        ("distancebetweenbscanslabs", &tags::DistanceBetweenBscanSlabs),
-        ("treatmentdeliverytype", &tags::TreatmentDeliveryType),
-        ("pixelbandwidth", &tags::PixelBandwidth),
-        ("viewpointposition", &tags::ViewpointPosition),
-        ("graphicgrouplabel", &tags::GraphicGroupLabel),
-        ("relatedprocedurestepsequence", &tags::RelatedProcedureStepSequence),
-        ("channeleffectivelength", &tags::ChannelEffectiveLength),
-        ("fovealsensitivitymeasured", &tags::FovealSensitivityMeasured),
-        ("countrate", &tags::CountRate),
-        ("roboticnodeidentifier", &tags::RoboticNodeIdentifier),
-        ("geometryofkspacetraversal", &tags::GeometryofkSpaceTraversal),
-        ("transducertype", &tags::TransducerType),
-        ("treatmenttechniquenotes", &tags::TreatmentTechniqueNotes),
-        ("tabletopeccentricadjustedangle", &tags::TableTopEccentricAdjustedAngle),
-        ("mrspectroscopyacquisitiontype", &tags::MRSpectroscopyAcquisitionType),
-        ("deliverymaximumdose", &tags::DeliveryMaximumDose),
-        ("fluencedatascale", &tags::FluenceDataScale),
-        ("premedication", &tags::PreMedication),
-        ("transactionstatuscomment", &tags::TransactionStatusComment),
-        ("patientstelephonenumbers", &tags::PatientsTelephoneNumbers),
-        ("reconstructionmethod", &tags::ReconstructionMethod),
-        ("rangemodulatorgatingstopvalue", &tags::RangeModulatorGatingStopValue),
-        ("rtaccessoryholderslotid", &tags::RTAccessoryHolderSlotID),
-        ("placerordernumberimagingservicerequest", &tags::PlacerOrderNumberImagingServiceRequest),
-        ("specifiedtreatmenttime", &tags::SpecifiedTreatmentTime),
-        ("presentationdisplaycollectionuid", &tags::PresentationDisplayCollectionUID),
-        ("typeofopticalcorrection", &tags::TypeofOpticalCorrection),
-        ("predictorcolumns", &tags::PredictorColumns),
-        ("personstelecominformation", &tags::PersonsTelecomInformation),
-        ("fractionstatussummarysequence", &tags::FractionStatusSummarySequence),
-        ("authorizationequipmentcertificationnumber", &tags::AuthorizationEquipmentCertificationNumber),
-        ("shutterupperhorizontaledge", &tags::ShutterUpperHorizontalEdge),
-        ("overridedatetime", &tags::OverrideDateTime),
-        ("actualrespiratorytriggerdelaytime", &tags::ActualRespiratoryTriggerDelayTime),
-        ("absolutedosimetricobjectiveflag", &tags::AbsoluteDosimetricObjectiveFlag),
-        ("contrastbolusingredient", &tags::ContrastBolusIngredient),
-        ("contacturi", &tags::ContactURI),
-        ("datablock", &tags::DataBlock),
-        ("degreeoffreedomid", &tags::DegreeofFreedomID),
-        ("lensconstantsequence", &tags::LensConstantSequence),
-        ("surfacemodellabel", &tags::SurfaceModelLabel),
-        ("referencedosetype", &tags::ReferenceDoseType),
-        ("reasonforchannelomissiondescription", &tags::ReasonforChannelOmissionDescription),
-        ("gpstrack", &tags::GPSTrack),
-        ("showimagetruesizeflag", &tags::ShowImageTrueSizeFlag),
-        ("frameofreferenceuid", &tags::FrameofReferenceUID),
-        ("rangeshiftertype", &tags::RangeShifterType),
-        ("surfacepointsnormalssequence", &tags::SurfacePointsNormalsSequence),
-        ("rtphysicianintentinputinstancesequence", &tags::RTPhysicianIntentInputInstanceSequence),
-        ("ophthalmicthicknessmapthresholdqualityrating", &tags::OphthalmicThicknessMapThresholdQualityRating),
-        ("compensatordivergence", &tags::CompensatorDivergence),
-        ("equipmentadministratorsequence", &tags::EquipmentAdministratorSequence),
-        ("stoptrim", &tags::StopTrim),
-        ("compensatorcolumnoffset", &tags::CompensatorColumnOffset),
-        ("pixeldataproviderurl", &tags::PixelDataProviderURL),
-        ("numberofelements", &tags::NumberofElements),
-        ("deidentificationmethodcodesequence", &tags::DeidentificationMethodCodeSequence),
-        ("detectortype", &tags::DetectorType),
-        ("relevantinformationsequence", &tags::RelevantInformationSequence),
-        ("assessedsopinstancesequence", &tags::AssessedSOPInstanceSequence),
-        ("patientsetupsequence", &tags::PatientSetupSequence),
-        ("codenumberformat", &tags::CodeNumberFormat),
-        ("performedprocedurestepstatus", &tags::PerformedProcedureStepStatus),
-        ("filemetainformationversion", &tags::FileMetaInformationVersion),
-        ("rtpatientpositiondisplacementsequence", &tags::RTPatientPositionDisplacementSequence),
-        ("addpower", &tags::AddPower),
-        ("radiationmachinessd", &tags::RadiationMachineSSD),
-        ("reasonforrequestedprocedurecodesequence", &tags::ReasonforRequestedProcedureCodeSequence),
-        ("algorithmparameters", &tags::AlgorithmParameters),
-        ("realworldvaluelastvaluemapped", &tags::RealWorldValueLastValueMapped),
-        ("interlockresolutionusersequence", &tags::InterlockResolutionUserSequence),
-        ("petframetypesequence", &tags::PETFrameTypeSequence),
-        ("seriesnumber", &tags::SeriesNumber),
-        ("radiopharmaceuticalroute", &tags::RadiopharmaceuticalRoute),
-        ("globaldeviationprobability", &tags::GlobalDeviationProbability),
-        ("issuerofserviceepisodeid", &tags::IssuerofServiceEpisodeID),
-        ("collimatorgridname", &tags::CollimatorgridName),
-        ("templateextensionflag", &tags::TemplateExtensionFlag),
-        ("treatmenttimelimit", &tags::TreatmentTimeLimit),
-        ("imagelocation", &tags::ImageLocation),
-        ("combinationsegmentreferencesequence", &tags::CombinationSegmentReferenceSequence),
-        ("filmdestination", &tags::FilmDestination),
-        ("diffusiondirectionality", &tags::DiffusionDirectionality),
-        ("custodialorganizationsequence", &tags::CustodialOrganizationSequence),
-        ("reconstructionstartlocationsequence", &tags::ReconstructionStartLocationSequence),
-        ("filelengthincontainer", &tags::FileLengthinContainer),
-        ("anatomiclocationofexamininginstrumentdescription_trial", &tags::AnatomicLocationOfExaminingInstrumentDescription_Trial),
-        ("framecomments", &tags::FrameComments),
-        ("displacementreferencelocationcodesequence", &tags::DisplacementReferenceLocationCodeSequence),
-        ("exclusionstartdatetime", &tags::ExclusionStartDateTime),
-        ("axialacceptance", &tags::AxialAcceptance),
-        ("ionblocksequence", &tags::IonBlockSequence),
-        ("gantryangletolerance", &tags::GantryAngleTolerance),
-        ("tickalignment", &tags::TickAlignment),
-        ("lossyimagecompressionratio", &tags::LossyImageCompressionRatio),
-        ("flowidentifiersequence", &tags::FlowIdentifierSequence),
-        ("chemicalshiftsequence", &tags::ChemicalShiftSequence),
-        ("referencedpatientphotosequence", &tags::ReferencedPatientPhotoSequence),
-        ("decompositiondescription", &tags::DecompositionDescription),
-        ("referencedstructuresetsequence", &tags::ReferencedStructureSetSequence),
-        ("ctexposuresequence", &tags::CTExposureSequence),
-        ("sourceposition", &tags::SourcePosition),
-        ("yoffsetinslidecoordinatesystem", &tags::YOffsetinSlideCoordinateSystem),
-        ("informationissuedatetime", &tags::InformationIssueDateTime),
-        ("atdassessmentsequence", &tags::ATDAssessmentSequence),
-        ("alphapalettecolorlookuptabledata", &tags::AlphaPaletteColorLookupTableData),
-        ("rtimageframegeneralcontentsequence", &tags::RTImageFrameGeneralContentSequence),
-        ("radiationdoseinvivomeasurementlabel", &tags::RadiationDoseInVivoMeasurementLabel),
-        ("containercomponentid", &tags::ContainerComponentID),
-        ("usercontentlonglabel", &tags::UserContentLongLabel),
-        ("brachyapplicationsetupdosespecificationpoint", &tags::BrachyApplicationSetupDoseSpecificationPoint),
-        ("acquisitioncomments", &tags::AcquisitionComments),
-        ("gpsdatestamp", &tags::GPSDateStamp),
-        ("instructionsequence", &tags::InstructionSequence),
-        ("blendingposition", &tags::BlendingPosition),
-        ("verbalsource_trial", &tags::VerbalSource_Trial),
-        ("gpsdop", &tags::GPSDOP),
-        ("fractionnumber", &tags::FractionNumber),
-        ("filterlookuptabledescription", &tags::FilterLookupTableDescription),
-        ("modifyingsystem", &tags::ModifyingSystem),
-        ("deliverednominalrangemodulationfractions", &tags::DeliveredNominalRangeModulationFractions),
-        ("digitalimageformatacquired", &tags::DigitalImageFormatAcquired),
-        ("referencedpatientsetupprocedureindex", &tags::ReferencedPatientSetupProcedureIndex),
-        ("requestedprocedurecodesequence", &tags::RequestedProcedureCodeSequence),
-        ("settlingphaseframe", &tags::SettlingPhaseFrame),
-        ("recordedbrachyaccessorydevicesequence", &tags::RecordedBrachyAccessoryDeviceSequence),
-        ("activesourcelength", &tags::ActiveSourceLength),
-        ("primarydosimeterunit", &tags::PrimaryDosimeterUnit),
-        ("largestimagepixelvalueinplane", &tags::LargestImagePixelValueinPlane),
-        ("dopplersamplevolumeyposition", &tags::DopplerSampleVolumeYPosition),
-        ("treatmenttoleranceviolationcategory", &tags::TreatmentToleranceViolationCategory),
-        ("rttreatmentapproachlabel", &tags::RTTreatmentApproachLabel),
-        ("reference", &tags::Reference),
-        ("waveformoriginality", &tags::WaveformOriginality),
-        ("exposure", &tags::Exposure),
-        ("beamstopperposition", &tags::BeamStopperPosition),
-        ("indexnormalsflag", &tags::IndexNormalsFlag),
-        ("relatedreferencertimagesequence", &tags::RelatedReferenceRTImageSequence),
-        ("keratometrymeasurementtypecodesequence", &tags::KeratometryMeasurementTypeCodeSequence),
-        ("gpsimgdirection", &tags::GPSImgDirection),
-        ("referencedframenumber", &tags::ReferencedFrameNumber),
-        ("nominalminenergy", &tags::NominalMinEnergy),
-        ("referencedfiducialuid", &tags::ReferencedFiducialUID),
-        ("backgroundcolor", &tags::BackgroundColor),
-        ("emittercolortemperature", &tags::EmitterColorTemperature),
-        ("mprviewheight", &tags::MPRViewHeight),
-        ("diffusionmodelcodesequence", &tags::DiffusionModelCodeSequence),
-        ("lightpathfilterpassband", &tags::LightPathFilterPassBand),
-        ("patientlocationcoordinatescodesequence", &tags::PatientLocationCoordinatesCodeSequence),
-        ("omittedradiationsequence", &tags::OmittedRadiationSequence),
-        ("rtaccessoryslotdistance", &tags::RTAccessorySlotDistance),
-        ("geometricmaximumdistortion", &tags::GeometricMaximumDistortion),
-        ("offsetdirection", &tags::OffsetDirection),
-        ("specimenreferencesequence", &tags::SpecimenReferenceSequence),
-        ("surfacescanacquisitiontypecodesequence", &tags::SurfaceScanAcquisitionTypeCodeSequence),
-        ("numberofpaintings", &tags::NumberofPaintings),
-        ("zoomfactor", &tags::ZoomFactor),
-        ("intendedrttreatmentphasesequence", &tags::IntendedRTTreatmentPhaseSequence),
-        ("roiobservationlabel", &tags::ROIObservationLabel),
-        ("channelbaseline", &tags::ChannelBaseline),
-        ("planenormal", &tags::PlaneNormal),
-        ("axialresolution", &tags::AxialResolution),
-        ("structuresetdescription", &tags::StructureSetDescription),
-        ("segmentreferencesequence", &tags::SegmentReferenceSequence),
-        ("objectbinaryidentifier_trial", &tags::ObjectBinaryIdentifier_Trial),
-        ("performedproceduretypedescription", &tags::PerformedProcedureTypeDescription),
-        ("calculatedanatomythickness", &tags::CalculatedAnatomyThickness),
-        ("channelshieldname", &tags::ChannelShieldName),
-        ("folderaccessuri", &tags::FolderAccessURI),
-        ("renderingmethod", &tags::RenderingMethod),
-        ("columnoverlap", &tags::ColumnOverlap),
-        ("energywindowvector", &tags::EnergyWindowVector),
-        ("gradientoutputtype", &tags::GradientOutputType),
-        ("referencedcontentitem", &tags::ReferencedContentItem),
-        ("numberoftablerows", &tags::NumberofTableRows),
-        ("pixelspacingsequence", &tags::PixelSpacingSequence),
-        ("graphiccoordinatesdatasequence", &tags::GraphicCoordinatesDataSequence),
-        ("radiationdoseidentificationlabel", &tags::RadiationDoseIdentificationLabel),
-        ("implanttypecodesequence", &tags::ImplantTypeCodeSequence),
-        ("minimumkeratometricsequence", &tags::MinimumKeratometricSequence),
-        ("largebluepalettecolorlookuptabledata", &tags::LargeBluePaletteColorLookupTableData),
-        ("selectorulvalue", &tags::SelectorULValue),
-        ("imageindex", &tags::ImageIndex),
-        ("patientsalternativecalendar", &tags::PatientsAlternativeCalendar),
-        ("roinumber", &tags::ROINumber),
-        ("presentationgroupnumber", &tags::PresentationGroupNumber),
-        ("patientnotproperlyfixatedquantity", &tags::PatientNotProperlyFixatedQuantity),
-        ("weekdayfractionpatternsequence", &tags::WeekdayFractionPatternSequence),
-        ("fiducialidentifiercodesequence", &tags::FiducialIdentifierCodeSequence),
-        ("calibrationsequence", &tags::CalibrationSequence),
-        ("additionalparameterrecordinginstancesequence", &tags::AdditionalParameterRecordingInstanceSequence),
-        ("rangeoffreedom", &tags::RangeofFreedom),
-        ("illuminationbandwidth", &tags::IlluminationBandwidth),
-        ("synchronizedscrollingsequence", &tags::SynchronizedScrollingSequence),
-        ("treatmentsummarycalculateddosereferencesequence", &tags::TreatmentSummaryCalculatedDoseReferenceSequence),
-        ("privatedataelementdescription", &tags::PrivateDataElementDescription),
-        ("pointpositionaccuracy", &tags::PointPositionAccuracy),
-        ("oecfcolumns", &tags::OECFColumns),
-        ("numberoftotalobjects", &tags::NumberofTotalObjects),
-        ("aslslabthickness", &tags::ASLSlabThickness),
-        ("strainsourceregistrycodesequence", &tags::StrainSourceRegistryCodeSequence),
-        ("globaldeviationprobabilitysequence", &tags::GlobalDeviationProbabilitySequence),
-        ("ctdivol", &tags::CTDIvol),
-        ("radiusofcircularexposurecontrolsensingregion", &tags::RadiusofCircularExposureControlSensingRegion),
-        ("visualfieldtestpointycoordinate", &tags::VisualFieldTestPointYCoordinate),
-        ("mrspectroscopyfovgeometrysequence", &tags::MRSpectroscopyFOVGeometrySequence),
-        ("rwavepointer", &tags::RWavePointer),
-        ("multiplecomponentapprovalsequence", &tags::MultipleComponentApprovalSequence),
-        ("requestedseriesdescriptioncodesequence", &tags::RequestedSeriesDescriptionCodeSequence),
-        ("edgepointindexlist", &tags::EdgePointIndexList),
-        ("exposurecontrolsensingregionupperhorizontaledge", &tags::ExposureControlSensingRegionUpperHorizontalEdge),
-        ("referencedfractiongroupsequence", &tags::ReferencedFractionGroupSequence),
-        ("imageboxsmallscrollamount", &tags::ImageBoxSmallScrollAmount),
-        ("respiratorymotioncompensationtechnique", &tags::RespiratoryMotionCompensationTechnique),
-        ("treatmentsessionbeamsequence", &tags::TreatmentSessionBeamSequence),
-        ("ivuspullbackstopframenumber", &tags::IVUSPullbackStopFrameNumber),
-        ("rtimageframemvradiationacquisitionsequence", &tags::RTImageFrameMVRadiationAcquisitionSequence),
-        ("enhancedrtbeamlimitingdevicedefinitionflag", &tags::EnhancedRTBeamLimitingDeviceDefinitionFlag),
-        ("scheduledprocedurestepsequence", &tags::ScheduledProcedureStepSequence),
-        ("interventiondescription", &tags::InterventionDescription),
-        ("waveformdatadisplayscale", &tags::WaveformDataDisplayScale),
-        ("functionalgrouppointer", &tags::FunctionalGroupPointer),
-        ("interpretationdiagnosiscodesequence", &tags::InterpretationDiagnosisCodeSequence),
-        ("hl7documenteffectivetime", &tags::HL7DocumentEffectiveTime),
-        ("machinespecifictreatmentterminationcodesequence", &tags::MachineSpecificTreatmentTerminationCodeSequence),
-        ("sensitivity", &tags::Sensitivity),
-        ("substanceadministrationnotes", &tags::SubstanceAdministrationNotes),
-        ("imageid", &tags::ImageID),
-        ("referenceimagenumber", &tags::ReferenceImageNumber),
-        ("radiopharmaceuticalstarttime", &tags::RadiopharmaceuticalStartTime),
-        ("dataelementlabelitemsequence", &tags::DataElementLabelItemSequence),
-        ("planepositionslidesequence", &tags::PlanePositionSlideSequence),
-        ("largepalettecolorlookuptableuid", &tags::LargePaletteColorLookupTableUID),
-        ("otherapprovalstatus", &tags::OtherApprovalStatus),
-        ("offsetofthefirstdirectoryrecordoftherootdirectoryentity", &tags::OffsetoftheFirstDirectoryRecordoftheRootDirectoryEntity),
-        ("planninglandmarklinesequence", &tags::PlanningLandmarkLineSequence),
-        ("endacquisitiondatetime", &tags::EndAcquisitionDateTime),
-        ("currenttreatmentstatus", &tags::CurrentTreatmentStatus),
-        ("patientsupportid", &tags::PatientSupportID),
-        ("contrastbolususagesequence", &tags::ContrastBolusUsageSequence),
-        ("signaldomaincolumns", &tags::SignalDomainColumns),
-        ("bscancycletime", &tags::BscanCycleTime),
-        ("beamdosespecificationpoint", &tags::BeamDoseSpecificationPoint),
-        ("respiratorysignalsource", &tags::RespiratorySignalSource),
-        ("pupilcentroidxcoordinate", &tags::PupilCentroidXCoordinate),
-        ("ultrasoundacquisitiongeometry", &tags::UltrasoundAcquisitionGeometry),
-        ("italic", &tags::Italic),
-        ("radiationdoseidentificationsequence", &tags::RadiationDoseIdentificationSequence),
-        ("sopinstanceuidofconcatenationsource", &tags::SOPInstanceUIDofConcatenationSource),
-        ("conceptualvolumecombinationflag", &tags::ConceptualVolumeCombinationFlag),
-        ("interpretationidissuer", &tags::InterpretationIDIssuer),
-        ("referencedplanoverviewindex", &tags::ReferencedPlanOverviewIndex),
-        ("entitylabel", &tags::EntityLabel),
-        ("contextgroupidentificationsequence", &tags::ContextGroupIdentificationSequence),
-        ("transformedaxisunits", &tags::TransformedAxisUnits),
-        ("paddledescription", &tags::PaddleDescription),
-        ("spacingbetweenslices", &tags::SpacingBetweenSlices),
-        ("recommendeddisplayframerateinfloat", &tags::RecommendedDisplayFrameRateinFloat),
-        ("detectorvector", &tags::DetectorVector),
-        ("startangle", &tags::StartAngle),
-        ("cassettesize", &tags::CassetteSize),
-        ("dvhtype", &tags::DVHType),
-        ("autorefractionlefteyesequence", &tags::AutorefractionLeftEyeSequence),
-        ("codingschemedesignator", &tags::CodingSchemeDesignator),
-        ("errorcomment", &tags::ErrorComment),
-        ("mydriaticagentsequence", &tags::MydriaticAgentSequence),
-        ("billingitemsequence", &tags::BillingItemSequence),
-        ("ophthalmicaxiallengthmeasurementmodified", &tags::OphthalmicAxialLengthMeasurementModified),
-        ("applicatoropeningx", &tags::ApplicatorOpeningX),
-        ("reasonfortheimagingservicerequest", &tags::ReasonfortheImagingServiceRequest),
-        ("imagingfrequency", &tags::ImagingFrequency),
-        ("originalimplantassemblytemplatesequence", &tags::OriginalImplantAssemblyTemplateSequence),
-        ("mraveragessequence", &tags::MRAveragesSequence),
-        ("interventiondrugname", &tags::InterventionDrugName),
-        ("hpglcontourpennumber", &tags::HPGLContourPenNumber),
-        ("catchtrialsdataflag", &tags::CatchTrialsDataFlag),
-        ("objectthicknesssequence", &tags::ObjectThicknessSequence),
-        ("analogfiltercharacteristicssequence", &tags::AnalogFilterCharacteristicsSequence),
-        ("imagelaterality", &tags::ImageLaterality),
-        ("ethnicgroup", &tags::EthnicGroup),
-        ("alongscanspatialresolution", &tags::AlongscanSpatialResolution),
-        ("sourcetoblocktraydistance", &tags::SourcetoBlockTrayDistance),
-        ("depthoftransverseimage", &tags::DepthofTransverseImage),
-        ("responsibleperson", &tags::ResponsiblePerson),
-        ("totalpixelmatrixfocalplanes", &tags::TotalPixelMatrixFocalPlanes),
-        ("applicationsetupname", &tags::ApplicationSetupName),
-        ("roboticbaselocationindicator", &tags::RoboticBaseLocationIndicator),
-        ("ivusgatedrate", &tags::IVUSGatedRate),
-        ("studyidissuer", &tags::StudyIDIssuer),
-        ("procedureidentifiercodesequence_trial", &tags::ProcedureIdentifierCodeSequence_Trial),
-        ("leftlenssequence", &tags::LeftLensSequence),
-        ("colorfilterarraypatternrows", &tags::ColorFilterArrayPatternRows),
-        ("columnangulationpatient", &tags::ColumnAngulationPatient),
-        ("petpositionsequence", &tags::PETPositionSequence),
-        ("pulsertype", &tags::PulserType),
-        ("measuredbandwidth", &tags::MeasuredBandwidth),
-        ("fovealpointprobabilityvalue", &tags::FovealPointProbabilityValue),
-        ("scheduledstudystoptime", &tags::ScheduledStudyStopTime),
-        ("referencedimagesequence", &tags::ReferencedImageSequence),
-        ("requestingservice", &tags::RequestingService),
-        ("hpgldocumentscaling", &tags::HPGLDocumentScaling),
-        ("translationratex", &tags::TranslationRateX),
-        ("textcolorcielabvalue", &tags::TextColorCIELabValue),
-        ("blockedgedatasequence", &tags::BlockEdgeDataSequence),
-        ("mrspatialsaturationsequence", &tags::MRSpatialSaturationSequence),
-        ("refractiveindexofcornea", &tags::RefractiveIndexofCornea),
-        ("ophthalmicmappingdevicetype", &tags::OphthalmicMappingDeviceType),
-        ("radiusofcircularcollimator", &tags::RadiusofCircularCollimator),
-        ("templateversion", &tags::TemplateVersion),
-        ("matingfeaturesetssequence", &tags::MatingFeatureSetsSequence),
-        ("sopinstanceuid", &tags::SOPInstanceUID),
-        ("multienergyctacquisition", &tags::MultienergyCTAcquisition),
-        ("manufacturersrelatedmodelgroup", &tags::ManufacturersRelatedModelGroup),
-        ("referencegeometrycodesequence", &tags::ReferenceGeometryCodeSequence),
-        ("contraindicationscodesequence", &tags::ContraindicationsCodeSequence),
-        ("verticesofthepolygonalcollimator", &tags::VerticesofthePolygonalCollimator),
-        ("samplesperpixel", &tags::SamplesperPixel),
-        ("navigationindicatorsequence", &tags::NavigationIndicatorSequence),
-        ("performedstationclasscodesequence", &tags::PerformedStationClassCodeSequence),
-        ("sourceofcorneameasurementdatacodesequence", &tags::SourceofCorneaMeasurementDataCodeSequence),
-        ("abstractpriorvalue", &tags::AbstractPriorValue),
-        ("spectralwidth", &tags::SpectralWidth),
-        ("rtroirelationship", &tags::RTROIRelationship),
-        ("sortingdirection", &tags::SortingDirection),
-        ("verticesoftheoutlineofpupil", &tags::VerticesoftheOutlineofPupil),
-        ("straindescription", &tags::StrainDescription),
-        ("pixelspacingcalibrationtype", &tags::PixelSpacingCalibrationType),
-        ("referencedbolussequence", &tags::ReferencedBolusSequence),
-        ("assessmentsummarydescription", &tags::AssessmentSummaryDescription),
-        ("protocolcontextsequence", &tags::ProtocolContextSequence),
-        ("oecfvalues", &tags::OECFValues),
-        ("channelstatus", &tags::ChannelStatus),
-        ("acousticcouplingmediumflag", &tags::AcousticCouplingMediumFlag),
-        ("actualframeduration", &tags::ActualFrameDuration),
-        ("tableheadtiltangle", &tags::TableHeadTiltAngle),
-        ("maximumnumberofintervaldays", &tags::MaximumNumberofIntervalDays),
-        ("tabletoppitchadjustedangle", &tags::TableTopPitchAdjustedAngle),
-        ("numberofparallelrtbeamdelimiters", &tags::NumberofParallelRTBeamDelimiters),
-        ("treatmentdeviceidentificationsequence", &tags::TreatmentDeviceIdentificationSequence),
-        ("prcstorcsorientation", &tags::PRCStoRCSOrientation),
-        ("surfaceasymmetryindex", &tags::SurfaceAsymmetryIndex),
-        ("carrierid", &tags::CarrierID),
-        ("referencedpatientsetupnumber", &tags::ReferencedPatientSetupNumber),
-        ("distributionaddress", &tags::DistributionAddress),
-        ("fluencedatasource", &tags::FluenceDataSource),
-        ("estimatedradiographicmagnificationfactor", &tags::EstimatedRadiographicMagnificationFactor),
-        ("imagescalerepresentation", &tags::ImageScaleRepresentation),
-        ("signature", &tags::Signature),
-        ("cornealpointlocation", &tags::CornealPointLocation),
-        ("preservecompositeinstancesaftermediacreation", &tags::PreserveCompositeInstancesAfterMediaCreation),
-        ("largebluepalettecolorlookuptabledescriptor", &tags::LargeBluePaletteColorLookupTableDescriptor),
-        ("metersetexposure", &tags::MetersetExposure),
+        ("linacoutput", &tags::LINACOutput),
+        ("thresholddensity", &tags::ThresholdDensity),
+        ("dvhmeandose", &tags::DVHMeanDose),
+        ("shutterlowerhorizontaledge", &tags::ShutterLowerHorizontalEdge),
         ("implantassemblytemplateversion", &tags::ImplantAssemblyTemplateVersion),
-        ("shieldingdeviceposition", &tags::ShieldingDevicePosition),
-        ("pertinentsopclassesinstudy", &tags::PertinentSOPClassesinStudy),
-        ("distancepupillarydistance", &tags::DistancePupillaryDistance),
-        ("fieldofviewdimensions", &tags::FieldofViewDimensions),
-        ("seriesdescription", &tags::SeriesDescription),
-        ("referencedassertionuid", &tags::ReferencedAssertionUID),
-        ("annotationpropertytypemodifiercodesequence", &tags::AnnotationPropertyTypeModifierCodeSequence),
-        ("focallengthin35mmfilm", &tags::FocalLengthIn35mmFilm),
-        ("taganglesecondaxis", &tags::TagAngleSecondAxis),
-        ("waveformbitsallocated", &tags::WaveformBitsAllocated),
-        ("facetsequence", &tags::FacetSequence),
-        ("waterdepth", &tags::WaterDepth),
-        ("perimetertable", &tags::PerimeterTable),
-        ("storagemediafilesetid", &tags::StorageMediaFilesetID),
-        ("specimenaccessionnumber", &tags::SpecimenAccessionNumber),
-        ("numberofrrintervals", &tags::NumberofRRIntervals),
-        ("beltheight", &tags::BeltHeight),
-        ("widefieldophthalmicphotographyqualitythresholdsequence", &tags::WideFieldOphthalmicPhotographyQualityThresholdSequence),
-        ("performedstationnamecodesequence", &tags::PerformedStationNameCodeSequence),
-        ("approvalsubjectsequence", &tags::ApprovalSubjectSequence),
-        ("referenceddosereferencenumber", &tags::ReferencedDoseReferenceNumber),
-        ("continuationstarttotalreferenceairkerma", &tags::ContinuationStartTotalReferenceAirKerma),
-        ("itemnumber", &tags::ItemNumber),
-        ("cellvaluessequence", &tags::CellValuesSequence),
-        ("orderfilleridentifiersequence", &tags::OrderFillerIdentifierSequence),
-        ("depthvalueaveragingflag", &tags::DepthValueAveragingFlag),
-        ("endingrespiratoryamplitude", &tags::EndingRespiratoryAmplitude),
-        ("acousticcouplingmediumcodesequence", &tags::AcousticCouplingMediumCodeSequence),
-        ("filemetainformationgrouplength", &tags::FileMetaInformationGroupLength),
-        ("procedurestepprogress", &tags::ProcedureStepProgress),
-        ("item", &tags::Item),
-        ("toriciolpowersequence", &tags::ToricIOLPowerSequence),
-        ("doubleexposuremeterset", &tags::DoubleExposureMeterset),
-        ("component2referencedid", &tags::Component2ReferencedID),
-        ("barcodesymbology", &tags::BarcodeSymbology),
-        ("iolpowerforexacttargetrefraction", &tags::IOLPowerForExactTargetRefraction),
-        ("rttreatmentsourcecoordinates", &tags::RTTreatmentSourceCoordinates),
-        ("acquisitionmatrix", &tags::AcquisitionMatrix),
-        ("presentationpixelspacing", &tags::PresentationPixelSpacing),
-        ("pupildilated", &tags::PupilDilated),
-        ("numberofrtbeamlimitingdevices", &tags::NumberofRTBeamLimitingDevices),
-        ("universalentityidtype", &tags::UniversalEntityIDType),
-        ("pertinentotherevidencesequence", &tags::PertinentOtherEvidenceSequence),
-        ("numberofzerofills", &tags::NumberofZeroFills),
-        ("testpointnormalsdataflag", &tags::TestPointNormalsDataFlag),
-        ("planarconfiguration", &tags::PlanarConfiguration),
-        ("frameorigintimestamp", &tags::FrameOriginTimestamp),
-        ("rangingdepth", &tags::RangingDepth),
-        ("modifiedimagedate", &tags::ModifiedImageDate),
-        ("imageboxsynchronizationsequence", &tags::ImageBoxSynchronizationSequence),
-        ("tracksetanatomicaltypecodesequence", &tags::TrackSetAnatomicalTypeCodeSequence),
-        ("prescriptiondescription", &tags::PrescriptionDescription),
-        ("beamdosepointequivalentdepth", &tags::BeamDosePointEquivalentDepth),
-        ("lutdata", &tags::LUTData),
-        ("allowmediasplitting", &tags::AllowMediaSplitting),
-        ("predictedrefractiveerror", &tags::PredictedRefractiveError),
-        ("imagedatatypesequence", &tags::ImageDataTypeSequence),
-        ("usedfiducialssequence", &tags::UsedFiducialsSequence),
-        ("softtissuethermalindex", &tags::SoftTissueThermalIndex),
-        ("lightsourcepolarization", &tags::LightSourcePolarization),
-        ("roiinterpreter", &tags::ROIInterpreter),
-        ("metersetratedelivered", &tags::MetersetRateDelivered),
-        ("imagequalityindicatormaterial", &tags::ImageQualityIndicatorMaterial),
-        ("orderplaceridentifiersequence", &tags::OrderPlacerIdentifierSequence),
-        ("centerofcircularshutter", &tags::CenterofCircularShutter),
-        ("referencedprintjobsequencepullstoredprint", &tags::ReferencedPrintJobSequencePullStoredPrint),
-        ("performedprotocoltype", &tags::PerformedProtocolType),
-        ("tabletoppitchangle", &tags::TableTopPitchAngle),
-        ("availabletransfersyntaxuid", &tags::AvailableTransferSyntaxUID),
-        ("matrixsequence", &tags::MatrixSequence),
-        ("ionbeamlimitingdevicesequence", &tags::IonBeamLimitingDeviceSequence),
-        ("blockdivergence", &tags::BlockDivergence),
-        ("productexpirationdatetime", &tags::ProductExpirationDateTime),
-        ("blendingdisplayinputsequence", &tags::BlendingDisplayInputSequence),
-        ("smokingstatus", &tags::SmokingStatus),
-        ("referenceddefineddeviceindex", &tags::ReferencedDefinedDeviceIndex),
-        ("tomoclass", &tags::TomoClass),
-        ("histogramnumberofbins", &tags::HistogramNumberofBins),
-        ("fluencemapsequence", &tags::FluenceMapSequence),
-        ("time", &tags::Time),
-        ("secondaryapprovalstatus", &tags::SecondaryApprovalStatus),
-        ("optotypedetaileddefinition", &tags::OptotypeDetailedDefinition),
-        ("specifiednumberofpulses", &tags::SpecifiedNumberofPulses),
-        ("hangingprotocolname", &tags::HangingProtocolName),
-        ("interpretationtypeid", &tags::InterpretationTypeID),
-        ("beamspotsize", &tags::BeamSpotSize),
-        ("contextuid", &tags::ContextUID),
-        ("octfocaldistance", &tags::OCTFocalDistance),
-        ("numberofrtbeamlimitingdeviceopenings", &tags::NumberofRTBeamLimitingDeviceOpenings),
-        ("wholebodytechnique", &tags::WholeBodyTechnique),
-        ("treatmentmachinespecialmodecodesequence", &tags::TreatmentMachineSpecialModeCodeSequence),
-        ("treatmentpositionsequence", &tags::TreatmentPositionSequence),
-        ("contrastbolusagentadministered", &tags::ContrastBolusAgentAdministered),
-        ("mass", &tags::Mass),
-        ("ptolocationdescription", &tags::PTOLocationDescription),
-        ("majortickssequence", &tags::MajorTicksSequence),
-        ("rangeshiftersetting", &tags::RangeShifterSetting),
-        ("weightingtransferfunctionsequence", &tags::WeightingTransferFunctionSequence),
-        ("steepcornealaxissequence", &tags::SteepCornealAxisSequence),
-        ("ntpsourceaddress", &tags::NTPSourceAddress),
-        ("derivationconceptualvolumesequence", &tags::DerivationConceptualVolumeSequence),
-        ("planepositionsequence", &tags::PlanePositionSequence),
-        ("graphiclayer", &tags::GraphicLayer),
-        ("displaysetnumber", &tags::DisplaySetNumber),
-        ("measureddosereferencenumber", &tags::MeasuredDoseReferenceNumber),
-        ("verifyingobserveridentificationcodesequence", &tags::VerifyingObserverIdentificationCodeSequence),
-        ("beamholdoriginatingdevicesequence", &tags::BeamHoldOriginatingDeviceSequence),
-        ("datetimeoflastcalibration", &tags::DateTimeofLastCalibration),
-        ("meteringmode", &tags::MeteringMode),
-        ("tag_2dplanecoordinatessequence", &tags::Tag_2DPlaneCoordinatesSequence),
-        ("generalaccessorydescription", &tags::GeneralAccessoryDescription),
-        ("cylinderpower", &tags::CylinderPower),
-        ("referencedfiducialsuid", &tags::ReferencedFiducialsUID),
-        ("qualitythreshold", &tags::QualityThreshold),
-        ("devicediameter", &tags::DeviceDiameter),
-        ("unformattedtextvalue", &tags::UnformattedTextValue),
-        ("isocentertoblocktraydistance", &tags::IsocentertoBlockTrayDistance),
+        ("sensortemperature", &tags::SensorTemperature),
+        ("energywindowtotalwidth", &tags::EnergyWindowTotalWidth),
+        ("regionlocationminx0", &tags::RegionLocationMinX0),
+        ("operatoridentificationsequence", &tags::OperatorIdentificationSequence),
+        ("graphicgroupsequence", &tags::GraphicGroupSequence),
+        ("numberofrotations", &tags::NumberofRotations),
+        ("ophthalmicaxialmeasurementslefteyesequence", &tags::OphthalmicAxialMeasurementsLeftEyeSequence),
+        ("usercontentlabel", &tags::UserContentLabel),
+        ("strainstocksequence", &tags::StrainStockSequence),
+        ("physicaldeltax", &tags::PhysicalDeltaX),
+        ("numberoffractionsincluded", &tags::NumberofFractionsIncluded),
+        ("lensstatusdescription", &tags::LensStatusDescription),
+        ("defaultsmoothingtype", &tags::DefaultSmoothingType),
+        ("wadorsretrievalsequence", &tags::WADORSRetrievalSequence),
+        ("positionerisocentersecondaryangle", &tags::PositionerIsocenterSecondaryAngle),
+        ("primarypositionerincrement", &tags::PrimaryPositionerIncrement),
+        ("synchronizationframeofreferenceuid", &tags::SynchronizationFrameofReferenceUID),
+        ("resultsidissuer", &tags::ResultsIDIssuer),
+        ("fixedrtbeamdelimiterdevicesequence", &tags::FixedRTBeamDelimiterDeviceSequence),
+        ("imagerotation", &tags::ImageRotation),
         ("relatedrttreatmentphaseindex", &tags::RelatedRTTreatmentPhaseIndex),
-        ("filmboxcontentsequence", &tags::FilmBoxContentSequence),
-        ("fixationdevicerollangle", &tags::FixationDeviceRollAngle),
-        ("phantomtype", &tags::PhantomType),
-        ("exposurecontrolsensingregionlowerhorizontaledge", &tags::ExposureControlSensingRegionLowerHorizontalEdge),
-        ("attributeidentifierlist", &tags::AttributeIdentifierList),
-        ("tag_2dimplanttemplategroupmembermatchingpoint", &tags::Tag_2DImplantTemplateGroupMemberMatchingPoint),
-        ("numberoftimeslots", &tags::NumberofTimeSlots),
-        ("cornealwavefront", &tags::CornealWavefront),
-        ("studyarrivaltime", &tags::StudyArrivalTime),
-        ("spatialfrequencyresponsecolumns", &tags::SpatialFrequencyResponseColumns),
-        ("totalpixelmatrixoriginsequence", &tags::TotalPixelMatrixOriginSequence),
-        ("maskselectionmode", &tags::MaskSelectionMode),
-        ("radiopharmaceuticalvolume", &tags::RadiopharmaceuticalVolume),
+        ("studyreaddate", &tags::StudyReadDate),
+        ("contentqualification", &tags::ContentQualification),
+        ("chemicalshiftsequence", &tags::ChemicalShiftSequence),
+        ("referencedassertionuid", &tags::ReferencedAssertionUID),
+        ("patientsupportadjustedangle", &tags::PatientSupportAdjustedAngle),
+        ("selectorurvalue", &tags::SelectorURValue),
+        ("chemicalshiftmaximumintegrationlimitinppm", &tags::ChemicalShiftMaximumIntegrationLimitinppm),
+        ("rtrelatedroisequence", &tags::RTRelatedROISequence),
+        ("executionstatusinfo", &tags::ExecutionStatusInfo),
         ("frameofreferencetransformationcomment", &tags::FrameofReferenceTransformationComment),
-        ("blindspotxcoordinate", &tags::BlindSpotXCoordinate),
-        ("numberofleafjawpairs", &tags::NumberofLeafJawPairs),
+        ("entityname", &tags::EntityName),
+        ("absorbeddosetometersetratio", &tags::AbsorbedDosetoMetersetRatio),
+        ("referencedcontentitem", &tags::ReferencedContentItem),
+        ("privatecreatorreference", &tags::PrivateCreatorReference),
+        ("clinicalfractionnumber", &tags::ClinicalFractionNumber),
+        ("pulsespecificbrachycontrolpointdeliveredsequence", &tags::PulseSpecificBrachyControlPointDeliveredSequence),
+        ("secondarycapturedevicemanufacturersmodelname", &tags::SecondaryCaptureDeviceManufacturersModelName),
+        ("studycompletiontime", &tags::StudyCompletionTime),
+        ("referenceddosereferenceuid", &tags::ReferencedDoseReferenceUID),
+        ("registrationtypecodesequence", &tags::RegistrationTypeCodeSequence),
+        ("referencedtolerancetablenumber", &tags::ReferencedToleranceTableNumber),
+        ("procedureversion", &tags::ProcedureVersion),
+        ("constraintviolationcondition", &tags::ConstraintViolationCondition),
+        ("degreeofdilation", &tags::DegreeofDilation),
+        ("analogfiltercharacteristicssequence", &tags::AnalogFilterCharacteristicsSequence),
+        ("graphiccoordinatesdatasequence", &tags::GraphicCoordinatesDataSequence),
+        ("testresult", &tags::TestResult),
+        ("wedgetotalheight", &tags::WedgeTotalHeight),
+        ("quantifieddefect", &tags::QuantifiedDefect),
+        ("compensatorcolumns", &tags::CompensatorColumns),
+        ("digitalzoomratio", &tags::DigitalZoomRatio),
+        ("nominalcardiactriggertimepriortorpeak", &tags::NominalCardiacTriggerTimePriorToRPeak),
+        ("pixelvaluemappingexplanation", &tags::PixelValueMappingExplanation),
+        ("percentphasefieldofview", &tags::PercentPhaseFieldofView),
+        ("referencedframenumbers", &tags::ReferencedFrameNumbers),
+        ("referencedplanoverviewindex", &tags::ReferencedPlanOverviewIndex),
+        ("sensitivity", &tags::Sensitivity),
+        ("numberofcompletedsuboperations", &tags::NumberofCompletedSuboperations),
+        ("templatelocalversion", &tags::TemplateLocalVersion),
+        ("tabletoplongitudinalposition", &tags::TableTopLongitudinalPosition),
+        ("rtaccessoryholderslotdistance", &tags::RTAccessoryHolderSlotDistance),
+        ("numberofblocks", &tags::NumberofBlocks),
+        ("bscancycletime", &tags::BscanCycleTime),
+        ("procedurecontextflag_trial", &tags::ProcedureContextFlag_Trial),
+        ("reconstructionmethod", &tags::ReconstructionMethod),
+        ("gpstrackref", &tags::GPSTrackRef),
+        ("phasedelay", &tags::PhaseDelay),
+        ("implanttemplategroupmembermatching2dcoordinatessequence", &tags::ImplantTemplateGroupMemberMatching2DCoordinatesSequence),
+        ("structureddisplayimageboxsequence", &tags::StructuredDisplayImageBoxSequence),
+        ("phantomtype", &tags::PhantomType),
+        ("imagednucleus", &tags::ImagedNucleus),
+        ("numberofhorizontalpixels", &tags::NumberofHorizontalPixels),
+        ("hl7instanceidentifier", &tags::HL7InstanceIdentifier),
+        ("bluepalettecolorlookuptabledata", &tags::BluePaletteColorLookupTableData),
+        ("badpixelimage", &tags::BadPixelImage),
+        ("tabletoppitchadjustedangle", &tags::TableTopPitchAdjustedAngle),
+        ("patientsupportpositiontolerancesequence", &tags::PatientSupportPositionToleranceSequence),
+        ("numberofscreens", &tags::NumberofScreens),
+        ("detectortemperature", &tags::DetectorTemperature),
+        ("issuerofthespecimenidentifiersequence", &tags::IssueroftheSpecimenIdentifierSequence),
+        ("lutframerange", &tags::LUTFrameRange),
+        ("sourcetype", &tags::SourceType),
+        ("decaycorrected", &tags::DecayCorrected),
+        ("surfacesequence", &tags::SurfaceSequence),
+        ("additionalrtroiidentificationcodesequence", &tags::AdditionalRTROIIdentificationCodeSequence),
+        ("presentationpixelspacing", &tags::PresentationPixelSpacing),
+        ("additionalrtaccessorydevicesequence", &tags::AdditionalRTAccessoryDeviceSequence),
+        ("contributiondatetime", &tags::ContributionDateTime),
+        ("inconcatenationtotalnumber", &tags::InconcatenationTotalNumber),
+        ("hpgldocumentsequence", &tags::HPGLDocumentSequence),
+        ("roiobservationdescription", &tags::ROIObservationDescription),
+        ("protocolcontextsequence", &tags::ProtocolContextSequence),
+        ("contrastbolusingredientopaque", &tags::ContrastBolusIngredientOpaque),
+        ("selectorvaluenumber", &tags::SelectorValueNumber),
+        ("ooitypedescriptor", &tags::OOITypeDescriptor),
+        ("attributemodificationdatetime", &tags::AttributeModificationDateTime),
+        ("rtbeammodifierdefinitiondistance", &tags::RTBeamModifierDefinitionDistance),
+        ("measureddosedescription", &tags::MeasuredDoseDescription),
+        ("endmeterset", &tags::EndMeterset),
+        ("printerpixelspacing", &tags::PrinterPixelSpacing),
+        ("functionalmrsequence", &tags::FunctionalMRSequence),
+        ("alternaterepresentationsequence", &tags::AlternateRepresentationSequence),
+        ("smallestvalidpixelvalue", &tags::SmallestValidPixelValue),
+        ("radiopharmaceuticalcodesequence", &tags::RadiopharmaceuticalCodeSequence),
+        ("aircounts", &tags::AirCounts),
+        ("aslboluscutoffdelaytime", &tags::ASLBolusCutoffDelayTime),
+        ("measurementequipmenttype", &tags::MeasurementEquipmentType),
+        ("coordinatesystemnumberofaxes", &tags::CoordinateSystemNumberofAxes),
+        ("typeofsynchronization", &tags::TypeofSynchronization),
+        ("renderprojection", &tags::RenderProjection),
+        ("recommendedviewingmode", &tags::RecommendedViewingMode),
+        ("doubleexposuremeterset_trial", &tags::DoubleExposureMeterset_Trial),
+        ("measuredbeamdimensiona", &tags::MeasuredBeamDimensionA),
+        ("abortreason", &tags::AbortReason),
+        ("rgbatransferfunctiondescription", &tags::RGBATransferFunctionDescription),
+        ("channeldeliveryorderindex", &tags::ChannelDeliveryOrderIndex),
+        ("falsepositivesestimateflag", &tags::FalsePositivesEstimateFlag),
+        ("ionbeamsequence", &tags::IonBeamSequence),
+        ("emptyvaluematchingsequence", &tags::EmptyValueMatchingSequence),
+        ("transformationmethodcodesequence", &tags::TransformationMethodCodeSequence),
+        ("fovealsensitivitymeasured", &tags::FovealSensitivityMeasured),
+        ("tickalignment", &tags::TickAlignment),
+        ("shifttabletriplet", &tags::ShiftTableTriplet),
+        ("rotationdirection", &tags::RotationDirection),
+        ("probeorientationangle", &tags::ProbeOrientationAngle),
+        ("prescriptionoverviewsequence", &tags::PrescriptionOverviewSequence),
+        ("blocktrayid", &tags::BlockTrayID),
+        ("numberofdisplaysubsystems", &tags::NumberofDisplaySubsystems),
+        ("organatriskfullvolumedose", &tags::OrganatRiskFullvolumeDose),
+        ("showacquisitiontechniquesflag", &tags::ShowAcquisitionTechniquesFlag),
+        ("decoupling", &tags::Decoupling),
+        ("cameraownername", &tags::CameraOwnerName),
+        ("sourcestrength", &tags::SourceStrength),
+        ("sourceenddatetime", &tags::SourceEndDateTime),
+        ("recommendedpointradius", &tags::RecommendedPointRadius),
+        ("chemicalshiftminimumintegrationlimitinppm", &tags::ChemicalShiftMinimumIntegrationLimitinppm),
+        ("patientmotioncorrected", &tags::PatientMotionCorrected),
+        ("contactdisplayname", &tags::ContactDisplayName),
+        ("participantsequence", &tags::ParticipantSequence),
+        ("referencedsopclassuidinfile", &tags::ReferencedSOPClassUIDinFile),
+        ("bibliographiccitation_trial", &tags::BibliographicCitation_Trial),
+        ("filmsizeid", &tags::FilmSizeID),
+        ("backgroundilluminationcolorcodesequence", &tags::BackgroundIlluminationColorCodeSequence),
+        ("deliveryrateunitsequence", &tags::DeliveryRateUnitSequence),
+        ("clinicaltrialtimepointtypecodesequence", &tags::ClinicalTrialTimePointTypeCodeSequence),
+        ("reasonforomissioncodesequence", &tags::ReasonforOmissionCodeSequence),
+        ("navigationindicatorsequence", &tags::NavigationIndicatorSequence),
+        ("attachedcontours", &tags::AttachedContours),
+        ("positionofisocenterprojection", &tags::PositionofIsocenterProjection),
+        ("graphicfilled", &tags::GraphicFilled),
+        ("tag_3dmatingaxes", &tags::Tag_3DMatingAxes),
+        ("elementpitcha", &tags::ElementPitchA),
+        ("numberofstudyrelatedseries", &tags::NumberofStudyRelatedSeries),
+        ("relatedseriessequence", &tags::RelatedSeriesSequence),
         ("outlinerightverticaledge", &tags::OutlineRightVerticalEdge),
-        ("reportdetailsequence_trial", &tags::ReportDetailSequence_Trial),
-        ("transfersyntaxuid", &tags::TransferSyntaxUID),
-        ("imagepositionpatient", &tags::ImagePositionPatient),
-        ("presentationsequencecollectionuid", &tags::PresentationSequenceCollectionUID),
-        ("identificationdescription_trial", &tags::IdentificationDescription_Trial),
-        ("patienttransportarrangements", &tags::PatientTransportArrangements),
-        ("intensifieractivedimensions", &tags::IntensifierActiveDimensions),
-        ("subjectiverefractionlefteyesequence", &tags::SubjectiveRefractionLeftEyeSequence),
-        ("vectorgriddata", &tags::VectorGridData),
-        ("referencedspatialregistrationsequence", &tags::ReferencedSpatialRegistrationSequence),
-        ("inventoryaccessendpointssequence", &tags::InventoryAccessEndPointsSequence),
-        ("instructionperformeddatetime", &tags::InstructionPerformedDateTime),
-        ("primarydosevalueindicator", &tags::PrimaryDoseValueIndicator),
-        ("spherepower", &tags::SpherePower),
-        ("deviceorderindex", &tags::DeviceOrderIndex),
-        ("materialscodesequence", &tags::MaterialsCodeSequence),
-        ("studydescription", &tags::StudyDescription),
-        ("wedgeorientation", &tags::WedgeOrientation),
-        ("selectorucvalue", &tags::SelectorUCValue),
-        ("ooiownercreationtime", &tags::OOIOwnerCreationTime),
-        ("flashmode", &tags::FlashMode),
-        ("procedurecreationdate", &tags::ProcedureCreationDate),
-        ("imageboxoverlappriority", &tags::ImageBoxOverlapPriority),
-        ("darkcurrentcounts", &tags::DarkCurrentCounts),
-        ("selectorovvalue", &tags::SelectorOVValue),
-        ("dvhnormalizationdosevalue", &tags::DVHNormalizationDoseValue),
-        ("relativetime", &tags::RelativeTime),
-        ("observationdatetime", &tags::ObservationDateTime),
-        ("presentationlutsequence", &tags::PresentationLUTSequence),
-        ("subtractionitemid", &tags::SubtractionItemID),
-        ("numberofframesintegrated", &tags::NumberofFramesIntegrated),
-        ("compressionforce", &tags::CompressionForce),
-        ("requestedsopclassuid", &tags::RequestedSOPClassUID),
-        ("radiationmachinesad", &tags::RadiationMachineSAD),
-        ("basicgrayscaleimagesequence", &tags::BasicGrayscaleImageSequence),
-        ("dischargediagnosiscodesequence", &tags::DischargeDiagnosisCodeSequence),
-        ("multiplexedaudiochannelsdescriptioncodesequence", &tags::MultiplexedAudioChannelsDescriptionCodeSequence),
-        ("surfacemeshprimitivessequence", &tags::SurfaceMeshPrimitivesSequence),
-        ("privatedataelementcharacteristicssequence", &tags::PrivateDataElementCharacteristicsSequence),
-        ("waveformdisplaybackgroundcielabvalue", &tags::WaveformDisplayBackgroundCIELabValue),
-        ("assessmentobservationssequence", &tags::AssessmentObservationsSequence),
-        ("cssfontname", &tags::CSSFontName),
-        ("implanttargetanatomysequence", &tags::ImplantTargetAnatomySequence),
-        ("videoimageformatacquired", &tags::VideoImageFormatAcquired),
-        ("referencedimageboxsequence", &tags::ReferencedImageBoxSequence),
-        ("roigenerationalgorithm", &tags::ROIGenerationAlgorithm),
-        ("localdeviationprobabilitynormalsflag", &tags::LocalDeviationProbabilityNormalsFlag),
-        ("excitationwavelength", &tags::ExcitationWavelength),
-        ("depthsoffocus", &tags::DepthsofFocus),
-        ("referencedprocedurestepsequence", &tags::ReferencedProcedureStepSequence),
-        ("defaultmagnificationtype", &tags::DefaultMagnificationType),
-        ("therapydescription", &tags::TherapyDescription),
-        ("lutexplanation", &tags::LUTExplanation),
-        ("scheduledprocedurestepdescription", &tags::ScheduledProcedureStepDescription),
-        ("xrayacquisitiondosesequence", &tags::XRayAcquisitionDoseSequence),
-        ("flatcornealaxissequence", &tags::FlatCornealAxisSequence),
-        ("studyinstanceuid", &tags::StudyInstanceUID),
-        ("observationsubjectcontextflag_trial", &tags::ObservationSubjectContextFlag_Trial),
-        ("fractiongroupsequence", &tags::FractionGroupSequence),
-        ("magnifytonumberofcolumns", &tags::MagnifytoNumberofColumns),
-        ("whitebalance", &tags::WhiteBalance),
-        ("toriciolpowerforexactemmetropiasequence", &tags::ToricIOLPowerforExactEmmetropiaSequence),
-        ("pertinentdocumentssequence", &tags::PertinentDocumentsSequence),
-        ("energywindowinformationsequence", &tags::EnergyWindowInformationSequence),
-        ("ivuspullbackrate", &tags::IVUSPullbackRate),
-        ("beammodifiercoordinatespresenceflag", &tags::BeamModifierCoordinatesPresenceFlag),
-        ("primarypositionerincrementsign", &tags::PrimaryPositionerIncrementSign),
-        ("visualfieldverticalextent", &tags::VisualFieldVerticalExtent),
-        ("radiopharmaceuticalstartdatetime", &tags::RadiopharmaceuticalStartDateTime),
-        ("pixelcomponentrangestart", &tags::PixelComponentRangeStart),
-        ("gpslatituderef", &tags::GPSLatitudeRef),
-        ("deliveredpulserepetitioninterval", &tags::DeliveredPulseRepetitionInterval),
-        ("componentassemblysequence", &tags::ComponentAssemblySequence),
-        ("compensatorpixelspacing", &tags::CompensatorPixelSpacing),
-        ("documentingorganizationidentifiercodesequence_trial", &tags::DocumentingOrganizationIdentifierCodeSequence_Trial),
-        ("rttreatmentphaseintentpresenceflag", &tags::RTTreatmentPhaseIntentPresenceFlag),
-        ("tag_2ddegreeoffreedomaxis", &tags::Tag_2DDegreeofFreedomAxis),
-        ("displaysetpresentationgroupdescription", &tags::DisplaySetPresentationGroupDescription),
-        ("currentrequestedprocedureevidencesequence", &tags::CurrentRequestedProcedureEvidenceSequence),
-        ("fixationdevicepitchangle", &tags::FixationDevicePitchAngle),
-        ("mrarterialspinlabelingsequence", &tags::MRArterialSpinLabelingSequence),
-        ("ophthalmicpatientclinicalinformationrighteyesequence", &tags::OphthalmicPatientClinicalInformationRightEyeSequence),
-        ("dimensionindexpointer", &tags::DimensionIndexPointer),
-        ("brachyaccessorydevicenominalthickness", &tags::BrachyAccessoryDeviceNominalThickness),
-        ("annotationflag", &tags::AnnotationFlag),
-        ("measurementvaluessequence", &tags::MeasurementValuesSequence),
-        ("receivingpresentationaddress", &tags::ReceivingPresentationAddress),
-        ("referencedstopcontrolpointindex", &tags::ReferencedStopControlPointIndex),
-        ("updatedmetadatasequence", &tags::UpdatedMetadataSequence),
-        ("positionacquisitiontemplatename", &tags::PositionAcquisitionTemplateName),
-        ("treatmenttoleranceviolationattributesequence", &tags::TreatmentToleranceViolationAttributeSequence),
-        ("transducerbeamsteeringcodesequence", &tags::TransducerBeamSteeringCodeSequence),
-        ("parallelreductionfactorsecondinplane", &tags::ParallelReductionFactorSecondInplane),
+        ("specificcharacterset", &tags::SpecificCharacterSet),
+        ("inventorypurpose", &tags::InventoryPurpose),
+        ("typeofopticalcorrection", &tags::TypeofOpticalCorrection),
+        ("originalimagesequence", &tags::OriginalImageSequence),
+        ("rtvcommunicationsopinstanceuid", &tags::RTVCommunicationSOPInstanceUID),
+        ("batterylevel", &tags::BatteryLevel),
+        ("rtradiationsetusage", &tags::RTRadiationSetUsage),
+        ("treatmenttoleranceviolationdescription", &tags::TreatmentToleranceViolationDescription),
+        ("tabletoplateralsetupdisplacement", &tags::TableTopLateralSetupDisplacement),
+        ("imagecenterpointcoordinatessequence", &tags::ImageCenterPointCoordinatesSequence),
+        ("reportnumber", &tags::ReportNumber),
+        ("presentationpixelmagnificationratio", &tags::PresentationPixelMagnificationRatio),
+        ("focaldistance", &tags::FocalDistance),
+        ("equivalentcodesequence", &tags::EquivalentCodeSequence),
+        ("sourcetocompensatortraydistance", &tags::SourcetoCompensatorTrayDistance),
+        ("codingschemeurltype", &tags::CodingSchemeURLType),
+        ("patternonopacity", &tags::PatternOnOpacity),
+        ("observationdate_trial", &tags::ObservationDate_Trial),
+        ("targetpositionreferenceindicator", &tags::TargetPositionReferenceIndicator),
+        ("aslslaborientation", &tags::ASLSlabOrientation),
+        ("surfaceprocessing", &tags::SurfaceProcessing),
+        ("illuminatortypecodesequence", &tags::IlluminatorTypeCodeSequence),
+        ("mracquisitionphaseencodingstepsinplane", &tags::MRAcquisitionPhaseEncodingStepsinplane),
+        ("maxaperturevalue", &tags::MaxApertureValue),
+        ("functionalgroupprivatecreator", &tags::FunctionalGroupPrivateCreator),
+        ("protocoldesignrationale", &tags::ProtocolDesignRationale),
+        ("mrspatialsaturationsequence", &tags::MRSpatialSaturationSequence),
+        ("intravascularframecontentsequence", &tags::IntravascularFrameContentSequence),
+        ("gpsdestlatitude", &tags::GPSDestLatitude),
+        ("applicationmaximumrepainttime", &tags::ApplicationMaximumRepaintTime),
+        ("emittercolortemperature", &tags::EmitterColorTemperature),
+        ("graphicannotationsequence", &tags::GraphicAnnotationSequence),
+        ("ntpsourceaddress", &tags::NTPSourceAddress),
+        ("oecfvalues", &tags::OECFValues),
+        ("numericvaluequalifiercodesequence", &tags::NumericValueQualifierCodeSequence),
+        ("soundpathlength", &tags::SoundPathLength),
+        ("impedancemeasurementcurrenttype", &tags::ImpedanceMeasurementCurrentType),
+        ("mediumtype", &tags::MediumType),
+        ("generalizeddefectcorrectedsensitivitydeviationvalue", &tags::GeneralizedDefectCorrectedSensitivityDeviationValue),
+        ("reconstructionindex", &tags::ReconstructionIndex),
+        ("steepcornealaxissequence", &tags::SteepCornealAxisSequence),
+        ("nominalrespiratorytriggerdelaytime", &tags::NominalRespiratoryTriggerDelayTime),
+        ("lightsourcepolarization", &tags::LightSourcePolarization),
+        ("plateid", &tags::PlateID),
+        ("referencedbeamnumber", &tags::ReferencedBeamNumber),
+        ("graphiclayerdescription", &tags::GraphicLayerDescription),
+        ("optotype", &tags::Optotype),
+        ("mimetypeofencapsulateddocument", &tags::MIMETypeofEncapsulatedDocument),
+        ("bridgeresistors", &tags::BridgeResistors),
+        ("treatmenttoleranceviolationidentification", &tags::TreatmentToleranceViolationIdentification),
+        ("variablemodalitylutsequence", &tags::VariableModalityLUTSequence),
+        ("presentationinputtype", &tags::PresentationInputType),
+        ("performedprocedurestepdescription", &tags::PerformedProcedureStepDescription),
+        ("indicationdisposition", &tags::IndicationDisposition),
+        ("curvedimensions", &tags::CurveDimensions),
+        ("cardiacsynchronizationsequence", &tags::CardiacSynchronizationSequence),
+        ("sourcehangingprotocolsequence", &tags::SourceHangingProtocolSequence),
+        ("coefficientssdvn", &tags::CoefficientsSDVN),
+        ("rwavepointer", &tags::RWavePointer),
+        ("multiplexgrouptimeoffset", &tags::MultiplexGroupTimeOffset),
+        ("rescaletype", &tags::RescaleType),
+        ("usedrtstructuresetroisequence", &tags::UsedRTStructureSetROISequence),
+        ("fixationdevicelabel", &tags::FixationDeviceLabel),
+        ("vectorcoordinatedata", &tags::VectorCoordinateData),
+        ("dopplersamplevolumeyposition_retired", &tags::DopplerSampleVolumeYPosition_Retired),
         ("reportproductionstatus_trial", &tags::ReportProductionStatus_Trial),
-        ("contrastbolusingredientpercentbyvolume", &tags::ContrastBolusIngredientPercentbyVolume),
-        ("potentialreasonsforprocedurecodesequence", &tags::PotentialReasonsforProcedureCodeSequence),
-        ("scheduledprocedurestependdate", &tags::ScheduledProcedureStepEndDate),
-        ("shadowstyle", &tags::ShadowStyle),
-        ("irradiationeventidentificationsequence", &tags::IrradiationEventIdentificationSequence),
-        ("rttreatmentterminationreasoncodesequence", &tags::RTTreatmentTerminationReasonCodeSequence),
-        ("specimenpreparationsequence", &tags::SpecimenPreparationSequence),
+        ("ophthalmicmappingdevicetype", &tags::OphthalmicMappingDeviceType),
+        ("pulsertype", &tags::PulserType),
+        ("deidentificationaction", &tags::DeidentificationAction),
+        ("incidentangle", &tags::IncidentAngle),
+        ("reconstructiondescription", &tags::ReconstructionDescription),
+        ("issuerofadmissionid", &tags::IssuerofAdmissionID),
+        ("contributiondescription", &tags::ContributionDescription),
+        ("scheduledstationaetitle", &tags::ScheduledStationAETitle),
+        ("setuptechnique", &tags::SetupTechnique),
+        ("sourcerollangle", &tags::SourceRollAngle),
+        ("rttreatmentfractioncompletionstatus", &tags::RTTreatmentFractionCompletionStatus),
+        ("sopclassuid", &tags::SOPClassUID),
+        ("secondarycapturedevicemanufacturer", &tags::SecondaryCaptureDeviceManufacturer),
+        ("beamsequence", &tags::BeamSequence),
+        ("scatterfractionfactor", &tags::ScatterFractionFactor),
+        ("assessmentsummarydescription", &tags::AssessmentSummaryDescription),
+        ("containercomponentsequence", &tags::ContainerComponentSequence),
+        ("referencedxraydetectorindex", &tags::ReferencedXRayDetectorIndex),
+        ("personstelecominformation", &tags::PersonsTelecomInformation),
+        ("focusmethod", &tags::FocusMethod),
+        ("storedinstancebaseuri", &tags::StoredInstanceBaseURI),
+        ("defaultmagnificationtype", &tags::DefaultMagnificationType),
         ("distancesourcetodatacollectioncenter", &tags::DistanceSourcetoDataCollectionCenter),
-        ("instancelevelreferencedperformedprocedurestepsequence", &tags::InstanceLevelReferencedPerformedProcedureStepSequence),
-        ("referencedcompensatornumber", &tags::ReferencedCompensatorNumber),
-        ("numberofpulses", &tags::NumberofPulses),
-        ("imageboxposition", &tags::ImageBoxPosition),
-        ("grid", &tags::Grid),
-        ("patternonopacity", &tags::PatternOnOpacity),
-        ("segmentedbluepalettecolorlookuptabledata", &tags::SegmentedBluePaletteColorLookupTableData),
-        ("gantrypitchangle", &tags::GantryPitchAngle),
-        ("cardiaccycleposition", &tags::CardiacCyclePosition),
-        ("countryofresidence", &tags::CountryofResidence),
-        ("radiationdosemeasurementpointcoordinates", &tags::RadiationDoseMeasurementPointCoordinates),
-        ("sourceacquisitionbeamnumber", &tags::SourceAcquisitionBeamNumber),
-        ("leafpositionboundaries", &tags::LeafPositionBoundaries),
-        ("analogfiltertypecodesequence", &tags::AnalogFilterTypeCodeSequence),
-        ("presentationcreationdate", &tags::PresentationCreationDate),
-        ("finalcumulativetimeweight", &tags::FinalCumulativeTimeWeight),
-        ("illuminationtranslationflag", &tags::IlluminationTranslationFlag),
-        ("excessivefixationlossesdataflag", &tags::ExcessiveFixationLossesDataFlag),
-        ("dvhminimumdose", &tags::DVHMinimumDose),
-        ("echopeakposition", &tags::EchoPeakPosition),
-        ("radiobiologicaldoseeffectflag", &tags::RadiobiologicalDoseEffectFlag),
-        ("cumulativemeterset", &tags::CumulativeMeterset),
-        ("materialpipediameter", &tags::MaterialPipeDiameter),
-        ("deadtimefactor", &tags::DeadTimeFactor),
-        ("histogramsequence", &tags::HistogramSequence),
-        ("channelsensitivityunitssequence", &tags::ChannelSensitivityUnitsSequence),
-        ("displacementmatrix", &tags::DisplacementMatrix),
-        ("documentidentifiercodesequence_trial", &tags::DocumentIdentifierCodeSequence_Trial),
-        ("toplefthandcorneroflocalizerarea", &tags::TopLeftHandCornerofLocalizerArea),
-        ("blendingmode", &tags::BlendingMode),
-        ("imagingsourcelocationspecificationtype", &tags::ImagingSourceLocationSpecificationType),
-        ("institutionname", &tags::InstitutionName),
-        ("luminanceresultsequence", &tags::LuminanceResultSequence),
-        ("implanttemplategrouptargetanatomysequence", &tags::ImplantTemplateGroupTargetAnatomySequence),
-        ("tabletopeccentricaxisdistance", &tags::TableTopEccentricAxisDistance),
-        ("b1rms", &tags::B1rms),
-        ("surfacemeshzpixeloffset", &tags::SurfaceMeshZPixelOffset),
-        ("totalcompensatortrayfactor", &tags::TotalCompensatorTrayFactor),
-        ("rtimageposition", &tags::RTImagePosition),
-        ("imagingsourcepositionsequence", &tags::ImagingSourcePositionSequence),
-        ("compensatordescription", &tags::CompensatorDescription),
-        ("imagingdevicelocationparametersequence", &tags::ImagingDeviceLocationParameterSequence),
-        ("pixelintensityrelationship", &tags::PixelIntensityRelationship),
-        ("protocoldesignrationale", &tags::ProtocolDesignRationale),
-        ("vvaluedata", &tags::VValueData),
-        ("tag_2dmatingaxes", &tags::Tag_2DMatingAxes),
-        ("sourcemanufacturer", &tags::SourceManufacturer),
-        ("maskvisibilitypercentage", &tags::MaskVisibilityPercentage),
-        ("referencedroinumber", &tags::ReferencedROINumber),
-        ("blocksequence", &tags::BlockSequence),
-        ("convolutionkernelgroup", &tags::ConvolutionKernelGroup),
-        ("realworldvaluelutdata", &tags::RealWorldValueLUTData),
-        ("channelposition", &tags::ChannelPosition),
-        ("textstring", &tags::TextString),
-        ("elementpitcha", &tags::ElementPitchA),
-        ("roielementalcompositionsequence", &tags::ROIElementalCompositionSequence),
-        ("templatelocalversion", &tags::TemplateLocalVersion),
-        ("measurementfunctions", &tags::MeasurementFunctions),
-        ("planeidentification", &tags::PlaneIdentification),
-        ("deliveryverificationimagesequence", &tags::DeliveryVerificationImageSequence),
-        ("rtplandescription", &tags::RTPlanDescription),
-        ("contrastflowduration", &tags::ContrastFlowDuration),
-        ("relativeelevation", &tags::RelativeElevation),
-        ("referencedstartcontrolpointindex", &tags::ReferencedStartControlPointIndex),
-        ("rawdatahandling", &tags::RawDataHandling),
-        ("wedgeid", &tags::WedgeID),
-        ("referencedsegmentnumber", &tags::ReferencedSegmentNumber),
-        ("lesionnumber", &tags::LesionNumber),
-        ("secondarycountsaccumulated", &tags::SecondaryCountsAccumulated),
-        ("soundspeedcorrectionmechanismcodesequence", &tags::SoundSpeedCorrectionMechanismCodeSequence),
-        ("reasonforchannelomission", &tags::ReasonforChannelOmission),
-        ("generalaccessorysequence", &tags::GeneralAccessorySequence),
-        ("gpsdestlatituderef", &tags::GPSDestLatitudeRef),
-        ("timerange", &tags::TimeRange),
-        ("referencedrtpatientsetupsequence", &tags::ReferencedRTPatientSetupSequence),
-        ("privatedataelementname", &tags::PrivateDataElementName),
-        ("ambientlightvaluesource", &tags::AmbientLightValueSource),
-        ("positionerisocenterprimaryangle", &tags::PositionerIsocenterPrimaryAngle),
-        ("stagename", &tags::StageName),
-        ("referencedophthalmicaxialmeasurementssequence", &tags::ReferencedOphthalmicAxialMeasurementsSequence),
-        ("compensatormaporientation", &tags::CompensatorMapOrientation),
-        ("tableframeofreferenceuid", &tags::TableFrameofReferenceUID),
-        ("objectivelenspower", &tags::ObjectiveLensPower),
-        ("flashfunctionpresent", &tags::FlashFunctionPresent),
+        ("fractiongrouptype", &tags::FractionGroupType),
+        ("fillerordernumberimagingservicerequest", &tags::FillerOrderNumberImagingServiceRequest),
         ("specimendetaileddescription", &tags::SpecimenDetailedDescription),
-        ("cardiacsignalsource", &tags::CardiacSignalSource),
-        ("recordedlateralspreadingdevicesequence", &tags::RecordedLateralSpreadingDeviceSequence),
-        ("stereohorizontalpixeloffset", &tags::StereoHorizontalPixelOffset),
-        ("templateextensioncreatoruid", &tags::TemplateExtensionCreatorUID),
-        ("anatomicportalofentrancecodesequence_trial", &tags::AnatomicPortalOfEntranceCodeSequence_Trial),
-        ("applicatortype", &tags::ApplicatorType),
-        ("selectorunvalue", &tags::SelectorUNValue),
-        ("numberoftomosynthesissourceimages", &tags::NumberofTomosynthesisSourceImages),
-        ("exposurecontrolmodedescription", &tags::ExposureControlModeDescription),
-        ("greenpalettecolorlookuptabledata", &tags::GreenPaletteColorLookupTableData),
-        ("graphiclayerorder", &tags::GraphicLayerOrder),
-        ("requestedprocedurepriority", &tags::RequestedProcedurePriority),
-        ("clinicaltrialsubjectreadingid", &tags::ClinicalTrialSubjectReadingID),
-        ("performedworkitemcodesequence", &tags::PerformedWorkitemCodeSequence),
-        ("endcumulativemetersetweight", &tags::EndCumulativeMetersetWeight),
-        ("operatoridentificationsequence", &tags::OperatorIdentificationSequence),
-        ("fluencemodeid", &tags::FluenceModeID),
-        ("constantvolumeflag", &tags::ConstantVolumeFlag),
-        ("reformattingoperationtype", &tags::ReformattingOperationType),
-        ("energyweightingfactor", &tags::EnergyWeightingFactor),
-        ("autosequenceflag", &tags::AutosequenceFlag),
-        ("aslboluscutofftimingsequence", &tags::ASLBolusCutoffTimingSequence),
-        ("spatialfrequencyresponsecolumnnames", &tags::SpatialFrequencyResponseColumnNames),
-        ("applicablesafetystandarddescription", &tags::ApplicableSafetyStandardDescription),
-        ("referencedtreatmentrecordsequence", &tags::ReferencedTreatmentRecordSequence),
-        ("waveformamplifiertype", &tags::WaveformAmplifierType),
-        ("ctdivolnotificationtrigger", &tags::CTDIvolNotificationTrigger),
-        ("segmentedalphapalettecolorlookuptabledata", &tags::SegmentedAlphaPaletteColorLookupTableData),
-        ("blocknumber", &tags::BlockNumber),
-        ("vitreousstatusdescription", &tags::VitreousStatusDescription),
-        ("mrreceivecoilsequence", &tags::MRReceiveCoilSequence),
-        ("acquisitiontype", &tags::AcquisitionType),
-        ("interlockdatetime", &tags::InterlockDateTime),
-        ("shutterrightverticaledge", &tags::ShutterRightVerticalEdge),
-        ("pulserequipmentsequence", &tags::PulserEquipmentSequence),
-        ("performedlocation", &tags::PerformedLocation),
-        ("requestingae", &tags::RequestingAE),
-        ("blocknumberofpoints", &tags::BlockNumberofPoints),
-        ("camerapositiongroupsequence", &tags::CameraPositionGroupSequence),
-        ("positionacquisitiontemplateidentificationsequence", &tags::PositionAcquisitionTemplateIdentificationSequence),
+        ("timeoflastcalibration", &tags::TimeofLastCalibration),
+        ("horizontalalignment", &tags::HorizontalAlignment),
+        ("registeredlocalizerbottomrighthandcorner", &tags::RegisteredLocalizerBottomRightHandCorner),
+        ("organizationalrolecodesequence", &tags::OrganizationalRoleCodeSequence),
+        ("sourceofanteriorchamberdepthdatacodesequence", &tags::SourceofAnteriorChamberDepthDataCodeSequence),
+        ("waveformbitsstored", &tags::WaveformBitsStored),
+        ("positionerprimaryangleincrement", &tags::PositionerPrimaryAngleIncrement),
+        ("decimalvisualacuity", &tags::DecimalVisualAcuity),
+        ("segmentedpropertytypemodifiercodesequence", &tags::SegmentedPropertyTypeModifierCodeSequence),
+        ("signaltonoiseratio", &tags::SignaltoNoiseRatio),
+        ("selectoratvalue", &tags::SelectorATValue),
+        ("photographicexposureindex", &tags::PhotographicExposureIndex),
+        ("nominalpercentageofrespiratoryphase", &tags::NominalPercentageofRespiratoryPhase),
+        ("centerofpto", &tags::CenterofPTO),
+        ("projectioneponymousnamecodesequence", &tags::ProjectionEponymousNameCodeSequence),
+        ("verticesofthepolygonaloutline", &tags::VerticesofthePolygonalOutline),
+        ("multicoilconfiguration", &tags::MultiCoilConfiguration),
+        ("energyderivationcodesequence", &tags::EnergyDerivationCodeSequence),
+        ("columnoverlap", &tags::ColumnOverlap),
+        ("chemicalshiftminimumintegrationlimitinhz", &tags::ChemicalShiftMinimumIntegrationLimitinHz),
+        ("retaininstances", &tags::RetainInstances),
+        ("reference", &tags::Reference),
+        ("overlaydescription", &tags::OverlayDescription),
+        ("lateralspreadingdevicedescription", &tags::LateralSpreadingDeviceDescription),
+        ("deliverednominalrangemodulationfractions", &tags::DeliveredNominalRangeModulationFractions),
+        ("requestedproceduredescription_trial", &tags::RequestedProcedureDescription_Trial),
+        ("userselectedphase", &tags::UserSelectedPhase),
+        ("scanspotprescribedindices", &tags::ScanSpotPrescribedIndices),
+        ("monoenergeticenergyequivalent", &tags::MonoenergeticEnergyEquivalent),
+        ("energywindownumber", &tags::EnergyWindowNumber),
+        ("sourcepresentationaddress", &tags::SourcePresentationAddress),
+        ("tabletoppitchrotationdirection", &tags::TableTopPitchRotationDirection),
         ("bonethermalindex", &tags::BoneThermalIndex),
-        ("recommendeddefaultvaluesequence", &tags::RecommendedDefaultValueSequence),
-        ("nominalrespiratorytriggerdelaytime", &tags::NominalRespiratoryTriggerDelayTime),
-        ("institutionaldepartmenttypecodesequence", &tags::InstitutionalDepartmentTypeCodeSequence),
-        ("organdose", &tags::OrganDose),
-        ("component2referencedmatingfeaturesetid", &tags::Component2ReferencedMatingFeatureSetID),
-        ("routesegmentstartlocationid", &tags::RouteSegmentStartLocationID),
-        ("confirmationsequence", &tags::ConfirmationSequence),
-        ("collimatorupperhorizontaledge", &tags::CollimatorUpperHorizontalEdge),
-        ("codingschemeexternalid", &tags::CodingSchemeExternalID),
-        ("maximummemoryallocation", &tags::MaximumMemoryAllocation),
-        ("patientsupportaccessorycode", &tags::PatientSupportAccessoryCode),
-        ("sourcedescription", &tags::SourceDescription),
-        ("assertioncodesequence", &tags::AssertionCodeSequence),
-        ("ophthalmicaxiallengthdatasourcecodesequence", &tags::OphthalmicAxialLengthDataSourceCodeSequence),
-        ("numberofscreens", &tags::NumberofScreens),
-        ("primaryfluencemodesequence", &tags::PrimaryFluenceModeSequence),
-        ("interlockresolutioncodesequence", &tags::InterlockResolutionCodeSequence),
-        ("temporalpositionsequence", &tags::TemporalPositionSequence),
-        ("numberofsurfacepoints", &tags::NumberofSurfacePoints),
-        ("codingschemeuid", &tags::CodingSchemeUID),
-        ("frameofinteresttype", &tags::FrameofInterestType),
-        ("hpglpenlabel", &tags::HPGLPenLabel),
-        ("filterbeampathlengthmaximum", &tags::FilterBeamPathLengthMaximum),
-        ("falsenegativesestimate", &tags::FalseNegativesEstimate),
-        ("privatedataelementdefinitionsequence", &tags::PrivateDataElementDefinitionSequence),
-        ("numberofframesinphase", &tags::NumberofFramesinPhase),
-        ("perprojectionacquisitionsequence", &tags::PerProjectionAcquisitionSequence),
-        ("sourceofcornealsizedatacodesequence", &tags::SourceofCornealSizeDataCodeSequence),
-        ("registrationtolocalizersequence", &tags::RegistrationtoLocalizerSequence),
-        ("mrdiffusionsequence", &tags::MRDiffusionSequence),
-        ("relatedframeofreferenceuid", &tags::RelatedFrameofReferenceUID),
-        ("applicatorapertureshape", &tags::ApplicatorApertureShape),
-        ("apexposition", &tags::ApexPosition),
-        ("beamholdtransitiondatetime", &tags::BeamHoldTransitionDateTime),
-        ("vieworientationcodesequence", &tags::ViewOrientationCodeSequence),
-        ("spectroscopyacquisitionoutofplanephasesteps", &tags::SpectroscopyAcquisitionOutofplanePhaseSteps),
-        ("planeorientationvolumesequence", &tags::PlaneOrientationVolumeSequence),
-        ("admittingdiagnosescodesequence", &tags::AdmittingDiagnosesCodeSequence),
-        ("segmentcharacteristicssequence", &tags::SegmentCharacteristicsSequence),
-        ("othersmoothingtypesavailable", &tags::OtherSmoothingTypesAvailable),
-        ("entitylonglabel", &tags::EntityLongLabel),
-        ("reconstructiondescription", &tags::ReconstructionDescription),
-        ("detectorbinning", &tags::DetectorBinning),
-        ("radionuclidepositronfraction", &tags::RadionuclidePositronFraction),
-        ("textobjectsequence", &tags::TextObjectSequence),
-        ("alinesperframe", &tags::AlinesPerFrame),
-        ("viewpointlookatpoint", &tags::ViewpointLookAtPoint),
-        ("filesetaccesssequence", &tags::FileSetAccessSequence),
-        ("referencedoverlayplanegroups", &tags::ReferencedOverlayPlaneGroups),
-        ("transducerdata", &tags::TransducerData),
-        ("numberofstudyrecordsininstance", &tags::NumberofStudyRecordsinInstance),
-        ("wedgeoffsety", &tags::WedgeOffsetY),
-        ("detectorelementphysicalsize", &tags::DetectorElementPhysicalSize),
-        ("sourceacquisitionprotocolelementnumber", &tags::SourceAcquisitionProtocolElementNumber),
-        ("timesource", &tags::TimeSource),
-        ("driveprobesequence", &tags::DriveProbeSequence),
-        ("attributeitemselector", &tags::AttributeItemSelector),
-        ("derivationimagesequence", &tags::DerivationImageSequence),
-        ("variablemodalitylutsequence", &tags::VariableModalityLUTSequence),
-        ("lensstatuscodesequence", &tags::LensStatusCodeSequence),
-        ("repetitiontime", &tags::RepetitionTime),
-        ("completionflag", &tags::CompletionFlag),
-        ("datarepresentation", &tags::DataRepresentation),
-        ("objectpixelspacingincenterofbeam", &tags::ObjectPixelSpacinginCenterofBeam),
-        ("refractiveprocedureoccurred", &tags::RefractiveProcedureOccurred),
-        ("tag_2dmatingfeaturecoordinatessequence", &tags::Tag_2DMatingFeatureCoordinatesSequence),
-        ("pettabledynamicssequence", &tags::PETTableDynamicsSequence),
-        ("drivetype", &tags::DriveType),
+        ("sourceorientation", &tags::SourceOrientation),
+        ("globaldeviationprobability", &tags::GlobalDeviationProbability),
+        ("multicoilelementname", &tags::MultiCoilElementName),
+        ("scanspotreordered", &tags::ScanSpotReordered),
+        ("verificationflag", &tags::VerificationFlag),
+        ("producttypecodesequence", &tags::ProductTypeCodeSequence),
+        ("lensmake", &tags::LensMake),
+        ("multienergyctxraysourcesequence", &tags::MultienergyCTXRaySourceSequence),
+        ("privaterecorduid", &tags::PrivateRecordUID),
+        ("strainnomenclature", &tags::StrainNomenclature),
+        ("relatedgeneralsopclassuid", &tags::RelatedGeneralSOPClassUID),
+        ("largestmonochromepixelvalue", &tags::LargestMonochromePixelValue),
+        ("colorfilterarraypatternvalues", &tags::ColorFilterArrayPatternValues),
+        ("refractiveerrorbeforerefractivesurgerycodesequence", &tags::RefractiveErrorBeforeRefractiveSurgeryCodeSequence),
+        ("oecfcolumnnames", &tags::OECFColumnNames),
+        ("detectorinformationsequence", &tags::DetectorInformationSequence),
+        ("offsetofreferencedlowerleveldirectoryentity", &tags::OffsetofReferencedLowerLevelDirectoryEntity),
+        ("componentwelderids", &tags::ComponentWelderIDs),
+        ("totalwedgetraywaterequivalentthickness", &tags::TotalWedgeTrayWaterEquivalentThickness),
+        ("viewname", &tags::ViewName),
+        ("sensorname", &tags::SensorName),
+        ("timeslicevector", &tags::TimeSliceVector),
+        ("fractionpattern", &tags::FractionPattern),
+        ("beamdosepointequivalentdepth", &tags::BeamDosePointEquivalentDepth),
+        ("reasonforperformedprocedurecodesequence", &tags::ReasonForPerformedProcedureCodeSequence),
+        ("retrieveurl", &tags::RetrieveURL),
+        ("timeslottime", &tags::TimeSlotTime),
+        ("emptyimageboxcielabvalue", &tags::EmptyImageBoxCIELabValue),
+        ("patienteyemovementcommanded", &tags::PatientEyeMovementCommanded),
+        ("materialattenuationsequence", &tags::MaterialAttenuationSequence),
+        ("treatmentverificationstatus", &tags::TreatmentVerificationStatus),
+        ("deliverednominalrangemodulatedregiondepths", &tags::DeliveredNominalRangeModulatedRegionDepths),
+        ("bitsmappedtocolorlookuptable", &tags::BitsMappedtoColorLookupTable),
+        ("assigningagencyordepartmentcodesequence", &tags::AssigningAgencyorDepartmentCodeSequence),
+        ("numberofpoints", &tags::NumberofPoints),
+        ("numberoflateralspreadingdevices", &tags::NumberofLateralSpreadingDevices),
+        ("tabletoprollangle", &tags::TableTopRollAngle),
+        ("certifiedtimestamptype", &tags::CertifiedTimestampType),
+        ("numberofcopies", &tags::NumberofCopies),
+        ("scheduleddischargetime", &tags::ScheduledDischargeTime),
+        ("graphicgroupid", &tags::GraphicGroupID),
+        ("imagingservicerequestcomments", &tags::ImagingServiceRequestComments),
+        ("gpsdestbearing", &tags::GPSDestBearing),
+        ("transducerscanpatterncodesequence", &tags::TransducerScanPatternCodeSequence),
+        ("enhancedrtbeamlimitingdevicesequence", &tags::EnhancedRTBeamLimitingDeviceSequence),
+        ("referencedroinumber", &tags::ReferencedROINumber),
+        ("dosereferencestructuretype", &tags::DoseReferenceStructureType),
+        ("rtdeliverystartpatientpositionsequence", &tags::RTDeliveryStartPatientPositionSequence),
+        ("setupdevicetype", &tags::SetupDeviceType),
+        ("specimenidentifier", &tags::SpecimenIdentifier),
+        ("fillpattern", &tags::FillPattern),
+        ("referenceddefinedprotocolsequence", &tags::ReferencedDefinedProtocolSequence),
+        ("axialresolution", &tags::AxialResolution),
+        ("patientsbodymassindex", &tags::PatientsBodyMassIndex),
+        ("patternoffopacity", &tags::PatternOffOpacity),
+        ("scheduledprocedurestepdescription", &tags::ScheduledProcedureStepDescription),
+        ("dvhsequence", &tags::DVHSequence),
+        ("beamspotsize", &tags::BeamSpotSize),
+        ("moveoriginatorapplicationentitytitle", &tags::MoveOriginatorApplicationEntityTitle),
+        ("selectorusvalue", &tags::SelectorUSValue),
+        ("blendinginputnumber", &tags::BlendingInputNumber),
+        ("interlockcodesequence", &tags::InterlockCodeSequence),
+        ("oecfrows", &tags::OECFRows),
+        ("bulkmotionsignalsource", &tags::BulkMotionSignalSource),
+        ("receivecoilname", &tags::ReceiveCoilName),
+        ("corneameasurementmethodcodesequence", &tags::CorneaMeasurementMethodCodeSequence),
+        ("sourcetowedgetraydistance", &tags::SourcetoWedgeTrayDistance),
+        ("steeringangle", &tags::SteeringAngle),
+        ("channeltimeskew", &tags::ChannelTimeSkew),
+        ("gpsstatus", &tags::GPSStatus),
+        ("imageboxlargescrollamount", &tags::ImageBoxLargeScrollAmount),
+        ("wedgefrontgap", &tags::WedgeFrontGap),
+        ("measuredcenterfrequency", &tags::MeasuredCenterFrequency),
+        ("compressionrecognitioncode", &tags::CompressionRecognitionCode),
+        ("urncodevalue", &tags::URNCodeValue),
+        ("mappingresourceidentificationsequence", &tags::MappingResourceIdentificationSequence),
+        ("showgraphicannotationflag", &tags::ShowGraphicAnnotationFlag),
+        ("samplesperpixelused", &tags::SamplesperPixelUsed),
+        ("detectoractiveareaorientation", &tags::DetectorActiveAreaOrientation),
+        ("studydescription", &tags::StudyDescription),
+        ("matrixregistrationsequence", &tags::MatrixRegistrationSequence),
+        ("rtroirelationship", &tags::RTROIRelationship),
+        ("imageboxscrolldirection", &tags::ImageBoxScrollDirection),
+        ("rtprescriptionindex", &tags::RTPrescriptionIndex),
+        ("preferredplaybacksequencing", &tags::PreferredPlaybackSequencing),
+        ("mediainstalledsequence", &tags::MediaInstalledSequence),
+        ("referencedimplanttemplategroupmemberid", &tags::ReferencedImplantTemplateGroupMemberID),
+        ("shorttermfluctuation", &tags::ShortTermFluctuation),
+        ("numberofelements", &tags::NumberofElements),
+        ("gantrypitchangletolerance", &tags::GantryPitchAngleTolerance),
+        ("hpglcontourpennumber", &tags::HPGLContourPenNumber),
+        ("conceptualvolumecombinationflag", &tags::ConceptualVolumeCombinationFlag),
+        ("viewingdistancetype", &tags::ViewingDistanceType),
+        ("spectrallyselectedexcitation", &tags::SpectrallySelectedExcitation),
+        ("angioflag", &tags::AngioFlag),
+        ("implanttype", &tags::ImplantType),
+        ("nominalinterval", &tags::NominalInterval),
+        ("navigationdisplayset", &tags::NavigationDisplaySet),
+        ("materialpropertiesdescription", &tags::MaterialPropertiesDescription),
+        ("component1referencedmatingfeatureid", &tags::Component1ReferencedMatingFeatureID),
+        ("parallelreductionfactoroutofplane", &tags::ParallelReductionFactoroutofplane),
+        ("sopauthorizationcomment", &tags::SOPAuthorizationComment),
+        ("beamlimitingdeviceangle", &tags::BeamLimitingDeviceAngle),
+        ("lensconstantdescription", &tags::LensConstantDescription),
         ("dosereferenceuid", &tags::DoseReferenceUID),
-        ("patienteyemovementcommandcodesequence", &tags::PatientEyeMovementCommandCodeSequence),
-        ("pixelrepresentation", &tags::PixelRepresentation),
-        ("imagedisplayformat", &tags::ImageDisplayFormat),
-        ("referencedosedefinition", &tags::ReferenceDoseDefinition),
-        ("tagspacingfirstdimension", &tags::TagSpacingFirstDimension),
-        ("referencedcurvesequence", &tags::ReferencedCurveSequence),
-        ("transformlabel", &tags::TransformLabel),
-        ("ctimageframetypesequence", &tags::CTImageFrameTypeSequence),
+        ("exposuretime", &tags::ExposureTime),
+        ("beamorderindex", &tags::BeamOrderIndex),
         ("lightdirection", &tags::LightDirection),
-        ("intervalsrejected", &tags::IntervalsRejected),
-        ("referencedperformedprotocolsequence", &tags::ReferencedPerformedProtocolSequence),
-        ("patientsname", &tags::PatientsName),
-        ("radiationgenerationmodelabel", &tags::RadiationGenerationModeLabel),
-        ("whitepoint", &tags::WhitePoint),
-        ("floatingpointvalues", &tags::FloatingPointValues),
-        ("radiopharmaceuticalstopdatetime", &tags::RadiopharmaceuticalStopDateTime),
-        ("refractivesurgerytypecodesequence", &tags::RefractiveSurgeryTypeCodeSequence),
-        ("indicationdescription", &tags::IndicationDescription),
-        ("frameincrementpointer", &tags::FrameIncrementPointer),
-        ("performedprocedurestepstartdate", &tags::PerformedProcedureStepStartDate),
-        ("taggingdelay", &tags::TaggingDelay),
-        ("commandgrouplength", &tags::CommandGroupLength),
-        ("imagesinseries", &tags::ImagesinSeries),
-        ("imagingsourcetobeammodifierdefinitionplanedistance", &tags::ImagingSourcetoBeamModifierDefinitionPlaneDistance),
-        ("presentationlutshape", &tags::PresentationLUTShape),
-        ("renderprojection", &tags::RenderProjection),
-        ("bscanrate", &tags::BscanRate),
-        ("smoothingtype", &tags::SmoothingType),
-        ("compressionrecognitioncode", &tags::CompressionRecognitionCode),
-        ("physicalunitsydirection", &tags::PhysicalUnitsYDirection),
-        ("selectorcodesequencevalue", &tags::SelectorCodeSequenceValue),
-        ("exposuresonplate", &tags::ExposuresonPlate),
-        ("maximumnominalenergy", &tags::MaximumNominalEnergy),
-        ("numberoffractionpatterndigitsperday", &tags::NumberofFractionPatternDigitsPerDay),
-        ("pixelpaddingrangelimit", &tags::PixelPaddingRangeLimit),
-        ("iteminventorydatetime", &tags::ItemInventoryDateTime),
-        ("deliveryrateunitsequence", &tags::DeliveryRateUnitSequence),
-        ("lowercutofffrequency", &tags::LowerCutoffFrequency),
-        ("requestedimagesizeflag", &tags::RequestedImageSizeFlag),
-        ("fillerordernumberimagingservicerequest", &tags::FillerOrderNumberImagingServiceRequest),
-        ("doublefloatrealworldvaluelastvaluemapped", &tags::DoubleFloatRealWorldValueLastValueMapped),
-        ("suvtype", &tags::SUVType),
-        ("applicationsetupmanufacturer", &tags::ApplicationSetupManufacturer),
-        ("compressioncontactarea", &tags::CompressionContactArea),
-        ("procedurelastmodifieddate", &tags::ProcedureLastModifiedDate),
-        ("studyverifiedtime", &tags::StudyVerifiedTime),
-        ("algorithmname", &tags::AlgorithmName),
-        ("anatomicstructurespaceorregionsequence", &tags::AnatomicStructureSpaceorRegionSequence),
-        ("probedrivenotes", &tags::ProbeDriveNotes),
-        ("referencedrttreatmentphaseindex", &tags::ReferencedRTTreatmentPhaseIndex),
-        ("issuerofthespecimenidentifiersequence", &tags::IssueroftheSpecimenIdentifierSequence),
-        ("synchronizationframeofreferenceuid", &tags::SynchronizationFrameofReferenceUID),
-        ("blockthickness", &tags::BlockThickness),
-        ("treatmentsessionuid", &tags::TreatmentSessionUID),
-        ("measurementprecisiondescription_trial", &tags::MeasurementPrecisionDescription_Trial),
-        ("subjectrelativepositioninimage", &tags::SubjectRelativePositioninImage),
-        ("gpsspeed", &tags::GPSSpeed),
+        ("coordinatesystemtransformrotationandscalematrix", &tags::CoordinateSystemTransformRotationandScaleMatrix),
         ("absolutechanneldisplayscale", &tags::AbsoluteChannelDisplayScale),
-        ("relatedgeneralsopclassuid", &tags::RelatedGeneralSOPClassUID),
-        ("keratometryrighteyesequence", &tags::KeratometryRightEyeSequence),
-        ("spectralsensitivity", &tags::SpectralSensitivity),
-        ("percentphasefieldofview", &tags::PercentPhaseFieldofView),
-        ("derivationalgorithmsequence", &tags::DerivationAlgorithmSequence),
-        ("targetmaximumluminance", &tags::TargetMaximumLuminance),
-        ("shorttermfluctuationcalculated", &tags::ShortTermFluctuationCalculated),
-        ("spoiling", &tags::Spoiling),
-        ("zoffsetinslidecoordinatesystem", &tags::ZOffsetinSlideCoordinateSystem),
-        ("pixeldataareaoriginrelativetofov", &tags::PixelDataAreaOriginRelativeToFOV),
-        ("diffusionbvalueyy", &tags::DiffusionbvalueYY),
-        ("geneticmodificationscodesequence", &tags::GeneticModificationsCodeSequence),
-        ("patientorientation", &tags::PatientOrientation),
-        ("illuminationpower", &tags::IlluminationPower),
-        ("coordinatesystemnumberofaxes", &tags::CoordinateSystemNumberofAxes),
-        ("blockcolumns", &tags::BlockColumns),
-        ("minimumnominalenergy", &tags::MinimumNominalEnergy),
-        ("compensatorthicknessdata", &tags::CompensatorThicknessData),
-        ("contentdate", &tags::ContentDate),
-        ("numberoftransformsteps", &tags::NumberofTransformSteps),
-        ("reasonfortheattributemodification", &tags::ReasonfortheAttributeModification),
-        ("mainroofangle", &tags::MainRoofAngle),
-        ("flatkeratometricaxissequence", &tags::FlatKeratometricAxisSequence),
-        ("requestedsopinstanceuid", &tags::RequestedSOPInstanceUID),
-        ("reconstructiontype", &tags::ReconstructionType),
-        ("ooiownersequence", &tags::OOIOwnerSequence),
-        ("scheduledstationgeographiclocationcodesequence", &tags::ScheduledStationGeographicLocationCodeSequence),
-        ("bloodsignalnulling", &tags::BloodSignalNulling),
-        ("beamtasksequence", &tags::BeamTaskSequence),
-        ("predecessorstructuresetsequence", &tags::PredecessorStructureSetSequence),
-        ("offsetofthenextdirectoryrecord", &tags::OffsetoftheNextDirectoryRecord),
-        ("eventtimeoffset", &tags::EventTimeOffset),
-        ("acquisitiondatetime", &tags::AcquisitionDateTime),
-        ("diffusionbvaluexy", &tags::DiffusionbvalueXY),
-        ("constraintvaluesequence", &tags::ConstraintValueSequence),
-        ("graphicannotationunits", &tags::GraphicAnnotationUnits),
-        ("assessmentsummary", &tags::AssessmentSummary),
-        ("transfertubenumber", &tags::TransferTubeNumber),
-        ("scattercorrectionmethod", &tags::ScatterCorrectionMethod),
-        ("seriestype", &tags::SeriesType),
-        ("rttreatmentphaseindex", &tags::RTTreatmentPhaseIndex),
+        ("syringecounts", &tags::SyringeCounts),
+        ("deadtimefactor", &tags::DeadTimeFactor),
+        ("acquisitioncomments", &tags::AcquisitionComments),
+        ("detectorxpositiontoisocenter", &tags::DetectorXPositiontoIsocenter),
+        ("recordinuseflag", &tags::RecordInuseFlag),
+        ("codingschemeregistry", &tags::CodingSchemeRegistry),
+        ("dosegridscaling", &tags::DoseGridScaling),
+        ("queryretrievelevel", &tags::QueryRetrieveLevel),
+        ("alternatecontentdescriptionsequence", &tags::AlternateContentDescriptionSequence),
+        ("visualacuitytypecodesequence", &tags::VisualAcuityTypeCodeSequence),
+        ("modelgroupuid", &tags::ModelGroupUID),
+        ("xaacquisitionphasedetailssequence", &tags::XAAcquisitionPhaseDetailsSequence),
+        ("averagebeamdosepointssd", &tags::AverageBeamDosePointSSD),
+        ("beamdosespecificationpoint", &tags::BeamDoseSpecificationPoint),
+        ("coordinatesystemaxistype", &tags::CoordinateSystemAxisType),
+        ("smoothingtype", &tags::SmoothingType),
+        ("relationshiptype", &tags::RelationshipType),
+        ("reconstructiontargetcenterlocationsequence", &tags::ReconstructionTargetCenterLocationSequence),
+        ("radiopharmaceuticalstartdatetime", &tags::RadiopharmaceuticalStartDateTime),
+        ("referringphysicianstelephonenumbers", &tags::ReferringPhysiciansTelephoneNumbers),
+        ("largepalettecolorlookuptableuid", &tags::LargePaletteColorLookupTableUID),
+        ("setupdevicesequence", &tags::SetupDeviceSequence),
+        ("xraytubecurrentinmicroa", &tags::XRayTubeCurrentinmicroA),
+        ("sourceapplicatorwallnominaltransmission", &tags::SourceApplicatorWallNominalTransmission),
+        ("ophthalmicthicknessmapthresholdqualityrating", &tags::OphthalmicThicknessMapThresholdQualityRating),
+        ("twodimensionaltothreedimensionalmapsequence", &tags::TwoDimensionaltoThreeDimensionalMapSequence),
+        ("imagedvolumedepth", &tags::ImagedVolumeDepth),
+        ("visualfieldtestpointxcoordinate", &tags::VisualFieldTestPointXCoordinate),
+        ("graphiclayerrecommendeddisplaygrayscalevalue", &tags::GraphicLayerRecommendedDisplayGrayscaleValue),
+        ("sourceimageids", &tags::SourceImageIDs),
+        ("referencedimagenavigationsequence", &tags::ReferencedImageNavigationSequence),
+        ("datavaluerepresentation", &tags::DataValueRepresentation),
+        ("overlaycolumns", &tags::OverlayColumns),
+        ("rtsegmentannotationsequence", &tags::RTSegmentAnnotationSequence),
+        ("compensatordescription", &tags::CompensatorDescription),
+        ("reconstructionalgorithm", &tags::ReconstructionAlgorithm),
+        ("shadowstyle", &tags::ShadowStyle),
+        ("spectroscopyacquisitiondatacolumns", &tags::SpectroscopyAcquisitionDataColumns),
         ("nominalscreendefinitionsequence", &tags::NominalScreenDefinitionSequence),
-        ("trianglefansequence", &tags::TriangleFanSequence),
-        ("channelsequence", &tags::ChannelSequence),
-        ("expectedinvivomeasurementvaluessequence", &tags::ExpectedInVivoMeasurementValuesSequence),
+        ("reportedvaluesorigin", &tags::ReportedValuesOrigin),
+        ("resultinggeneralpurposeperformedprocedurestepssequence", &tags::ResultingGeneralPurposePerformedProcedureStepsSequence),
+        ("magneticfieldstrength", &tags::MagneticFieldStrength),
+        ("referencedpatientaliassequence", &tags::ReferencedPatientAliasSequence),
+        ("volumetricpresentationinputannotationsequence", &tags::VolumetricPresentationInputAnnotationSequence),
+        ("ophthalmicanatomicreferencepointycoordinate", &tags::OphthalmicAnatomicReferencePointYCoordinate),
+        ("ophthalmicimagetypedescription", &tags::OphthalmicImageTypeDescription),
+        ("implantregulatorydisapprovalcodesequence", &tags::ImplantRegulatoryDisapprovalCodeSequence),
+        ("geneticmodificationsnomenclature", &tags::GeneticModificationsNomenclature),
+        ("xaacquisitionduration", &tags::XAAcquisitionDuration),
+        ("calibrationimage", &tags::CalibrationImage),
+        ("displacementmatrix", &tags::DisplacementMatrix),
+        ("depthsoffocus", &tags::DepthsofFocus),
+        ("inputavailabilityflag", &tags::InputAvailabilityFlag),
+        ("physicaldeltay", &tags::PhysicalDeltaY),
+        ("interoperabilityversion", &tags::InteroperabilityVersion),
+        ("filterhighfrequency", &tags::FilterHighFrequency),
+        ("absolutedosimetricobjectiveflag", &tags::AbsoluteDosimetricObjectiveFlag),
+        ("anatomicstructurespaceorregionsequence", &tags::AnatomicStructureSpaceorRegionSequence),
+        ("coordinatesystemaxisnumber", &tags::CoordinateSystemAxisNumber),
+        ("stationaetitle", &tags::StationAETitle),
+        ("studycompletiondate", &tags::StudyCompletionDate),
+        ("referencedrangemodulatornumber", &tags::ReferencedRangeModulatorNumber),
+        ("recordkey", &tags::RecordKey),
+        ("blendingdisplaysequence", &tags::BlendingDisplaySequence),
+        ("fixationlightazimuthalangle", &tags::FixationLightAzimuthalAngle),
+        ("continuityofcontent", &tags::ContinuityOfContent),
+        ("stimuluspresentationtime", &tags::StimulusPresentationTime),
+        ("performingphysiciansname", &tags::PerformingPhysiciansName),
+        ("identificationdescription_trial", &tags::IdentificationDescription_Trial),
+        ("nominalfrequency", &tags::NominalFrequency),
+        ("framecomments", &tags::FrameComments),
+        ("multicoildefinitionsequence", &tags::MultiCoilDefinitionSequence),
+        ("segmentreferenceindex", &tags::SegmentReferenceIndex),
+        ("selectorcsvalue", &tags::SelectorCSValue),
+        ("channelsampleskew", &tags::ChannelSampleSkew),
+        ("referencedrtpatientsetupsequence", &tags::ReferencedRTPatientSetupSequence),
+        ("photonenergy", &tags::PhotonEnergy),
+        ("xdsstoragesequence", &tags::XDSStorageSequence),
+        ("positionerprimaryangledirection", &tags::PositionerPrimaryAngleDirection),
+        ("patientsprimarylanguagecodesequence", &tags::PatientsPrimaryLanguageCodeSequence),
+        ("radionuclide", &tags::Radionuclide),
+        ("compensatortrayid", &tags::CompensatorTrayID),
+        ("structuresetname", &tags::StructureSetName),
+        ("leafpositionboundaries", &tags::LeafPositionBoundaries),
+        ("rtbeamlimitingdevicetype", &tags::RTBeamLimitingDeviceType),
+        ("radionuclidehalflife", &tags::RadionuclideHalfLife),
+        ("detectorconditionsnominalflag", &tags::DetectorConditionsNominalFlag),
+        ("cardiacsignalsource", &tags::CardiacSignalSource),
+        ("location", &tags::Location),
+        ("doseunits", &tags::DoseUnits),
+        ("shadowopacity", &tags::ShadowOpacity),
+        ("drivetype", &tags::DriveType),
+        ("institutionname", &tags::InstitutionName),
+        ("ophthalmicaxiallengthmeasurementssegmentallengthsequence", &tags::OphthalmicAxialLengthMeasurementsSegmentalLengthSequence),
+        ("performingphysicianidentificationsequence", &tags::PerformingPhysicianIdentificationSequence),
+        ("localizingcursorposition", &tags::LocalizingCursorPosition),
+        ("privatedataelementencoding", &tags::PrivateDataElementEncoding),
+        ("framepixeldatapropertiessequence", &tags::FramePixelDataPropertiesSequence),
+        ("annotationpropertycategorycodesequence", &tags::AnnotationPropertyCategoryCodeSequence),
+        ("studyupdatedatetime", &tags::StudyUpdateDateTime),
+        ("instancecreationtime", &tags::InstanceCreationTime),
+        ("compensatordefinitionsequence", &tags::CompensatorDefinitionSequence),
+        ("labelstyleselection", &tags::LabelStyleSelection),
+        ("equipmentframeofreferenceuid", &tags::EquipmentFrameofReferenceUID),
         ("isocentertogeneralaccessorydistance", &tags::IsocentertoGeneralAccessoryDistance),
-        ("billingprocedurestepsequence", &tags::BillingProcedureStepSequence),
-        ("nominalmaxenergy", &tags::NominalMaxEnergy),
-        ("calciumscoringmassfactordevice", &tags::CalciumScoringMassFactorDevice),
-        ("agecorrectedsensitivitydeviationvalue", &tags::AgeCorrectedSensitivityDeviationValue),
-        ("surfacepointssequence", &tags::SurfacePointsSequence),
-        ("numberofopticalpaths", &tags::NumberofOpticalPaths),
-        ("timeslotinformationsequence", &tags::TimeSlotInformationSequence),
-        ("curvetime", &tags::CurveTime),
-        ("scheduledprotocolcodesequence", &tags::ScheduledProtocolCodeSequence),
-        ("deviceuid", &tags::DeviceUID),
-        ("specimendescription_trial", &tags::SpecimenDescription_Trial),
-        ("acquisitionmethodcodesequence", &tags::AcquisitionMethodCodeSequence),
-        ("channelsensitivitycorrectionfactor", &tags::ChannelSensitivityCorrectionFactor),
-        ("echotrainlength", &tags::EchoTrainLength),
-        ("firstalinelocation", &tags::FirstAlineLocation),
-        ("referencedvoilutboxsequence", &tags::ReferencedVOILUTBoxSequence),
-        ("stimulusarea", &tags::StimulusArea),
-        ("positioninglandmarksequence", &tags::PositioningLandmarkSequence),
-        ("surfaceentrypoint", &tags::SurfaceEntryPoint),
-        ("appliedmasksubtractionflag", &tags::AppliedMaskSubtractionFlag),
-        ("mydriaticagentcodesequence", &tags::MydriaticAgentCodeSequence),
-        ("localizeddeviationprobability", &tags::LocalizedDeviationProbability),
-        ("aslmidslabposition", &tags::ASLMidSlabPosition),
-        ("petframeacquisitionsequence", &tags::PETFrameAcquisitionSequence),
-        ("rangeshifterdescription", &tags::RangeShifterDescription),
-        ("exposuretimeinms", &tags::ExposureTimeinms),
-        ("attenuationcorrectiontemporalrelationship", &tags::AttenuationCorrectionTemporalRelationship),
-        ("detectoractiveareatlhcposition", &tags::DetectorActiveAreaTLHCPosition),
-        ("monoenergeticenergyequivalent", &tags::MonoenergeticEnergyEquivalent),
-        ("referencedgeneralpurposescheduledprocedurestepsequence", &tags::ReferencedGeneralPurposeScheduledProcedureStepSequence),
-        ("tmlinepositiony1", &tags::TMLinePositionY1),
-        ("performedprocessingapplicationscodesequence", &tags::PerformedProcessingApplicationsCodeSequence),
+        ("wedgeposition", &tags::WedgePosition),
+        ("ophthalmicaxiallengthmeasurementstotallengthsequence", &tags::OphthalmicAxialLengthMeasurementsTotalLengthSequence),
+        ("exposure", &tags::Exposure),
+        ("segmentationfractionaltype", &tags::SegmentationFractionalType),
+        ("patienttransportarrangements", &tags::PatientTransportArrangements),
+        ("gpsversionid", &tags::GPSVersionID),
+        ("hangingprotocoldescription", &tags::HangingProtocolDescription),
+        ("radiationdosesourcetoexternalcontourdistance", &tags::RadiationDoseSourcetoExternalContourDistance),
+        ("targetrefraction", &tags::TargetRefraction),
+        ("personstelephonenumbers", &tags::PersonsTelephoneNumbers),
+        ("imagelaterality", &tags::ImageLaterality),
+        ("dicomstoragesequence", &tags::DICOMStorageSequence),
+        ("referencedrttreatmentphasesequence", &tags::ReferencedRTTreatmentPhaseSequence),
+        ("ctexposuresequence", &tags::CTExposureSequence),
+        ("contouruncertaintyradius", &tags::ContourUncertaintyRadius),
+        ("sharpness", &tags::Sharpness),
+        ("falsenegativesestimate", &tags::FalseNegativesEstimate),
+        ("detectoractivetime", &tags::DetectorActiveTime),
+        ("diaphragmposition", &tags::DiaphragmPosition),
+        ("interlockresolutioncodesequence", &tags::InterlockResolutionCodeSequence),
+        ("flashenergy", &tags::FlashEnergy),
+        ("instancecoerciondatetime", &tags::InstanceCoercionDateTime),
+        ("numberoftimeslices", &tags::NumberofTimeSlices),
+        ("blockdefinitionsequence", &tags::BlockDefinitionSequence),
+        ("interpretationapproversequence", &tags::InterpretationApproverSequence),
+        ("triggersampleposition", &tags::TriggerSamplePosition),
+        ("contrastbolusingredientcodesequence", &tags::ContrastBolusIngredientCodeSequence),
+        ("sphericallenspower", &tags::SphericalLensPower),
+        ("beamlimitingdevicepositiontolerance", &tags::BeamLimitingDevicePositionTolerance),
+        ("isotopenumber", &tags::IsotopeNumber),
+        ("implanttemplategroupname", &tags::ImplantTemplateGroupName),
+        ("verticalalignment", &tags::VerticalAlignment),
+        ("recordedrangeshiftersequence", &tags::RecordedRangeShifterSequence),
+        ("flowidentifiersequence", &tags::FlowIdentifierSequence),
+        ("distancesourcetopatient", &tags::DistanceSourcetoPatient),
+        ("displaysetssequence", &tags::DisplaySetsSequence),
+        ("operatingmode", &tags::OperatingMode),
+        ("referencedopticalpathidentifier", &tags::ReferencedOpticalPathIdentifier),
+        ("analyzedarea", &tags::AnalyzedArea),
+        ("rangeshiftersettingssequence", &tags::RangeShifterSettingsSequence),
+        ("uvmappingsequence", &tags::UVMappingSequence),
+        ("cornealeccentricityindex", &tags::CornealEccentricityIndex),
+        ("rescaleintercept", &tags::RescaleIntercept),
+        ("respiratorymotioncompensationtechniquedescription", &tags::RespiratoryMotionCompensationTechniqueDescription),
+        ("contrastbolustotaldose", &tags::ContrastBolusTotalDose),
+        ("cornealsizesequence", &tags::CornealSizeSequence),
+        ("positionersecondaryangle", &tags::PositionerSecondaryAngle),
+        ("exposurecontrolsensingregionrightverticaledge", &tags::ExposureControlSensingRegionRightVerticalEdge),
+        ("volumetotransducermappingmatrix", &tags::VolumetoTransducerMappingMatrix),
+        ("radiationsourcecoordinatesystemyawangle", &tags::RadiationSourceCoordinateSystemYawAngle),
+        ("lensmodel", &tags::LensModel),
+        ("numberofpatientrelatedseries", &tags::NumberofPatientRelatedSeries),
+        ("terminationdensitythreshold", &tags::TerminationDensityThreshold),
+        ("tabletopverticalpositiontolerance", &tags::TableTopVerticalPositionTolerance),
+        ("sourceoflensthicknessdatacodesequence", &tags::SourceofLensThicknessDataCodeSequence),
+        ("gridspacingmaterial", &tags::GridSpacingMaterial),
+        ("diffusereflectionintensity", &tags::DiffuseReflectionIntensity),
+        ("predecessorstructuresetsequence", &tags::PredecessorStructureSetSequence),
+        ("referencebasiscodesequence", &tags::ReferenceBasisCodeSequence),
+        ("contrastbolusagentadministered", &tags::ContrastBolusAgentAdministered),
+        ("gpsdestlongituderef", &tags::GPSDestLongitudeRef),
+        ("isospeedlatitudeyyy", &tags::ISOSpeedLatitudeyyy),
+        ("referencedcontentitemidentifier", &tags::ReferencedContentItemIdentifier),
+        ("isocentertocompensatortraydistance", &tags::IsocentertoCompensatorTrayDistance),
+        ("contraindicationscodesequence", &tags::ContraindicationsCodeSequence),
+        ("hardcopydevicemanufacturersmodelname", &tags::HardcopyDeviceManufacturersModelName),
+        ("dicosversion", &tags::DICOSVersion),
+        ("imagingsourcelocationspecificationtype", &tags::ImagingSourceLocationSpecificationType),
+        ("scanspottimeoffset", &tags::ScanSpotTimeOffset),
+        ("ophthalmicimagetypecodesequence", &tags::OphthalmicImageTypeCodeSequence),
+        ("screenminimumgrayscalebitdepth", &tags::ScreenMinimumGrayscaleBitDepth),
+        ("specifiedprimarymeterset", &tags::SpecifiedPrimaryMeterset),
+        ("intendedstartdayofweek", &tags::IntendedStartDayofWeek),
+        ("tableypositiontoisocenter", &tags::TableYPositiontoIsocenter),
+        ("regionlocationmaxx1", &tags::RegionLocationMaxX1),
+        ("alinesperframe", &tags::AlinesPerFrame),
+        ("treatmentpositionindex", &tags::TreatmentPositionIndex),
+        ("visualfieldtestpointsequence", &tags::VisualFieldTestPointSequence),
+        ("wedgeangle", &tags::WedgeAngle),
+        ("grayscale", &tags::GrayScale),
+        ("palettecolorlookuptablesequence", &tags::PaletteColorLookupTableSequence),
+        ("triggertimeoffset", &tags::TriggerTimeOffset),
+        ("planninglandmarkidentificationcodesequence", &tags::PlanningLandmarkIdentificationCodeSequence),
+        ("pyramidlabel", &tags::PyramidLabel),
+        ("scenecapturetype", &tags::SceneCaptureType),
+        ("dosevaluepurpose", &tags::DoseValuePurpose),
+        ("numberofcontourpoints", &tags::NumberofContourPoints),
+        ("relevantoptattributessequence", &tags::RelevantOPTAttributesSequence),
+        ("imagevolumegeometry", &tags::ImageVolumeGeometry),
+        ("modulatedscanmodetype", &tags::ModulatedScanModeType),
+        ("configurationname", &tags::ConfigurationName),
+        ("overlaycompressioncode", &tags::OverlayCompressionCode),
+        ("sourceapplicatorname", &tags::SourceApplicatorName),
+        ("overlayplanes", &tags::OverlayPlanes),
+        ("phasevector", &tags::PhaseVector),
+        ("numberofframes", &tags::NumberofFrames),
+        ("otherapprovalstatus", &tags::OtherApprovalStatus),
+        ("keratometrylefteyesequence", &tags::KeratometryLeftEyeSequence),
+        ("tidoffset", &tags::TIDOffset),
+        ("seriesdescriptioncodesequence", &tags::SeriesDescriptionCodeSequence),
+        ("referencedstoredprintsequence", &tags::ReferencedStoredPrintSequence),
+        ("otherfailuressequence", &tags::OtherFailuresSequence),
+        ("referencedexpectedinvivomeasurementvalueindex", &tags::ReferencedExpectedInVivoMeasurementValueIndex),
+        ("acquisitiondate", &tags::AcquisitionDate),
+        ("rectilinearphaseencodereordering", &tags::RectilinearPhaseEncodeReordering),
+        ("maskpointers", &tags::MaskPointers),
+        ("channelminimumvalue", &tags::ChannelMinimumValue),
+        ("implantassemblytemplatetargetanatomysequence", &tags::ImplantAssemblyTemplateTargetAnatomySequence),
+        ("pointpositionaccuracy", &tags::PointPositionAccuracy),
+        ("alternatevaluesequence", &tags::AlternateValueSequence),
+        ("calculateddosereferencesequence", &tags::CalculatedDoseReferenceSequence),
+        ("ctacquisitiondetailssequence", &tags::CTAcquisitionDetailsSequence),
+        ("channelwidth", &tags::ChannelWidth),
+        ("coordinatesystemaxessequence", &tags::CoordinateSystemAxesSequence),
+        ("voilutsequence", &tags::VOILUTSequence),
+        ("totalblocktrayfactor", &tags::TotalBlockTrayFactor),
+        ("ivusacquisition", &tags::IVUSAcquisition),
+        ("equivalentpupilradius", &tags::EquivalentPupilRadius),
+        ("energywindowname", &tags::EnergyWindowName),
+        ("mydriaticagentconcentration", &tags::MydriaticAgentConcentration),
+        ("mrfovgeometrysequence", &tags::MRFOVGeometrySequence),
+        ("finitevolume", &tags::FiniteVolume),
+        ("lightpathfiltertypestackcodesequence", &tags::LightPathFilterTypeStackCodeSequence),
+        ("currentfractionnumber", &tags::CurrentFractionNumber),
+        ("failureattributes", &tags::FailureAttributes),
+        ("exposurecontrolsensingregionlowerhorizontaledge", &tags::ExposureControlSensingRegionLowerHorizontalEdge),
         ("algorithmcodesequence_trial", &tags::AlgorithmCodeSequence_Trial),
-        ("maximumacrossscandistortion", &tags::MaximumAcrossscanDistortion),
-        ("ooitypedescriptor", &tags::OOITypeDescriptor),
-        ("masksubtractionsequence", &tags::MaskSubtractionSequence),
-        ("matingfeaturesetlabel", &tags::MatingFeatureSetLabel),
-        ("gpsareainformation", &tags::GPSAreaInformation),
-        ("dataelementdescription", &tags::DataElementDescription),
-        ("secondaryreviewername", &tags::SecondaryReviewerName),
-        ("positionofisocenterprojection", &tags::PositionofIsocenterProjection),
-        ("interpretationdiagnosisdescription", &tags::InterpretationDiagnosisDescription),
-        ("radiationdosevalue", &tags::RadiationDoseValue),
-        ("barcodevalue", &tags::BarcodeValue),
-        ("scanspotsizesdelivered", &tags::ScanSpotSizesDelivered),
-        ("templatenumber", &tags::TemplateNumber),
-        ("beamtasktype", &tags::BeamTaskType),
-        ("documentauthoridentifiercodesequence_trial", &tags::DocumentAuthorIdentifierCodeSequence_Trial),
-        ("modeofpercutaneousaccesssequence", &tags::ModeofPercutaneousAccessSequence),
+        ("subtaskworkitemcodesequence", &tags::SubtaskWorkitemCodeSequence),
+        ("externalcontourentrypoint", &tags::ExternalContourEntryPoint),
+        ("tomotime", &tags::TomoTime),
+        ("cardiacnumberofimages", &tags::CardiacNumberofImages),
+        ("shieldingdeviceposition", &tags::ShieldingDevicePosition),
+        ("minimumcoordinatevalue", &tags::MinimumCoordinateValue),
+        ("deliveryrate", &tags::DeliveryRate),
+        ("datapointrows", &tags::DataPointRows),
+        ("datapointcolumns", &tags::DataPointColumns),
+        ("rtradiationsetintent", &tags::RTRadiationSetIntent),
+        ("triggerwindow", &tags::TriggerWindow),
+        ("radiationmachinename", &tags::RadiationMachineName),
+        ("rtdoseroisequence", &tags::RTDoseROISequence),
+        ("graphiclayerorder", &tags::GraphicLayerOrder),
+        ("verbalsourceidentifiercodesequence_trial", &tags::VerbalSourceIdentifierCodeSequence_Trial),
+        ("centerofcircularshutter", &tags::CenterofCircularShutter),
+        ("constraintvaluesequence", &tags::ConstraintValueSequence),
+        ("anatomicregionmodifiersequence", &tags::AnatomicRegionModifierSequence),
+        ("compressionsteppointers", &tags::CompressionStepPointers),
+        ("referencedradiationdoseidentificationindex", &tags::ReferencedRadiationDoseIdentificationIndex),
+        ("resultscomments", &tags::ResultsComments),
+        ("tableheight", &tags::TableHeight),
+        ("nonconformingdataelementvalue", &tags::NonconformingDataElementValue),
+        ("abstractpriorcodesequence", &tags::AbstractPriorCodeSequence),
+        ("breedregistrationsequence", &tags::BreedRegistrationSequence),
+        ("waterequivalentdiameter", &tags::WaterEquivalentDiameter),
+        ("objectivelensnumericalaperture", &tags::ObjectiveLensNumericalAperture),
+        ("ionrangecompensatorsequence", &tags::IonRangeCompensatorSequence),
+        ("horizontalprismbase", &tags::HorizontalPrismBase),
+        ("displayfilterpercentage", &tags::DisplayFilterPercentage),
+        ("allowmediasplitting", &tags::AllowMediaSplitting),
+        ("quadraturereceivecoil", &tags::QuadratureReceiveCoil),
+        ("segmentalgorithmtype", &tags::SegmentAlgorithmType),
+        ("detailsofcoefficients", &tags::DetailsofCoefficients),
+        ("gpssatellites", &tags::GPSSatellites),
+        ("verticesofthepolygonalexposurecontrolsensingregion", &tags::VerticesofthePolygonalExposureControlSensingRegion),
+        ("outputdestinationsequence", &tags::OutputDestinationSequence),
+        ("hardcopydevicesoftwareversion", &tags::HardcopyDeviceSoftwareVersion),
+        ("personidentificationcodesequence", &tags::PersonIdentificationCodeSequence),
+        ("replacedimplanttemplategroupsequence", &tags::ReplacedImplantTemplateGroupSequence),
+        ("physicalunitsxdirection", &tags::PhysicalUnitsXDirection),
+        ("tabletoplateraladjustedposition", &tags::TableTopLateralAdjustedPosition),
+        ("falsepositivesestimate", &tags::FalsePositivesEstimate),
+        ("prescriptionnotes", &tags::PrescriptionNotes),
+        ("tag_2dplaneintersection", &tags::Tag_2DPlaneIntersection),
+        ("roiphysicalpropertiessequence", &tags::ROIPhysicalPropertiesSequence),
+        ("qualitycontrolimage", &tags::QualityControlImage),
+        ("acousticcouplingmediumsoundspeed", &tags::AcousticCouplingMediumSoundSpeed),
+        ("approvalstatus", &tags::ApprovalStatus),
+        ("templateextensionorganizationuid", &tags::TemplateExtensionOrganizationUID),
+        ("wedgemodelnumber", &tags::WedgeModelNumber),
+        ("radiobiologicaldoseeffectflag", &tags::RadiobiologicalDoseEffectFlag),
+        ("contournumber", &tags::ContourNumber),
+        ("receivecoiltype", &tags::ReceiveCoilType),
+        ("structuredconstraintobservationsequence", &tags::StructuredConstraintObservationSequence),
+        ("labelusinginformationextractedfrominstances", &tags::LabelUsingInformationExtractedFromInstances),
+        ("dataelementname", &tags::DataElementName),
+        ("focusdepth", &tags::FocusDepth),
+        ("dataelementminimumcharacters", &tags::DataElementMinimumCharacters),
         ("scheduledstudylocation", &tags::ScheduledStudyLocation),
-        ("implementationclassuid", &tags::ImplementationClassUID),
-        ("compensatordistalthicknessmap", &tags::CompensatorDistalThicknessMap),
-        ("lutlabel", &tags::LUTLabel),
-        ("operatorsname", &tags::OperatorsName),
-        ("multienergydetectortype", &tags::MultienergyDetectorType),
-        ("sourcemovementtype", &tags::SourceMovementType),
-        ("waveformbitsstored", &tags::WaveformBitsStored),
-        ("shorttermfluctuationprobability", &tags::ShortTermFluctuationProbability),
-        ("calculateddosereferencedescription", &tags::CalculatedDoseReferenceDescription),
-        ("hardcopydevicemanufacturersmodelname", &tags::HardcopyDeviceManufacturersModelName),
-        ("privatedataelementencoding", &tags::PrivateDataElementEncoding),
-        ("axiallengthoftheeye", &tags::AxialLengthoftheEye),
-        ("dosimetricobjectivetypecodesequence", &tags::DosimetricObjectiveTypeCodeSequence),
-        ("imageboxcontentsequence", &tags::ImageBoxContentSequence),
-        ("contextgroupversion", &tags::ContextGroupVersion),
-        ("tagthickness", &tags::TagThickness),
-        ("equivalentcodesequence", &tags::EquivalentCodeSequence),
-        ("functionalsyncpulse", &tags::FunctionalSyncPulse),
-        ("contrastflowrate", &tags::ContrastFlowRate),
-        ("segmentsurfacesourceinstancesequence", &tags::SegmentSurfaceSourceInstanceSequence),
-        ("alarmdecisiontime", &tags::AlarmDecisionTime),
-        ("gatingbeamholdtransitionsequence", &tags::GatingBeamHoldTransitionSequence),
-        ("replacedimplantassemblytemplatesequence", &tags::ReplacedImplantAssemblyTemplateSequence),
-        ("timeseriesblending", &tags::TimeSeriesBlending),
-        ("sourcestartdatetime", &tags::SourceStartDateTime),
+        ("channelstatus", &tags::ChannelStatus),
+        ("radiationbeamcompensatormillingtooldiameter", &tags::RadiationBeamCompensatorMillingToolDiameter),
+        ("dosevalue", &tags::DoseValue),
+        ("imagequalityindicatormaterial", &tags::ImageQualityIndicatorMaterial),
+        ("rtbeamdelimitergeometrysequence", &tags::RTBeamDelimiterGeometrySequence),
+        ("slicevector", &tags::SliceVector),
+        ("implanttemplategroupversion", &tags::ImplantTemplateGroupVersion),
+        ("presentationsequencepositionindex", &tags::PresentationSequencePositionIndex),
+        ("waveformfilterdescription", &tags::WaveformFilterDescription),
+        ("vitalstaincodesequence_trial", &tags::VitalStainCodeSequence_Trial),
+        ("reconstructionprotocolelementspecificationsequence", &tags::ReconstructionProtocolElementSpecificationSequence),
+        ("billingitemsequence", &tags::BillingItemSequence),
+        ("volumetricpresentationstateinputsequence", &tags::VolumetricPresentationStateInputSequence),
+        ("findingssequence_trial", &tags::FindingsSequence_Trial),
+        ("contextgrouplocalversion", &tags::ContextGroupLocalVersion),
+        ("patienteyemovementcommandcodesequence", &tags::PatientEyeMovementCommandCodeSequence),
+        ("contrastbolususagesequence", &tags::ContrastBolusUsageSequence),
+        ("addothersequence", &tags::AddOtherSequence),
+        ("rtbeamlimitingdevicedefinitionsequence", &tags::RTBeamLimitingDeviceDefinitionSequence),
+        ("lateralspreadingdevicenumber", &tags::LateralSpreadingDeviceNumber),
+        ("ctxraydetailssequence", &tags::CTXRayDetailsSequence),
+        ("recommendeddisplaycielabvalue", &tags::RecommendedDisplayCIELabValue),
+        ("numberofverticalpixels", &tags::NumberofVerticalPixels),
+        ("skipbeats", &tags::SkipBeats),
+        ("annotationgroupuid", &tags::AnnotationGroupUID),
+        ("numberofpatientrelatedstudies", &tags::NumberofPatientRelatedStudies),
+        ("dataelementlabelitemsequence", &tags::DataElementLabelItemSequence),
+        ("gpsprocessingmethod", &tags::GPSProcessingMethod),
+        ("imageboxoverlappriority", &tags::ImageBoxOverlapPriority),
+        ("hangingprotocoldefinitionsequence", &tags::HangingProtocolDefinitionSequence),
+        ("interlockresolutionusersequence", &tags::InterlockResolutionUserSequence),
+        ("deliverywarningdose", &tags::DeliveryWarningDose),
+        ("requestedsubsequentworkitemcodesequence", &tags::RequestedSubsequentWorkitemCodeSequence),
+        ("reasonforvisitcodesequence", &tags::ReasonforVisitCodeSequence),
+        ("numberofframesinphase", &tags::NumberofFramesinPhase),
+        ("softwareversions", &tags::SoftwareVersions),
+        ("imagepathfiltertypestackcodesequence", &tags::ImagePathFilterTypeStackCodeSequence),
+        ("radiationsourcecoordinatesystemrollangle", &tags::RadiationSourceCoordinateSystemRollAngle),
+        ("longitudinaltemporaleventtype", &tags::LongitudinalTemporalEventType),
         ("radiopharmaceuticalspecificactivity", &tags::RadiopharmaceuticalSpecificActivity),
-        ("patientposition", &tags::PatientPosition),
-        ("compensatortype", &tags::CompensatorType),
-        ("detectorsecondaryangle", &tags::DetectorSecondaryAngle),
-        ("conceptnamecodesequence", &tags::ConceptNameCodeSequence),
-        ("multienergysourcetechnique", &tags::MultienergySourceTechnique),
-        ("constraintviolationcondition", &tags::ConstraintViolationCondition),
-        ("mediainstalledsequence", &tags::MediaInstalledSequence),
-        ("volumetricpresentationinputsetuid", &tags::VolumetricPresentationInputSetUID),
-        ("patienttreatmentpreparationproceduresequence", &tags::PatientTreatmentPreparationProcedureSequence),
-        ("tableheight", &tags::TableHeight),
-        ("tolerancevalue", &tags::ToleranceValue),
-        ("autokvpupperbound", &tags::AutoKVPUpperBound),
-        ("scheduledpatientinstitutionresidence", &tags::ScheduledPatientInstitutionResidence),
-        ("collimatortype", &tags::CollimatorType),
-        ("basiccolorimagesequence", &tags::BasicColorImageSequence),
-        ("volumeframeofreferenceuid", &tags::VolumeFrameofReferenceUID),
-        ("radiationbeamblockslabthickness", &tags::RadiationBeamBlockSlabThickness),
-        ("studycompletiontime", &tags::StudyCompletionTime),
-        ("excitationwavelengthsequence", &tags::ExcitationWavelengthSequence),
-        ("othermediaavailablesequence", &tags::OtherMediaAvailableSequence),
-        ("stagenumber", &tags::StageNumber),
-        ("biopsytargetsequence", &tags::BiopsyTargetSequence),
-        ("alternatevaluesequence", &tags::AlternateValueSequence),
-        ("doublefloatrealworldvaluefirstvaluemapped", &tags::DoubleFloatRealWorldValueFirstValueMapped),
-        ("centerofrotation", &tags::CenterofRotation),
-        ("patientorientationinframesequence", &tags::PatientOrientationinFrameSequence),
-        ("inplanephaseencodingdirection", &tags::InplanePhaseEncodingDirection),
-        ("treatmentmachinename", &tags::TreatmentMachineName),
-        ("distancesourcetopatient", &tags::DistanceSourcetoPatient),
-        ("measuredbeamdimensiona", &tags::MeasuredBeamDimensionA),
-        ("numberofscanspotpositions", &tags::NumberofScanSpotPositions),
-        ("imagetranslationvector", &tags::ImageTranslationVector),
-        ("xraysourceindex", &tags::XRaySourceIndex),
-        ("numberofalarmobjects", &tags::NumberofAlarmObjects),
-        ("interpretationrecorder", &tags::InterpretationRecorder),
-        ("waterreferencedphasecorrection", &tags::WaterReferencedPhaseCorrection),
-        ("treatmentsessionionbeamsequence", &tags::TreatmentSessionIonBeamSequence),
-        ("outputpower", &tags::OutputPower),
-        ("energywindowname", &tags::EnergyWindowName),
-        ("straincodesequence", &tags::StrainCodeSequence),
-        ("gpsdestlatitude", &tags::GPSDestLatitude),
-        ("beamorderindex", &tags::BeamOrderIndex),
-        ("recordedsourcesequence", &tags::RecordedSourceSequence),
-        ("creatorversionuid", &tags::CreatorVersionUID),
-        ("axisofrotation", &tags::AxisofRotation),
-        ("entrancedosederivation", &tags::EntranceDoseDerivation),
-        ("anatomicstructurereferencepoint", &tags::AnatomicStructureReferencePoint),
-        ("columnpositionintotalimagepixelmatrix", &tags::ColumnPositionInTotalImagePixelMatrix),
-        ("numberofbscansperframe", &tags::NumberofBscansPerFrame),
-        ("roidescription", &tags::ROIDescription),
-        ("planeorientationsequence", &tags::PlaneOrientationSequence),
-        ("contributingsourcessequence", &tags::ContributingSourcesSequence),
-        ("measurementequipmentsequence", &tags::MeasurementEquipmentSequence),
-        ("brachypulsecontrolpointdeliveredsequence", &tags::BrachyPulseControlPointDeliveredSequence),
-        ("acquisitionuid", &tags::AcquisitionUID),
-        ("rangemodulatorgatingstartvalue", &tags::RangeModulatorGatingStartValue),
-        ("imagedvolumeheight", &tags::ImagedVolumeHeight),
-        ("parameterpointer", &tags::ParameterPointer),
-        ("sourcerollangle", &tags::SourceRollAngle),
-        ("predictorconstants", &tags::PredictorConstants),
-        ("currentpatientlocation", &tags::CurrentPatientLocation),
-        ("columns", &tags::Columns),
-        ("contrastbolusautoinjectiontriggerflag", &tags::ContrastBolusAutoInjectionTriggerFlag),
-        ("retrieveaetitle", &tags::RetrieveAETitle),
-        ("keratoconuspredictionindex", &tags::KeratoconusPredictionIndex),
-        ("centerfrequency", &tags::CenterFrequency),
-        ("atdassessmentprobability", &tags::ATDAssessmentProbability),
-        ("deletionlock", &tags::DeletionLock),
-        ("corneameasurementmethodcodesequence", &tags::CorneaMeasurementMethodCodeSequence),
-        ("codingschemeresourcessequence", &tags::CodingSchemeResourcesSequence),
-        ("totalpixelmatrixrows", &tags::TotalPixelMatrixRows),
-        ("numericvaluequalifiercodesequence", &tags::NumericValueQualifierCodeSequence),
-        ("rtimageplane", &tags::RTImagePlane),
-        ("nominalpercentageofrespiratoryphase", &tags::NominalPercentageofRespiratoryPhase),
-        ("scheduledstudylocationaetitle", &tags::ScheduledStudyLocationAETitle),
-        ("anatomicperspectivecodesequence_trial", &tags::AnatomicPerspectiveCodeSequence_Trial),
-        ("macparameterssequence", &tags::MACParametersSequence),
-        ("notchfiltercharacteristicssequence", &tags::NotchFilterCharacteristicsSequence),
-        ("volumecroppingsequence", &tags::VolumeCroppingSequence),
-        ("dosemeasurementdevicecodesequence", &tags::DoseMeasurementDeviceCodeSequence),
-        ("actualhumanperformerssequence", &tags::ActualHumanPerformersSequence),
-        ("immersionmedia", &tags::ImmersionMedia),
-        ("commentsonradiationdose", &tags::CommentsonRadiationDose),
-        ("sourcetoexternalcontourdistance", &tags::SourcetoExternalContourDistance),
-        ("referencedrtphysicianintentsequence", &tags::ReferencedRTPhysicianIntentSequence),
-        ("originalattributessequence", &tags::OriginalAttributesSequence),
-        ("devicealternateidentifier", &tags::DeviceAlternateIdentifier),
-        ("fieldofvieworigin", &tags::FieldofViewOrigin),
-        ("trim", &tags::Trim),
-        ("privatedataelementvaluemultiplicity", &tags::PrivateDataElementValueMultiplicity),
-        ("userselectedgainy", &tags::UserSelectedGainY),
-        ("multienergyacquisitiondescription", &tags::MultienergyAcquisitionDescription),
-        ("multiplanarexcitation", &tags::MultiplanarExcitation),
-        ("numberofrtcontrolpoints", &tags::NumberofRTControlPoints),
-        ("keratometerindex", &tags::KeratometerIndex),
-        ("displaywindowlabelvector", &tags::DisplayWindowLabelVector),
-        ("pixelshiftframerange", &tags::PixelShiftFrameRange),
-        ("reconstructiontargetcenterpatient", &tags::ReconstructionTargetCenterPatient),
-        ("outlineleftverticaledge", &tags::OutlineLeftVerticalEdge),
-        ("substanceadministrationdeviceid", &tags::SubstanceAdministrationDeviceID),
-        ("applicationsetupsequence", &tags::ApplicationSetupSequence),
-        ("rtplantime", &tags::RTPlanTime),
-        ("setupdevicetype", &tags::SetupDeviceType),
-        ("channeldeliveryorderindex", &tags::ChannelDeliveryOrderIndex),
-        ("thresholdsequence", &tags::ThresholdSequence),
-        ("dosegridscaling", &tags::DoseGridScaling),
-        ("compensatormillingtooldiameter", &tags::CompensatorMillingToolDiameter),
-        ("viewpointupdirection", &tags::ViewpointUpDirection),
-        ("timeofframegroupsequence", &tags::TimeofFrameGroupSequence),
-        ("patientsbirthdate", &tags::PatientsBirthDate),
-        ("mac", &tags::MAC),
-        ("normalizationpoint", &tags::NormalizationPoint),
-        ("multicoilelementname", &tags::MultiCoilElementName),
-        ("exposurecontrolsensingregionleftverticaledge", &tags::ExposureControlSensingRegionLeftVerticalEdge),
-        ("decaycorrected", &tags::DecayCorrected),
-        ("referencedaccessionsequence_trial", &tags::ReferencedAccessionSequence_Trial),
-        ("usedrtstructuresetroisequence", &tags::UsedRTStructureSetROISequence),
-        ("snoutpositiontolerance", &tags::SnoutPositionTolerance),
-        ("interpretationtranscriptiontime", &tags::InterpretationTranscriptionTime),
-        ("axialdetectordimension", &tags::AxialDetectorDimension),
-        ("messageid", &tags::MessageID),
-        ("treatmentsites", &tags::TreatmentSites),
-        ("sourcetoreferenceobjectdistance", &tags::SourcetoReferenceObjectDistance),
-        ("echotime", &tags::EchoTime),
-        ("detectorypositiontoisocenter", &tags::DetectorYPositiontoIsocenter),
-        ("focaldistance", &tags::FocalDistance),
-        ("maskpointers", &tags::MaskPointers),
-        ("numberofreferences", &tags::NumberofReferences),
-        ("contouruncertaintyradius", &tags::ContourUncertaintyRadius),
-        ("positionertype", &tags::PositionerType),
-        ("transducerapplicationcodesequence", &tags::TransducerApplicationCodeSequence),
-        ("gpsmeasuremode", &tags::GPSMeasureMode),
-        ("beamsequence", &tags::BeamSequence),
-        ("wedgefrontgap", &tags::WedgeFrontGap),
-        ("systemstatus", &tags::SystemStatus),
-        ("numberoflateralspreadingdevices", &tags::NumberofLateralSpreadingDevices),
-        ("hpgldocumentlabel", &tags::HPGLDocumentLabel),
-        ("alphaluttransferfunction", &tags::AlphaLUTTransferFunction),
-        ("beamlimitingdeviceangletolerance", &tags::BeamLimitingDeviceAngleTolerance),
-        ("referenceddosimetricobjectiveuid", &tags::ReferencedDosimetricObjectiveUID),
-        ("highdosetechniquetype", &tags::HighDoseTechniqueType),
-        ("bolusdefinitionsequence", &tags::BolusDefinitionSequence),
-        ("calculationcomment", &tags::CalculationComment),
-        ("currentobserver_trial", &tags::CurrentObserver_Trial),
-        ("treatmentcontrolpointtime", &tags::TreatmentControlPointTime),
+        ("volumetricproperties", &tags::VolumetricProperties),
+        ("radiationsourcecoordinatesystempitchangle", &tags::RadiationSourceCoordinateSystemPitchAngle),
+        ("interlockorigindescription", &tags::InterlockOriginDescription),
+        ("coefficientcoding", &tags::CoefficientCoding),
+        ("productname", &tags::ProductName),
+        ("referencednonimagecompositesopinstancesequence", &tags::ReferencedNonImageCompositeSOPInstanceSequence),
+        ("photoacousticimageframetypesequence", &tags::PhotoacousticImageFrameTypeSequence),
+        ("shifttablesize", &tags::ShiftTableSize),
+        ("contrastbolusagent", &tags::ContrastBolusAgent),
+        ("internaldetectorframetime", &tags::InternalDetectorFrameTime),
+        ("institutionaldepartmentname", &tags::InstitutionalDepartmentName),
+        ("planeorientationvolumesequence", &tags::PlaneOrientationVolumeSequence),
+        ("blendinglut1transferfunction", &tags::BlendingLUT1TransferFunction),
+        ("twodimensionaltothreedimensionalmapdata", &tags::TwoDimensionaltoThreeDimensionalMapData),
+        ("roiderivationalgorithmidentificationsequence", &tags::ROIDerivationAlgorithmIdentificationSequence),
+        ("blockorientation", &tags::BlockOrientation),
+        ("verifyingorganization", &tags::VerifyingOrganization),
+        ("rtimageframeradiationacquisitionsequence", &tags::RTImageFrameRadiationAcquisitionSequence),
+        ("pixelcomponentphysicalunits", &tags::PixelComponentPhysicalUnits),
+        ("overlaybackgrounddensity", &tags::OverlayBackgroundDensity),
+        ("flashfiringstatus", &tags::FlashFiringStatus),
+        ("entitylonglabel", &tags::EntityLongLabel),
+        ("manufacturer", &tags::Manufacturer),
+        ("rangemodulatordescription", &tags::RangeModulatorDescription),
+        ("nameofphysiciansreadingstudy", &tags::NameofPhysiciansReadingStudy),
+        ("illumination", &tags::Illumination),
+        ("filemetainformationversion", &tags::FileMetaInformationVersion),
+        ("postprocessingfunction", &tags::PostprocessingFunction),
+        ("rangemodulatorgatingstartwaterequivalentthickness", &tags::RangeModulatorGatingStartWaterEquivalentThickness),
+        ("delaylawidentifier", &tags::DelayLawIdentifier),
+        ("reconstructiondiameter", &tags::ReconstructionDiameter),
+        ("nominalminenergy", &tags::NominalMinEnergy),
+        ("ophthalmicpatientclinicalinformationrighteyesequence", &tags::OphthalmicPatientClinicalInformationRightEyeSequence),
+        ("medicalalerts", &tags::MedicalAlerts),
+        ("pertinentotherevidencesequence", &tags::PertinentOtherEvidenceSequence),
+        ("calculatedframelist", &tags::CalculatedFrameList),
+        ("waveformdisplaybackgroundcielabvalue", &tags::WaveformDisplayBackgroundCIELabValue),
+        ("mrimagingmodifiersequence", &tags::MRImagingModifierSequence),
+        ("scenetype", &tags::SceneType),
+        ("routesegmentlocationidtype", &tags::RouteSegmentLocationIDType),
+        ("destinationae", &tags::DestinationAE),
+        ("axialpower", &tags::AxialPower),
+        ("gridperiod", &tags::GridPeriod),
+        ("algorithmfamilycodesequence", &tags::AlgorithmFamilyCodeSequence),
+        ("applicationsetupmanufacturer", &tags::ApplicationSetupManufacturer),
+        ("activeimageareaoverlaygroup", &tags::ActiveImageAreaOverlayGroup),
+        ("rtphysicianintentindex", &tags::RTPhysicianIntentIndex),
+        ("diffusionbvalueyz", &tags::DiffusionbvalueYZ),
+        ("repeatinterval", &tags::RepeatInterval),
+        ("substanceadministrationdeviceid", &tags::SubstanceAdministrationDeviceID),
+        ("pixelcomponentorganization", &tags::PixelComponentOrganization),
+        ("referenceddosimetricobjectivessequence", &tags::ReferencedDosimetricObjectivesSequence),
+        ("patientidentityremoved", &tags::PatientIdentityRemoved),
+        ("relatedprocedurestepsequence", &tags::RelatedProcedureStepSequence),
+        ("datasetdescription", &tags::DataSetDescription),
+        ("seamlineindex", &tags::SeamLineIndex),
+        ("tablehorizontalrotationangle", &tags::TableHorizontalRotationAngle),
+        ("occupation", &tags::Occupation),
+        ("radialposition", &tags::RadialPosition),
         ("conversiontype", &tags::ConversionType),
-        ("inventorypurpose", &tags::InventoryPurpose),
-        ("imagepathfilterpassband", &tags::ImagePathFilterPassBand),
-        ("numberofenergywindows", &tags::NumberofEnergyWindows),
-        ("trayaccessorycode", &tags::TrayAccessoryCode),
-        ("breastimplantpresent", &tags::BreastImplantPresent),
+        ("imagesetnumber", &tags::ImageSetNumber),
+        ("referenceddigitalsignaturesequence", &tags::ReferencedDigitalSignatureSequence),
+        ("fiducialspropertycategorycodesequence", &tags::FiducialsPropertyCategoryCodeSequence),
+        ("rangemodulatorsequence", &tags::RangeModulatorSequence),
+        ("zonalmap", &tags::ZonalMap),
+        ("framereferencetime", &tags::FrameReferenceTime),
+        ("imagingsourcetobeammodifierdefinitionplanedistance", &tags::ImagingSourcetoBeamModifierDefinitionPlaneDistance),
+        ("anatomicapproachdirectioncodesequence_trial", &tags::AnatomicApproachDirectionCodeSequence_Trial),
+        ("xraytubecurrentinma", &tags::XRayTubeCurrentinmA),
+        ("gradientoutput", &tags::GradientOutput),
+        ("worklistlabel", &tags::WorklistLabel),
+        ("referringphysiciansaddress", &tags::ReferringPhysiciansAddress),
+        ("displaysubsystemdescription", &tags::DisplaySubsystemDescription),
+        ("numberofrtaccessoryholders", &tags::NumberofRTAccessoryHolders),
+        ("patientsupportdevicessequence", &tags::PatientSupportDevicesSequence),
+        ("derivationalgorithmsequence", &tags::DerivationAlgorithmSequence),
+        ("displaysetnumber", &tags::DisplaySetNumber),
+        ("instructiontext", &tags::InstructionText),
+        ("tabletopeccentricrotationdirection", &tags::TableTopEccentricRotationDirection),
+        ("stackid", &tags::StackID),
+        ("dataelementlabelsequence", &tags::DataElementLabelSequence),
+        ("usercontentlonglabel", &tags::UserContentLongLabel),
+        ("selectorattributevr", &tags::SelectorAttributeVR),
+        ("sequencedelimitationitem", &tags::SequenceDelimitationItem),
+        ("compensatordistalthicknessmap", &tags::CompensatorDistalThicknessMap),
+        ("informationfrommanufacturersequence", &tags::InformationFromManufacturerSequence),
+        ("temporalpositiontimeoffset", &tags::TemporalPositionTimeOffset),
+        ("completionflag", &tags::CompletionFlag),
+        ("largebluepalettecolorlookuptabledescriptor", &tags::LargeBluePaletteColorLookupTableDescriptor),
+        ("xfocuscenter", &tags::XFocusCenter),
+        ("hpglpensequence", &tags::HPGLPenSequence),
+        ("whitebalance", &tags::WhiteBalance),
+        ("contacturi", &tags::ContactURI),
+        ("mrtransmitcoilsequence", &tags::MRTransmitCoilSequence),
+        ("decompositionmaterialsequence", &tags::DecompositionMaterialSequence),
+        ("militaryrank", &tags::MilitaryRank),
         ("uvaluedata", &tags::UValueData),
-        ("framepixeldatapropertiessequence", &tags::FramePixelDataPropertiesSequence),
-        ("offsetdistance", &tags::OffsetDistance),
-        ("rtcontrolpointindex", &tags::RTControlPointIndex),
-        ("topickeywords", &tags::TopicKeywords),
-        ("applicatorid", &tags::ApplicatorID),
-        ("instructionperformedflag", &tags::InstructionPerformedFlag),
-        ("contentcreatorsname", &tags::ContentCreatorsName),
-        ("coordinatesystemaxiscodesequence", &tags::CoordinateSystemAxisCodeSequence),
-        ("fixationmonitoringcodesequence", &tags::FixationMonitoringCodeSequence),
-        ("selftimermode", &tags::SelfTimerMode),
-        ("detectorpositioningtype", &tags::DetectorPositioningType),
-        ("segmentdescription", &tags::SegmentDescription),
-        ("sharpness", &tags::Sharpness),
-        ("deviceserialnumber", &tags::DeviceSerialNumber),
-        ("contentqualification", &tags::ContentQualification),
-        ("referencepixelphysicalvaluey", &tags::ReferencePixelPhysicalValueY),
-        ("assigningfacilitysequence", &tags::AssigningFacilitySequence),
+        ("mediastoragesopinstanceuid", &tags::MediaStorageSOPInstanceUID),
         ("globalcroppingspecificationindex", &tags::GlobalCroppingSpecificationIndex),
-        ("vieworientationmodifiercodesequence", &tags::ViewOrientationModifierCodeSequence),
-        ("selectorlovalue", &tags::SelectorLOValue),
-        ("iconimagesequence", &tags::IconImageSequence),
-        ("energywindowupperlimit", &tags::EnergyWindowUpperLimit),
-        ("sourceisotopename", &tags::SourceIsotopeName),
-        ("implanttemplategroupvariationdimensionranksequence", &tags::ImplantTemplateGroupVariationDimensionRankSequence),
-        ("rttolerancesetlabel", &tags::RTToleranceSetLabel),
+        ("averagepulsewidth", &tags::AveragePulseWidth),
+        ("filmboxcontentsequence", &tags::FilmBoxContentSequence),
+        ("maximumnominalenergy", &tags::MaximumNominalEnergy),
+        ("radiopharmaceuticalroute", &tags::RadiopharmaceuticalRoute),
+        ("nearpupillarydistance", &tags::NearPupillaryDistance),
+        ("alinepixelspacing", &tags::AlinePixelSpacing),
+        ("volumestreamsequence", &tags::VolumeStreamSequence),
+        ("timeoflastdetectorcalibration", &tags::TimeofLastDetectorCalibration),
+        ("numberofvisualstimuli", &tags::NumberofVisualStimuli),
+        ("blendingposition", &tags::BlendingPosition),
+        ("compensatorid", &tags::CompensatorID),
+        ("sourceidentifier", &tags::SourceIdentifier),
+        ("framedetectorparameterssequence", &tags::FrameDetectorParametersSequence),
+        ("omittedradiationsequence", &tags::OmittedRadiationSequence),
+        ("annotationgroupdescription", &tags::AnnotationGroupDescription),
+        ("referencedstopcontrolpointindex", &tags::ReferencedStopControlPointIndex),
+        ("collimatorlowerhorizontaledge", &tags::CollimatorLowerHorizontalEdge),
+        ("numberofframesinrotation", &tags::NumberofFramesinRotation),
+        ("studyverifiedtime", &tags::StudyVerifiedTime),
+        ("referencedbrachyapplicationsetupnumber", &tags::ReferencedBrachyApplicationSetupNumber),
+        ("filelengthincontainer", &tags::FileLengthinContainer),
+        ("subjectdistance", &tags::SubjectDistance),
+        ("fillmode", &tags::FillMode),
+        ("urgencyorpriorityalerts_trial", &tags::UrgencyorPriorityAlerts_Trial),
+        ("gpslatituderef", &tags::GPSLatitudeRef),
+        ("completionflagdescription", &tags::CompletionFlagDescription),
+        ("scheduledadmissiontime", &tags::ScheduledAdmissionTime),
+        ("collimatorupperhorizontaledge", &tags::CollimatorUpperHorizontalEdge),
+        ("deliveredmeterset", &tags::DeliveredMeterset),
+        ("tabulatedvaluessequence", &tags::TabulatedValuesSequence),
+        ("selectedframefunctionalgroupssequence", &tags::SelectedFrameFunctionalGroupsSequence),
+        ("scheduledstepattributessequence", &tags::ScheduledStepAttributesSequence),
+        ("lowenergydetectors", &tags::LowEnergyDetectors),
+        ("corneameasurementssequence", &tags::CorneaMeasurementsSequence),
+        ("specificabsorptionratedefinition", &tags::SpecificAbsorptionRateDefinition),
+        ("otherpatientnames", &tags::OtherPatientNames),
+        ("rttreatmentterminationstatus", &tags::RTTreatmentTerminationStatus),
+        ("scanspotpositionmap", &tags::ScanSpotPositionMap),
+        ("testpointnormalsdataflag", &tags::TestPointNormalsDataFlag),
+        ("archiverequested", &tags::ArchiveRequested),
+        ("channelrecommendeddisplaycielabvalue", &tags::ChannelRecommendedDisplayCIELabValue),
+        ("informationsummary", &tags::InformationSummary),
+        ("lowrrvalue", &tags::LowRRValue),
+        ("annotationgroupnumber", &tags::AnnotationGroupNumber),
+        ("secondarycapturedevicesoftwareversions", &tags::SecondaryCaptureDeviceSoftwareVersions),
+        ("channelsensitivity", &tags::ChannelSensitivity),
+        ("visualfieldcatchtrialsequence", &tags::VisualFieldCatchTrialSequence),
+        ("deidentificationmethod", &tags::DeidentificationMethod),
+        ("interpretationapprovaldate", &tags::InterpretationApprovalDate),
+        ("radiopharmaceuticalvolume", &tags::RadiopharmaceuticalVolume),
+        ("fiducialsetsequence", &tags::FiducialSetSequence),
+        ("rationalnumeratorvalue", &tags::RationalNumeratorValue),
+        ("energywindowinformationsequence", &tags::EnergyWindowInformationSequence),
+        ("tabletoplongitudinaladjustedposition", &tags::TableTopLongitudinalAdjustedPosition),
+        ("acquisitionsinstudy", &tags::AcquisitionsinStudy),
+        ("imageboxtilehorizontaldimension", &tags::ImageBoxTileHorizontalDimension),
+        ("pertinentsopclassesinseries", &tags::PertinentSOPClassesinSeries),
+        ("rtradiationsalvagerecordcontrolpointsequence", &tags::RTRadiationSalvageRecordControlPointSequence),
+        ("geometricalproperties", &tags::GeometricalProperties),
+        ("conceptualvolumesequence", &tags::ConceptualVolumeSequence),
+        ("approvalstatusdatetime", &tags::ApprovalStatusDateTime),
+        ("carrieridassigningauthority", &tags::CarrierIDAssigningAuthority),
+        ("selectorattributeprivatecreator", &tags::SelectorAttributePrivateCreator),
+        ("referencedotherplanesequence", &tags::ReferencedOtherPlaneSequence),
+        ("tabletoplongitudinalpositiontolerance", &tags::TableTopLongitudinalPositionTolerance),
+        ("numberofaverages", &tags::NumberofAverages),
+        ("selectorutvalue", &tags::SelectorUTValue),
+        ("presentationlutflag", &tags::PresentationLUTFlag),
+        ("pressure", &tags::Pressure),
+        ("displaydevicetypecodesequence", &tags::DisplayDeviceTypeCodeSequence),
+        ("recordedsourceapplicatorsequence", &tags::RecordedSourceApplicatorSequence),
+        ("patientsetupphotodescription", &tags::PatientSetupPhotoDescription),
+        ("pixelpresentation", &tags::PixelPresentation),
+        ("interpretationtypeid", &tags::InterpretationTypeID),
+        ("nominalrangemodulationfractions", &tags::NominalRangeModulationFractions),
+        ("wedgecurve", &tags::WedgeCurve),
+        ("curvenumber", &tags::CurveNumber),
+        ("performedproceduretypedescription", &tags::PerformedProcedureTypeDescription),
+        ("longcodevalue", &tags::LongCodeValue),
+        ("patientsprimarylanguagemodifiercodesequence", &tags::PatientsPrimaryLanguageModifierCodeSequence),
+        ("acquisitiontaskapplicabilitysequence", &tags::AcquisitionTaskApplicabilitySequence),
+        ("coordinatessetgeometrictype_trial", &tags::CoordinatesSetGeometricType_Trial),
+        ("measurementssequence", &tags::MeasurementsSequence),
+        ("detectortemperaturesequence", &tags::DetectorTemperatureSequence),
+        ("fieldofviewhorizontalflip", &tags::FieldofViewHorizontalFlip),
+        ("xrayfiltersequence", &tags::XRayFilterSequence),
+        ("diameterofcircularoutline", &tags::DiameterofCircularOutline),
+        ("numberofscanspotpositions", &tags::NumberofScanSpotPositions),
+        ("stagename", &tags::StageName),
+        ("attenuationcorrectiontemporalrelationship", &tags::AttenuationCorrectionTemporalRelationship),
+        ("dopplercorrectionangle", &tags::DopplerCorrectionAngle),
+        ("internationalroutesegment", &tags::InternationalRouteSegment),
+        ("tag_3dplanenormal", &tags::Tag_3DPlaneNormal),
+        ("generatorid", &tags::GeneratorID),
+        ("intensifieractivedimensions", &tags::IntensifierActiveDimensions),
+        ("overlayrows", &tags::OverlayRows),
+        ("datasettype", &tags::DataSetType),
+        ("curvereferencedoverlaygroup", &tags::CurveReferencedOverlayGroup),
+        ("scheduledprocedurestepsequence", &tags::ScheduledProcedureStepSequence),
+        ("imagesetselectorusageflag", &tags::ImageSetSelectorUsageFlag),
+        ("originalspecializedsopclassuid", &tags::OriginalSpecializedSOPClassUID),
+        ("calciumscoringmassfactordevice", &tags::CalciumScoringMassFactorDevice),
+        ("setuptechniquedescription", &tags::SetupTechniqueDescription),
+        ("repeatfractioncyclelength", &tags::RepeatFractionCycleLength),
+        ("potentialscheduledprotocolcodesequence", &tags::PotentialScheduledProtocolCodeSequence),
         ("endcumulativetimeweight", &tags::EndCumulativeTimeWeight),
-        ("shutterpresentationcolorcielabvalue", &tags::ShutterPresentationColorCIELabValue),
-        ("rtimagelabel", &tags::RTImageLabel),
-        ("nuclearmedicineseriestype", &tags::NuclearMedicineSeriesType),
-        ("visitcomments", &tags::VisitComments),
-        ("rangeshifternumber", &tags::RangeShifterNumber),
-        ("rowpositionintotalimagepixelmatrix", &tags::RowPositionInTotalImagePixelMatrix),
-        ("referencedwedgenumber", &tags::ReferencedWedgeNumber),
-        ("setupdevicedescription", &tags::SetupDeviceDescription),
-        ("imageorientationvolume", &tags::ImageOrientationVolume),
-        ("framedimensionpointer", &tags::FrameDimensionPointer),
-        ("rtbeamlimitingdevicetype", &tags::RTBeamLimitingDeviceType),
-        ("tablecolumnnumber", &tags::TableColumnNumber),
-        ("xaacquisitionphasedetailssequence", &tags::XAAcquisitionPhaseDetailsSequence),
-        ("widefieldophthalmicphotographythresholdqualityrating", &tags::WideFieldOphthalmicPhotographyThresholdQualityRating),
-        ("patientsdeathdateinalternativecalendar", &tags::PatientsDeathDateinAlternativeCalendar),
-        ("catheterdirectionofrotation", &tags::CatheterDirectionofRotation),
-        ("referencedperformedprocedurestepsequence", &tags::ReferencedPerformedProcedureStepSequence),
-        ("rtvcommunicationsopclassuid", &tags::RTVCommunicationSOPClassUID),
-        ("illuminationwavelength", &tags::IlluminationWaveLength),
-        ("referencedtolerancetablenumber", &tags::ReferencedToleranceTableNumber),
-        ("roielementalcompositionatomicmassfraction", &tags::ROIElementalCompositionAtomicMassFraction),
-        ("omittedchannelsequence", &tags::OmittedChannelSequence),
-        ("secondarycapturedevicemanufacturer", &tags::SecondaryCaptureDeviceManufacturer),
-        ("numberofpatientrelatedseries", &tags::NumberofPatientRelatedSeries),
-        ("specimenlabelinimage", &tags::SpecimenLabelinImage),
-        ("beammeterset", &tags::BeamMeterset),
-        ("continuationendtotalreferenceairkerma", &tags::ContinuationEndTotalReferenceAirKerma),
-        ("materialattenuationsequence", &tags::MaterialAttenuationSequence),
-        ("totalblocktraywaterequivalentthickness", &tags::TotalBlockTrayWaterEquivalentThickness),
-        ("referencedverificationimagesequence", &tags::ReferencedVerificationImageSequence),
-        ("blendinglut2transferfunction", &tags::BlendingLUT2TransferFunction),
-        ("transducerorientation", &tags::TransducerOrientation),
-        ("dimensionorganizationuid", &tags::DimensionOrganizationUID),
-        ("aircounts", &tags::AirCounts),
-        ("sensitivityvalue", &tags::SensitivityValue),
-        ("annotationclipping", &tags::AnnotationClipping),
-        ("lutframerange", &tags::LUTFrameRange),
-        ("secondarypositionerscanarc", &tags::SecondaryPositionerScanArc),
-        ("devicepositiontoequipmentmappingmatrix", &tags::DevicePositiontoEquipmentMappingMatrix),
-        ("scanprocedure", &tags::ScanProcedure),
-        ("sourcestrengthunits", &tags::SourceStrengthUnits),
-        ("transducertechnologysequence", &tags::TransducerTechnologySequence),
-        ("contoursequence", &tags::ContourSequence),
-        ("frequencycorrection", &tags::FrequencyCorrection),
-        ("numberofrangemodulators", &tags::NumberofRangeModulators),
-        ("acquisitiondevicesequence", &tags::AcquisitionDeviceSequence),
-        ("generalaccessorydefinitionsequence", &tags::GeneralAccessoryDefinitionSequence),
-        ("devicelength", &tags::DeviceLength),
-        ("referencedsourceapplicatornumber", &tags::ReferencedSourceApplicatorNumber),
-        ("alternatebeamdosetype", &tags::AlternateBeamDoseType),
+        ("referencedbrachyaccessorydevicenumber", &tags::ReferencedBrachyAccessoryDeviceNumber),
+        ("imagingdevicelocationmatrixsequence", &tags::ImagingDeviceLocationMatrixSequence),
         ("meanpointdistance", &tags::MeanPointDistance),
-        ("frameofreferencerelationshipsequence", &tags::FrameofReferenceRelationshipSequence),
-        ("boundingboxtoplefthandcorner", &tags::BoundingBoxTopLeftHandCorner),
-        ("exifversion", &tags::EXIFVersion),
-        ("frameprimaryanglevector", &tags::FramePrimaryAngleVector),
-        ("scheduledprocessingapplicationscodesequence", &tags::ScheduledProcessingApplicationsCodeSequence),
-        ("patientsbirthdateinalternativecalendar", &tags::PatientsBirthDateinAlternativeCalendar),
-        ("atdabilityassessment", &tags::ATDAbilityAssessment),
-        ("treatmentterminationdescription", &tags::TreatmentTerminationDescription),
-        ("acquisitiondeviceprocessingdescription", &tags::AcquisitionDeviceProcessingDescription),
-        ("detectortemperature", &tags::DetectorTemperature),
-        ("darkcurrentsequence", &tags::DarkCurrentSequence),
-        ("ophthalmicvolumetricpropertiesflag", &tags::OphthalmicVolumetricPropertiesFlag),
-        ("patientsetupphotodescription", &tags::PatientSetupPhotoDescription),
-        ("patientssizecodesequence", &tags::PatientsSizeCodeSequence),
-        ("applicationname", &tags::ApplicationName),
-        ("equipmentcoordinatesystemidentification", &tags::EquipmentCoordinateSystemIdentification),
-        ("includesimagingsubject", &tags::IncludesImagingSubject),
-        ("brachyaccessorydevicename", &tags::BrachyAccessoryDeviceName),
-        ("illuminatortypecodesequence", &tags::IlluminatorTypeCodeSequence),
+        ("recordedbrachyaccessorydevicesequence", &tags::RecordedBrachyAccessoryDeviceSequence),
+        ("substanceadministrationapproval", &tags::SubstanceAdministrationApproval),
+        ("focalspots", &tags::FocalSpots),
+        ("acquisitionmethodalgorithmsequence", &tags::AcquisitionMethodAlgorithmSequence),
+        ("angularstep", &tags::AngularStep),
+        ("visualacuityrighteyesequence", &tags::VisualAcuityRightEyeSequence),
+        ("diffusionbvalueyy", &tags::DiffusionbvalueYY),
+        ("matingfeaturesetlabel", &tags::MatingFeatureSetLabel),
+        ("horizontaloffsetofsensor", &tags::HorizontalOffsetofSensor),
+        ("distancesourcetodetector", &tags::DistanceSourcetoDetector),
+        ("displayenvironmentspatialposition", &tags::DisplayEnvironmentSpatialPosition),
+        ("acquisitionstartcondition", &tags::AcquisitionStartCondition),
+        ("surfacemeshzpixeloffset", &tags::SurfaceMeshZPixelOffset),
+        ("overlaycompressionsteppointers", &tags::OverlayCompressionStepPointers),
         ("contentsequence", &tags::ContentSequence),
-        ("rttreatmentintenttype", &tags::RTTreatmentIntentType),
-        ("tableofxbreakpoints", &tags::TableofXBreakPoints),
-        ("annotationappliestoallopticalpaths", &tags::AnnotationAppliestoAllOpticalPaths),
-        ("beammodifierorientationangle", &tags::BeamModifierOrientationAngle),
-        ("nonuniformradialsamplingcorrected", &tags::NonuniformRadialSamplingCorrected),
-        ("mrtimingandrelatedparameterssequence", &tags::MRTimingandRelatedParametersSequence),
-        ("segmentnumber", &tags::SegmentNumber),
-        ("alternatebeamdose", &tags::AlternateBeamDose),
-        ("inboundarrivaltype", &tags::InboundArrivalType),
-        ("rtreferencedstudysequence", &tags::RTReferencedStudySequence),
-        ("physicianapprovinginterpretation", &tags::PhysicianApprovingInterpretation),
-        ("assessmentsetid", &tags::AssessmentSetID),
-        ("calculationcommentsequence", &tags::CalculationCommentSequence),
+        ("privatedataelementkeyword", &tags::PrivateDataElementKeyword),
+        ("numberoftransformsteps", &tags::NumberofTransformSteps),
+        ("gpsaltituderef", &tags::GPSAltitudeRef),
+        ("tabletopverticalsetupdisplacement", &tags::TableTopVerticalSetupDisplacement),
+        ("detectorlinesofresponseused", &tags::DetectorLinesofResponseUsed),
+        ("reconstructionangle", &tags::ReconstructionAngle),
+        ("referencedimagerealworldvaluemappingsequence", &tags::ReferencedImageRealWorldValueMappingSequence),
+        ("keratometrymeasurementtypecodesequence", &tags::KeratometryMeasurementTypeCodeSequence),
+        ("conceptualvolumetypecodesequence", &tags::ConceptualVolumeTypeCodeSequence),
+        ("mainlobeangle", &tags::MainLobeAngle),
+        ("detectorisocentersecondaryangle", &tags::DetectorIsocenterSecondaryAngle),
+        ("lossyimagecompression_retired", &tags::LossyImageCompression_Retired),
+        ("probedrivenotes", &tags::ProbeDriveNotes),
+        ("implantname", &tags::ImplantName),
+        ("rtimagesid", &tags::RTImageSID),
+        ("surfacecomments", &tags::SurfaceComments),
+        ("tag_2dmatingfeaturecoordinatessequence", &tags::Tag_2DMatingFeatureCoordinatesSequence),
         ("rtroiinterpretedtype", &tags::RTROIInterpretedType),
-        ("performedprotocolcodesequence", &tags::PerformedProtocolCodeSequence),
-        ("sequencevariant", &tags::SequenceVariant),
-        ("outerdiameter", &tags::OuterDiameter),
-        ("fovealpointnormativedataflag", &tags::FovealPointNormativeDataFlag),
-        ("physiciansofrecord", &tags::PhysiciansofRecord),
-        ("relativeopacity", &tags::RelativeOpacity),
-        ("contrastbolusagentsequence", &tags::ContrastBolusAgentSequence),
-        ("innerdiameter", &tags::InnerDiameter),
-        ("chemicalshiftreference", &tags::ChemicalShiftReference),
+        ("devicealternateidentifierformat", &tags::DeviceAlternateIdentifierFormat),
+        ("dicommediaretrievalsequence", &tags::DICOMMediaRetrievalSequence),
+        ("beamholdtransitiondatetime", &tags::BeamHoldTransitionDateTime),
+        ("assertionexpirationdatetime", &tags::AssertionExpirationDateTime),
+        ("safepositionexitdate", &tags::SafePositionExitDate),
+        ("cylinderaxis", &tags::CylinderAxis),
+        ("multiframesourcesopinstanceuid", &tags::MultiframeSourceSOPInstanceUID),
+        ("referencedpatientsequence", &tags::ReferencedPatientSequence),
+        ("rtacquisitionpatientpositionsequence", &tags::RTAcquisitionPatientPositionSequence),
+        ("equipmentadministratorsequence", &tags::EquipmentAdministratorSequence),
+        ("transmitcoiltype", &tags::TransmitCoilType),
+        ("waterreferenceacquisition", &tags::WaterReferenceAcquisition),
+        ("spectralwidth", &tags::SpectralWidth),
+        ("repetitiontime", &tags::RepetitionTime),
+        ("typeoffilters", &tags::TypeofFilters),
+        ("slicelocation", &tags::SliceLocation),
+        ("routesegmentstartlocationid", &tags::RouteSegmentStartLocationID),
+        ("yoffsetinslidecoordinatesystem", &tags::YOffsetinSlideCoordinateSystem),
+        ("digitalimageformatacquired", &tags::DigitalImageFormatAcquired),
+        ("curvetime", &tags::CurveTime),
+        ("receivetransducersettingssequence", &tags::ReceiveTransducerSettingsSequence),
+        ("energyunitcodesequence", &tags::EnergyUnitCodeSequence),
+        ("leafjawpositions", &tags::LeafJawPositions),
+        ("numberofopticalpaths", &tags::NumberofOpticalPaths),
+        ("ionmachineverificationsequence", &tags::IonMachineVerificationSequence),
+        ("activesourcediameter", &tags::ActiveSourceDiameter),
+        ("imageplanepixelspacing", &tags::ImagePlanePixelSpacing),
+        ("coincidencewindowwidth", &tags::CoincidenceWindowWidth),
+        ("curveactivationlayer", &tags::CurveActivationLayer),
+        ("mappedpixelvalue", &tags::MappedPixelValue),
+        ("petframecorrectionfactorssequence", &tags::PETFrameCorrectionFactorsSequence),
+        ("requestedprocedurelocation", &tags::RequestedProcedureLocation),
+        ("totalnumberofstudyrecords", &tags::TotalNumberofStudyRecords),
+        ("realworldvaluelutdata", &tags::RealWorldValueLUTData),
+        ("tabletoprolladjustedangle", &tags::TableTopRollAdjustedAngle),
+        ("waterdepth", &tags::WaterDepth),
+        ("imagepositionvolume", &tags::ImagePositionVolume),
+        ("typeofdata", &tags::TypeofData),
+        ("greenpalettecolorlookuptabledescriptor", &tags::GreenPaletteColorLookupTableDescriptor),
+        ("creationdate", &tags::CreationDate),
+        ("volumetricpresentationinputsetuid", &tags::VolumetricPresentationInputSetUID),
+        ("targetminimumdose", &tags::TargetMinimumDose),
+        ("boundingboxtoplefthandcorner", &tags::BoundingBoxTopLeftHandCorner),
+        ("scanvelocity", &tags::ScanVelocity),
+        ("coordinatestartvalue", &tags::CoordinateStartValue),
+        ("consentfordistributionflag", &tags::ConsentforDistributionFlag),
+        ("attenuationcorrectionmethod", &tags::AttenuationCorrectionMethod),
+        ("measuredlateraldimension", &tags::MeasuredLateralDimension),
+        ("annotationdisplayformatid", &tags::AnnotationDisplayFormatID),
+        ("deliveredchanneltotaltime", &tags::DeliveredChannelTotalTime),
+        ("organatriskmaximumdose", &tags::OrganatRiskMaximumDose),
+        ("encapsulatedpixeldatavaluetotallength", &tags::EncapsulatedPixelDataValueTotalLength),
+        ("hangingprotocolusergroupname", &tags::HangingProtocolUserGroupName),
         ("replacedimplanttemplatesequence", &tags::ReplacedImplantTemplateSequence),
-        ("filtertype", &tags::FilterType),
-        ("opticalpathdescription", &tags::OpticalPathDescription),
-        ("imagefilterdescription", &tags::ImageFilterDescription),
-        ("boundingrectangle", &tags::BoundingRectangle),
-        ("absorbeddosetometersetratio", &tags::AbsorbedDosetoMetersetRatio),
-        ("secondaryinspectionmethodsequence", &tags::SecondaryInspectionMethodSequence),
-        ("digitalsignaturepurposecodesequence", &tags::DigitalSignaturePurposeCodeSequence),
-        ("triggervector", &tags::TriggerVector),
-        ("startmeterset", &tags::StartMeterset),
-        ("numberofkspacetrajectories", &tags::NumberofkSpaceTrajectories),
-        ("materialid", &tags::MaterialID),
-        ("vertexpointindexlist", &tags::VertexPointIndexList),
-        ("ophthalmicthicknessmapqualityratingsequence", &tags::OphthalmicThicknessMapQualityRatingSequence),
-        ("reportstatusid_trial", &tags::ReportStatusID_Trial),
-        ("acquisitioncontextsequence", &tags::AcquisitionContextSequence),
-        ("numberofintervalfractions", &tags::NumberofIntervalFractions),
-        ("nondicomoutputcodesequence", &tags::NonDICOMOutputCodeSequence),
-        ("contrastbolusingredientconcentration", &tags::ContrastBolusIngredientConcentration),
-        ("printerstatusinfo", &tags::PrinterStatusInfo),
-        ("displaysetpatientorientation", &tags::DisplaySetPatientOrientation),
-        ("hangingprotocolcreationdatetime", &tags::HangingProtocolCreationDateTime),
-        ("basisrttreatmentphaseindex", &tags::BasisRTTreatmentPhaseIndex),
-        ("tablexpositiontoisocenter", &tags::TableXPositiontoIsocenter),
+        ("movedestination", &tags::MoveDestination),
+        ("adaptivemapformat", &tags::AdaptiveMapFormat),
+        ("mprviewwidthdirection", &tags::MPRViewWidthDirection),
+        ("actualrespiratorytriggerdelaytime", &tags::ActualRespiratoryTriggerDelayTime),
+        ("tagspacingseconddimension", &tags::TagSpacingSecondDimension),
+        ("privateinformation", &tags::PrivateInformation),
+        ("referencedtreatmentpositiongroupuid", &tags::ReferencedTreatmentPositionGroupUID),
+        ("darkcurrentsequence", &tags::DarkCurrentSequence),
+        ("boundingboxcrop", &tags::BoundingBoxCrop),
+        ("surfacescanmodecodesequence", &tags::SurfaceScanModeCodeSequence),
+        ("regiondatatype", &tags::RegionDataType),
+        ("columns", &tags::Columns),
+        ("stereoverticalpixeloffset", &tags::StereoVerticalPixelOffset),
+        ("hpgldocumentlabel", &tags::HPGLDocumentLabel),
+        ("pixelcomponentdatatype", &tags::PixelComponentDataType),
+        ("surfaceasymmetryindex", &tags::SurfaceAsymmetryIndex),
+        ("qaresultssequence", &tags::QAResultsSequence),
+        ("boundingpolygon", &tags::BoundingPolygon),
+        ("fiducialidentifier", &tags::FiducialIdentifier),
+        ("multiplanarreconstructionstyle", &tags::MultiPlanarReconstructionStyle),
+        ("renderfieldofview", &tags::RenderFieldofView),
+        ("mprthicknesstype", &tags::MPRThicknessType),
+        ("selectorofvalue", &tags::SelectorOFValue),
+        ("relativeimagepositioncodesequence", &tags::RelativeImagePositionCodeSequence),
+        ("linethickness", &tags::LineThickness),
+        ("inventoryaccessendpointssequence", &tags::InventoryAccessEndPointsSequence),
+        ("tracksetdescription", &tags::TrackSetDescription),
+        ("vectoraccuracy", &tags::VectorAccuracy),
+        ("pixelcomponentmask", &tags::PixelComponentMask),
+        ("zoomcenter", &tags::ZoomCenter),
+        ("approvalstatusfurtherdescription", &tags::ApprovalStatusFurtherDescription),
+        ("otherpupillarydistance", &tags::OtherPupillaryDistance),
+        ("inversiontime", &tags::InversionTime),
+        ("imagesetselectorsequence", &tags::ImageSetSelectorSequence),
+        ("opticalpathidentifier", &tags::OpticalPathIdentifier),
+        ("showpatientdemographicsflag", &tags::ShowPatientDemographicsFlag),
+        ("lensthickness", &tags::LensThickness),
+        ("gradientechotrainlength", &tags::GradientEchoTrainLength),
+        ("dosevaluessequence", &tags::DoseValuesSequence),
+        ("normalizationpoint", &tags::NormalizationPoint),
+        ("dosimetricobjectiveevaluationscope", &tags::DosimetricObjectiveEvaluationScope),
+        ("inputreadinessstate", &tags::InputReadinessState),
+        ("ophthalmicaxiallengthmeasurementslengthsummationsequence", &tags::OphthalmicAxialLengthMeasurementsLengthSummationSequence),
+        ("date", &tags::Date),
+        ("probecenterlocationz", &tags::ProbeCenterLocationZ),
+        ("catheterdirectionofrotation", &tags::CatheterDirectionofRotation),
+        ("dvhnormalizationdosevalue", &tags::DVHNormalizationDoseValue),
+        ("detectoractivationoffsetfromexposure", &tags::DetectorActivationOffsetFromExposure),
+        ("averagecornealpower", &tags::AverageCornealPower),
+        ("distanceobjecttotabletop", &tags::DistanceObjecttoTableTop),
+        ("radiationmode", &tags::RadiationMode),
+        ("safepositionreturntime", &tags::SafePositionReturnTime),
+        ("rttreatmenttechniquecodesequence", &tags::RTTreatmentTechniqueCodeSequence),
+        ("specimendescription_trial", &tags::SpecimenDescription_Trial),
+        ("bodypartthickness", &tags::BodyPartThickness),
+        ("detectorid", &tags::DetectorID),
+        ("columnangulation", &tags::ColumnAngulation),
+        ("gaincontrol", &tags::GainControl),
+        ("visualevaluationtestsequence", &tags::VisualEvaluationTestSequence),
+        ("procedurestepprogress", &tags::ProcedureStepProgress),
+        ("cttabledynamicssequence", &tags::CTTableDynamicsSequence),
+        ("referencedparentrtprescriptionindex", &tags::ReferencedParentRTPrescriptionIndex),
+        ("includenondicomobjects", &tags::IncludeNonDICOMObjects),
+        ("overlaycodetablelocation", &tags::OverlayCodeTableLocation),
+        ("exposurecontrolsensingregionshape", &tags::ExposureControlSensingRegionShape),
+        ("breastsupportisocenterprimaryangle", &tags::BreastSupportIsocenterPrimaryAngle),
+        ("recordedsourcesequence", &tags::RecordedSourceSequence),
+        ("transducerposition", &tags::TransducerPosition),
+        ("sourceencapsulationnominaltransmission", &tags::SourceEncapsulationNominalTransmission),
+        ("rangingdepth", &tags::RangingDepth),
+        ("coverageofkspace", &tags::CoverageofkSpace),
+        ("roielementalcompositionsequence", &tags::ROIElementalCompositionSequence),
+        ("framedelay", &tags::FrameDelay),
+        ("receivernotes", &tags::ReceiverNotes),
+        ("radiationbeamblockslabthickness", &tags::RadiationBeamBlockSlabThickness),
+        ("switchingphasenominalduration", &tags::SwitchingPhaseNominalDuration),
+        ("materialscodesequence", &tags::MaterialsCodeSequence),
+        ("respiratorysynchronizationsequence", &tags::RespiratorySynchronizationSequence),
+        ("rtimagelabel", &tags::RTImageLabel),
+        ("manufacturersdeviceclassuid", &tags::ManufacturersDeviceClassUID),
+        ("regionlocationminy0", &tags::RegionLocationMinY0),
+        ("transducerpositionmodifiersequence", &tags::TransducerPositionModifierSequence),
+        ("presentationlutcontentsequence", &tags::PresentationLUTContentSequence),
+        ("volumecroppingmethod", &tags::VolumeCroppingMethod),
+        ("xraygridsequence", &tags::XRayGridSequence),
+        ("implanttemplategroupissuer", &tags::ImplantTemplateGroupIssuer),
+        ("patienttreatmentpreparationmethoddescription", &tags::PatientTreatmentPreparationMethodDescription),
+        ("italic", &tags::Italic),
+        ("multiplanarexcitation", &tags::MultiplanarExcitation),
+        ("consultingphysicianidentificationsequence", &tags::ConsultingPhysicianIdentificationSequence),
+        ("acquisitionsubtasksequence", &tags::AcquisitionSubtaskSequence),
+        ("planintent", &tags::PlanIntent),
+        ("curvaturetype", &tags::CurvatureType),
+        ("dataframeassignmentsequence", &tags::DataFrameAssignmentSequence),
+        ("refractiveindexofcornea", &tags::RefractiveIndexofCornea),
+        ("transformationalgorithmsequence", &tags::TransformationAlgorithmSequence),
+        ("derivationcodesequence", &tags::DerivationCodeSequence),
+        ("numberofrtbeamlimitingdeviceopenings", &tags::NumberofRTBeamLimitingDeviceOpenings),
+        ("relatedrtroiobservationssequence", &tags::RelatedRTROIObservationsSequence),
+        ("referencedobservationclass_trial", &tags::ReferencedObservationClass_Trial),
+        ("patientspeciesdescription", &tags::PatientSpeciesDescription),
+        ("implantassemblytemplatename", &tags::ImplantAssemblyTemplateName),
+        ("selectorovvalue", &tags::SelectorOVValue),
+        ("segmentsurfacesourceinstancesequence", &tags::SegmentSurfaceSourceInstanceSequence),
+        ("reconstructionalgorithmsequence", &tags::ReconstructionAlgorithmSequence),
+        ("aslboluscutofftechnique", &tags::ASLBolusCutoffTechnique),
+        ("modality", &tags::Modality),
+        ("presentationcreationdate", &tags::PresentationCreationDate),
+        ("numberofsurfaces", &tags::NumberofSurfaces),
+        ("specialneeds", &tags::SpecialNeeds),
+        ("clinicaltrialcoordinatingcentername", &tags::ClinicalTrialCoordinatingCenterName),
+        ("interventiondrugcodesequence", &tags::InterventionDrugCodeSequence),
+        ("roboticnodeidentifier", &tags::RoboticNodeIdentifier),
+        ("referencedstructuresetsequence", &tags::ReferencedStructureSetSequence),
+        ("graylookuptabledescriptor", &tags::GrayLookupTableDescriptor),
+        ("assigningjurisdictioncodesequence", &tags::AssigningJurisdictionCodeSequence),
+        ("calibrationdate", &tags::CalibrationDate),
+        ("headfixationangletolerance", &tags::HeadFixationAngleTolerance),
+        ("presentationlutshape", &tags::PresentationLUTShape),
+        ("ethicscommitteeapprovaleffectivenessenddate", &tags::EthicsCommitteeApprovalEffectivenessEndDate),
+        ("proceduresteplabel", &tags::ProcedureStepLabel),
+        ("verificationimagetiming", &tags::VerificationImageTiming),
+        ("ophthalmicaxiallengthselectionmethodcodesequence", &tags::OphthalmicAxialLengthSelectionMethodCodeSequence),
+        ("secondarypositionerscanstartangle", &tags::SecondaryPositionerScanStartAngle),
+        ("institutioncodesequence", &tags::InstitutionCodeSequence),
+        ("patientreliabilityindicator", &tags::PatientReliabilityIndicator),
+        ("sourcemovementtype", &tags::SourceMovementType),
+        ("probedrivesettingssequence", &tags::ProbeDriveSettingsSequence),
+        ("scattercorrected", &tags::ScatterCorrected),
+        ("roielementalcompositionatomicmassfraction", &tags::ROIElementalCompositionAtomicMassFraction),
+        ("rttolerancesetsequence", &tags::RTToleranceSetSequence),
+        ("curvedescription", &tags::CurveDescription),
+        ("mindensity", &tags::MinDensity),
+        ("autorefractionlefteyesequence", &tags::AutorefractionLeftEyeSequence),
+        ("filtermaterial", &tags::FilterMaterial),
+        ("recordedcompensatorsequence", &tags::RecordedCompensatorSequence),
+        ("componentmanufacturingprocedure", &tags::ComponentManufacturingProcedure),
+        ("totalnumberofpiecesofmediacreated", &tags::TotalNumberofPiecesofMediaCreated),
+        ("reformattingoperationtype", &tags::ReformattingOperationType),
+        ("parallelacquisition", &tags::ParallelAcquisition),
+        ("prescriptionnotessequence", &tags::PrescriptionNotesSequence),
         ("collimatorleftverticaledge", &tags::CollimatorLeftVerticalEdge),
-        ("sourceirradiationeventsequence", &tags::SourceIrradiationEventSequence),
-        ("startcardiactriggercountthreshold", &tags::StartCardiacTriggerCountThreshold),
-        ("variableflipangleflag", &tags::VariableFlipAngleFlag),
-        ("tabletoplateralposition", &tags::TableTopLateralPosition),
-        ("roigenerationdescription", &tags::ROIGenerationDescription),
-        ("verifyingobservername", &tags::VerifyingObserverName),
-        ("regionlocationmaxx1", &tags::RegionLocationMaxX1),
-        ("dosecalibrationconditionssequence", &tags::DoseCalibrationConditionsSequence),
-        ("measuredmetersettodosemappingsequence", &tags::MeasuredMetersettoDoseMappingSequence),
-        ("annotationcoordinatetype", &tags::AnnotationCoordinateType),
-        ("diffusionbvaluexz", &tags::DiffusionbvalueXZ),
-        ("rgbatransferfunctiondescription", &tags::RGBATransferFunctionDescription),
-        ("patientsupporttype", &tags::PatientSupportType),
-        ("surfacemodeldescriptionsequence", &tags::SurfaceModelDescriptionSequence),
-        ("coordinatessetgeometrictype_trial", &tags::CoordinatesSetGeometricType_Trial),
-        ("bibliographiccitation_trial", &tags::BibliographicCitation_Trial),
-        ("sliceprogressiondirection", &tags::SliceProgressionDirection),
-        ("gpsdestdistance", &tags::GPSDestDistance),
-        ("temporalpositiontimeoffset", &tags::TemporalPositionTimeOffset),
-        ("fieldofviewdimensionsinfloat", &tags::FieldofViewDimensionsinFloat),
-        ("calibrationtime", &tags::CalibrationTime),
-        ("numberofsubsets", &tags::NumberofSubsets),
-        ("studyarrivaldate", &tags::StudyArrivalDate),
-        ("unassignedperframeconvertedattributessequence", &tags::UnassignedPerFrameConvertedAttributesSequence),
-        ("failedsopinstanceuidlist", &tags::FailedSOPInstanceUIDList),
-        ("patientcomments", &tags::PatientComments),
-        ("observationsignificance", &tags::ObservationSignificance),
-        ("aslcrusherdescription", &tags::ASLCrusherDescription),
-        ("oecfcolumnnames", &tags::OECFColumnNames),
-        ("planintent", &tags::PlanIntent),
-        ("firstorderphasecorrectionangle", &tags::FirstOrderPhaseCorrectionAngle),
-        ("treatmenttoleranceviolationtypecodesequence", &tags::TreatmentToleranceViolationTypeCodeSequence),
-        ("gantrydetectorslew", &tags::GantryDetectorSlew),
-        ("showgraphicannotationflag", &tags::ShowGraphicAnnotationFlag),
-        ("transactionstatus", &tags::TransactionStatus),
-        ("preferredplaybacksequencing", &tags::PreferredPlaybackSequencing),
-        ("tracksetstatisticssequence", &tags::TrackSetStatisticsSequence),
-        ("annotationgroupdescription", &tags::AnnotationGroupDescription),
-        ("gpsdestbearing", &tags::GPSDestBearing),
-        ("prescriptionoverviewsequence", &tags::PrescriptionOverviewSequence),
-        ("referencedwaveformsequence", &tags::ReferencedWaveformSequence),
-        ("spatiallocationspreserved", &tags::SpatialLocationsPreserved),
-        ("controlpoint3dposition", &tags::ControlPoint3DPosition),
-        ("segmentedpropertytypecodesequence", &tags::SegmentedPropertyTypeCodeSequence),
-        ("animationcurvesequence", &tags::AnimationCurveSequence),
-        ("datapathid", &tags::DataPathID),
-        ("tag_2dpointcoordinates", &tags::Tag_2DPointCoordinates),
-        ("frameextractionsequence", &tags::FrameExtractionSequence),
-        ("therapeuticroletypecodesequence", &tags::TherapeuticRoleTypeCodeSequence),
-        ("patientadditionalposition", &tags::PatientAdditionalPosition),
-        ("mimetypeofencapsulateddocument", &tags::MIMETypeofEncapsulatedDocument),
-        ("nominalfrequency", &tags::NominalFrequency),
-        ("opticalpathidentificationsequence", &tags::OpticalPathIdentificationSequence),
-        ("gpsdestlongituderef", &tags::GPSDestLongitudeRef),
-        ("regionlocationminx0", &tags::RegionLocationMinX0),
-        ("verticaloffsetofsensor", &tags::VerticalOffsetofSensor),
-        ("blendinglut2sequence", &tags::BlendingLUT2Sequence),
-        ("secondarypositionerincrement", &tags::SecondaryPositionerIncrement),
-        ("outputinformationsequence", &tags::OutputInformationSequence),
-        ("confocalmicroscopyimageframetypesequence", &tags::ConfocalMicroscopyImageFrameTypeSequence),
-        ("iolformuladetail", &tags::IOLFormulaDetail),
-        ("contributingequipmentsequence", &tags::ContributingEquipmentSequence),
-        ("sourcetype", &tags::SourceType),
-        ("horizontaloffsetofsensor", &tags::HorizontalOffsetofSensor),
-        ("volumebasedcalculationtechnique", &tags::VolumeBasedCalculationTechnique),
+        ("contrastframeaveraging", &tags::ContrastFrameAveraging),
+        ("relatedframeofreferenceuid", &tags::RelatedFrameofReferenceUID),
+        ("synchronizedscrollingsequence", &tags::SynchronizedScrollingSequence),
+        ("primaryanatomicstructuresequence", &tags::PrimaryAnatomicStructureSequence),
+        ("protocolelementname", &tags::ProtocolElementName),
+        ("queryretrieveview", &tags::QueryRetrieveView),
+        ("annotationposition", &tags::AnnotationPosition),
+        ("eventtimersequence", &tags::EventTimerSequence),
+        ("referenceddosereferencesequence", &tags::ReferencedDoseReferenceSequence),
+        ("qrmeasurementssequence", &tags::QRMeasurementsSequence),
+        ("radiopharmaceutical", &tags::Radiopharmaceutical),
+        ("contentcreatorsname", &tags::ContentCreatorsName),
+        ("intermediatepupillarydistance", &tags::IntermediatePupillaryDistance),
+        ("beamorderindex_trial", &tags::BeamOrderIndex_Trial),
+        ("effectivedatetime", &tags::EffectiveDateTime),
+        ("protocoldefinedpatientposition", &tags::ProtocolDefinedPatientPosition),
+        ("gpsdestdistanceref", &tags::GPSDestDistanceRef),
+        ("reflectedambientlight", &tags::ReflectedAmbientLight),
+        ("beamdosetype", &tags::BeamDoseType),
+        ("wedgeoffsety", &tags::WedgeOffsetY),
+        ("spectroscopydata", &tags::SpectroscopyData),
+        ("delivereddistaldepthfraction", &tags::DeliveredDistalDepthFraction),
+        ("treatmentsessionionbeamsequence", &tags::TreatmentSessionIonBeamSequence),
+        ("verticesoftheoutlineofpupil", &tags::VerticesoftheOutlineofPupil),
+        ("refractiveindexofaqueoushumor", &tags::RefractiveIndexofAqueousHumor),
+        ("overlaytime", &tags::OverlayTime),
+        ("reconstructionpixelspacing", &tags::ReconstructionPixelSpacing),
+        ("patientsetupsequence", &tags::PatientSetupSequence),
+        ("beamangle", &tags::BeamAngle),
+        ("distributionaddress", &tags::DistributionAddress),
+        ("codingschemeversion", &tags::CodingSchemeVersion),
+        ("reviewtime", &tags::ReviewTime),
+        ("globaldeviationfromnormal", &tags::GlobalDeviationFromNormal),
+        ("trianglefansequence", &tags::TriangleFanSequence),
+        ("recommendedpresentationtype", &tags::RecommendedPresentationType),
+        ("overlaypixeldatasequence", &tags::OverlayPixelDataSequence),
+        ("largeredpalettecolorlookuptabledata", &tags::LargeRedPaletteColorLookupTableData),
+        ("echotrainlength", &tags::EchoTrainLength),
+        ("bitsforcodeword", &tags::BitsForCodeWord),
+        ("reasonforomissiondescription", &tags::ReasonforOmissionDescription),
+        ("treatmenttechniquenotes", &tags::TreatmentTechniqueNotes),
+        ("measuredapdimension", &tags::MeasuredAPDimension),
+        ("topicauthor", &tags::TopicAuthor),
+        ("treatmentsessionbeamsequence", &tags::TreatmentSessionBeamSequence),
+        ("retrieveuri", &tags::RetrieveURI),
+        ("numberofblockslabitems", &tags::NumberofBlockSlabItems),
+        ("negativecatchtrialsquantity", &tags::NegativeCatchTrialsQuantity),
+        ("scattercorrectionmethod", &tags::ScatterCorrectionMethod),
+        ("sourceapplicatorwallnominalthickness", &tags::SourceApplicatorWallNominalThickness),
+        ("selectorasvalue", &tags::SelectorASValue),
+        ("displacementreferencelocationcodesequence", &tags::DisplacementReferenceLocationCodeSequence),
+        ("mandatorycomponenttype", &tags::MandatoryComponentType),
+        ("totalcompensatortrayfactor", &tags::TotalCompensatorTrayFactor),
+        ("lensserialnumber", &tags::LensSerialNumber),
+        ("representativeframenumber", &tags::RepresentativeFrameNumber),
+        ("relativetime", &tags::RelativeTime),
+        ("convolutionkernel", &tags::ConvolutionKernel),
+        ("referencedtimeoffsets", &tags::ReferencedTimeOffsets),
+        ("orderfilleridentifiersequence", &tags::OrderFillerIdentifierSequence),
+        ("recommendedexposureindex", &tags::RecommendedExposureIndex),
+        ("contentdescription", &tags::ContentDescription),
+        ("matingfeaturedegreeoffreedomsequence", &tags::MatingFeatureDegreeofFreedomSequence),
+        ("rttreatmentapproachlabel", &tags::RTTreatmentApproachLabel),
+        ("zonalmaplocation", &tags::ZonalMapLocation),
+        ("compensatorshapesequence", &tags::CompensatorShapeSequence),
+        ("personsaddress", &tags::PersonsAddress),
+        ("smallestimagepixelvalue", &tags::SmallestImagePixelValue),
+        ("channeloverlap", &tags::ChannelOverlap),
+        ("inspectionselectioncriteria", &tags::InspectionSelectionCriteria),
+        ("axialdetectordimension", &tags::AxialDetectorDimension),
+        ("ophthalmicaxiallengthqualitymetrictypedescription", &tags::OphthalmicAxialLengthQualityMetricTypeDescription),
+        ("bscanslabthickness", &tags::BscanSlabThickness),
+        ("proberesistance", &tags::ProbeResistance),
+        ("implanttargetanatomysequence", &tags::ImplantTargetAnatomySequence),
+        ("routeidassigningauthority", &tags::RouteIDAssigningAuthority),
+        ("tmlinepositiony1_retired", &tags::TMLinePositionY1_Retired),
+        ("framelaterality", &tags::FrameLaterality),
+        ("referencedsourceapplicatornumber", &tags::ReferencedSourceApplicatorNumber),
+        ("basicgrayscaleimagesequence", &tags::BasicGrayscaleImageSequence),
+        ("ophthalmicaxiallengthmeasurementstype", &tags::OphthalmicAxialLengthMeasurementsType),
+        ("pupilcentroidycoordinate", &tags::PupilCentroidYCoordinate),
+        ("continuationendmeterset", &tags::ContinuationEndMeterset),
+        ("expectedinvivomeasurementvaluessequence", &tags::ExpectedInVivoMeasurementValuesSequence),
+        ("units", &tags::Units),
+        ("mprviewheight", &tags::MPRViewHeight),
+        ("referencedrtprescriptionindex", &tags::ReferencedRTPrescriptionIndex),
+        ("qualitythreshold", &tags::QualityThreshold),
+        ("insuranceplanidentification", &tags::InsurancePlanIdentification),
+        ("privatedataelementvaluerepresentation", &tags::PrivateDataElementValueRepresentation),
+        ("numberofpatientrelatedinstances", &tags::NumberofPatientRelatedInstances),
+        ("inputsequencepositionindex", &tags::InputSequencePositionIndex),
+        ("numberoftables", &tags::NumberofTables),
+        ("viewpointupdirection", &tags::ViewpointUpDirection),
+        ("velocityencodingminimumvalue", &tags::VelocityEncodingMinimumValue),
+        ("rfechotrainlength", &tags::RFEchoTrainLength),
+        ("therapytype", &tags::TherapyType),
+        ("templatename", &tags::TemplateName),
+        ("secondarypositionerscanarc", &tags::SecondaryPositionerScanArc),
+        ("planeidentification", &tags::PlaneIdentification),
+        ("pixelcomponentrangestop", &tags::PixelComponentRangeStop),
+        ("escapetriplet", &tags::EscapeTriplet),
+        ("rtbeamlimitingdeviceopeningsequence", &tags::RTBeamLimitingDeviceOpeningSequence),
+        ("devicemotionobservationmode", &tags::DeviceMotionObservationMode),
+        ("referencedresultssequence", &tags::ReferencedResultsSequence),
+        ("registeredlocalizerunits", &tags::RegisteredLocalizerUnits),
+        ("measuredbeamdimensionb", &tags::MeasuredBeamDimensionB),
+        ("calibrationsequence", &tags::CalibrationSequence),
+        ("transfersyntaxuid", &tags::TransferSyntaxUID),
+        ("palettecolorlookuptableuid", &tags::PaletteColorLookupTableUID),
+        ("controlpointindex", &tags::ControlPointIndex),
+        ("reasonforcancellation", &tags::ReasonforCancellation),
+        ("fractionbasedrelationshipintervalanchor", &tags::FractionBasedRelationshipIntervalAnchor),
+        ("xraydetectorindex", &tags::XRayDetectorIndex),
+        ("imagepositionpatient", &tags::ImagePositionPatient),
+        ("beamdescription", &tags::BeamDescription),
+        ("constraintviolationsignificance", &tags::ConstraintViolationSignificance),
+        ("referencedptosequence", &tags::ReferencedPTOSequence),
+        ("distancesourcetoisocenter", &tags::DistanceSourcetoIsocenter),
+        ("scheduleddischargedate", &tags::ScheduledDischargeDate),
+        ("b1rms", &tags::B1rms),
+        ("gradientoutputtype", &tags::GradientOutputType),
+        ("threatsequence", &tags::ThreatSequence),
+        ("gpsdatestamp", &tags::GPSDateStamp),
+        ("currentconfigurationid", &tags::CurrentConfigurationID),
+        ("strainsourceregistrycodesequence", &tags::StrainSourceRegistryCodeSequence),
+        ("delivereddistaldepth", &tags::DeliveredDistalDepth),
+        ("devicedescription", &tags::DeviceDescription),
+        ("mappingresource", &tags::MappingResource),
+        ("numberofpolygonalvertices", &tags::NumberofPolygonalVertices),
+        ("maximumcornealcurvaturelocation", &tags::MaximumCornealCurvatureLocation),
+        ("energywindowrangesequence", &tags::EnergyWindowRangeSequence),
+        ("radiationdosevalue", &tags::RadiationDoseValue),
+        ("metersetrateset", &tags::MetersetRateSet),
         ("extendeddepthoffield", &tags::ExtendedDepthofField),
+        ("fixationeye", &tags::FixationEye),
+        ("component2referencedmatingfeatureid", &tags::Component2ReferencedMatingFeatureID),
+        ("secondarycapturedeviceid", &tags::SecondaryCaptureDeviceID),
+        ("applicationsetupname", &tags::ApplicationSetupName),
+        ("positionermotion", &tags::PositionerMotion),
+        ("indicationtype", &tags::IndicationType),
+        ("filesetid", &tags::FilesetID),
+        ("overlaybitsforcodeword", &tags::OverlayBitsForCodeWord),
+        ("performedseriessequence", &tags::PerformedSeriesSequence),
+        ("tiptype", &tags::TIPType),
+        ("filesource", &tags::FileSource),
+        ("inputinformationsequence", &tags::InputInformationSequence),
+        ("radiationbeameffectivewedgeangle", &tags::RadiationBeamEffectiveWedgeAngle),
+        ("stationname", &tags::StationName),
+        ("huffmantablesize", &tags::HuffmanTableSize),
+        ("acquisitiontimesynchronized", &tags::AcquisitionTimeSynchronized),
+        ("voilutfunction", &tags::VOILUTFunction),
+        ("rtaccessoryholderdefinitionsequence", &tags::RTAccessoryHolderDefinitionSequence),
+        ("imageboxlargescrolltype", &tags::ImageBoxLargeScrollType),
+        ("performedstationgeographiclocationcodesequence", &tags::PerformedStationGeographicLocationCodeSequence),
+        ("exposuretimeinseconds", &tags::ExposureTimeinSeconds),
+        ("selectorodvalue", &tags::SelectorODValue),
+        ("resourcedescription", &tags::ResourceDescription),
+        ("rectificationtype", &tags::RectificationType),
+        ("displaywindowlabelvector", &tags::DisplayWindowLabelVector),
+        ("devicesequence", &tags::DeviceSequence),
+        ("fontnametype", &tags::FontNameType),
+        ("propertylabel", &tags::PropertyLabel),
+        ("performedstationnamecodesequence", &tags::PerformedStationNameCodeSequence),
+        ("trianglestripsequence", &tags::TriangleStripSequence),
+        ("scanmode", &tags::ScanMode),
+        ("flatkeratometricaxissequence", &tags::FlatKeratometricAxisSequence),
+        ("clinicaltrialtimepointdescription", &tags::ClinicalTrialTimePointDescription),
+        ("dosereferencedescription", &tags::DoseReferenceDescription),
+        ("admissionid", &tags::AdmissionID),
+        ("displaysubsystemid", &tags::DisplaySubsystemID),
+        ("shadowoffsetx", &tags::ShadowOffsetX),
+        ("imageindex", &tags::ImageIndex),
+        ("volumetricpresentationinputnumber", &tags::VolumetricPresentationInputNumber),
+        ("responsibleorganization", &tags::ResponsibleOrganization),
+        ("lateralspreadingdevicesetting", &tags::LateralSpreadingDeviceSetting),
+        ("terminationrelativedensitythreshold", &tags::TerminationRelativeDensityThreshold),
+        ("multicoilelementused", &tags::MultiCoilElementUsed),
+        ("referencedpositionreferenceinstancesequence", &tags::ReferencedPositionReferenceInstanceSequence),
+        ("powerlinefrequency", &tags::PowerlineFrequency),
+        ("alphaluttransferfunction", &tags::AlphaLUTTransferFunction),
+        ("flashfunctionpresent", &tags::FlashFunctionPresent),
+        ("orderenteredby", &tags::OrderEnteredBy),
+        ("floatingpointvalues", &tags::FloatingPointValues),
+        ("displayshadingflag", &tags::DisplayShadingFlag),
+        ("collimatorgridname", &tags::CollimatorgridName),
+        ("coordinatesystemtransformsequence", &tags::CoordinateSystemTransformSequence),
+        ("midslabposition", &tags::MidSlabPosition),
+        ("imagetranslationvector", &tags::ImageTranslationVector),
+        ("lesionnumber", &tags::LesionNumber),
+        ("gpsspeedref", &tags::GPSSpeedRef),
+        ("sourcestartdatetime", &tags::SourceStartDateTime),
+        ("interventiondrugstoptime", &tags::InterventionDrugStopTime),
+        ("isocenterposition", &tags::IsocenterPosition),
+        ("patternoffcolorcielabvalue", &tags::PatternOffColorCIELabValue),
+        ("algorithmname", &tags::AlgorithmName),
+        ("imageframeorigin", &tags::ImageFrameOrigin),
+        ("studyreadtime", &tags::StudyReadTime),
+        ("rtimagedescription", &tags::RTImageDescription),
+        ("wedgeid", &tags::WedgeID),
+        ("rttreatmentsourcecoordinates", &tags::RTTreatmentSourceCoordinates),
+        ("algorithmtype", &tags::AlgorithmType),
+        ("transactionstatuscomment", &tags::TransactionStatusComment),
+        ("softcopyvoilutsequence", &tags::SoftcopyVOILUTSequence),
+        ("compensatorsequence", &tags::CompensatorSequence),
+        ("procedureexpirationdate", &tags::ProcedureExpirationDate),
+        ("surfacemodelscalingfactor", &tags::SurfaceModelScalingFactor),
+        ("additionalscreeningperformed", &tags::AdditionalScreeningPerformed),
+        ("startcardiactriggercountthreshold", &tags::StartCardiacTriggerCountThreshold),
+        ("planeorientationsequence", &tags::PlaneOrientationSequence),
+        ("contrastbolusinjectiondelay", &tags::ContrastBolusInjectionDelay),
+        ("depthdoseparameterssequence", &tags::DepthDoseParametersSequence),
+        ("observationstartdatetime", &tags::ObservationStartDateTime),
+        ("roiname", &tags::ROIName),
+        ("mediadisposition", &tags::MediaDisposition),
+        ("frequencyencodingcodesequence", &tags::FrequencyEncodingCodeSequence),
+        ("stagenumber", &tags::StageNumber),
+        ("numberofbscansperframe", &tags::NumberofBscansPerFrame),
+        ("referencedrtplansequence", &tags::ReferencedRTPlanSequence),
+        ("referencedimagesequence", &tags::ReferencedImageSequence),
+        ("unformattedtextvalue", &tags::UnformattedTextValue),
+        ("frameofreferencetodisplayedcoordinatesystemtransformationmatrix", &tags::FrameofReferencetoDisplayedCoordinateSystemTransformationMatrix),
+        ("numberofalarmobjects", &tags::NumberofAlarmObjects),
+        ("radiationdosemeasurementpointcoordinates", &tags::RadiationDoseMeasurementPointCoordinates),
+        ("tableheadtiltangle", &tags::TableHeadTiltAngle),
+        ("fixationlightazimuthalangletolerance", &tags::FixationLightAzimuthalAngleTolerance),
+        ("codingschemeidentificationsequence", &tags::CodingSchemeIdentificationSequence),
+        ("linearitycorrectiontechnique", &tags::LinearityCorrectionTechnique),
+        ("overlaybitsgrouped", &tags::OverlayBitsGrouped),
+        ("selectorattributekeyword", &tags::SelectorAttributeKeyword),
+        ("positioninglandmarksequence", &tags::PositioningLandmarkSequence),
+        ("requestedseriesdescription", &tags::RequestedSeriesDescription),
+        ("doubleexposurefielddelta_trial", &tags::DoubleExposureFieldDelta_Trial),
+        ("isocenterreferencesystemsequence", &tags::IsocenterReferenceSystemSequence),
+        ("calibrationdatetime", &tags::CalibrationDateTime),
+        ("treatmentrecordcontentorigin", &tags::TreatmentRecordContentOrigin),
+        ("smallestpixelvalueinseries", &tags::SmallestPixelValueinSeries),
+        ("trackpointindexlist", &tags::TrackPointIndexList),
+        ("excludedintervalssequence", &tags::ExcludedIntervalsSequence),
+        ("fovealsensitivity", &tags::FovealSensitivity),
+        ("itineraryidassigningauthority", &tags::ItineraryIDAssigningAuthority),
+        ("diffusionanisotropytype", &tags::DiffusionAnisotropyType),
+        ("calciumscoringmassfactorpatient", &tags::CalciumScoringMassFactorPatient),
+        ("annotationpropertytypecodesequence", &tags::AnnotationPropertyTypeCodeSequence),
+        ("barcodevalue", &tags::BarcodeValue),
+        ("anteriorchamberdepthdefinitioncodesequence", &tags::AnteriorChamberDepthDefinitionCodeSequence),
+        ("ivusgatedrate", &tags::IVUSGatedRate),
+        ("tableofybreakpoints", &tags::TableofYBreakPoints),
+        ("deliveredsecondarymeterset", &tags::DeliveredSecondaryMeterset),
+        ("potentialreasonsforprocedure", &tags::PotentialReasonsforProcedure),
+        ("relatedreferencertimagesequence", &tags::RelatedReferenceRTImageSequence),
+        ("dbdt", &tags::DBdt),
+        ("imageid", &tags::ImageID),
+        ("conceptualvolumecategorycodesequence", &tags::ConceptualVolumeCategoryCodeSequence),
+        ("patientposition", &tags::PatientPosition),
+        ("procedurelastmodifieddate", &tags::ProcedureLastModifiedDate),
+        ("headfixationangle", &tags::HeadFixationAngle),
+        ("transducergeometrycodesequence", &tags::TransducerGeometryCodeSequence),
+        ("widefieldophthalmicphotographyqualityratingsequence", &tags::WideFieldOphthalmicPhotographyQualityRatingSequence),
+        ("hangingprotocoluseridentificationcodesequence", &tags::HangingProtocolUserIdentificationCodeSequence),
+        ("messageidbeingrespondedto", &tags::MessageIDBeingRespondedTo),
+        ("refractivepower", &tags::RefractivePower),
         ("treatmentsite", &tags::TreatmentSite),
-        ("contrastbolusagentdetected", &tags::ContrastBolusAgentDetected),
-        ("sourceofophthalmicaxiallengthcodesequence", &tags::SourceofOphthalmicAxialLengthCodeSequence),
-        ("processingfunction", &tags::ProcessingFunction),
-        ("retrieveuri", &tags::RetrieveURI),
-        ("calibrationdatasequence", &tags::CalibrationDataSequence),
-        ("treatmentterminationstatus", &tags::TreatmentTerminationStatus),
-        ("arterialspinlabelingcontrast", &tags::ArterialSpinLabelingContrast),
-        ("rtphysicianintentindex", &tags::RTPhysicianIntentIndex),
-        ("referencepixely0", &tags::ReferencePixelY0),
-        ("conversionsourceattributessequence", &tags::ConversionSourceAttributesSequence),
-        ("coordinatesystemaxisdescription", &tags::CoordinateSystemAxisDescription),
+        ("parametervaluenumber", &tags::ParameterValueNumber),
+        ("findingsflag_trial", &tags::FindingsFlag_Trial),
+        ("condenserlenspower", &tags::CondenserLensPower),
+        ("deviceid", &tags::DeviceID),
+        ("rtphysicianintentpredecessorsequence", &tags::RTPhysicianIntentPredecessorSequence),
+        ("gpslongitude", &tags::GPSLongitude),
+        ("generalizeddefectcorrectedsensitivitydeviationflag", &tags::GeneralizedDefectCorrectedSensitivityDeviationFlag),
+        ("appliedmasksubtractionflag", &tags::AppliedMaskSubtractionFlag),
+        ("contributingequipmentsequence", &tags::ContributingEquipmentSequence),
+        ("radiationsourceaxisdistance", &tags::RadiationSourceAxisDistance),
+        ("measuredcharacteristics", &tags::MeasuredCharacteristics),
         ("referencedrtprescriptionsequence", &tags::ReferencedRTPrescriptionSequence),
-        ("numberofseriesrelatedinstances", &tags::NumberofSeriesRelatedInstances),
-        ("percentsampling", &tags::PercentSampling),
-        ("inversionrecovery", &tags::InversionRecovery),
-        ("aslboluscutoffflag", &tags::ASLBolusCutoffFlag),
-        ("dosemodename", &tags::DoseModeName),
-        ("acousticcouplingmediumsoundspeed", &tags::AcousticCouplingMediumSoundSpeed),
-        ("ophthalmicaxiallengthacquisitionmethodcodesequence", &tags::OphthalmicAxialLengthAcquisitionMethodCodeSequence),
-        ("fieldofviewshape", &tags::FieldofViewShape),
-        ("authorobserversequence", &tags::AuthorObserverSequence),
-        ("receivecoilmanufacturername", &tags::ReceiveCoilManufacturerName),
-        ("rtradiationsequence", &tags::RTRadiationSequence),
-        ("detectormode", &tags::DetectorMode),
-        ("ultrasoundselectedophthalmicaxiallengthsequence", &tags::UltrasoundSelectedOphthalmicAxialLengthSequence),
-        ("rtvflowrtpsamplingrate", &tags::RTVFlowRTPSamplingRate),
-        ("chemicalshiftminimumintegrationlimitinppm", &tags::ChemicalShiftMinimumIntegrationLimitinppm),
-        ("cornealeccentricityindex", &tags::CornealEccentricityIndex),
-        ("gpsaltitude", &tags::GPSAltitude),
-        ("illuminationtypecodesequence", &tags::IlluminationTypeCodeSequence),
-        ("patientphysiologicalstatecodesequence", &tags::PatientPhysiologicalStateCodeSequence),
-        ("rtimagename", &tags::RTImageName),
-        ("encapsulateddocumentlength", &tags::EncapsulatedDocumentLength),
-        ("sequencename", &tags::SequenceName),
-        ("gpsdestdistanceref", &tags::GPSDestDistanceRef),
-        ("selectorstvalue", &tags::SelectorSTValue),
-        ("mracquisitionphaseencodingstepsinplane", &tags::MRAcquisitionPhaseEncodingStepsinplane),
-        ("threatroiextents", &tags::ThreatROIExtents),
-        ("assigningagencyordepartmentcodesequence", &tags::AssigningAgencyorDepartmentCodeSequence),
-        ("primarypositionerscanstartangle", &tags::PrimaryPositionerScanStartAngle),
-        ("compoundgraphicsequence", &tags::CompoundGraphicSequence),
-        ("filmsizeid", &tags::FilmSizeID),
-        ("xrayfilterdetailssequence", &tags::XRayFilterDetailsSequence),
-        ("mappingresourceidentificationsequence", &tags::MappingResourceIdentificationSequence),
-        ("totalwedgetraywaterequivalentthickness", &tags::TotalWedgeTrayWaterEquivalentThickness),
-        ("routesegmentstarttime", &tags::RouteSegmentStartTime),
-        ("findingsgroupuid_trial", &tags::FindingsGroupUID_Trial),
-        ("tag_2dmatingpoint", &tags::Tag_2DMatingPoint),
-        ("rtradiationphysicalandgeometriccontentdetailflag", &tags::RTRadiationPhysicalandGeometricContentDetailFlag),
-        ("expectedcompletiondatetime", &tags::ExpectedCompletionDateTime),
-        ("relationshiptype", &tags::RelationshipType),
-        ("sourceapplicatortype", &tags::SourceApplicatorType),
-        ("referencedcalculateddosereferencenumber", &tags::ReferencedCalculatedDoseReferenceNumber),
-        ("colorspace", &tags::ColorSpace),
-        ("manufacturersmodelname", &tags::ManufacturersModelName),
-        ("rttreatmentphaseintervalsequence", &tags::RTTreatmentPhaseIntervalSequence),
-        ("printerresolutionid", &tags::PrinterResolutionID),
-        ("tablelongitudinalincrement", &tags::TableLongitudinalIncrement),
-        ("templatename", &tags::TemplateName),
-        ("acquisitionsinseries", &tags::AcquisitionsinSeries),
-        ("largegreenpalettecolorlookuptabledata", &tags::LargeGreenPaletteColorLookupTableData),
-        ("filterhighfrequencycharacteristicssequence", &tags::FilterHighFrequencyCharacteristicsSequence),
-        ("treatmenttoleranceviolationdatetime", &tags::TreatmentToleranceViolationDateTime),
-        ("projectioneponymousnamecodesequence", &tags::ProjectionEponymousNameCodeSequence),
-        ("sourceofrefractivemeasurementscodesequence", &tags::SourceofRefractiveMeasurementsCodeSequence),
-        ("date", &tags::Date),
-        ("breedregistrycodesequence", &tags::BreedRegistryCodeSequence),
-        ("numberofgraphicpoints", &tags::NumberofGraphicPoints),
-        ("pixelshiftsequence", &tags::PixelShiftSequence),
-        ("typeofsynchronization", &tags::TypeofSynchronization),
-        ("currentfractionnumber", &tags::CurrentFractionNumber),
-        ("devicepositionparametersequence", &tags::DevicePositionParameterSequence),
-        ("temporalresolution", &tags::TemporalResolution),
-        ("physicaldeltax", &tags::PhysicalDeltaX),
-        ("therapeuticrolecategorycodesequence", &tags::TherapeuticRoleCategoryCodeSequence),
-        ("scheduledprocedurestepstatus", &tags::ScheduledProcedureStepStatus),
-        ("filenameincontainer", &tags::FilenameinContainer),
-        ("units", &tags::Units),
-        ("fileaccessuri", &tags::FileAccessURI),
-        ("targetminimumluminance", &tags::TargetMinimumLuminance),
-        ("visualacuitymodifiers", &tags::VisualAcuityModifiers),
-        ("acquiredsubtractionmaskflag", &tags::AcquiredSubtractionMaskFlag),
-        ("shutterpresentationvalue", &tags::ShutterPresentationValue),
-        ("probeorientationangle", &tags::ProbeOrientationAngle),
-        ("bluepalettecolorlookuptabledata", &tags::BluePaletteColorLookupTableData),
+        ("tagspacingfirstdimension", &tags::TagSpacingFirstDimension),
+        ("volumelocalizationtechnique", &tags::VolumeLocalizationTechnique),
+        ("radiationmassnumber", &tags::RadiationMassNumber),
+        ("pixelspacingcalibrationtype", &tags::PixelSpacingCalibrationType),
+        ("linepattern", &tags::LinePattern),
+        ("totalblocktraywaterequivalentthickness", &tags::TotalBlockTrayWaterEquivalentThickness),
+        ("planesinacquisition", &tags::PlanesinAcquisition),
+        ("admittingdiagnosescodesequence", &tags::AdmittingDiagnosesCodeSequence),
+        ("pseudocolorpaletteinstancereferencesequence", &tags::PseudoColorPaletteInstanceReferenceSequence),
+        ("selectorflvalue", &tags::SelectorFLValue),
+        ("ophthalmicthicknessmappingnormalssequence", &tags::OphthalmicThicknessMappingNormalsSequence),
+        ("compensatormountingposition", &tags::CompensatorMountingPosition),
+        ("totalprocessingtime", &tags::TotalProcessingTime),
+        ("privategroupreference", &tags::PrivateGroupReference),
+        ("iolpowersequence", &tags::IOLPowerSequence),
+        ("currentpatientlocation", &tags::CurrentPatientLocation),
+        ("axisofrotation", &tags::AxisofRotation),
+        ("decayfactor", &tags::DecayFactor),
+        ("selectorfdvalue", &tags::SelectorFDValue),
+        ("acousticcouplingmediumcodesequence", &tags::AcousticCouplingMediumCodeSequence),
+        ("scanstoppositionsequence", &tags::ScanStopPositionSequence),
+        ("ophthalmicaxiallengthdatasourcecodesequence", &tags::OphthalmicAxialLengthDataSourceCodeSequence),
+        ("implanttemplategroupvariationdimensionrank", &tags::ImplantTemplateGroupVariationDimensionRank),
+        ("scheduledprocedurestepstarttime", &tags::ScheduledProcedureStepStartTime),
+        ("studyinstanceuid", &tags::StudyInstanceUID),
+        ("routesegmentendlocationid", &tags::RouteSegmentEndLocationID),
+        ("inventoriedstudiessequence", &tags::InventoriedStudiesSequence),
+        ("outlineshapetype", &tags::OutlineShapeType),
+        ("planes", &tags::Planes),
+        ("matrixsequence", &tags::MatrixSequence),
+        ("flowrtpsamplingrate", &tags::FlowRTPSamplingRate),
+        ("expirydate", &tags::ExpiryDate),
+        ("timeslotnumber", &tags::TimeSlotNumber),
+        ("continuationstarttotalreferenceairkerma", &tags::ContinuationStartTotalReferenceAirKerma),
+        ("modalityluttype", &tags::ModalityLUTType),
+        ("presentationsizemode", &tags::PresentationSizeMode),
+        ("applicableframerange", &tags::ApplicableFrameRange),
+        ("metersetratedelivered", &tags::MetersetRateDelivered),
+        ("filterlookuptablesequence", &tags::FilterLookupTableSequence),
+        ("udisequence", &tags::UDISequence),
+        ("optotypepresentation", &tags::OptotypePresentation),
+        ("sourcetopatientsurfacedistance", &tags::SourcetoPatientSurfaceDistance),
+        ("windowwidth", &tags::WindowWidth),
+        ("acrossscanspatialresolution", &tags::AcrossscanSpatialResolution),
+        ("transducerfrequency", &tags::TransducerFrequency),
+        ("numberoftemporalpositions", &tags::NumberofTemporalPositions),
+        ("verifyingobserveridentificationcodesequence", &tags::VerifyingObserverIdentificationCodeSequence),
+        ("recommendedpresentationopacity", &tags::RecommendedPresentationOpacity),
+        ("triggervector", &tags::TriggerVector),
+        ("commandgrouplength", &tags::CommandGroupLength),
         ("displaysubsystemqaresultssequence", &tags::DisplaySubsystemQAResultsSequence),
-        ("channeldisplaysequence", &tags::ChannelDisplaySequence),
-        ("modifiedimageid", &tags::ModifiedImageID),
-        ("extendedcodevalue", &tags::ExtendedCodeValue),
-        ("referencedrawdatasequence", &tags::ReferencedRawDataSequence),
-        ("nominalinterval", &tags::NominalInterval),
-        ("masksubpixelshift", &tags::MaskSubpixelShift),
-        ("waveformchannelnumber", &tags::WaveformChannelNumber),
-        ("referencedradiationdoseidentificationindex", &tags::ReferencedRadiationDoseIdentificationIndex),
-        ("iterativereconstructionmethod", &tags::IterativeReconstructionMethod),
-        ("rtbeamlimitingdeviceproximaldistance", &tags::RTBeamLimitingDeviceProximalDistance),
-        ("bluepalettecolorlookuptabledescriptor", &tags::BluePaletteColorLookupTableDescriptor),
+        ("sharedfunctionalgroupssequence", &tags::SharedFunctionalGroupsSequence),
+        ("assertioncodesequence", &tags::AssertionCodeSequence),
+        ("correctedparametersequence", &tags::CorrectedParameterSequence),
+        ("anchorpoint", &tags::AnchorPoint),
+        ("transducertechnologysequence", &tags::TransducerTechnologySequence),
+        ("referenceddirectsegmentinstancesequence", &tags::ReferencedDirectSegmentInstanceSequence),
+        ("acquisitionuid", &tags::AcquisitionUID),
+        ("treatmentmachinename", &tags::TreatmentMachineName),
+        ("alphapalettecolorlookuptabledescriptor", &tags::AlphaPaletteColorLookupTableDescriptor),
+        ("visualfieldglobalresultsindexsequence", &tags::VisualFieldGlobalResultsIndexSequence),
+        ("visualevaluationmethodcodesequence", &tags::VisualEvaluationMethodCodeSequence),
+        ("scheduledprotocolcodesequence", &tags::ScheduledProtocolCodeSequence),
+        ("objectdirectorybinaryidentifier_trial", &tags::ObjectDirectoryBinaryIdentifier_Trial),
+        ("mracquisitiontype", &tags::MRAcquisitionType),
+        ("rtvflowidentifier", &tags::RTVFlowIdentifier),
+        ("numberoffractions", &tags::NumberofFractions),
+        ("inventoryinstancedescription", &tags::InventoryInstanceDescription),
+        ("timedistributionprotocol", &tags::TimeDistributionProtocol),
+        ("annotationclipping", &tags::AnnotationClipping),
+        ("simulatedkeratometriccylindersequence", &tags::SimulatedKeratometricCylinderSequence),
+        ("spectroscopyacquisitionoutofplanephasesteps", &tags::SpectroscopyAcquisitionOutofplanePhaseSteps),
+        ("accessionnumber", &tags::AccessionNumber),
+        ("referencedobservationuid_trial", &tags::ReferencedObservationUID_Trial),
+        ("conceptualvolumecombinationdescription", &tags::ConceptualVolumeCombinationDescription),
+        ("ionwedgepositionsequence", &tags::IonWedgePositionSequence),
+        ("numberofphases", &tags::NumberofPhases),
+        ("numberoftotalobjects", &tags::NumberofTotalObjects),
+        ("materialnotes", &tags::MaterialNotes),
+        ("waveformsampleinterpretation", &tags::WaveformSampleInterpretation),
+        ("procedurestepprogressdescription", &tags::ProcedureStepProgressDescription),
+        ("axiallengthoftheeye", &tags::AxialLengthoftheEye),
+        ("dactimepoints", &tags::DACTimePoints),
+        ("rtimageframecontextsequence", &tags::RTImageFrameContextSequence),
+        ("radiationdosesourcetoskindistance", &tags::RadiationDoseSourcetoSkinDistance),
+        ("requestingphysician", &tags::RequestingPhysician),
+        ("patienttreatmentpreparationprocedurecodesequence", &tags::PatientTreatmentPreparationProcedureCodeSequence),
+        ("valuetype", &tags::ValueType),
+        ("overlaycodelabel", &tags::OverlayCodeLabel),
+        ("placerordernumberimagingservicerequest_retired", &tags::PlacerOrderNumberImagingServiceRequest_Retired),
+        ("patientorientationcodesequence", &tags::PatientOrientationCodeSequence),
+        ("tomotype", &tags::TomoType),
+        ("sourcesequence", &tags::SourceSequence),
+        ("treatmentsites", &tags::TreatmentSites),
+        ("memoryallocation", &tags::MemoryAllocation),
+        ("clinicaltrialtimepointid", &tags::ClinicalTrialTimePointID),
+        ("highenergydetectors", &tags::HighEnergyDetectors),
+        ("scanspotsizesdelivered", &tags::ScanSpotSizesDelivered),
+        ("intervalsrejected", &tags::IntervalsRejected),
+        ("radiopharmaceuticalstopdatetime", &tags::RadiopharmaceuticalStopDateTime),
+        ("framevoilutsequence", &tags::FrameVOILUTSequence),
+        ("identifiertypecode", &tags::IdentifierTypeCode),
+        ("numberofrtbeamlimitingdevices", &tags::NumberofRTBeamLimitingDevices),
+        ("referencedprintjobsequence", &tags::ReferencedPrintJobSequence),
+        ("containercomponentlength", &tags::ContainerComponentLength),
+        ("fillerordernumberprocedure", &tags::FillerOrderNumberProcedure),
+        ("numberoftablebreakpoints", &tags::NumberofTableBreakPoints),
+        ("countlossnormalizationcorrected", &tags::CountLossNormalizationCorrected),
+        ("imagesetselectorcategory", &tags::ImageSetSelectorCategory),
+        ("interpretationtext", &tags::InterpretationText),
+        ("materialgrade", &tags::MaterialGrade),
+        ("radiopharmaceuticalinformationsequence", &tags::RadiopharmaceuticalInformationSequence),
+        ("tableverticalincrement", &tags::TableVerticalIncrement),
+        ("filterthicknessminimum", &tags::FilterThicknessMinimum),
+        ("sourcestrengthunits", &tags::SourceStrengthUnits),
+        ("histogramdata", &tags::HistogramData),
+        ("mracquisitionfrequencyencodingsteps", &tags::MRAcquisitionFrequencyEncodingSteps),
+        ("doubleexposureflag", &tags::DoubleExposureFlag),
+        ("potentialrequestedprocedurecodesequence", &tags::PotentialRequestedProcedureCodeSequence),
+        ("numberofwaveformsamples", &tags::NumberofWaveformSamples),
+        ("channelbaseline", &tags::ChannelBaseline),
+        ("datainformationsequence", &tags::DataInformationSequence),
+        ("subjectdistancerange", &tags::SubjectDistanceRange),
+        ("pupilsize", &tags::PupilSize),
+        ("decompositiondescription", &tags::DecompositionDescription),
+        ("ophthalmicimageorientation", &tags::OphthalmicImageOrientation),
+        ("displaysetpresentationgroup", &tags::DisplaySetPresentationGroup),
+        ("effectiveduration", &tags::EffectiveDuration),
+        ("addintermediatesequence", &tags::AddIntermediateSequence),
+        ("recordedblocksequence", &tags::RecordedBlockSequence),
+        ("pixelvaluemappingtocodedconceptsequence", &tags::PixelValueMappingtoCodedConceptSequence),
+        ("potentialdiagnostictasks", &tags::PotentialDiagnosticTasks),
+        ("acquiredsoundpathlength", &tags::AcquiredSoundpathLength),
+        ("performedstationclasscodesequence", &tags::PerformedStationClassCodeSequence),
+        ("issuedateofimagingservicerequest", &tags::IssueDateofImagingServiceRequest),
+        ("directoryrecordsequence", &tags::DirectoryRecordSequence),
+        ("perimetervalue", &tags::PerimeterValue),
+        ("stopcumulativemeterset", &tags::StopCumulativeMeterset),
+        ("ultrasoundophthalmicaxiallengthmeasurementssequence", &tags::UltrasoundOphthalmicAxialLengthMeasurementsSequence),
+        ("brachytasksequence", &tags::BrachyTaskSequence),
+        ("histogramexplanation", &tags::HistogramExplanation),
+        ("displaysetscrollinggroup", &tags::DisplaySetScrollingGroup),
+        ("sensitivitytype", &tags::SensitivityType),
+        ("registrationtolocalizersequence", &tags::RegistrationtoLocalizerSequence),
+        ("contrastbolusagentphase", &tags::ContrastBolusAgentPhase),
+        ("referencedbrachyapplicationsetupsequence", &tags::ReferencedBrachyApplicationSetupSequence),
+        ("averagebeamdosepointequivalentdepth", &tags::AverageBeamDosePointEquivalentDepth),
+        ("ophthalmicaxiallengthmeasurementssegmentnamecodesequence", &tags::OphthalmicAxialLengthMeasurementsSegmentNameCodeSequence),
+        ("parallelrtbeamdelimiteropeningmode", &tags::ParallelRTBeamDelimiterOpeningMode),
+        ("beamtasksequence", &tags::BeamTaskSequence),
+        ("surfaceprocessingratio", &tags::SurfaceProcessingRatio),
+        ("numberofwedgepositions", &tags::NumberofWedgePositions),
+        ("radiationtype", &tags::RadiationType),
+        ("devicevolume", &tags::DeviceVolume),
+        ("referencedchannelnumber", &tags::ReferencedChannelNumber),
+        ("anteriorchamberdepth", &tags::AnteriorChamberDepth),
+        ("scanarctype", &tags::ScanArcType),
+        ("colorimageprintingflag", &tags::ColorImagePrintingFlag),
+        ("channeldefinitionsequence", &tags::ChannelDefinitionSequence),
+        ("segmentedkspacetraversal", &tags::SegmentedkSpaceTraversal),
+        ("blockedpixels", &tags::BlockedPixels),
+        ("alinerate", &tags::AlineRate),
+        ("primarydosevalueindicator", &tags::PrimaryDoseValueIndicator),
+        ("numberofparallelrtbeamdelimiters", &tags::NumberofParallelRTBeamDelimiters),
+        ("totalnumberofexposures", &tags::TotalNumberofExposures),
+        ("positionacquisitiontemplateid", &tags::PositionAcquisitionTemplateID),
+        ("radiationbeamwedgeangle", &tags::RadiationBeamWedgeAngle),
+        ("numberoffailedsuboperations", &tags::NumberofFailedSuboperations),
+        ("requestedprocedurecodesequence", &tags::RequestedProcedureCodeSequence),
+        ("brachytreatmenttype", &tags::BrachyTreatmentType),
+        ("numberofacquisitiondevices", &tags::NumberofAcquisitionDevices),
+        ("gpsdestdistance", &tags::GPSDestDistance),
+        ("channellength", &tags::ChannelLength),
+        ("spatiallocationspreserved", &tags::SpatialLocationsPreserved),
+        ("radionuclidepositronfraction", &tags::RadionuclidePositronFraction),
+        ("averagebeamdosepointsourcetoexternalcontourdistance", &tags::AverageBeamDosePointSourcetoExternalContourDistance),
+        ("hpglpenlabel", &tags::HPGLPenLabel),
+        ("ctdivol", &tags::CTDIvol),
+        ("irradiationeventidentificationsequence", &tags::IrradiationEventIdentificationSequence),
+        ("entrancedosederivation", &tags::EntranceDoseDerivation),
+        ("intendednumberoffractions", &tags::IntendedNumberofFractions),
+        ("acousticcouplingmediumtemperature", &tags::AcousticCouplingMediumTemperature),
+        ("maximumcoordinatevalue", &tags::MaximumCoordinateValue),
+        ("maximumpointdistance", &tags::MaximumPointDistance),
         ("scanpatterntypecodesequence", &tags::ScanPatternTypeCodeSequence),
-        ("radiationfluencemodifiercodesequence", &tags::RadiationFluenceModifierCodeSequence),
-        ("nameofphysiciansreadingstudy", &tags::NameofPhysiciansReadingStudy),
-        ("aslcrusherflowlimit", &tags::ASLCrusherFlowLimit),
-        ("tablelateralincrement", &tags::TableLateralIncrement),
-        ("notificationfrommanufacturersequence", &tags::NotificationFromManufacturerSequence),
-        ("positionerisocentersecondaryangle", &tags::PositionerIsocenterSecondaryAngle),
-        ("lensmake", &tags::LensMake),
+        ("hardcopycreationdeviceid", &tags::HardcopyCreationDeviceID),
+        ("ophthalmicaxialmeasurementsrighteyesequence", &tags::OphthalmicAxialMeasurementsRightEyeSequence),
+        ("bitsallocated", &tags::BitsAllocated),
+        ("imagegeometrytype", &tags::ImageGeometryType),
+        ("geometricmaximumdistortion", &tags::GeometricMaximumDistortion),
+        ("transmitcoilname", &tags::TransmitCoilName),
+        ("functionalgrouppointer", &tags::FunctionalGroupPointer),
+        ("treatmenttime", &tags::TreatmentTime),
+        ("overlaycomments", &tags::OverlayComments),
+        ("dosecalibrationfactor", &tags::DoseCalibrationFactor),
+        ("xdsretrievalsequence", &tags::XDSRetrievalSequence),
+        ("qualitycontrolsubjecttypecodesequence", &tags::QualityControlSubjectTypeCodeSequence),
+        ("enhancedpalettecolorlookuptablesequence", &tags::EnhancedPaletteColorLookupTableSequence),
+        ("channelimpedancesequence", &tags::ChannelImpedanceSequence),
+        ("observertype", &tags::ObserverType),
+        ("performedprocedurestependtime", &tags::PerformedProcedureStepEndTime),
+        ("deviationindex", &tags::DeviationIndex),
+        ("fluoroscopypersistenceflag", &tags::FluoroscopyPersistenceFlag),
+        ("histogramnumberofbins", &tags::HistogramNumberofBins),
+        ("derivationimplantassemblytemplatesequence", &tags::DerivationImplantAssemblyTemplateSequence),
+        ("issuerofpatientidqualifierssequence", &tags::IssuerofPatientIDQualifiersSequence),
+        ("interlockdescription", &tags::InterlockDescription),
+        ("nominalcardiactriggerdelaytime", &tags::NominalCardiacTriggerDelayTime),
+        ("referencedsurfacenumber", &tags::ReferencedSurfaceNumber),
+        ("gantrymotioncorrected", &tags::GantryMotionCorrected),
+        ("sopclassesinstudy", &tags::SOPClassesinStudy),
+        ("gpsdifferential", &tags::GPSDifferential),
+        ("scheduledstationnamecodesequence", &tags::ScheduledStationNameCodeSequence),
+        ("scheduledprocessingparameterssequence", &tags::ScheduledProcessingParametersSequence),
+        ("gatesettingssequence", &tags::GateSettingsSequence),
+        ("synchronizationtrigger", &tags::SynchronizationTrigger),
+        ("supportedimagedisplayformatssequence", &tags::SupportedImageDisplayFormatsSequence),
+        ("acquisitionsubtaskindex", &tags::AcquisitionSubtaskIndex),
+        ("sourceposition", &tags::SourcePosition),
+        ("overlaydescriptorred", &tags::OverlayDescriptorRed),
+        ("radiopharmaceuticalstoptime", &tags::RadiopharmaceuticalStopTime),
+        ("histogrambinwidth", &tags::HistogramBinWidth),
+        ("sourcenumber", &tags::SourceNumber),
+        ("laterality", &tags::Laterality),
+        ("inventorycompletionstatus", &tags::InventoryCompletionStatus),
+        ("ioncontrolpointsequence", &tags::IonControlPointSequence),
+        ("applicationsetuptype", &tags::ApplicationSetupType),
+        ("pointcoordinatesdata", &tags::PointCoordinatesData),
+        ("component1referencedid", &tags::Component1ReferencedID),
+        ("referencedrtphysicianintentsequence", &tags::ReferencedRTPhysicianIntentSequence),
+        ("beamdosepointssd", &tags::BeamDosePointSSD),
+        ("timerange", &tags::TimeRange),
+        ("lateralspreadingdevicewaterequivalentthickness", &tags::LateralSpreadingDeviceWaterEquivalentThickness),
+        ("cadfileformat", &tags::CADFileFormat),
+        ("observationtime_trial", &tags::ObservationTime_Trial),
+        ("cornealaxis", &tags::CornealAxis),
+        ("secondaryinspectionmethodsequence", &tags::SecondaryInspectionMethodSequence),
+        ("reasonfortherequestedprocedure", &tags::ReasonfortheRequestedProcedure),
+        ("referencedrawdatasequence", &tags::ReferencedRawDataSequence),
+        ("linestylesequence", &tags::LineStyleSequence),
+        ("shorttermfluctuationprobabilitycalculated", &tags::ShortTermFluctuationProbabilityCalculated),
+        ("imageboxlayouttype", &tags::ImageBoxLayoutType),
+        ("acquisitionprotocolelementspecificationsequence", &tags::AcquisitionProtocolElementSpecificationSequence),
+        ("performedprocedurestepstartdate", &tags::PerformedProcedureStepStartDate),
+        ("expirationdatetime", &tags::ExpirationDateTime),
+        ("transmittransducersettingssequence", &tags::TransmitTransducerSettingsSequence),
+        ("sourcetobeamlimitingdevicedistance", &tags::SourcetoBeamLimitingDeviceDistance),
+        ("sendingpresentationaddress", &tags::SendingPresentationAddress),
+        ("dynamicrange", &tags::DynamicRange),
+        ("displaysetpresentationgroupdescription", &tags::DisplaySetPresentationGroupDescription),
+        ("imagepresentationcomments", &tags::ImagePresentationComments),
+        ("numberofpatientsupportdevices", &tags::NumberofPatientSupportDevices),
+        ("measurementprecisiondescription_trial", &tags::MeasurementPrecisionDescription_Trial),
+        ("currenttreatmentstatus", &tags::CurrentTreatmentStatus),
+        ("maccalculationtransfersyntaxuid", &tags::MACCalculationTransferSyntaxUID),
+        ("interventionstatus", &tags::InterventionStatus),
+        ("patientadditionalposition", &tags::PatientAdditionalPosition),
+        ("sourceaxisdistance", &tags::SourceAxisDistance),
+        ("presentationanimationstyle", &tags::PresentationAnimationStyle),
+        ("assessmentrequestersequence", &tags::AssessmentRequesterSequence),
+        ("encryptedcontenttransfersyntaxuid", &tags::EncryptedContentTransferSyntaxUID),
+        ("brachyaccessorydevicetype", &tags::BrachyAccessoryDeviceType),
+        ("rangeshifterwaterequivalentthickness", &tags::RangeShifterWaterEquivalentThickness),
+        ("dosesummationtype", &tags::DoseSummationType),
+        ("imagingsourcepositionsequence", &tags::ImagingSourcePositionSequence),
+        ("selectorowvalue", &tags::SelectorOWValue),
+        ("removedfromoperationaluse", &tags::RemovedfromOperationalUse),
+        ("channelshieldname", &tags::ChannelShieldName),
+        ("longprimitivepointindexlist", &tags::LongPrimitivePointIndexList),
+        ("presentationstatecompositorcomponentsequence", &tags::PresentationStateCompositorComponentSequence),
+        ("centerofcircularoutline", &tags::CenterofCircularOutline),
+        ("dosemeasurementdevicecodesequence", &tags::DoseMeasurementDeviceCodeSequence),
+        ("gatedinformationsequence", &tags::GatedInformationSequence),
+        ("atdassessmentflag", &tags::ATDAssessmentFlag),
+        ("dosimetricobjectiveuid", &tags::DosimetricObjectiveUID),
+        ("positionacquisitiontemplateidentificationsequence", &tags::PositionAcquisitionTemplateIdentificationSequence),
+        ("fieldofvieworigin", &tags::FieldofViewOrigin),
+        ("totalpixelmatrixoriginsequence", &tags::TotalPixelMatrixOriginSequence),
+        ("magnetizationtransfer", &tags::MagnetizationTransfer),
+        ("roboticpathnodesetcodesequence", &tags::RoboticPathNodeSetCodeSequence),
+        ("generalaccessorytype", &tags::GeneralAccessoryType),
+        ("datapathid", &tags::DataPathID),
+        ("devicesettingdescription", &tags::DeviceSettingDescription),
+        ("luminanceresultsequence", &tags::LuminanceResultSequence),
+        ("channelmode", &tags::ChannelMode),
+        ("specimenshortdescription", &tags::SpecimenShortDescription),
+        ("minimumkeratometricsequence", &tags::MinimumKeratometricSequence),
+        ("audiotype", &tags::AudioType),
+        ("generalmatchingsequence", &tags::GeneralMatchingSequence),
+        ("whitepointflag", &tags::WhitePointFlag),
+        ("calculateddosereferencedosevalue", &tags::CalculatedDoseReferenceDoseValue),
+        ("offsetofthelastdirectoryrecordoftherootdirectoryentity", &tags::OffsetoftheLastDirectoryRecordoftheRootDirectoryEntity),
+        ("groupofpatientsidentificationsequence", &tags::GroupofPatientsIdentificationSequence),
+        ("rttreatmentintenttype", &tags::RTTreatmentIntentType),
+        ("offsetdistance", &tags::OffsetDistance),
+        ("seriestime", &tags::SeriesTime),
+        ("exposurecontrolsensingregionupperhorizontaledge", &tags::ExposureControlSensingRegionUpperHorizontalEdge),
+        ("relationshiptypecodesequence_trial", &tags::RelationshipTypeCodeSequence_Trial),
+        ("ophthalmicthicknessmaptypecodesequence", &tags::OphthalmicThicknessMapTypeCodeSequence),
+        ("coordinatesystemaxiscodesequence", &tags::CoordinateSystemAxisCodeSequence),
+        ("clinicaltrialsubjectid", &tags::ClinicalTrialSubjectID),
+        ("rangeshifternumber", &tags::RangeShifterNumber),
+        ("treatmenttoleranceviolationsequence", &tags::TreatmentToleranceViolationSequence),
+        ("performedprocessingparameterssequence", &tags::PerformedProcessingParametersSequence),
+        ("performedprocedurestepstatus", &tags::PerformedProcedureStepStatus),
+        ("eventelapsedtimes", &tags::EventElapsedTimes),
+        ("relativeurireferencewithinencapsulateddocument", &tags::RelativeURIReferenceWithinEncapsulatedDocument),
+        ("homecommunityid", &tags::HomeCommunityID),
+        ("filterbeampathlengthmaximum", &tags::FilterBeamPathLengthMaximum),
+        ("intravascularlongitudinaldistance", &tags::IntravascularLongitudinalDistance),
+        ("identifyingcomments", &tags::IdentifyingComments),
+        ("instanceavailability", &tags::InstanceAvailability),
+        ("startrespiratorytriggercountthreshold", &tags::StartRespiratoryTriggerCountThreshold),
+        ("correctedlocalizeddeviationfromnormalcalculated", &tags::CorrectedLocalizedDeviationFromNormalCalculated),
+        ("startmeterset", &tags::StartMeterset),
+        ("annotationgroupgenerationtype", &tags::AnnotationGroupGenerationType),
+        ("instructionperformedflag", &tags::InstructionPerformedFlag),
+        ("rectifiersmoothing", &tags::RectifierSmoothing),
+        ("phasecontrast", &tags::PhaseContrast),
+        ("transformedaxisunits", &tags::TransformedAxisUnits),
+        ("windowcenter", &tags::WindowCenter),
+        ("applicatorapertureshape", &tags::ApplicatorApertureShape),
+        ("requestedmediaapplicationprofile", &tags::RequestedMediaApplicationProfile),
+        ("sourcewaveformsequence", &tags::SourceWaveformSequence),
+        ("transformlabel", &tags::TransformLabel),
+        ("requestedstatusinterval", &tags::RequestedStatusInterval),
+        ("widefieldophthalmicphotographyqualitythresholdsequence", &tags::WideFieldOphthalmicPhotographyQualityThresholdSequence),
+        ("implanttemplateversion", &tags::ImplantTemplateVersion),
+        ("rtimageframegeneralcontentsequence", &tags::RTImageFrameGeneralContentSequence),
+        ("conceptualvolumetypemodifiercodesequence", &tags::ConceptualVolumeTypeModifierCodeSequence),
+        ("indexprobabilitysequence", &tags::IndexProbabilitySequence),
+        ("falsenegativesquantity", &tags::FalseNegativesQuantity),
+        ("administrationroutecodesequence", &tags::AdministrationRouteCodeSequence),
+        ("lensescodesequence", &tags::LensesCodeSequence),
+        ("derivationdescription", &tags::DerivationDescription),
+        ("spatialpresaturation", &tags::SpatialPresaturation),
+        ("parallelrtbeamdelimiterleafmountingside", &tags::ParallelRTBeamDelimiterLeafMountingSide),
+        ("minimumsensitivityvalue", &tags::MinimumSensitivityValue),
+        ("acquisitionduration", &tags::AcquisitionDuration),
+        ("identifyingprivateelements", &tags::IdentifyingPrivateElements),
+        ("patientsname", &tags::PatientsName),
+        ("cornealpower", &tags::CornealPower),
+        ("containercomponenttypecodesequence", &tags::ContainerComponentTypeCodeSequence),
+        ("spectralsensitivity", &tags::SpectralSensitivity),
+        ("gpstimestamp", &tags::GPSTimeStamp),
+        ("hardcopydevicemanufacturer", &tags::HardcopyDeviceManufacturer),
+        ("planninglandmarkplanesequence", &tags::PlanningLandmarkPlaneSequence),
+        ("collimatorshapesequence", &tags::CollimatorShapeSequence),
+        ("status", &tags::Status),
+        ("mrmodifiersequence", &tags::MRModifierSequence),
+        ("authorobserversequence", &tags::AuthorObserverSequence),
+        ("rtimageframemvradiationacquisitionsequence", &tags::RTImageFrameMVRadiationAcquisitionSequence),
+        ("cardiacbeatrejectiontechnique", &tags::CardiacBeatRejectionTechnique),
+        ("agecorrectedsensitivitydeviationvalue", &tags::AgeCorrectedSensitivityDeviationValue),
+        ("overlaynumber", &tags::OverlayNumber),
+        ("gpsaltitude", &tags::GPSAltitude),
+        ("eventcodesequence", &tags::EventCodeSequence),
+        ("medicalrecordlocator", &tags::MedicalRecordLocator),
+        ("imagesetssequence", &tags::ImageSetsSequence),
+        ("physiciansofrecord", &tags::PhysiciansofRecord),
+        ("relatedassertionsequence", &tags::RelatedAssertionSequence),
+        ("ivuspullbackstartframenumber", &tags::IVUSPullbackStartFrameNumber),
+        ("rttreatmentterminationreasoncodesequence", &tags::RTTreatmentTerminationReasonCodeSequence),
+        ("compressiondescription", &tags::CompressionDescription),
+        ("observationcategorycodesequence_trial", &tags::ObservationCategoryCodeSequence_Trial),
+        ("pulserequipmentsequence", &tags::PulserEquipmentSequence),
+        ("timeofframegroupsequence", &tags::TimeofFrameGroupSequence),
+        ("rtdevicedistancereferencelocationcodesequence", &tags::RTDeviceDistanceReferenceLocationCodeSequence),
+        ("productlotidentifier", &tags::ProductLotIdentifier),
+        ("transducerbeamsteeringcodesequence", &tags::TransducerBeamSteeringCodeSequence),
+        ("codingschemeexternalid", &tags::CodingSchemeExternalID),
+        ("scanarc", &tags::ScanArc),
+        ("autorefractionrighteyesequence", &tags::AutorefractionRightEyeSequence),
+        ("echopulsesequence", &tags::EchoPulseSequence),
+        ("fnumber", &tags::FNumber),
+        ("printqueueid", &tags::PrintQueueID),
         ("evaluationattempt", &tags::EvaluationAttempt),
-        ("scheduledprocedurestepexpirationdatetime", &tags::ScheduledProcedureStepExpirationDateTime),
-        ("chemicalshiftmaximumintegrationlimitinppm", &tags::ChemicalShiftMaximumIntegrationLimitinppm),
-        ("pixelcomponentorganization", &tags::PixelComponentOrganization),
-        ("ophthalmicthicknessmapqualitythresholdsequence", &tags::OphthalmicThicknessMapQualityThresholdSequence),
-        ("numberoftemporalpositions", &tags::NumberofTemporalPositions),
-        ("radiationdosesourcetoskindistance", &tags::RadiationDoseSourcetoSkinDistance),
-        ("numberoffractionsincluded", &tags::NumberofFractionsIncluded),
-        ("nominalpercentageofcardiacphase", &tags::NominalPercentageofCardiacPhase),
-        ("pregnancystatus", &tags::PregnancyStatus),
-        ("entrancedose", &tags::EntranceDose),
-        ("segmentationfractionaltype", &tags::SegmentationFractionalType),
-        ("aperturevalue", &tags::ApertureValue),
-        ("cornealsize", &tags::CornealSize),
-        ("receiveprobesequence", &tags::ReceiveProbeSequence),
-        ("receiverequipmentsequence", &tags::ReceiverEquipmentSequence),
-        ("reviewername", &tags::ReviewerName),
-        ("directoryrecordtype", &tags::DirectoryRecordType),
-        ("generalpurposescheduledproceduresteppriority", &tags::GeneralPurposeScheduledProcedureStepPriority),
-        ("kvusedingaincalibration", &tags::KVUsedinGainCalibration),
-        ("photonenergy", &tags::PhotonEnergy),
-        ("fluoroscopylastimageholdpersistenceflag", &tags::FluoroscopyLastImageHoldPersistenceFlag),
-        ("moveoriginatormessageid", &tags::MoveOriginatorMessageID),
-        ("volumetricpresentationinputannotationsequence", &tags::VolumetricPresentationInputAnnotationSequence),
-        ("partialviewdescription", &tags::PartialViewDescription),
-        ("gpsdifferential", &tags::GPSDifferential),
-        ("carmphotonelectroncontrolpointsequence", &tags::CArmPhotonElectronControlPointSequence),
-        ("contentdescription", &tags::ContentDescription),
-        ("rangemodulatorgatingstopwaterequivalentthickness", &tags::RangeModulatorGatingStopWaterEquivalentThickness),
-        ("referenceddirectsegmentinstancesequence", &tags::ReferencedDirectSegmentInstanceSequence),
-        ("definitionsourcesequence", &tags::DefinitionSourceSequence),
-        ("condenserlenspower", &tags::CondenserLensPower),
-        ("additionalrtaccessorydevicesequence", &tags::AdditionalRTAccessoryDeviceSequence),
-        ("sourcetowedgetraydistance", &tags::SourcetoWedgeTrayDistance),
-        ("framesecondaryanglevector", &tags::FrameSecondaryAngleVector),
-        ("tabletoplateraladjustedposition", &tags::TableTopLateralAdjustedPosition),
-        ("samplesperpixelused", &tags::SamplesperPixelUsed),
+        ("atdassessmentprobability", &tags::ATDAssessmentProbability),
+        ("flashredeyemode", &tags::FlashRedEyeMode),
+        ("orderplaceridentifiersequence", &tags::OrderPlacerIdentifierSequence),
         ("elementshape", &tags::ElementShape),
-        ("contributingsopinstancesreferencesequence", &tags::ContributingSOPInstancesReferenceSequence),
-        ("radiationdosimeterunitsequence", &tags::RadiationDosimeterUnitSequence),
-        ("petdetectormotiondetailssequence", &tags::PETDetectorMotionDetailsSequence),
-        ("patientpositioninginstructionsequence", &tags::PatientPositioningInstructionSequence),
-        ("acquisitionterminationcondition", &tags::AcquisitionTerminationCondition),
-        ("tomotype", &tags::TomoType),
-        ("leftimagesequence", &tags::LeftImageSequence),
-        ("stimulusresults", &tags::StimulusResults),
-        ("calibrationnotes", &tags::CalibrationNotes),
-        ("trackstatisticssequence", &tags::TrackStatisticsSequence),
-        ("energywindowrangesequence", &tags::EnergyWindowRangeSequence),
-        ("transduceridentificationsequence", &tags::TransducerIdentificationSequence),
-        ("radiationdosesequence", &tags::RadiationDoseSequence),
-        ("matingfeaturesetid", &tags::MatingFeatureSetID),
-        ("ophthalmicaxialmeasurementsrighteyesequence", &tags::OphthalmicAxialMeasurementsRightEyeSequence),
-        ("terminationcardiactriggercountthreshold", &tags::TerminationCardiacTriggerCountThreshold),
-        ("acquisitionstartconditiondata", &tags::AcquisitionStartConditionData),
-        ("conceptualvolumeconstituentindex", &tags::ConceptualVolumeConstituentIndex),
-        ("dbdt", &tags::DBdt),
-        ("channelinnerlength", &tags::ChannelInnerLength),
-        ("rotationoffset", &tags::RotationOffset),
-        ("presentationintenttype", &tags::PresentationIntentType),
-        ("volumetricpresentationstateinputsequence", &tags::VolumetricPresentationStateInputSequence),
-        ("asserteridentificationsequence", &tags::AsserterIdentificationSequence),
-        ("graphicfilled", &tags::GraphicFilled),
+        ("transactionstatus", &tags::TransactionStatus),
+        ("filtermaterialusedingaincalibration", &tags::FilterMaterialUsedinGainCalibration),
+        ("implanttemplate3dmodelsurfacenumber", &tags::ImplantTemplate3DModelSurfaceNumber),
+        ("visualfieldhorizontalextent", &tags::VisualFieldHorizontalExtent),
+        ("slabthickness", &tags::SlabThickness),
+        ("coatingmaterialscodesequence", &tags::CoatingMaterialsCodeSequence),
+        ("rtplantime", &tags::RTPlanTime),
         ("tag_3dlinecoordinates", &tags::Tag_3DLineCoordinates),
-        ("patientsaddress", &tags::PatientsAddress),
-        ("presentationstatecompositorcomponentsequence", &tags::PresentationStateCompositorComponentSequence),
-        ("coefficientssddn", &tags::CoefficientsSDDN),
-        ("xray3dframetypesequence", &tags::XRay3DFrameTypeSequence),
-        ("colorfilterarraypatterncolumns", &tags::ColorFilterArrayPatternColumns),
-        ("acrossscanspatialresolution", &tags::AcrossscanSpatialResolution),
-        ("lastmenstrualdate", &tags::LastMenstrualDate),
-        ("annotationindexlist", &tags::AnnotationIndexList),
-        ("referencedopticalpathidentifier", &tags::ReferencedOpticalPathIdentifier),
-        ("overrideparameterpointer", &tags::OverrideParameterPointer),
-        ("filterthicknessminimum", &tags::FilterThicknessMinimum),
-        ("coincidencewindowwidth", &tags::CoincidenceWindowWidth),
-        ("sequenceofultrasoundregions", &tags::SequenceofUltrasoundRegions),
-        ("modality", &tags::Modality),
-        ("decouplingmethod", &tags::DecouplingMethod),
-        ("pixelcomponentrangestop", &tags::PixelComponentRangeStop),
-        ("structuredconstraintobservationsequence", &tags::StructuredConstraintObservationSequence),
-        ("burnedinannotation", &tags::BurnedInAnnotation),
-        ("calculatedtargetposition", &tags::CalculatedTargetPosition),
-        ("mappedpixelvalue", &tags::MappedPixelValue),
-        ("blendinglookuptabledata", &tags::BlendingLookupTableData),
-        ("dosetype", &tags::DoseType),
-        ("scantype", &tags::ScanType),
-        ("exposurestatus", &tags::ExposureStatus),
-        ("vectorcoordinatedata", &tags::VectorCoordinateData),
-        ("outlineshapetype", &tags::OutlineShapeType),
-        ("numberofacquisitiondevices", &tags::NumberofAcquisitionDevices),
-        ("wedgeincontactlength", &tags::WedgeInContactLength),
-        ("creationtime", &tags::CreationTime),
-        ("wedgename", &tags::WedgeName),
-        ("parallelreductionfactoroutofplane", &tags::ParallelReductionFactoroutofplane),
-        ("queuestatus", &tags::QueueStatus),
-        ("cornealtopographymappingnormalssequence", &tags::CornealTopographyMappingNormalsSequence),
-        ("organatriskmaximumdose", &tags::OrganatRiskMaximumDose),
-        ("conceptualvolumesegmentationreferencesequence", &tags::ConceptualVolumeSegmentationReferenceSequence),
-        ("numberofslices", &tags::NumberofSlices),
-        ("templatetype", &tags::TemplateType),
-        ("phasecontrast", &tags::PhaseContrast),
-        ("productlotidentifier", &tags::ProductLotIdentifier),
-        ("sensitivitytype", &tags::SensitivityType),
-        ("referencedosepointcoordinates", &tags::ReferenceDosePointCoordinates),
+        ("wedgenumber", &tags::WedgeNumber),
+        ("reasonfortheattributemodification", &tags::ReasonfortheAttributeModification),
+        ("procedureidentifiercodesequence_trial", &tags::ProcedureIdentifierCodeSequence_Trial),
+        ("setupimagecomment", &tags::SetupImageComment),
+        ("actualcardiactriggerdelaytime", &tags::ActualCardiacTriggerDelayTime),
+        ("biopsytargetsequence", &tags::BiopsyTargetSequence),
+        ("tag_2dpointcoordinatessequence", &tags::Tag_2DPointCoordinatesSequence),
+        ("radionuclidecodesequence", &tags::RadionuclideCodeSequence),
+        ("potentialthreatobjectid", &tags::PotentialThreatObjectID),
+        ("totalprescriptiondose", &tags::TotalPrescriptionDose),
+        ("aslcontext", &tags::ASLContext),
+        ("contrastbolusagentdetected", &tags::ContrastBolusAgentDetected),
+        ("flatcornealaxissequence", &tags::FlatCornealAxisSequence),
+        ("patientsupportpositionsequence", &tags::PatientSupportPositionSequence),
+        ("analogfiltertypecodesequence", &tags::AnalogFilterTypeCodeSequence),
+        ("acquisitionsinseries", &tags::AcquisitionsinSeries),
+        ("parametersequencepointer", &tags::ParameterSequencePointer),
+        ("processingfunction", &tags::ProcessingFunction),
+        ("radiationdeviceconfigurationandcommissioningkeysequence", &tags::RadiationDeviceConfigurationandCommissioningKeySequence),
+        ("exposuretimeinmicros", &tags::ExposureTimeinmicroS),
+        ("fixationdevicetype", &tags::FixationDeviceType),
+        ("tomotherapeuticleafinitialcloseddurations", &tags::TomotherapeuticLeafInitialClosedDurations),
+        ("frametimevector", &tags::FrameTimeVector),
+        ("radiusalongthewedge", &tags::RadiusAlongtheWedge),
+        ("scheduledperformingphysicianidentificationsequence", &tags::ScheduledPerformingPhysicianIdentificationSequence),
+        ("constraintweight", &tags::ConstraintWeight),
+        ("imagesinacquisition", &tags::ImagesinAcquisition),
+        ("compressionpressure", &tags::CompressionPressure),
+        ("overriddenattributessequence", &tags::OverriddenAttributesSequence),
+        ("anchorpointannotationunits", &tags::AnchorPointAnnotationUnits),
+        ("contrastbolusingredientconcentration", &tags::ContrastBolusIngredientConcentration),
+        ("additionalinspectionselectioncriteria", &tags::AdditionalInspectionSelectionCriteria),
+        ("intermarkerdistance", &tags::InterMarkerDistance),
+        ("coordinatesystemaxisvalues", &tags::CoordinateSystemAxisValues),
+        ("selectorslvalue", &tags::SelectorSLValue),
+        ("endacquisitiondatetime", &tags::EndAcquisitionDateTime),
         ("segmentationcreationtemplatelabel", &tags::SegmentationCreationTemplateLabel),
-        ("isocentertolateralspreadingdevicedistance", &tags::IsocentertoLateralSpreadingDeviceDistance),
-        ("fixationlightazimuthalangle", &tags::FixationLightAzimuthalAngle),
-        ("widefieldophthalmicphotographyqualityratingsequence", &tags::WideFieldOphthalmicPhotographyQualityRatingSequence),
-        ("xfocuscenter", &tags::XFocusCenter),
-        ("referencedframenumbers", &tags::ReferencedFrameNumbers),
-        ("decoupling", &tags::Decoupling),
-        ("segmentedredpalettecolorlookuptabledata", &tags::SegmentedRedPaletteColorLookupTableData),
-        ("dynamicrange", &tags::DynamicRange),
-        ("acquisitionsinstudy", &tags::AcquisitionsinStudy),
-        ("applicatoropening", &tags::ApplicatorOpening),
-        ("tabletoplongitudinalpositiontolerance", &tags::TableTopLongitudinalPositionTolerance),
-        ("breastsupportypositiontoisocenter", &tags::BreastSupportYPositiontoIsocenter),
-        ("hpglpensequence", &tags::HPGLPenSequence),
-        ("attenuationcorrected", &tags::AttenuationCorrected),
-        ("interoperabilityversion", &tags::InteroperabilityVersion),
-        ("underlined", &tags::Underlined),
-        ("probecenterlocationz", &tags::ProbeCenterLocationZ),
-        ("treatmentsitemodifiercodesequence", &tags::TreatmentSiteModifierCodeSequence),
-        ("selectordtvalue", &tags::SelectorDTValue),
+        ("uid", &tags::UID),
+        ("gantrydetectorslew", &tags::GantryDetectorSlew),
+        ("structuresetdescription", &tags::StructureSetDescription),
+        ("channelshieldid", &tags::ChannelShieldID),
+        ("entrancedose", &tags::EntranceDose),
+        ("annotationgroupalgorithmidentificationsequence", &tags::AnnotationGroupAlgorithmIdentificationSequence),
+        ("interpretationid", &tags::InterpretationID),
+        ("brachyapplicationsetupdose", &tags::BrachyApplicationSetupDose),
+        ("sourceapplicatorstepsize", &tags::SourceApplicatorStepSize),
+        ("countssource", &tags::CountsSource),
+        ("ioncontrolpointdeliverysequence", &tags::IonControlPointDeliverySequence),
+        ("numberofvolumetriccurvepoints", &tags::NumberofVolumetricCurvePoints),
+        ("blocktransmission", &tags::BlockTransmission),
+        ("reasonforsuperseding", &tags::ReasonforSuperseding),
+        ("segmentcharacteristicssequence", &tags::SegmentCharacteristicsSequence),
+        ("diameterofvisibility", &tags::DiameterofVisibility),
+        ("xaxrfframecharacteristicssequence", &tags::XAXRFFrameCharacteristicsSequence),
+        ("referencecoordinates", &tags::ReferenceCoordinates),
+        ("fractionalbandwidth", &tags::FractionalBandwidth),
+        ("sourceofrefractivemeasurementssequence", &tags::SourceofRefractiveMeasurementsSequence),
+        ("targetminimumluminance", &tags::TargetMinimumLuminance),
+        ("countsaccumulated", &tags::CountsAccumulated),
+        ("receivecoilmanufacturername", &tags::ReceiveCoilManufacturerName),
+        ("referenceddosereferencenumber", &tags::ReferencedDoseReferenceNumber),
+        ("measureddosetype", &tags::MeasuredDoseType),
+        ("networkid", &tags::NetworkID),
+        ("applicationname", &tags::ApplicationName),
+        ("subjectrelativepositioninimage", &tags::SubjectRelativePositioninImage),
+        ("sourceofrefractivemeasurementscodesequence", &tags::SourceofRefractiveMeasurementsCodeSequence),
+        ("sliceprogressiondirection", &tags::SliceProgressionDirection),
+        ("humidity", &tags::Humidity),
+        ("patientspecificationsequence", &tags::PatientSpecificationSequence),
+        ("wedgesequence", &tags::WedgeSequence),
+        ("channelderivationdescription", &tags::ChannelDerivationDescription),
+        ("waveformannotationsequence", &tags::WaveformAnnotationSequence),
+        ("referencedcalculateddosereferencesequence", &tags::ReferencedCalculatedDoseReferenceSequence),
+        ("rtphysicianintentsequence", &tags::RTPhysicianIntentSequence),
+        ("screenminimumcolorbitdepth", &tags::ScreenMinimumColorBitDepth),
+        ("boundingboxbottomrighthandcorner", &tags::BoundingBoxBottomRightHandCorner),
+        ("decompositionalgorithmidentificationsequence", &tags::DecompositionAlgorithmIdentificationSequence),
+        ("encryptedcontent", &tags::EncryptedContent),
+        ("scheduledprocedurestepstartdatetime", &tags::ScheduledProcedureStepStartDateTime),
+        ("refractivesurgerytypecodesequence", &tags::RefractiveSurgeryTypeCodeSequence),
+        ("structureddisplaytextboxsequence", &tags::StructuredDisplayTextBoxSequence),
+        ("prcstorcsorientation", &tags::PRCStoRCSOrientation),
+        ("overlaydescriptorgreen", &tags::OverlayDescriptorGreen),
+        ("stowrsstoragesequence", &tags::STOWRSStorageSequence),
+        ("sopclassessupported", &tags::SOPClassesSupported),
+        ("alternatecontaineridentifiersequence", &tags::AlternateContainerIdentifierSequence),
+        ("visualacuitymeasurementsequence", &tags::VisualAcuityMeasurementSequence),
+        ("intendedphaseenddate", &tags::IntendedPhaseEndDate),
+        ("observationsubjectuid_trial", &tags::ObservationSubjectUID_Trial),
+        ("physiciansofrecordidentificationsequence", &tags::PhysiciansofRecordIdentificationSequence),
+        ("observationuid", &tags::ObservationUID),
+        ("smallestimagepixelvalueinplane", &tags::SmallestImagePixelValueinPlane),
+        ("estimatedradiographicmagnificationfactor", &tags::EstimatedRadiographicMagnificationFactor),
+        ("visualfieldtestduration", &tags::VisualFieldTestDuration),
+        ("modalitiesinstudy", &tags::ModalitiesinStudy),
+        ("rangeshiftersequence", &tags::RangeShifterSequence),
+        ("basismaterialscodesequence", &tags::BasisMaterialsCodeSequence),
+        ("arterialspinlabelingcontrast", &tags::ArterialSpinLabelingContrast),
+        ("numberofseriesrelatedinstances", &tags::NumberofSeriesRelatedInstances),
+        ("alternatebeamdosetype", &tags::AlternateBeamDoseType),
+        ("gantrytype", &tags::GantryType),
+        ("verifyingobservername", &tags::VerifyingObserverName),
+        ("lutexplanation", &tags::LUTExplanation),
+        ("studystatusid", &tags::StudyStatusID),
+        ("sensitivityvalue", &tags::SensitivityValue),
+        ("echoplanarpulsesequence", &tags::EchoPlanarPulseSequence),
+        ("datacollectiondiameter", &tags::DataCollectionDiameter),
+        ("referencedimageboxsequence", &tags::ReferencedImageBoxSequence),
+        ("cornealtopographymappingnormalssequence", &tags::CornealTopographyMappingNormalsSequence),
+        ("ophthalmicpatientclinicalinformationlefteyesequence", &tags::OphthalmicPatientClinicalInformationLeftEyeSequence),
+        ("dvhdata", &tags::DVHData),
+        ("fluoroscopylastimageholdpersistenceflag", &tags::FluoroscopyLastImageHoldPersistenceFlag),
+        ("softtissuethermalindex", &tags::SoftTissueThermalIndex),
+        ("vitreousstatusdescription", &tags::VitreousStatusDescription),
+        ("applicatorid", &tags::ApplicatorID),
+        ("endingrespiratoryphase", &tags::EndingRespiratoryPhase),
+        ("geometryfordisplay", &tags::GeometryforDisplay),
         ("probedriveequipmentsequence", &tags::ProbeDriveEquipmentSequence),
-        ("retrieveurl", &tags::RetrieveURL),
-        ("dischargetime", &tags::DischargeTime),
-        ("referencedframeofreferenceuid", &tags::ReferencedFrameofReferenceUID),
-        ("upperlimitnumberofpersistentfluoroscopyframes", &tags::UpperLimitNumberOfPersistentFluoroscopyFrames),
-        ("sourceconceptualvolumesequence", &tags::SourceConceptualVolumeSequence),
-        ("metadatasequence", &tags::MetadataSequence),
-        ("indicationroisequence", &tags::IndicationROISequence),
-        ("graphicannotationsequence", &tags::GraphicAnnotationSequence),
-        ("interventiondrugstarttime", &tags::InterventionDrugStartTime),
+        ("channelsensitivitycorrectionfactor", &tags::ChannelSensitivityCorrectionFactor),
+        ("indicationsequence", &tags::IndicationSequence),
+        ("studyaccessendpointssequence", &tags::StudyAccessEndPointsSequence),
+        ("materialisolationdiameter", &tags::MaterialIsolationDiameter),
+        ("trim", &tags::Trim),
+        ("sourcetocompensatordistance", &tags::SourcetoCompensatorDistance),
+        ("performedstationaetitle", &tags::PerformedStationAETitle),
+        ("numberofboluses", &tags::NumberofBoluses),
+        ("scanstartpositionsequence", &tags::ScanStartPositionSequence),
+        ("screeningbaselinemeasuredsequence", &tags::ScreeningBaselineMeasuredSequence),
+        ("constantvolumeflag", &tags::ConstantVolumeFlag),
+        ("frameacquisitionnumber", &tags::FrameAcquisitionNumber),
+        ("graphicgroupdescription", &tags::GraphicGroupDescription),
+        ("tabletoplongitudinalsetupdisplacement", &tags::TableTopLongitudinalSetupDisplacement),
+        ("cameraangleofview", &tags::CameraAngleofView),
+        ("toriciolpowersequence", &tags::ToricIOLPowerSequence),
+        ("referencedreferenceimagenumber", &tags::ReferencedReferenceImageNumber),
+        ("nominalenergy", &tags::NominalEnergy),
+        ("fluencedatascale", &tags::FluenceDataScale),
+        ("patientsaddress", &tags::PatientsAddress),
+        ("detectorzpositiontoisocenter", &tags::DetectorZPositiontoIsocenter),
+        ("sourceimagesequence", &tags::SourceImageSequence),
+        ("halfvaluelayer", &tags::HalfValueLayer),
+        ("imagesinseries", &tags::ImagesinSeries),
+        ("gpsmeasuremode", &tags::GPSMeasureMode),
+        ("doubleexposureordering", &tags::DoubleExposureOrdering),
+        ("tablelongitudinalincrement", &tags::TableLongitudinalIncrement),
+        ("otherstudynumbers", &tags::OtherStudyNumbers),
+        ("positionmeasuringdeviceused", &tags::PositionMeasuringDeviceUsed),
+        ("imagescalerepresentation", &tags::ImageScaleRepresentation),
         ("imagequalityindicatorsize", &tags::ImageQualityIndicatorSize),
-        ("reconstructionprotocolelementspecificationsequence", &tags::ReconstructionProtocolElementSpecificationSequence),
-        ("oversamplingphase", &tags::OversamplingPhase),
-        ("reasonforcancellation", &tags::ReasonforCancellation),
-        ("languagecodesequence_trial", &tags::LanguageCodeSequence_Trial),
-        ("patternoffcolorcielabvalue", &tags::PatternOffColorCIELabValue),
-        ("inversiontimes", &tags::InversionTimes),
-        ("calibrationprocedure", &tags::CalibrationProcedure),
-        ("segmentationalgorithmidentificationsequence", &tags::SegmentationAlgorithmIdentificationSequence),
-        ("organizationalrolecodesequence", &tags::OrganizationalRoleCodeSequence),
-        ("voilutfunction", &tags::VOILUTFunction),
-        ("stereoverticalpixeloffset", &tags::StereoVerticalPixelOffset),
-        ("snoutposition", &tags::SnoutPosition),
-        ("attributeoccurrencepointer", &tags::AttributeOccurrencePointer),
-        ("exclusionduration", &tags::ExclusionDuration),
-        ("patientsinstitutionresidence", &tags::PatientsInstitutionResidence),
-        ("surfacecount", &tags::SurfaceCount),
-        ("resultsid", &tags::ResultsID),
-        ("resultscomments", &tags::ResultsComments),
-        ("rationaldenominatorvalue", &tags::RationalDenominatorValue),
-        ("compoundgraphicunits", &tags::CompoundGraphicUnits),
-        ("materialnotes", &tags::MaterialNotes),
-        ("sourceinstancesequence", &tags::SourceInstanceSequence),
-        ("dosevalueinterpretation", &tags::DoseValueInterpretation),
-        ("measurementautomation_trial", &tags::MeasurementAutomation_Trial),
-        ("acquisitionprotocolelementspecificationsequence", &tags::AcquisitionProtocolElementSpecificationSequence),
-        ("platetype", &tags::PlateType),
-        ("anchorpoint", &tags::AnchorPoint),
-        ("compressioncode", &tags::CompressionCode),
-        ("timeoflastcalibration", &tags::TimeofLastCalibration),
-        ("beamdeliverydurationlimit", &tags::BeamDeliveryDurationLimit),
-        ("setupdevicelabel", &tags::SetupDeviceLabel),
-        ("applicatordescription", &tags::ApplicatorDescription),
-        ("histogramlastbinvalue", &tags::HistogramLastBinValue),
-        ("contournumber", &tags::ContourNumber),
-        ("receivingae", &tags::ReceivingAE),
-        ("screeningbaselinemeasured", &tags::ScreeningBaselineMeasured),
+        ("correctedlocalizeddeviationfromnormalprobabilitycalculated", &tags::CorrectedLocalizedDeviationFromNormalProbabilityCalculated),
+        ("sourceapplicatorid", &tags::SourceApplicatorID),
+        ("imageacquisitiondepth", &tags::ImageAcquisitionDepth),
+        ("echonumbers", &tags::EchoNumbers),
+        ("fieldofviewrotation", &tags::FieldofViewRotation),
+        ("thresholdvaluesequence", &tags::ThresholdValueSequence),
+        ("agecorrectedsensitivitydeviationprobabilityvalue", &tags::AgeCorrectedSensitivityDeviationProbabilityValue),
+        ("warningreason", &tags::WarningReason),
+        ("referencedcomparisonsopinstancesequence", &tags::ReferencedComparisonSOPInstanceSequence),
+        ("configurationinformationdescription", &tags::ConfigurationInformationDescription),
+        ("patientsbirthdate", &tags::PatientsBirthDate),
+        ("conventionalcontrolpointverificationsequence", &tags::ConventionalControlPointVerificationSequence),
+        ("filterthicknessusedingaincalibration", &tags::FilterThicknessUsedinGainCalibration),
+        ("requestedseriesdescriptioncodesequence", &tags::RequestedSeriesDescriptionCodeSequence),
+        ("referencedmeasureddosereferencesequence", &tags::ReferencedMeasuredDoseReferenceSequence),
+        ("aslpulsetrainduration", &tags::ASLPulseTrainDuration),
+        ("displaysubsystemsequence", &tags::DisplaySubsystemSequence),
+        ("dataelementdescription", &tags::DataElementDescription),
+        ("randomscorrected", &tags::RandomsCorrected),
+        ("frameofreferencetransformationtype", &tags::FrameofReferenceTransformationType),
+        ("transducerapplicationcodesequence", &tags::TransducerApplicationCodeSequence),
+        ("dosetype", &tags::DoseType),
+        ("interpretationrecorder", &tags::InterpretationRecorder),
+        ("fileaccessuri", &tags::FileAccessURI),
+        ("photographicsensitivity", &tags::PhotographicSensitivity),
+        ("referencedstereometricinstancesequence", &tags::ReferencedStereometricInstanceSequence),
+        ("displaysethorizontaljustification", &tags::DisplaySetHorizontalJustification),
+        ("componentassemblysequence", &tags::ComponentAssemblySequence),
+        ("compensatormillingtooldiameter", &tags::CompensatorMillingToolDiameter),
+        ("iolformulacodesequence", &tags::IOLFormulaCodeSequence),
+        ("prescriptiondescription", &tags::PrescriptionDescription),
+        ("rightlenssequence", &tags::RightLensSequence),
+        ("templateversion", &tags::TemplateVersion),
+        ("referencepixely0", &tags::ReferencePixelY0),
+        ("parallelrtbeamdelimiterpositions", &tags::ParallelRTBeamDelimiterPositions),
+        ("channelinnerlength", &tags::ChannelInnerLength),
+        ("acquisitionprotocoldescription", &tags::AcquisitionProtocolDescription),
+        ("catchtrialsdataflag", &tags::CatchTrialsDataFlag),
+        ("realworldvalueslope", &tags::RealWorldValueSlope),
+        ("productparametersequence", &tags::ProductParameterSequence),
+        ("codingschemeresourcessequence", &tags::CodingSchemeResourcesSequence),
+        ("patientssexneutered", &tags::PatientsSexNeutered),
+        ("initialcinerunstate", &tags::InitialCineRunState),
+        ("rrintervaltimenominal", &tags::RRIntervalTimeNominal),
+        ("numberofframesinoverlay", &tags::NumberofFramesinOverlay),
+        ("spacingbetweenslices", &tags::SpacingBetweenSlices),
+        ("overlaycompressionlabel", &tags::OverlayCompressionLabel),
+        ("documentclasscodesequence", &tags::DocumentClassCodeSequence),
+        ("segmentalgorithmname", &tags::SegmentAlgorithmName),
+        ("displayedareatoplefthandcorner", &tags::DisplayedAreaTopLeftHandCorner),
+        ("exposuredosesequence", &tags::ExposureDoseSequence),
+        ("concatenationuid", &tags::ConcatenationUID),
+        ("cardiacsynchronizationtechnique", &tags::CardiacSynchronizationTechnique),
+        ("cinerate", &tags::CineRate),
+        ("numberofzerofills", &tags::NumberofZeroFills),
+        ("perprojectionacquisitionsequence", &tags::PerProjectionAcquisitionSequence),
+        ("brachyaccessorydevicename", &tags::BrachyAccessoryDeviceName),
+        ("interventiondrugdose", &tags::InterventionDrugDose),
+        ("recognizablevisualfeatures", &tags::RecognizableVisualFeatures),
+        ("ctpositionsequence", &tags::CTPositionSequence),
+        ("timeslotinformationsequence", &tags::TimeSlotInformationSequence),
+        ("patientstate", &tags::PatientState),
+        ("dacgainpoints", &tags::DACGainPoints),
+        ("studyidissuer", &tags::StudyIDIssuer),
+        ("positionersecondaryangleincrement", &tags::PositionerSecondaryAngleIncrement),
+        ("scanprogressiondirection", &tags::ScanProgressionDirection),
+        ("reconstructiontargetcenterpatient", &tags::ReconstructionTargetCenterPatient),
+        ("screeningbaselinevalue", &tags::ScreeningBaselineValue),
+        ("ciexywhitepoint", &tags::CIExyWhitePoint),
+        ("overlaysblue", &tags::OverlaysBlue),
+        ("maximumstoredvaluemapped", &tags::MaximumStoredValueMapped),
+        ("lensdescription", &tags::LensDescription),
+        ("beamdosemeaning", &tags::BeamDoseMeaning),
+        ("kspacefiltering", &tags::KspaceFiltering),
+        ("responsiblepersonrole", &tags::ResponsiblePersonRole),
+        ("filterbyattributepresence", &tags::FilterbyAttributePresence),
+        ("oecfcolumns", &tags::OECFColumns),
+        ("lengthtoend", &tags::LengthtoEnd),
+        ("modelusagecodesequence", &tags::ModelUsageCodeSequence),
+        ("atdabilityassessment", &tags::ATDAbilityAssessment),
+        ("patientsetuplabel", &tags::PatientSetupLabel),
+        ("patientsreligiouspreference", &tags::PatientsReligiousPreference),
+        ("maximumnumberofintervaldays", &tags::MaximumNumberofIntervalDays),
+        ("selectorunvalue", &tags::SelectorUNValue),
+        ("targetunderdosevolumefraction", &tags::TargetUnderdoseVolumeFraction),
+        ("reportstatusid_trial", &tags::ReportStatusID_Trial),
+        ("admittingtime", &tags::AdmittingTime),
+        ("startcumulativemetersetweight", &tags::StartCumulativeMetersetWeight),
+        ("measurementunitscodesequence", &tags::MeasurementUnitsCodeSequence),
+        ("procedurestepcancellationdatetime", &tags::ProcedureStepCancellationDateTime),
+        ("proposedstudysequence", &tags::ProposedStudySequence),
+        ("transportclassification", &tags::TransportClassification),
+        ("blockname", &tags::BlockName),
+        ("xray3dreconstructionsequence", &tags::XRay3DReconstructionSequence),
+        ("velocityencodingdirection", &tags::VelocityEncodingDirection),
+        ("cornealwavefront", &tags::CornealWavefront),
+        ("exposuremodulationtype", &tags::ExposureModulationType),
+        ("weightingtransferfunctionsequence", &tags::WeightingTransferFunctionSequence),
+        ("referencedsurfacedatasequence", &tags::ReferencedSurfaceDataSequence),
+        ("referencedcalculateddosereferencenumber", &tags::ReferencedCalculatedDoseReferenceNumber),
+        ("postdeformationmatrixregistrationsequence", &tags::PostDeformationMatrixRegistrationSequence),
+        ("edgepointindexlist", &tags::EdgePointIndexList),
+        ("planenormal", &tags::PlaneNormal),
+        ("referencedstudysequence", &tags::ReferencedStudySequence),
+        ("beamnumber", &tags::BeamNumber),
+        ("transversedetectorseparation", &tags::TransverseDetectorSeparation),
+        ("fixationlightpolarangle", &tags::FixationLightPolarAngle),
+        ("gpsdop", &tags::GPSDOP),
+        ("referencedfractiongroupsequence", &tags::ReferencedFractionGroupSequence),
+        ("parameteritemindex", &tags::ParameterItemIndex),
+        ("filterbyoperator", &tags::FilterbyOperator),
+        ("heartrate", &tags::HeartRate),
+        ("shadowcolorcielabvalue", &tags::ShadowColorCIELabValue),
+        ("documentingobserveridentifiercodesequence_trial", &tags::DocumentingObserverIdentifierCodeSequence_Trial),
+        ("encryptedattributessequence", &tags::EncryptedAttributesSequence),
+        ("segmentannotationtypemodifiercodesequence", &tags::SegmentAnnotationTypeModifierCodeSequence),
+        ("imageboxtileverticaldimension", &tags::ImageBoxTileVerticalDimension),
+        ("patientsweight", &tags::PatientsWeight),
+        ("inversionrecovery", &tags::InversionRecovery),
+        ("applicablesafetystandarddescription", &tags::ApplicableSafetyStandardDescription),
+        ("angularposition", &tags::AngularPosition),
+        ("referencedxraysourceindex", &tags::ReferencedXRaySourceIndex),
+        ("variablenextdatagroup", &tags::VariableNextDataGroup),
+        ("threatcategorydescription", &tags::ThreatCategoryDescription),
+        ("overlaydescriptorgray", &tags::OverlayDescriptorGray),
+        ("indicationnumber", &tags::IndicationNumber),
+        ("effectivedosecalculationmethodcodesequence", &tags::EffectiveDoseCalculationMethodCodeSequence),
+        ("additionalinspectionmethodsequence", &tags::AdditionalInspectionMethodSequence),
+        ("variableflipangleflag", &tags::VariableFlipAngleFlag),
+        ("acceleration", &tags::Acceleration),
+        ("patientcomments", &tags::PatientComments),
+        ("rtplanname", &tags::RTPlanName),
+        ("numberofremainingsuboperations", &tags::NumberofRemainingSuboperations),
+        ("scheduledprocedurestependdate", &tags::ScheduledProcedureStepEndDate),
+        ("contentdate", &tags::ContentDate),
+        ("primaryanatomicstructuremodifiersequence", &tags::PrimaryAnatomicStructureModifierSequence),
+        ("lossyimagecompressionmethod", &tags::LossyImageCompressionMethod),
+        ("partialdatadisplayhandling", &tags::PartialDataDisplayHandling),
+        ("mrspectroscopyfovgeometrysequence", &tags::MRSpectroscopyFOVGeometrySequence),
+        ("overlayrepeatinterval", &tags::OverlayRepeatInterval),
+        ("cinerelativetorealtime", &tags::CineRelativetoRealTime),
+        ("structureddisplaybackgroundcielabvalue", &tags::StructuredDisplayBackgroundCIELabValue),
+        ("sourceofcornealsizedatacodesequence", &tags::SourceofCornealSizeDataCodeSequence),
+        ("settlingphaseframe", &tags::SettlingPhaseFrame),
+        ("mvimaginggenerationparameterssequence", &tags::MVImagingGenerationParametersSequence),
+        ("assessmentobservationssequence", &tags::AssessmentObservationsSequence),
+        ("beamlimitingdevicerotationdirection", &tags::BeamLimitingDeviceRotationDirection),
+        ("originalimageidentification", &tags::OriginalImageIdentification),
+        ("patientsupportid", &tags::PatientSupportID),
+        ("scheduledspecimensequence", &tags::ScheduledSpecimenSequence),
+        ("longdevicedescription", &tags::LongDeviceDescription),
+        ("detectordescription", &tags::DetectorDescription),
+        ("energywindowlowerlimit", &tags::EnergyWindowLowerLimit),
+        ("dosereferencenumber", &tags::DoseReferenceNumber),
+        ("secondaryreviewername", &tags::SecondaryReviewerName),
+        ("channelshieldnominalthickness", &tags::ChannelShieldNominalThickness),
+        ("storagemediafilesetid", &tags::StorageMediaFilesetID),
+        ("curvedata", &tags::CurveData),
+        ("acquisitionsamplesize", &tags::AcquisitionSampleSize),
+        ("labeltext", &tags::LabelText),
+        ("partialview", &tags::PartialView),
+        ("rttreatmentpreparationpatientpositionsequence", &tags::RTTreatmentPreparationPatientPositionSequence),
+        ("radiopharmaceuticaladministrationeventuid", &tags::RadiopharmaceuticalAdministrationEventUID),
+        ("performedprocessingapplicationscodesequence", &tags::PerformedProcessingApplicationsCodeSequence),
+        ("acquisitionprotocolelementsequence", &tags::AcquisitionProtocolElementSequence),
+        ("rangemodulatornumber", &tags::RangeModulatorNumber),
+        ("referencedpresentationstatesequence", &tags::ReferencedPresentationStateSequence),
+        ("ophthalmicthicknessmapqualitythresholdsequence", &tags::OphthalmicThicknessMapQualityThresholdSequence),
         ("borderdensity", &tags::BorderDensity),
-        ("hl7documenttypecodesequence", &tags::HL7DocumentTypeCodeSequence),
-        ("digitalsignaturessequence", &tags::DigitalSignaturesSequence),
-        ("treatmentsessionapplicationsetupsequence", &tags::TreatmentSessionApplicationSetupSequence),
-        ("referencedinstancesequence", &tags::ReferencedInstanceSequence),
-        ("fluoroscopyflag", &tags::FluoroscopyFlag),
-        ("selectorcsvalue", &tags::SelectorCSValue),
-        ("distancesourcetoentrance", &tags::DistanceSourcetoEntrance),
-        ("positionermotion", &tags::PositionerMotion),
-        ("tableofpixelvalues", &tags::TableofPixelValues),
-        ("slicelocationvector", &tags::SliceLocationVector),
-        ("toriciolpowerforexacttargetrefractionsequence", &tags::ToricIOLPowerforExactTargetRefractionSequence),
-        ("octopticalcenterwavelength", &tags::OCTOpticalCenterWavelength),
-        ("blindspotycoordinate", &tags::BlindSpotYCoordinate),
-        ("safepositionreturntime", &tags::SafePositionReturnTime),
-        ("referencedsopclassuid", &tags::ReferencedSOPClassUID),
-        ("deadtimecorrectionflag", &tags::DeadTimeCorrectionFlag),
-        ("linethickness", &tags::LineThickness),
-        ("targetmaterialsequence", &tags::TargetMaterialSequence),
-        ("rotationvector", &tags::RotationVector),
-        ("identifiercodesequence_trial", &tags::IdentifierCodeSequence_Trial),
-        ("tablemotion", &tags::TableMotion),
-        ("detectorlinesofresponseused", &tags::DetectorLinesofResponseUsed),
-        ("wholeslidemicroscopyimageframetypesequence", &tags::WholeSlideMicroscopyImageFrameTypeSequence),
-        ("blocktype", &tags::BlockType),
-        ("cardiacbeatrejectiontechnique", &tags::CardiacBeatRejectionTechnique),
-        ("scanningspotsize", &tags::ScanningSpotSize),
-        ("patientsweight", &tags::PatientsWeight),
-        ("radiationdeviceconfigurationandcommissioningkeysequence", &tags::RadiationDeviceConfigurationandCommissioningKeySequence),
-        ("channeloverlap", &tags::ChannelOverlap),
-        ("cornealaxis", &tags::CornealAxis),
-        ("radiationbeamcompensatormillingtooldiameter", &tags::RadiationBeamCompensatorMillingToolDiameter),
-        ("patternoffopacity", &tags::PatternOffOpacity),
-        ("acquisitioncompressiontype", &tags::AcquisitionCompressionType),
-        ("longtrianglepointindexlist", &tags::LongTrianglePointIndexList),
-        ("calculateddosereferencedosevalue", &tags::CalculatedDoseReferenceDoseValue),
-        ("contrastbolusstoptime", &tags::ContrastBolusStopTime),
-        ("tablerowdefinitionsequence", &tags::TableRowDefinitionSequence),
-        ("channeltimeskew", &tags::ChannelTimeSkew),
-        ("breastsupportisocenterprimaryangle", &tags::BreastSupportIsocenterPrimaryAngle),
-        ("verifyingobserversequence", &tags::VerifyingObserverSequence),
-        ("beamdescription", &tags::BeamDescription),
-        ("originatingsopinstancereferencesequence", &tags::OriginatingSOPInstanceReferenceSequence),
-        ("parametersequencepointer", &tags::ParameterSequencePointer),
-        ("unspecifiedlateralitylenssequence", &tags::UnspecifiedLateralityLensSequence),
-        ("additionalscreeningperformed", &tags::AdditionalScreeningPerformed),
-        ("substanceadministrationdatetime", &tags::SubstanceAdministrationDateTime),
-        ("relatedseriessequence", &tags::RelatedSeriesSequence),
-        ("ordercallbackphonenumber", &tags::OrderCallbackPhoneNumber),
-        ("operatingmodesequence", &tags::OperatingModeSequence),
-        ("visualfieldhorizontalextent", &tags::VisualFieldHorizontalExtent),
-        ("beamarealimitsequence", &tags::BeamAreaLimitSequence),
-        ("fieldofviewsequence", &tags::FieldofViewSequence),
-        ("requestedseriesdescription", &tags::RequestedSeriesDescription),
-        ("documentauthor_trial", &tags::DocumentAuthor_Trial),
-        ("deliveredprimarymeterset", &tags::DeliveredPrimaryMeterset),
+        ("echopeakposition", &tags::EchoPeakPosition),
+        ("imagedisplayformat", &tags::ImageDisplayFormat),
         ("referencedcontrolpointindex", &tags::ReferencedControlPointIndex),
-        ("observercontextflag_trial", &tags::ObserverContextFlag_Trial),
-        ("imagesetssequence", &tags::ImageSetsSequence),
-        ("referencedsurfacemeshidentificationsequence", &tags::ReferencedSurfaceMeshIdentificationSequence),
-        ("patientspeciesdescription", &tags::PatientSpeciesDescription),
-        ("recordedrtcontrolpointdatetime", &tags::RecordedRTControlPointDateTime),
+        ("studyarrivaltime", &tags::StudyArrivalTime),
+        ("showgrayscaleinverted", &tags::ShowGrayscaleInverted),
+        ("displayedareatoplefthandcorner_trial", &tags::DisplayedAreaTopLeftHandCorner_Trial),
+        ("segmentedredpalettecolorlookuptabledata", &tags::SegmentedRedPaletteColorLookupTableData),
+        ("nominalbeamangle", &tags::NominalBeamAngle),
+        ("numberofsurfacepoints", &tags::NumberofSurfacePoints),
+        ("acquisitionmatrix", &tags::AcquisitionMatrix),
+        ("referencedcurvesequence", &tags::ReferencedCurveSequence),
+        ("wedgemanufacturername", &tags::WedgeManufacturerName),
+        ("sopinstanceuidofconcatenationsource", &tags::SOPInstanceUIDofConcatenationSource),
+        ("angularviewvector", &tags::AngularViewVector),
         ("tabletoprollrotationdirection", &tags::TableTopRollRotationDirection),
-        ("ophthalmicaxiallengthqualitymetrictypecodesequence", &tags::OphthalmicAxialLengthQualityMetricTypeCodeSequence),
-        ("coordinatesystemaxistype", &tags::CoordinateSystemAxisType),
-        ("coordinatesystemdatasetmapping", &tags::CoordinateSystemDataSetMapping),
-        ("channelimpedancesequence", &tags::ChannelImpedanceSequence),
-        ("pixelpaddingvalue", &tags::PixelPaddingValue),
-        ("imagetype", &tags::ImageType),
-        ("userselectedoffsetx", &tags::UserSelectedOffsetX),
-        ("pupilcentroidycoordinate", &tags::PupilCentroidYCoordinate),
-        ("multienergyctcharacteristicssequence", &tags::MultienergyCTCharacteristicsSequence),
-        ("maximumstimulusluminance", &tags::MaximumStimulusLuminance),
-        ("overlayorimagemagnification", &tags::OverlayorImageMagnification),
-        ("shorttermfluctuation", &tags::ShortTermFluctuation),
-        ("scattercorrected", &tags::ScatterCorrected),
-        ("referencedoverlaysequence", &tags::ReferencedOverlaySequence),
-        ("requestedproceduredescription", &tags::RequestedProcedureDescription),
-        ("segmentsequence", &tags::SegmentSequence),
-        ("sourceapplicatornumber", &tags::SourceApplicatorNumber),
-        ("algorithmversion", &tags::AlgorithmVersion),
-        ("beamdosepointssd", &tags::BeamDosePointSSD),
+        ("tableangle", &tags::TableAngle),
+        ("dacamplitude", &tags::DACAmplitude),
+        ("radiationgenerationmodeindex", &tags::RadiationGenerationModeIndex),
+        ("filesetdescriptorfileid", &tags::FilesetDescriptorFileID),
+        ("opticalpathdescription", &tags::OpticalPathDescription),
+        ("signaldomaincolumns", &tags::SignalDomainColumns),
+        ("compressionforce", &tags::CompressionForce),
+        ("usimagedescriptionsequence", &tags::USImageDescriptionSequence),
+        ("wedgematerialvelocity", &tags::WedgeMaterialVelocity),
+        ("applicatordescription", &tags::ApplicatorDescription),
+        ("overalltemplatespatialtolerance", &tags::OverallTemplateSpatialTolerance),
+        ("retinalthicknessdefinitioncodesequence", &tags::RetinalThicknessDefinitionCodeSequence),
+        ("timeofflightcontrast", &tags::TimeofFlightContrast),
+        ("graphiclayersequence", &tags::GraphicLayerSequence),
+        ("namesofintendedrecipientsofresults", &tags::NamesofIntendedRecipientsofResults),
+        ("issuetimeofimagingservicerequest", &tags::IssueTimeofImagingServiceRequest),
+        ("fractiongroupsummarysequence", &tags::FractionGroupSummarySequence),
+        ("hpglpendescription", &tags::HPGLPenDescription),
+        ("tomotherapeuticleafopendurations", &tags::TomotherapeuticLeafOpenDurations),
+        ("cornealpointestimated", &tags::CornealPointEstimated),
+        ("dimensionindexvalues", &tags::DimensionIndexValues),
+        ("requestedsopclassuid", &tags::RequestedSOPClassUID),
+        ("rtradiationphysicalandgeometriccontentdetailflag", &tags::RTRadiationPhysicalandGeometricContentDetailFlag),
+        ("contourslabthickness", &tags::ContourSlabThickness),
+        ("graphicdimensions", &tags::GraphicDimensions),
+        ("generalaccessorydescription", &tags::GeneralAccessoryDescription),
+        ("calibrationreferencepointdepth", &tags::CalibrationReferencePointDepth),
+        ("interpretationtranscriptiontime", &tags::InterpretationTranscriptionTime),
+        ("temporalpositionsequence", &tags::TemporalPositionSequence),
+        ("sourcemanufacturer", &tags::SourceManufacturer),
+        ("sourceofophthalmicaxiallengthcodesequence", &tags::SourceofOphthalmicAxialLengthCodeSequence),
+        ("brachytreatmenttechnique", &tags::BrachyTreatmentTechnique),
+        ("digitalsignaturedatetime", &tags::DigitalSignatureDateTime),
+        ("therapeuticroletypecodesequence", &tags::TherapeuticRoleTypeCodeSequence),
+        ("fovealpointprobabilityvalue", &tags::FovealPointProbabilityValue),
+        ("generalizeddefectsensitivitydeviationalgorithmsequence", &tags::GeneralizedDefectSensitivityDeviationAlgorithmSequence),
+        ("rtroiobservationssequence", &tags::RTROIObservationsSequence),
+        ("numberofreferences", &tags::NumberofReferences),
+        ("dvhtype", &tags::DVHType),
+        ("specimenpreparationsequence", &tags::SpecimenPreparationSequence),
+        ("sourceconceptualvolumeuid", &tags::SourceConceptualVolumeUID),
+        ("revolutiontime", &tags::RevolutionTime),
+        ("fixationdevicerollangle", &tags::FixationDeviceRollAngle),
+        ("gridfocaldistance", &tags::GridFocalDistance),
+        ("slideidentifier", &tags::SlideIdentifier),
+        ("deliveryverificationimagesequence", &tags::DeliveryVerificationImageSequence),
+        ("isocentertobeamlimitingdevicedistance", &tags::IsocentertoBeamLimitingDeviceDistance),
+        ("dateoflastdetectorcalibration", &tags::DateofLastDetectorCalibration),
+        ("dvhminimumdose", &tags::DVHMinimumDose),
+        ("imagedvolumewidth", &tags::ImagedVolumeWidth),
+        ("compensatorcolumnoffset", &tags::CompensatorColumnOffset),
+        ("shieldingdevicesequence", &tags::ShieldingDeviceSequence),
+        ("filesetconsistencyflag", &tags::FilesetConsistencyFlag),
+        ("wedgetimedelay", &tags::WedgeTimeDelay),
         ("routeid", &tags::RouteID),
-        ("fixationdevicesequence", &tags::FixationDeviceSequence),
-        ("referringphysicianstelephonenumbers", &tags::ReferringPhysiciansTelephoneNumbers),
-        ("proberesistance", &tags::ProbeResistance),
-        ("multienergyctpathindex", &tags::MultienergyCTPathIndex),
-        ("rtprescriptionlabel", &tags::RTPrescriptionLabel),
-        ("reconstructionindex", &tags::ReconstructionIndex),
-        ("channelshieldsequence", &tags::ChannelShieldSequence),
-        ("referencedcontrolpointsequence", &tags::ReferencedControlPointSequence),
-        ("modalityluttype", &tags::ModalityLUTType),
-        ("doubleexposureordering", &tags::DoubleExposureOrdering),
-        ("sourceapplicatorlength", &tags::SourceApplicatorLength),
-        ("intraocularlenscalculationslefteyesequence", &tags::IntraocularLensCalculationsLeftEyeSequence),
-        ("gaincorrectionreferencesequence", &tags::GainCorrectionReferenceSequence),
-        ("acquisitiontasksequence", &tags::AcquisitionTaskSequence),
-        ("patientphysiologicalstatesequence", &tags::PatientPhysiologicalStateSequence),
-        ("selectorsequencepointer", &tags::SelectorSequencePointer),
-        ("scheduledprocedurestependtime", &tags::ScheduledProcedureStepEndTime),
-        ("exposurebiasvalue", &tags::ExposureBiasValue),
-        ("carmpositionertabletoprelationship", &tags::CarmPositionerTabletopRelationship),
-        ("mrmetabolitemapsequence", &tags::MRMetaboliteMapSequence),
-        ("clinicaltrialseriesdescription", &tags::ClinicalTrialSeriesDescription),
-        ("rtplanname", &tags::RTPlanName),
-        ("ophthalmicaxiallengthqualitymetricsequence", &tags::OphthalmicAxialLengthQualityMetricSequence),
-        ("consultingphysicianidentificationsequence", &tags::ConsultingPhysicianIdentificationSequence),
-        ("parallelrtbeamdelimiterleafmountingside", &tags::ParallelRTBeamDelimiterLeafMountingSide),
-        ("transactionuid", &tags::TransactionUID),
-        ("parameteritemindex", &tags::ParameterItemIndex),
-        ("implantassemblytemplatetype", &tags::ImplantAssemblyTemplateType),
-        ("rtplandate", &tags::RTPlanDate),
-        ("lightpathfiltertypestackcodesequence", &tags::LightPathFilterTypeStackCodeSequence),
-        ("secondarycapturedevicesoftwareversions", &tags::SecondaryCaptureDeviceSoftwareVersions),
-        ("sourceapplicatortiplength", &tags::SourceApplicatorTipLength),
-        ("acquisitionnumber", &tags::AcquisitionNumber),
-        ("responsiblepersonrole", &tags::ResponsiblePersonRole),
-        ("dvhdosescaling", &tags::DVHDoseScaling),
-        ("warningreason", &tags::WarningReason),
-        ("numberofremainingsuboperations", &tags::NumberofRemainingSuboperations),
-        ("performedprocedurestepdiscontinuationreasoncodesequence", &tags::PerformedProcedureStepDiscontinuationReasonCodeSequence),
-        ("radiationgenerationmodedescription", &tags::RadiationGenerationModeDescription),
-        ("registeredlocalizertoplefthandcorner", &tags::RegisteredLocalizerTopLeftHandCorner),
-        ("imageposition", &tags::ImagePosition),
-        ("privatedataelement", &tags::PrivateDataElement),
-        ("nominalbeamenergyunit", &tags::NominalBeamEnergyUnit),
-        ("dosereferencedescription", &tags::DoseReferenceDescription),
-        ("dimensiondescriptionlabel", &tags::DimensionDescriptionLabel),
-        ("firstorderphasecorrection", &tags::FirstOrderPhaseCorrection),
-        ("contrastbolusadministrationroutesequence", &tags::ContrastBolusAdministrationRouteSequence),
-        ("gpsimgdirectionref", &tags::GPSImgDirectionRef),
-        ("imagesetselectorsequence", &tags::ImageSetSelectorSequence),
-        ("channelminimumvalue", &tags::ChannelMinimumValue),
-        ("averagebeamdosepointsourcetoexternalcontourdistance", &tags::AverageBeamDosePointSourcetoExternalContourDistance),
-        ("largestmonochromepixelvalue", &tags::LargestMonochromePixelValue),
-        ("softtissuesurfacethermalindex", &tags::SoftTissuesurfaceThermalIndex),
-        ("screeningtestmodecodesequence", &tags::ScreeningTestModeCodeSequence),
-        ("sourceencapsulationnominaltransmission", &tags::SourceEncapsulationNominalTransmission),
-        ("cardiacnumberofimages", &tags::CardiacNumberofImages),
-        ("patientsmothersbirthname", &tags::PatientsMothersBirthName),
-        ("reasonforsuperseding", &tags::ReasonforSuperseding),
-        ("selectordavalue", &tags::SelectorDAValue),
-        ("elementdimensionb", &tags::ElementDimensionB),
-        ("shininess", &tags::Shininess),
-        ("xdsretrievalsequence", &tags::XDSRetrievalSequence),
-        ("shotoffsettime", &tags::ShotOffsetTime),
-        ("rttolerancesetsequence", &tags::RTToleranceSetSequence),
-        ("iolpower", &tags::IOLPower),
-        ("continuationendmeterset", &tags::ContinuationEndMeterset),
-        ("transducerpositionsequence", &tags::TransducerPositionSequence),
-        ("targetprescriptiondose", &tags::TargetPrescriptionDose),
-        ("specificcharacterset", &tags::SpecificCharacterSet),
-        ("lenssegmenttype", &tags::LensSegmentType),
-        ("commandfield", &tags::CommandField),
-        ("patientstate", &tags::PatientState),
-        ("topicauthor", &tags::TopicAuthor),
-        ("collimatorshape", &tags::CollimatorShape),
-        ("respiratorysynchronizationsequence", &tags::RespiratorySynchronizationSequence),
-        ("acceleration", &tags::Acceleration),
-        ("observationcategorycodesequence_trial", &tags::ObservationCategoryCodeSequence_Trial),
-        ("segmentidentificationsequence", &tags::SegmentIdentificationSequence),
-        ("address_trial", &tags::Address_Trial),
-        ("eventtypeid", &tags::EventTypeID),
+        ("lightpathfilterpassthroughwavelength", &tags::LightPathFilterPassThroughWavelength),
+        ("referencelocationdescription", &tags::ReferenceLocationDescription),
+        ("thresholdvalue", &tags::ThresholdValue),
+        ("overlayorimagemagnification", &tags::OverlayorImageMagnification),
+        ("shorttermfluctuationcalculated", &tags::ShortTermFluctuationCalculated),
+        ("straincodesequence", &tags::StrainCodeSequence),
+        ("maximumnumberofrecords", &tags::MaximumNumberofRecords),
+        ("screeningbaselinemeasured", &tags::ScreeningBaselineMeasured),
+        ("wedgerearheight", &tags::WedgeRearHeight),
+        ("tmlinepositionx0", &tags::TMLinePositionX0),
+        ("environmentalconditions", &tags::EnvironmentalConditions),
+        ("transmittransducersequence", &tags::TransmitTransducerSequence),
+        ("effectivedosecalculationmethoddescription", &tags::EffectiveDoseCalculationMethodDescription),
+        ("tag_2dlinecoordinatessequence", &tags::Tag_2DLineCoordinatesSequence),
+        ("histogramlastbinvalue", &tags::HistogramLastBinValue),
+        ("targetmaximumluminance", &tags::TargetMaximumLuminance),
+        ("folderaccessuri", &tags::FolderAccessURI),
+        ("mrtimingandrelatedparameterssequence", &tags::MRTimingandRelatedParametersSequence),
+        ("cornealsize", &tags::CornealSize),
+        ("rtimageplane", &tags::RTImagePlane),
+        ("admittingdate", &tags::AdmittingDate),
+        ("bloodsignalnulling", &tags::BloodSignalNulling),
+        ("numberofslices", &tags::NumberofSlices),
+        ("pixelspacingcalibrationdescription", &tags::PixelSpacingCalibrationDescription),
+        ("referencedoverlaysequence", &tags::ReferencedOverlaySequence),
+        ("shapetype", &tags::ShapeType),
+        ("addpower", &tags::AddPower),
+        ("contourimagesequence", &tags::ContourImageSequence),
+        ("selectorstvalue", &tags::SelectorSTValue),
+        ("leftlenssequence", &tags::LeftLensSequence),
+        ("exposuresequence", &tags::ExposureSequence),
+        ("issuerofserviceepisodeid", &tags::IssuerofServiceEpisodeID),
+        ("scopeofinventorysequence", &tags::ScopeofInventorySequence),
+        ("intravascularoctframetypesequence", &tags::IntravascularOCTFrameTypeSequence),
+        ("centerofcircularcollimator", &tags::CenterofCircularCollimator),
+        ("parallelrtbeamdelimiterdevicesequence", &tags::ParallelRTBeamDelimiterDeviceSequence),
+        ("acquisitiontaskworkitemcodesequence", &tags::AcquisitionTaskWorkitemCodeSequence),
+        ("tissuelocation", &tags::TissueLocation),
+        ("presentationpixelaspectratio", &tags::PresentationPixelAspectRatio),
+        ("numberofwarningsuboperations", &tags::NumberofWarningSuboperations),
+        ("commanddatasettype", &tags::CommandDataSetType),
+        ("acquiredimageareadoseproduct", &tags::AcquiredImageAreaDoseProduct),
+        ("fractiongroupnumber", &tags::FractionGroupNumber),
+        ("totalpixelmatrixfocalplanes", &tags::TotalPixelMatrixFocalPlanes),
+        ("exposurecontrolsensingregionleftverticaledge", &tags::ExposureControlSensingRegionLeftVerticalEdge),
+        ("displaysubsystemname", &tags::DisplaySubsystemName),
+        ("respiratorycycleposition", &tags::RespiratoryCyclePosition),
+        ("mechanicalindex", &tags::MechanicalIndex),
+        ("scanoptions", &tags::ScanOptions),
+        ("spectroscopyacquisitionphaserows", &tags::SpectroscopyAcquisitionPhaseRows),
+        ("radiusofcircularcollimator", &tags::RadiusofCircularCollimator),
+        ("multiplecopiesflag", &tags::MultipleCopiesFlag),
+        ("acquisitionfieldofviewlabel", &tags::AcquisitionFieldofViewLabel),
+        ("devicespecificacquisitionparametersequence", &tags::DeviceSpecificAcquisitionParameterSequence),
+        ("channelsequence", &tags::ChannelSequence),
+        ("deliveredtreatmenttime", &tags::DeliveredTreatmentTime),
+        ("rtaccessoryholderslotsequence", &tags::RTAccessoryHolderSlotSequence),
+        ("reconstructionstartlocationsequence", &tags::ReconstructionStartLocationSequence),
+        ("radiationdoseidentificationindex", &tags::RadiationDoseIdentificationIndex),
+        ("thresholdtype", &tags::ThresholdType),
+        ("interpretationrecordeddate", &tags::InterpretationRecordedDate),
+        ("clinicaltrialprotocolid", &tags::ClinicalTrialProtocolID),
+        ("recommendedabsentpixelcielabvalue", &tags::RecommendedAbsentPixelCIELabValue),
+        ("purposeofreferencecodesequence", &tags::PurposeofReferenceCodeSequence),
+        ("acquisitionsignaltype", &tags::AcquisitionSignalType),
+        ("exposuresonplate", &tags::ExposuresonPlate),
+        ("breastsupportxpositiontoisocenter", &tags::BreastSupportXPositiontoIsocenter),
+        ("retestsensitivityvalue", &tags::RetestSensitivityValue),
+        ("specifiedtreatmenttime", &tags::SpecifiedTreatmentTime),
+        ("detectorvector", &tags::DetectorVector),
+        ("assessmentsetid", &tags::AssessmentSetID),
+        ("procedurecreationdate", &tags::ProcedureCreationDate),
+        ("shutterspeedvalue", &tags::ShutterSpeedValue),
+        ("terminationcountsthreshold", &tags::TerminationCountsThreshold),
+        ("substanceadministrationparametersequence", &tags::SubstanceAdministrationParameterSequence),
         ("distributiontype", &tags::DistributionType),
-        ("addintermediatesequence", &tags::AddIntermediateSequence),
-        ("patientidentityremoved", &tags::PatientIdentityRemoved),
-        ("referencedrtplansequence", &tags::ReferencedRTPlanSequence),
-        ("pulsernotes", &tags::PulserNotes),
-        ("activeimageareaoverlaygroup", &tags::ActiveImageAreaOverlayGroup),
-        ("switchingphasenominalduration", &tags::SwitchingPhaseNominalDuration),
-        ("summarizedfilterlookuptablesequence", &tags::SummarizedFilterLookupTableSequence),
-        ("spatialpresaturation", &tags::SpatialPresaturation),
-        ("acquisitionstartlocationsequence", &tags::AcquisitionStartLocationSequence),
-        ("studypriorityid", &tags::StudyPriorityID),
+        ("steepkeratometricaxissequence", &tags::SteepKeratometricAxisSequence),
+        ("collimatorrightverticaledge", &tags::CollimatorRightVerticalEdge),
+        ("tableframeofreferenceuid", &tags::TableFrameofReferenceUID),
+        ("xrayoutput", &tags::XRayOutput),
+        ("allowlossycompression", &tags::AllowLossyCompression),
+        ("preservecompositeinstancesaftermediacreation", &tags::PreserveCompositeInstancesAfterMediaCreation),
+        ("frameacquisitionsequence", &tags::FrameAcquisitionSequence),
+        ("generalaccessoryid", &tags::GeneralAccessoryID),
+        ("modifyingsystem", &tags::ModifyingSystem),
+        ("numberofassessmentobservations", &tags::NumberofAssessmentObservations),
+        ("referencedreferenceimagesequence", &tags::ReferencedReferenceImageSequence),
+        ("routesegmentstarttime", &tags::RouteSegmentStartTime),
+        ("custodialorganizationsequence", &tags::CustodialOrganizationSequence),
+        ("scanspotreorderingallowed", &tags::ScanSpotReorderingAllowed),
+        ("mrreceivecoilsequence", &tags::MRReceiveCoilSequence),
         ("xraysourceid", &tags::XRaySourceID),
-        ("blocktrayid", &tags::BlockTrayID),
-        ("pixelcomponentmask", &tags::PixelComponentMask),
-        ("linesequence", &tags::LineSequence),
-        ("referencedrtprescriptionindex", &tags::ReferencedRTPrescriptionIndex),
-        ("xaacquisitionduration", &tags::XAAcquisitionDuration),
-        ("iccprofile", &tags::ICCProfile),
-        ("specularreflectionintensity", &tags::SpecularReflectionIntensity),
-        ("transmitterfrequency", &tags::TransmitterFrequency),
-        ("averagebeamdosepointssd", &tags::AverageBeamDosePointSSD),
-        ("lateralspreadingdeviceid", &tags::LateralSpreadingDeviceID),
-        ("bitsgrouped", &tags::BitsGrouped),
-        ("fixationlightpolarangle", &tags::FixationLightPolarAngle),
-        ("filterbyattributepresence", &tags::FilterbyAttributePresence),
-        ("selectedframefunctionalgroupssequence", &tags::SelectedFrameFunctionalGroupsSequence),
-        ("patienttreatmentpreparationmethoddescription", &tags::PatientTreatmentPreparationMethodDescription),
-        ("selectorssvalue", &tags::SelectorSSValue),
-        ("doublefloatpixelpaddingrangelimit", &tags::DoubleFloatPixelPaddingRangeLimit),
-        ("referencedobservationclass_trial", &tags::ReferencedObservationClass_Trial),
-        ("segmentedrtaccessorydevicesequence", &tags::SegmentedRTAccessoryDeviceSequence),
-        ("reasonforvisitcodesequence", &tags::ReasonforVisitCodeSequence),
-        ("userselectedoffsety", &tags::UserSelectedOffsetY),
-        ("intraocularlenscalculationsrighteyesequence", &tags::IntraocularLensCalculationsRightEyeSequence),
-        ("annotationgroupuid", &tags::AnnotationGroupUID),
-        ("equipmentframeofreferenceuid", &tags::EquipmentFrameofReferenceUID),
-        ("potentialrequestedprocedurecodesequence", &tags::PotentialRequestedProcedureCodeSequence),
-        ("recordedrangemodulatorsequence", &tags::RecordedRangeModulatorSequence),
-        ("lowenergydetectors", &tags::LowEnergyDetectors),
-        ("numberofdisplaysubsystems", &tags::NumberofDisplaySubsystems),
-        ("shadowcolorcielabvalue", &tags::ShadowColorCIELabValue),
-        ("exposureinmas", &tags::ExposureinmAs),
-        ("refractivestatesequence", &tags::RefractiveStateSequence),
-        ("positionerprimaryangledirection", &tags::PositionerPrimaryAngleDirection),
-        ("soundpathlength", &tags::SoundPathLength),
-        ("functionalmrsequence", &tags::FunctionalMRSequence),
-        ("acquisitionsubtaskindex", &tags::AcquisitionSubtaskIndex),
-        ("aitdevicetype", &tags::AITDeviceType),
+        ("updatedmetadatasequence", &tags::UpdatedMetadataSequence),
+        ("applicationsetupsequence", &tags::ApplicationSetupSequence),
+        ("numberofcontrolpoints", &tags::NumberofControlPoints),
+        ("requestedprocedureid", &tags::RequestedProcedureID),
+        ("ooiownercreationtime", &tags::OOIOwnerCreationTime),
+        ("userselectedoffsetx", &tags::UserSelectedOffsetX),
+        ("reasonfortheimagingservicerequest", &tags::ReasonfortheImagingServiceRequest),
+        ("requestedresolutionid", &tags::RequestedResolutionID),
+        ("terminationrespiratorytriggercountthreshold", &tags::TerminationRespiratoryTriggerCountThreshold),
+        ("omittedchannelsequence", &tags::OmittedChannelSequence),
+        ("xraytubecurrent", &tags::XRayTubeCurrent),
+        ("overlaydate", &tags::OverlayDate),
+        ("correctedlocalizeddeviationfromnormal", &tags::CorrectedLocalizedDeviationFromNormal),
+        ("topicsubject", &tags::TopicSubject),
+        ("targetluminancecharacteristicssequence", &tags::TargetLuminanceCharacteristicsSequence),
+        ("originalimplantassemblytemplatesequence", &tags::OriginalImplantAssemblyTemplateSequence),
         ("proceduretypecodesequence", &tags::ProcedureTypeCodeSequence),
-        ("intervalsacquired", &tags::IntervalsAcquired),
-        ("contrastbolusagentphase", &tags::ContrastBolusAgentPhase),
-        ("storedinstancebaseuri", &tags::StoredInstanceBaseURI),
-        ("contextgroupextensionflag", &tags::ContextGroupExtensionFlag),
-        ("measureddosevalue", &tags::MeasuredDoseValue),
-        ("fiducialuid", &tags::FiducialUID),
-        ("rtdeliverystartpatientpositionsequence", &tags::RTDeliveryStartPatientPositionSequence),
-        ("pulsesequencename", &tags::PulseSequenceName),
-        ("averagepulsewidth", &tags::AveragePulseWidth),
-        ("resultsidissuer", &tags::ResultsIDIssuer),
-        ("beamdose", &tags::BeamDose),
-        ("diffusionanisotropytype", &tags::DiffusionAnisotropyType),
-        ("patientmotioncorrected", &tags::PatientMotionCorrected),
-        ("treatmentpositiongrouplabel", &tags::TreatmentPositionGroupLabel),
+        ("variablecoefficientssdvn", &tags::VariableCoefficientsSDVN),
+        ("nominalpercentageofcardiacphase", &tags::NominalPercentageofCardiacPhase),
+        ("pulserepetitionfrequency", &tags::PulseRepetitionFrequency),
+        ("textstring", &tags::TextString),
+        ("tolerancetablelabel", &tags::ToleranceTableLabel),
+        ("radiusofcircularexposurecontrolsensingregion", &tags::RadiusofCircularExposureControlSensingRegion),
+        ("contrastbolusstoptime", &tags::ContrastBolusStopTime),
+        ("reteststimulusseen", &tags::RetestStimulusSeen),
+        ("applicatoropeningx", &tags::ApplicatorOpeningX),
+        ("deletionlock", &tags::DeletionLock),
+        ("fieldofviewdescription", &tags::FieldofViewDescription),
+        ("beltheight", &tags::BeltHeight),
+        ("sar", &tags::SAR),
+        ("beatrejectionflag", &tags::BeatRejectionFlag),
+        ("reasonforrequestedprocedurecodesequence", &tags::ReasonforRequestedProcedureCodeSequence),
+        ("diffusionbvalue", &tags::Diffusionbvalue),
+        ("scheduledprocedurestepstatus", &tags::ScheduledProcedureStepStatus),
+        ("exposureinmas", &tags::ExposureinmAs),
+        ("ooiownersequence", &tags::OOIOwnerSequence),
+        ("gantryid", &tags::GantryID),
+        ("endcumulativemetersetweight", &tags::EndCumulativeMetersetWeight),
+        ("brachyapplicationsetupdosespecificationpoint", &tags::BrachyApplicationSetupDoseSpecificationPoint),
+        ("gpslongituderef", &tags::GPSLongitudeRef),
+        ("languagecodesequence_trial", &tags::LanguageCodeSequence_Trial),
+        ("falsenegativesestimateflag", &tags::FalseNegativesEstimateFlag),
+        ("threatroibase", &tags::ThreatROIBase),
+        ("gpsspeed", &tags::GPSSpeed),
+        ("compressionsequence", &tags::CompressionSequence),
+        ("acquisitionnumber", &tags::AcquisitionNumber),
+        ("excitationpulseduration", &tags::ExcitationPulseDuration),
+        ("tmlinepositiony1", &tags::TMLinePositionY1),
+        ("cylinderlenspower", &tags::CylinderLensPower),
+        ("imageprocessingapplied", &tags::ImageProcessingApplied),
+        ("acquisitioncontrast", &tags::AcquisitionContrast),
+        ("anatomicperspectivedescription_trial", &tags::AnatomicPerspectiveDescription_Trial),
+        ("anatomiclocationofexamininginstrumentdescription_trial", &tags::AnatomicLocationOfExaminingInstrumentDescription_Trial),
+        ("interventionsequence", &tags::InterventionSequence),
+        ("replacedprocedurestepsequence", &tags::ReplacedProcedureStepSequence),
+        ("scheduledprocedurestepstartdate", &tags::ScheduledProcedureStepStartDate),
+        ("xraydetectorlabel", &tags::XRayDetectorLabel),
+        ("diffusionbvaluexx", &tags::DiffusionbvalueXX),
+        ("emmetropicmagnification", &tags::EmmetropicMagnification),
+        ("cassettesize", &tags::CassetteSize),
+        ("horizontalfieldofview", &tags::HorizontalFieldofView),
+        ("predictedtoricerrorsequence", &tags::PredictedToricErrorSequence),
+        ("numberofboli", &tags::NumberofBoli),
+        ("radiationorderindex", &tags::RadiationOrderIndex),
+        ("digitalsignaturepurposecodesequence", &tags::DigitalSignaturePurposeCodeSequence),
+        ("hpglpennumber", &tags::HPGLPenNumber),
+        ("rtradiationusage", &tags::RTRadiationUsage),
+        ("affectedsopclassuid", &tags::AffectedSOPClassUID),
+        ("overlayforegrounddensity", &tags::OverlayForegroundDensity),
+        ("countryofresidence", &tags::CountryofResidence),
+        ("saturationrecovery", &tags::SaturationRecovery),
+        ("elementdimensionb", &tags::ElementDimensionB),
+        ("effectivewedgeangle", &tags::EffectiveWedgeAngle),
+        ("stimulusresults", &tags::StimulusResults),
+        ("originalattributessequence", &tags::OriginalAttributesSequence),
+        ("masksubpixelshift", &tags::MaskSubpixelShift),
+        ("tabletopeccentricangle", &tags::TableTopEccentricAngle),
+        ("treatmentmachinespecialmodecodesequence", &tags::TreatmentMachineSpecialModeCodeSequence),
+        ("doublepointcoordinatesdata", &tags::DoublePointCoordinatesData),
+        ("implantassemblytemplatetype", &tags::ImplantAssemblyTemplateType),
+        ("roicontoursequence", &tags::ROIContourSequence),
+        ("referencedstoragemediasequence", &tags::ReferencedStorageMediaSequence),
+        ("roiobservationlabel", &tags::ROIObservationLabel),
+        ("ptoregionsequence", &tags::PTORegionSequence),
+        ("translationratey", &tags::TranslationRateY),
+        ("interpretationdiagnosiscodesequence", &tags::InterpretationDiagnosisCodeSequence),
+        ("masksubtractionsequence", &tags::MaskSubtractionSequence),
+        ("quantity", &tags::Quantity),
+        ("diffusiondirectionality", &tags::DiffusionDirectionality),
+        ("segmentidentificationsequence", &tags::SegmentIdentificationSequence),
+        ("documentauthor_trial", &tags::DocumentAuthor_Trial),
+        ("selectoruivalue", &tags::SelectorUIValue),
+        ("fixationmethodcodesequence", &tags::FixationMethodCodeSequence),
+        ("overlayactivationlayer", &tags::OverlayActivationLayer),
+        ("sourcemodelid", &tags::SourceModelID),
+        ("rtimageframeimagingdevicepositionsequence", &tags::RTImageFrameImagingDevicePositionSequence),
+        ("rowpositionintotalimagepixelmatrix", &tags::RowPositionInTotalImagePixelMatrix),
+        ("snoutid", &tags::SnoutID),
+        ("tag_3drenderingtype", &tags::Tag_3DRenderingType),
+        ("alarmdecisiontime", &tags::AlarmDecisionTime),
+        ("pulsewidth", &tags::PulseWidth),
+        ("firsttreatmentdate", &tags::FirstTreatmentDate),
+        ("printjobid", &tags::PrintJobID),
+        ("offendingelement", &tags::OffendingElement),
+        ("maximummemoryallocation", &tags::MaximumMemoryAllocation),
+        ("sourceinstancesequence", &tags::SourceInstanceSequence),
+        ("dvhnumberofbins", &tags::DVHNumberofBins),
+        ("tomoangle", &tags::TomoAngle),
+        ("longitudinaltemporaloffsetfromevent", &tags::LongitudinalTemporalOffsetfromEvent),
+        ("filterhighfrequencycharacteristicssequence", &tags::FilterHighFrequencyCharacteristicsSequence),
+        ("imageandfluoroscopyareadoseproduct", &tags::ImageandFluoroscopyAreaDoseProduct),
+        ("selectorolvalue", &tags::SelectorOLValue),
+        ("effectiveechotime", &tags::EffectiveEchoTime),
+        ("scanlength", &tags::ScanLength),
+        ("radiationmachinesad", &tags::RadiationMachineSAD),
+        ("referencedobjectobservationclass_trial", &tags::ReferencedObjectObservationClass_Trial),
+        ("cardiacrrintervalspecified", &tags::CardiacRRIntervalSpecified),
+        ("performedprocedurestepid", &tags::PerformedProcedureStepID),
+        ("codingschemeuid", &tags::CodingSchemeUID),
+        ("dimensiondescriptionlabel", &tags::DimensionDescriptionLabel),
+        ("ticklabel", &tags::TickLabel),
+        ("mydriaticagentsequence", &tags::MydriaticAgentSequence),
+        ("autokvpupperbound", &tags::AutoKVPUpperBound),
+        ("notchfilterbandwidth", &tags::NotchFilterBandwidth),
+        ("structuresetroisequence", &tags::StructureSetROISequence),
+        ("conceptualvolumeidentificationsequence", &tags::ConceptualVolumeIdentificationSequence),
+        ("iolformuladetail", &tags::IOLFormulaDetail),
+        ("transformversionnumber", &tags::TransformVersionNumber),
+        ("overlayformat", &tags::OverlayFormat),
+        ("rtimagescopesequence", &tags::RTImageScopeSequence),
+        ("materialpropertiesfileformat", &tags::MaterialPropertiesFileFormat),
+        ("requestedprocedurecomments", &tags::RequestedProcedureComments),
+        ("specimenaccessionnumber", &tags::SpecimenAccessionNumber),
+        ("largeredpalettecolorlookuptabledescriptor", &tags::LargeRedPaletteColorLookupTableDescriptor),
+        ("codingschemedesignator", &tags::CodingSchemeDesignator),
+        ("pixeldataarearotationanglerelativetofov", &tags::PixelDataAreaRotationAngleRelativeToFOV),
+        ("datasetname", &tags::DataSetName),
+        ("referencedfiducialsuid", &tags::ReferencedFiducialsUID),
+        ("imagedatalocation", &tags::ImageDataLocation),
+        ("measurementequipmentsequence", &tags::MeasurementEquipmentSequence),
+        ("secondaryreviewdate", &tags::SecondaryReviewDate),
+        ("attenuationcorrected", &tags::AttenuationCorrected),
+        ("globaldeviationprobabilitynormalsflag", &tags::GlobalDeviationProbabilityNormalsFlag),
+        ("studyid", &tags::StudyID),
+        ("repairid", &tags::RepairID),
+        ("colorfilterarraypatternrows", &tags::ColorFilterArrayPatternRows),
+        ("beamlimitingdeviceleafpairssequence", &tags::BeamLimitingDeviceLeafPairsSequence),
         ("presentationstateclassificationcomponentsequence", &tags::PresentationStateClassificationComponentSequence),
-        ("respiratoryintervaltime", &tags::RespiratoryIntervalTime),
-        ("gatedinformationsequence", &tags::GatedInformationSequence),
-        ("dosimetricobjectiveparametersequence", &tags::DosimetricObjectiveParameterSequence),
-        ("observationsubjecttypecodesequence_trial", &tags::ObservationSubjectTypeCodeSequence_Trial),
-        ("imagesetselectorusageflag", &tags::ImageSetSelectorUsageFlag),
-        ("acquisitiondevicetypecodesequence", &tags::AcquisitionDeviceTypeCodeSequence),
-        ("roiobservationdescription", &tags::ROIObservationDescription),
-        ("applicationsetuptype", &tags::ApplicationSetupType),
-        ("compositingmethod", &tags::CompositingMethod),
+        ("largestpixelvalueinseries", &tags::LargestPixelValueinSeries),
+        ("containercomponentdescription", &tags::ContainerComponentDescription),
+        ("selectorpnvalue", &tags::SelectorPNValue),
+        ("requestingphysicianidentificationsequence", &tags::RequestingPhysicianIdentificationSequence),
+        ("fileaccesssequence", &tags::FileAccessSequence),
+        ("waterequivalentdiametercalculationmethodcodesequence", &tags::WaterEquivalentDiameterCalculationMethodCodeSequence),
+        ("assessedattributevaluesequence", &tags::AssessedAttributeValueSequence),
+        ("filterbycategory", &tags::FilterbyCategory),
+        ("containeridentifier", &tags::ContainerIdentifier),
+        ("treatmentdeliverycontinuationflag", &tags::TreatmentDeliveryContinuationFlag),
+        ("selectordsvalue", &tags::SelectorDSValue),
+        ("othersecondaryapprovalstatus", &tags::OtherSecondaryApprovalStatus),
+        ("largestimagepixelvalue", &tags::LargestImagePixelValue),
+        ("issuerofthecontaineridentifiersequence", &tags::IssueroftheContainerIdentifierSequence),
+        ("relativexrayexposure", &tags::RelativeXRayExposure),
+        ("conceptualvolumeoptimizationprecedence", &tags::ConceptualVolumeOptimizationPrecedence),
+        ("longvertexpointindexlist", &tags::LongVertexPointIndexList),
+        ("imageboxnumber", &tags::ImageBoxNumber),
+        ("overridesequence", &tags::OverrideSequence),
+        ("positionerisocenterprimaryangle", &tags::PositionerIsocenterPrimaryAngle),
+        ("tangentialpower", &tags::TangentialPower),
+        ("componenttype", &tags::ComponentType),
+        ("combinationsegmentreferencesequence", &tags::CombinationSegmentReferenceSequence),
+        ("pausebetweenframes", &tags::PauseBetweenFrames),
+        ("flowtransfersyntaxuid", &tags::FlowTransferSyntaxUID),
+        ("onaxisbackgroundanatomicstructurecodesequence_trial", &tags::OnAxisBackgroundAnatomicStructureCodeSequence_Trial),
+        ("wedgepositionsequence", &tags::WedgePositionSequence),
+        ("referencedtreatmentpositionindex", &tags::ReferencedTreatmentPositionIndex),
+        ("referencedsurfacesequence", &tags::ReferencedSurfaceSequence),
+        ("dosereferencetype", &tags::DoseReferenceType),
+        ("implanttemplategrouptargetanatomysequence", &tags::ImplantTemplateGroupTargetAnatomySequence),
+        ("sourceisotopehalflife", &tags::SourceIsotopeHalfLife),
+        ("othermagnificationtypesavailable", &tags::OtherMagnificationTypesAvailable),
+        ("distancebetweenbscanslabs", &tags::DistanceBetweenBscanSlabs),
+        ("referenceddeviceindex", &tags::ReferencedDeviceIndex),
+        ("radionuclidetotaldose", &tags::RadionuclideTotalDose),
+        ("reviewername", &tags::ReviewerName),
+        ("sensingmethod", &tags::SensingMethod),
+        ("numberofluminancepoints", &tags::NumberofLuminancePoints),
+        ("beamholdoriginatingdevicesequence", &tags::BeamHoldOriginatingDeviceSequence),
+        ("temporalrelationshipintervalanchor", &tags::TemporalRelationshipIntervalAnchor),
+        ("devicemotionexecutionmode", &tags::DeviceMotionExecutionMode),
+        ("dischargedate", &tags::DischargeDate),
+        ("additionaldrugsequence", &tags::AdditionalDrugSequence),
+        ("time", &tags::Time),
+        ("compoundgraphicsequence", &tags::CompoundGraphicSequence),
+        ("preamplifiersettingssequence", &tags::PreAmplifierSettingsSequence),
+        ("scanspotmetersetsdelivered", &tags::ScanSpotMetersetsDelivered),
+        ("conceptcodesequence", &tags::ConceptCodeSequence),
+        ("exclusivecomponenttype", &tags::ExclusiveComponentType),
+        ("confirmationsequence", &tags::ConfirmationSequence),
+        ("tabletoplateralposition", &tags::TableTopLateralPosition),
+        ("itemdelimitationitem", &tags::ItemDelimitationItem),
+        ("timeslotvector", &tags::TimeSlotVector),
+        ("volumetriccurveupdirections", &tags::VolumetricCurveUpDirections),
+        ("sourceapplicatornumber", &tags::SourceApplicatorNumber),
+        ("lastmenstrualdate", &tags::LastMenstrualDate),
+        ("cumulativedosetodosereference", &tags::CumulativeDosetoDoseReference),
+        ("originalimageidentificationnomenclature", &tags::OriginalImageIdentificationNomenclature),
+        ("requestpriority", &tags::RequestPriority),
+        ("telephonenumber_trial", &tags::TelephoneNumber_Trial),
+        ("performedprocedurecodesequence", &tags::PerformedProcedureCodeSequence),
+        ("findingsgrouprecordingdate_trial", &tags::FindingsGroupRecordingDate_Trial),
+        ("treatmentdeliverytype", &tags::TreatmentDeliveryType),
+        ("mrimageframetypesequence", &tags::MRImageFrameTypeSequence),
+        ("radiopharmaceuticalagentnumber", &tags::RadiopharmaceuticalAgentNumber),
         ("opticalophthalmicaxiallengthmeasurementssequence", &tags::OpticalOphthalmicAxialLengthMeasurementsSequence),
+        ("currentrequestedprocedureevidencesequence", &tags::CurrentRequestedProcedureEvidenceSequence),
+        ("channeldeliveryordersequence", &tags::ChannelDeliveryOrderSequence),
+        ("samplesperpixel", &tags::SamplesperPixel),
+        ("dataelementssigned", &tags::DataElementsSigned),
+        ("scheduledpatientinstitutionresidence", &tags::ScheduledPatientInstitutionResidence),
+        ("bolusdefinitionsequence", &tags::BolusDefinitionSequence),
+        ("templateextensioncreatoruid", &tags::TemplateExtensionCreatorUID),
+        ("continuationpulsenumber", &tags::ContinuationPulseNumber),
+        ("acquisitionmode", &tags::AcquisitionMode),
+        ("tracksequence", &tags::TrackSequence),
+        ("acquisitiondevicetypecodesequence", &tags::AcquisitionDeviceTypeCodeSequence),
+        ("planoverviewindex", &tags::PlanOverviewIndex),
+        ("actualcardiactriggertimepriortorpeak", &tags::ActualCardiacTriggerTimePriorToRPeak),
+        ("alongscanspatialresolution", &tags::AlongscanSpatialResolution),
+        ("energywindowupperlimit", &tags::EnergyWindowUpperLimit),
+        ("referencedbasicannotationboxsequence", &tags::ReferencedBasicAnnotationBoxSequence),
+        ("breedregistrycodesequence", &tags::BreedRegistryCodeSequence),
+        ("safepositionexittime", &tags::SafePositionExitTime),
+        ("authoridentificationsequence", &tags::AuthorIdentificationSequence),
+        ("illuminationbandwidth", &tags::IlluminationBandwidth),
+        ("dateofinstallation", &tags::DateofInstallation),
+        ("signature", &tags::Signature),
+        ("numberofchannels", &tags::NumberofChannels),
+        ("sequencingindicator_trial", &tags::SequencingIndicator_Trial),
+        ("magnitudeencodingcodesequence", &tags::MagnitudeEncodingCodeSequence),
+        ("productexpirationdatetime", &tags::ProductExpirationDateTime),
+        ("cornealpointlocation", &tags::CornealPointLocation),
+        ("rescaleslope", &tags::RescaleSlope),
+        ("testpatterncodesequence", &tags::TestPatternCodeSequence),
+        ("compoundgraphicunits", &tags::CompoundGraphicUnits),
+        ("imageoverlayflag", &tags::ImageOverlayFlag),
+        ("visualevaluationresultsequence", &tags::VisualEvaluationResultSequence),
+        ("reconstructionfieldofview", &tags::ReconstructionFieldofView),
+        ("pulserepetitioninterval", &tags::PulseRepetitionInterval),
+        ("doseratedelivered", &tags::DoseRateDelivered),
+        ("planepositionslidesequence", &tags::PlanePositionSlideSequence),
+        ("luminanceresponsesequence", &tags::LuminanceResponseSequence),
+        ("tag_3ddegreeoffreedomaxis", &tags::Tag_3DDegreeofFreedomAxis),
+        ("rtradiationsequence", &tags::RTRadiationSequence),
+        ("sourcetosurfacedistance", &tags::SourcetoSurfaceDistance),
+        ("offsetdirection", &tags::OffsetDirection),
+        ("wedgeelement1position", &tags::WedgeElement1Position),
+        ("graphiclayer", &tags::GraphicLayer),
+        ("regionflags", &tags::RegionFlags),
+        ("volumelocalizationsequence", &tags::VolumeLocalizationSequence),
+        ("tabletopeccentricadjustedangle", &tags::TableTopEccentricAdjustedAngle),
+        ("doubleexposurefielddelta", &tags::DoubleExposureFieldDelta),
+        ("parametricmapframetypesequence", &tags::ParametricMapFrameTypeSequence),
+        ("bold", &tags::Bold),
+        ("ionblocksequence", &tags::IonBlockSequence),
+        ("illuminationcolorcodesequence", &tags::IlluminationColorCodeSequence),
+        ("codelabel", &tags::CodeLabel),
+        ("shotdurationtime", &tags::ShotDurationTime),
+        ("patientsupportdisplacementsequence", &tags::PatientSupportDisplacementSequence),
+        ("roidescription", &tags::ROIDescription),
+        ("filemetainformationgrouplength", &tags::FileMetaInformationGroupLength),
+        ("temperature", &tags::Temperature),
+        ("tag_2dimplanttemplategroupmembermatchingaxes", &tags::Tag_2DImplantTemplateGroupMemberMatchingAxes),
+        ("rtimageorientation", &tags::RTImageOrientation),
+        ("xraygeometrysequence", &tags::XRayGeometrySequence),
+        ("gpsimgdirection", &tags::GPSImgDirection),
+        ("brachyaccessorydeviceid", &tags::BrachyAccessoryDeviceID),
+        ("iterativereconstructionmethod", &tags::IterativeReconstructionMethod),
+        ("transducerorientationmodifiersequence", &tags::TransducerOrientationModifierSequence),
+        ("curverange", &tags::CurveRange),
         ("partialfourierdirection", &tags::PartialFourierDirection),
-        ("terminationtimethreshold", &tags::TerminationTimeThreshold),
-        ("bolusid", &tags::BolusID),
-        ("treatmenttoleranceviolationcausecodesequence", &tags::TreatmentToleranceViolationCauseCodeSequence),
-        ("radiationsourceaxisdistance", &tags::RadiationSourceAxisDistance),
-        ("sar", &tags::SAR),
-        ("finalcumulativemetersetweight", &tags::FinalCumulativeMetersetWeight),
-        ("largestpixelvalueinseries", &tags::LargestPixelValueinSeries),
-        ("triggertimeoffset", &tags::TriggerTimeOffset),
-        ("ctacquisitiontypesequence", &tags::CTAcquisitionTypeSequence),
-        ("xoffsetinslidecoordinatesystem", &tags::XOffsetinSlideCoordinateSystem),
-        ("storageurl", &tags::StorageURL),
-        ("alphapalettecolorlookuptabledescriptor", &tags::AlphaPaletteColorLookupTableDescriptor),
-        ("quantitysequence", &tags::QuantitySequence),
-        ("detectorcalibrationdata", &tags::DetectorCalibrationData),
-        ("scheduledspecimensequence", &tags::ScheduledSpecimenSequence),
-        ("otherstudynumbers", &tags::OtherStudyNumbers),
-        ("frameofinterestdescription", &tags::FrameofInterestDescription),
-        ("gpstrackref", &tags::GPSTrackRef),
-        ("numberofblockslabitems", &tags::NumberofBlockSlabItems),
-        ("analogfilterrolloff", &tags::AnalogFilterRollOff),
-        ("diffusionbvalueyz", &tags::DiffusionbvalueYZ),
-        ("redpalettecolorlookuptabledescriptor", &tags::RedPaletteColorLookupTableDescriptor),
-        ("refractiveerrorbeforerefractivesurgerycodesequence", &tags::RefractiveErrorBeforeRefractiveSurgeryCodeSequence),
-        ("stackid", &tags::StackID),
-        ("annotationcontentsequence", &tags::AnnotationContentSequence),
-        ("compensatorrows", &tags::CompensatorRows),
-        ("tissueheterogeneitycorrection", &tags::TissueHeterogeneityCorrection),
+        ("innerdiameter", &tags::InnerDiameter),
+        ("blocktype", &tags::BlockType),
+        ("ophthalmicframelocationsequence", &tags::OphthalmicFrameLocationSequence),
+        ("blockrows", &tags::BlockRows),
+        ("cassetteid", &tags::CassetteID),
+        ("failedsopinstanceuidlist", &tags::FailedSOPInstanceUIDList),
+        ("rangeshifterid", &tags::RangeShifterID),
+        ("decouplingchemicalshiftreference", &tags::DecouplingChemicalShiftReference),
+        ("treatmentpositiongrouplabel", &tags::TreatmentPositionGroupLabel),
+        ("patienttreatmentpreparationprocedureindex", &tags::PatientTreatmentPreparationProcedureIndex),
+        ("keratometerindex", &tags::KeratometerIndex),
+        ("numberoffractionsplanned", &tags::NumberofFractionsPlanned),
+        ("positionacquisitiontemplatecodesequence", &tags::PositionAcquisitionTemplateCodeSequence),
+        ("brachyreferenceddosereferencesequence", &tags::BrachyReferencedDoseReferenceSequence),
+        ("detectorpositioningtype", &tags::DetectorPositioningType),
+        ("sourceserialnumber", &tags::SourceSerialNumber),
+        ("compositingmethod", &tags::CompositingMethod),
+        ("axisunits", &tags::AxisUnits),
+        ("storedinstancetransfersyntaxuid", &tags::StoredInstanceTransferSyntaxUID),
+        ("seriesinstudy", &tags::SeriesinStudy),
+        ("segmentedpropertycategorycodesequence", &tags::SegmentedPropertyCategoryCodeSequence),
+        ("segmentannotationcategorycodesequence", &tags::SegmentAnnotationCategoryCodeSequence),
+        ("tablecradletiltangle", &tags::TableCradleTiltAngle),
+        ("requestingservicecodesequence", &tags::RequestingServiceCodeSequence),
+        ("startingrespiratoryamplitude", &tags::StartingRespiratoryAmplitude),
+        ("rangemodulatorsettingssequence", &tags::RangeModulatorSettingsSequence),
+        ("imagefilter", &tags::ImageFilter),
+        ("algorithmsource", &tags::AlgorithmSource),
+        ("excitationwavelengthsequence", &tags::ExcitationWavelengthSequence),
+        ("metersettodosemappingsequence", &tags::MetersettoDoseMappingSequence),
+        ("billingprocedurestepsequence", &tags::BillingProcedureStepSequence),
+        ("contrastbolusagentsequence", &tags::ContrastBolusAgentSequence),
+        ("consentforclinicaltrialusesequence", &tags::ConsentforClinicalTrialUseSequence),
+        ("excitationfrequency", &tags::ExcitationFrequency),
+        ("xrayfilterdetailssequence", &tags::XRayFilterDetailsSequence),
+        ("volumeframeofreferenceuid", &tags::VolumeFrameofReferenceUID),
+        ("ophthalmicaxiallengthvelocity", &tags::OphthalmicAxialLengthVelocity),
+        ("beamdeliverydurationlimit", &tags::BeamDeliveryDurationLimit),
+        ("exifversion", &tags::EXIFVersion),
+        ("configurationqaresultssequence", &tags::ConfigurationQAResultsSequence),
+        ("contextgroupidentificationsequence", &tags::ContextGroupIdentificationSequence),
+        ("conceptualvolumesegmentationreferencesequence", &tags::ConceptualVolumeSegmentationReferenceSequence),
+        ("optotypedetaileddefinition", &tags::OptotypeDetailedDefinition),
+        ("phasenumber", &tags::PhaseNumber),
+        ("tag_2dmatingaxes", &tags::Tag_2DMatingAxes),
+        ("lensthicknesssequence", &tags::LensThicknessSequence),
+        ("radiationfluencemodifiercodesequence", &tags::RadiationFluenceModifierCodeSequence),
+        ("soundspeedcorrectionmechanismcodesequence", &tags::SoundSpeedCorrectionMechanismCodeSequence),
+        ("surfaceprocessingdescription", &tags::SurfaceProcessingDescription),
+        ("resultsid", &tags::ResultsID),
+        ("imageformat", &tags::ImageFormat),
+        ("scheduledworkitemcodesequence", &tags::ScheduledWorkitemCodeSequence),
+        ("pregnancystatus", &tags::PregnancyStatus),
+        ("observationnumber", &tags::ObservationNumber),
+        ("aslcrusherflag", &tags::ASLCrusherFlag),
+        ("segmentsoverlap", &tags::SegmentsOverlap),
+        ("selectorisvalue", &tags::SelectorISValue),
+        ("presentationsequencecollectionuid", &tags::PresentationSequenceCollectionUID),
+        ("referencedvoilutboxsequence", &tags::ReferencedVOILUTBoxSequence),
+        ("meteringmode", &tags::MeteringMode),
+        ("roiphysicalproperty", &tags::ROIPhysicalProperty),
+        ("patienttreatmentpreparationmethodcodesequence", &tags::PatientTreatmentPreparationMethodCodeSequence),
+        ("snrthreshold", &tags::SNRThreshold),
+        ("constituentconceptualvolumeuid", &tags::ConstituentConceptualVolumeUID),
+        ("instructionperformeddatetime", &tags::InstructionPerformedDateTime),
+        ("illuminationtranslationflag", &tags::IlluminationTranslationFlag),
+        ("imagepathfilterpassthroughwavelength", &tags::ImagePathFilterPassThroughWavelength),
+        ("sourcetogeneralaccessorydistance", &tags::SourcetoGeneralAccessoryDistance),
+        ("facetsequence", &tags::FacetSequence),
+        ("interpolationtype", &tags::InterpolationType),
+        ("referencedsopinstancemacsequence", &tags::ReferencedSOPInstanceMACSequence),
+        ("modifiableconstraintflag", &tags::ModifiableConstraintFlag),
+        ("registrationsequence", &tags::RegistrationSequence),
+        ("referenceddosimetricobjectiveuid", &tags::ReferencedDosimetricObjectiveUID),
+        ("relatedsegmentcharacteristicssequence", &tags::RelatedSegmentCharacteristicsSequence),
+        ("columnpositionintotalimagepixelmatrix", &tags::ColumnPositionInTotalImagePixelMatrix),
+        ("codetablelocation", &tags::CodeTableLocation),
+        ("petreconstructionsequence", &tags::PETReconstructionSequence),
+        ("cassetteorientation", &tags::CassetteOrientation),
+        ("beamarealimitsequence", &tags::BeamAreaLimitSequence),
+        ("basisrttreatmentphaseindex", &tags::BasisRTTreatmentPhaseIndex),
+        ("planoverviewsequence", &tags::PlanOverviewSequence),
+        ("acquisitionendlocationsequence", &tags::AcquisitionEndLocationSequence),
+        ("roivolume", &tags::ROIVolume),
+        ("framedimensionpointer", &tags::FrameDimensionPointer),
+        ("referencedrtaccessoryholderdeviceindex", &tags::ReferencedRTAccessoryHolderDeviceIndex),
+        ("suvtype", &tags::SUVType),
+        ("patientssex", &tags::PatientsSex),
+        ("manipulatedimage", &tags::ManipulatedImage),
+        ("preamplifierequipmentsequence", &tags::PreAmplifierEquipmentSequence),
+        ("channeleffectivelength", &tags::ChannelEffectiveLength),
+        ("depthofscanfield", &tags::DepthofScanField),
+        ("vectordimensionality", &tags::VectorDimensionality),
+        ("frameorigintimestamp", &tags::FrameOriginTimestamp),
+        ("fractionalchanneldisplayscale", &tags::FractionalChannelDisplayScale),
+        ("planninglandmarklinesequence", &tags::PlanningLandmarkLineSequence),
+        ("imagedvolumeheight", &tags::ImagedVolumeHeight),
+        ("registrationmethodcodesequence", &tags::RegistrationMethodCodeSequence),
+        ("spatialfrequencyresponsecolumnnames", &tags::SpatialFrequencyResponseColumnNames),
+        ("attributeidentifierlist", &tags::AttributeIdentifierList),
+        ("geneticmodificationsdescription", &tags::GeneticModificationsDescription),
+        ("trackinguid", &tags::TrackingUID),
         ("hpgldocumentid", &tags::HPGLDocumentID),
-        ("applicableframerange", &tags::ApplicableFrameRange),
-        ("respiratorysignalsourceid", &tags::RespiratorySignalSourceID),
-        ("fieldofviewrotation", &tags::FieldofViewRotation),
-        ("phasedelay", &tags::PhaseDelay),
-        ("bottomrighthandcorneroflocalizerarea", &tags::BottomRightHandCornerofLocalizerArea),
-        ("channelderivationdescription", &tags::ChannelDerivationDescription),
-        ("referencedlateralspreadingdevicenumber", &tags::ReferencedLateralSpreadingDeviceNumber),
-        ("continuationpulsenumber", &tags::ContinuationPulseNumber),
-        ("creationdate", &tags::CreationDate),
-        ("fixedrtbeamdelimiterdevicesequence", &tags::FixedRTBeamDelimiterDeviceSequence),
-        ("palettecolorlookuptablesequence", &tags::PaletteColorLookupTableSequence),
-        ("imageboxpresentationlutflag", &tags::ImageBoxPresentationLUTFlag),
-        ("nominalpriordose", &tags::NominalPriorDose),
-        ("displayedareabottomrighthandcorner_trial", &tags::DisplayedAreaBottomRightHandCorner_Trial),
-        ("datasettype", &tags::DataSetType),
-        ("decaycorrectiondatetime", &tags::DecayCorrectionDateTime),
-        ("treatmentrecordcontentorigin", &tags::TreatmentRecordContentOrigin),
-        ("executionstatusinfo", &tags::ExecutionStatusInfo),
-        ("hangingprotocoluseridentificationcodesequence", &tags::HangingProtocolUserIdentificationCodeSequence),
-        ("dimensionindexprivatecreator", &tags::DimensionIndexPrivateCreator),
-        ("applicationmanufacturer", &tags::ApplicationManufacturer),
-        ("filmconsumptionsequence", &tags::FilmConsumptionSequence),
-        ("showpatientdemographicsflag", &tags::ShowPatientDemographicsFlag),
-        ("rtvflowactualframeduration", &tags::RTVFlowActualFrameDuration),
-        ("floatpixelpaddingrangelimit", &tags::FloatPixelPaddingRangeLimit),
-        ("issuerofadmissionid", &tags::IssuerofAdmissionID),
-        ("registeredlocalizerunits", &tags::RegisteredLocalizerUnits),
-        ("mracquisitiontype", &tags::MRAcquisitionType),
-        ("cornealisvalue", &tags::CornealISValue),
-        ("planninglandmarkdescription", &tags::PlanningLandmarkDescription),
-        ("shieldingdevicelabel", &tags::ShieldingDeviceLabel),
-        ("detectornormalizationcorrection", &tags::DetectorNormalizationCorrection),
+        ("entitydescription", &tags::EntityDescription),
+        ("imageorientationslide", &tags::ImageOrientationSlide),
+        ("sequenceofultrasoundregions", &tags::SequenceofUltrasoundRegions),
+        ("roboticpathcontrolpointsequence", &tags::RoboticPathControlPointSequence),
+        ("currentobserver_trial", &tags::CurrentObserver_Trial),
+        ("quantitysequence", &tags::QuantitySequence),
+        ("unifiedprocedurestepliststatus", &tags::UnifiedProcedureStepListStatus),
+        ("axialmash", &tags::AxialMash),
+        ("exposurecontrolsensingregionssequence", &tags::ExposureControlSensingRegionsSequence),
+        ("macalgorithm", &tags::MACAlgorithm),
+        ("patientbreeddescription", &tags::PatientBreedDescription),
+        ("generalaccessorydefinitionsequence", &tags::GeneralAccessoryDefinitionSequence),
+        ("prismsequence", &tags::PrismSequence),
+        ("ddlvalue", &tags::DDLValue),
+        ("roidisplaycolor", &tags::ROIDisplayColor),
+        ("wedgethinedgeposition", &tags::WedgeThinEdgePosition),
+        ("tabletopverticalposition", &tags::TableTopVerticalPosition),
+        ("extendedcodevalue", &tags::ExtendedCodeValue),
+        ("fractionbasedrelationshipsequence", &tags::FractionBasedRelationshipSequence),
+        ("modifiercodesequence", &tags::ModifierCodeSequence),
+        ("overlayplaneorigin", &tags::OverlayPlaneOrigin),
+        ("radiationchargestate", &tags::RadiationChargeState),
+        ("dopplersamplevolumexposition", &tags::DopplerSampleVolumeXPosition),
+        ("component2referencedmatingfeaturesetid", &tags::Component2ReferencedMatingFeatureSetID),
+        ("referencepixelphysicalvaluey", &tags::ReferencePixelPhysicalValueY),
         ("specificabsorptionratevalue", &tags::SpecificAbsorptionRateValue),
-        ("contrastbolusroute", &tags::ContrastBolusRoute),
-        ("doublepointcoordinatesdata", &tags::DoublePointCoordinatesData),
-        ("instancecreatoruid", &tags::InstanceCreatorUID),
-        ("impedancemeasurementcurrenttype", &tags::ImpedanceMeasurementCurrentType),
-        ("intendedstartdayofweek", &tags::IntendedStartDayofWeek),
-        ("generalaccessorytype", &tags::GeneralAccessoryType),
-        ("scanspottimeoffset", &tags::ScanSpotTimeOffset),
-        ("patientsupportadjustedangle", &tags::PatientSupportAdjustedAngle),
-        ("modifyingdeviceid", &tags::ModifyingDeviceID),
-        ("procedurestepprogressparameterssequence", &tags::ProcedureStepProgressParametersSequence),
-        ("contrast", &tags::Contrast),
-        ("gantrypitchangletolerance", &tags::GantryPitchAngleTolerance),
-        ("generalaccessoryid", &tags::GeneralAccessoryID),
-        ("modelmirroring", &tags::ModelMirroring),
-        ("primarychromaticities", &tags::PrimaryChromaticities),
-        ("filterlowfrequency", &tags::FilterLowFrequency),
-        ("sourcepresentationaddress", &tags::SourcePresentationAddress),
-        ("frameacquisitionnumber", &tags::FrameAcquisitionNumber),
-        ("mausedingaincalibration", &tags::MAUsedinGainCalibration),
-        ("physicaldetectorsize", &tags::PhysicalDetectorSize),
-        ("nominalcardiactriggerdelaytime", &tags::NominalCardiacTriggerDelayTime),
-        ("rtimagesid", &tags::RTImageSID),
-        ("algorithmdescription_trial", &tags::AlgorithmDescription_Trial),
-        ("pixelcomponentphysicalunits", &tags::PixelComponentPhysicalUnits),
-        ("framevoilutsequence", &tags::FrameVOILUTSequence),
-        ("breastsupportzpositiontoisocenter", &tags::BreastSupportZPositiontoIsocenter),
-        ("deformableregistrationsequence", &tags::DeformableRegistrationSequence),
-        ("extendedcodemeaning", &tags::ExtendedCodeMeaning),
-        ("visualfieldcatchtrialsequence", &tags::VisualFieldCatchTrialSequence),
-        ("rtradiationsetdeliverynumber", &tags::RTRadiationSetDeliveryNumber),
-        ("acquisitionindex", &tags::AcquisitionIndex),
-        ("tableposition", &tags::TablePosition),
-        ("centerofcircularcollimator", &tags::CenterofCircularCollimator),
-        ("luminanceresponsesequence", &tags::LuminanceResponseSequence),
-        ("indicationdisposition", &tags::IndicationDisposition),
-        ("maximumcornealcurvature", &tags::MaximumCornealCurvature),
-        ("linepattern", &tags::LinePattern),
-        ("overalltemplatespatialtolerance", &tags::OverallTemplateSpatialTolerance),
-        ("gaplength", &tags::GapLength),
-        ("framedisplaysequence", &tags::FrameDisplaySequence),
-        ("blockidentifyinginformationstatus", &tags::BlockIdentifyingInformationStatus),
-        ("scheduledprocedurestepstarttime", &tags::ScheduledProcedureStepStartTime),
-        ("isocentertorangemodulatordistance", &tags::IsocentertoRangeModulatorDistance),
-        ("treatmentstatuscomment", &tags::TreatmentStatusComment),
-        ("heartrate", &tags::HeartRate),
-        ("timezoneoffsetfromutc", &tags::TimezoneOffsetFromUTC),
-        ("nominalrangemodulationfractions", &tags::NominalRangeModulationFractions),
-        ("tablehorizontalrotationangle", &tags::TableHorizontalRotationAngle),
-        ("referencedsetupimagesequence", &tags::ReferencedSetupImageSequence),
-        ("approvalstatusfurtherdescription", &tags::ApprovalStatusFurtherDescription),
-        ("sopauthorizationcomment", &tags::SOPAuthorizationComment),
-        ("batterylevel", &tags::BatteryLevel),
-        ("selectorflvalue", &tags::SelectorFLValue),
-        ("nominalscannedpixelspacing", &tags::NominalScannedPixelSpacing),
-        ("registeredlocalizerbottomrighthandcorner", &tags::RegisteredLocalizerBottomRightHandCorner),
-        ("flowidentifier", &tags::FlowIdentifier),
-        ("seriesdate", &tags::SeriesDate),
-        ("tabletype", &tags::TableType),
-        ("numberofphases", &tags::NumberofPhases),
-        ("datasetversion", &tags::DataSetVersion),
-        ("multicoilelementused", &tags::MultiCoilElementUsed),
-        ("treatmentpositiongroupuid", &tags::TreatmentPositionGroupUID),
-        ("ophthalmicenfaceimagequalityratingsequence", &tags::OphthalmicEnFaceImageQualityRatingSequence),
-        ("referencebasiscodesequence", &tags::ReferenceBasisCodeSequence),
-        ("deformableregistrationgridsequence", &tags::DeformableRegistrationGridSequence),
-        ("reasonforomissioncodesequence", &tags::ReasonforOmissionCodeSequence),
-        ("ionmachineverificationsequence", &tags::IonMachineVerificationSequence),
-        ("printercharacteristicssequence", &tags::PrinterCharacteristicsSequence),
-        ("density", &tags::Density),
-        ("filesource", &tags::FileSource),
-        ("dosereferencetype", &tags::DoseReferenceType),
-        ("exposuresondetectorsincelastcalibration", &tags::ExposuresonDetectorSinceLastCalibration),
-        ("totalprocessingtime", &tags::TotalProcessingTime),
-        ("decaycorrection", &tags::DecayCorrection),
-        ("ophthalmicaxiallengthmeasurementssegmentnamecodesequence", &tags::OphthalmicAxialLengthMeasurementsSegmentNameCodeSequence),
-        ("parallelreductionfactorinplane_retired", &tags::ParallelReductionFactorInplane_Retired),
-        ("patientsupportpositionspecificationmethod", &tags::PatientSupportPositionSpecificationMethod),
-        ("displaysetpresentationgroup", &tags::DisplaySetPresentationGroup),
-        ("spiralpitchfactor", &tags::SpiralPitchFactor),
-        ("patientorientationmodifiercodesequence", &tags::PatientOrientationModifierCodeSequence),
-        ("recommendedviewingmode", &tags::RecommendedViewingMode),
-        ("presentationsequencepositionindex", &tags::PresentationSequencePositionIndex),
-        ("acquisitionprotocolelementsequence", &tags::AcquisitionProtocolElementSequence),
-        ("activesourcediameter", &tags::ActiveSourceDiameter),
-        ("positionersecondaryangleincrement", &tags::PositionerSecondaryAngleIncrement),
-        ("locationofmeasuredbeamdiameter", &tags::LocationofMeasuredBeamDiameter),
-        ("treatmentverificationstatus", &tags::TreatmentVerificationStatus),
-        ("dacgainpoints", &tags::DACGainPoints),
-        ("observertype", &tags::ObserverType),
-        ("velocityofsound", &tags::VelocityofSound),
-        ("catheterrotationalrate", &tags::CatheterRotationalRate),
-        ("verificationimagetiming", &tags::VerificationImageTiming),
-        ("radiationtype", &tags::RadiationType),
-        ("wedgetype", &tags::WedgeType),
-        ("imageformat", &tags::ImageFormat),
-        ("pixelpresentation", &tags::PixelPresentation),
-        ("secondaryreviewtime", &tags::SecondaryReviewTime),
-        ("collimatorshapesequence", &tags::CollimatorShapeSequence),
-        ("concatenationuid", &tags::ConcatenationUID),
-        ("gantrytype", &tags::GantryType),
-        ("targetframeofreferenceuid", &tags::TargetFrameofReferenceUID),
-        ("transmitcoilmanufacturername", &tags::TransmitCoilManufacturerName),
-        ("rtbeamlimitingdevicedefinitionsequence", &tags::RTBeamLimitingDeviceDefinitionSequence),
-        ("magneticfieldstrength", &tags::MagneticFieldStrength),
-        ("overridesequence", &tags::OverrideSequence),
-        ("eventtimernames", &tags::EventTimerNames),
-        ("frameanatomysequence", &tags::FrameAnatomySequence),
-        ("realworldvalueintercept", &tags::RealWorldValueIntercept),
-        ("numberofiterations", &tags::NumberofIterations),
-        ("outputdestinationsequence", &tags::OutputDestinationSequence),
-        ("compoundgraphictype", &tags::CompoundGraphicType),
-        ("centerofmass", &tags::CenterofMass),
-        ("switchingphasetransitionduration", &tags::SwitchingPhaseTransitionDuration),
-        ("codevalue", &tags::CodeValue),
-        ("correctedimage", &tags::CorrectedImage),
-        ("spatialtransformofdose", &tags::SpatialTransformofDose),
-        ("parametersspecificationsequence", &tags::ParametersSpecificationSequence),
-        ("acquisitionterminationconditiondata", &tags::AcquisitionTerminationConditionData),
-        ("userselectedphase", &tags::UserSelectedPhase),
-        ("transmitcoilname", &tags::TransmitCoilName),
-        ("structuresetlabel", &tags::StructureSetLabel),
-        ("surgicaltechnique", &tags::SurgicalTechnique),
-        ("sopinstancestatus", &tags::SOPInstanceStatus),
-        ("viewingdistance", &tags::ViewingDistance),
-        ("retrievelocationuid", &tags::RetrieveLocationUID),
-        ("referencedfileid", &tags::ReferencedFileID),
-        ("wedgeoffsetz", &tags::WedgeOffsetZ),
-        ("applicationsetupcheck", &tags::ApplicationSetupCheck),
-        ("lateralspreadingdevicesetting", &tags::LateralSpreadingDeviceSetting),
-        ("sourceapplicatorwallnominalthickness", &tags::SourceApplicatorWallNominalThickness),
-        ("localizeddeviationfromnormal", &tags::LocalizedDeviationFromNormal),
-        ("averagebeamdosepointdepth", &tags::AverageBeamDosePointDepth),
-        ("linestylesequence", &tags::LineStyleSequence),
-        ("humanperformersname", &tags::HumanPerformersName),
-        ("minimumhoursbetweenfractions", &tags::MinimumHoursbetweenFractions),
-        ("observationstartdatetime", &tags::ObservationStartDateTime),
-        ("recognitioncode", &tags::RecognitionCode),
-        ("fractiongrouptype", &tags::FractionGroupType),
-        ("productdescription", &tags::ProductDescription),
-        ("sourceorientation", &tags::SourceOrientation),
-        ("stereobaselineangle", &tags::StereoBaselineAngle),
-        ("rtphysicianintentnarrative", &tags::RTPhysicianIntentNarrative),
-        ("imageboxessequence", &tags::ImageBoxesSequence),
-        ("radionuclide", &tags::Radionuclide),
-        ("numberofframesinrotation", &tags::NumberofFramesinRotation),
-        ("correctionvalue", &tags::CorrectionValue),
-        ("referencedtargetluminancecharacteristicsid", &tags::ReferencedTargetLuminanceCharacteristicsID),
-        ("smallestimagepixelvalueinplane", &tags::SmallestImagePixelValueinPlane),
-        ("totalreferenceairkerma", &tags::TotalReferenceAirKerma),
-        ("fillerordernumberimagingservicerequest_retired", &tags::FillerOrderNumberImagingServiceRequest_Retired),
-        ("measurementlaterality", &tags::MeasurementLaterality),
-        ("fillerordernumberprocedure", &tags::FillerOrderNumberProcedure),
-        ("actiontypeid", &tags::ActionTypeID),
+        ("boundingboxtexthorizontaljustification", &tags::BoundingBoxTextHorizontalJustification),
+        ("maximumcumulativemetersetexposure", &tags::MaximumCumulativeMetersetExposure),
+        ("numberofstudyrecordsininstance", &tags::NumberofStudyRecordsinInstance),
+        ("interlockdatetime", &tags::InterlockDateTime),
+        ("relativeelevation", &tags::RelativeElevation),
+        ("fluencemapsequence", &tags::FluenceMapSequence),
+        ("blindspotxcoordinate", &tags::BlindSpotXCoordinate),
+        ("deviceuid", &tags::DeviceUID),
+        ("biplaneacquisitionsequence", &tags::BiPlaneAcquisitionSequence),
+        ("digitalfiltercharacteristicssequence", &tags::DigitalFilterCharacteristicsSequence),
+        ("weightinglookuptabledescriptor", &tags::WeightingLookupTableDescriptor),
+        ("flowcompensationdirection", &tags::FlowCompensationDirection),
+        ("weekdayfractionpatternsequence", &tags::WeekdayFractionPatternSequence),
+        ("referencedtexturesequence", &tags::ReferencedTextureSequence),
+        ("isocentertorangeshifterdistance", &tags::IsocentertoRangeShifterDistance),
+        ("numberofstages", &tags::NumberofStages),
+        ("datetime", &tags::DateTime),
+        ("apertureelevation", &tags::ApertureElevation),
+        ("visualfieldtestpointnormalssequence", &tags::VisualFieldTestPointNormalsSequence),
+        ("distancebetweenfocalplanes", &tags::DistanceBetweenFocalPlanes),
+        ("componenttypecodesequence", &tags::ComponentTypeCodeSequence),
+        ("sortbycategory", &tags::SortbyCategory),
+        ("printmanagementcapabilitiessequence", &tags::PrintManagementCapabilitiesSequence),
+        ("referencedsopinstanceuid", &tags::ReferencedSOPInstanceUID),
+        ("xraydetectorid", &tags::XRayDetectorID),
+        ("devicediameterunits", &tags::DeviceDiameterUnits),
+        ("ctgeometrysequence", &tags::CTGeometrySequence),
+        ("tag_3dimplanttemplategroupmembermatchingpoint", &tags::Tag_3DImplantTemplateGroupMemberMatchingPoint),
+        ("excessivefalsenegatives", &tags::ExcessiveFalseNegatives),
+        ("actualenvironmentalconditions", &tags::ActualEnvironmentalConditions),
+        ("predecessordocumentssequence", &tags::PredecessorDocumentsSequence),
+        ("scanningsequence", &tags::ScanningSequence),
+        ("dateoflastcalibration", &tags::DateofLastCalibration),
+        ("priortreatmentdosedescription", &tags::PriorTreatmentDoseDescription),
+        ("bolusdescription", &tags::BolusDescription),
+        ("rotationangle", &tags::RotationAngle),
+        ("tracksetstatisticssequence", &tags::TrackSetStatisticsSequence),
+        ("privatedataelementname", &tags::PrivateDataElementName),
+        ("iontolerancetablesequence", &tags::IonToleranceTableSequence),
+        ("patientnotproperlyfixatedquantity", &tags::PatientNotProperlyFixatedQuantity),
+        ("renderedimagereferencesequence", &tags::RenderedImageReferenceSequence),
+        ("impedancevalue", &tags::ImpedanceValue),
+        ("calculateddosereferencedescription", &tags::CalculatedDoseReferenceDescription),
+        ("itemnumber", &tags::ItemNumber),
+        ("imagelocation", &tags::ImageLocation),
+        ("referencedsopsequence", &tags::ReferencedSOPSequence),
+        ("imagesetlabel", &tags::ImageSetLabel),
+        ("maximumdepthdistortion", &tags::MaximumDepthDistortion),
+        ("planninglandmarkid", &tags::PlanningLandmarkID),
+        ("screeningtestmodecodesequence", &tags::ScreeningTestModeCodeSequence),
+        ("entrancedoseinmgy", &tags::EntranceDoseinmGy),
+        ("assertionuid", &tags::AssertionUID),
+        ("hl7structureddocumentreferencesequence", &tags::HL7StructuredDocumentReferenceSequence),
+        ("requesteddecimatecropbehavior", &tags::RequestedDecimateCropBehavior),
+        ("pixelaspectratio", &tags::PixelAspectRatio),
+        ("transducerpositionsequence", &tags::TransducerPositionSequence),
+        ("flipangle", &tags::FlipAngle),
+        ("implementationversionname", &tags::ImplementationVersionName),
+        ("detectorsecondaryangle", &tags::DetectorSecondaryAngle),
+        ("channeldescriptioncodesequence", &tags::ChannelDescriptionCodeSequence),
+        ("refractivestatesequence", &tags::RefractiveStateSequence),
+        ("positionerprimaryangle", &tags::PositionerPrimaryAngle),
+        ("tagthickness", &tags::TagThickness),
+        ("observationsubjecttypecodesequence_trial", &tags::ObservationSubjectTypeCodeSequence_Trial),
+        ("functionalsyncpulse", &tags::FunctionalSyncPulse),
+        ("coordinatestepvalue", &tags::CoordinateStepValue),
+        ("dlpnotificationtrigger", &tags::DLPNotificationTrigger),
+        ("detectorgeometry", &tags::DetectorGeometry),
+        ("horizontalprismpower", &tags::HorizontalPrismPower),
+        ("treatmentpositiongroupsequence", &tags::TreatmentPositionGroupSequence),
+        ("equipmentframeofreferencedescription", &tags::EquipmentFrameofReferenceDescription),
+        ("numberofkspacetrajectories", &tags::NumberofkSpaceTrajectories),
+        ("mediastoragesopclassuid", &tags::MediaStorageSOPClassUID),
+        ("imagerotation_retired", &tags::ImageRotation_Retired),
+        ("amplifiertype", &tags::AmplifierType),
+        ("tabletraverse", &tags::TableTraverse),
+        ("spatialfrequencyresponsecolumns", &tags::SpatialFrequencyResponseColumns),
+        ("rangeshiftersetting", &tags::RangeShifterSetting),
+        ("displaysubsystemconfigurationsequence", &tags::DisplaySubsystemConfigurationSequence),
+        ("radiopharmaceuticalstarttime", &tags::RadiopharmaceuticalStartTime),
+        ("brachyaccessorydevicenominalthickness", &tags::BrachyAccessoryDeviceNominalThickness),
+        ("notchfilterfrequency", &tags::NotchFilterFrequency),
+        ("component2referencedid", &tags::Component2ReferencedID),
+        ("devicelabel", &tags::DeviceLabel),
+        ("compressionoriginator", &tags::CompressionOriginator),
+        ("mrechosequence", &tags::MREchoSequence),
+        ("surgicallyinducedastigmatismsequence", &tags::SurgicallyInducedAstigmatismSequence),
+        ("exposuresondetectorsincemanufactured", &tags::ExposuresonDetectorSinceManufactured),
+        ("carmphotonelectroncontrolpointsequence", &tags::CArmPhotonElectronControlPointSequence),
+        ("clinicaltrialsponsorname", &tags::ClinicalTrialSponsorName),
+        ("cumulativemetersetweight", &tags::CumulativeMetersetWeight),
+        ("performedstationname", &tags::PerformedStationName),
+        ("shotoffsettime", &tags::ShotOffsetTime),
+        ("planarconfiguration", &tags::PlanarConfiguration),
+        ("observationdatetime", &tags::ObservationDateTime),
+        ("contrast", &tags::Contrast),
+        ("primarydosimeterunit", &tags::PrimaryDosimeterUnit),
+        ("wedgeincontactlength", &tags::WedgeInContactLength),
+        ("placerordernumberimagingservicerequest", &tags::PlacerOrderNumberImagingServiceRequest),
+        ("numberofsubsets", &tags::NumberofSubsets),
+        ("rtplanlabel", &tags::RTPlanLabel),
+        ("referencedpresentationlutsequence", &tags::ReferencedPresentationLUTSequence),
+        ("exposureinmicroas", &tags::ExposureinmicroAs),
+        ("brachytherapysourcetype", &tags::BrachytherapySourceType),
+        ("referencelocationlabel", &tags::ReferenceLocationLabel),
+        ("trackingid", &tags::TrackingID),
         ("clinicaltrialprotocolethicscommitteename", &tags::ClinicalTrialProtocolEthicsCommitteeName),
+        ("imagingdevicespecificacquisitionparameters", &tags::ImagingDeviceSpecificAcquisitionParameters),
+        ("patientspeciescodesequence", &tags::PatientSpeciesCodeSequence),
+        ("predictorcolumns", &tags::PredictorColumns),
+        ("procedurestepprogressparameterssequence", &tags::ProcedureStepProgressParametersSequence),
+        ("productdescription", &tags::ProductDescription),
+        ("targetlabel", &tags::TargetLabel),
+        ("privatedataelementdescription", &tags::PrivateDataElementDescription),
+        ("setupdevicedescription", &tags::SetupDeviceDescription),
+        ("conceptualvolumederivationalgorithmsequence", &tags::ConceptualVolumeDerivationAlgorithmSequence),
+        ("framesecondaryanglevector", &tags::FrameSecondaryAngleVector),
+        ("waveformpresentationgroupsequence", &tags::WaveformPresentationGroupSequence),
+        ("nominalscannedpixelspacing", &tags::NominalScannedPixelSpacing),
+        ("addnearsequence", &tags::AddNearSequence),
+        ("gantrypitchangle", &tags::GantryPitchAngle),
+        ("detectoractiveorigin", &tags::DetectorActiveOrigin),
+        ("cranialthermalindex", &tags::CranialThermalIndex),
+        ("overlaycompressionoriginator", &tags::OverlayCompressionOriginator),
+        ("overlaysubtype", &tags::OverlaySubtype),
+        ("fractiongroupsequence", &tags::FractionGroupSequence),
         ("multienergyctpathsequence", &tags::MultienergyCTPathSequence),
-        ("prescriptionnotes", &tags::PrescriptionNotes),
-        ("reviewtime", &tags::ReviewTime),
-        ("privatedataelementkeyword", &tags::PrivateDataElementKeyword),
-        ("primarypositionerscanarc", &tags::PrimaryPositionerScanArc),
-        ("numberofcompletedsuboperations", &tags::NumberofCompletedSuboperations),
-        ("intendedrecipientsofresultsidentificationsequence", &tags::IntendedRecipientsofResultsIdentificationSequence),
-        ("clinicaltrialprotocolid", &tags::ClinicalTrialProtocolID),
-        ("mracquisitionfrequencyencodingsteps", &tags::MRAcquisitionFrequencyEncodingSteps),
-        ("rttreatmentterminationstatus", &tags::RTTreatmentTerminationStatus),
-        ("dicomretrievalsequence", &tags::DICOMRetrievalSequence),
-        ("referencedsopinstancemacsequence", &tags::ReferencedSOPInstanceMACSequence),
-        ("imageboxnumber", &tags::ImageBoxNumber),
-        ("dosimetricobjectiveweight", &tags::DosimetricObjectiveWeight),
-        ("clinicaltrialsubjectid", &tags::ClinicalTrialSubjectID),
-        ("seriestime", &tags::SeriesTime),
-        ("displayshadingflag", &tags::DisplayShadingFlag),
-        ("horizontalfieldofview", &tags::HorizontalFieldofView),
-        ("radiationsourcecoordinatesystemrollangle", &tags::RadiationSourceCoordinateSystemRollAngle),
-        ("implanttemplateversion", &tags::ImplantTemplateVersion),
-        ("planes", &tags::Planes),
-        ("relativexrayexposure", &tags::RelativeXRayExposure),
-        ("dimensionindexvalues", &tags::DimensionIndexValues),
-        ("ophthalmicaxiallengthselectionmethodcodesequence", &tags::OphthalmicAxialLengthSelectionMethodCodeSequence),
-        ("filesetid", &tags::FilesetID),
-        ("issuerofadmissionidsequence", &tags::IssuerofAdmissionIDSequence),
-        ("ophthalmicimagetypecodesequence", &tags::OphthalmicImageTypeCodeSequence),
-        ("sourcesequence", &tags::SourceSequence),
-        ("ophthalmicfov", &tags::OphthalmicFOV),
-        ("ophthalmicaxiallengthmethod", &tags::OphthalmicAxialLengthMethod),
-        ("windowcenter_and_widthexplanation", &tags::WindowCenter_and_WidthExplanation),
-        ("recordedsourceapplicatorsequence", &tags::RecordedSourceApplicatorSequence),
-        ("saturation", &tags::Saturation),
-        ("sourceapplicatorid", &tags::SourceApplicatorID),
-        ("verticalalignment", &tags::VerticalAlignment),
-        ("referencedcomparisonsopinstancesequence", &tags::ReferencedComparisonSOPInstanceSequence),
-        ("recommendeddisplaycielabvalue", &tags::RecommendedDisplayCIELabValue),
-        ("pulserepetitionfrequency", &tags::PulseRepetitionFrequency),
-        ("modifyingdevicemanufacturer", &tags::ModifyingDeviceManufacturer),
-        ("tissuelocation", &tags::TissueLocation),
-        ("physiciansofrecordidentificationsequence", &tags::PhysiciansofRecordIdentificationSequence),
-        ("emmetropicmagnification", &tags::EmmetropicMagnification),
-        ("flowcompensationdirection", &tags::FlowCompensationDirection),
-        ("recommendeddisplaycielabvaluelist", &tags::RecommendedDisplayCIELabValueList),
+        ("digitalfilterorder", &tags::DigitalFilterOrder),
+        ("multiplespinecho", &tags::MultipleSpinEcho),
+        ("filterlowfrequencycharacteristicssequence", &tags::FilterLowFrequencyCharacteristicsSequence),
+        ("referencedcontrolpointsequence", &tags::ReferencedControlPointSequence),
+        ("maskvisibilitypercentage", &tags::MaskVisibilityPercentage),
+        ("beammeterset", &tags::BeamMeterset),
+        ("parameterpointer", &tags::ParameterPointer),
+        ("performedprocedurestepdiscontinuationreasoncodesequence", &tags::PerformedProcedureStepDiscontinuationReasonCodeSequence),
+        ("totalpixelmatrixcolumns", &tags::TotalPixelMatrixColumns),
+        ("usedsegmentssequence", &tags::UsedSegmentsSequence),
+        ("snoutpositiontolerance", &tags::SnoutPositionTolerance),
+        ("numberoftriggersinphase", &tags::NumberofTriggersinPhase),
+        ("datablockdescription", &tags::DataBlockDescription),
+        ("interventiondruginformationsequence", &tags::InterventionDrugInformationSequence),
+        ("localdeviationprobabilitynormalsflag", &tags::LocalDeviationProbabilityNormalsFlag),
+        ("dvhreferencedroisequence", &tags::DVHReferencedROISequence),
+        ("isocentertocompensatordistances", &tags::IsocentertoCompensatorDistances),
+        ("commentsonpatientsperformanceofvisualfield", &tags::CommentsonPatientsPerformanceofVisualField),
+        ("alternatebeamdose", &tags::AlternateBeamDose),
+        ("predictorrows", &tags::PredictorRows),
+        ("numberofsamples", &tags::NumberofSamples),
+        ("roimean", &tags::ROIMean),
+        ("sensitivitycalibrated", &tags::SensitivityCalibrated),
+        ("applicationmanufacturer", &tags::ApplicationManufacturer),
+        ("frameofinteresttype", &tags::FrameofInterestType),
+        ("referencedwaveformsequence", &tags::ReferencedWaveformSequence),
+        ("volumetricpresentationinputsetsequence", &tags::VolumetricPresentationInputSetSequence),
+        ("startcumulativemeterset", &tags::StartCumulativeMeterset),
         ("humanperformercodesequence", &tags::HumanPerformerCodeSequence),
-        ("numberofbrachyapplicationsetups", &tags::NumberofBrachyApplicationSetups),
-        ("instructiondescription", &tags::InstructionDescription),
-        ("selectorattributekeyword", &tags::SelectorAttributeKeyword),
-        ("datasettrailingpadding", &tags::DataSetTrailingPadding),
-        ("ethicscommitteeapprovaleffectivenessenddate", &tags::EthicsCommitteeApprovalEffectivenessEndDate),
-        ("findingsflag_trial", &tags::FindingsFlag_Trial),
-        ("originator", &tags::Originator),
-        ("displaysetssequence", &tags::DisplaySetsSequence),
-        ("visualfieldmeansensitivity", &tags::VisualFieldMeanSensitivity),
-        ("longprimitivepointindexlist", &tags::LongPrimitivePointIndexList),
-        ("hangingprotocollevel", &tags::HangingProtocolLevel),
-        ("referencedobservationuid_trial", &tags::ReferencedObservationUID_Trial),
-        ("contrastbolusagentnumber", &tags::ContrastBolusAgentNumber),
-        ("sourceapplicatormanufacturer", &tags::SourceApplicatorManufacturer),
-        ("contributiondatetime", &tags::ContributionDateTime),
-        ("referencedimageevidencesequence", &tags::ReferencedImageEvidenceSequence),
-        ("mandatorycomponenttype", &tags::MandatoryComponentType),
-        ("gradientechotrainlength", &tags::GradientEchoTrainLength),
-        ("imageboxlargescrolltype", &tags::ImageBoxLargeScrollType),
-        ("planoverviewindex", &tags::PlanOverviewIndex),
-        ("numberoftablebreakpoints", &tags::NumberofTableBreakPoints),
-        ("scpstatus", &tags::SCPStatus),
-        ("sourcestrength", &tags::SourceStrength),
-        ("algorithmnamecodesequence", &tags::AlgorithmNameCodeSequence),
-        ("gaincontrol", &tags::GainControl),
-        ("referencepixelphysicalvaluex", &tags::ReferencePixelPhysicalValueX),
-        ("detectoractivetime", &tags::DetectorActiveTime),
-        ("secondarycapturedevicemanufacturersmodelname", &tags::SecondaryCaptureDeviceManufacturersModelName),
-        ("gridthickness", &tags::GridThickness),
-        ("renderfieldofview", &tags::RenderFieldofView),
-        ("beamhardeningcorrectiontechnique", &tags::BeamHardeningCorrectionTechnique),
-        ("displaysetscrollinggroup", &tags::DisplaySetScrollingGroup),
-        ("echonumbers", &tags::EchoNumbers),
-        ("imagesetselectorcategory", &tags::ImageSetSelectorCategory),
-        ("distaldepthfraction", &tags::DistalDepthFraction),
-        ("ionwedgepositionsequence", &tags::IonWedgePositionSequence),
-        ("datetime", &tags::DateTime),
-        ("subjectdistancerange", &tags::SubjectDistanceRange),
-        ("preliminaryflag", &tags::PreliminaryFlag),
-        ("animationstepsize", &tags::AnimationStepSize),
-        ("diameterofvisibility", &tags::DiameterofVisibility),
-        ("aslslabnumber", &tags::ASLSlabNumber),
-        ("focalspots", &tags::FocalSpots),
+        ("tracksetsequence", &tags::TrackSetSequence),
+        ("physicaldetectorsize", &tags::PhysicalDetectorSize),
+        ("mprviewheightdirection", &tags::MPRViewHeightDirection),
+        ("numberofpaintings", &tags::NumberofPaintings),
+        ("detectorprimaryangle", &tags::DetectorPrimaryAngle),
+        ("subtractionitemid", &tags::SubtractionItemID),
+        ("selectorcodesequencevalue", &tags::SelectorCodeSequenceValue),
+        ("degreeoffreedomid", &tags::DegreeofFreedomID),
+        ("failedattributessequence", &tags::FailedAttributesSequence),
+        ("billingsuppliesanddevicessequence", &tags::BillingSuppliesandDevicesSequence),
+        ("positionerpositionsequence", &tags::PositionerPositionSequence),
+        ("fixationcheckedquantity", &tags::FixationCheckedQuantity),
+        ("configurationid", &tags::ConfigurationID),
+        ("performedprotocolcodesequence", &tags::PerformedProtocolCodeSequence),
+        ("creationtime", &tags::CreationTime),
+        ("channellabel", &tags::ChannelLabel),
+        ("receivingpresentationaddress", &tags::ReceivingPresentationAddress),
+        ("hangingprotocolname", &tags::HangingProtocolName),
+        ("referencedconceptualvolumeuid", &tags::ReferencedConceptualVolumeUID),
+        ("generalaccessorysequence", &tags::GeneralAccessorySequence),
+        ("rtvsourceidentifier", &tags::RTVSourceIdentifier),
+        ("implementationclassuid", &tags::ImplementationClassUID),
+        ("ctdivolnotificationtrigger", &tags::CTDIvolNotificationTrigger),
+        ("pertinentresourcessequence", &tags::PertinentResourcesSequence),
+        ("waveformfiltertype", &tags::WaveformFilterType),
+        ("referencedinstancesequence", &tags::ReferencedInstanceSequence),
+        ("xray3dframetypesequence", &tags::XRay3DFrameTypeSequence),
+        ("surfacecount", &tags::SurfaceCount),
+        ("scanspottuneid", &tags::ScanSpotTuneID),
+        ("generalmachineverificationsequence", &tags::GeneralMachineVerificationSequence),
+        ("brachyaccessorydevicesequence", &tags::BrachyAccessoryDeviceSequence),
+        ("contourdata", &tags::ContourData),
+        ("conceptualvolumecombinationexpression", &tags::ConceptualVolumeCombinationExpression),
+        ("printingbitdepth", &tags::PrintingBitDepth),
+        ("curvelabel", &tags::CurveLabel),
+        ("brachyaccessorydevicenumber", &tags::BrachyAccessoryDeviceNumber),
+        ("columnangulationpatient", &tags::ColumnAngulationPatient),
+        ("waveformdata", &tags::WaveformData),
+        ("compensatorbaseplaneoffset", &tags::CompensatorBasePlaneOffset),
+        ("detectortimesincelastexposure", &tags::DetectorTimeSinceLastExposure),
+        ("calibrationprocedure", &tags::CalibrationProcedure),
+        ("numberofframesintegrated", &tags::NumberofFramesIntegrated),
+        ("overridereason", &tags::OverrideReason),
+        ("stimulusarea", &tags::StimulusArea),
+        ("bolusid", &tags::BolusID),
+        ("interlockoriginatingdevicesequence", &tags::InterlockOriginatingDeviceSequence),
+        ("centerfrequency", &tags::CenterFrequency),
+        ("commentsonthescheduledprocedurestep", &tags::CommentsontheScheduledProcedureStep),
+        ("sopinstancestatus", &tags::SOPInstanceStatus),
+        ("detectortype", &tags::DetectorType),
+        ("selectorlovalue", &tags::SelectorLOValue),
+        ("bodypartexamined", &tags::BodyPartExamined),
+        ("organatriskoverdosevolumefraction", &tags::OrganatRiskOverdoseVolumeFraction),
+        ("patientstelephonenumbers", &tags::PatientsTelephoneNumbers),
+        ("numberofiterations", &tags::NumberofIterations),
+        ("bulkmotioncompensationtechnique", &tags::BulkMotionCompensationTechnique),
+        ("tomolayerheight", &tags::TomoLayerHeight),
+        ("storedvaluecolorrangesequence", &tags::StoredValueColorRangeSequence),
+        ("treatmentpositiongroupuid", &tags::TreatmentPositionGroupUID),
+        ("blendinglut2sequence", &tags::BlendingLUT2Sequence),
+        ("implanttemplategroupmemberid", &tags::ImplantTemplateGroupMemberID),
+        ("selectoraevalue", &tags::SelectorAEValue),
+        ("contributingsourcessequence", &tags::ContributingSourcesSequence),
+        ("fractionationnotes", &tags::FractionationNotes),
+        ("hangingprotocolcreationdatetime", &tags::HangingProtocolCreationDateTime),
+        ("patientsinstitutionresidence", &tags::PatientsInstitutionResidence),
+        ("storageprotocolelementspecificationsequence", &tags::StorageProtocolElementSpecificationSequence),
+        ("gpsdestbearingref", &tags::GPSDestBearingRef),
+        ("smokingstatus", &tags::SmokingStatus),
+        ("imageposition", &tags::ImagePosition),
+        ("brachypulsecontrolpointdeliveredsequence", &tags::BrachyPulseControlPointDeliveredSequence),
+        ("breastsupportisocentersecondaryangle", &tags::BreastSupportIsocenterSecondaryAngle),
+        ("lutnumber", &tags::LUTNumber),
+        ("calculationcommentsequence", &tags::CalculationCommentSequence),
+        ("radiusofcurvature", &tags::RadiusofCurvature),
+        ("reportstatuscomment_trial", &tags::ReportStatusComment_Trial),
+        ("numberofrrintervals", &tags::NumberofRRIntervals),
+        ("realworldvaluemappingsequence", &tags::RealWorldValueMappingSequence),
+        ("largestimagepixelvalueinplane", &tags::LargestImagePixelValueinPlane),
+        ("autokvpselectiontype", &tags::AutoKVPSelectionType),
+        ("overridedatetime", &tags::OverrideDateTime),
+        ("contentlabel", &tags::ContentLabel),
+        ("affectedsopinstanceuid", &tags::AffectedSOPInstanceUID),
+        ("xraysourceisocenterprimaryangle", &tags::XRaySourceIsocenterPrimaryAngle),
+        ("ophthalmicthicknessmapqualityratingsequence", &tags::OphthalmicThicknessMapQualityRatingSequence),
+        ("acquisitiondeviceprocessingdescription", &tags::AcquisitionDeviceProcessingDescription),
+        ("configurationdescription", &tags::ConfigurationDescription),
+        ("abstractpriorvalue", &tags::AbstractPriorValue),
+        ("modelmodification", &tags::ModelModification),
+        ("otherpatientidssequence", &tags::OtherPatientIDsSequence),
+        ("iccprofile", &tags::ICCProfile),
+        ("recommendeddisplaygrayscalevalue", &tags::RecommendedDisplayGrayscaleValue),
+        ("privatedataelementcharacteristicssequence", &tags::PrivateDataElementCharacteristicsSequence),
+        ("referencedrtradiationrecordsequence", &tags::ReferencedRTRadiationRecordSequence),
+        ("maximumcollatedfilms", &tags::MaximumCollatedFilms),
+        ("algorithmversion", &tags::AlgorithmVersion),
+        ("mrdrdirectoryrecordoffset", &tags::MRDRDirectoryRecordOffset),
+        ("ionwedgesequence", &tags::IonWedgeSequence),
+        ("patientssizecodesequence", &tags::PatientsSizeCodeSequence),
+        ("transformorderofaxes", &tags::TransformOrderofAxes),
+        ("timeofgaincalibration", &tags::TimeofGainCalibration),
         ("contourgeometrictype", &tags::ContourGeometricType),
-        ("attenuationcorrectionmethod", &tags::AttenuationCorrectionMethod),
-        ("screenminimumcolorbitdepth", &tags::ScreenMinimumColorBitDepth),
-        ("localnamespaceentityid", &tags::LocalNamespaceEntityID),
-        ("optotypepresentation", &tags::OptotypePresentation),
-        ("measurementpatterncodesequence", &tags::MeasurementPatternCodeSequence),
-        ("photoacousticexcitationcharacteristicssequence", &tags::PhotoacousticExcitationCharacteristicsSequence),
-        ("universalentityid", &tags::UniversalEntityID),
-        ("setupdevicesequence", &tags::SetupDeviceSequence),
-        ("presentationlutcontentsequence", &tags::PresentationLUTContentSequence),
-        ("energywindowtotalwidth", &tags::EnergyWindowTotalWidth),
+        ("numberofphaseencodingsteps", &tags::NumberofPhaseEncodingSteps),
+        ("unspecifiedlateralitylenssequence", &tags::UnspecifiedLateralityLensSequence),
+        ("templatenumber", &tags::TemplateNumber),
+        ("overlaybitsallocated", &tags::OverlayBitsAllocated),
+        ("componentid", &tags::ComponentID),
+        ("numberofstudyrelatedinstances", &tags::NumberofStudyRelatedInstances),
+        ("numberofrtcontrolpoints", &tags::NumberofRTControlPoints),
+        ("channelsettingssequence", &tags::ChannelSettingsSequence),
+        ("patientgantryrelationshipcodesequence", &tags::PatientGantryRelationshipCodeSequence),
+        ("equipmentreferencepointcodesequence", &tags::EquipmentReferencePointCodeSequence),
+        ("displaysetverticaljustification", &tags::DisplaySetVerticalJustification),
+        ("performedprocedurestependdate", &tags::PerformedProcedureStepEndDate),
+        ("excessivefalsepositivesdataflag", &tags::ExcessiveFalsePositivesDataFlag),
+        ("attributeoccurrencesequence", &tags::AttributeOccurrenceSequence),
+        ("waterreferencedphasecorrection", &tags::WaterReferencedPhaseCorrection),
+        ("rangemodulatorid", &tags::RangeModulatorID),
+        ("branchofservice", &tags::BranchofService),
+        ("imageorientation", &tags::ImageOrientation),
+        ("totalreferenceairkerma", &tags::TotalReferenceAirKerma),
+        ("wadoretrievalsequence", &tags::WADORetrievalSequence),
+        ("intensifieractiveshape", &tags::IntensifierActiveShape),
+        ("channelmaximumvalue", &tags::ChannelMaximumValue),
+        ("dimensionorganizationtype", &tags::DimensionOrganizationType),
+        ("referencedrtradiationsequence", &tags::ReferencedRTRadiationSequence),
+        ("centerofmass", &tags::CenterofMass),
+        ("arbitrary", &tags::Arbitrary),
+        ("verticaloffsetofsensor", &tags::VerticalOffsetofSensor),
+        ("refractiveprocedureoccurred", &tags::RefractiveProcedureOccurred),
+        ("numberofbeams", &tags::NumberofBeams),
+        ("authorizationequipmentcertificationnumber", &tags::AuthorizationEquipmentCertificationNumber),
+        ("wedgeorientation", &tags::WedgeOrientation),
+        ("effectivebinenergy", &tags::EffectiveBinEnergy),
+        ("aperturevalue", &tags::ApertureValue),
+        ("parallelreductionfactorinplane_retired", &tags::ParallelReductionFactorInplane_Retired),
+        ("notchfiltercharacteristicssequence", &tags::NotchFilterCharacteristicsSequence),
+        ("diffusionmodelcodesequence", &tags::DiffusionModelCodeSequence),
+        ("referencedosepointcoordinates", &tags::ReferenceDosePointCoordinates),
+        ("seriesdate", &tags::SeriesDate),
+        ("displayedareabottomrighthandcorner", &tags::DisplayedAreaBottomRightHandCorner),
+        ("interpretationtranscriber", &tags::InterpretationTranscriber),
+        ("performedprocedurestepstarttime", &tags::PerformedProcedureStepStartTime),
+        ("surfaceregularityindex", &tags::SurfaceRegularityIndex),
+        ("referencedgeneralpurposescheduledproceduresteptransactionuid", &tags::ReferencedGeneralPurposeScheduledProcedureStepTransactionUID),
+        ("lenssegmenttype", &tags::LensSegmentType),
+        ("anatomiclocationofexamininginstrumentcodesequence_trial", &tags::AnatomicLocationOfExaminingInstrumentCodeSequence_Trial),
+        ("performedworkitemcodesequence", &tags::PerformedWorkitemCodeSequence),
+        ("instackpositionnumber", &tags::InStackPositionNumber),
+        ("actualframeduration", &tags::ActualFrameDuration),
+        ("rowoverlap", &tags::RowOverlap),
+        ("deadtimecorrected", &tags::DeadTimeCorrected),
+        ("beammodifiercoordinatespresenceflag", &tags::BeamModifierCoordinatesPresenceFlag),
+        ("displayedareabottomrighthandcorner_trial", &tags::DisplayedAreaBottomRightHandCorner_Trial),
+        ("stimuluscolorcodesequence", &tags::StimulusColorCodeSequence),
+        ("mappingresourcename", &tags::MappingResourceName),
+        ("conversionsourceattributessequence", &tags::ConversionSourceAttributesSequence),
+        ("ionbeamlimitingdevicesequence", &tags::IonBeamLimitingDeviceSequence),
+        ("decaycorrectiondatetime", &tags::DecayCorrectionDateTime),
+        ("routeofadmissions", &tags::RouteofAdmissions),
+        ("imagesinstudy", &tags::ImagesinStudy),
+        ("instructionperformancecomment", &tags::InstructionPerformanceComment),
+        ("breastsupportypositiontoisocenter", &tags::BreastSupportYPositiontoIsocenter),
+        ("iolpower", &tags::IOLPower),
+        ("incorporatedinventoryinstancesequence", &tags::IncorporatedInventoryInstanceSequence),
+        ("pixeldataareaoriginrelativetofov", &tags::PixelDataAreaOriginRelativeToFOV),
+        ("terminationtimethreshold", &tags::TerminationTimeThreshold),
+        ("measurementvaluessequence", &tags::MeasurementValuesSequence),
+        ("perframefunctionalgroupssequence", &tags::PerFrameFunctionalGroupsSequence),
+        ("maximumacrossscandistortion", &tags::MaximumAcrossscanDistortion),
+        ("surfacemodellabel", &tags::SurfaceModelLabel),
+        ("directoryrecordtype", &tags::DirectoryRecordType),
+        ("conceptualvolumedescription", &tags::ConceptualVolumeDescription),
+        ("referencedrequestsequence", &tags::ReferencedRequestSequence),
+        ("specimentypecodesequence", &tags::SpecimenTypeCodeSequence),
+        ("numberofgraphicpoints", &tags::NumberofGraphicPoints),
+        ("pertinentdocumentssequence", &tags::PertinentDocumentsSequence),
+        ("doserateset", &tags::DoseRateSet),
+        ("totalcollimationwidth", &tags::TotalCollimationWidth),
+        ("lossyimagecompressionratio", &tags::LossyImageCompressionRatio),
+        ("templateidentifier", &tags::TemplateIdentifier),
+        ("usedfiducialssequence", &tags::UsedFiducialsSequence),
+        ("autosequenceflag", &tags::AutosequenceFlag),
+        ("transactionuid", &tags::TransactionUID),
+        ("sequencename", &tags::SequenceName),
+        ("issuerofserviceepisodeidsequence", &tags::IssuerofServiceEpisodeIDSequence),
+        ("nonconformingmodifiedattributessequence", &tags::NonconformingModifiedAttributesSequence),
+        ("materialthickness", &tags::MaterialThickness),
+        ("dimensionorganizationuid", &tags::DimensionOrganizationUID),
+        ("objectpixelspacingincenterofbeam", &tags::ObjectPixelSpacinginCenterofBeam),
+        ("wholeslidemicroscopyimageframetypesequence", &tags::WholeSlideMicroscopyImageFrameTypeSequence),
+        ("radiationdoseinvivomeasurementlabel", &tags::RadiationDoseInVivoMeasurementLabel),
+        ("tag_2dpointcoordinates", &tags::Tag_2DPointCoordinates),
+        ("zerovelocitypixelvalue", &tags::ZeroVelocityPixelValue),
+        ("nonidentifyingprivateelements", &tags::NonidentifyingPrivateElements),
+        ("scheduledhumanperformerssequence", &tags::ScheduledHumanPerformersSequence),
+        ("patientclinicaltrialparticipationsequence", &tags::PatientClinicalTrialParticipationSequence),
+        ("multiplecomponentapprovalsequence", &tags::MultipleComponentApprovalSequence),
+        ("institutionaddress", &tags::InstitutionAddress),
+        ("tubeangle", &tags::TubeAngle),
+        ("tabletoprollangletolerance", &tags::TableTopRollAngleTolerance),
+        ("countsincluded", &tags::CountsIncluded),
+        ("colorfilterarraypatterncolumns", &tags::ColorFilterArrayPatternColumns),
+        ("includesinformation", &tags::IncludesInformation),
+        ("exposurebiasvalue", &tags::ExposureBiasValue),
+        ("referenceddatetime", &tags::ReferencedDateTime),
+        ("echotime", &tags::EchoTime),
+        ("componentshape", &tags::ComponentShape),
+        ("distaldepth", &tags::DistalDepth),
+        ("codingschemename", &tags::CodingSchemeName),
+        ("referencedrttreatmentpreparationsequence", &tags::ReferencedRTTreatmentPreparationSequence),
+        ("wedgetotalwidth", &tags::WedgeTotalWidth),
+        ("primarypromptscountsaccumulated", &tags::PrimaryPromptsCountsAccumulated),
+        ("rtpatientpositionscopesequence", &tags::RTPatientPositionScopeSequence),
+        ("planepositionvolumesequence", &tags::PlanePositionVolumeSequence),
+        ("instructionsequence", &tags::InstructionSequence),
+        ("compressioncode", &tags::CompressionCode),
+        ("treatmentcontrolpointdate", &tags::TreatmentControlPointDate),
+        ("ambientreflectionintensity", &tags::AmbientReflectionIntensity),
+        ("executionstatus", &tags::ExecutionStatus),
+        ("dataelement", &tags::DataElement),
+        ("ooiownertype", &tags::OOIOwnerType),
+        ("deliverytimestructurecodesequence", &tags::DeliveryTimeStructureCodeSequence),
+        ("gantryrotationdirection", &tags::GantryRotationDirection),
+        ("patientorientation", &tags::PatientOrientation),
+        ("conceptualvolumesegmentationdefinedflag", &tags::ConceptualVolumeSegmentationDefinedFlag),
+        ("acquisitionstartlocationsequence", &tags::AcquisitionStartLocationSequence),
+        ("patientsbirthname", &tags::PatientsBirthName),
+        ("exposurecontrolmodedescription", &tags::ExposureControlModeDescription),
+        ("viewpointlookatpoint", &tags::ViewpointLookAtPoint),
+        ("patienttreatmentpreparationprocedureparametersequence", &tags::PatientTreatmentPreparationProcedureParameterSequence),
+        ("coordinatesystemaxisdescription", &tags::CoordinateSystemAxisDescription),
+        ("moveoriginatormessageid", &tags::MoveOriginatorMessageID),
+        ("printpriority", &tags::PrintPriority),
+        ("deidentificationmethodcodesequence", &tags::DeidentificationMethodCodeSequence),
+        ("segmentannotationtypecodesequence", &tags::SegmentAnnotationTypeCodeSequence),
+        ("frametime", &tags::FrameTime),
+        ("deliveredreferencedosedefinition", &tags::DeliveredReferenceDoseDefinition),
+        ("seriesdescription", &tags::SeriesDescription),
+        ("enhancedrtbeamlimitingdevicedefinitionflag", &tags::EnhancedRTBeamLimitingDeviceDefinitionFlag),
+        ("pixelspacing", &tags::PixelSpacing),
+        ("immersionmedia", &tags::ImmersionMedia),
+        ("referencedrtinstancesequence", &tags::ReferencedRTInstanceSequence),
+        ("lightpathfilterpassband", &tags::LightPathFilterPassBand),
+        ("centerofrotation", &tags::CenterofRotation),
+        ("effectivedosecalculationmethodcategorycodesequence", &tags::EffectiveDoseCalculationMethodCategoryCodeSequence),
+        ("sourceapplicationentitytitle", &tags::SourceApplicationEntityTitle),
+        ("totalcompensatortraywaterequivalentthickness", &tags::TotalCompensatorTrayWaterEquivalentThickness),
+        ("samplingfrequency", &tags::SamplingFrequency),
+        ("documentidentifiercodesequence_trial", &tags::DocumentIdentifierCodeSequence_Trial),
+        ("selectordtvalue", &tags::SelectorDTValue),
+        ("graylookuptabledata", &tags::GrayLookupTableData),
+        ("maximumcornealcurvature", &tags::MaximumCornealCurvature),
+        ("compensatortype", &tags::CompensatorType),
+        ("dctlabel", &tags::DCTLabel),
+        ("globaldeviationprobabilitysequence", &tags::GlobalDeviationProbabilitySequence),
+        ("toriciolpowerforexacttargetrefractionsequence", &tags::ToricIOLPowerforExactTargetRefractionSequence),
+        ("startrelativedensitydifferencethreshold", &tags::StartRelativeDensityDifferenceThreshold),
+        ("cornealtopographymaptypecodesequence", &tags::CornealTopographyMapTypeCodeSequence),
+        ("cssfontname", &tags::CSSFontName),
+        ("backgroundluminance", &tags::BackgroundLuminance),
+        ("xray3dacquisitionsequence", &tags::XRay3DAcquisitionSequence),
+        ("toriciolpowerforexactemmetropiasequence", &tags::ToricIOLPowerforExactEmmetropiaSequence),
+        ("eventtimernames", &tags::EventTimerNames),
+        ("protocolelementcharacteristicssummary", &tags::ProtocolElementCharacteristicsSummary),
+        ("detectormode", &tags::DetectorMode),
+        ("blendingmode", &tags::BlendingMode),
+        ("rttreatmentphaseuid", &tags::RTTreatmentPhaseUID),
+        ("presentationlutsequence", &tags::PresentationLUTSequence),
+        ("ptolocationdescription", &tags::PTOLocationDescription),
+        ("boundingrectangle", &tags::BoundingRectangle),
+        ("carmpositionertabletoprelationship", &tags::CarmPositionerTabletopRelationship),
+        ("mydriaticagentcodesequence", &tags::MydriaticAgentCodeSequence),
+        ("rows", &tags::Rows),
+        ("indexnormalsflag", &tags::IndexNormalsFlag),
+        ("tag_3dplaneorigin", &tags::Tag_3DPlaneOrigin),
+        ("clinicaltrialsubjectreadingid", &tags::ClinicalTrialSubjectReadingID),
+        ("numberofenergywindows", &tags::NumberofEnergyWindows),
+        ("dimensionindexprivatecreator", &tags::DimensionIndexPrivateCreator),
+        ("conceptualvolumeblockingconstraint", &tags::ConceptualVolumeBlockingConstraint),
+        ("acquisitionmethod", &tags::AcquisitionMethod),
+        ("algorithmdescription", &tags::AlgorithmDescription),
+        ("referencedsopclassuid", &tags::ReferencedSOPClassUID),
+        ("intraocularlenscalculationsrighteyesequence", &tags::IntraocularLensCalculationsRightEyeSequence),
+        ("concatenationframeoffsetnumber", &tags::ConcatenationFrameOffsetNumber),
+        ("numberofannotations", &tags::NumberofAnnotations),
+        ("lightsource", &tags::LightSource),
+        ("darkcurrentcounts", &tags::DarkCurrentCounts),
+        ("minimumnominalenergy", &tags::MinimumNominalEnergy),
+        ("sopinstanceuid", &tags::SOPInstanceUID),
+        ("gridabsorbingmaterial", &tags::GridAbsorbingMaterial),
+        ("decimatecropresult", &tags::DecimateCropResult),
+        ("ophthalmicenfaceimagequalityratingsequence", &tags::OphthalmicEnFaceImageQualityRatingSequence),
+        ("rtaccessoryholderslotexistenceflag", &tags::RTAccessoryHolderSlotExistenceFlag),
+        ("radiopharmaceuticalusagesequence", &tags::RadiopharmaceuticalUsageSequence),
+        ("highbit", &tags::HighBit),
+        ("autokvplowerbound", &tags::AutoKVPLowerBound),
+        ("dimensionindexpointer", &tags::DimensionIndexPointer),
+        ("tdrtype", &tags::TDRType),
+        ("materialpipediameter", &tags::MaterialPipeDiameter),
+        ("recognitioncode", &tags::RecognitionCode),
+        ("safepositionreturndate", &tags::SafePositionReturnDate),
+        ("gridaspectratio", &tags::GridAspectRatio),
+        ("pagenumbervector", &tags::PageNumberVector),
+        ("tomotherapeuticcontrolpointsequence", &tags::TomotherapeuticControlPointSequence),
+        ("recordedchannelshieldsequence", &tags::RecordedChannelShieldSequence),
+        ("tracksetanatomicaltypecodesequence", &tags::TrackSetAnatomicalTypeCodeSequence),
+        ("selectortmvalue", &tags::SelectorTMValue),
+        ("blindspotycoordinate", &tags::BlindSpotYCoordinate),
+        ("maskframenumbers", &tags::MaskFrameNumbers),
+        ("radiobiologicaldoseeffectsequence", &tags::RadiobiologicalDoseEffectSequence),
+        ("ophthalmicultrasoundmethodcodesequence", &tags::OphthalmicUltrasoundMethodCodeSequence),
+        ("petframetypesequence", &tags::PETFrameTypeSequence),
+        ("roiinterpreter", &tags::ROIInterpreter),
+        ("distancesourcetosupport", &tags::DistanceSourcetoSupport),
+        ("specifiedpulserepetitioninterval", &tags::SpecifiedPulseRepetitionInterval),
+        ("obliquecroppingplanesequence", &tags::ObliqueCroppingPlaneSequence),
+        ("vieworientationcodesequence", &tags::ViewOrientationCodeSequence),
+        ("modifiedimageid", &tags::ModifiedImageID),
+        ("highdosetechniquetype", &tags::HighDoseTechniqueType),
+        ("digitalfiltertypecodesequence", &tags::DigitalFilterTypeCodeSequence),
+        ("trackstatisticssequence", &tags::TrackStatisticsSequence),
+        ("rtprescriptionlabel", &tags::RTPrescriptionLabel),
+        ("waveformsequence", &tags::WaveformSequence),
+        ("longtrianglepointindexlist", &tags::LongTrianglePointIndexList),
+        ("metadatasequence", &tags::MetadataSequence),
+        ("deviceorderindex", &tags::DeviceOrderIndex),
+        ("detectorpositionsequence", &tags::DetectorPositionSequence),
+        ("primarypositionerincrementsign", &tags::PrimaryPositionerIncrementSign),
+        ("zeffective", &tags::ZEffective),
+        ("fluoroscopyflag", &tags::FluoroscopyFlag),
+        ("dicomretrievalsequence", &tags::DICOMRetrievalSequence),
+        ("multienergysourcetechnique", &tags::MultienergySourceTechnique),
+        ("threatroivoxelsequence", &tags::ThreatROIVoxelSequence),
+        ("anomalylocatorindicator", &tags::AnomalyLocatorIndicator),
+        ("presentationdisplaycollectionuid", &tags::PresentationDisplayCollectionUID),
+        ("visualfieldshape", &tags::VisualFieldShape),
+        ("pixelintensityrelationshipsign", &tags::PixelIntensityRelationshipSign),
+        ("audiosampledata", &tags::AudioSampleData),
+        ("procedurecodesequence", &tags::ProcedureCodeSequence),
+        ("macidnumber", &tags::MACIDNumber),
+        ("segmentlabel", &tags::SegmentLabel),
+        ("volumecroppingsequence", &tags::VolumeCroppingSequence),
+        ("sourcestrengthreferencetime", &tags::SourceStrengthReferenceTime),
+        ("pixelcomponentrangestart", &tags::PixelComponentRangeStart),
+        ("segmentedpropertytypecodesequence", &tags::SegmentedPropertyTypeCodeSequence),
+        ("backgroundcolor", &tags::BackgroundColor),
+        ("frameacquisitiondatetime", &tags::FrameAcquisitionDateTime),
+        ("measurementautomation_trial", &tags::MeasurementAutomation_Trial),
+        ("coefficientcodingpointers", &tags::CoefficientCodingPointers),
+        ("rtbeamlimitingdevicedistaldistance", &tags::RTBeamLimitingDeviceDistalDistance),
+        ("equipmentmodality", &tags::EquipmentModality),
+        ("rtdiagnosiscodesequence", &tags::RTDiagnosisCodeSequence),
+        ("animationcurvesequence", &tags::AnimationCurveSequence),
+        ("tracksetnumber", &tags::TrackSetNumber),
+        ("brachyaccessorydevicenominaltransmission", &tags::BrachyAccessoryDeviceNominalTransmission),
+        ("slicelocationvector", &tags::SliceLocationVector),
+        ("dimensionorganizationsequence", &tags::DimensionOrganizationSequence),
         ("irradiationeventuid", &tags::IrradiationEventUID),
-        ("falsenegativesquantity", &tags::FalseNegativesQuantity),
-        ("performedprocedurestepstarttime", &tags::PerformedProcedureStepStartTime),
-        ("configurationinformation", &tags::ConfigurationInformation),
-        ("presentationsizemode", &tags::PresentationSizeMode),
-        ("blendingsequence", &tags::BlendingSequence),
-        ("regionspatialformat", &tags::RegionSpatialFormat),
-        ("exposurecontrolsensingregionshape", &tags::ExposureControlSensingRegionShape),
-        ("isospeedlatitudeyyy", &tags::ISOSpeedLatitudeyyy),
-        ("billingsuppliesanddevicessequence", &tags::BillingSuppliesandDevicesSequence),
-        ("channelsourcemodifierssequence", &tags::ChannelSourceModifiersSequence),
-        ("endmeterset", &tags::EndMeterset),
-        ("referencedisplaysets", &tags::ReferenceDisplaySets),
-        ("treatmentcontrolpointdate", &tags::TreatmentControlPointDate),
-        ("referencedinterpretationsequence", &tags::ReferencedInterpretationSequence),
-        ("sourceidentifier", &tags::SourceIdentifier),
-        ("rangemodulatorgatingstartwaterequivalentthickness", &tags::RangeModulatorGatingStartWaterEquivalentThickness),
-        ("alternaterepresentationsequence", &tags::AlternateRepresentationSequence),
-        ("confidentialityconstraintonpatientdatadescription", &tags::ConfidentialityConstraintonPatientDataDescription),
-        ("componenttypecodesequence", &tags::ComponentTypeCodeSequence),
-        ("motionsynchronizationsequence", &tags::MotionSynchronizationSequence),
+        ("teletherapyradiationtype", &tags::TeletherapyRadiationType),
+        ("approvalsequence", &tags::ApprovalSequence),
+        ("shadowoffsety", &tags::ShadowOffsetY),
+        ("transformnumberofaxes", &tags::TransformNumberofAxes),
+        ("visitstatusid", &tags::VisitStatusID),
+        ("modulationtype", &tags::ModulationType),
+        ("specimenlabelinimage", &tags::SpecimenLabelinImage),
+        ("measureddosereferencenumber", &tags::MeasuredDoseReferenceNumber),
+        ("definitionsourcesequence", &tags::DefinitionSourceSequence),
+        ("wedgeoriginoffsetx", &tags::WedgeOriginOffsetX),
+        ("slicethickness", &tags::SliceThickness),
+        ("referencedgeneralpurposescheduledprocedurestepsequence", &tags::ReferencedGeneralPurposeScheduledProcedureStepSequence),
+        ("measuringunitssequence", &tags::MeasuringUnitsSequence),
+        ("radiationtypecodesequence", &tags::RadiationTypeCodeSequence),
+        ("opticalselectedophthalmicaxiallengthsequence", &tags::OpticalSelectedOphthalmicAxialLengthSequence),
+        ("visualfieldtestreliabilityglobalindexsequence", &tags::VisualFieldTestReliabilityGlobalIndexSequence),
+        ("numberofvectors", &tags::NumberofVectors),
+        ("specimenuid", &tags::SpecimenUID),
+        ("datatype", &tags::DataType),
+        ("testpointnormalssequence", &tags::TestPointNormalsSequence),
+        ("datasetversion", &tags::DataSetVersion),
+        ("textstylesequence", &tags::TextStyleSequence),
+        ("impedancemeasurementfrequency", &tags::ImpedanceMeasurementFrequency),
+        ("targetframeofreferenceuid", &tags::TargetFrameofReferenceUID),
+        ("nominalpriordose", &tags::NominalPriorDose),
+        ("patientsetupuid", &tags::PatientSetupUID),
+        ("audiosampleformat", &tags::AudioSampleFormat),
+        ("pixelshiftframerange", &tags::PixelShiftFrameRange),
+        ("roigenerationdescription", &tags::ROIGenerationDescription),
+        ("referencedfirstframesequence", &tags::ReferencedFirstFrameSequence),
+        ("multienergydetectortype", &tags::MultienergyDetectorType),
+        ("pixelpaddingvalue", &tags::PixelPaddingValue),
+        ("viewcodesequence", &tags::ViewCodeSequence),
+        ("gpsimgdirectionref", &tags::GPSImgDirectionRef),
+        ("containerdescription", &tags::ContainerDescription),
+        ("bulkmotionstatus", &tags::BulkMotionStatus),
+        ("beamlimitingdeviceangletolerance", &tags::BeamLimitingDeviceAngleTolerance),
+        ("interventiondrugname", &tags::InterventionDrugName),
+        ("sourceencapsulationnominalthickness", &tags::SourceEncapsulationNominalThickness),
+        ("containercomponentid", &tags::ContainerComponentID),
+        ("fractionpatternsequence", &tags::FractionPatternSequence),
+        ("tmlinepositionx0_retired", &tags::TMLinePositionX0_Retired),
+        ("beamcurrentmodulationid", &tags::BeamCurrentModulationID),
+        ("selectorucvalue", &tags::SelectorUCValue),
+        ("observationsignificance", &tags::ObservationSignificance),
+        ("scheduledprocedurestepmodificationdatetime", &tags::ScheduledProcedureStepModificationDateTime),
+        ("hpgldocument", &tags::HPGLDocument),
+        ("wedgefrontheight", &tags::WedgeFrontHeight),
+        ("applicationsetupcheck", &tags::ApplicationSetupCheck),
+        ("afterloaderchannelid", &tags::AfterloaderChannelID),
+        ("pixelvaluemappingcodesequence", &tags::PixelValueMappingCodeSequence),
+        ("asltechniquedescription", &tags::ASLTechniqueDescription),
+        ("temporalpositionidentifier", &tags::TemporalPositionIdentifier),
+        ("codingschemeresponsibleorganization", &tags::CodingSchemeResponsibleOrganization),
+        ("acquisitionprotocolname", &tags::AcquisitionProtocolName),
+        ("renderingmethod", &tags::RenderingMethod),
+        ("mrvelocityencodingsequence", &tags::MRVelocityEncodingSequence),
+        ("performedprotocoltype", &tags::PerformedProtocolType),
+        ("falsepositivesquantity", &tags::FalsePositivesQuantity),
+        ("underlined", &tags::Underlined),
         ("selectedtotalophthalmicaxiallengthsequence", &tags::SelectedTotalOphthalmicAxialLengthSequence),
-        ("gridperiod", &tags::GridPeriod),
-        ("referencelocationdescription", &tags::ReferenceLocationDescription),
-        ("wedgetotalwidth", &tags::WedgeTotalWidth),
-        ("fixationdeviceposition", &tags::FixationDevicePosition),
-        ("gpsdestlongitude", &tags::GPSDestLongitude),
-        ("couplingmedium", &tags::CouplingMedium),
-        ("anatomicperspectivedescription_trial", &tags::AnatomicPerspectiveDescription_Trial),
-        ("implanttemplategroupmemberid", &tags::ImplantTemplateGroupMemberID),
-        ("beamlimitingdeviceleafpairssequence", &tags::BeamLimitingDeviceLeafPairsSequence),
-        ("cinerate", &tags::CineRate),
-        ("startcumulativemetersetweight", &tags::StartCumulativeMetersetWeight),
-        ("privaterecorduid", &tags::PrivateRecordUID),
-        ("timeslicevector", &tags::TimeSliceVector),
-        ("lensthickness", &tags::LensThickness),
-        ("tablefeedperrotation", &tags::TableFeedperRotation),
-        ("detectoractiveshape", &tags::DetectorActiveShape),
-        ("gantrydetectortilt", &tags::GantryDetectorTilt),
-        ("predictorrows", &tags::PredictorRows),
-        ("reconstructionalgorithmsequence", &tags::ReconstructionAlgorithmSequence),
-        ("requestedprocedurelocation", &tags::RequestedProcedureLocation),
-        ("fiducialdescription", &tags::FiducialDescription),
-        ("numberofcontrolpoints", &tags::NumberofControlPoints),
-        ("resultinggeneralpurposeperformedprocedurestepssequence", &tags::ResultingGeneralPurposePerformedProcedureStepsSequence),
-        ("croppingspecificationindex", &tags::CroppingSpecificationIndex),
-        ("scanarc", &tags::ScanArc),
-        ("datapathassignment", &tags::DataPathAssignment),
-        ("recordedwedgesequence", &tags::RecordedWedgeSequence),
-        ("regiondatatype", &tags::RegionDataType),
-        ("fiducialspropertycategorycodesequence", &tags::FiducialsPropertyCategoryCodeSequence),
-        ("presentationcreationtime", &tags::PresentationCreationTime),
-        ("implantpartnumber", &tags::ImplantPartNumber),
-        ("medicalalerts", &tags::MedicalAlerts),
-        ("removedfromoperationaluse", &tags::RemovedfromOperationalUse),
-        ("derivationdescription", &tags::DerivationDescription),
-        ("findingssourcecategorycodesequence_trial", &tags::FindingsSourceCategoryCodeSequence_Trial),
-        ("selectorsequencepointeritems", &tags::SelectorSequencePointerItems),
-        ("humanperformersorganization", &tags::HumanPerformersOrganization),
-        ("globalcrop", &tags::GlobalCrop),
-        ("applicatorsequence", &tags::ApplicatorSequence),
-        ("imageorientation", &tags::ImageOrientation),
-        ("dosereferencestructuretype", &tags::DoseReferenceStructureType),
+        ("switchingphasenumber", &tags::SwitchingPhaseNumber),
+        ("errorid", &tags::ErrorID),
+        ("gatethreshold", &tags::GateThreshold),
+        ("countrate", &tags::CountRate),
+        ("acquisitionstartconditiondata", &tags::AcquisitionStartConditionData),
+        ("firstorderphasecorrectionangle", &tags::FirstOrderPhaseCorrectionAngle),
+        ("referencedpatientsetupnumber", &tags::ReferencedPatientSetupNumber),
+        ("instancecreationdate", &tags::InstanceCreationDate),
+        ("referencedmeasureddosereferencenumber", &tags::ReferencedMeasuredDoseReferenceNumber),
+        ("repositoryuniqueid", &tags::RepositoryUniqueID),
+        ("graphiclayerrecommendeddisplaycielabvalue", &tags::GraphicLayerRecommendedDisplayCIELabValue),
+        ("gantryangle", &tags::GantryAngle),
+        ("modifyingdeviceid", &tags::ModifyingDeviceID),
+        ("devicepositiontoequipmentmappingmatrix", &tags::DevicePositiontoEquipmentMappingMatrix),
+        ("recordedrtcontrolpointdatetime", &tags::RecordedRTControlPointDateTime),
+        ("shieldingdevicedescription", &tags::ShieldingDeviceDescription),
+        ("rtcontrolpointindex", &tags::RTControlPointIndex),
+        ("documentauthoridentifiercodesequence_trial", &tags::DocumentAuthorIdentifierCodeSequence_Trial),
+        ("universalentityidtype", &tags::UniversalEntityIDType),
+        ("beamdoseverificationcontrolpointsequence", &tags::BeamDoseVerificationControlPointSequence),
+        ("structuresetlabel", &tags::StructureSetLabel),
+        ("acquisitiondatetime", &tags::AcquisitionDateTime),
+        ("memorybitdepth", &tags::MemoryBitDepth),
+        ("ophthalmicvolumetricpropertiesflag", &tags::OphthalmicVolumetricPropertiesFlag),
+        ("treatmentsummarycalculateddosereferencesequence", &tags::TreatmentSummaryCalculatedDoseReferenceSequence),
+        ("rangematchingsequence", &tags::RangeMatchingSequence),
+        ("macparameterssequence", &tags::MACParametersSequence),
+        ("mrarterialspinlabelingsequence", &tags::MRArterialSpinLabelingSequence),
+        ("annotationindexlist", &tags::AnnotationIndexList),
+        ("ethnicgroup", &tags::EthnicGroup),
+        ("rangeshifterdescription", &tags::RangeShifterDescription),
+        ("wedgefactor", &tags::WedgeFactor),
+        ("treatmentcontrolpointtime", &tags::TreatmentControlPointTime),
+        ("startingrespiratoryphase", &tags::StartingRespiratoryPhase),
+        ("overlaybitposition", &tags::OverlayBitPosition),
+        ("bscancycletimevector", &tags::BscanCycleTimeVector),
+        ("observationsubjectclass_trial", &tags::ObservationSubjectClass_Trial),
+        ("partialviewcodesequence", &tags::PartialViewCodeSequence),
+        ("calibrationnotes", &tags::CalibrationNotes),
+        ("overlaysmoothingtype", &tags::OverlaySmoothingType),
+        ("digitizingdevicetransportdirection", &tags::DigitizingDeviceTransportDirection),
+        ("rtpatientpositiondisplacementsequence", &tags::RTPatientPositionDisplacementSequence),
+        ("othermediaavailablesequence", &tags::OtherMediaAvailableSequence),
+        ("excitationwavelength", &tags::ExcitationWavelength),
+        ("predictorconstants", &tags::PredictorConstants),
+        ("imagepathfilterpassband", &tags::ImagePathFilterPassBand),
+        ("implanttemplategroupdescription", &tags::ImplantTemplateGroupDescription),
+        ("acquisitiontime", &tags::AcquisitionTime),
+        ("componentreferencesystem", &tags::ComponentReferenceSystem),
+        ("graphicgrouplabel", &tags::GraphicGroupLabel),
+        ("rotationofscannedfilm", &tags::RotationofScannedFilm),
+        ("imageboxsmallscrolltype", &tags::ImageBoxSmallScrollType),
+        ("matingfeaturesequence", &tags::MatingFeatureSequence),
+        ("maxdensity", &tags::MaxDensity),
+        ("xrayreceptortype", &tags::XRayReceptorType),
+        ("imagingfrequency", &tags::ImagingFrequency),
+        ("projectionpixelcalibrationsequence", &tags::ProjectionPixelCalibrationSequence),
+        ("decaycorrection", &tags::DecayCorrection),
+        ("scheduledstudystarttime", &tags::ScheduledStudyStartTime),
+        ("pixelintensityrelationship", &tags::PixelIntensityRelationship),
+        ("unifiedprocedurestepperformedproceduresequence", &tags::UnifiedProcedureStepPerformedProcedureSequence),
+        ("surfacepointssequence", &tags::SurfacePointsSequence),
+        ("outlinelowerhorizontaledge", &tags::OutlineLowerHorizontalEdge),
+        ("ascanrate", &tags::AscanRate),
+        ("calibrationtime", &tags::CalibrationTime),
+        ("intraocularlenscalculationslefteyesequence", &tags::IntraocularLensCalculationsLeftEyeSequence),
+        ("probeinductance", &tags::ProbeInductance),
+        ("treatmenttoleranceviolationcausecodesequence", &tags::TreatmentToleranceViolationCauseCodeSequence),
+        ("contrastbolusingredientpercentbyvolume", &tags::ContrastBolusIngredientPercentbyVolume),
+        ("anatomicstructure", &tags::AnatomicStructure),
+        ("dvhvolumeunits", &tags::DVHVolumeUnits),
+        ("firstalinelocation", &tags::FirstAlineLocation),
+        ("mracquisitionphaseencodingstepsoutofplane", &tags::MRAcquisitionPhaseEncodingStepsoutofplane),
+        ("contouroffsetvector", &tags::ContourOffsetVector),
+        ("numberofpaddedalines", &tags::NumberofPaddedAlines),
+        ("tag_2dlinecoordinates", &tags::Tag_2DLineCoordinates),
+        ("selftimermode", &tags::SelfTimerMode),
+        ("decompositionmethod", &tags::DecompositionMethod),
+        ("waveformpaddingvalue", &tags::WaveformPaddingValue),
+        ("centerofcircularexposurecontrolsensingregion", &tags::CenterofCircularExposureControlSensingRegion),
+        ("respiratorytriggerdelaythreshold", &tags::RespiratoryTriggerDelayThreshold),
+        ("framedisplaysequence", &tags::FrameDisplaySequence),
+        ("tagging", &tags::Tagging),
+        ("specificationselectionguidance", &tags::SpecificationSelectionGuidance),
+        ("indicationroisequence", &tags::IndicationROISequence),
+        ("annotationpropertytypemodifiercodesequence", &tags::AnnotationPropertyTypeModifierCodeSequence),
+        ("maskoperation", &tags::MaskOperation),
+        ("fiducialidentifiercodesequence", &tags::FiducialIdentifierCodeSequence),
+        ("frameincrementpointer", &tags::FrameIncrementPointer),
+        ("frameofreferencerelationshipsequence", &tags::FrameofReferenceRelationshipSequence),
+        ("flowcompensation", &tags::FlowCompensation),
+        ("graphictype", &tags::GraphicType),
+        ("mostrecenttreatmentdate", &tags::MostRecentTreatmentDate),
+        ("patientid", &tags::PatientID),
+        ("sourcetoapplicatormountingpositiondistance", &tags::SourcetoApplicatorMountingPositionDistance),
+        ("magnifytonumberofcolumns", &tags::MagnifytoNumberofColumns),
+        ("calibrationsettingssequence", &tags::CalibrationSettingsSequence),
+        ("triggersourceortype", &tags::TriggerSourceorType),
+        ("tablexpositiontoisocenter", &tags::TableXPositiontoIsocenter),
+        ("imageboxsmallscrollamount", &tags::ImageBoxSmallScrollAmount),
+        ("diffusionbvaluezz", &tags::DiffusionbvalueZZ),
+        ("trackingalgorithmidentificationsequence", &tags::TrackingAlgorithmIdentificationSequence),
+        ("testimagevalidation", &tags::TestImageValidation),
+        ("compressionlabel", &tags::CompressionLabel),
+        ("transducerorientation", &tags::TransducerOrientation),
+        ("protocolplanninginformation", &tags::ProtocolPlanningInformation),
+        ("tabletoppitchangletolerance", &tags::TableTopPitchAngleTolerance),
+        ("visualacuitybotheyesopensequence", &tags::VisualAcuityBothEyesOpenSequence),
+        ("xaplanedetailssequence", &tags::XAPlaneDetailsSequence),
+        ("localnamespaceentityid", &tags::LocalNamespaceEntityID),
+        ("anglenumber", &tags::AngleNumber),
+        ("diffusiongradientorientation", &tags::DiffusionGradientOrientation),
+        ("rtplanrelationship", &tags::RTPlanRelationship),
+        ("atdassessmentsequence", &tags::ATDAssessmentSequence),
+        ("generalaccessorynumber", &tags::GeneralAccessoryNumber),
+        ("manifold", &tags::Manifold),
+        ("devicelength", &tags::DeviceLength),
+        ("omittedbeamtasksequence", &tags::OmittedBeamTaskSequence),
+        ("rtbeamlimitingdeviceproximaldistance", &tags::RTBeamLimitingDeviceProximalDistance),
+        ("rtbeamlimitingdeviceoffset", &tags::RTBeamLimitingDeviceOffset),
+        ("surfaceentrypoint", &tags::SurfaceEntryPoint),
+        ("datacollectioncenterpatient", &tags::DataCollectionCenterPatient),
+        ("containercomponentdiameter", &tags::ContainerComponentDiameter),
+        ("mprslabthickness", &tags::MPRSlabThickness),
+        ("triggertime", &tags::TriggerTime),
+        ("patientsbirthdateinalternativecalendar", &tags::PatientsBirthDateinAlternativeCalendar),
+        ("ambientlightvaluesource", &tags::AmbientLightValueSource),
+        ("showimagetruesizeflag", &tags::ShowImageTrueSizeFlag),
+        ("containerfiletype", &tags::ContainerFileType),
+        ("timeofsecondarycapture", &tags::TimeofSecondaryCapture),
+        ("radiationdosimeterunitsequence", &tags::RadiationDosimeterUnitSequence),
+        ("eventtypeid", &tags::EventTypeID),
+        ("applicatorgeometrysequence", &tags::ApplicatorGeometrySequence),
+        ("displayedareaselectionsequence", &tags::DisplayedAreaSelectionSequence),
+        ("frameofreferencetransformationmatrixtype", &tags::FrameofReferenceTransformationMatrixType),
+        ("pixelcoordinatesset_trial", &tags::PixelCoordinatesSet_Trial),
+        ("sourceimageevidencesequence", &tags::SourceImageEvidenceSequence),
+        ("starttrim", &tags::StartTrim),
+        ("otherpatientids", &tags::OtherPatientIDs),
+        ("specimendescriptionsequence_trial", &tags::SpecimenDescriptionSequence_Trial),
+        ("curvedate", &tags::CurveDate),
+        ("fieldofviewdimensionsinfloat", &tags::FieldofViewDimensionsinFloat),
+        ("positionerisocenterdetectorrotationangle", &tags::PositionerIsocenterDetectorRotationAngle),
+        ("deliverednumberofpulses", &tags::DeliveredNumberofPulses),
+        ("redpalettecolorlookuptabledescriptor", &tags::RedPaletteColorLookupTableDescriptor),
+        ("structuresettime", &tags::StructureSetTime),
+        ("numberofintervalfractions", &tags::NumberofIntervalFractions),
+        ("timedomainfiltering", &tags::TimeDomainFiltering),
+        ("surfacescanacquisitiontypecodesequence", &tags::SurfaceScanAcquisitionTypeCodeSequence),
+        ("treatmentterminationcode", &tags::TreatmentTerminationCode),
+        ("firstorderphasecorrection", &tags::FirstOrderPhaseCorrection),
+        ("imagerpixelspacing", &tags::ImagerPixelSpacing),
+        ("contrastbolust1relaxivity", &tags::ContrastBolusT1Relaxivity),
+        ("overlaynumberoftables", &tags::OverlayNumberofTables),
+        ("realworldvaluelastvaluemapped", &tags::RealWorldValueLastValueMapped),
+        ("numberofbrachyapplicationsetups", &tags::NumberofBrachyApplicationSetups),
+        ("minimumnumberofintervaldays", &tags::MinimumNumberofIntervalDays),
         ("excessivefixationlosses", &tags::ExcessiveFixationLosses),
-        ("nonidentifyingprivateelements", &tags::NonidentifyingPrivateElements),
-        ("wedgedescription", &tags::WedgeDescription),
-        ("maskingimage", &tags::MaskingImage),
-        ("eventelapsedtimes", &tags::EventElapsedTimes),
-        ("allowlossycompression", &tags::AllowLossyCompression),
-        ("strainadditionalinformation", &tags::StrainAdditionalInformation),
-        ("procedurestepdiscontinuationreasoncodesequence", &tags::ProcedureStepDiscontinuationReasonCodeSequence),
-        ("tdrtype", &tags::TDRType),
-        ("wedgeelement1position", &tags::WedgeElement1Position),
-        ("referencedrtradiationsetsequence", &tags::ReferencedRTRadiationSetSequence),
-        ("modifiercodesequence", &tags::ModifierCodeSequence),
-        ("presentedvisualstimulidataflag", &tags::PresentedVisualStimuliDataFlag),
-        ("totalnumberofpiecesofmediacreated", &tags::TotalNumberofPiecesofMediaCreated),
-        ("lensdescription", &tags::LensDescription),
-        ("treatmentpositionindex", &tags::TreatmentPositionIndex),
-        ("sourceapplicatorwallnominaltransmission", &tags::SourceApplicatorWallNominalTransmission),
-        ("impedancemeasurementdatetime", &tags::ImpedanceMeasurementDateTime),
-        ("targetpositionreferenceindicator", &tags::TargetPositionReferenceIndicator),
-        ("inventoriedseriessequence", &tags::InventoriedSeriesSequence),
+        ("sourceimagecornealprocesseddatasequence", &tags::SourceImageCornealProcessedDataSequence),
+        ("findingsgrouprecordingtime_trial", &tags::FindingsGroupRecordingTime_Trial),
+        ("xraysourceisocentersecondaryangle", &tags::XRaySourceIsocenterSecondaryAngle),
+        ("yfocuscenter", &tags::YFocusCenter),
+        ("fixationdeviceposition", &tags::FixationDevicePosition),
+        ("anatomicalorientationtype", &tags::AnatomicalOrientationType),
+        ("numberofcompensators", &tags::NumberofCompensators),
+        ("trianglepointindexlist", &tags::TrianglePointIndexList),
         ("timeofdocumentcreationorverbaltransaction_trial", &tags::TimeofDocumentCreationorVerbalTransaction_Trial),
-        ("protocoldefinedpatientposition", &tags::ProtocolDefinedPatientPosition),
-        ("enhancedrtbeamlimitingopeningsequence", &tags::EnhancedRTBeamLimitingOpeningSequence),
-        ("generalizeddefectcorrectedsensitivitydeviationflag", &tags::GeneralizedDefectCorrectedSensitivityDeviationFlag),
-        ("blockslabthickness", &tags::BlockSlabThickness),
-        ("selectorobvalue", &tags::SelectorOBValue),
-        ("specifiedsecondarymeterset", &tags::SpecifiedSecondaryMeterset),
-        ("acquisitionstatus", &tags::AcquisitionStatus),
-        ("generatorid", &tags::GeneratorID),
-        ("issuerofpatientid", &tags::IssuerofPatientID),
-        ("positionerprimaryangleincrement", &tags::PositionerPrimaryAngleIncrement),
-        ("referencedsurfacenumber", &tags::ReferencedSurfaceNumber),
-        ("assigningjurisdictioncodesequence", &tags::AssigningJurisdictionCodeSequence),
-        ("fixationdevicelabel", &tags::FixationDeviceLabel),
-        ("displaysetverticaljustification", &tags::DisplaySetVerticalJustification),
-        ("contactmethod", &tags::ContactMethod),
-        ("organatriskoverdosevolumefraction", &tags::OrganatRiskOverdoseVolumeFraction),
-        ("griddimensions", &tags::GridDimensions),
-        ("timeofsecondarycapture", &tags::TimeofSecondaryCapture),
-        ("implantname", &tags::ImplantName),
-        ("patientsetupuid", &tags::PatientSetupUID),
-        ("partialview", &tags::PartialView),
-        ("selectorpnvalue", &tags::SelectorPNValue),
-        ("sourcestrengthreferencedate", &tags::SourceStrengthReferenceDate),
-        ("quantity", &tags::Quantity),
-        ("diffusiongradientdirectionsequence", &tags::DiffusionGradientDirectionSequence),
-        ("filterlowfrequencycharacteristicssequence", &tags::FilterLowFrequencyCharacteristicsSequence),
-        ("dataelementname", &tags::DataElementName),
-        ("multicoildefinitionsequence", &tags::MultiCoilDefinitionSequence),
-        ("phosphortype", &tags::PhosphorType),
-        ("relativetimeunits", &tags::RelativeTimeUnits),
-        ("actualcardiactriggerdelaytime", &tags::ActualCardiacTriggerDelayTime),
+        ("numberoffocalplanes", &tags::NumberofFocalPlanes),
+        ("referenceairkermarate", &tags::ReferenceAirKermaRate),
+        ("numberofeventtimers", &tags::NumberofEventTimers),
+        ("sourceisotopename", &tags::SourceIsotopeName),
+        ("productpackageidentifier", &tags::ProductPackageIdentifier),
+        ("compoundgraphicinstanceid", &tags::CompoundGraphicInstanceID),
+        ("lensspecification", &tags::LensSpecification),
+        ("longedgepointindexlist", &tags::LongEdgePointIndexList),
+        ("contrastbolusingredient", &tags::ContrastBolusIngredient),
+        ("gpsmapdatum", &tags::GPSMapDatum),
+        ("filterlookuptabledata", &tags::FilterLookupTableData),
+        ("channeltotaltime", &tags::ChannelTotalTime),
+        ("shuttershape", &tags::ShutterShape),
+        ("imageboxessequence", &tags::ImageBoxesSequence),
+        ("rtaccessoryholderwaterequivalentthickness", &tags::RTAccessoryHolderWaterEquivalentThickness),
+        ("referencedfractiongroupnumber", &tags::ReferencedFractionGroupNumber),
+        ("sortingoperationssequence", &tags::SortingOperationsSequence),
+        ("referencedoverlayplanegroups", &tags::ReferencedOverlayPlaneGroups),
+        ("mprtoplefthandcorner", &tags::MPRTopLeftHandCorner),
+        ("dvhroicontributiontype", &tags::DVHROIContributionType),
+        ("receiversettingssequence", &tags::ReceiverSettingsSequence),
+        ("generalpurposescheduledprocedurestepstatus", &tags::GeneralPurposeScheduledProcedureStepStatus),
+        ("regionofresidence", &tags::RegionofResidence),
+        ("protocolname", &tags::ProtocolName),
+        ("framelabelvector", &tags::FrameLabelVector),
+        ("gpsdestlongitude", &tags::GPSDestLongitude),
+        ("octfocaldistance", &tags::OCTFocalDistance),
+        ("histogramfirstbinvalue", &tags::HistogramFirstBinValue),
+        ("modifiedimagetime", &tags::ModifiedImageTime),
+        ("interpretationtranscriptiondate", &tags::InterpretationTranscriptionDate),
+        ("energywindowvector", &tags::EnergyWindowVector),
+        ("reconstructionprotocolelementsequence", &tags::ReconstructionProtocolElementSequence),
+        ("threatroibitmap", &tags::ThreatROIBitmap),
+        ("referencedrealworldvaluemappinginstancesequence", &tags::ReferencedRealWorldValueMappingInstanceSequence),
+        ("fluencemode", &tags::FluenceMode),
+        ("blendingsequence", &tags::BlendingSequence),
+        ("imagetriggerdelay", &tags::ImageTriggerDelay),
         ("ycoordinatescenterpixelviewangle", &tags::YCoordinatesCenterPixelViewAngle),
-        ("targetrefraction", &tags::TargetRefraction),
-        ("expectedinvivomeasurementvalueindex", &tags::ExpectedInVivoMeasurementValueIndex),
-        ("primaryanatomicstructuresequence", &tags::PrimaryAnatomicStructureSequence),
-        ("axialmash", &tags::AxialMash),
-        ("curvaturetype", &tags::CurvatureType),
-        ("scanspotreorderingallowed", &tags::ScanSpotReorderingAllowed),
+        ("controlpointorientation", &tags::ControlPointOrientation),
+        ("diffusionbvaluexy", &tags::DiffusionbvalueXY),
+        ("informationissuedatetime", &tags::InformationIssueDateTime),
+        ("threatdetectionalgorithmandversion", &tags::ThreatDetectionAlgorithmandVersion),
+        ("sourcedescription", &tags::SourceDescription),
+        ("procedurestepprogressinformationsequence", &tags::ProcedureStepProgressInformationSequence),
+        ("refractiveindexapplied", &tags::RefractiveIndexApplied),
+        ("exposurecontrolmode", &tags::ExposureControlMode),
+        ("framedisplayshuttersequence", &tags::FrameDisplayShutterSequence),
+        ("machinespecifictreatmentterminationcodesequence", &tags::MachineSpecificTreatmentTerminationCodeSequence),
+        ("fixationlightpolarangletolerance", &tags::FixationLightPolarAngleTolerance),
+        ("modeofpercutaneousaccesssequence", &tags::ModeofPercutaneousAccessSequence),
+        ("pertinentsopclassesinstudy", &tags::PertinentSOPClassesinStudy),
+        ("rawdatahandling", &tags::RawDataHandling),
+        ("surgicaltechnique", &tags::SurgicalTechnique),
+        ("keratoconuspredictionindex", &tags::KeratoconusPredictionIndex),
+        ("parallelrtbeamdelimiterboundaries", &tags::ParallelRTBeamDelimiterBoundaries),
+        ("blockslabsequence", &tags::BlockSlabSequence),
+        ("graphicdata", &tags::GraphicData),
+        ("numberofrangemodulators", &tags::NumberofRangeModulators),
+        ("procedurecontextsequence_trial", &tags::ProcedureContextSequence_Trial),
+        ("patientsupportaccessorycode", &tags::PatientSupportAccessoryCode),
+        ("radiationdosesequence", &tags::RadiationDoseSequence),
+        ("requestedprocedurepriority", &tags::RequestedProcedurePriority),
+        ("scantype", &tags::ScanType),
+        ("sequencevariant", &tags::SequenceVariant),
+        ("resultsdistributionlistsequence", &tags::ResultsDistributionListSequence),
+        ("petdetectormotiondetailssequence", &tags::PETDetectorMotionDetailsSequence),
+        ("referencedophthalmicaxialmeasurementssequence", &tags::ReferencedOphthalmicAxialMeasurementsSequence),
+        ("graphicobjectsequence", &tags::GraphicObjectSequence),
+        ("anatomicregionsinstudycodesequence", &tags::AnatomicRegionsinStudyCodeSequence),
+        ("verifyingobserversequence", &tags::VerifyingObserverSequence),
+        ("blocknumber", &tags::BlockNumber),
+        ("inventorylevel", &tags::InventoryLevel),
+        ("activeaperture", &tags::ActiveAperture),
+        ("phasedescription", &tags::PhaseDescription),
+        ("templateextensionflag", &tags::TemplateExtensionFlag),
+        ("consultingphysiciansname", &tags::ConsultingPhysiciansName),
+        ("numberofwaveformchannels", &tags::NumberofWaveformChannels),
+        ("referencedpatientphotosequence", &tags::ReferencedPatientPhotoSequence),
+        ("rtradiationsetdeliveryusage", &tags::RTRadiationSetDeliveryUsage),
+        ("isocentertowedgetraydistance", &tags::IsocentertoWedgeTrayDistance),
+        ("includesimagingsubject", &tags::IncludesImagingSubject),
+        ("referencedblocknumber", &tags::ReferencedBlockNumber),
+        ("patientsage", &tags::PatientsAge),
+        ("commentsontheperformedprocedurestep", &tags::CommentsonthePerformedProcedureStep),
+        ("instancecreatoruid", &tags::InstanceCreatorUID),
+        ("energywindowcenterline", &tags::EnergyWindowCenterline),
+        ("plane", &tags::Plane),
+        ("terminationcardiactriggercountthreshold", &tags::TerminationCardiacTriggerCountThreshold),
+        ("waveformchannelnumber", &tags::WaveformChannelNumber),
+        ("directsegmentreferencesequence", &tags::DirectSegmentReferenceSequence),
+        ("outputinformationsequence", &tags::OutputInformationSequence),
+        ("roinumber", &tags::ROINumber),
+        ("textvalue", &tags::TextValue),
+        ("imageorientationpatient", &tags::ImageOrientationPatient),
+        ("fluencemodeid", &tags::FluenceModeID),
+        ("textcolorcielabvalue", &tags::TextColorCIELabValue),
+        ("exposurestatus", &tags::ExposureStatus),
+        ("framepixelshiftsequence", &tags::FramePixelShiftSequence),
+        ("therapeuticrolecategorycodesequence", &tags::TherapeuticRoleCategoryCodeSequence),
+        ("detectoractiveareatlhcposition", &tags::DetectorActiveAreaTLHCPosition),
+        ("rotationvector", &tags::RotationVector),
+        ("nominalbeamenergy", &tags::NominalBeamEnergy),
+        ("primitivepointindexlist", &tags::PrimitivePointIndexList),
+        ("outlineleftverticaledge", &tags::OutlineLeftVerticalEdge),
+        ("configurationinformation", &tags::ConfigurationInformation),
+        ("excitationenergy", &tags::ExcitationEnergy),
+        ("segmentdescription", &tags::SegmentDescription),
+        ("transducerresponsesequence", &tags::TransducerResponseSequence),
+        ("patientsupporttype", &tags::PatientSupportType),
+        ("contextuid", &tags::ContextUID),
+        ("instructionindex", &tags::InstructionIndex),
+        ("devicediameter", &tags::DeviceDiameter),
+        ("printerconfigurationsequence", &tags::PrinterConfigurationSequence),
+        ("devicepositionparametersequence", &tags::DevicePositionParameterSequence),
+        ("studyarrivaldate", &tags::StudyArrivalDate),
+        ("identicaldocumentssequence", &tags::IdenticalDocumentsSequence),
+        ("aslslabthickness", &tags::ASLSlabThickness),
+        ("patientsupportangle", &tags::PatientSupportAngle),
+        ("localizeddeviationfromnormal", &tags::LocalizedDeviationFromNormal),
+        ("overlaydescriptorblue", &tags::OverlayDescriptorBlue),
+        ("rtradiationsetdeliverynumber", &tags::RTRadiationSetDeliveryNumber),
+        ("petframeacquisitionsequence", &tags::PETFrameAcquisitionSequence),
+        ("offsetofthefirstdirectoryrecordoftherootdirectoryentity", &tags::OffsetoftheFirstDirectoryRecordoftheRootDirectoryEntity),
+        ("devicetypecodesequence", &tags::DeviceTypeCodeSequence),
+        ("filterthicknessmaximum", &tags::FilterThicknessMaximum),
         ("swivelrange", &tags::SwivelRange),
-        ("imageboxlargescrollamount", &tags::ImageBoxLargeScrollAmount),
-        ("energyunitcodesequence", &tags::EnergyUnitCodeSequence),
-        ("wedgematerialvelocity", &tags::WedgeMaterialVelocity),
-        ("gammavalue", &tags::GammaValue),
-        ("softtissuefocusthermalindex", &tags::SoftTissuefocusThermalIndex),
-        ("patientsbirthname", &tags::PatientsBirthName),
-        ("modalitiesinstudy", &tags::ModalitiesinStudy),
-        ("damping", &tags::Damping),
-        ("advancedblendingsequence", &tags::AdvancedBlendingSequence),
-        ("continuationstartmeterset", &tags::ContinuationStartMeterset),
-        ("alarmdecision", &tags::AlarmDecision),
-        ("sourceframeofreferenceuid", &tags::SourceFrameofReferenceUID),
-        ("windowcenter", &tags::WindowCenter),
-        ("multienergyctxraysourcesequence", &tags::MultienergyCTXRaySourceSequence),
-        ("evaluatorname", &tags::EvaluatorName),
-        ("digitalfilterorder", &tags::DigitalFilterOrder),
-        ("axialpower", &tags::AxialPower),
-        ("treatmentmachinesequence", &tags::TreatmentMachineSequence),
-        ("vectordimensionality", &tags::VectorDimensionality),
-        ("continuityofcontent", &tags::ContinuityOfContent),
-        ("referencedseriessequence", &tags::ReferencedSeriesSequence),
-        ("reportnumber", &tags::ReportNumber),
-        ("ctreconstructionsequence", &tags::CTReconstructionSequence),
-        ("shapetype", &tags::ShapeType),
-        ("tag_3dplanenormal", &tags::Tag_3DPlaneNormal),
-        ("resultsnormalssequence", &tags::ResultsNormalsSequence),
-        ("tag_2dlinecoordinates", &tags::Tag_2DLineCoordinates),
-        ("itemdelimitationitem", &tags::ItemDelimitationItem),
-        ("qualitycontrolsubjecttypecodesequence", &tags::QualityControlSubjectTypeCodeSequence),
-        ("imagingaperturesequence", &tags::ImagingApertureSequence),
-        ("radiationgenerationmodemachinecodesequence", &tags::RadiationGenerationModeMachineCodeSequence),
-        ("spectroscopyacquisitionphasecolumns", &tags::SpectroscopyAcquisitionPhaseColumns),
-        ("finitevolume", &tags::FiniteVolume),
+        ("reasonforchannelomissiondescription", &tags::ReasonforChannelOmissionDescription),
+        ("secondarypositionerincrement", &tags::SecondaryPositionerIncrement),
+        ("tag_2dplanecoordinatessequence", &tags::Tag_2DPlaneCoordinatesSequence),
+        ("vectorgriddata", &tags::VectorGridData),
+        ("patientsupportrotationdirection", &tags::PatientSupportRotationDirection),
         ("detectorisocenterprimaryangle", &tags::DetectorIsocenterPrimaryAngle),
-        ("compensatorid", &tags::CompensatorID),
-        ("includedisplayapplication", &tags::IncludeDisplayApplication),
-        ("chemicalshiftmaximumintegrationlimitinhz", &tags::ChemicalShiftMaximumIntegrationLimitinHz),
-        ("fixationeye", &tags::FixationEye),
-        ("longitudinaltemporalinformationmodified", &tags::LongitudinalTemporalInformationModified),
-        ("quantifieddefect", &tags::QuantifiedDefect),
-        ("scanarctype", &tags::ScanArcType),
-        ("referencecoordinates", &tags::ReferenceCoordinates),
-        ("implanttemplate3dmodelsurfacenumber", &tags::ImplantTemplate3DModelSurfaceNumber),
-        ("radiationmachinename", &tags::RadiationMachineName),
-        ("unifiedprocedurestepperformedproceduresequence", &tags::UnifiedProcedureStepPerformedProcedureSequence),
-        ("teletherapyradiationtype", &tags::TeletherapyRadiationType),
-        ("translationratey", &tags::TranslationRateY),
-        ("mrdrdirectoryrecordoffset", &tags::MRDRDirectoryRecordOffset),
-        ("surfacesequence", &tags::SurfaceSequence),
-        ("implantassemblytemplatetargetanatomysequence", &tags::ImplantAssemblyTemplateTargetAnatomySequence),
-        ("detectoractiveareaorientation", &tags::DetectorActiveAreaOrientation),
-        ("patientgantryrelationshipcodesequence", &tags::PatientGantryRelationshipCodeSequence),
-        ("referencedstoragemediasequence", &tags::ReferencedStorageMediaSequence),
-        ("pseudocolortype", &tags::PseudoColorType),
-        ("anatomicregionsinstudycodesequence", &tags::AnatomicRegionsinStudyCodeSequence),
-        ("velocityencodingacquisitionsequence", &tags::VelocityEncodingAcquisitionSequence),
-        ("cornealtopographysurface", &tags::CornealTopographySurface),
-        ("steeringangle", &tags::SteeringAngle),
-        ("instructionperformancecomment", &tags::InstructionPerformanceComment),
-        ("timeslotvector", &tags::TimeSlotVector),
-        ("headfixationangle", &tags::HeadFixationAngle),
-        ("datablockdescription", &tags::DataBlockDescription),
-        ("visualfieldtestpointxcoordinate", &tags::VisualFieldTestPointXCoordinate),
-        ("energywindownumber", &tags::EnergyWindowNumber),
-        ("transformationalgorithmsequence", &tags::TransformationAlgorithmSequence),
-        ("component1referencedid", &tags::Component1ReferencedID),
-        ("clinicaltrialtimepointid", &tags::ClinicalTrialTimePointID),
-        ("radionuclidetotaldose", &tags::RadionuclideTotalDose),
-        ("positionacquisitiontemplatedescription", &tags::PositionAcquisitionTemplateDescription),
-        ("contextgrouplocalversion", &tags::ContextGroupLocalVersion),
-        ("excessivefalsenegatives", &tags::ExcessiveFalseNegatives),
-        ("badpixelimage", &tags::BadPixelImage),
-        ("reasonforperformedprocedurecodesequence", &tags::ReasonForPerformedProcedureCodeSequence),
-        ("equivalentconceptualvolumeinstancereferencesequence", &tags::EquivalentConceptualVolumeInstanceReferenceSequence),
-        ("chairheadframeposition", &tags::ChairHeadFramePosition),
-        ("numberofaverages", &tags::NumberofAverages),
-        ("multiplanarreconstructionstyle", &tags::MultiPlanarReconstructionStyle),
-        ("excessivefalsepositivesdataflag", &tags::ExcessiveFalsePositivesDataFlag),
-        ("clinicaltrialprotocolname", &tags::ClinicalTrialProtocolName),
-        ("beamdosetype", &tags::BeamDoseType),
-        ("bulkmotionstatus", &tags::BulkMotionStatus),
-        ("volumetricannotationsequence", &tags::VolumetricAnnotationSequence),
-        ("ophthalmicaxiallengthqualitymetrictypedescription", &tags::OphthalmicAxialLengthQualityMetricTypeDescription),
-        ("sphericallenspower", &tags::SphericalLensPower),
-        ("compensatorrelativestoppingpowerratio", &tags::CompensatorRelativeStoppingPowerRatio),
-        ("generalmatchingsequence", &tags::GeneralMatchingSequence),
-        ("ophthalmicaxiallengthsequence", &tags::OphthalmicAxialLengthSequence),
-        ("respiratorymotioncompensationtechniquedescription", &tags::RespiratoryMotionCompensationTechniqueDescription),
-        ("wedgenumber", &tags::WedgeNumber),
-        ("lensspecification", &tags::LensSpecification),
-        ("diameterofcircularoutline", &tags::DiameterofCircularOutline),
-        ("coordinatesystemtransformrotationandscalematrix", &tags::CoordinateSystemTransformRotationandScaleMatrix),
-        ("tracksetnumber", &tags::TrackSetNumber),
-        ("substanceadministrationapproval", &tags::SubstanceAdministrationApproval),
-        ("contrastadministrationprofilesequence", &tags::ContrastAdministrationProfileSequence),
-        ("radiopharmaceuticaladministrationeventuid", &tags::RadiopharmaceuticalAdministrationEventUID),
-        ("navigationdisplayset", &tags::NavigationDisplaySet),
-        ("maximumcornealcurvaturelocation", &tags::MaximumCornealCurvatureLocation),
-        ("visualevaluationtestsequence", &tags::VisualEvaluationTestSequence),
-        ("numberofstudyrelatedinstances", &tags::NumberofStudyRelatedInstances),
-        ("imagepresentationcomments", &tags::ImagePresentationComments),
-        ("pixelvaluetransformationsequence", &tags::PixelValueTransformationSequence),
-        ("rationalnumeratorvalue", &tags::RationalNumeratorValue),
+        ("requestedimagesizeflag", &tags::RequestedImageSizeFlag),
+        ("treatmentdeviceidentificationsequence", &tags::TreatmentDeviceIdentificationSequence),
+        ("startdensitythreshold", &tags::StartDensityThreshold),
+        ("unassignedperframeconvertedattributessequence", &tags::UnassignedPerFrameConvertedAttributesSequence),
+        ("elementpitchb", &tags::ElementPitchB),
+        ("multiplexgrouplabel", &tags::MultiplexGroupLabel),
+        ("referencedoverlayplanesequence", &tags::ReferencedOverlayPlaneSequence),
+        ("rtprescriptionsequence", &tags::RTPrescriptionSequence),
+        ("compoundgraphictype", &tags::CompoundGraphicType),
+        ("operatingmodetype", &tags::OperatingModeType),
+        ("pvcrejection", &tags::PVCRejection),
+        ("extendedcodemeaning", &tags::ExtendedCodeMeaning),
+        ("patienttreatmentpreparationprocedureparameterdescription", &tags::PatientTreatmentPreparationProcedureParameterDescription),
+        ("ophthalmicaxiallengthmeasurementmodified", &tags::OphthalmicAxialLengthMeasurementModified),
+        ("relationshipsequence_trial", &tags::RelationshipSequence_Trial),
+        ("cumulativedosereferencecoefficient", &tags::CumulativeDoseReferenceCoefficient),
+        ("carrierid", &tags::CarrierID),
+        ("ultrasoundacquisitiongeometry", &tags::UltrasoundAcquisitionGeometry),
+        ("ticklabelalignment", &tags::TickLabelAlignment),
+        ("snoutposition", &tags::SnoutPosition),
+        ("parallelreductionfactorsecondinplane", &tags::ParallelReductionFactorSecondInplane),
+        ("referencedtreatmentrecordsequence", &tags::ReferencedTreatmentRecordSequence),
+        ("selectordavalue", &tags::SelectorDAValue),
+        ("correctionvalue", &tags::CorrectionValue),
+        ("surfacemeshprimitivessequence", &tags::SurfaceMeshPrimitivesSequence),
+        ("rangemodulatorgatingstopvalue", &tags::RangeModulatorGatingStopValue),
+        ("greenpalettecolorlookuptabledata", &tags::GreenPaletteColorLookupTableData),
+        ("treatmentprotocols", &tags::TreatmentProtocols),
+        ("conceptnamecodesequence", &tags::ConceptNameCodeSequence),
+        ("scheduledprocedurestepexpirationdatetime", &tags::ScheduledProcedureStepExpirationDateTime),
+        ("excessivefixationlossesdataflag", &tags::ExcessiveFixationLossesDataFlag),
+        ("positioningmethodcodesequence", &tags::PositioningMethodCodeSequence),
+        ("keratometricaxis", &tags::KeratometricAxis),
+        ("visualfieldtestpointycoordinate", &tags::VisualFieldTestPointYCoordinate),
+        ("expectedinvivomeasurementvalueindex", &tags::ExpectedInVivoMeasurementValueIndex),
+        ("scheduledstationgeographiclocationcodesequence", &tags::ScheduledStationGeographicLocationCodeSequence),
+        ("gantrypitchrotationdirection", &tags::GantryPitchRotationDirection),
+        ("rtsegmentannotationindex", &tags::RTSegmentAnnotationIndex),
+        ("signaldomainrows", &tags::SignalDomainRows),
+        ("clinicaltrialseriesid", &tags::ClinicalTrialSeriesID),
+        ("patientsupportangletolerance", &tags::PatientSupportAngleTolerance),
+        ("tablepositionsequence", &tags::TablePositionSequence),
+        ("rangemodulatorgatingstartvalue", &tags::RangeModulatorGatingStartValue),
+        ("roboticbaselocationindicator", &tags::RoboticBaseLocationIndicator),
+        ("specimenlocalizationcontentitemsequence", &tags::SpecimenLocalizationContentItemSequence),
+        ("scheduledproceduresteppriority", &tags::ScheduledProcedureStepPriority),
+        ("rtpatientpositionsequence", &tags::RTPatientPositionSequence),
+        ("recordedsnoutsequence", &tags::RecordedSnoutSequence),
+        ("fillstylesequence", &tags::FillStyleSequence),
+        ("overlayorigin", &tags::OverlayOrigin),
+        ("replacedimplantassemblytemplatesequence", &tags::ReplacedImplantAssemblyTemplateSequence),
+        ("outputpower", &tags::OutputPower),
+        ("errorcomment", &tags::ErrorComment),
         ("conceptualvolumeuid", &tags::ConceptualVolumeUID),
-        ("proceduresteprelationshiptype", &tags::ProcedureStepRelationshipType),
-        ("gridresolution", &tags::GridResolution),
-        ("devicespecificacquisitionparametersequence", &tags::DeviceSpecificAcquisitionParameterSequence),
-        ("errorid", &tags::ErrorID),
-        ("materialpropertiesdescription", &tags::MaterialPropertiesDescription),
-        ("encapsulateddocument", &tags::EncapsulatedDocument),
-        ("hpglpendescription", &tags::HPGLPenDescription),
-        ("hangingprotocolcreator", &tags::HangingProtocolCreator),
-        ("realworldvaluemappingsequence", &tags::RealWorldValueMappingSequence),
-        ("manipulatedimage", &tags::ManipulatedImage),
-        ("fileoffsetincontainer", &tags::FileOffsetinContainer),
-        ("transducerorientationsequence", &tags::TransducerOrientationSequence),
-        ("encryptedcontent", &tags::EncryptedContent),
-        ("numberofpolygonalvertices", &tags::NumberofPolygonalVertices),
-        ("cylinderaxis", &tags::CylinderAxis),
-        ("startrespiratorytriggercountthreshold", &tags::StartRespiratoryTriggerCountThreshold),
-        ("phasevector", &tags::PhaseVector),
-        ("sourceaxisdistance", &tags::SourceAxisDistance),
-        ("tomotherapeuticcontrolpointsequence", &tags::TomotherapeuticControlPointSequence),
-        ("priorrecordkey", &tags::PriorRecordKey),
-        ("specimenuid", &tags::SpecimenUID),
-        ("numberofvectors", &tags::NumberofVectors),
-        ("iolpowersequence", &tags::IOLPowerSequence),
-        ("framedelay", &tags::FrameDelay),
-        ("roiderivationalgorithmidentificationsequence", &tags::ROIDerivationAlgorithmIdentificationSequence),
-        ("frameacquisitiondatetime", &tags::FrameAcquisitionDateTime),
-        ("proceduresteplabel", &tags::ProcedureStepLabel),
-        ("recommendedrotationpoint", &tags::RecommendedRotationPoint),
-        ("configurationinformationdescription", &tags::ConfigurationInformationDescription),
-        ("referencedsopinstanceuidinfile", &tags::ReferencedSOPInstanceUIDinFile),
-        ("textstylesequence", &tags::TextStyleSequence),
-        ("controlpointdeliverysequence", &tags::ControlPointDeliverySequence),
-        ("acquisitiontaskindex", &tags::AcquisitionTaskIndex),
-        ("contrastbolust1relaxivity", &tags::ContrastBolusT1Relaxivity),
-        ("dvhvolumeunits", &tags::DVHVolumeUnits),
-        ("datapointrows", &tags::DataPointRows),
-        ("containercomponentsequence", &tags::ContainerComponentSequence),
-        ("acquisitiontimesynchronized", &tags::AcquisitionTimeSynchronized),
-        ("amplifiertype", &tags::AmplifierType),
-        ("digitalsignatureuid", &tags::DigitalSignatureUID),
-        ("framereferencedatetime", &tags::FrameReferenceDateTime),
-        ("contextgroupextensioncreatoruid", &tags::ContextGroupExtensionCreatorUID),
-        ("externalcontourentrypoint", &tags::ExternalContourEntryPoint),
+        ("channelnumber", &tags::ChannelNumber),
+        ("slaborientation", &tags::SlabOrientation),
+        ("visualfieldtestnormalsflag", &tags::VisualFieldTestNormalsFlag),
+        ("diffusionbvaluexz", &tags::DiffusionbvalueXZ),
+        ("patientsupportpositiondeviceparametersequence", &tags::PatientSupportPositionDeviceParameterSequence),
+        ("referencedperformedprocedurestepsequence", &tags::ReferencedPerformedProcedureStepSequence),
+        ("gridid", &tags::GridID),
+        ("baselinecorrection", &tags::BaselineCorrection),
+        ("referencedophthalmicaxiallengthmeasurementqcimagesequence", &tags::ReferencedOphthalmicAxialLengthMeasurementQCImageSequence),
+        ("certifiedtimestamp", &tags::CertifiedTimestamp),
+        ("decouplingfrequency", &tags::DecouplingFrequency),
+        ("magnificationtype", &tags::MagnificationType),
+        ("tracksetlabel", &tags::TrackSetLabel),
+        ("transduceridentificationsequence", &tags::TransducerIdentificationSequence),
+        ("contentitemmodifiersequence", &tags::ContentItemModifierSequence),
+        ("convolutionkernelgroup", &tags::ConvolutionKernelGroup),
+        ("dischargediagnosisdescription", &tags::DischargeDiagnosisDescription),
+        ("mprviewwidth", &tags::MPRViewWidth),
+        ("deadtimecorrectionflag", &tags::DeadTimeCorrectionFlag),
+        ("serviceepisodedescription", &tags::ServiceEpisodeDescription),
+        ("patientsdeathdateinalternativecalendar", &tags::PatientsDeathDateinAlternativeCalendar),
+        ("sopauthorizationdatetime", &tags::SOPAuthorizationDateTime),
+        ("targetmaterialsequence", &tags::TargetMaterialSequence),
+        ("realworldvaluefirstvaluemapped", &tags::RealWorldValueFirstValueMapped),
+        ("devicealternateidentifier", &tags::DeviceAlternateIdentifier),
+        ("tabletopeccentricaxisdistance", &tags::TableTopEccentricAxisDistance),
+        ("calculationcommenttype", &tags::CalculationCommentType),
+        ("assertiondatetime", &tags::AssertionDateTime),
+        ("referencedperformedprotocolsequence", &tags::ReferencedPerformedProtocolSequence),
+        ("treatmentdate", &tags::TreatmentDate),
+        ("metabolitemapdescription", &tags::MetaboliteMapDescription),
+        ("exposuretimeinms", &tags::ExposureTimeinms),
+        ("gpsdestlatituderef", &tags::GPSDestLatitudeRef),
+        ("timebasedimagesetssequence", &tags::TimeBasedImageSetsSequence),
+        ("metabolitemapcodesequence", &tags::MetaboliteMapCodeSequence),
+        ("radiusofcircularshutter", &tags::RadiusofCircularShutter),
+        ("wedgedefinitionsequence", &tags::WedgeDefinitionSequence),
+        ("selectorulvalue", &tags::SelectorULValue),
+        ("referenceimagenumber", &tags::ReferenceImageNumber),
+        ("octacquisitiondomain", &tags::OCTAcquisitionDomain),
+        ("blockmountingposition", &tags::BlockMountingPosition),
+        ("intendedphasestartdate", &tags::IntendedPhaseStartDate),
+        ("dosimetricobjectivetypecodesequence", &tags::DosimetricObjectiveTypeCodeSequence),
+        ("blockslabthickness", &tags::BlockSlabThickness),
+        ("pyramiduid", &tags::PyramidUID),
+        ("frameanatomysequence", &tags::FrameAnatomySequence),
+        ("segmentationalgorithmidentificationsequence", &tags::SegmentationAlgorithmIdentificationSequence),
+        ("luminancecharacteristicsid", &tags::LuminanceCharacteristicsID),
+        ("treatmentpositionsequence", &tags::TreatmentPositionSequence),
+        ("bscanrate", &tags::BscanRate),
+        ("apexposition", &tags::ApexPosition),
+        ("wedgetype", &tags::WedgeType),
+        ("threatcategory", &tags::ThreatCategory),
+        ("pupilcentroidxcoordinate", &tags::PupilCentroidXCoordinate),
+        ("floatpixeldata", &tags::FloatPixelData),
+        ("contributingsopinstancesreferencesequence", &tags::ContributingSOPInstancesReferenceSequence),
+        ("studydate", &tags::StudyDate),
+        ("transducerorientationsequence", &tags::TransducerOrientationSequence),
+        ("doublefloatrealworldvaluelastvaluemapped", &tags::DoubleFloatRealWorldValueLastValueMapped),
+        ("confocalmicroscopyimageframetypesequence", &tags::ConfocalMicroscopyImageFrameTypeSequence),
+        ("alphapalettecolorlookuptabledata", &tags::AlphaPaletteColorLookupTableData),
+        ("selectorssvalue", &tags::SelectorSSValue),
+        ("nonuniformradialsamplingcorrected", &tags::NonuniformRadialSamplingCorrected),
+        ("velocityofsound", &tags::VelocityofSound),
+        ("numberofgeneralaccessories", &tags::NumberofGeneralAccessories),
+        ("wedgeincontactwidth", &tags::WedgeInContactWidth),
+        ("predictedrefractiveerror", &tags::PredictedRefractiveError),
+        ("treatmenttoleranceviolationtypecodesequence", &tags::TreatmentToleranceViolationTypeCodeSequence),
+        ("detectoractiveshape", &tags::DetectorActiveShape),
+        ("patientorientationinframesequence", &tags::PatientOrientationinFrameSequence),
+        ("brightnessvalue", &tags::BrightnessValue),
+        ("nominalmaxenergy", &tags::NominalMaxEnergy),
+        ("treatmentterminationstatus", &tags::TreatmentTerminationStatus),
         ("instancenumber", &tags::InstanceNumber),
-        ("waveformsampleinterpretation", &tags::WaveformSampleInterpretation),
-        ("screeningbaselinemeasuredsequence", &tags::ScreeningBaselineMeasuredSequence),
-        ("displayedareatoplefthandcorner", &tags::DisplayedAreaTopLeftHandCorner),
-        ("specialneeds", &tags::SpecialNeeds),
-        ("numberofvolumetriccurvepoints", &tags::NumberofVolumetricCurvePoints),
-        ("rotationangle", &tags::RotationAngle),
-        ("regionpixelshiftsequence", &tags::RegionPixelShiftSequence),
-        ("temperature", &tags::Temperature),
-        ("messageidbeingrespondedto", &tags::MessageIDBeingRespondedTo),
-        ("dvhmeandose", &tags::DVHMeanDose),
-        ("rtaccessorydeviceslotid", &tags::RTAccessoryDeviceSlotID),
-        ("steadystatepulsesequence", &tags::SteadyStatePulseSequence),
-        ("framereferencetime", &tags::FrameReferenceTime),
-        ("framedetectorparameterssequence", &tags::FrameDetectorParametersSequence),
-        ("fractionationnotes", &tags::FractionationNotes),
-        ("nonconformingdataelementvalue", &tags::NonconformingDataElementValue),
-        ("environmentalconditions", &tags::EnvironmentalConditions),
-        ("implanttemplategroupdescription", &tags::ImplantTemplateGroupDescription),
-        ("startrelativedensitydifferencethreshold", &tags::StartRelativeDensityDifferenceThreshold),
-        ("breastsupportisocentersecondaryangle", &tags::BreastSupportIsocenterSecondaryAngle),
-        ("referencedfilmsessionsequence", &tags::ReferencedFilmSessionSequence),
-        ("pixelorigininterpretation", &tags::PixelOriginInterpretation),
-        ("fnumber", &tags::FNumber),
-        ("numberoftriggersinphase", &tags::NumberofTriggersinPhase),
-        ("measuredapdimension", &tags::MeasuredAPDimension),
-        ("annotationgroupalgorithmidentificationsequence", &tags::AnnotationGroupAlgorithmIdentificationSequence),
-        ("gpsdestbearingref", &tags::GPSDestBearingRef),
-        ("measureddosereferencesequence", &tags::MeasuredDoseReferenceSequence),
-        ("treatmenttime", &tags::TreatmentTime),
-        ("certificatetype", &tags::CertificateType),
+        ("positivecatchtrialsquantity", &tags::PositiveCatchTrialsQuantity),
+        ("deformableregistrationsequence", &tags::DeformableRegistrationSequence),
+        ("dateofsecondarycapture", &tags::DateofSecondaryCapture),
+        ("maximumfractionalvalue", &tags::MaximumFractionalValue),
+        ("exclusionduration", &tags::ExclusionDuration),
+        ("transfertubelength", &tags::TransferTubeLength),
+        ("decouplednucleus", &tags::DecoupledNucleus),
+        ("couplingtechnique", &tags::CouplingTechnique),
+        ("recommendeddisplayframerate", &tags::RecommendedDisplayFrameRate),
+        ("ooisize", &tags::OOISize),
+        ("contentcreatorsidentificationcodesequence", &tags::ContentCreatorsIdentificationCodeSequence),
+        ("referencedaccessionsequence_trial", &tags::ReferencedAccessionSequence_Trial),
+        ("referencedrangeshifternumber", &tags::ReferencedRangeShifterNumber),
+        ("referencedbeamsequence", &tags::ReferencedBeamSequence),
         ("blendingoperationtype", &tags::BlendingOperationType),
-        ("referenceairkermarate", &tags::ReferenceAirKermaRate),
-        ("intendeddeliveryduration", &tags::IntendedDeliveryDuration),
-        ("interpretationrecordeddate", &tags::InterpretationRecordedDate),
-        ("subjectdistance", &tags::SubjectDistance),
-        ("longvertexpointindexlist", &tags::LongVertexPointIndexList),
-        ("singlecollimationwidth", &tags::SingleCollimationWidth),
-        ("patientsbirthtime", &tags::PatientsBirthTime),
-        ("generalizeddefectcorrectedsensitivitydeviationvalue", &tags::GeneralizedDefectCorrectedSensitivityDeviationValue),
-        ("channeldescriptioncodesequence", &tags::ChannelDescriptionCodeSequence),
+        ("boundingboxannotationunits", &tags::BoundingBoxAnnotationUnits),
+        ("positionreferenceindicator", &tags::PositionReferenceIndicator),
+        ("beamholdtransition", &tags::BeamHoldTransition),
+        ("sourceconceptualvolumesequence", &tags::SourceConceptualVolumeSequence),
+        ("tag_2ddegreeoffreedomaxis", &tags::Tag_2DDegreeofFreedomAxis),
+        ("multiplexedaudiochannelsdescriptioncodesequence", &tags::MultiplexedAudioChannelsDescriptionCodeSequence),
+        ("distributionname", &tags::DistributionName),
+        ("studycomponentstatusid", &tags::StudyComponentStatusID),
+        ("displaysetpatientorientation", &tags::DisplaySetPatientOrientation),
+        ("respiratorysignalsourceid", &tags::RespiratorySignalSourceID),
+        ("photometricinterpretation", &tags::PhotometricInterpretation),
+        ("columnsfornthordercoefficients", &tags::ColumnsForNthOrderCoefficients),
+        ("mac", &tags::MAC),
+        ("displaycalibrationresultsequence", &tags::DisplayCalibrationResultSequence),
+        ("privatedataelementvaluemultiplicity", &tags::PrivateDataElementValueMultiplicity),
+        ("luminancevalue", &tags::LuminanceValue),
+        ("codenumberformat", &tags::CodeNumberFormat),
+        ("numberofpulses", &tags::NumberofPulses),
+        ("numberoffilms", &tags::NumberofFilms),
+        ("referencedsamplepositions", &tags::ReferencedSamplePositions),
+        ("isocentertorangemodulatordistance", &tags::IsocentertoRangeModulatorDistance),
+        ("lossyimagecompression", &tags::LossyImageCompression),
+        ("aslslabsequence", &tags::ASLSlabSequence),
+        ("multienergyctxraydetectorsequence", &tags::MultienergyCTXRayDetectorSequence),
+        ("tablecolumndefinitionsequence", &tags::TableColumnDefinitionSequence),
+        ("reprojectionmethod", &tags::ReprojectionMethod),
+        ("referencetorecordedsound", &tags::ReferencetoRecordedSound),
+        ("scheduledstationname", &tags::ScheduledStationName),
+        ("subjectarea", &tags::SubjectArea),
+        ("datasetsource", &tags::DataSetSource),
+        ("positionertype", &tags::PositionerType),
+        ("referencedsegmentreferenceindex", &tags::ReferencedSegmentReferenceIndex),
+        ("makernote", &tags::MakerNote),
+        ("luminanceuniformityresultsequence", &tags::LuminanceUniformityResultSequence),
+        ("rtvcommunicationsopclassuid", &tags::RTVCommunicationSOPClassUID),
+        ("referencedsegmentnumber", &tags::ReferencedSegmentNumber),
+        ("fieldofviewsequence", &tags::FieldofViewSequence),
+        ("aslboluscutoffflag", &tags::ASLBolusCutoffFlag),
+        ("structuresetdate", &tags::StructureSetDate),
+        ("pyramiddescription", &tags::PyramidDescription),
+        ("specifiedmeterset", &tags::SpecifiedMeterset),
+        ("item", &tags::Item),
+        ("tablezpositiontoisocenter", &tags::TableZPositiontoIsocenter),
+        ("controlpointsequence", &tags::ControlPointSequence),
+        ("othersmoothingtypesavailable", &tags::OtherSmoothingTypesAvailable),
+        ("tickposition", &tags::TickPosition),
+        ("pulsesequencename", &tags::PulseSequenceName),
+        ("minimumstoredvaluemapped", &tags::MinimumStoredValueMapped),
+        ("rtimageposition", &tags::RTImagePosition),
+        ("omittedapplicationsetupsequence", &tags::OmittedApplicationSetupSequence),
+        ("listofuidmatchingsequence", &tags::ListofUIDMatchingSequence),
+        ("extendedoffsettablelengths", &tags::ExtendedOffsetTableLengths),
+        ("intendedrttreatmentphasesequence", &tags::IntendedRTTreatmentPhaseSequence),
+        ("treatmentsitemodifiercodesequence", &tags::TreatmentSiteModifierCodeSequence),
+        ("tablecolumnnumber", &tags::TableColumnNumber),
+        ("humanperformersorganization", &tags::HumanPerformersOrganization),
+        ("encapsulateddocumentlength", &tags::EncapsulatedDocumentLength),
+        ("temporalrangetype", &tags::TemporalRangeType),
+        ("doublefloatpixelpaddingvalue", &tags::DoubleFloatPixelPaddingValue),
+        ("instructiondescription", &tags::InstructionDescription),
+        ("diffusionacquisitioncodesequence", &tags::DiffusionAcquisitionCodeSequence),
+        ("acquisitionterminationcondition", &tags::AcquisitionTerminationCondition),
+        ("channeldisplaysequence", &tags::ChannelDisplaySequence),
+        ("identifiercodesequence_trial", &tags::IdentifierCodeSequence_Trial),
+        ("planninglandmarkdescription", &tags::PlanningLandmarkDescription),
+        ("parallelrtbeamdelimiteropeningextents", &tags::ParallelRTBeamDelimiterOpeningExtents),
+        ("blockcolumns", &tags::BlockColumns),
+        ("startangle", &tags::StartAngle),
+        ("originalimplanttemplatesequence", &tags::OriginalImplantTemplateSequence),
+        ("rwavetimevector", &tags::RWaveTimeVector),
+        ("pixelintensityrelationshiplutsequence", &tags::PixelIntensityRelationshipLUTSequence),
+        ("beamtasktype", &tags::BeamTaskType),
+        ("radiationatomicnumber", &tags::RadiationAtomicNumber),
+        ("majortickssequence", &tags::MajorTicksSequence),
+        ("annotationcontentsequence", &tags::AnnotationContentSequence),
+        ("wedgedescription", &tags::WedgeDescription),
+        ("originatingsopinstancereferencesequence", &tags::OriginatingSOPInstanceReferenceSequence),
+        ("gantrydetectortilt", &tags::GantryDetectorTilt),
+        ("manufacturersrelatedmodelgroup", &tags::ManufacturersRelatedModelGroup),
+        ("scheduledprocessingapplicationscodesequence", &tags::ScheduledProcessingApplicationsCodeSequence),
+        ("participationtype", &tags::ParticipationType),
+        ("startingmetersetvalueknownflag", &tags::StartingMetersetValueKnownFlag),
+        ("stimuliretestingquantity", &tags::StimuliRetestingQuantity),
+        ("displaysetlabel", &tags::DisplaySetLabel),
+        ("messageid", &tags::MessageID),
+        ("xrayimagereceptortranslation", &tags::XRayImageReceptorTranslation),
+        ("referencedframenumber", &tags::ReferencedFrameNumber),
+        ("institutionaldepartmenttypecodesequence", &tags::InstitutionalDepartmentTypeCodeSequence),
+        ("filesetaccesssequence", &tags::FileSetAccessSequence),
+        ("ophthalmicfov", &tags::OphthalmicFOV),
+        ("detectoractivedimensions", &tags::DetectorActiveDimensions),
+        ("partialviewdescription", &tags::PartialViewDescription),
+        ("viewpointposition", &tags::ViewpointPosition),
+        ("bluepalettecolorlookuptabledescriptor", &tags::BluePaletteColorLookupTableDescriptor),
+        ("breastsupportzpositiontoisocenter", &tags::BreastSupportZPositiontoIsocenter),
+        ("patientsupportpositionspecificationmethod", &tags::PatientSupportPositionSpecificationMethod),
+        ("datasettrailingpadding", &tags::DataSetTrailingPadding),
+        ("referencedchannelshieldnumber", &tags::ReferencedChannelShieldNumber),
+        ("volumetricpresentationinputindex", &tags::VolumetricPresentationInputIndex),
+        ("tag_2dimplanttemplategroupmembermatchingpoint", &tags::Tag_2DImplantTemplateGroupMemberMatchingPoint),
+        ("imageoverlayboxcontentsequence", &tags::ImageOverlayBoxContentSequence),
+        ("measuredvaluesequence", &tags::MeasuredValueSequence),
+        ("beamlimitingdevicepositionsequence", &tags::BeamLimitingDevicePositionSequence),
+        ("manufacturersdeviceidentifier", &tags::ManufacturersDeviceIdentifier),
+        ("patientequipmentrelationshipcodesequence", &tags::PatientEquipmentRelationshipCodeSequence),
+        ("lensstatuscodesequence", &tags::LensStatusCodeSequence),
+        ("correctedimage", &tags::CorrectedImage),
+        ("rtplangeometry", &tags::RTPlanGeometry),
+        ("wedgename", &tags::WedgeName),
+        ("compensatorshapefabricationcodesequence", &tags::CompensatorShapeFabricationCodeSequence),
+        ("petpositionsequence", &tags::PETPositionSequence),
+        ("receiverequipmentsequence", &tags::ReceiverEquipmentSequence),
+        ("totaltimeoffluoroscopy", &tags::TotalTimeofFluoroscopy),
+        ("deliveredpulserepetitioninterval", &tags::DeliveredPulseRepetitionInterval),
+        ("linacenergy", &tags::LINACEnergy),
+        ("radiationgenerationmodemachinecodesequence", &tags::RadiationGenerationModeMachineCodeSequence),
+        ("primarypositionerscanarc", &tags::PrimaryPositionerScanArc),
+        ("anchorpointvisibility", &tags::AnchorPointVisibility),
+        ("fixationsequence", &tags::FixationSequence),
+        ("anatomicstructurespaceorregioncodesequence_trial", &tags::AnatomicStructureSpaceOrRegionCodeSequence_Trial),
+        ("illuminationtypecodesequence", &tags::IlluminationTypeCodeSequence),
+        ("privateinformationcreatoruid", &tags::PrivateInformationCreatorUID),
+        ("commentsonradiationdose", &tags::CommentsonRadiationDose),
+        ("framereferencedatetime", &tags::FrameReferenceDateTime),
+        ("pixeldataproviderurl", &tags::PixelDataProviderURL),
+        ("segmentationtemplateuid", &tags::SegmentationTemplateUID),
+        ("frameprimaryanglevector", &tags::FramePrimaryAngleVector),
+        ("referringphysiciansname", &tags::ReferringPhysiciansName),
+        ("clinicaltrialsitename", &tags::ClinicalTrialSiteName),
+        ("analogfilterrolloff", &tags::AnalogFilterRollOff),
+        ("fractionstatussummarysequence", &tags::FractionStatusSummarySequence),
+        ("segmentcharacteristicsprecedence", &tags::SegmentCharacteristicsPrecedence),
+        ("samplingstepsize", &tags::SamplingStepSize),
+        ("devicemotioncontrolsequence", &tags::DeviceMotionControlSequence),
+        ("keratometricpower", &tags::KeratometricPower),
+        ("framecontentsequence", &tags::FrameContentSequence),
+        ("focallengthin35mmfilm", &tags::FocalLengthIn35mmFilm),
+        ("frameusefulnessgroupsequence", &tags::FrameUsefulnessGroupSequence),
+        ("rtplandate", &tags::RTPlanDate),
+        ("fieldofviewshape", &tags::FieldofViewShape),
+        ("blendingweightconstant", &tags::BlendingWeightConstant),
+        ("requestedlateralitycodesequence", &tags::RequestedLateralityCodeSequence),
+        ("indicationlabel", &tags::IndicationLabel),
+        ("endingrespiratoryamplitude", &tags::EndingRespiratoryAmplitude),
+        ("manufacturersmodelversion", &tags::ManufacturersModelVersion),
+        ("reconstructionendlocationsequence", &tags::ReconstructionEndLocationSequence),
+        ("frameofreferenceuid", &tags::FrameofReferenceUID),
+        ("compensatordivergence", &tags::CompensatorDivergence),
+        ("contrastbolusstarttime", &tags::ContrastBolusStartTime),
+        ("croppingspecificationindex", &tags::CroppingSpecificationIndex),
+        ("acquisitiontasksequence", &tags::AcquisitionTaskSequence),
+        ("spatialfrequencyresponserows", &tags::SpatialFrequencyResponseRows),
+        ("patternoncolorcielabvalue", &tags::PatternOnColorCIELabValue),
+        ("cornealisvalue", &tags::CornealISValue),
+        ("asserteridentificationsequence", &tags::AsserterIdentificationSequence),
+        ("uniformresourcelocator_trial", &tags::UniformResourceLocator_Trial),
+        ("iteminventorydatetime", &tags::ItemInventoryDateTime),
+        ("confocalmode", &tags::ConfocalMode),
+        ("surfacenumber", &tags::SurfaceNumber),
+        ("imageboxpresentationlutflag", &tags::ImageBoxPresentationLUTFlag),
+        ("damping", &tags::Damping),
+        ("collationflag", &tags::CollationFlag),
+        ("largestvalidpixelvalue", &tags::LargestValidPixelValue),
+        ("aslslabnumber", &tags::ASLSlabNumber),
+        ("blendingdisplayinputsequence", &tags::BlendingDisplayInputSequence),
+        ("proceduresteprelationshiptype", &tags::ProcedureStepRelationshipType),
+        ("t2preparation", &tags::T2Preparation),
+        ("ophthalmicaxialmeasurementsdevicetype", &tags::OphthalmicAxialMeasurementsDeviceType),
+        ("lensconstantsequence", &tags::LensConstantSequence),
+        ("compensatormaporientation", &tags::CompensatorMapOrientation),
+        ("confidentialitycode", &tags::ConfidentialityCode),
+        ("measurementfunctions", &tags::MeasurementFunctions),
+        ("density", &tags::Density),
+        ("inconcatenationnumber", &tags::InconcatenationNumber),
+        ("contextgroupversion", &tags::ContextGroupVersion),
+        ("accessorycode", &tags::AccessoryCode),
+        ("roielementalcompositionatomicnumber", &tags::ROIElementalCompositionAtomicNumber),
+        ("intravascularoctframecontentsequence", &tags::IntravascularOCTFrameContentSequence),
+        ("roistandarddeviation", &tags::ROIStandardDeviation),
+        ("materialid", &tags::MaterialID),
+        ("reportdetailsequence_trial", &tags::ReportDetailSequence_Trial),
+        ("requestedimagesize", &tags::RequestedImageSize),
+        ("dataobservationsequence", &tags::DataObservationSequence),
+        ("gantryangletolerance", &tags::GantryAngleTolerance),
+        ("anomalylocatorindicatorsequence", &tags::AnomalyLocatorIndicatorSequence),
+        ("positionacquisitiontemplatedescription", &tags::PositionAcquisitionTemplateDescription),
+        ("codevalue", &tags::CodeValue),
+        ("multienergyctpathindex", &tags::MultienergyCTPathIndex),
+        ("radiationbeamblockthickness", &tags::RadiationBeamBlockThickness),
+        ("parallelrtbeamdelimiterdeviceorientationlabelcodesequence", &tags::ParallelRTBeamDelimiterDeviceOrientationLabelCodeSequence),
+        ("conventionalmachineverificationsequence", &tags::ConventionalMachineVerificationSequence),
+        ("synchronizationchannel", &tags::SynchronizationChannel),
+        ("inventoriedinstancessequence", &tags::InventoriedInstancesSequence),
+        ("stereohorizontalpixeloffset", &tags::StereoHorizontalPixelOffset),
+        ("lowercutofffrequency", &tags::LowerCutoffFrequency),
+        ("applicatorshapereferencedroinumber", &tags::ApplicatorShapeReferencedROINumber),
+        ("dimensionindexsequence", &tags::DimensionIndexSequence),
+        ("breedregistrationnumber", &tags::BreedRegistrationNumber),
+        ("measureddosevalue", &tags::MeasuredDoseValue),
+        ("patientsupportpositiondevicetolerancesequence", &tags::PatientSupportPositionDeviceToleranceSequence),
+        ("respiratorysignalsource", &tags::RespiratorySignalSource),
+        ("strainsource", &tags::StrainSource),
+        ("multiplexgroupuid", &tags::MultiplexGroupUID),
+        ("dosemodename", &tags::DoseModeName),
+        ("calibrationdatasequence", &tags::CalibrationDataSequence),
+        ("sourcetoblocktraydistance", &tags::SourcetoBlockTrayDistance),
+        ("assessmentsummary", &tags::AssessmentSummary),
+        ("modalitylutsequence", &tags::ModalityLUTSequence),
+        ("compleximagecomponent", &tags::ComplexImageComponent),
+        ("uniquedeviceidentifier", &tags::UniqueDeviceIdentifier),
+        ("anatomicperspectivecodesequence_trial", &tags::AnatomicPerspectiveCodeSequence_Trial),
+        ("treatmenttoleranceviolationattributesequence", &tags::TreatmentToleranceViolationAttributeSequence),
+        ("clinicaltrialprotocolethicscommitteeapprovalnumber", &tags::ClinicalTrialProtocolEthicsCommitteeApprovalNumber),
+        ("voitype", &tags::VOIType),
+        ("exposuresondetectorsincelastcalibration", &tags::ExposuresonDetectorSinceLastCalibration),
+        ("setupdevicelabel", &tags::SetupDeviceLabel),
+        ("rtvmetainformationversion", &tags::RTVMetaInformationVersion),
+        ("relativetimeunits", &tags::RelativeTimeUnits),
+        ("volumeofpto", &tags::VolumeofPTO),
+        ("universalentityid", &tags::UniversalEntityID),
+        ("specimensequence", &tags::SpecimenSequence),
+        ("ophthalmicaxiallengthdatasourcedescription", &tags::OphthalmicAxialLengthDataSourceDescription),
+        ("intensifiersize", &tags::IntensifierSize),
+        ("matingfeaturesetssequence", &tags::MatingFeatureSetsSequence),
+        ("referencedstartcontrolpointindex", &tags::ReferencedStartControlPointIndex),
+        ("referencedbolussequence", &tags::ReferencedBolusSequence),
+        ("aslboluscutofftimingsequence", &tags::ASLBolusCutoffTimingSequence),
+        ("octzoffsetapplied", &tags::OCTZOffsetApplied),
+        ("tolerancetablenumber", &tags::ToleranceTableNumber),
+        ("curvereferencedoverlaysequence", &tags::CurveReferencedOverlaySequence),
+        ("cornealvertexlocation", &tags::CornealVertexLocation),
+        ("originator", &tags::Originator),
+        ("controlpointrelativeposition", &tags::ControlPointRelativePosition),
+        ("queuestatus", &tags::QueueStatus),
+        ("languagecodesequence", &tags::LanguageCodeSequence),
+        ("beamstopperposition", &tags::BeamStopperPosition),
+        ("metersetexposure", &tags::MetersetExposure),
+        ("patientorientationmodifiercodesequence", &tags::PatientOrientationModifierCodeSequence),
+        ("contrastbolusvolume", &tags::ContrastBolusVolume),
+        ("viewingdistance", &tags::ViewingDistance),
+        ("weightinglookuptabledata", &tags::WeightingLookupTableData),
+        ("exposureindex", &tags::ExposureIndex),
+        ("localizeddeviationprobability", &tags::LocalizedDeviationProbability),
+        ("finalcumulativemetersetweight", &tags::FinalCumulativeMetersetWeight),
+        ("equipmentcoordinatesystemidentification", &tags::EquipmentCoordinateSystemIdentification),
+        ("alarmdecision", &tags::AlarmDecision),
+        ("verticesofthepolygonalcollimator", &tags::VerticesofthePolygonalCollimator),
+        ("contoursequence", &tags::ContourSequence),
+        ("selectedsegmentalophthalmicaxiallengthsequence", &tags::SelectedSegmentalOphthalmicAxialLengthSequence),
+        ("referencedosetype", &tags::ReferenceDoseType),
+        ("therapydescription", &tags::TherapyDescription),
+        ("organdose", &tags::OrganDose),
+        ("dosecalibrationconditionssequence", &tags::DoseCalibrationConditionsSequence),
+        ("burnedinannotation", &tags::BurnedInAnnotation),
+        ("treatmentsummarymeasureddosereferencesequence", &tags::TreatmentSummaryMeasuredDoseReferenceSequence),
+        ("vvaluedata", &tags::VValueData),
+        ("catheterrotationalrate", &tags::CatheterRotationalRate),
+        ("upperlowerpixelvalues", &tags::UpperLowerPixelValues),
+        ("recordedwedgesequence", &tags::RecordedWedgeSequence),
+        ("ivuspullbackstopframenumber", &tags::IVUSPullbackStopFrameNumber),
+        ("treatmentmachinesequence", &tags::TreatmentMachineSequence),
+        ("findingsgroupuid_trial", &tags::FindingsGroupUID_Trial),
+        ("continuationendtotalreferenceairkerma", &tags::ContinuationEndTotalReferenceAirKerma),
+        ("ophthalmicaxiallengthqualitymetrictypecodesequence", &tags::OphthalmicAxialLengthQualityMetricTypeCodeSequence),
+        ("acquiredsubtractionmaskflag", &tags::AcquiredSubtractionMaskFlag),
+        ("projectionimagingacquisitionparametersequence", &tags::ProjectionImagingAcquisitionParameterSequence),
+        ("acquisitiondevicesequence", &tags::AcquisitionDeviceSequence),
+        ("distancereceptorplanetodetectorhousing", &tags::DistanceReceptorPlanetoDetectorHousing),
+        ("nominalbeamenergyunit", &tags::NominalBeamEnergyUnit),
+        ("sourceofpreviousvalues", &tags::SourceofPreviousValues),
+        ("equipmentreferencepointcoordinatessequence", &tags::EquipmentReferencePointCoordinatesSequence),
+        ("saturation", &tags::Saturation),
+        ("dosereferencepointcoordinates", &tags::DoseReferencePointCoordinates),
+        ("rgbluttransferfunction", &tags::RGBLUTTransferFunction),
+        ("emptyimagedensity", &tags::EmptyImageDensity),
+        ("referencedinterpretationsequence", &tags::ReferencedInterpretationSequence),
+        ("preamplifiernotes", &tags::PreAmplifierNotes),
+        ("sourceapplicatorlength", &tags::SourceApplicatorLength),
+        ("reasonforremovalcodesequence", &tags::ReasonforRemovalCodeSequence),
+        ("commandfield", &tags::CommandField),
+        ("griddimensions", &tags::GridDimensions),
+        ("depthoftransverseimage", &tags::DepthofTransverseImage),
+        ("gridthickness", &tags::GridThickness),
+        ("instancelevelreferencedperformedprocedurestepsequence", &tags::InstanceLevelReferencedPerformedProcedureStepSequence),
+        ("anatomicportalofentrancecodesequence_trial", &tags::AnatomicPortalOfEntranceCodeSequence_Trial),
+        ("seriesinstanceuid", &tags::SeriesInstanceUID),
+        ("scpstatus", &tags::SCPStatus),
+        ("bitsstored", &tags::BitsStored),
+        ("outerdiameter", &tags::OuterDiameter),
+        ("respiratorytriggertype", &tags::RespiratoryTriggerType),
+        ("maskingimage", &tags::MaskingImage),
+        ("whitepoint", &tags::WhitePoint),
         ("routesegmentendtime", &tags::RouteSegmentEndTime),
-        ("mprviewheightdirection", &tags::MPRViewHeightDirection),
-        ("patientsage", &tags::PatientsAge),
-        ("xrayimagereceptortranslation", &tags::XRayImageReceptorTranslation),
-        ("graphiclayerdescription", &tags::GraphicLayerDescription),
-        ("notchfilterfrequency", &tags::NotchFilterFrequency),
-        ("strainstocksequence", &tags::StrainStockSequence),
-        ("scannersettingssequence", &tags::ScannerSettingsSequence),
-        ("functionalgroupprivatecreator", &tags::FunctionalGroupPrivateCreator),
-        ("wedgerearheight", &tags::WedgeRearHeight),
-        ("quantitydefinitionsequence", &tags::QuantityDefinitionSequence),
-        ("specimenpreparationstepcontentitemsequence", &tags::SpecimenPreparationStepContentItemSequence),
-        ("startingrespiratoryamplitude", &tags::StartingRespiratoryAmplitude),
-        ("rfechotrainlength", &tags::RFEchoTrainLength),
-        ("patientsetupnumber", &tags::PatientSetupNumber),
-        ("dvhdata", &tags::DVHData),
-        ("referencedprintjobsequence", &tags::ReferencedPrintJobSequence),
-        ("pixelcomponentdatatype", &tags::PixelComponentDataType),
-        ("dosereferencenumber", &tags::DoseReferenceNumber),
-        ("samplingstepsize", &tags::SamplingStepSize),
-        ("centerofrotationoffset", &tags::CenterofRotationOffset),
-        ("studyaccessendpointssequence", &tags::StudyAccessEndPointsSequence),
-        ("relevantoptattributessequence", &tags::RelevantOPTAttributesSequence),
-        ("verificationflag", &tags::VerificationFlag),
-        ("measuringunitssequence", &tags::MeasuringUnitsSequence),
-        ("dopplersamplevolumexposition", &tags::DopplerSampleVolumeXPosition),
-        ("blockdata", &tags::BlockData),
-        ("diaphragmposition", &tags::DiaphragmPosition),
-        ("conceptualvolumeblockingconstraint", &tags::ConceptualVolumeBlockingConstraint),
-        ("totalnumberofstudyrecords", &tags::TotalNumberofStudyRecords),
-        ("radiationbeamwedgethinedgedistance", &tags::RadiationBeamWedgeThinEdgeDistance),
-        ("additionaldrugsequence", &tags::AdditionalDrugSequence),
-        ("predecessordocumentssequence", &tags::PredecessorDocumentsSequence),
-        ("channeltotaltime", &tags::ChannelTotalTime),
-        ("bodypartthickness", &tags::BodyPartThickness),
-        ("relatedsegmentcharacteristicssequence", &tags::RelatedSegmentCharacteristicsSequence),
-        ("gpstimestamp", &tags::GPSTimeStamp),
-        ("nominalenergy", &tags::NominalEnergy),
-        ("patientid", &tags::PatientID),
-        ("tagspacingseconddimension", &tags::TagSpacingSecondDimension),
-        ("gradientoutput", &tags::GradientOutput),
-        ("issuedateofimagingservicerequest", &tags::IssueDateofImagingServiceRequest),
-        ("algorithmfamilycodesequence", &tags::AlgorithmFamilyCodeSequence),
-        ("examinedbodythickness", &tags::ExaminedBodyThickness),
-        ("specimentypecodesequence", &tags::SpecimenTypeCodeSequence),
-        ("exposureinmicroas", &tags::ExposureinmicroAs),
-        ("thresholdvaluesequence", &tags::ThresholdValueSequence),
-        ("highenergydetectors", &tags::HighEnergyDetectors),
-        ("tabletopverticaladjustedposition", &tags::TableTopVerticalAdjustedPosition),
-        ("tabulatedvaluessequence", &tags::TabulatedValuesSequence),
-        ("positionreferenceindicator", &tags::PositionReferenceIndicator),
-        ("multiplexgrouplabel", &tags::MultiplexGroupLabel),
+        ("spatialtransformofdose", &tags::SpatialTransformofDose),
+        ("beamlimitingdevicesequence", &tags::BeamLimitingDeviceSequence),
+        ("depthvalueaveragingflag", &tags::DepthValueAveragingFlag),
+        ("sourceirradiationeventsequence", &tags::SourceIrradiationEventSequence),
+        ("gpsareainformation", &tags::GPSAreaInformation),
+        ("coordinatesystemtransformtranslationmatrix", &tags::CoordinateSystemTransformTranslationMatrix),
+        ("imageboxposition", &tags::ImageBoxPosition),
+        ("octzoffsetcorrection", &tags::OCTZOffsetCorrection),
+        ("ctadditionalxraysourcesequence", &tags::CTAdditionalXRaySourceSequence),
+        ("algorithmroutingcodesequence", &tags::AlgorithmRoutingCodeSequence),
+        ("assessedsopinstancesequence", &tags::AssessedSOPInstanceSequence),
+        ("userselectedoffsety", &tags::UserSelectedOffsetY),
+        ("realtimebulkdataflowsequence", &tags::RealTimeBulkDataFlowSequence),
+        ("testresultcomment", &tags::TestResultComment),
+        ("spatialresolution", &tags::SpatialResolution),
+        ("measurementlaterality", &tags::MeasurementLaterality),
+        ("ophthalmicaxiallengthmethod", &tags::OphthalmicAxialLengthMethod),
+        ("collimatorshape", &tags::CollimatorShape),
+        ("relativeopacity", &tags::RelativeOpacity),
+        ("applicationversion", &tags::ApplicationVersion),
+        ("stereorotation", &tags::StereoRotation),
+        ("reformattingthickness", &tags::ReformattingThickness),
+        ("scheduledstudylocationaetitle", &tags::ScheduledStudyLocationAETitle),
         ("requestedcontrastagent", &tags::RequestedContrastAgent),
-        ("doserateset", &tags::DoseRateSet),
-        ("geneticmodificationssequence", &tags::GeneticModificationsSequence),
-        ("devicelabel", &tags::DeviceLabel),
-        ("protocolplanninginformation", &tags::ProtocolPlanningInformation),
-        ("tmlinepositionx1_retired", &tags::TMLinePositionX1_Retired),
-        ("filtermaterial", &tags::FilterMaterial),
-        ("gridframeoffsetvector", &tags::GridFrameOffsetVector),
-        ("privatecreatorreference", &tags::PrivateCreatorReference),
-        ("acquiredimageareadoseproduct", &tags::AcquiredImageAreaDoseProduct),
-        ("brachyapplicationsetupdose", &tags::BrachyApplicationSetupDose),
-        ("usercontentlabel", &tags::UserContentLabel),
-        ("interpretationapproversequence", &tags::InterpretationApproverSequence),
-        ("blendinglut1sequence", &tags::BlendingLUT1Sequence),
-        ("anodetargetmaterial", &tags::AnodeTargetMaterial),
-        ("inventorycompletionstatus", &tags::InventoryCompletionStatus),
-        ("verificationdatetime", &tags::VerificationDateTime),
-        ("rtimageframeradiationacquisitionsequence", &tags::RTImageFrameRadiationAcquisitionSequence),
-        ("volumetotransducerrelationship", &tags::VolumetoTransducerRelationship),
-        ("geometryfordisplay", &tags::GeometryforDisplay),
-        ("graphicgroupsequence", &tags::GraphicGroupSequence),
-        ("mrfovgeometrysequence", &tags::MRFOVGeometrySequence),
-        ("ambientreflectionintensity", &tags::AmbientReflectionIntensity),
-        ("internaldetectorframetime", &tags::InternalDetectorFrameTime),
-        ("positionersecondaryangle", &tags::PositionerSecondaryAngle),
-        ("anatomicapproachdirectioncodesequence_trial", &tags::AnatomicApproachDirectionCodeSequence_Trial),
-        ("frameacquisitionduration", &tags::FrameAcquisitionDuration),
-        ("patientspecificationsequence", &tags::PatientSpecificationSequence),
-        ("referencedsegmentreferenceindex", &tags::ReferencedSegmentReferenceIndex),
-        ("replacedprocedurestepsequence", &tags::ReplacedProcedureStepSequence),
-        ("acquisitionprotocolname", &tags::AcquisitionProtocolName),
-        ("ophthalmicaxialmeasurementslefteyesequence", &tags::OphthalmicAxialMeasurementsLeftEyeSequence),
-        ("ciexywhitepoint", &tags::CIExyWhitePoint),
-        ("effectivewedgeangle", &tags::EffectiveWedgeAngle),
-        ("voitype", &tags::VOIType),
-        ("annotationappliestoallzplanes", &tags::AnnotationAppliestoAllZPlanes),
-        ("studystatusid", &tags::StudyStatusID),
-        ("physiciansreadingstudyidentificationsequence", &tags::PhysiciansReadingStudyIdentificationSequence),
-        ("institutionaddress", &tags::InstitutionAddress),
-        ("includenondicomobjects", &tags::IncludeNonDICOMObjects),
-        ("personstelephonenumbers", &tags::PersonsTelephoneNumbers),
-        ("rangeshifterid", &tags::RangeShifterID),
-        ("visualfieldglobalresultsindexsequence", &tags::VisualFieldGlobalResultsIndexSequence),
-        ("selectorusvalue", &tags::SelectorUSValue),
-        ("strainnomenclature", &tags::StrainNomenclature),
+        ("controlpointdeliverysequence", &tags::ControlPointDeliverySequence),
+        ("applicatoropeningy", &tags::ApplicatorOpeningY),
+        ("rtphysicianintentnarrative", &tags::RTPhysicianIntentNarrative),
+        ("referencedwaveformchannels", &tags::ReferencedWaveformChannels),
+        ("presentedvisualstimulidataflag", &tags::PresentedVisualStimuliDataFlag),
+        ("overlaylocation", &tags::OverlayLocation),
+        ("selectorsequencepointer", &tags::SelectorSequencePointer),
+        ("referencedradiationrtcontrolpointindex", &tags::ReferencedRadiationRTControlPointIndex),
+        ("dateofgaincalibration", &tags::DateofGainCalibration),
+        ("pulsernotes", &tags::PulserNotes),
+        ("aslcrusherflowlimit", &tags::ASLCrusherFlowLimit),
+        ("documentingorganizationidentifiercodesequence_trial", &tags::DocumentingOrganizationIdentifierCodeSequence_Trial),
+        ("storagemediafilesetuid", &tags::StorageMediaFilesetUID),
+        ("blockdivergence", &tags::BlockDivergence),
+        ("bitsgrouped", &tags::BitsGrouped),
+        ("shorttermfluctuationprobability", &tags::ShortTermFluctuationProbability),
+        ("implantpartnumber", &tags::ImplantPartNumber),
+        ("datetimeoflastcalibration", &tags::DateTimeofLastCalibration),
+        ("nominalrangemodulatedregiondepths", &tags::NominalRangeModulatedRegionDepths),
+        ("reasonforstudy", &tags::ReasonforStudy),
+        ("performedprocedurestependdatetime", &tags::PerformedProcedureStepEndDateTime),
+        ("orderentererslocation", &tags::OrderEnterersLocation),
+        ("treatmenttoleranceviolationcategory", &tags::TreatmentToleranceViolationCategory),
+        ("viewmodifiercodesequence", &tags::ViewModifierCodeSequence),
+        ("contenttime", &tags::ContentTime),
+        ("aslmidslabposition", &tags::ASLMidSlabPosition),
         ("tmlinepositionx1", &tags::TMLinePositionX1),
-        ("constituentconceptualvolumeuid", &tags::ConstituentConceptualVolumeUID),
-        ("recommendedpresentationtype", &tags::RecommendedPresentationType),
-        ("rtradiationtasksequence", &tags::RTRadiationTaskSequence),
-        ("sourceserialnumber", &tags::SourceSerialNumber),
-        ("serviceepisodeid", &tags::ServiceEpisodeID),
-        ("additionalinspectionselectioncriteria", &tags::AdditionalInspectionSelectionCriteria),
-        ("coordinatesystemaxessequence", &tags::CoordinateSystemAxesSequence),
-        ("matingfeatureid", &tags::MatingFeatureID),
-        ("enhancedpalettecolorlookuptablesequence", &tags::EnhancedPaletteColorLookupTableSequence),
-        ("scanningsequence", &tags::ScanningSequence),
-        ("totalcompensatortraywaterequivalentthickness", &tags::TotalCompensatorTrayWaterEquivalentThickness),
-        ("fixationmethodcodesequence", &tags::FixationMethodCodeSequence),
-        ("structuresetname", &tags::StructureSetName),
-        ("compensatortrayid", &tags::CompensatorTrayID),
-        ("rrintervaltimenominal", &tags::RRIntervalTimeNominal),
-        ("compensatorposition", &tags::CompensatorPosition),
-        ("referencedbrachyapplicationsetupsequence", &tags::ReferencedBrachyApplicationSetupSequence),
-        ("metersettodosemappingsequence", &tags::MetersettoDoseMappingSequence),
-        ("filterbycategory", &tags::FilterbyCategory),
-        ("startingrespiratoryphase", &tags::StartingRespiratoryPhase),
-        ("delivereddistaldepth", &tags::DeliveredDistalDepth),
-        ("volumetricpresentationinputindex", &tags::VolumetricPresentationInputIndex),
-        ("approvalsequence", &tags::ApprovalSequence),
-        ("roiphysicalpropertyvalue", &tags::ROIPhysicalPropertyValue),
-        ("isocentertobeamlimitingdevicedistance", &tags::IsocentertoBeamLimitingDeviceDistance),
-        ("scheduledstationclasscodesequence", &tags::ScheduledStationClassCodeSequence),
-        ("mprslabthickness", &tags::MPRSlabThickness),
-        ("surfaceregularityindex", &tags::SurfaceRegularityIndex),
-        ("frametimevector", &tags::FrameTimeVector),
-        ("luminanceuniformityresultsequence", &tags::LuminanceUniformityResultSequence),
-        ("targetexposureindex", &tags::TargetExposureIndex),
-        ("pixelspacingcalibrationdescription", &tags::PixelSpacingCalibrationDescription),
-        ("perframefunctionalgroupssequence", &tags::PerFrameFunctionalGroupsSequence),
-        ("graphicdata", &tags::GraphicData),
-        ("specificabsorptionratedefinition", &tags::SpecificAbsorptionRateDefinition),
-        ("numberofeventtimers", &tags::NumberofEventTimers),
-        ("testresult", &tags::TestResult),
-        ("procedurestepprogressinformationsequence", &tags::ProcedureStepProgressInformationSequence),
-        ("printerconfigurationsequence", &tags::PrinterConfigurationSequence),
-        ("intendedphasestartdate", &tags::IntendedPhaseStartDate),
-        ("isocentertorangeshifterdistance", &tags::IsocentertoRangeShifterDistance),
+        ("responsibleperson", &tags::ResponsiblePerson),
+        ("grid", &tags::Grid),
+        ("anatomicstructurereferencepoint", &tags::AnatomicStructureReferencePoint),
+        ("assessmentlabel", &tags::AssessmentLabel),
+        ("scheduledstudystopdate", &tags::ScheduledStudyStopDate),
+        ("patientssize", &tags::PatientsSize),
+        ("gridframeoffsetvector", &tags::GridFrameOffsetVector),
+        ("timesource", &tags::TimeSource),
+        ("plannedverificationimagesequence", &tags::PlannedVerificationImageSequence),
+        ("couplingvelocity", &tags::CouplingVelocity),
+        ("referencedimageoverlayboxsequence", &tags::ReferencedImageOverlayBoxSequence),
+        ("approvalsubjectsequence", &tags::ApprovalSubjectSequence),
+        ("steadystatepulsesequence", &tags::SteadyStatePulseSequence),
+        ("numberoftableentries", &tags::NumberofTableEntries),
+        ("acquisitioncompressiontype", &tags::AcquisitionCompressionType),
+        ("ophthalmicanatomicreferencepointxcoordinate", &tags::OphthalmicAnatomicReferencePointXCoordinate),
+        ("pseudocolortype", &tags::PseudoColorType),
+        ("parallelreductionfactorinplane", &tags::ParallelReductionFactorInplane),
+        ("containercomponentmaterial", &tags::ContainerComponentMaterial),
+        ("scheduledadmissiondate", &tags::ScheduledAdmissionDate),
+        ("maximumcornealcurvaturesequence", &tags::MaximumCornealCurvatureSequence),
+        ("tablespeed", &tags::TableSpeed),
+        ("channeloffset", &tags::ChannelOffset),
+        ("imageorientationvolume", &tags::ImageOrientationVolume),
+        ("referencedfilmboxsequence", &tags::ReferencedFilmBoxSequence),
+        ("inboundarrivaltype", &tags::InboundArrivalType),
+        ("anatomicstructurespaceorregionmodifiercodesequence_trial", &tags::AnatomicStructureSpaceOrRegionModifierCodeSequence_Trial),
+        ("rangemodulatortype", &tags::RangeModulatorType),
+        ("participationdatetime", &tags::ParticipationDateTime),
+        ("setupdeviceparameter", &tags::SetupDeviceParameter),
+        ("predeformationmatrixregistrationsequence", &tags::PreDeformationMatrixRegistrationSequence),
+        ("personname", &tags::PersonName),
+        ("roiarea", &tags::ROIArea),
+        ("storageurl", &tags::StorageURL),
+        ("inventoriedseriessequence", &tags::InventoriedSeriesSequence),
+        ("rttreatmentphaseindex", &tags::RTTreatmentPhaseIndex),
+        ("generalizeddefectcorrectedsensitivitydeviationprobabilityvalue", &tags::GeneralizedDefectCorrectedSensitivityDeviationProbabilityValue),
+        ("rotationoffset", &tags::RotationOffset),
+        ("clinicaltrialseriesdescription", &tags::ClinicalTrialSeriesDescription),
+        ("patienttreatmentpreparationproceduresequence", &tags::PatientTreatmentPreparationProcedureSequence),
+        ("volumetotablemappingmatrix", &tags::VolumetoTableMappingMatrix),
+        ("segmentreferencesequence", &tags::SegmentReferenceSequence),
+        ("chairheadframeposition", &tags::ChairHeadFramePosition),
+        ("numberoffractionsdelivered", &tags::NumberofFractionsDelivered),
+        ("referencedrtphysicianintentindex", &tags::ReferencedRTPhysicianIntentIndex),
+        ("largebluepalettecolorlookuptabledata", &tags::LargeBluePaletteColorLookupTableData),
+        ("displayfunctiontype", &tags::DisplayFunctionType),
+        ("skipframerangeflag", &tags::SkipFrameRangeFlag),
+        ("annotationgrouplabel", &tags::AnnotationGroupLabel),
+        ("annotationappliestoallopticalpaths", &tags::AnnotationAppliestoAllOpticalPaths),
+        ("imagetransformationmatrix", &tags::ImageTransformationMatrix),
+        ("selectorltvalue", &tags::SelectorLTValue),
+        ("segmentedalphapalettecolorlookuptabledata", &tags::SegmentedAlphaPaletteColorLookupTableData),
+        ("hangingprotocolcreator", &tags::HangingProtocolCreator),
+        ("sourcepixelplanescharacteristicssequence", &tags::SourcePixelPlanesCharacteristicsSequence),
+        ("distancesourcetoentrance", &tags::DistanceSourcetoEntrance),
+        ("beamtype", &tags::BeamType),
+        ("verticalprismbase", &tags::VerticalPrismBase),
+        ("documenttitle", &tags::DocumentTitle),
+        ("impedancemeasurementdatetime", &tags::ImpedanceMeasurementDateTime),
+        ("applicatortype", &tags::ApplicatorType),
+        ("intendedfractionstarttime", &tags::IntendedFractionStartTime),
+        ("doubleexposuremeterset", &tags::DoubleExposureMeterset),
+        ("surfacepointpresentationvaluedata", &tags::SurfacePointPresentationValueData),
+        ("verticesofthepolygonalshutter", &tags::VerticesofthePolygonalShutter),
+        ("refractiveparametersusedonpatientsequence", &tags::RefractiveParametersUsedonPatientSequence),
+        ("axislabels", &tags::AxisLabels),
+        ("transformdescription", &tags::TransformDescription),
+        ("wedgetotallength", &tags::WedgeTotalLength),
+        ("calculationcomment", &tags::CalculationComment),
+        ("scannersettingssequence", &tags::ScannerSettingsSequence),
+        ("seamlinelocation", &tags::SeamLineLocation),
+        ("extendedmatchingmechanisms", &tags::ExtendedMatchingMechanisms),
+        ("detectorcalibrationdata", &tags::DetectorCalibrationData),
+        ("codingschemeurl", &tags::CodingSchemeURL),
+        ("lutdescriptor", &tags::LUTDescriptor),
+        ("priortreatmentreferencesequence", &tags::PriorTreatmentReferenceSequence),
+        ("applicablesafetystandardagency", &tags::ApplicableSafetyStandardAgency),
+        ("illuminationwavelength", &tags::IlluminationWaveLength),
+        ("routesegmentsequence", &tags::RouteSegmentSequence),
+        ("translationratex", &tags::TranslationRateX),
+        ("detectornormalizationcorrection", &tags::DetectorNormalizationCorrection),
+        ("oversamplingphase", &tags::OversamplingPhase),
+        ("kvp", &tags::KVP),
+        ("equivalentconceptualvolumeinstancereferencesequence", &tags::EquivalentConceptualVolumeInstanceReferenceSequence),
+        ("implanttemplategroupvariationdimensionsequence", &tags::ImplantTemplateGroupVariationDimensionSequence),
+        ("rtprotocolcodesequence", &tags::RTProtocolCodeSequence),
+        ("referencedspatialregistrationsequence", &tags::ReferencedSpatialRegistrationSequence),
+        ("numberofwedges", &tags::NumberofWedges),
+        ("overlaytype", &tags::OverlayType),
+        ("visualfieldmeansensitivity", &tags::VisualFieldMeanSensitivity),
+        ("sourceofcorneameasurementdatacodesequence", &tags::SourceofCorneaMeasurementDataCodeSequence),
+        ("rtrecordflag", &tags::RTRecordFlag),
+        ("intendeddeliveryduration", &tags::IntendedDeliveryDuration),
+        ("boardingpassid", &tags::BoardingPassID),
+        ("fileoffsetincontainer", &tags::FileOffsetinContainer),
         ("imagecomments", &tags::ImageComments),
-        ("derivationimplanttemplatesequence", &tags::DerivationImplantTemplateSequence),
-        ("materialcodesequence", &tags::MaterialCodeSequence),
-        ("receivernotes", &tags::ReceiverNotes),
-        ("skipbeats", &tags::SkipBeats),
-        ("shutteroverlaygroup", &tags::ShutterOverlayGroup),
-        ("inventoriedstudiessequence", &tags::InventoriedStudiesSequence),
-        ("ctimagingacquisitionparametersequence", &tags::CTImagingAcquisitionParameterSequence),
-        ("rtpatientpositionscopesequence", &tags::RTPatientPositionScopeSequence),
-        ("interventiondrugstoptime", &tags::InterventionDrugStopTime),
-        ("potentialreasonsforprocedure", &tags::PotentialReasonsforProcedure),
-        ("presentationpixelmagnificationratio", &tags::PresentationPixelMagnificationRatio),
-        ("recordedchannelshieldsequence", &tags::RecordedChannelShieldSequence),
-        ("planepositionvolumesequence", &tags::PlanePositionVolumeSequence),
-        ("assertioncomments", &tags::AssertionComments),
-        ("recommendedexposureindex", &tags::RecommendedExposureIndex),
-        ("filterthicknessmaximum", &tags::FilterThicknessMaximum),
-        ("chemicalshiftminimumintegrationlimitinhz", &tags::ChemicalShiftMinimumIntegrationLimitinHz),
-        ("exposurecontrolsensingregionssequence", &tags::ExposureControlSensingRegionsSequence),
-        ("randomscorrected", &tags::RandomsCorrected),
-        ("clinicalfractionnumber", &tags::ClinicalFractionNumber),
-        ("tag_3ddegreeoffreedomaxis", &tags::Tag_3DDegreeofFreedomAxis),
-        ("frameofreferencetransformationmatrixtype", &tags::FrameofReferenceTransformationMatrixType),
-        ("udisequence", &tags::UDISequence),
-        ("anatomicalorientationtype", &tags::AnatomicalOrientationType),
-        ("alternatecontaineridentifiersequence", &tags::AlternateContainerIdentifierSequence),
-        ("structuresettime", &tags::StructureSetTime),
-        ("centerofcircularexposurecontrolsensingregion", &tags::CenterofCircularExposureControlSensingRegion),
-        ("sourcetoapplicatormountingpositiondistance", &tags::SourcetoApplicatorMountingPositionDistance),
-        ("templateidentifier", &tags::TemplateIdentifier),
-        ("depthspatialresolution", &tags::DepthSpatialResolution),
-        ("steepkeratometricaxissequence", &tags::SteepKeratometricAxisSequence),
-        ("numberoffilms", &tags::NumberofFilms),
-        ("measuredcharacteristics", &tags::MeasuredCharacteristics),
-        ("verticesofthepolygonalexposurecontrolsensingregion", &tags::VerticesofthePolygonalExposureControlSensingRegion),
-        ("magnitudeencodingcodesequence", &tags::MagnitudeEncodingCodeSequence),
-        ("rtdiagnosiscodesequence", &tags::RTDiagnosisCodeSequence),
-        ("rtbeamdelimitergeometrysequence", &tags::RTBeamDelimiterGeometrySequence),
-        ("radiationmassnumber", &tags::RadiationMassNumber),
-        ("intendedphaseenddate", &tags::IntendedPhaseEndDate),
-        ("filterbyoperator", &tags::FilterbyOperator),
-        ("devicedescription", &tags::DeviceDescription),
-        ("acquisitionduration", &tags::AcquisitionDuration),
-        ("reconstructionpipelinetype", &tags::ReconstructionPipelineType),
-        ("digitizingdevicetransportdirection", &tags::DigitizingDeviceTransportDirection),
-        ("referencedsopclassuidinfile", &tags::ReferencedSOPClassUIDinFile),
-        ("viewingdistancetype", &tags::ViewingDistanceType),
-        ("respiratorytriggerdelaythreshold", &tags::RespiratoryTriggerDelayThreshold),
-        ("matrixregistrationsequence", &tags::MatrixRegistrationSequence),
-        ("parallelrtbeamdelimiterdeviceorientationlabelcodesequence", &tags::ParallelRTBeamDelimiterDeviceOrientationLabelCodeSequence),
-        ("directoryrecordsequence", &tags::DirectoryRecordSequence),
-        ("volumestreamsequence", &tags::VolumeStreamSequence),
-        ("ctxraydetailssequence", &tags::CTXRayDetailsSequence),
-        ("admittingdate", &tags::AdmittingDate),
-        ("longcodevalue", &tags::LongCodeValue),
-        ("referencedvisitsequence", &tags::ReferencedVisitSequence),
+        ("tissueheterogeneitycorrection", &tags::TissueHeterogeneityCorrection),
+        ("wedgechamferheight", &tags::WedgeChamferHeight),
+        ("imagefilterdetailssequence", &tags::ImageFilterDetailsSequence),
+        ("responsiblegroupcodesequence", &tags::ResponsibleGroupCodeSequence),
+        ("conceptualvolumeconstituentindex", &tags::ConceptualVolumeConstituentIndex),
+        ("tag_3dimplanttemplategroupmembermatchingaxes", &tags::Tag_3DImplantTemplateGroupMemberMatchingAxes),
+        ("patientsupportpositionparameterorderindex", &tags::PatientSupportPositionParameterOrderIndex),
+        ("manufacturersmodelname", &tags::ManufacturersModelName),
+        ("ivuspullbackrate", &tags::IVUSPullbackRate),
+        ("dosevalueinterpretation", &tags::DoseValueInterpretation),
+        ("calculatedtargetposition", &tags::CalculatedTargetPosition),
+        ("acquisitioncontextsequence", &tags::AcquisitionContextSequence),
+        ("exposuremode", &tags::ExposureMode),
+        ("numberoftimeslots", &tags::NumberofTimeSlots),
+        ("realworldvalueintercept", &tags::RealWorldValueIntercept),
+        ("regionlocationmaxy1", &tags::RegionLocationMaxY1),
+        ("numericvalue", &tags::NumericValue),
+        ("thresholdsequence", &tags::ThresholdSequence),
+        ("blendinglookuptabledata", &tags::BlendingLookupTableData),
+        ("operatingmodesequence", &tags::OperatingModeSequence),
+        ("fiducialuid", &tags::FiducialUID),
+        ("typeofpatientid", &tags::TypeofPatientID),
+        ("isospeed", &tags::ISOSpeed),
+        ("selectedframenumber", &tags::SelectedFrameNumber),
+        ("referencedseriessequence", &tags::ReferencedSeriesSequence),
+        ("ethicscommitteeapprovaleffectivenessstartdate", &tags::EthicsCommitteeApprovalEffectivenessStartDate),
+        ("additionalparameterrecordinginstancesequence", &tags::AdditionalParameterRecordingInstanceSequence),
+        ("studyverifieddate", &tags::StudyVerifiedDate),
+        ("treatmenttimelimit", &tags::TreatmentTimeLimit),
+        ("preselectedforimplantation", &tags::PreSelectedforImplantation),
+        ("treatmentsitecodesequence", &tags::TreatmentSiteCodeSequence),
+        ("partialfourier", &tags::PartialFourier),
+        ("interpretationidissuer", &tags::InterpretationIDIssuer),
+        ("filmsessionlabel", &tags::FilmSessionLabel),
+        ("assessmenttypecodesequence", &tags::AssessmentTypeCodeSequence),
+        ("referencedimageevidencesequence", &tags::ReferencedImageEvidenceSequence),
+        ("effectiverefractiveindex", &tags::EffectiveRefractiveIndex),
+        ("scheduledperformingphysiciansname", &tags::ScheduledPerformingPhysiciansName),
+        ("shutterpresentationcolorcielabvalue", &tags::ShutterPresentationColorCIELabValue),
+        ("isospeedlatitudezzz", &tags::ISOSpeedLatitudezzz),
+        ("coordinatesystemaxisunits", &tags::CoordinateSystemAxisUnits),
+        ("specimenreferencesequence", &tags::SpecimenReferenceSequence),
+        ("patientlocationcoordinatessequence", &tags::PatientLocationCoordinatesSequence),
+        ("tomoclass", &tags::TomoClass),
+        ("studiescontainingotherreferencedinstancessequence", &tags::StudiesContainingOtherReferencedInstancesSequence),
+        ("simpleframelist", &tags::SimpleFrameList),
+        ("sourceapplicatormanufacturer", &tags::SourceApplicatorManufacturer),
+        ("contrastbolusadministrationroutesequence", &tags::ContrastBolusAdministrationRouteSequence),
+        ("enhancedrtbeamlimitingopeningsequence", &tags::EnhancedRTBeamLimitingOpeningSequence),
+        ("receiveprobesequence", &tags::ReceiveProbeSequence),
+        ("failedsopsequence", &tags::FailedSOPSequence),
+        ("componentinputsequence", &tags::ComponentInputSequence),
+        ("exclusionstartdatetime", &tags::ExclusionStartDateTime),
+        ("displacementreferencelabel", &tags::DisplacementReferenceLabel),
+        ("derivationconceptualvolumesequence", &tags::DerivationConceptualVolumeSequence),
+        ("cylinderpower", &tags::CylinderPower),
+        ("acousticcouplingmediumflag", &tags::AcousticCouplingMediumFlag),
+        ("temporalresolution", &tags::TemporalResolution),
+        ("attributetolerancevaluessequence", &tags::AttributeToleranceValuesSequence),
+        ("notificationfrommanufacturersequence", &tags::NotificationFromManufacturerSequence),
+        ("referencedframeofreferencesequence", &tags::ReferencedFrameofReferenceSequence),
+        ("rangeoffreedom", &tags::RangeofFreedom),
+        ("observationdescription", &tags::ObservationDescription),
+        ("selectorattribute", &tags::SelectorAttribute),
+        ("priorrecordkey", &tags::PriorRecordKey),
+        ("visualacuitylefteyesequence", &tags::VisualAcuityLeftEyeSequence),
+        ("decimalpotentialvisualacuity", &tags::DecimalPotentialVisualAcuity),
+        ("blockidentifyinginformationstatus", &tags::BlockIdentifyingInformationStatus),
+        ("organexposed", &tags::OrganExposed),
+        ("dacsequence", &tags::DACSequence),
+        ("calculatedanatomythickness", &tags::CalculatedAnatomyThickness),
+        ("recommendeddisplaycielabvaluelist", &tags::RecommendedDisplayCIELabValueList),
+        ("patientsalternativecalendar", &tags::PatientsAlternativeCalendar),
+        ("acquisitionmotion", &tags::AcquisitionMotion),
+        ("selectorsvvalue", &tags::SelectorSVValue),
+        ("sourceacquisitionbeamnumber", &tags::SourceAcquisitionBeamNumber),
+        ("referencedtdrinstancesequence", &tags::ReferencedTDRInstanceSequence),
+        ("excitationspectralwidth", &tags::ExcitationSpectralWidth),
+        ("dosimetricobjectivepurpose", &tags::DosimetricObjectivePurpose),
+        ("requestedproceduredescription", &tags::RequestedProcedureDescription),
+        ("temporalpositionindex", &tags::TemporalPositionIndex),
+        ("aitdevicetype", &tags::AITDeviceType),
+        ("roiphysicalpropertyvalue", &tags::ROIPhysicalPropertyValue),
+        ("intervalnumber", &tags::IntervalNumber),
+        ("referencedosedefinition", &tags::ReferenceDoseDefinition),
+        ("procedurestepstate", &tags::ProcedureStepState),
+        ("stereopairssequence", &tags::StereoPairsSequence),
+        ("ordercallbackphonenumber", &tags::OrderCallbackPhoneNumber),
+        ("admittingdiagnosesdescription", &tags::AdmittingDiagnosesDescription),
+        ("typeofdetectormotion", &tags::TypeofDetectorMotion),
+        ("maskoperationexplanation", &tags::MaskOperationExplanation),
+        ("dateofmanufacture", &tags::DateofManufacture),
+        ("basiccolorimagesequence", &tags::BasicColorImageSequence),
+        ("segmentsurfacegenerationalgorithmidentificationsequence", &tags::SegmentSurfaceGenerationAlgorithmIdentificationSequence),
+        ("overlaycompressiondescription", &tags::OverlayCompressionDescription),
+        ("filenameincontainer", &tags::FilenameinContainer),
+        ("phosphortype", &tags::PhosphorType),
         ("tag_3dpointcoordinates", &tags::Tag_3DPointCoordinates),
-        ("pulsespecificbrachycontrolpointdeliveredsequence", &tags::PulseSpecificBrachyControlPointDeliveredSequence),
-        ("usimagedescriptionsequence", &tags::USImageDescriptionSequence),
-        ("commonzcoordinatevalue", &tags::CommonZCoordinateValue),
-        ("exposureprogram", &tags::ExposureProgram),
-        ("showgrayscaleinverted", &tags::ShowGrayscaleInverted),
-        ("pvcrejection", &tags::PVCRejection),
-        ("actualcardiactriggertimepriortorpeak", &tags::ActualCardiacTriggerTimePriorToRPeak),
-        ("xraydetectorid", &tags::XRayDetectorID),
-        ("sopclassuid", &tags::SOPClassUID),
-        ("standardoutputsensitivity", &tags::StandardOutputSensitivity),
-        ("interlockdescription", &tags::InterlockDescription),
-        ("stopcumulativemeterset", &tags::StopCumulativeMeterset),
-        ("crop", &tags::Crop),
-        ("magnetizationtransfer", &tags::MagnetizationTransfer),
-        ("generalizeddefectcorrectedsensitivitydeviationprobabilityvalue", &tags::GeneralizedDefectCorrectedSensitivityDeviationProbabilityValue),
-        ("geneticmodificationsdescription", &tags::GeneticModificationsDescription),
-        ("topictitle", &tags::TopicTitle),
-        ("referencedrtinstancesequence", &tags::ReferencedRTInstanceSequence),
-        ("rtradiationsetdeliveryusage", &tags::RTRadiationSetDeliveryUsage),
-        ("autorefractionrighteyesequence", &tags::AutorefractionRightEyeSequence),
-        ("patternoncolorcielabvalue", &tags::PatternOnColorCIELabValue),
-        ("codingschemename", &tags::CodingSchemeName),
-        ("status", &tags::Status),
-        ("reviewdate", &tags::ReviewDate),
-        ("gridfocaldistance", &tags::GridFocalDistance),
-        ("prismsequence", &tags::PrismSequence),
-        ("rtimagedescription", &tags::RTImageDescription),
-        ("triggersourceortype", &tags::TriggerSourceorType),
-        ("structureddisplayimageboxsequence", &tags::StructuredDisplayImageBoxSequence),
-        ("selectedsegmentalophthalmicaxiallengthsequence", &tags::SelectedSegmentalOphthalmicAxialLengthSequence),
-        ("dosecalibrationconditionsverifiedflag", &tags::DoseCalibrationConditionsVerifiedFlag),
-        ("performedprocedurestepdescription", &tags::PerformedProcedureStepDescription),
-        ("codemeaning", &tags::CodeMeaning),
-        ("systemstatuscomment", &tags::SystemStatusComment),
-        ("rgbluttransferfunction", &tags::RGBLUTTransferFunction),
-        ("representativeframenumber", &tags::RepresentativeFrameNumber),
-        ("channelnumber", &tags::ChannelNumber),
-        ("imagesinacquisition", &tags::ImagesinAcquisition),
-        ("dosimetricobjectiveevaluationscope", &tags::DosimetricObjectiveEvaluationScope),
-        ("registrationtypecodesequence", &tags::RegistrationTypeCodeSequence),
-        ("modelgroupuid", &tags::ModelGroupUID),
-        ("volumecroppingmethod", &tags::VolumeCroppingMethod),
-        ("powerlinefrequency", &tags::PowerlineFrequency),
-        ("patientsupportpositiondevicetolerancesequence", &tags::PatientSupportPositionDeviceToleranceSequence),
-        ("graphiclayerrecommendeddisplaycielabvalue", &tags::GraphicLayerRecommendedDisplayCIELabValue),
-        ("surfacepointcolorcielabvaluedata", &tags::SurfacePointColorCIELabValueData),
-        ("imagedimensions", &tags::ImageDimensions),
-        ("regionflags", &tags::RegionFlags),
-        ("reasonforomissiondescription", &tags::ReasonforOmissionDescription),
-        ("performedprocessingparameterssequence", &tags::PerformedProcessingParametersSequence),
-        ("devicealternateidentifiertype", &tags::DeviceAlternateIdentifierType),
-        ("uniformresourcelocator_trial", &tags::UniformResourceLocator_Trial),
-        ("datainformationsequence", &tags::DataInformationSequence),
-        ("failurereason", &tags::FailureReason),
-        ("correctedlocalizeddeviationfromnormalprobabilitycalculated", &tags::CorrectedLocalizedDeviationFromNormalProbabilityCalculated),
-        ("beamcurrentmodulationid", &tags::BeamCurrentModulationID),
-        ("slicevector", &tags::SliceVector),
-        ("xraygeometrysequence", &tags::XRayGeometrySequence),
-        ("makernote", &tags::MakerNote),
-        ("brachyaccessorydevicesequence", &tags::BrachyAccessoryDeviceSequence),
-        ("palettecolorlookuptableuid", &tags::PaletteColorLookupTableUID),
-        ("fillmode", &tags::FillMode),
-        ("rangeshiftersequence", &tags::RangeShifterSequence),
-        ("realtimebulkdataflowsequence", &tags::RealTimeBulkDataFlowSequence),
-        ("horizontalprismbase", &tags::HorizontalPrismBase),
-        ("sourcetocompensatortraydistance", &tags::SourcetoCompensatorTrayDistance),
-        ("annotationposition", &tags::AnnotationPosition),
-        ("componenttype", &tags::ComponentType),
-        ("ophthalmicthicknessmappingnormalssequence", &tags::OphthalmicThicknessMappingNormalsSequence),
-        ("scenecapturetype", &tags::SceneCaptureType),
-        ("otherfailuressequence", &tags::OtherFailuresSequence),
-        ("sourceoflensthicknessdatacodesequence", &tags::SourceofLensThicknessDataCodeSequence),
-        ("equivalentconceptualvolumessequence", &tags::EquivalentConceptualVolumesSequence),
-        ("acquisitiontaskworkitemcodesequence", &tags::AcquisitionTaskWorkitemCodeSequence),
-        ("blendingweightconstant", &tags::BlendingWeightConstant),
-        ("mappingresourceuid", &tags::MappingResourceUID),
-        ("recordkey", &tags::RecordKey),
-        ("tag_2dpointcoordinatessequence", &tags::Tag_2DPointCoordinatesSequence),
-        ("lightpathfilterpassthroughwavelength", &tags::LightPathFilterPassThroughWavelength),
-        ("dataframeassignmentsequence", &tags::DataFrameAssignmentSequence),
-        ("realworldvaluefirstvaluemapped", &tags::RealWorldValueFirstValueMapped),
-        ("perimetervalue", &tags::PerimeterValue),
-        ("tracksetdescription", &tags::TrackSetDescription),
-        ("calibrationsettingssequence", &tags::CalibrationSettingsSequence),
-        ("algorithmtype", &tags::AlgorithmType),
-        ("fillstylesequence", &tags::FillStyleSequence),
+        ("variablepixeldata", &tags::VariablePixelData),
+        ("radiationmachinessd", &tags::RadiationMachineSSD),
+        ("metersetrate", &tags::MetersetRate),
+        ("videoimageformatacquired", &tags::VideoImageFormatAcquired),
+        ("tabletoppitchangle", &tags::TableTopPitchAngle),
+        ("wedgeoffsetx", &tags::WedgeOffsetX),
+        ("scheduledstationclasscodesequence", &tags::ScheduledStationClassCodeSequence),
+        ("hangingprotocollevel", &tags::HangingProtocolLevel),
+        ("containercomponentwidth", &tags::ContainerComponentWidth),
+        ("measuredbandwidth", &tags::MeasuredBandwidth),
+        ("calculateddosereferencenumber", &tags::CalculatedDoseReferenceNumber),
+        ("contextgroupextensionflag", &tags::ContextGroupExtensionFlag),
+        ("sourcetoexternalcontourdistance", &tags::SourcetoExternalContourDistance),
+        ("contrastflowduration", &tags::ContrastFlowDuration),
+        ("startcumulativetimeweight", &tags::StartCumulativeTimeWeight),
+        ("brachycontrolpointsequence", &tags::BrachyControlPointSequence),
+        ("imagefilterdescription", &tags::ImageFilterDescription),
+        ("kvimaginggenerationparameterssequence", &tags::KVImagingGenerationParametersSequence),
+        ("textobjectsequence", &tags::TextObjectSequence),
+        ("patientframeofreferencesource", &tags::PatientFrameofReferenceSource),
+        ("cameraelevationangle", &tags::CameraElevationAngle),
         ("vitreousstatuscodesequence", &tags::VitreousStatusCodeSequence),
-        ("inspectionselectioncriteria", &tags::InspectionSelectionCriteria),
-        ("spatialresolution", &tags::SpatialResolution),
-        ("rtimagescopesequence", &tags::RTImageScopeSequence),
-        ("doubleexposurefielddelta_trial", &tags::DoubleExposureFieldDelta_Trial),
-        ("compressionpressure", &tags::CompressionPressure),
-        ("xraysourceisocentersecondaryangle", &tags::XRaySourceIsocenterSecondaryAngle),
-        ("referencedrangemodulatornumber", &tags::ReferencedRangeModulatorNumber),
-        ("referringphysiciansname", &tags::ReferringPhysiciansName),
-        ("shutterspeedvalue", &tags::ShutterSpeedValue),
-        ("reconstructionprotocolelementsequence", &tags::ReconstructionProtocolElementSequence),
-        ("ticklabel", &tags::TickLabel),
-        ("signaltonoiseratio", &tags::SignaltoNoiseRatio),
-        ("wedgemodelnumber", &tags::WedgeModelNumber),
-        ("mrechosequence", &tags::MREchoSequence),
-        ("delaylawidentifier", &tags::DelayLawIdentifier),
-        ("multienergyctacquisitionsequence", &tags::MultienergyCTAcquisitionSequence),
-        ("interpretationstatusid", &tags::InterpretationStatusID),
-        ("expirationdatetime", &tags::ExpirationDateTime),
-        ("fractionalchanneldisplayscale", &tags::FractionalChannelDisplayScale),
-        ("backgroundluminance", &tags::BackgroundLuminance),
-        ("tabletraverse", &tags::TableTraverse),
-        ("consultingphysiciansname", &tags::ConsultingPhysiciansName),
-        ("referencedfractiongroupnumber", &tags::ReferencedFractionGroupNumber),
-        ("conventionalmachineverificationsequence", &tags::ConventionalMachineVerificationSequence),
-        ("pixelspacing", &tags::PixelSpacing),
-        ("physicalunitsxdirection", &tags::PhysicalUnitsXDirection),
-        ("refractiveindexapplied", &tags::RefractiveIndexApplied),
-        ("retaininstances", &tags::RetainInstances),
-        ("gpslongitude", &tags::GPSLongitude),
-        ("fractiongroupdescription", &tags::FractionGroupDescription),
-        ("treatmenttoleranceviolationidentification", &tags::TreatmentToleranceViolationIdentification),
-        ("contrastbolusingredientcodesequence", &tags::ContrastBolusIngredientCodeSequence),
-        ("equipmentreferencepointcoordinatessequence", &tags::EquipmentReferencePointCoordinatesSequence),
-        ("channelshieldnumber", &tags::ChannelShieldNumber),
-        ("lensthicknesssequence", &tags::LensThicknessSequence),
-        ("numberofpriorsreferenced", &tags::NumberofPriorsReferenced),
-        ("impedancevalue", &tags::ImpedanceValue),
-        ("planninglandmarkid", &tags::PlanningLandmarkID),
-        ("ionrangecompensatorsequence", &tags::IonRangeCompensatorSequence),
-        ("rangemodulatorsettingssequence", &tags::RangeModulatorSettingsSequence),
-        ("lensconstantdescription", &tags::LensConstantDescription),
-        ("imagetoequipmentmappingmatrix", &tags::ImagetoEquipmentMappingMatrix),
-        ("tomotime", &tags::TomoTime),
-        ("fractionalbandwidth", &tags::FractionalBandwidth),
-        ("instructiontext", &tags::InstructionText),
-        ("pulsewidth", &tags::PulseWidth),
-        ("timeofflightcontrast", &tags::TimeofFlightContrast),
-        ("scheduledstationaetitle", &tags::ScheduledStationAETitle),
-        ("tablecolumndefinitionsequence", &tags::TableColumnDefinitionSequence),
-        ("safepositionexittime", &tags::SafePositionExitTime),
-        ("potentialthreatobjectid", &tags::PotentialThreatObjectID),
-        ("beamdosemeaning", &tags::BeamDoseMeaning),
-        ("channeldeliverycontinuationsequence", &tags::ChannelDeliveryContinuationSequence),
-        ("deliverytimestructurecodesequence", &tags::DeliveryTimeStructureCodeSequence),
-        ("pupilsize", &tags::PupilSize),
-        ("annotationgroupnumber", &tags::AnnotationGroupNumber),
-        ("headfixationangletolerance", &tags::HeadFixationAngleTolerance),
-        ("terminationrespiratorytriggercountthreshold", &tags::TerminationRespiratoryTriggerCountThreshold),
-        ("administrationroutecodesequence", &tags::AdministrationRouteCodeSequence),
-        ("acquisitiontaskapplicabilitysequence", &tags::AcquisitionTaskApplicabilitySequence),
-        ("stagecodesequence", &tags::StageCodeSequence),
-        ("implanttemplategroupissuer", &tags::ImplantTemplateGroupIssuer),
-        ("printmanagementcapabilitiessequence", &tags::PrintManagementCapabilitiesSequence),
-        ("filesetdescriptorfileid", &tags::FilesetDescriptorFileID),
-        ("acquisitionfieldofviewlabel", &tags::AcquisitionFieldofViewLabel),
-        ("primarypositionerincrement", &tags::PrimaryPositionerIncrement),
-        ("brachytasksequence", &tags::BrachyTaskSequence),
-        ("compensatorshapesequence", &tags::CompensatorShapeSequence),
-        ("primaryanatomicstructuremodifiersequence", &tags::PrimaryAnatomicStructureModifierSequence),
-        ("iolmanufacturer", &tags::IOLManufacturer),
-        ("tabletoprolladjustedangle", &tags::TableTopRollAdjustedAngle),
+        ("dosecomment", &tags::DoseComment),
+        ("contextgroupextensioncreatoruid", &tags::ContextGroupExtensionCreatorUID),
+        ("estimateddosesaving", &tags::EstimatedDoseSaving),
+        ("coordinatesystemdatasetmapping", &tags::CoordinateSystemDataSetMapping),
+        ("expectedcompletiondatetime", &tags::ExpectedCompletionDateTime),
+        ("pixelspacingsequence", &tags::PixelSpacingSequence),
+        ("spiralpitchfactor", &tags::SpiralPitchFactor),
+        ("couplingmedium", &tags::CouplingMedium),
+        ("referencedstructuredcontextsequence", &tags::ReferencedStructuredContextSequence),
+        ("tmlinepositiony0_retired", &tags::TMLinePositionY0_Retired),
+        ("segmentedbluepalettecolorlookuptabledata", &tags::SegmentedBluePaletteColorLookupTableData),
+        ("connectortype", &tags::ConnectorType),
+        ("radiationbeamwedgethinedgedistance", &tags::RadiationBeamWedgeThinEdgeDistance),
+        ("referencedtransfersyntaxuidinfile", &tags::ReferencedTransferSyntaxUIDinFile),
+        ("rtaccessoryslotdistance", &tags::RTAccessorySlotDistance),
+        ("sendingapplicationentitytitle", &tags::SendingApplicationEntityTitle),
+        ("secondarypositionerincrementsign", &tags::SecondaryPositionerIncrementSign),
+        ("anatomicregionsequence", &tags::AnatomicRegionSequence),
+        ("straindescription", &tags::StrainDescription),
+        ("rtanatomicprescriptionsequence", &tags::RTAnatomicPrescriptionSequence),
+        ("includedisplayapplication", &tags::IncludeDisplayApplication),
+        ("fixationmonitoringcodesequence", &tags::FixationMonitoringCodeSequence),
+        ("ophthalmicaxiallengthacquisitionmethodcodesequence", &tags::OphthalmicAxialLengthAcquisitionMethodCodeSequence),
+        ("radiationdoseidentificationlabel", &tags::RadiationDoseIdentificationLabel),
+        ("referencedcompensatornumber", &tags::ReferencedCompensatorNumber),
+        ("totaltime", &tags::TotalTime),
+        ("doublefloatpixeldata", &tags::DoubleFloatPixelData),
+        ("patientlocationcoordinatescodesequence", &tags::PatientLocationCoordinatesCodeSequence),
+        ("seriestype", &tags::SeriesType),
+        ("numberofdetectors", &tags::NumberofDetectors),
+        ("curvedatadescriptor", &tags::CurveDataDescriptor),
+        ("referencedfileid", &tags::ReferencedFileID),
     ],
 };
 
@@ -12139,6 +12248,128 @@ pub static TAG_BY_VALUE: phf::Map<u32, TagRef> = ::phf::Map {
     ],
 };
 
+pub static TAG_BY_MASKED_VALUE: phf::Map<u32, TagRef> = ::phf::Map {
+    key: 16263683158343804936,
+    disps: &[
+        (1, 26),
+        (0, 65),
+        (7, 83),
+        (0, 24),
+        (1, 38),
+        (0, 5),
+        (0, 0),
+        (0, 8),
+        (1, 2),
+        (0, 12),
+        (0, 0),
+        (1, 5),
+        (1, 0),
+        (1, 56),
+        (1, 72),
+        (0, 40),
+        (19, 45),
+        (6, 23),
+    ],
+    entries: &[
+        (1342185484, &tags::AudioSampleData),
+        (1610612800, &tags::OverlayType),
+        (1342185482, &tags::TotalTime),
+        (1610617091, &tags::OverlayDescriptorBlue),
+        (1610618112, &tags::OverlayLabel),
+        (268435456, &tags::EscapeTriplet),
+        (1342177542, &tags::CurveRange),
+        (2623488, &tags::CodeLabel),
+        (2623492, &tags::BitsForCodeWord),
+        (2623491, &tags::CodeTableLocation),
+        (2622464, &tags::RowsForNthOrderCoefficients),
+        (1610629120, &tags::OverlayComments),
+        (1610612757, &tags::NumberofFramesinOverlay),
+        (1610612994, &tags::OverlayBitPosition),
+        (2109696, &tags::SourceImageIDs),
+        (268435461, &tags::ShiftTableTriplet),
+        (1610612834, &tags::OverlayCompressionLabel),
+        (2130706448, &tags::VariablePixelData),
+        (1610612833, &tags::OverlayCompressionOriginator),
+        (1610612816, &tags::OverlayOrigin),
+        (1610617347, &tags::OverlaysBlue),
+        (1610612770, &tags::OverlayDescription),
+        (1610616833, &tags::OverlayActivationLayer),
+        (1610614788, &tags::OverlayBitsForCodeWord),
+        (268435458, &tags::HuffmanTableSize),
+        (268435460, &tags::ShiftTableSize),
+        (1342185486, &tags::AudioComments),
+        (1342185474, &tags::AudioSampleFormat),
+        (2130706464, &tags::VariableCoefficientsSDVN),
+        (2623496, &tags::ImageDataLocation),
+        (1342177556, &tags::CoordinateStepValue),
+        (268435457, &tags::RunLengthTriplet),
+        (2130706496, &tags::VariableCoefficientsSDDN),
+        (1610612754, &tags::OverlayPlanes),
+        (1342177554, &tags::CoordinateStartValue),
+        (1610612752, &tags::OverlayRows),
+        (1342185480, &tags::SampleRate),
+        (2622465, &tags::ColumnsForNthOrderCoefficients),
+        (1342177539, &tags::DataValueRepresentation),
+        (1342187024, &tags::CurveReferencedOverlayGroup),
+        (1610612992, &tags::OverlayBitsAllocated),
+        (1610617345, &tags::OverlaysRed),
+        (1610617603, &tags::ROIStandardDeviation),
+        (1342177285, &tags::CurveDimensions),
+        (1342185472, &tags::AudioType),
+        (2622467, &tags::CoefficientCodingPointers),
+        (1610612805, &tags::OverlaySubtype),
+        (1342177541, &tags::MaximumCoordinateValue),
+        (1610617090, &tags::OverlayDescriptorGreen),
+        (1610612832, &tags::OverlayCompressionCode),
+        (1610612817, &tags::ImageFrameOrigin),
+        (1610617602, &tags::ROIMean),
+        (1342187008, &tags::CurveReferencedOverlaySequence),
+        (1342177296, &tags::NumberofPoints),
+        (2623490, &tags::NumberofTables),
+        (2130706449, &tags::VariableNextDataGroup),
+        (1342185478, &tags::NumberofSamples),
+        (2622466, &tags::CoefficientCoding),
+        (1610617088, &tags::OverlayDescriptorGray),
+        (1342186752, &tags::CurveLabel),
+        (1342189568, &tags::CurveData),
+        (1342177328, &tags::AxisUnits),
+        (1610612840, &tags::OverlayRepeatInterval),
+        (1342185476, &tags::NumberofChannels),
+        (1610617346, &tags::OverlaysGreen),
+        (1610612753, &tags::OverlayColumns),
+        (1610614786, &tags::OverlayNumberofTables),
+        (1610613008, &tags::OverlayFormat),
+        (1610625024, &tags::OverlayData),
+        (1342177344, &tags::AxisLabels),
+        (1610617344, &tags::OverlaysGray),
+        (1610617089, &tags::OverlayDescriptorRed),
+        (1610614784, &tags::OverlayCodeLabel),
+        (1610612818, &tags::OverlayPlaneOrigin),
+        (1342177312, &tags::TypeofData),
+        (1610614787, &tags::OverlayCodeTableLocation),
+        (1342177540, &tags::MinimumCoordinateValue),
+        (1342177314, &tags::CurveDescription),
+        (1342181377, &tags::CurveActivationLayer),
+        (268435459, &tags::HuffmanTableTriplet),
+        (2130706480, &tags::VariableCoefficientsSDHN),
+        (1610612835, &tags::OverlayCompressionDescription),
+        (1610612841, &tags::OverlayBitsGrouped),
+        (1610612838, &tags::OverlayCompressionStepPointers),
+        (269484032, &tags::ZonalMap),
+        (1610617601, &tags::ROIArea),
+        (1610613248, &tags::OverlayLocation),
+        (1342177552, &tags::CurveDataDescriptor),
+    ],
+};
+
+pub static TAG_MASKS: &[u32] = &[
+    0xFFFF_FF0F,
+    0xFFFF_FF00,
+    0xFF00_FFFF,
+    0xFFFF_000F,
+    0xFFFF_0000,
+];
+
 pub static TS_BY_IDENT: phf::Map<&'static str, TSRef> = ::phf::Map {
     key: 4636882946510197245,
     disps: &[
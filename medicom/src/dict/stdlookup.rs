@@ -15,8 +15,16 @@
 */
 
 use crate::{
-    core::defn::{dcmdict::DicomDictionary, tag::TagRef, ts::TSRef, uid::UIDRef},
-    dict::lookup::{TAG_BY_IDENT, TAG_BY_VALUE, TS_BY_IDENT, TS_BY_UID, UID_BY_IDENT, UID_BY_UID},
+    core::defn::{
+        dcmdict::DicomDictionary,
+        tag::{Tag, TagRef},
+        ts::TSRef,
+        uid::UIDRef,
+    },
+    dict::lookup::{
+        TAG_BY_IDENT, TAG_BY_MASKED_VALUE, TAG_BY_VALUE, TAG_MASKS, TS_BY_IDENT, TS_BY_UID,
+        UID_BY_IDENT, UID_BY_UID,
+    },
 };
 
 /// The Standard DICOM Dictionary
@@ -35,7 +43,20 @@ impl DicomDictionary for StandardDicomDictionary {
     }
 
     fn get_tag_by_number(&self, number: u32) -> Option<TagRef> {
-        TAG_BY_VALUE.get(&number).copied()
+        if let Some(tag) = TAG_BY_VALUE.get(&number) {
+            return Some(tag);
+        }
+        // Private tags have odd group numbers so cannot be in a standard repeating group, such as
+        // the even groups 6000-601E of Overlays.
+        if Tag::is_private(number) {
+            return None;
+        }
+        TAG_MASKS.iter().find_map(|mask| {
+            TAG_BY_MASKED_VALUE
+                .get(&(number & mask))
+                .copied()
+                .filter(|tag| tag.mask() == *mask)
+        })
     }
 
     fn get_tag_by_name(&self, name: &str) -> Option<TagRef> {
@@ -21914,6 +21914,20 @@ pub static NumberofSeriesRelatedInstances: Tag = Tag::new(
     "Number of Series Related Instances",
 );
 
+/// Source Image IDs
+///
+/// - **Tag:** (0020,31xx)
+/// - **VR:** CS
+/// - **VM:** 1-n
+pub static SourceImageIDs: Tag = Tag::new_masked(
+    "SourceImageIDs",
+    0x0020_3100,
+    0xFFFF_FF00,
+    Some(&vr::CS),
+    &VM::AtLeast(1),
+    "Source Image IDs",
+);
+
 /// Modifying Device ID
 ///
 /// - **Tag:** (0020,3401)
@@ -27244,6 +27258,20 @@ pub static TransformLabel: Tag = Tag::new(
     "Transform Label",
 );
 
+/// Rows For Nth Order Coefficients
+///
+/// - **Tag:** (0028,04x0)
+/// - **VR:** US
+/// - **VM:** 1
+pub static RowsForNthOrderCoefficients: Tag = Tag::new_masked(
+    "RowsForNthOrderCoefficients",
+    0x0028_0400,
+    0xFFFF_FF0F,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Rows For Nth Order Coefficients",
+);
+
 /// Transform Version Number
 ///
 /// - **Tag:** (0028,0401)
@@ -27257,6 +27285,20 @@ pub static TransformVersionNumber: Tag = Tag::new(
     "Transform Version Number",
 );
 
+/// Columns For Nth Order Coefficients
+///
+/// - **Tag:** (0028,04x1)
+/// - **VR:** US
+/// - **VM:** 1
+pub static ColumnsForNthOrderCoefficients: Tag = Tag::new_masked(
+    "ColumnsForNthOrderCoefficients",
+    0x0028_0401,
+    0xFFFF_FF0F,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Columns For Nth Order Coefficients",
+);
+
 /// Number of Transform Steps
 ///
 /// - **Tag:** (0028,0402)
@@ -27270,6 +27312,20 @@ pub static NumberofTransformSteps: Tag = Tag::new(
     "Number of Transform Steps",
 );
 
+/// Coefficient Coding
+///
+/// - **Tag:** (0028,04x2)
+/// - **VR:** LO
+/// - **VM:** 1-n
+pub static CoefficientCoding: Tag = Tag::new_masked(
+    "CoefficientCoding",
+    0x0028_0402,
+    0xFFFF_FF0F,
+    Some(&vr::LO),
+    &VM::AtLeast(1),
+    "Coefficient Coding",
+);
+
 /// Sequence of Compressed Data
 ///
 /// - **Tag:** (0028,0403)
@@ -27283,6 +27339,20 @@ pub static SequenceofCompressedData: Tag = Tag::new(
     "Sequence of Compressed Data",
 );
 
+/// Coefficient Coding Pointers
+///
+/// - **Tag:** (0028,04x3)
+/// - **VR:** AT
+/// - **VM:** 1-n
+pub static CoefficientCodingPointers: Tag = Tag::new_masked(
+    "CoefficientCodingPointers",
+    0x0028_0403,
+    0xFFFF_FF0F,
+    Some(&vr::AT),
+    &VM::AtLeast(1),
+    "Coefficient Coding Pointers",
+);
+
 /// Details of Coefficients
 ///
 /// - **Tag:** (0028,0404)
@@ -27413,6 +27483,76 @@ pub static CodeNumberFormat: Tag = Tag::new(
     "Code Number Format",
 );
 
+/// Code Label
+///
+/// - **Tag:** (0028,08x0)
+/// - **VR:** CS
+/// - **VM:** 1-n
+pub static CodeLabel: Tag = Tag::new_masked(
+    "CodeLabel",
+    0x0028_0800,
+    0xFFFF_FF0F,
+    Some(&vr::CS),
+    &VM::AtLeast(1),
+    "Code Label",
+);
+
+/// Number of Tables
+///
+/// - **Tag:** (0028,08x2)
+/// - **VR:** US
+/// - **VM:** 1
+pub static NumberofTables: Tag = Tag::new_masked(
+    "NumberofTables",
+    0x0028_0802,
+    0xFFFF_FF0F,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Number of Tables",
+);
+
+/// Code Table Location
+///
+/// - **Tag:** (0028,08x3)
+/// - **VR:** AT
+/// - **VM:** 1-n
+pub static CodeTableLocation: Tag = Tag::new_masked(
+    "CodeTableLocation",
+    0x0028_0803,
+    0xFFFF_FF0F,
+    Some(&vr::AT),
+    &VM::AtLeast(1),
+    "Code Table Location",
+);
+
+/// Bits For Code Word
+///
+/// - **Tag:** (0028,08x4)
+/// - **VR:** US
+/// - **VM:** 1
+pub static BitsForCodeWord: Tag = Tag::new_masked(
+    "BitsForCodeWord",
+    0x0028_0804,
+    0xFFFF_FF0F,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Bits For Code Word",
+);
+
+/// Image Data Location
+///
+/// - **Tag:** (0028,08x8)
+/// - **VR:** AT
+/// - **VM:** 1-n
+pub static ImageDataLocation: Tag = Tag::new_masked(
+    "ImageDataLocation",
+    0x0028_0808,
+    0xFFFF_FF0F,
+    Some(&vr::AT),
+    &VM::AtLeast(1),
+    "Image Data Location",
+);
+
 /// Pixel Spacing Calibration Type
 ///
 /// - **Tag:** (0028,0A02)
@@ -48252,6 +48392,104 @@ pub static InstanceOriginStatus: Tag = Tag::new(
     "Instance Origin Status",
 );
 
+/// Escape Triplet
+///
+/// - **Tag:** (1000,xxx0)
+/// - **VR:** US
+/// - **VM:** 3
+pub static EscapeTriplet: Tag = Tag::new_masked(
+    "EscapeTriplet",
+    0x1000_0000,
+    0xFFFF_000F,
+    Some(&vr::US),
+    &VM::Distinct(3),
+    "Escape Triplet",
+);
+
+/// Run Length Triplet
+///
+/// - **Tag:** (1000,xxx1)
+/// - **VR:** US
+/// - **VM:** 3
+pub static RunLengthTriplet: Tag = Tag::new_masked(
+    "RunLengthTriplet",
+    0x1000_0001,
+    0xFFFF_000F,
+    Some(&vr::US),
+    &VM::Distinct(3),
+    "Run Length Triplet",
+);
+
+/// Huffman Table Size
+///
+/// - **Tag:** (1000,xxx2)
+/// - **VR:** US
+/// - **VM:** 1
+pub static HuffmanTableSize: Tag = Tag::new_masked(
+    "HuffmanTableSize",
+    0x1000_0002,
+    0xFFFF_000F,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Huffman Table Size",
+);
+
+/// Huffman Table Triplet
+///
+/// - **Tag:** (1000,xxx3)
+/// - **VR:** US
+/// - **VM:** 3
+pub static HuffmanTableTriplet: Tag = Tag::new_masked(
+    "HuffmanTableTriplet",
+    0x1000_0003,
+    0xFFFF_000F,
+    Some(&vr::US),
+    &VM::Distinct(3),
+    "Huffman Table Triplet",
+);
+
+/// Shift Table Size
+///
+/// - **Tag:** (1000,xxx4)
+/// - **VR:** US
+/// - **VM:** 1
+pub static ShiftTableSize: Tag = Tag::new_masked(
+    "ShiftTableSize",
+    0x1000_0004,
+    0xFFFF_000F,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Shift Table Size",
+);
+
+/// Shift Table Triplet
+///
+/// - **Tag:** (1000,xxx5)
+/// - **VR:** US
+/// - **VM:** 3
+pub static ShiftTableTriplet: Tag = Tag::new_masked(
+    "ShiftTableTriplet",
+    0x1000_0005,
+    0xFFFF_000F,
+    Some(&vr::US),
+    &VM::Distinct(3),
+    "Shift Table Triplet",
+);
+
+/// Zonal Map
+///
+/// - **Tag:** (1010,xxxx)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static ZonalMap: Tag = Tag::new_masked(
+    "ZonalMap",
+    0x1010_0000,
+    0xFFFF_0000,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Zonal Map",
+);
+
 /// Number of Copies
 ///
 /// - **Tag:** (2000,0010)
@@ -65217,6 +65455,370 @@ pub static MACParametersSequence: Tag = Tag::new(
     "MAC Parameters Sequence",
 );
 
+/// Curve Dimensions
+///
+/// - **Tag:** (50xx,0005)
+/// - **VR:** US
+/// - **VM:** 1
+pub static CurveDimensions: Tag = Tag::new_masked(
+    "CurveDimensions",
+    0x5000_0005,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Curve Dimensions",
+);
+
+/// Number of Points
+///
+/// - **Tag:** (50xx,0010)
+/// - **VR:** US
+/// - **VM:** 1
+pub static NumberofPoints: Tag = Tag::new_masked(
+    "NumberofPoints",
+    0x5000_0010,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Number of Points",
+);
+
+/// Type of Data
+///
+/// - **Tag:** (50xx,0020)
+/// - **VR:** CS
+/// - **VM:** 1
+pub static TypeofData: Tag = Tag::new_masked(
+    "TypeofData",
+    0x5000_0020,
+    0xFF00_FFFF,
+    Some(&vr::CS),
+    &VM::Distinct(1),
+    "Type of Data",
+);
+
+/// Curve Description
+///
+/// - **Tag:** (50xx,0022)
+/// - **VR:** LO
+/// - **VM:** 1
+pub static CurveDescription: Tag = Tag::new_masked(
+    "CurveDescription",
+    0x5000_0022,
+    0xFF00_FFFF,
+    Some(&vr::LO),
+    &VM::Distinct(1),
+    "Curve Description",
+);
+
+/// Axis Units
+///
+/// - **Tag:** (50xx,0030)
+/// - **VR:** SH
+/// - **VM:** 1-n
+pub static AxisUnits: Tag = Tag::new_masked(
+    "AxisUnits",
+    0x5000_0030,
+    0xFF00_FFFF,
+    Some(&vr::SH),
+    &VM::AtLeast(1),
+    "Axis Units",
+);
+
+/// Axis Labels
+///
+/// - **Tag:** (50xx,0040)
+/// - **VR:** SH
+/// - **VM:** 1-n
+pub static AxisLabels: Tag = Tag::new_masked(
+    "AxisLabels",
+    0x5000_0040,
+    0xFF00_FFFF,
+    Some(&vr::SH),
+    &VM::AtLeast(1),
+    "Axis Labels",
+);
+
+/// Data Value Representation
+///
+/// - **Tag:** (50xx,0103)
+/// - **VR:** US
+/// - **VM:** 1
+pub static DataValueRepresentation: Tag = Tag::new_masked(
+    "DataValueRepresentation",
+    0x5000_0103,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Data Value Representation",
+);
+
+/// Minimum Coordinate Value
+///
+/// - **Tag:** (50xx,0104)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static MinimumCoordinateValue: Tag = Tag::new_masked(
+    "MinimumCoordinateValue",
+    0x5000_0104,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Minimum Coordinate Value",
+);
+
+/// Maximum Coordinate Value
+///
+/// - **Tag:** (50xx,0105)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static MaximumCoordinateValue: Tag = Tag::new_masked(
+    "MaximumCoordinateValue",
+    0x5000_0105,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Maximum Coordinate Value",
+);
+
+/// Curve Range
+///
+/// - **Tag:** (50xx,0106)
+/// - **VR:** SH
+/// - **VM:** 1-n
+pub static CurveRange: Tag = Tag::new_masked(
+    "CurveRange",
+    0x5000_0106,
+    0xFF00_FFFF,
+    Some(&vr::SH),
+    &VM::AtLeast(1),
+    "Curve Range",
+);
+
+/// Curve Data Descriptor
+///
+/// - **Tag:** (50xx,0110)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static CurveDataDescriptor: Tag = Tag::new_masked(
+    "CurveDataDescriptor",
+    0x5000_0110,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Curve Data Descriptor",
+);
+
+/// Coordinate Start Value
+///
+/// - **Tag:** (50xx,0112)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static CoordinateStartValue: Tag = Tag::new_masked(
+    "CoordinateStartValue",
+    0x5000_0112,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Coordinate Start Value",
+);
+
+/// Coordinate Step Value
+///
+/// - **Tag:** (50xx,0114)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static CoordinateStepValue: Tag = Tag::new_masked(
+    "CoordinateStepValue",
+    0x5000_0114,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Coordinate Step Value",
+);
+
+/// Curve Activation Layer
+///
+/// - **Tag:** (50xx,1001)
+/// - **VR:** CS
+/// - **VM:** 1
+pub static CurveActivationLayer: Tag = Tag::new_masked(
+    "CurveActivationLayer",
+    0x5000_1001,
+    0xFF00_FFFF,
+    Some(&vr::CS),
+    &VM::Distinct(1),
+    "Curve Activation Layer",
+);
+
+/// Audio Type
+///
+/// - **Tag:** (50xx,2000)
+/// - **VR:** US
+/// - **VM:** 1
+pub static AudioType: Tag = Tag::new_masked(
+    "AudioType",
+    0x5000_2000,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Audio Type",
+);
+
+/// Audio Sample Format
+///
+/// - **Tag:** (50xx,2002)
+/// - **VR:** US
+/// - **VM:** 1
+pub static AudioSampleFormat: Tag = Tag::new_masked(
+    "AudioSampleFormat",
+    0x5000_2002,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Audio Sample Format",
+);
+
+/// Number of Channels
+///
+/// - **Tag:** (50xx,2004)
+/// - **VR:** US
+/// - **VM:** 1
+pub static NumberofChannels: Tag = Tag::new_masked(
+    "NumberofChannels",
+    0x5000_2004,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Number of Channels",
+);
+
+/// Number of Samples
+///
+/// - **Tag:** (50xx,2006)
+/// - **VR:** UL
+/// - **VM:** 1
+pub static NumberofSamples: Tag = Tag::new_masked(
+    "NumberofSamples",
+    0x5000_2006,
+    0xFF00_FFFF,
+    Some(&vr::UL),
+    &VM::Distinct(1),
+    "Number of Samples",
+);
+
+/// Sample Rate
+///
+/// - **Tag:** (50xx,2008)
+/// - **VR:** UL
+/// - **VM:** 1
+pub static SampleRate: Tag = Tag::new_masked(
+    "SampleRate",
+    0x5000_2008,
+    0xFF00_FFFF,
+    Some(&vr::UL),
+    &VM::Distinct(1),
+    "Sample Rate",
+);
+
+/// Total Time
+///
+/// - **Tag:** (50xx,200A)
+/// - **VR:** UL
+/// - **VM:** 1
+pub static TotalTime: Tag = Tag::new_masked(
+    "TotalTime",
+    0x5000_200A,
+    0xFF00_FFFF,
+    Some(&vr::UL),
+    &VM::Distinct(1),
+    "Total Time",
+);
+
+/// Audio Sample Data
+///
+/// - **Tag:** (50xx,200C)
+/// - **VR:** OB
+/// - **VM:** 1
+pub static AudioSampleData: Tag = Tag::new_masked(
+    "AudioSampleData",
+    0x5000_200C,
+    0xFF00_FFFF,
+    Some(&vr::OB),
+    &VM::Distinct(1),
+    "Audio Sample Data",
+);
+
+/// Audio Comments
+///
+/// - **Tag:** (50xx,200E)
+/// - **VR:** LT
+/// - **VM:** 1
+pub static AudioComments: Tag = Tag::new_masked(
+    "AudioComments",
+    0x5000_200E,
+    0xFF00_FFFF,
+    Some(&vr::LT),
+    &VM::Distinct(1),
+    "Audio Comments",
+);
+
+/// Curve Label
+///
+/// - **Tag:** (50xx,2500)
+/// - **VR:** LO
+/// - **VM:** 1
+pub static CurveLabel: Tag = Tag::new_masked(
+    "CurveLabel",
+    0x5000_2500,
+    0xFF00_FFFF,
+    Some(&vr::LO),
+    &VM::Distinct(1),
+    "Curve Label",
+);
+
+/// Curve Referenced Overlay Sequence
+///
+/// - **Tag:** (50xx,2600)
+/// - **VR:** SQ
+/// - **VM:** 1
+pub static CurveReferencedOverlaySequence: Tag = Tag::new_masked(
+    "CurveReferencedOverlaySequence",
+    0x5000_2600,
+    0xFF00_FFFF,
+    Some(&vr::SQ),
+    &VM::Distinct(1),
+    "Curve Referenced Overlay Sequence",
+);
+
+/// Curve Referenced Overlay Group
+///
+/// - **Tag:** (50xx,2610)
+/// - **VR:** US
+/// - **VM:** 1
+pub static CurveReferencedOverlayGroup: Tag = Tag::new_masked(
+    "CurveReferencedOverlayGroup",
+    0x5000_2610,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Curve Referenced Overlay Group",
+);
+
+/// Curve Data
+///
+/// - **Tag:** (50xx,3000)
+/// - **VR:** OB
+/// - **VM:** 1
+pub static CurveData: Tag = Tag::new_masked(
+    "CurveData",
+    0x5000_3000,
+    0xFF00_FFFF,
+    Some(&vr::OB),
+    &VM::Distinct(1),
+    "Curve Data",
+);
+
 /// Shared Functional Groups Sequence
 ///
 /// - **Tag:** (5200,9229)
@@ -65360,6 +65962,636 @@ pub static SpectroscopyData: Tag = Tag::new(
     "Spectroscopy Data",
 );
 
+/// Overlay Rows
+///
+/// - **Tag:** (60xx,0010)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayRows: Tag = Tag::new_masked(
+    "OverlayRows",
+    0x6000_0010,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Rows",
+);
+
+/// Overlay Columns
+///
+/// - **Tag:** (60xx,0011)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayColumns: Tag = Tag::new_masked(
+    "OverlayColumns",
+    0x6000_0011,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Columns",
+);
+
+/// Overlay Planes
+///
+/// - **Tag:** (60xx,0012)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayPlanes: Tag = Tag::new_masked(
+    "OverlayPlanes",
+    0x6000_0012,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Planes",
+);
+
+/// Number of Frames in Overlay
+///
+/// - **Tag:** (60xx,0015)
+/// - **VR:** IS
+/// - **VM:** 1
+pub static NumberofFramesinOverlay: Tag = Tag::new_masked(
+    "NumberofFramesinOverlay",
+    0x6000_0015,
+    0xFF00_FFFF,
+    Some(&vr::IS),
+    &VM::Distinct(1),
+    "Number of Frames in Overlay",
+);
+
+/// Overlay Description
+///
+/// - **Tag:** (60xx,0022)
+/// - **VR:** LO
+/// - **VM:** 1
+pub static OverlayDescription: Tag = Tag::new_masked(
+    "OverlayDescription",
+    0x6000_0022,
+    0xFF00_FFFF,
+    Some(&vr::LO),
+    &VM::Distinct(1),
+    "Overlay Description",
+);
+
+/// Overlay Type
+///
+/// - **Tag:** (60xx,0040)
+/// - **VR:** CS
+/// - **VM:** 1
+pub static OverlayType: Tag = Tag::new_masked(
+    "OverlayType",
+    0x6000_0040,
+    0xFF00_FFFF,
+    Some(&vr::CS),
+    &VM::Distinct(1),
+    "Overlay Type",
+);
+
+/// Overlay Subtype
+///
+/// - **Tag:** (60xx,0045)
+/// - **VR:** LO
+/// - **VM:** 1
+pub static OverlaySubtype: Tag = Tag::new_masked(
+    "OverlaySubtype",
+    0x6000_0045,
+    0xFF00_FFFF,
+    Some(&vr::LO),
+    &VM::Distinct(1),
+    "Overlay Subtype",
+);
+
+/// Overlay Origin
+///
+/// - **Tag:** (60xx,0050)
+/// - **VR:** SS
+/// - **VM:** 2
+pub static OverlayOrigin: Tag = Tag::new_masked(
+    "OverlayOrigin",
+    0x6000_0050,
+    0xFF00_FFFF,
+    Some(&vr::SS),
+    &VM::Distinct(2),
+    "Overlay Origin",
+);
+
+/// Image Frame Origin
+///
+/// - **Tag:** (60xx,0051)
+/// - **VR:** US
+/// - **VM:** 1
+pub static ImageFrameOrigin: Tag = Tag::new_masked(
+    "ImageFrameOrigin",
+    0x6000_0051,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Image Frame Origin",
+);
+
+/// Overlay Plane Origin
+///
+/// - **Tag:** (60xx,0052)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayPlaneOrigin: Tag = Tag::new_masked(
+    "OverlayPlaneOrigin",
+    0x6000_0052,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Plane Origin",
+);
+
+/// Overlay Compression Code
+///
+/// - **Tag:** (60xx,0060)
+/// - **VR:** CS
+/// - **VM:** 1
+pub static OverlayCompressionCode: Tag = Tag::new_masked(
+    "OverlayCompressionCode",
+    0x6000_0060,
+    0xFF00_FFFF,
+    Some(&vr::CS),
+    &VM::Distinct(1),
+    "Overlay Compression Code",
+);
+
+/// Overlay Compression Originator
+///
+/// - **Tag:** (60xx,0061)
+/// - **VR:** SH
+/// - **VM:** 1
+pub static OverlayCompressionOriginator: Tag = Tag::new_masked(
+    "OverlayCompressionOriginator",
+    0x6000_0061,
+    0xFF00_FFFF,
+    Some(&vr::SH),
+    &VM::Distinct(1),
+    "Overlay Compression Originator",
+);
+
+/// Overlay Compression Label
+///
+/// - **Tag:** (60xx,0062)
+/// - **VR:** SH
+/// - **VM:** 1
+pub static OverlayCompressionLabel: Tag = Tag::new_masked(
+    "OverlayCompressionLabel",
+    0x6000_0062,
+    0xFF00_FFFF,
+    Some(&vr::SH),
+    &VM::Distinct(1),
+    "Overlay Compression Label",
+);
+
+/// Overlay Compression Description
+///
+/// - **Tag:** (60xx,0063)
+/// - **VR:** CS
+/// - **VM:** 1
+pub static OverlayCompressionDescription: Tag = Tag::new_masked(
+    "OverlayCompressionDescription",
+    0x6000_0063,
+    0xFF00_FFFF,
+    Some(&vr::CS),
+    &VM::Distinct(1),
+    "Overlay Compression Description",
+);
+
+/// Overlay Compression Step Pointers
+///
+/// - **Tag:** (60xx,0066)
+/// - **VR:** AT
+/// - **VM:** 1-n
+pub static OverlayCompressionStepPointers: Tag = Tag::new_masked(
+    "OverlayCompressionStepPointers",
+    0x6000_0066,
+    0xFF00_FFFF,
+    Some(&vr::AT),
+    &VM::AtLeast(1),
+    "Overlay Compression Step Pointers",
+);
+
+/// Overlay Repeat Interval
+///
+/// - **Tag:** (60xx,0068)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayRepeatInterval: Tag = Tag::new_masked(
+    "OverlayRepeatInterval",
+    0x6000_0068,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Repeat Interval",
+);
+
+/// Overlay Bits Grouped
+///
+/// - **Tag:** (60xx,0069)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayBitsGrouped: Tag = Tag::new_masked(
+    "OverlayBitsGrouped",
+    0x6000_0069,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Bits Grouped",
+);
+
+/// Overlay Bits Allocated
+///
+/// - **Tag:** (60xx,0100)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayBitsAllocated: Tag = Tag::new_masked(
+    "OverlayBitsAllocated",
+    0x6000_0100,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Bits Allocated",
+);
+
+/// Overlay Bit Position
+///
+/// - **Tag:** (60xx,0102)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayBitPosition: Tag = Tag::new_masked(
+    "OverlayBitPosition",
+    0x6000_0102,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Bit Position",
+);
+
+/// Overlay Format
+///
+/// - **Tag:** (60xx,0110)
+/// - **VR:** CS
+/// - **VM:** 1
+pub static OverlayFormat: Tag = Tag::new_masked(
+    "OverlayFormat",
+    0x6000_0110,
+    0xFF00_FFFF,
+    Some(&vr::CS),
+    &VM::Distinct(1),
+    "Overlay Format",
+);
+
+/// Overlay Location
+///
+/// - **Tag:** (60xx,0200)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayLocation: Tag = Tag::new_masked(
+    "OverlayLocation",
+    0x6000_0200,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Location",
+);
+
+/// Overlay Code Label
+///
+/// - **Tag:** (60xx,0800)
+/// - **VR:** CS
+/// - **VM:** 1-n
+pub static OverlayCodeLabel: Tag = Tag::new_masked(
+    "OverlayCodeLabel",
+    0x6000_0800,
+    0xFF00_FFFF,
+    Some(&vr::CS),
+    &VM::AtLeast(1),
+    "Overlay Code Label",
+);
+
+/// Overlay Number of Tables
+///
+/// - **Tag:** (60xx,0802)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayNumberofTables: Tag = Tag::new_masked(
+    "OverlayNumberofTables",
+    0x6000_0802,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Number of Tables",
+);
+
+/// Overlay Code Table Location
+///
+/// - **Tag:** (60xx,0803)
+/// - **VR:** AT
+/// - **VM:** 1-n
+pub static OverlayCodeTableLocation: Tag = Tag::new_masked(
+    "OverlayCodeTableLocation",
+    0x6000_0803,
+    0xFF00_FFFF,
+    Some(&vr::AT),
+    &VM::AtLeast(1),
+    "Overlay Code Table Location",
+);
+
+/// Overlay Bits For Code Word
+///
+/// - **Tag:** (60xx,0804)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayBitsForCodeWord: Tag = Tag::new_masked(
+    "OverlayBitsForCodeWord",
+    0x6000_0804,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Bits For Code Word",
+);
+
+/// Overlay Activation Layer
+///
+/// - **Tag:** (60xx,1001)
+/// - **VR:** CS
+/// - **VM:** 1
+pub static OverlayActivationLayer: Tag = Tag::new_masked(
+    "OverlayActivationLayer",
+    0x6000_1001,
+    0xFF00_FFFF,
+    Some(&vr::CS),
+    &VM::Distinct(1),
+    "Overlay Activation Layer",
+);
+
+/// Overlay Descriptor - Gray
+///
+/// - **Tag:** (60xx,1100)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayDescriptorGray: Tag = Tag::new_masked(
+    "OverlayDescriptorGray",
+    0x6000_1100,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Descriptor - Gray",
+);
+
+/// Overlay Descriptor - Red
+///
+/// - **Tag:** (60xx,1101)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayDescriptorRed: Tag = Tag::new_masked(
+    "OverlayDescriptorRed",
+    0x6000_1101,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Descriptor - Red",
+);
+
+/// Overlay Descriptor - Green
+///
+/// - **Tag:** (60xx,1102)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayDescriptorGreen: Tag = Tag::new_masked(
+    "OverlayDescriptorGreen",
+    0x6000_1102,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Descriptor - Green",
+);
+
+/// Overlay Descriptor - Blue
+///
+/// - **Tag:** (60xx,1103)
+/// - **VR:** US
+/// - **VM:** 1
+pub static OverlayDescriptorBlue: Tag = Tag::new_masked(
+    "OverlayDescriptorBlue",
+    0x6000_1103,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Overlay Descriptor - Blue",
+);
+
+/// Overlays - Gray
+///
+/// - **Tag:** (60xx,1200)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static OverlaysGray: Tag = Tag::new_masked(
+    "OverlaysGray",
+    0x6000_1200,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Overlays - Gray",
+);
+
+/// Overlays - Red
+///
+/// - **Tag:** (60xx,1201)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static OverlaysRed: Tag = Tag::new_masked(
+    "OverlaysRed",
+    0x6000_1201,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Overlays - Red",
+);
+
+/// Overlays - Green
+///
+/// - **Tag:** (60xx,1202)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static OverlaysGreen: Tag = Tag::new_masked(
+    "OverlaysGreen",
+    0x6000_1202,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Overlays - Green",
+);
+
+/// Overlays - Blue
+///
+/// - **Tag:** (60xx,1203)
+/// - **VR:** US
+/// - **VM:** 1-n
+pub static OverlaysBlue: Tag = Tag::new_masked(
+    "OverlaysBlue",
+    0x6000_1203,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::AtLeast(1),
+    "Overlays - Blue",
+);
+
+/// ROI Area
+///
+/// - **Tag:** (60xx,1301)
+/// - **VR:** IS
+/// - **VM:** 1
+pub static ROIArea: Tag = Tag::new_masked(
+    "ROIArea",
+    0x6000_1301,
+    0xFF00_FFFF,
+    Some(&vr::IS),
+    &VM::Distinct(1),
+    "ROI Area",
+);
+
+/// ROI Mean
+///
+/// - **Tag:** (60xx,1302)
+/// - **VR:** DS
+/// - **VM:** 1
+pub static ROIMean: Tag = Tag::new_masked(
+    "ROIMean",
+    0x6000_1302,
+    0xFF00_FFFF,
+    Some(&vr::DS),
+    &VM::Distinct(1),
+    "ROI Mean",
+);
+
+/// ROI Standard Deviation
+///
+/// - **Tag:** (60xx,1303)
+/// - **VR:** DS
+/// - **VM:** 1
+pub static ROIStandardDeviation: Tag = Tag::new_masked(
+    "ROIStandardDeviation",
+    0x6000_1303,
+    0xFF00_FFFF,
+    Some(&vr::DS),
+    &VM::Distinct(1),
+    "ROI Standard Deviation",
+);
+
+/// Overlay Label
+///
+/// - **Tag:** (60xx,1500)
+/// - **VR:** LO
+/// - **VM:** 1
+pub static OverlayLabel: Tag = Tag::new_masked(
+    "OverlayLabel",
+    0x6000_1500,
+    0xFF00_FFFF,
+    Some(&vr::LO),
+    &VM::Distinct(1),
+    "Overlay Label",
+);
+
+/// Overlay Data
+///
+/// - **Tag:** (60xx,3000)
+/// - **VR:** OB
+/// - **VM:** 1
+pub static OverlayData: Tag = Tag::new_masked(
+    "OverlayData",
+    0x6000_3000,
+    0xFF00_FFFF,
+    Some(&vr::OB),
+    &VM::Distinct(1),
+    "Overlay Data",
+);
+
+/// Overlay Comments
+///
+/// - **Tag:** (60xx,4000)
+/// - **VR:** LT
+/// - **VM:** 1
+pub static OverlayComments: Tag = Tag::new_masked(
+    "OverlayComments",
+    0x6000_4000,
+    0xFF00_FFFF,
+    Some(&vr::LT),
+    &VM::Distinct(1),
+    "Overlay Comments",
+);
+
+/// Variable Pixel Data
+///
+/// - **Tag:** (7Fxx,0010)
+/// - **VR:** OB
+/// - **VM:** 1
+pub static VariablePixelData: Tag = Tag::new_masked(
+    "VariablePixelData",
+    0x7F00_0010,
+    0xFF00_FFFF,
+    Some(&vr::OB),
+    &VM::Distinct(1),
+    "Variable Pixel Data",
+);
+
+/// Variable Next Data Group
+///
+/// - **Tag:** (7Fxx,0011)
+/// - **VR:** US
+/// - **VM:** 1
+pub static VariableNextDataGroup: Tag = Tag::new_masked(
+    "VariableNextDataGroup",
+    0x7F00_0011,
+    0xFF00_FFFF,
+    Some(&vr::US),
+    &VM::Distinct(1),
+    "Variable Next Data Group",
+);
+
+/// Variable Coefficients SDVN
+///
+/// - **Tag:** (7Fxx,0020)
+/// - **VR:** OW
+/// - **VM:** 1
+pub static VariableCoefficientsSDVN: Tag = Tag::new_masked(
+    "VariableCoefficientsSDVN",
+    0x7F00_0020,
+    0xFF00_FFFF,
+    Some(&vr::OW),
+    &VM::Distinct(1),
+    "Variable Coefficients SDVN",
+);
+
+/// Variable Coefficients SDHN
+///
+/// - **Tag:** (7Fxx,0030)
+/// - **VR:** OW
+/// - **VM:** 1
+pub static VariableCoefficientsSDHN: Tag = Tag::new_masked(
+    "VariableCoefficientsSDHN",
+    0x7F00_0030,
+    0xFF00_FFFF,
+    Some(&vr::OW),
+    &VM::Distinct(1),
+    "Variable Coefficients SDHN",
+);
+
+/// Variable Coefficients SDDN
+///
+/// - **Tag:** (7Fxx,0040)
+/// - **VR:** OW
+/// - **VM:** 1
+pub static VariableCoefficientsSDDN: Tag = Tag::new_masked(
+    "VariableCoefficientsSDDN",
+    0x7F00_0040,
+    0xFF00_FFFF,
+    Some(&vr::OW),
+    &VM::Distinct(1),
+    "Variable Coefficients SDDN",
+);
+
 /// Extended Offset Table
 ///
 /// - **Tag:** (7FE0,0001)
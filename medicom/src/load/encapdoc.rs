@@ -0,0 +1,708 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Encapsulated Documents, instances whose Encapsulated Document holds a file of another format,
+//! such as a PDF report, an HL7 CDA document or a 3D model, and the creation of such instances
+//! from a document's bytes.
+
+use std::{
+    collections::BTreeMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use uuid::Uuid;
+
+use crate::{
+    core::{
+        charset::CSRef,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{
+            constants::ts::ExplicitVRLittleEndian,
+            tag::Tag,
+            uid::UIDRef,
+            vr::{SQ, UN},
+        },
+        values::RawValue,
+    },
+    dict::{tags, uids},
+    load::{pixeldata::LoadError, Code},
+};
+
+/// The Implementation Class UID of instances created by this crate. This is the UUID-derived UID
+/// (PS3.5 Annex B.2) of the fixed UUID `8513a7b5-8892-451d-b1ff-46d13a3ac41a`, under the `2.25`
+/// root which ISO/IEC 9834-8 registers for UUIDs, so that no organizational root is needed. It
+/// identifies the implementation across versions, which are given by the Implementation Version
+/// Name instead.
+const IMPLEMENTATION_CLASS_UID: &str = "2.25.176889378624673988628389931547348354074";
+
+/// The kinds of documents which can be encapsulated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DocumentType {
+    Pdf,
+    /// An HL7 Clinical Document Architecture document.
+    Cda,
+    Stl,
+    Obj,
+}
+
+impl DocumentType {
+    pub const ALL: [DocumentType; 4] = [Self::Pdf, Self::Cda, Self::Stl, Self::Obj];
+
+    /// The Storage SOP Class of instances encapsulating this type of document.
+    #[must_use]
+    pub fn sop_class(&self) -> UIDRef {
+        match self {
+            Self::Pdf => &uids::EncapsulatedPDFStorage,
+            Self::Cda => &uids::EncapsulatedCDAStorage,
+            Self::Stl => &uids::EncapsulatedSTLStorage,
+            Self::Obj => &uids::EncapsulatedOBJStorage,
+        }
+    }
+
+    /// The MIME Type of Encapsulated Document for this type of document.
+    #[must_use]
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Pdf => "application/pdf",
+            Self::Cda => "text/XML",
+            Self::Stl => "model/stl",
+            Self::Obj => "model/obj",
+        }
+    }
+
+    /// The Modality of instances encapsulating this type of document.
+    #[must_use]
+    pub fn modality(&self) -> &'static str {
+        match self {
+            Self::Pdf | Self::Cda => "DOC",
+            Self::Stl | Self::Obj => "M3D",
+        }
+    }
+
+    /// The file extension for this type of document.
+    #[must_use]
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::Cda => "xml",
+            Self::Stl => "stl",
+            Self::Obj => "obj",
+        }
+    }
+
+    #[must_use]
+    pub fn from_sop_class_uid(uid: &str) -> Option<Self> {
+        let uid = uid.trim_end_matches('\0').trim();
+        Self::ALL.into_iter().find(|t| t.sop_class().uid() == uid)
+    }
+
+    /// The type of document with the given MIME type, ignoring case.
+    #[must_use]
+    pub fn from_mime_type(mime_type: &str) -> Option<Self> {
+        match mime_type.trim().to_ascii_lowercase().as_str() {
+            "application/pdf" => Some(Self::Pdf),
+            "text/xml" => Some(Self::Cda),
+            "model/stl" | "application/sla" => Some(Self::Stl),
+            "model/obj" => Some(Self::Obj),
+            _ => None,
+        }
+    }
+
+    /// The type of document with the given file extension, ignoring case.
+    #[must_use]
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "pdf" => Some(Self::Pdf),
+            "xml" | "cda" => Some(Self::Cda),
+            "stl" => Some(Self::Stl),
+            "obj" => Some(Self::Obj),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DocumentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.sop_class().name().fmt(f)
+    }
+}
+
+/// The patient and study which an encapsulated document is created within. Values which are
+/// empty are encoded as empty attributes.
+#[derive(Clone, Debug, Default)]
+pub struct DocumentContext {
+    pub patient_name: String,
+    pub patient_id: String,
+    pub patient_birth_date: String,
+    pub patient_sex: String,
+    /// The Study Instance UID of the study to add the document to. If not specified then the
+    /// document is created in a new study.
+    pub study_uid: Option<String>,
+    pub study_id: String,
+    pub study_date: String,
+    pub study_time: String,
+    pub study_description: String,
+    pub accession_number: String,
+    pub referring_physician: String,
+    /// The Series Instance UID of the series to add the document to. If not specified then the
+    /// document is created in a new series.
+    pub series_uid: Option<String>,
+    pub series_number: Option<i32>,
+    pub instance_number: Option<i32>,
+    pub manufacturer: String,
+}
+
+impl DocumentContext {
+    /// Copies the patient and study of an existing instance, to create a document in its study.
+    #[must_use]
+    pub fn from_dcmroot(dcmroot: &DicomRoot) -> Self {
+        let string = |tag: &Tag| {
+            dcmroot
+                .get_value_by_tag(tag)
+                .and_then(|v| {
+                    v.string()
+                        .map(|s| s.trim_end_matches(['\0', ' ']).to_owned())
+                })
+                .unwrap_or_default()
+        };
+        let study_uid = Some(string(&tags::StudyInstanceUID)).filter(|s| !s.is_empty());
+        Self {
+            patient_name: string(&tags::PatientsName),
+            patient_id: string(&tags::PatientID),
+            patient_birth_date: string(&tags::PatientsBirthDate),
+            patient_sex: string(&tags::PatientsSex),
+            study_uid,
+            study_id: string(&tags::StudyID),
+            study_date: string(&tags::StudyDate),
+            study_time: string(&tags::StudyTime),
+            study_description: string(&tags::StudyDescription),
+            accession_number: string(&tags::AccessionNumber),
+            referring_physician: string(&tags::ReferringPhysiciansName),
+            ..Self::default()
+        }
+    }
+}
+
+/// A document encapsulated within an instance.
+#[derive(Clone, Debug)]
+pub struct EncapsulatedDocument {
+    doc_type: Option<DocumentType>,
+    mime_type: String,
+    title: String,
+    data: Vec<u8>,
+}
+
+impl EncapsulatedDocument {
+    /// Creates a document of the given type from its bytes, to be encapsulated with `to_dcmroot`.
+    #[must_use]
+    pub fn new(doc_type: DocumentType, data: Vec<u8>) -> Self {
+        Self {
+            doc_type: Some(doc_type),
+            mime_type: doc_type.mime_type().to_owned(),
+            title: String::new(),
+            data,
+        }
+    }
+
+    /// Sets the Document Title.
+    #[must_use]
+    pub fn with_title(mut self, title: &str) -> Self {
+        title.clone_into(&mut self.title);
+        self
+    }
+
+    /// Reads the document of an Encapsulated Document instance. The padding added to make the
+    /// document an even length is removed, using the Encapsulated Document Length if present.
+    ///
+    /// # Errors
+    /// - `InvalidEncapsulatedDocument` if there is no Encapsulated Document.
+    pub fn from_dcmroot(dcmroot: &DicomRoot) -> Result<Self, LoadError> {
+        let Some(doc_obj) = dcmroot.get_child_by_tag(&tags::EncapsulatedDocument) else {
+            return Err(LoadError::InvalidEncapsulatedDocument(
+                "missing Encapsulated Document".to_owned(),
+            ));
+        };
+        let string = |tag: &Tag| {
+            dcmroot
+                .get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .unwrap_or_default()
+        };
+        let mime_type = string(&tags::MIMETypeofEncapsulatedDocument);
+        let doc_type = DocumentType::from_mime_type(&mime_type)
+            .or_else(|| DocumentType::from_sop_class_uid(&string(&tags::SOPClassUID)));
+
+        let mut data = doc_obj.element().data().clone();
+        let length = dcmroot
+            .get_value_by_tag(&tags::EncapsulatedDocumentLength)
+            .and_then(|v| v.uint())
+            .and_then(|len| usize::try_from(len).ok());
+        match length {
+            Some(len) if len <= data.len() => data.truncate(len),
+            _ => {
+                if Self::is_padded(doc_type, &data) {
+                    data.pop();
+                }
+            }
+        }
+
+        Ok(Self {
+            doc_type,
+            mime_type,
+            title: string(&tags::DocumentTitle),
+            data,
+        })
+    }
+
+    /// Whether the document ends with a padding byte, when its length is not otherwise known.
+    fn is_padded(doc_type: Option<DocumentType>, data: &[u8]) -> bool {
+        if data.len() % 2 != 0 || data.last() != Some(&0) {
+            return false;
+        }
+        // A binary STL ends with the attribute byte count of its last triangle, which is usually
+        // zero. Its length is determined by the triangle count in its header.
+        if doc_type == Some(DocumentType::Stl) && data.len() >= 84 {
+            let triangles = u32::from_le_bytes([data[80], data[81], data[82], data[83]]);
+            return usize::try_from(triangles)
+                .ok()
+                .and_then(|t| t.checked_mul(50))
+                .is_none_or(|len| len + 84 != data.len());
+        }
+        true
+    }
+
+    /// The type of document, or `None` if its MIME type is not one which can be created.
+    #[must_use]
+    pub fn doc_type(&self) -> Option<DocumentType> {
+        self.doc_type
+    }
+
+    /// The MIME Type of Encapsulated Document.
+    #[must_use]
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The bytes of the document.
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    #[must_use]
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Creates an Encapsulated Document instance for this document within the given patient and
+    /// study, including its File Meta. New UIDs are generated for the instance and any study or
+    /// series not specified by the context. Dates and times of creation are in UTC.
+    ///
+    /// # Errors
+    /// - `InvalidEncapsulatedDocument` if the type of document is unknown, a PDF document does not
+    ///   start with the PDF header, or a CDA document has no `ClinicalDocument` id.
+    /// - `ParseError` if a value cannot be encoded.
+    pub fn to_dcmroot(&self, ctx: &DocumentContext) -> Result<DicomRoot, LoadError> {
+        let Some(doc_type) = self.doc_type else {
+            return Err(LoadError::InvalidEncapsulatedDocument(format!(
+                "unsupported MIME type: {}",
+                self.mime_type
+            )));
+        };
+        if doc_type == DocumentType::Pdf && !self.data.starts_with(b"%PDF-") {
+            return Err(LoadError::InvalidEncapsulatedDocument(
+                "document is not a PDF".to_owned(),
+            ));
+        }
+        let hl7_id = if doc_type == DocumentType::Cda {
+            let Some(id) = Self::cda_instance_identifier(&self.data) else {
+                return Err(LoadError::InvalidEncapsulatedDocument(
+                    "CDA document has no ClinicalDocument id".to_owned(),
+                ));
+            };
+            Some(id)
+        } else {
+            None
+        };
+
+        let (date, time) = now_utc();
+        let sop_class_uid = doc_type.sop_class().uid();
+        let sop_uid = generate_uid();
+        let (study_uid, study_date, study_time) = match &ctx.study_uid {
+            Some(uid) => (uid.clone(), ctx.study_date.clone(), ctx.study_time.clone()),
+            None => (generate_uid(), date.clone(), time.clone()),
+        };
+        let series_uid = ctx.series_uid.clone().unwrap_or_else(generate_uid);
+
+        let ts = &ExplicitVRLittleEndian;
+        let mut children: BTreeMap<u32, DicomObject> = BTreeMap::new();
+        let mut put = |tag: &Tag, val: RawValue| -> Result<(), LoadError> {
+            let mut elem = DicomElement::new_empty(tag, tag.implicit_vr().unwrap_or(&UN), ts);
+            elem.encode_val(val)?;
+            children.insert(elem.tag(), DicomObject::new(elem));
+            Ok(())
+        };
+        let string = |s: &str| RawValue::of_string(s);
+        let number = |n: Option<i32>| RawValue::of_string(n.unwrap_or(1).to_string());
+
+        // File Meta.
+        put(
+            &tags::FileMetaInformationVersion,
+            RawValue::Bytes(vec![0x00, 0x01]),
+        )?;
+        put(
+            &tags::MediaStorageSOPClassUID,
+            RawValue::of_uid(sop_class_uid),
+        )?;
+        put(
+            &tags::MediaStorageSOPInstanceUID,
+            RawValue::of_uid(&sop_uid),
+        )?;
+        put(
+            &tags::TransferSyntaxUID,
+            RawValue::of_uid(ExplicitVRLittleEndian.uid().uid()),
+        )?;
+        put(
+            &tags::ImplementationClassUID,
+            RawValue::of_uid(IMPLEMENTATION_CLASS_UID),
+        )?;
+        put(
+            &tags::ImplementationVersionName,
+            string(&format!("MEDICOM_{}", env!("CARGO_PKG_VERSION"))),
+        )?;
+
+        // SOP Common.
+        put(&tags::SpecificCharacterSet, string("ISO_IR 100"))?;
+        put(&tags::InstanceCreationDate, string(&date))?;
+        put(&tags::InstanceCreationTime, string(&time))?;
+        put(&tags::SOPClassUID, RawValue::of_uid(sop_class_uid))?;
+        put(&tags::SOPInstanceUID, RawValue::of_uid(&sop_uid))?;
+        put(&tags::TimezoneOffsetFromUTC, string("+0000"))?;
+
+        // Patient and General Study.
+        put(&tags::PatientsName, string(&ctx.patient_name))?;
+        put(&tags::PatientID, string(&ctx.patient_id))?;
+        put(&tags::PatientsBirthDate, string(&ctx.patient_birth_date))?;
+        put(&tags::PatientsSex, string(&ctx.patient_sex))?;
+        put(&tags::StudyInstanceUID, RawValue::of_uid(&study_uid))?;
+        put(&tags::StudyDate, string(&study_date))?;
+        put(&tags::StudyTime, string(&study_time))?;
+        put(&tags::StudyID, string(&ctx.study_id))?;
+        put(&tags::AccessionNumber, string(&ctx.accession_number))?;
+        put(
+            &tags::ReferringPhysiciansName,
+            string(&ctx.referring_physician),
+        )?;
+        if !ctx.study_description.is_empty() {
+            put(&tags::StudyDescription, string(&ctx.study_description))?;
+        }
+
+        // Encapsulated Document Series and the equipment.
+        put(&tags::Modality, string(doc_type.modality()))?;
+        put(&tags::SeriesInstanceUID, RawValue::of_uid(&series_uid))?;
+        put(&tags::SeriesNumber, number(ctx.series_number))?;
+        match doc_type {
+            DocumentType::Pdf | DocumentType::Cda => {
+                put(&tags::Manufacturer, string(&ctx.manufacturer))?;
+                // Workstation, as the document was not converted from another modality.
+                put(&tags::ConversionType, string("WSD"))?;
+            }
+            DocumentType::Stl | DocumentType::Obj => {
+                // The Enhanced General Equipment attributes are all required.
+                let manufacturer = Some(ctx.manufacturer.as_str())
+                    .filter(|m| !m.is_empty())
+                    .unwrap_or("medicom");
+                put(&tags::Manufacturer, string(manufacturer))?;
+                put(&tags::ManufacturersModelName, string("medicom"))?;
+                put(&tags::DeviceSerialNumber, string("0"))?;
+                put(&tags::SoftwareVersions, string(env!("CARGO_PKG_VERSION")))?;
+                put(&tags::FrameofReferenceUID, RawValue::of_uid(generate_uid()))?;
+                put(&tags::PositionReferenceIndicator, string(""))?;
+            }
+        }
+
+        // Encapsulated Document.
+        put(&tags::InstanceNumber, number(ctx.instance_number))?;
+        put(&tags::ContentDate, string(&date))?;
+        put(&tags::ContentTime, string(&time))?;
+        put(&tags::AcquisitionDateTime, string(&format!("{date}{time}")))?;
+        if doc_type == DocumentType::Pdf {
+            // The text of a PDF may identify the patient.
+            put(&tags::BurnedInAnnotation, string("YES"))?;
+        }
+        put(&tags::DocumentTitle, string(&self.title))?;
+        if let Some(hl7_id) = &hl7_id {
+            put(&tags::HL7InstanceIdentifier, string(hl7_id))?;
+        }
+        put(
+            &tags::MIMETypeofEncapsulatedDocument,
+            string(doc_type.mime_type()),
+        )?;
+        put(
+            &tags::EncapsulatedDocument,
+            RawValue::Bytes(self.data.clone()),
+        )?;
+        put(
+            &tags::EncapsulatedDocumentLength,
+            RawValue::of_uint(u32::try_from(self.data.len()).map_err(|_| {
+                LoadError::InvalidEncapsulatedDocument("document is too large".to_owned())
+            })?),
+        )?;
+        let concept_sq = DicomElement::new_empty(&tags::ConceptNameCodeSequence, &SQ, ts);
+        children.insert(concept_sq.tag(), DicomObject::new(concept_sq));
+        if matches!(doc_type, DocumentType::Stl | DocumentType::Obj) {
+            let millimeter = Code {
+                value: "mm".to_owned(),
+                scheme: "UCUM".to_owned(),
+                meaning: "mm".to_owned(),
+            };
+            let units = millimeter.to_sequence(&tags::MeasurementUnitsCodeSequence, ts)?;
+            children.insert(units.element().tag(), units);
+        }
+
+        Ok(DicomRoot::new(ts, CSRef::default(), children, Vec::new()))
+    }
+
+    /// The HL7 Instance Identifier of a CDA document, the `root` and `extension` of the `id` of
+    /// its `ClinicalDocument`.
+    fn cda_instance_identifier(data: &[u8]) -> Option<String> {
+        let text = String::from_utf8_lossy(data);
+        let doc_start = text.find("ClinicalDocument")?;
+        let id_start = doc_start + text[doc_start..].find("<id ")?;
+        let id_end = id_start + text[id_start..].find('>')?;
+        let id = &text[id_start..id_end];
+        let attr = |name: &str| {
+            let key = format!(" {name}=\"");
+            let start = id.find(&key)? + key.len();
+            let len = id[start..].find('"')?;
+            Some(id[start..start + len].to_owned())
+        };
+        let root = attr("root")?;
+        Some(match attr("extension") {
+            Some(extension) => format!("{root}^{extension}"),
+            None => root,
+        })
+    }
+}
+
+/// The UUID-derived UID (PS3.5 Annex B.2) of a UUID, its 128-bit value in decimal under `2.25`.
+fn uuid_to_uid(uuid: Uuid) -> String {
+    format!("2.25.{}", uuid.as_u128())
+}
+
+/// Generates a new UID derived from a random (version 4) UUID.
+fn generate_uid() -> String {
+    uuid_to_uid(Uuid::new_v4())
+}
+
+/// The current date and time in UTC, formatted as DA and TM values.
+fn now_utc() -> (String, String) {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let (year, month, day) = civil_date(secs / 86_400);
+    let secs = secs % 86_400;
+    (
+        format!("{year:04}{month:02}{day:02}"),
+        format!("{:02}{:02}{:02}", secs / 3600, secs / 60 % 60, secs % 60),
+    )
+}
+
+/// The year, month and day of the given number of days since 1970-01-01, in the proleptic
+/// Gregorian calendar.
+fn civil_date(days: u64) -> (u64, u64, u64) {
+    // Shift the epoch to 0000-03-01, so that leap days are at the end of each year, and count in
+    // eras of 400 years.
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = era * 400 + year_of_era + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        core::{dcmobject::DicomRoot, read::ParserBuilder, write::builder::WriterBuilder},
+        dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+        load::{
+            encapdoc::{
+                civil_date, generate_uid, uuid_to_uid, DocumentContext, DocumentType,
+                EncapsulatedDocument, IMPLEMENTATION_CLASS_UID,
+            },
+            Code,
+        },
+    };
+
+    fn roundtrip(dcmroot: &DicomRoot) -> DicomRoot {
+        let mut writer = WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+        writer.write_dcmroot(dcmroot).unwrap();
+        let bytes = writer.into_dataset();
+        let mut parser =
+            ParserBuilder::default().build(bytes.as_slice(), &STANDARD_DICOM_DICTIONARY);
+        DicomRoot::parse(&mut parser).unwrap().unwrap()
+    }
+
+    #[test]
+    pub fn test_uuid_uids() {
+        let uuid = Uuid::parse_str("8513a7b5-8892-451d-b1ff-46d13a3ac41a").unwrap();
+        assert_eq!(IMPLEMENTATION_CLASS_UID, uuid_to_uid(uuid));
+
+        let uid = generate_uid();
+        assert_ne!(uid, generate_uid());
+        let uuid = Uuid::from_u128(uid.strip_prefix("2.25.").unwrap().parse().unwrap());
+        assert_eq!(Some(uuid::Version::Random), uuid.get_version());
+        // UIDs are limited to 64 characters.
+        assert!(uid.len() <= 64);
+    }
+
+    #[test]
+    pub fn test_civil_date() {
+        assert_eq!((1970, 1, 1), civil_date(0));
+        assert_eq!((2000, 2, 29), civil_date(11_016));
+        assert_eq!((2000, 3, 1), civil_date(11_017));
+        assert_eq!((2024, 12, 31), civil_date(20_088));
+    }
+
+    #[test]
+    pub fn test_pdf_roundtrip() {
+        // An odd length, so the document is padded when encoded.
+        let pdf = b"%PDF-1.4\n%%EOF\n".to_vec();
+        let ctx = DocumentContext {
+            patient_name: "Doe^Jane".to_owned(),
+            patient_id: "12345".to_owned(),
+            study_uid: Some("1.2.3.4".to_owned()),
+            study_date: "20240102".to_owned(),
+            ..DocumentContext::default()
+        };
+        let dcmroot = EncapsulatedDocument::new(DocumentType::Pdf, pdf.clone())
+            .with_title("Report")
+            .to_dcmroot(&ctx)
+            .unwrap();
+        let dcmroot = roundtrip(&dcmroot);
+
+        let string = |tag| {
+            dcmroot
+                .get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .unwrap_or_default()
+        };
+        assert_eq!("1.2.840.10008.5.1.4.1.1.104.1", string(&tags::SOPClassUID));
+        assert_eq!(
+            string(&tags::SOPInstanceUID),
+            string(&tags::MediaStorageSOPInstanceUID)
+        );
+        assert!(string(&tags::SOPInstanceUID).starts_with("2.25."));
+        assert_eq!("1.2.3.4", string(&tags::StudyInstanceUID));
+        assert_eq!("20240102", string(&tags::StudyDate));
+        assert_eq!("Doe^Jane", string(&tags::PatientsName));
+        assert_eq!("DOC", string(&tags::Modality));
+        assert_eq!("YES", string(&tags::BurnedInAnnotation));
+        assert_eq!(
+            pdf.len() + 1,
+            dcmroot
+                .get_child_by_tag(&tags::EncapsulatedDocument)
+                .unwrap()
+                .element()
+                .data()
+                .len()
+        );
+
+        let doc = EncapsulatedDocument::from_dcmroot(&dcmroot).unwrap();
+        assert_eq!(Some(DocumentType::Pdf), doc.doc_type());
+        assert_eq!("application/pdf", doc.mime_type());
+        assert_eq!("Report", doc.title());
+        assert_eq!(pdf, doc.data());
+
+        let copied = DocumentContext::from_dcmroot(&dcmroot);
+        assert_eq!("12345", copied.patient_id);
+        assert_eq!(Some("1.2.3.4".to_owned()), copied.study_uid);
+
+        let not_pdf = EncapsulatedDocument::new(DocumentType::Pdf, b"text".to_vec());
+        assert!(not_pdf.to_dcmroot(&ctx).is_err());
+    }
+
+    #[test]
+    pub fn test_stl_and_cda() {
+        // A binary STL of one triangle, ending with a zero attribute byte count.
+        let mut stl = vec![0u8; 80];
+        stl.extend_from_slice(&1u32.to_le_bytes());
+        stl.extend_from_slice(&[0u8; 50]);
+        let dcmroot = EncapsulatedDocument::new(DocumentType::Stl, stl.clone())
+            .to_dcmroot(&DocumentContext::default())
+            .unwrap();
+        let mut dcmroot = roundtrip(&dcmroot);
+        let units = dcmroot
+            .get_child_by_tag(&tags::MeasurementUnitsCodeSequence)
+            .and_then(|sq| sq.get_item_by_index(1))
+            .and_then(Code::from_item)
+            .unwrap();
+        assert_eq!("mm", units.value);
+        assert_eq!("UCUM", units.scheme);
+        assert!(dcmroot
+            .get_value_by_tag(&tags::FrameofReferenceUID)
+            .is_some());
+
+        // Without the Encapsulated Document Length, the trailing zero of a binary STL is kept.
+        dcmroot.remove_child_by_tag(&tags::EncapsulatedDocumentLength);
+        let doc = EncapsulatedDocument::from_dcmroot(&dcmroot).unwrap();
+        assert_eq!(Some(DocumentType::Stl), doc.doc_type());
+        assert_eq!(stl, doc.data());
+
+        let cda = br#"<?xml version="1.0"?>
+<ClinicalDocument xmlns="urn:hl7-org:v3">
+  <typeId root="2.16.840.1.113883.1.3" extension="POCD_HD000040"/>
+  <id root="2.16.840.1.113883.19.4" extension="c266"/>
+</ClinicalDocument>"#
+            .to_vec();
+        let dcmroot = EncapsulatedDocument::new(DocumentType::Cda, cda.clone())
+            .to_dcmroot(&DocumentContext::default())
+            .unwrap();
+        let mut dcmroot = roundtrip(&dcmroot);
+        assert_eq!(
+            Some("2.16.840.1.113883.19.4^c266"),
+            dcmroot
+                .get_value_by_tag(&tags::HL7InstanceIdentifier)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .as_deref()
+        );
+        // The CDA has an odd length, so its padding is removed without the Encapsulated Document
+        // Length.
+        dcmroot.remove_child_by_tag(&tags::EncapsulatedDocumentLength);
+        let doc = EncapsulatedDocument::from_dcmroot(&dcmroot).unwrap();
+        assert_eq!(Some(DocumentType::Cda), doc.doc_type());
+        assert_eq!(cda, doc.data());
+    }
+}
@@ -0,0 +1,372 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The geometry of loaded volumes within the patient coordinate system, Part 3 Section C.7.6.2.1.1,
+//! where x increases to the patient's left, y to the posterior, and z to the head (LPS).
+
+use crate::load::{DicomVec, IndexVec, EPSILON_F32};
+
+/// The angle in degrees between the slice stacking direction and the image plane normal beyond
+/// which the volume is considered to be acquired with a tilted gantry.
+const TILT_TOLERANCE_DEG: f32 = 0.1;
+
+/// The fraction of the nominal spacing by which the spacing between two slices may differ before
+/// the volume is considered to have non-uniform spacing.
+const SPACING_TOLERANCE: f32 = 0.01;
+
+/// An affine transform of 3D points, such as from voxel indices to patient coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine {
+    /// The row-major 4x4 matrix applied to homogeneous coordinates. The last row is `[0, 0, 0, 1]`.
+    matrix: [[f32; 4]; 4],
+}
+
+impl Affine {
+    /// Creates the transform which maps a point (x, y, z) to `origin + x * x_step + y * y_step +
+    /// z * z_step`.
+    #[must_use]
+    pub fn from_columns(
+        x_step: DicomVec,
+        y_step: DicomVec,
+        z_step: DicomVec,
+        origin: DicomVec,
+    ) -> Self {
+        Self {
+            matrix: [
+                [x_step.x, y_step.x, z_step.x, origin.x],
+                [x_step.y, y_step.y, z_step.y, origin.y],
+                [x_step.z, y_step.z, z_step.z, origin.z],
+                [0_f32, 0_f32, 0_f32, 1_f32],
+            ],
+        }
+    }
+
+    /// The row-major 4x4 matrix of this transform.
+    #[must_use]
+    pub fn matrix(&self) -> &[[f32; 4]; 4] {
+        &self.matrix
+    }
+
+    /// The columns of the matrix: the steps for a unit increase in x, y and z, and the translation.
+    #[must_use]
+    pub fn columns(&self) -> [DicomVec; 4] {
+        let m = &self.matrix;
+        [0, 1, 2, 3].map(|c| DicomVec::new(m[0][c], m[1][c], m[2][c]))
+    }
+
+    /// Transforms the given point.
+    #[must_use]
+    pub fn apply(&self, point: DicomVec) -> DicomVec {
+        let row = |r: &[f32; 4]| r[0] * point.x + r[1] * point.y + r[2] * point.z + r[3];
+        DicomVec::new(
+            row(&self.matrix[0]),
+            row(&self.matrix[1]),
+            row(&self.matrix[2]),
+        )
+    }
+
+    /// Transforms the given voxel index.
+    #[must_use]
+    pub fn apply_index(&self, index: IndexVec) -> DicomVec {
+        #[allow(clippy::cast_precision_loss)]
+        self.apply(DicomVec::new(
+            index.x as f32,
+            index.y as f32,
+            index.z as f32,
+        ))
+    }
+
+    /// The inverse transform, or `None` if this transform is not invertible, such as when the
+    /// slices of a volume have no spacing.
+    #[must_use]
+    pub fn inverse(&self) -> Option<Affine> {
+        let m = self.matrix.map(|row| row.map(f64::from));
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        // The adjugate of the upper 3x3 matrix, transposed cofactors.
+        let adj = [
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ];
+        let det = m[0][0] * adj[0][0] + m[0][1] * adj[1][0] + m[0][2] * adj[2][0];
+        if det.abs() < f64::from(f32::EPSILON) {
+            return None;
+        }
+
+        let mut matrix = [[0_f32; 4]; 4];
+        matrix[3][3] = 1_f32;
+        for (r, row) in adj.iter().enumerate() {
+            let inv = row.map(|v| v / det);
+            let translate = -(inv[0] * m[0][3] + inv[1] * m[1][3] + inv[2] * m[2][3]);
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                matrix[r] = [
+                    inv[0] as f32,
+                    inv[1] as f32,
+                    inv[2] as f32,
+                    translate as f32,
+                ];
+            }
+        }
+        Some(Affine { matrix })
+    }
+}
+
+/// A run of slices missing from a volume, detected by a gap between two slices which is a multiple
+/// of the nominal spacing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SliceGap {
+    /// The index of the slice preceding the gap.
+    pub after: usize,
+    /// The number of slices which would fill the gap.
+    pub missing: usize,
+}
+
+/// The regularity of the positions of the slices of a volume: gantry tilt, missing slices, and
+/// non-uniform spacing between slices.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SliceGeometry {
+    spacings: Vec<f32>,
+    spacing: f32,
+    gantry_tilt: f32,
+    gaps: Vec<SliceGap>,
+}
+
+impl SliceGeometry {
+    /// Determines the geometry of slices at the given Image Position (Patient), in their order
+    /// within the volume, whose image planes have the given unit normal.
+    #[must_use]
+    pub fn new(positions: &[DicomVec], normal: DicomVec) -> Self {
+        let spacings = positions
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).dot(&normal).abs())
+            .collect::<Vec<f32>>();
+
+        // The median is the nominal spacing, so that a few gaps do not skew it.
+        let mut sorted = spacings.clone();
+        sorted.sort_by(f32::total_cmp);
+        let spacing = sorted.get(sorted.len() / 2).copied().unwrap_or_default();
+
+        let gantry_tilt = match (positions.first(), positions.last()) {
+            (Some(first), Some(last)) => (*last - *first).normalized().map_or(0_f32, |dir| {
+                dir.dot(&normal).abs().min(1_f32).acos().to_degrees()
+            }),
+            _ => 0_f32,
+        };
+
+        let mut geometry = Self {
+            spacings,
+            spacing,
+            gantry_tilt,
+            gaps: Vec::new(),
+        };
+        if spacing > EPSILON_F32 {
+            let tolerance = geometry.tolerance();
+            geometry.gaps = geometry
+                .spacings
+                .iter()
+                .enumerate()
+                .filter_map(|(after, s)| {
+                    let steps = (s / spacing).round();
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let missing = (steps as usize).saturating_sub(1);
+                    (missing > 0 && (s - steps * spacing).abs() <= tolerance * steps)
+                        .then_some(SliceGap { after, missing })
+                })
+                .collect();
+        }
+        geometry
+    }
+
+    /// The distance along the normal between each slice and the next.
+    #[must_use]
+    pub fn spacings(&self) -> &[f32] {
+        &self.spacings
+    }
+
+    /// The nominal spacing between slices, the median of `spacings`.
+    #[must_use]
+    pub fn spacing(&self) -> f32 {
+        self.spacing
+    }
+
+    /// The angle in degrees between the direction the slices are stacked in and the normal of the
+    /// image plane, which is non-zero for acquisitions with a tilted gantry.
+    #[must_use]
+    pub fn gantry_tilt(&self) -> f32 {
+        self.gantry_tilt
+    }
+
+    #[must_use]
+    pub fn is_tilted(&self) -> bool {
+        self.gantry_tilt > TILT_TOLERANCE_DEG
+    }
+
+    /// The gaps in the volume which are a multiple of the nominal spacing.
+    #[must_use]
+    pub fn gaps(&self) -> &[SliceGap] {
+        &self.gaps
+    }
+
+    /// The total number of slices missing from the gaps in the volume.
+    #[must_use]
+    pub fn missing_slices(&self) -> usize {
+        self.gaps.iter().map(|gap| gap.missing).sum()
+    }
+
+    /// Whether the spacing between every pair of slices is the nominal spacing. Volumes with
+    /// missing slices do not have uniform spacing.
+    #[must_use]
+    pub fn is_uniform(&self) -> bool {
+        let tolerance = self.tolerance();
+        self.spacings
+            .iter()
+            .all(|s| (s - self.spacing).abs() <= tolerance)
+    }
+
+    /// Whether the slices are untilted and uniformly spaced, such that the volume is a regular
+    /// grid of voxels.
+    #[must_use]
+    pub fn is_regular(&self) -> bool {
+        !self.is_tilted() && self.is_uniform()
+    }
+
+    fn tolerance(&self) -> f32 {
+        EPSILON_F32.max(self.spacing * SPACING_TOLERANCE)
+    }
+}
+
+impl std::fmt::Display for SliceGeometry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "spacing {:.2}mm", self.spacing)?;
+        if self.is_tilted() {
+            write!(f, ", gantry tilt {:.2} degrees", self.gantry_tilt)?;
+        }
+        for gap in &self.gaps {
+            write!(
+                f,
+                ", {} slice(s) missing after slice {}",
+                gap.missing,
+                gap.after + 1
+            )?;
+        }
+        if !self.is_uniform() {
+            let min = self.spacings.iter().copied().fold(f32::MAX, f32::min);
+            let max = self.spacings.iter().copied().fold(f32::MIN, f32::max);
+            write!(f, ", non-uniform spacing {min:.2}mm to {max:.2}mm")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::load::{
+        geometry::{Affine, SliceGap, SliceGeometry},
+        DicomVec, IndexVec,
+    };
+
+    fn assert_close(expected: DicomVec, actual: DicomVec) {
+        assert!(
+            (expected - actual).length() < 1e-4,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    pub fn test_affine_inverse() {
+        // An oblique plane rotated 30 degrees about the z-axis, with slices stepping down z.
+        let (sin, cos) = 30_f32.to_radians().sin_cos();
+        let affine = Affine::from_columns(
+            DicomVec::new(cos, sin, 0.0) * 0.5,
+            DicomVec::new(-sin, cos, 0.0) * 0.5,
+            DicomVec::new(0.0, 0.0, -2.0),
+            DicomVec::new(-100.0, 50.0, 20.0),
+        );
+        let pos = affine.apply_index(IndexVec { x: 2, y: 4, z: 3 });
+        assert_close(
+            DicomVec::new(-100.0 + cos - 2.0 * sin, 50.0 + sin + 2.0 * cos, 14.0),
+            pos,
+        );
+
+        let inverse = affine.inverse().unwrap();
+        assert_close(DicomVec::new(2.0, 4.0, 3.0), inverse.apply(pos));
+
+        let flat = Affine::from_columns(
+            DicomVec::new(1.0, 0.0, 0.0),
+            DicomVec::new(0.0, 1.0, 0.0),
+            DicomVec::default(),
+            DicomVec::default(),
+        );
+        assert!(flat.inverse().is_none());
+    }
+
+    #[test]
+    pub fn test_slice_geometry() {
+        let normal = DicomVec::new(0.0, 0.0, 1.0);
+        let at = |z: f32| DicomVec::new(0.0, 0.0, z);
+        let regular = SliceGeometry::new(&[at(10.0), at(8.0), at(6.0), at(4.0)], normal);
+        assert!(regular.is_regular());
+        assert!((regular.spacing() - 2.0).abs() < f32::EPSILON);
+        assert_eq!(0, regular.missing_slices());
+
+        let missing = SliceGeometry::new(&[at(10.0), at(8.0), at(2.0), at(0.0)], normal);
+        assert_eq!(
+            &[SliceGap {
+                after: 1,
+                missing: 2
+            }],
+            missing.gaps()
+        );
+        assert!(!missing.is_uniform());
+        assert_eq!(
+            "spacing 2.00mm, 2 slice(s) missing after slice 2, non-uniform spacing 2.00mm to 6.00mm",
+            missing.to_string()
+        );
+
+        let uneven = SliceGeometry::new(&[at(10.0), at(8.0), at(5.0), at(3.0)], normal);
+        assert!(uneven.gaps().is_empty());
+        assert!(!uneven.is_uniform());
+
+        // Each slice is shifted along y as well as z, as with a 10 degree gantry tilt.
+        let tan = 10_f32.to_radians().tan();
+        let tilted = SliceGeometry::new(
+            &[
+                DicomVec::new(0.0, 0.0, 0.0),
+                DicomVec::new(0.0, tan, 1.0),
+                DicomVec::new(0.0, 2.0 * tan, 2.0),
+            ],
+            normal,
+        );
+        assert!(tilted.is_tilted());
+        assert!((tilted.gantry_tilt() - 10.0).abs() < 1e-3);
+        assert!((tilted.spacing() - 1.0).abs() < f32::EPSILON);
+    }
+}
@@ -16,41 +16,60 @@
 
 //! Loaded DICOM image volume datasets.
 
-use std::cmp::Ordering;
+use std::{cmp::Ordering, ops::Range};
 
 use crate::{
-    core::{dcmobject::DicomRoot, values::RawValue},
+    core::{codec::registry::CodecRegistry, dcmobject::DicomRoot, values::RawValue},
     dict::tags,
     load::{
+        geometry::{Affine, SliceGeometry},
         pixeldata::{
-            pdinfo::PixelDataSliceInfo, pixel_i16::PixelDataSliceI16, pixel_i32::PixelDataSliceI32,
-            pixel_u16::PixelDataSliceU16, pixel_u32::PixelDataSliceU32, pixel_u8::PixelDataSliceU8,
-            winlevel::WindowLevel, BitsAlloc, LoadError, PhotoInterp,
+            display::{GrayscalePipeline, ModalityTransform, VoiTransform},
+            lut::Lut,
+            overlay::Overlay,
+            pdinfo::PixelDataSliceInfo,
+            pixel_f32::PixelDataSliceF32,
+            pixel_f64::PixelDataSliceF64,
+            pixel_i16::PixelDataSliceI16,
+            pixel_i32::PixelDataSliceI32,
+            pixel_u16::PixelDataSliceU16,
+            pixel_u32::PixelDataSliceU32,
+            pixel_u8::PixelDataSliceU8,
+            winlevel::WindowLevel,
+            BitsAlloc, LoadError, PhotoInterp, SliceBuffer,
         },
-        IndexVec, VolAxis, VolDims, VolPixel, EPSILON_F32,
+        projection::ProjectionMode,
+        resample::{Interpolation, ObliquePlane, ResampledVolume},
+        stats::{Histogram, Region, Statistics},
+        DicomVec, IndexVec, VolAxis, VolDims, VolPixel, EPSILON_F32,
     },
 };
 
-/// Slices loaded into memory. Pixel values are `i16`.
+/// Slices loaded into memory. Pixel values are kept in the sample type of each slice's
+/// `PixelData`, see `SliceBuffer`.
 pub struct ImageVolume {
-    slices: Vec<Vec<i16>>,
+    slices: Vec<SliceBuffer>,
     infos: Vec<PixelDataSliceInfo>,
 
     patient_name: String,
     patient_id: String,
     series_uid: String,
     series_desc: String,
+    frame_of_ref_uid: Option<String>,
 
     dims: VolDims,
     stride: usize,
     is_rgb: bool,
-    pixel_pad: Option<i16>,
+    pixel_pad: Option<f32>,
     slope: f32,
     intercept: f32,
+    modality_lut: Option<Lut>,
     samples_per_pixel: usize,
     photo_interp: PhotoInterp,
-    min_val: i16,
-    max_val: i16,
+    min_val: f32,
+    max_val: f32,
+
+    codecs: CodecRegistry,
 }
 
 impl Default for ImageVolume {
@@ -63,6 +82,7 @@ impl Default for ImageVolume {
             patient_id: String::new(),
             series_uid: String::new(),
             series_desc: String::new(),
+            frame_of_ref_uid: None,
 
             dims: VolDims::default(),
             stride: 0usize,
@@ -70,17 +90,40 @@ impl Default for ImageVolume {
             pixel_pad: None,
             slope: 1_f32,
             intercept: 0_f32,
+            modality_lut: None,
             samples_per_pixel: 0usize,
             photo_interp: PhotoInterp::Unsupported("Unspecified".to_owned()),
-            min_val: i16::MAX,
-            max_val: i16::MIN,
+            min_val: f32::MAX,
+            max_val: f32::MIN,
+
+            codecs: CodecRegistry::default(),
         }
     }
 }
 
 impl ImageVolume {
+    /// Creates an empty volume which decodes encapsulated `PixelData` using the given codecs.
+    #[must_use]
+    pub fn with_codecs(codecs: CodecRegistry) -> Self {
+        Self {
+            codecs,
+            ..Self::default()
+        }
+    }
+
+    /// The codecs used to decode encapsulated `PixelData` of slices loaded into this volume.
     #[must_use]
-    pub fn slices(&self) -> &Vec<Vec<i16>> {
+    pub fn codecs(&self) -> &CodecRegistry {
+        &self.codecs
+    }
+
+    /// The codecs used to decode encapsulated `PixelData`, for registering additional codecs.
+    pub fn codecs_mut(&mut self) -> &mut CodecRegistry {
+        &mut self.codecs
+    }
+
+    #[must_use]
+    pub fn slices(&self) -> &Vec<SliceBuffer> {
         &self.slices
     }
 
@@ -109,6 +152,12 @@ impl ImageVolume {
         &self.series_desc
     }
 
+    /// The Frame of Reference UID which the slices are positioned in.
+    #[must_use]
+    pub fn frame_of_ref_uid(&self) -> Option<&str> {
+        self.frame_of_ref_uid.as_deref()
+    }
+
     #[must_use]
     pub fn dims(&self) -> &VolDims {
         &self.dims
@@ -125,7 +174,7 @@ impl ImageVolume {
     }
 
     #[must_use]
-    pub fn pixel_pad(&self) -> Option<i16> {
+    pub fn pixel_pad(&self) -> Option<f32> {
         self.pixel_pad
     }
 
@@ -145,23 +194,57 @@ impl ImageVolume {
     }
 
     #[must_use]
-    pub fn min_val(&self) -> i16 {
+    pub fn min_val(&self) -> f32 {
         self.min_val
     }
 
     #[must_use]
-    pub fn max_val(&self) -> i16 {
+    pub fn max_val(&self) -> f32 {
         self.max_val
     }
 
+    /// The Modality LUT of the slices, which is used in place of the slope and intercept.
     #[must_use]
+    pub fn modality_lut(&self) -> Option<&Lut> {
+        self.modality_lut.as_ref()
+    }
+
+    /// The transform from stored values to modality values, the Modality LUT if present and
+    /// otherwise the slope and intercept.
+    #[must_use]
+    pub fn modality_transform(&self) -> ModalityTransform {
+        if let Some(lut) = &self.modality_lut {
+            ModalityTransform::Lut(lut.clone())
+        } else {
+            ModalityTransform::Rescale {
+                slope: self.slope,
+                intercept: self.intercept,
+            }
+        }
+    }
+
+    /// Converts a stored value into a modality value.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
     pub fn rescale(&self, val: f32) -> f32 {
-        val * self.slope + self.intercept
+        self.rescale_f64(f64::from(val)) as f32
+    }
+
+    /// Converts a stored value into a modality value in `f64`, which represents every stored value
+    /// of 32-bit samples exactly.
+    #[must_use]
+    pub fn rescale_f64(&self, val: f64) -> f64 {
+        if let Some(lut) = &self.modality_lut {
+            #[allow(clippy::cast_possible_truncation)]
+            f64::from(lut.lookup(val.round() as i64))
+        } else {
+            val * f64::from(self.slope) + f64::from(self.intercept)
+        }
     }
 
     #[must_use]
     pub fn byte_size(&self) -> usize {
-        self.slices().iter().flatten().count() * std::mem::size_of::<i16>()
+        self.slices().iter().map(SliceBuffer::byte_size).sum()
     }
 
     /// Returns the dimensions ordered by (width, height, depth) oriented to the given axis.
@@ -198,27 +281,496 @@ impl ImageVolume {
     /// compute the center and width. The out range is `f32::MIN` to `f32::MAX`.
     #[must_use]
     pub fn minmax_winlevel(&self) -> WindowLevel {
-        let min = self.min_val();
-        let max = self.max_val();
+        let min = self.rescale(self.min_val());
+        let max = self.rescale(self.max_val());
+        let (min, max) = (min.min(max), min.max(max));
         let width = max - min;
-        let center = min + width / 2;
-        WindowLevel::new(
-            String::new(),
-            self.rescale(f32::from(center)),
-            self.rescale(f32::from(width)),
-            f32::MIN,
-            f32::MAX,
+        let center = min + width / 2_f32;
+        WindowLevel::new(String::new(), center, width, f32::MIN, f32::MAX)
+    }
+
+    /// Creates the grayscale standard display pipeline for the pixel values returned by
+    /// `get_pixel`, which have already had the modality transform applied. The VOI transform is
+    /// the first slice's first VOI LUT, otherwise its first window, otherwise `minmax_winlevel`.
+    /// The output is inverted per the Presentation LUT Shape, or for `MONOCHROME1`.
+    #[must_use]
+    pub fn grayscale_pipeline(&self) -> GrayscalePipeline {
+        let first = self.infos.first();
+        let voi = first
+            .and_then(|info| info.voi_luts().first())
+            .map(|lut| VoiTransform::Lut(lut.clone()))
+            .or_else(|| {
+                first
+                    .and_then(|info| info.win_levels().first())
+                    .filter(|winlevel| winlevel.width() > 0_f32)
+                    .map(|winlevel| VoiTransform::Window(winlevel.clone()))
+            })
+            .unwrap_or_else(|| VoiTransform::Window(self.minmax_winlevel()));
+        let inverse = first.is_some_and(PixelDataSliceInfo::is_inverse);
+        GrayscalePipeline::new(self.modality_transform(), voi, inverse)
+    }
+
+    /// The affine transform from voxel indices (x, y, z) to patient coordinates (LPS) in mm. The
+    /// step between slices is the average of the loaded slices' positions, which accounts for
+    /// gantry tilt. With a single slice the step is the slice spacing, opposite the normal.
+    #[must_use]
+    pub fn voxel_to_patient(&self) -> Affine {
+        let voxel_dims = self.dims.voxel_dims();
+        let first = self.infos.first().map(Self::image_pos).unwrap_or_default();
+        let z_step = match self.infos.last().filter(|_| self.infos.len() > 1) {
+            #[allow(clippy::cast_precision_loss)]
+            Some(last) => (Self::image_pos(last) - first) * (1_f32 / (self.infos.len() - 1) as f32),
+            None => self.dims.normal() * -voxel_dims.z,
+        };
+        Affine::from_columns(
+            self.dims.row_dir() * voxel_dims.x,
+            self.dims.col_dir() * voxel_dims.y,
+            z_step,
+            first,
         )
     }
 
-    /// Loads a slice into this volume.
+    /// The affine transform from patient coordinates (LPS) in mm to voxel indices (x, y, z), or
+    /// `None` if the voxels have no size.
+    #[must_use]
+    pub fn patient_to_voxel(&self) -> Option<Affine> {
+        self.voxel_to_patient().inverse()
+    }
+
+    /// The regularity of the positions of the loaded slices, for detecting gantry tilt, missing
+    /// slices and non-uniform spacing.
+    #[must_use]
+    pub fn slice_geometry(&self) -> SliceGeometry {
+        let positions = self
+            .infos
+            .iter()
+            .map(Self::image_pos)
+            .collect::<Vec<DicomVec>>();
+        SliceGeometry::new(&positions, self.dims.normal())
+    }
+
+    /// Samples the volume at a continuous voxel index (x, y, z) using the given interpolation.
+    /// Returns the red, green and blue values, which are equal for grayscale volumes, or `None` if
+    /// the position is outside the volume.
+    #[must_use]
+    pub fn sample(&self, index: DicomVec, interp: Interpolation) -> Option<[f32; 3]> {
+        interp.sample(&self.dims.counts, index, |coord| {
+            self.get_pixel(coord).ok().map(|p| [p.r, p.g, p.b])
+        })
+    }
+
+    /// Samples the volume at a position in patient coordinates (LPS) in mm. See `sample`.
+    #[must_use]
+    pub fn sample_patient(&self, pos: DicomVec, interp: Interpolation) -> Option<[f32; 3]> {
+        let index = self.patient_to_voxel()?.apply(pos);
+        self.sample(index, interp)
+    }
+
+    /// Resamples the volume onto the grid of voxels with the given counts, positioned in patient
+    /// space by the given transform. Voxels outside this volume are the minimum value.
+    ///
+    /// # Errors
+    /// - `InvalidDims` if this volume has no extent so positions cannot be mapped into it.
+    pub fn resample_to(
+        &self,
+        voxel_to_patient: Affine,
+        counts: IndexVec,
+        interp: Interpolation,
+    ) -> Result<ResampledVolume, LoadError> {
+        let Some(patient_to_voxel) = self.patient_to_voxel() else {
+            return Err(LoadError::InvalidDims(format!(
+                "Unable to resample volume without extent: {}",
+                self.dims
+            )));
+        };
+        let background = self.background();
+        let samples_per_voxel = if self.is_rgb { 3 } else { 1 };
+        let mut samples = Vec::with_capacity(counts.x * counts.y * counts.z * samples_per_voxel);
+        for z in 0..counts.z {
+            for y in 0..counts.y {
+                for x in 0..counts.x {
+                    let pos = voxel_to_patient.apply_index(IndexVec { x, y, z });
+                    let vals = self
+                        .sample(patient_to_voxel.apply(pos), interp)
+                        .unwrap_or(background);
+                    samples.extend_from_slice(&vals[..samples_per_voxel]);
+                }
+            }
+        }
+        Ok(ResampledVolume::new(
+            counts,
+            voxel_to_patient,
+            self.is_rgb,
+            samples,
+        ))
+    }
+
+    /// Resamples the volume onto a grid with the given spacing in mm, in the orientation of the
+    /// image plane and covering the same extent. Slices of the grid are perpendicular to the plane
+    /// normal, which corrects for gantry tilt.
+    ///
+    /// # Errors
+    /// - `InvalidDims` if the spacing is not positive or this volume has no extent.
+    pub fn resample(
+        &self,
+        spacing: DicomVec,
+        interp: Interpolation,
+    ) -> Result<ResampledVolume, LoadError> {
+        if ![spacing.x, spacing.y, spacing.z]
+            .into_iter()
+            .all(VolDims::is_valid_dim)
+        {
+            return Err(LoadError::InvalidDims(format!(
+                "Invalid resampling spacing: {spacing:?}"
+            )));
+        }
+        let [x_step, y_step, z_step, origin] = self.voxel_to_patient().columns();
+        let normal = self.dims.normal();
+        let z_dir = if z_step.dot(&normal) > 0_f32 {
+            normal
+        } else {
+            normal * -1_f32
+        };
+        let counts = self.dims.counts;
+        #[allow(clippy::cast_precision_loss)]
+        let extent = |step: f32, count: usize| step * count.saturating_sub(1) as f32;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let count = |extent: f32, spacing: f32| (extent / spacing + 1e-3).floor() as usize + 1;
+        let grid = IndexVec {
+            x: count(extent(x_step.length(), counts.x), spacing.x),
+            y: count(extent(y_step.length(), counts.y), spacing.y),
+            z: count(extent(z_step.dot(&normal).abs(), counts.z), spacing.z),
+        };
+        let affine = Affine::from_columns(
+            self.dims.row_dir() * spacing.x,
+            self.dims.col_dir() * spacing.y,
+            z_dir * spacing.z,
+            origin,
+        );
+        self.resample_to(affine, grid, interp)
+    }
+
+    /// Resamples the volume onto a grid of cubic voxels, whose size is the smallest of the pixel
+    /// spacing and the spacing between slices. See `resample`.
+    ///
+    /// # Errors
+    /// - `InvalidDims` if this volume has no extent.
+    pub fn resample_isotropic(&self, interp: Interpolation) -> Result<ResampledVolume, LoadError> {
+        let size = self.isotropic_spacing();
+        self.resample(DicomVec::new(size, size, size), interp)
+    }
+
+    /// Samples an arbitrary plane in patient space, for multi-planar reformatting. The result is
+    /// a single slice whose pixels are (column, row, 0).
+    ///
+    /// # Errors
+    /// - `InvalidDims` if this volume has no extent.
+    pub fn reformat(
+        &self,
+        plane: &ObliquePlane,
+        interp: Interpolation,
+    ) -> Result<ResampledVolume, LoadError> {
+        let counts = IndexVec {
+            x: plane.cols,
+            y: plane.rows,
+            z: 1,
+        };
+        self.resample_to(plane.voxel_to_patient(), counts, interp)
+    }
+
+    /// The plane through the slice at the given index oriented to the given axis, with square
+    /// pixels of the isotropic spacing, so that views along the `X` and `Y` axes are not
+    /// stretched by the spacing between slices.
+    #[must_use]
+    pub fn axis_plane(&self, axis: &VolAxis, axis_index: usize) -> ObliquePlane {
+        let affine = self.voxel_to_patient();
+        let [x_step, y_step, z_step, _] = affine.columns();
+        let spacing = self.isotropic_spacing();
+        let counts = self.dims.counts;
+        #[allow(
+            clippy::cast_precision_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss
+        )]
+        let span = |step: &DicomVec, count: usize| {
+            (step.length() * count.saturating_sub(1) as f32 / spacing).round() as usize + 1
+        };
+        let (origin, row_step, col_step, cols, rows) = match axis {
+            VolAxis::X => (
+                IndexVec {
+                    x: axis_index,
+                    y: 0,
+                    z: 0,
+                },
+                y_step,
+                z_step,
+                span(&y_step, counts.y),
+                span(&z_step, counts.z),
+            ),
+            VolAxis::Y => (
+                IndexVec {
+                    x: 0,
+                    y: axis_index,
+                    z: 0,
+                },
+                x_step,
+                z_step,
+                span(&x_step, counts.x),
+                span(&z_step, counts.z),
+            ),
+            VolAxis::Z => (
+                IndexVec {
+                    x: 0,
+                    y: 0,
+                    z: axis_index,
+                },
+                x_step,
+                y_step,
+                span(&x_step, counts.x),
+                span(&y_step, counts.y),
+            ),
+        };
+        ObliquePlane {
+            origin: affine.apply_index(origin),
+            row_dir: row_step,
+            col_dir: col_step,
+            cols,
+            rows,
+            spacing: (spacing, spacing),
+        }
+    }
+
+    /// The range of slice indices along the given axis of a slab of the given thickness in mm,
+    /// centered on the slice at `center`. The range is limited to the slices of the volume.
+    #[must_use]
+    pub fn axis_slab(&self, axis: &VolAxis, center: usize, thickness: f32) -> Range<usize> {
+        let [x_step, y_step, z_step, _] = self.voxel_to_patient().columns();
+        let (step, count) = match axis {
+            VolAxis::X => (x_step.length(), self.dims.counts.x),
+            VolAxis::Y => (y_step.length(), self.dims.counts.y),
+            VolAxis::Z => (z_step.dot(&self.dims.normal()).abs(), self.dims.counts.z),
+        };
+        let half = if VolDims::is_valid_dim(step) && VolDims::is_valid_dim(thickness) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let half = (thickness / 2_f32 / step).round() as usize;
+            half
+        } else {
+            0
+        };
+        let center = center.min(count.saturating_sub(1));
+        center.saturating_sub(half)..(center + half + 1).min(count)
+    }
+
+    /// Projects a slab of slices along the given axis into a single image, combining the voxels
+    /// along the axis by the given mode. Voxels of Pixel Padding Value are excluded. The image is
+    /// positioned at the first slice of the slab, with pixels (column, row, 0) as with
+    /// `slice_iter`.
+    ///
+    /// # Errors
+    /// - `InvalidProjection` if the range of slices is empty or beyond the volume.
+    pub fn project(
+        &self,
+        axis: &VolAxis,
+        slices: Range<usize>,
+        mode: ProjectionMode,
+    ) -> Result<ResampledVolume, LoadError> {
+        let axis_dims = self.axis_dims(axis);
+        if slices.is_empty() || slices.end > axis_dims.z {
+            return Err(LoadError::InvalidProjection(format!(
+                "slices {slices:?} are not within the {} slices along the {axis} axis",
+                axis_dims.z
+            )));
+        }
+
+        let counts = self.dims.counts;
+        let background = self.background();
+        let samples_per_voxel = if self.is_rgb { 3 } else { 1 };
+        let mut samples = Vec::with_capacity(axis_dims.x * axis_dims.y * samples_per_voxel);
+        for index in 0..axis_dims.x * axis_dims.y {
+            let values = slices.clone().filter_map(|slice| {
+                let coord = axis.slice_coord(&counts, slice, index)?;
+                let p = self.get_pixel(coord).ok()?;
+                Some([p.r, p.g, p.b]).filter(|vals| !self.is_padding(vals))
+            });
+            let vals = mode.combine(values).unwrap_or(background);
+            samples.extend_from_slice(&vals[..samples_per_voxel]);
+        }
+
+        let affine = self.voxel_to_patient();
+        let [x_step, y_step, z_step, _] = affine.columns();
+        let (col_step, row_step, slab_step) = match axis {
+            VolAxis::X => (y_step, z_step, x_step),
+            VolAxis::Y => (x_step, z_step, y_step),
+            VolAxis::Z => (x_step, y_step, z_step),
+        };
+        let origin = axis
+            .slice_coord(&counts, slices.start, 0)
+            .map(|coord| affine.apply_index(coord))
+            .unwrap_or_default();
+        Ok(ResampledVolume::new(
+            IndexVec {
+                x: axis_dims.x,
+                y: axis_dims.y,
+                z: 1,
+            },
+            Affine::from_columns(col_step, row_step, slab_step, origin),
+            self.is_rgb,
+            samples,
+        ))
+    }
+
+    /// Projects a slab of the given thickness in mm centered on an arbitrary plane into a single
+    /// image, combining the samples along the plane normal by the given mode. Samples are spaced
+    /// by the smallest spacing between voxels. See `reformat` and `project`.
+    ///
+    /// # Errors
+    /// - `InvalidProjection` if the thickness is negative.
+    /// - `InvalidDims` if this volume has no extent.
+    pub fn project_oblique(
+        &self,
+        plane: &ObliquePlane,
+        thickness: f32,
+        mode: ProjectionMode,
+        interp: Interpolation,
+    ) -> Result<ResampledVolume, LoadError> {
+        if thickness.is_nan() || thickness < 0_f32 {
+            return Err(LoadError::InvalidProjection(format!(
+                "invalid slab thickness: {thickness}"
+            )));
+        }
+        let Some(patient_to_voxel) = self.patient_to_voxel() else {
+            return Err(LoadError::InvalidDims(format!(
+                "Unable to project volume without extent: {}",
+                self.dims
+            )));
+        };
+
+        let affine = plane.voxel_to_patient();
+        let step = self.isotropic_spacing();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let num_samples = (thickness / step).floor() as usize + 1;
+        #[allow(clippy::cast_precision_loss)]
+        let offsets = (0..num_samples)
+            .map(|i| (i as f32 - (num_samples - 1) as f32 / 2_f32) * step)
+            .collect::<Vec<f32>>();
+
+        let background = self.background();
+        let samples_per_voxel = if self.is_rgb { 3 } else { 1 };
+        let mut samples = Vec::with_capacity(plane.cols * plane.rows * samples_per_voxel);
+        for y in 0..plane.rows {
+            for x in 0..plane.cols {
+                #[allow(clippy::cast_precision_loss)]
+                let values = offsets.iter().filter_map(|offset| {
+                    let pos = affine.apply(DicomVec::new(x as f32, y as f32, *offset));
+                    self.sample(patient_to_voxel.apply(pos), interp)
+                        .filter(|vals| !self.is_padding(vals))
+                });
+                let vals = mode.combine(values).unwrap_or(background);
+                samples.extend_from_slice(&vals[..samples_per_voxel]);
+            }
+        }
+        Ok(ResampledVolume::new(
+            IndexVec {
+                x: plane.cols,
+                y: plane.rows,
+                z: 1,
+            },
+            affine,
+            self.is_rgb,
+            samples,
+        ))
+    }
+
+    /// Computes statistics of the values within a region of the volume, in modality units. Voxels
+    /// of Pixel Padding Value are excluded, and RGB volumes use the luminance of each voxel. The
+    /// area and volume use the pixel spacing and the nominal spacing between slices.
+    ///
+    /// # Errors
+    /// - `InvalidRegion` if the region is not within the volume or contains no voxels.
+    pub fn statistics(&self, region: &Region) -> Result<Statistics, LoadError> {
+        let counts = self.dims.counts;
+        let mut values = Vec::new();
+        for z in region.slices(&counts)? {
+            for y in 0..counts.y {
+                for x in 0..counts.x {
+                    let coord = IndexVec { x, y, z };
+                    if !region.contains(&counts, &coord) {
+                        continue;
+                    }
+                    let Ok(p) = self.get_pixel(coord) else {
+                        continue;
+                    };
+                    let vals = [p.r, p.g, p.b];
+                    if self.is_padding(&vals) {
+                        continue;
+                    }
+                    values.push(if self.is_rgb {
+                        0.299 * p.r + 0.587 * p.g + 0.114 * p.b
+                    } else {
+                        p.r
+                    });
+                }
+            }
+        }
+
+        let voxel_dims = self.dims.voxel_dims();
+        let spacing = self.slice_geometry().spacing();
+        let slice_spacing = if VolDims::is_valid_dim(spacing) {
+            spacing
+        } else {
+            voxel_dims.z
+        };
+        Statistics::new(values, voxel_dims.x * voxel_dims.y, slice_spacing)
+            .ok_or_else(|| LoadError::InvalidRegion("region contains no voxels".to_owned()))
+    }
+
+    /// Computes a histogram of the values within a region of the volume with the given number of
+    /// bins spanning the minimum to maximum value of the region. See `statistics`.
+    ///
+    /// # Errors
+    /// - `InvalidRegion` if the region is not within the volume or contains no voxels.
+    pub fn histogram(&self, region: &Region, bins: usize) -> Result<Histogram, LoadError> {
+        Ok(self.statistics(region)?.histogram(bins))
+    }
+
+    /// The value of voxels sampled outside of the volume, black for RGB and otherwise the minimum
+    /// value.
+    fn background(&self) -> [f32; 3] {
+        if self.is_rgb {
+            [0_f32; 3]
+        } else {
+            [self.rescale(self.min_val); 3]
+        }
+    }
+
+    /// Whether the grayscale value is the Pixel Padding Value, after the modality transform.
+    fn is_padding(&self, vals: &[f32; 3]) -> bool {
+        !self.is_rgb
+            && self
+                .pixel_pad
+                .is_some_and(|pad| (self.rescale(pad) - vals[0]).abs() < f32::EPSILON)
+    }
+
+    /// The smallest positive spacing between voxels along any axis, or 1mm if there is none.
+    fn isotropic_spacing(&self) -> f32 {
+        let [x_step, y_step, z_step, _] = self.voxel_to_patient().columns();
+        let z_spacing = z_step.dot(&self.dims.normal()).abs();
+        [x_step.length(), y_step.length(), z_spacing]
+            .into_iter()
+            .filter(|v| VolDims::is_valid_dim(*v))
+            .reduce(f32::min)
+            .unwrap_or(1_f32)
+    }
+
+    /// Loads a slice into this volume. Each frame of a multi-frame dataset is loaded as its own
+    /// slice, positioned by the Per-frame Functional Groups.
     ///
     /// # Errors
     /// - `ParseError` any errors parsing the dataset.
-    /// - `PixelValueError` if the pixel values fail to parse into `i16`.
+    /// - `UnsupportedTransferSyntax` if the `PixelData` is encapsulated in a transfer syntax
+    ///   without a registered codec.
     /// - `InconsistentSliceFormat` if the slice is not in the same format as other slices already
     ///   loaded in to this volume.
-    #[allow(clippy::too_many_lines)]
     pub fn load_slice(&mut self, dcmroot: DicomRoot) -> Result<(), LoadError> {
         let sop_uid = dcmroot.sop_instance_id()?;
         let series_uid = dcmroot.series_instance_id()?;
@@ -233,31 +785,67 @@ impl ImageVolume {
                 patient_id.clone_into(&mut self.patient_id);
             }
         }
+        if let Some(frame_of_ref_uid) = dcmroot
+            .get_value_by_tag(&tags::FrameofReferenceUID)
+            .and_then(|rv| rv.string().cloned())
+        {
+            self.frame_of_ref_uid = Some(frame_of_ref_uid);
+        }
 
         let series_desc = dcmroot
             .get_value_by_tag(&tags::SeriesDescription)
             .and_then(|rv| rv.string().cloned())
             .unwrap_or_default();
 
-        let pdinfo = PixelDataSliceInfo::process(dcmroot)?;
+        let pdinfo = PixelDataSliceInfo::process(dcmroot, &self.codecs)?;
+        for frame in pdinfo.into_frames()? {
+            self.insert_slice(&sop_uid, &series_uid, &series_desc, frame)?;
+        }
 
+        Ok(())
+    }
+
+    /// Validates that the slice is consistent with the slices already loaded into this volume,
+    /// then loads its pixel data and inserts it in z-position order.
+    #[allow(clippy::too_many_lines)]
+    fn insert_slice(
+        &mut self,
+        sop_uid: &str,
+        series_uid: &str,
+        series_desc: &str,
+        pdinfo: PixelDataSliceInfo,
+    ) -> Result<(), LoadError> {
+        let sop_uid = sop_uid.to_owned();
         let dims = pdinfo.vol_dims();
         let stride = pdinfo.stride();
         let is_rgb = pdinfo.is_rgb();
-        let pixel_pad = pdinfo.pixel_pad().map(|v| v as i16);
+        #[allow(clippy::cast_possible_truncation)]
+        let pixel_pad = if pdinfo.is_float() {
+            pdinfo.float_pixel_pad().map(|v| v as f32)
+        } else {
+            pdinfo.pixel_pad().map(|v| {
+                if pdinfo.is_signed() {
+                    f32::from(i16::from_ne_bytes(v.to_ne_bytes()))
+                } else {
+                    f32::from(v)
+                }
+            })
+        };
         let slope = pdinfo.slope().unwrap_or(1_f32);
         let intercept = pdinfo.intercept().unwrap_or(0_f32);
+        let modality_lut = pdinfo.modality_lut().cloned();
         let samples_per_pixel = usize::from(pdinfo.samples_per_pixel());
 
         if self.infos.is_empty() {
-            self.series_uid = series_uid;
-            self.series_desc = series_desc;
+            series_uid.clone_into(&mut self.series_uid);
+            series_desc.clone_into(&mut self.series_desc);
             self.dims = dims;
             self.stride = stride;
             self.is_rgb = is_rgb;
             self.pixel_pad = pixel_pad;
             self.slope = slope;
             self.intercept = intercept;
+            self.modality_lut = modality_lut;
             self.samples_per_pixel = samples_per_pixel;
         } else {
             if series_uid != self.series_uid {
@@ -278,6 +866,12 @@ impl ImageVolume {
                     format!("Dimensions mismatch, this: {dims}, other: {}", self.dims),
                 ));
             }
+            if !self.dims.orientation_matches(&dims) {
+                return Err(LoadError::InconsistentSliceFormat(
+                    sop_uid,
+                    "Image Orientation (Patient) mismatch".to_owned(),
+                ));
+            }
             if stride != self.stride {
                 return Err(LoadError::InconsistentSliceFormat(
                     sop_uid,
@@ -311,6 +905,12 @@ impl ImageVolume {
                     format!("Intercept mismatch: {intercept}, other: {}", self.intercept),
                 ));
             }
+            if modality_lut != self.modality_lut {
+                return Err(LoadError::InconsistentSliceFormat(
+                    sop_uid,
+                    "Modality LUT mismatch".to_owned(),
+                ));
+            }
             if samples_per_pixel != self.samples_per_pixel {
                 return Err(LoadError::InconsistentSliceFormat(
                     sop_uid,
@@ -323,11 +923,15 @@ impl ImageVolume {
         }
 
         let loaded = Self::load_pixel_data(pdinfo)?;
-        self.min_val = self.min_val.min(loaded.0.min_val() as i16);
-        self.max_val = self.max_val.max(loaded.0.max_val() as i16);
+        self.min_val = self.min_val.min(loaded.0.min_val());
+        self.max_val = self.max_val.max(loaded.0.max_val());
 
         let seek = &loaded.0;
-        match self.infos.binary_search_by(|i| Self::cmp_by_zpos(seek, i)) {
+        let normal = self.dims.normal();
+        match self
+            .infos
+            .binary_search_by(|i| Self::cmp_along_normal(&normal, seek, i))
+        {
             Err(loc) => {
                 self.infos.insert(loc, loaded.0);
                 self.slices.insert(loc, loaded.1);
@@ -339,7 +943,7 @@ impl ImageVolume {
             Ok(_existing) => {
                 return Err(LoadError::InconsistentSliceFormat(
                     loaded.0.sop_instance_id(),
-                    "Multiple slices in the same z-pos".to_owned(),
+                    "Multiple slices in the same position".to_owned(),
                 ))
             }
         }
@@ -347,11 +951,15 @@ impl ImageVolume {
         Ok(())
     }
 
-    fn cmp_by_zpos(a: &PixelDataSliceInfo, b: &PixelDataSliceInfo) -> Ordering {
-        // The X and Y of image position are likely to be the same, unless it's something like
-        // a spinal MR acquisition.
-        let a_pos = a.image_pos()[2];
-        let b_pos = b.image_pos()[2];
+    /// Compares the positions of slices projected onto the normal of their image plane, so that
+    /// sagittal, coronal and oblique slices are ordered by their location through the volume.
+    fn cmp_along_normal(
+        normal: &DicomVec,
+        a: &PixelDataSliceInfo,
+        b: &PixelDataSliceInfo,
+    ) -> Ordering {
+        let a_pos = normal.dot(&Self::image_pos(a));
+        let b_pos = normal.dot(&Self::image_pos(b));
         if a_pos < b_pos {
             Ordering::Less
         } else if a_pos > b_pos {
@@ -361,23 +969,75 @@ impl ImageVolume {
         }
     }
 
-    /// Loads the `PixelData` for the given slice. The pixel values will be trunacted to `i16`.
+    fn image_pos(info: &PixelDataSliceInfo) -> DicomVec {
+        let [x, y, z] = *info.image_pos();
+        DicomVec::new(x, y, z)
+    }
+
+    /// Loads the `PixelData` for the given slice, keeping the pixel values in their native sample
+    /// type. Float and Double Float Pixel Data are loaded as `f32` and `f64`.
     fn load_pixel_data(
         pdinfo: PixelDataSliceInfo,
-    ) -> Result<(PixelDataSliceInfo, Vec<i16>), LoadError> {
-        match (pdinfo.bits_alloc(), pdinfo.is_rgb()) {
-            (BitsAlloc::Unsupported(val), _) => Err(LoadError::InvalidBitsAlloc(*val)),
-            (BitsAlloc::Eight, true) => Ok(PixelDataSliceU8::from_rgb_8bit(pdinfo).into_i16()),
-            (BitsAlloc::Eight, false) => {
-                Ok(PixelDataSliceI16::from_mono_8bit(pdinfo).into_buffer())
+    ) -> Result<(PixelDataSliceInfo, SliceBuffer), LoadError> {
+        if pdinfo.is_float() {
+            return match pdinfo.bits_alloc() {
+                BitsAlloc::ThirtyTwo => {
+                    let (pdinfo, buffer) =
+                        PixelDataSliceF32::from_float_32bit(pdinfo)?.into_buffer();
+                    Ok((pdinfo, SliceBuffer::F32(buffer)))
+                }
+                BitsAlloc::SixtyFour => {
+                    let (pdinfo, buffer) =
+                        PixelDataSliceF64::from_float_64bit(pdinfo)?.into_buffer();
+                    Ok((pdinfo, SliceBuffer::F64(buffer)))
+                }
+                other => Err(LoadError::InvalidBitsAlloc(other.val())),
+            };
+        }
+
+        let (pdinfo, buffer) = match (pdinfo.bits_alloc(), pdinfo.is_rgb(), pdinfo.is_signed()) {
+            (BitsAlloc::Unsupported(val), _, _) => return Err(LoadError::InvalidBitsAlloc(*val)),
+            (BitsAlloc::SixtyFour, _, _) => return Err(LoadError::InvalidBitsAlloc(64)),
+            // 1-bit Pixel Data is unpacked to 8-bit by `PixelDataSliceInfo::process`.
+            (BitsAlloc::One, _, _) => return Err(LoadError::InvalidBitsAlloc(1)),
+            (BitsAlloc::Eight, true, _) => {
+                let (pdinfo, buffer) = PixelDataSliceU8::from_rgb_8bit(pdinfo).into_buffer();
+                (pdinfo, SliceBuffer::U8(buffer))
             }
-            (BitsAlloc::Sixteen, true) => PixelDataSliceU16::from_rgb_16bit(pdinfo)?.into_i16(),
-            (BitsAlloc::Sixteen, false) => {
-                Ok(PixelDataSliceI16::from_mono_16bit(pdinfo)?.into_buffer())
+            (BitsAlloc::Eight, false, true) => {
+                let (pdinfo, buffer) = PixelDataSliceI16::from_mono_8bit(pdinfo).into_buffer();
+                (pdinfo, SliceBuffer::I16(buffer))
             }
-            (BitsAlloc::ThirtyTwo, true) => PixelDataSliceU32::from_rgb_32bit(pdinfo)?.into_i16(),
-            (BitsAlloc::ThirtyTwo, false) => PixelDataSliceI32::from_mono_32bit(pdinfo)?.into_i16(),
-        }
+            (BitsAlloc::Eight, false, false) => {
+                let (pdinfo, buffer) = PixelDataSliceU8::from_mono_8bit(pdinfo).into_buffer();
+                (pdinfo, SliceBuffer::U8(buffer))
+            }
+            (BitsAlloc::Sixteen, true, _) => {
+                let (pdinfo, buffer) = PixelDataSliceU16::from_rgb_16bit(pdinfo)?.into_buffer();
+                (pdinfo, SliceBuffer::U16(buffer))
+            }
+            (BitsAlloc::Sixteen, false, true) => {
+                let (pdinfo, buffer) = PixelDataSliceI16::from_mono_16bit(pdinfo)?.into_buffer();
+                (pdinfo, SliceBuffer::I16(buffer))
+            }
+            (BitsAlloc::Sixteen, false, false) => {
+                let (pdinfo, buffer) = PixelDataSliceU16::from_mono_16bit(pdinfo)?.into_buffer();
+                (pdinfo, SliceBuffer::U16(buffer))
+            }
+            (BitsAlloc::ThirtyTwo, true, _) => {
+                let (pdinfo, buffer) = PixelDataSliceU32::from_rgb_32bit(pdinfo)?.into_buffer();
+                (pdinfo, SliceBuffer::U32(buffer))
+            }
+            (BitsAlloc::ThirtyTwo, false, true) => {
+                let (pdinfo, buffer) = PixelDataSliceI32::from_mono_32bit(pdinfo)?.into_buffer();
+                (pdinfo, SliceBuffer::I32(buffer))
+            }
+            (BitsAlloc::ThirtyTwo, false, false) => {
+                let (pdinfo, buffer) = PixelDataSliceU32::from_mono_32bit(pdinfo)?.into_buffer();
+                (pdinfo, SliceBuffer::U32(buffer))
+            }
+        };
+        Ok((pdinfo, buffer))
     }
 
     /// Gets the pixel at the given coordinate (x, y, z).
@@ -407,18 +1067,21 @@ impl ImageVolume {
             return Err(LoadError::InvalidPixelSource(pixel_count));
         }
 
+        // Samples are rescaled in `f64` and narrowed to `f32` only at the end, so that 32-bit
+        // samples are not rounded before the modality transform.
+        #[allow(clippy::cast_possible_truncation)]
+        let narrow = |v: f64| v as f32;
         let (r, g, b) = if self.is_rgb {
-            let red = buffer[pixel_count];
-            let green = buffer[pixel_count + self.stride];
-            let blue = buffer[pixel_count + self.stride * 2];
-            (f32::from(red), f32::from(green), f32::from(blue))
+            let sample = |index: usize| buffer.get(index).map(narrow).unwrap_or_default();
+            let red = sample(pixel_count);
+            let green = sample(pixel_count + self.stride);
+            let blue = sample(pixel_count + self.stride * 2);
+            (red, green, blue)
         } else {
             let applied_val = buffer
                 .get(pixel_count)
-                .copied()
-                .map(f32::from)
-                .or_else(|| self.pixel_pad().map(f32::from))
-                .map(|v| self.rescale(v))
+                .or(self.pixel_pad().map(f64::from))
+                .map(|v| narrow(self.rescale_f64(v)))
                 .unwrap_or_default();
             let val = applied_val;
             (val, val, val)
@@ -427,6 +1090,21 @@ impl ImageVolume {
         Ok(VolPixel { coord, r, g, b })
     }
 
+    /// The Overlay Planes of the slice at the given z-index.
+    #[must_use]
+    pub fn overlays(&self, z: usize) -> &[Overlay] {
+        self.infos.get(z).map_or(&[], PixelDataSliceInfo::overlays)
+    }
+
+    /// Whether any Overlay Plane of the slice is set at the given coordinate (x, y, z), in the
+    /// native plane orientation, `VolAxis::Z`.
+    #[must_use]
+    pub fn overlay_at(&self, coord: &IndexVec) -> bool {
+        self.overlays(coord.z)
+            .iter()
+            .any(|overlay| overlay.is_set(0, coord.y, coord.x))
+    }
+
     #[must_use]
     pub fn slice_iter(&'_ self, axis: &VolAxis, axis_index: usize) -> ImageVolumeAxisSliceIter<'_> {
         ImageVolumeAxisSliceIter {
@@ -451,63 +1129,383 @@ pub struct ImageVolumeAxisSliceIter<'buf> {
     pixel_count: usize,
 }
 
-impl ImageVolumeAxisSliceIter<'_> {
-    /// Compute the relative row and column from `pixel_count`, which used with `axis_index` to
-    /// produce the x,y,z coordinate within the volume whose pixel to retrieve.
-    fn compute_coord(&self, index: usize) -> Option<IndexVec> {
-        match self.axis {
-            VolAxis::X => {
-                let cols = self.vol.dims.counts.y;
-                let rows = self.vol.dims.counts.z;
-                if index >= rows * cols {
-                    return None;
-                }
-                let y = index % cols;
-                let z = (index / cols) % rows;
-                Some(IndexVec {
-                    x: self.axis_index,
-                    y,
-                    z,
-                })
-            }
-            VolAxis::Y => {
-                let cols = self.vol.dims.counts.x;
-                let rows = self.vol.dims.counts.z;
-                if index >= rows * cols {
-                    return None;
-                }
-                let x = index % cols;
-                let z = (index / cols) % rows;
-                Some(IndexVec {
-                    x,
-                    y: self.axis_index,
-                    z,
-                })
-            }
-            VolAxis::Z => {
-                let cols = self.vol.dims.counts.x;
-                let rows = self.vol.dims.counts.y;
-                if index >= rows * cols {
-                    return None;
-                }
-                let x = index % cols;
-                let y = (index / cols) % rows;
-                Some(IndexVec {
-                    x,
-                    y,
-                    z: self.axis_index,
-                })
-            }
-        }
-    }
-}
-
 impl Iterator for ImageVolumeAxisSliceIter<'_> {
     type Item = VolPixel;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let coord = self.compute_coord(self.pixel_count)?;
+        let coord =
+            self.axis
+                .slice_coord(&self.vol.dims.counts, self.axis_index, self.pixel_count)?;
         self.pixel_count += 1;
         self.vol.get_pixel(coord).ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{defn::tag::Tag, values::RawValue},
+        dict::tags,
+        load::{
+            imgvol::ImageVolume,
+            pixeldata::{display::VoiTransform, winlevel::VoiLutFunction, BitsAlloc, SliceBuffer},
+            testsupport::{elem, frame_groups, item, multi_frame, seq},
+            IndexVec, VolAxis,
+        },
+    };
+
+    #[test]
+    pub fn test_load_enhanced_multi_frame() {
+        let dcmroot = multi_frame(
+            3,
+            vec![frame_groups(10.0), frame_groups(0.0), frame_groups(5.0)],
+        );
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+
+        assert_eq!(3, volume.dims().counts().z);
+        assert!((volume.dims().voxel_dims().x - 0.5).abs() < f32::EPSILON);
+        assert!((volume.intercept() + 1024.0).abs() < f32::EPSILON);
+        // Slices are sorted by the position of each frame, with the highest z-position first.
+        let frames = volume
+            .infos()
+            .iter()
+            .map(|info| info.frame_index())
+            .collect::<Vec<Option<usize>>>();
+        assert_eq!(vec![Some(0), Some(2), Some(1)], frames);
+        assert_eq!(
+            vec![
+                SliceBuffer::U16(vec![0; 4]),
+                SliceBuffer::U16(vec![2; 4]),
+                SliceBuffer::U16(vec![1; 4])
+            ],
+            *volume.slices()
+        );
+        assert!((volume.dims().origin().z - 10.0).abs() < f32::EPSILON);
+        // The frames do not each hold a copy of every frame's functional groups.
+        assert!(volume.infos()[0]
+            .dcmroot()
+            .get_child_by_tag(&tags::PerFrameFunctionalGroupsSequence)
+            .is_none());
+    }
+
+    #[test]
+    pub fn test_load_multi_frame_without_positions() {
+        let mut volume = ImageVolume::default();
+        volume.load_slice(multi_frame(2, Vec::new())).unwrap();
+
+        // Frames are stacked in order along the normal, spaced by the slice thickness.
+        assert_eq!(2, volume.dims().counts().z);
+        let z = volume
+            .infos()
+            .iter()
+            .map(|info| info.image_pos()[2])
+            .collect::<Vec<f32>>();
+        assert_eq!(vec![2.0, 0.0], z);
+        assert_eq!(
+            vec![SliceBuffer::U16(vec![1; 4]), SliceBuffer::U16(vec![0; 4])],
+            *volume.slices()
+        );
+    }
+
+    #[test]
+    pub fn test_sagittal_geometry() {
+        // Sagittal frames, whose rows are anterior to posterior and columns head to foot, out of
+        // order along the x-axis and all at the same z.
+        let sagittal = |x: f64| {
+            item(vec![
+                seq(
+                    &tags::PlanePositionSequence,
+                    vec![item(vec![elem(
+                        &tags::ImagePositionPatient,
+                        RawValue::Doubles(vec![x, -10.0, -10.0]),
+                    )])],
+                ),
+                seq(
+                    &tags::PlaneOrientationSequence,
+                    vec![item(vec![elem(
+                        &tags::ImageOrientationPatient,
+                        RawValue::Doubles(vec![0.0, 1.0, 0.0, 0.0, 0.0, -1.0]),
+                    )])],
+                ),
+            ])
+        };
+        let dcmroot = multi_frame(3, vec![sagittal(0.0), sagittal(10.0), sagittal(5.0)]);
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+
+        // The normal is towards the patient's right, so slices are ordered left to right.
+        let x = volume
+            .infos()
+            .iter()
+            .map(|info| info.image_pos()[0])
+            .collect::<Vec<f32>>();
+        assert_eq!(vec![0.0, 5.0, 10.0], x);
+
+        let affine = volume.voxel_to_patient();
+        let pos = affine.apply_index(IndexVec { x: 1, y: 1, z: 1 });
+        assert!((pos.x - 5.0).abs() < f32::EPSILON);
+        assert!((pos.y - -9.5).abs() < f32::EPSILON);
+        assert!((pos.z - -10.5).abs() < f32::EPSILON);
+        let index = volume.patient_to_voxel().unwrap().apply(pos);
+        assert!((index.x - 1.0).abs() < 1e-4);
+        assert!((index.y - 1.0).abs() < 1e-4);
+        assert!((index.z - 1.0).abs() < 1e-4);
+
+        let coord = volume.dims().coordinate(IndexVec { x: 1, y: 1, z: 0 });
+        assert!((coord.y - -9.5).abs() < f32::EPSILON);
+        assert!((coord.z - -10.5).abs() < f32::EPSILON);
+
+        let geometry = volume.slice_geometry();
+        assert!(geometry.is_regular());
+        assert!((geometry.spacing() - 5.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    pub fn test_load_native_sample_types() {
+        // Unsigned 16-bit values above `i16::MAX` are retained.
+        let mut dcmroot = multi_frame(1, Vec::new());
+        dcmroot.remove_child_by_tag(&tags::PixelData);
+        let words = vec![0u16, 32_768, 40_000, u16::MAX];
+        dcmroot.add_child_with_val(&tags::PixelData, RawValue::Words(words.clone()));
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+        assert_eq!(SliceBuffer::U16(words), volume.slices()[0]);
+        assert_eq!(8, volume.byte_size());
+        assert!((volume.max_val() - f32::from(u16::MAX)).abs() < f32::EPSILON);
+        let pixel = volume.get_pixel(IndexVec { x: 0, y: 1, z: 0 }).unwrap();
+        assert!((pixel.r - (40_000_f32 - 1024_f32)).abs() < f32::EPSILON);
+        let rescaled = volume
+            .slice_iter(&VolAxis::Z, 0)
+            .map(|p| p.r)
+            .collect::<Vec<f32>>();
+        assert_eq!(vec![-1024.0, 31_744.0, 38_976.0, 64_511.0], rescaled);
+
+        // Signed 32-bit values, such as dose grids, are not truncated to `i16`.
+        let mut dcmroot = multi_frame(1, Vec::new());
+        dcmroot.remove_child_by_tag(&tags::PixelData);
+        for (tag, val) in [
+            (&tags::BitsAllocated, 32),
+            (&tags::BitsStored, 32),
+            (&tags::PixelRepresentation, 1),
+        ] {
+            dcmroot.remove_child_by_tag(tag);
+            dcmroot.add_child_with_val(tag, RawValue::of_ushort(val));
+        }
+        let ints = vec![-100_000i32, 0, 70_000, 16_777_217];
+        let bytes = ints.iter().flat_map(|v| v.to_le_bytes()).collect();
+        dcmroot.add_child_with_val(&tags::PixelData, RawValue::Bytes(bytes));
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+        assert_eq!(SliceBuffer::I32(ints), volume.slices()[0]);
+        assert!((volume.min_val() + 100_000_f32).abs() < f32::EPSILON);
+        assert!((volume.max_val() - 16_777_216_f32).abs() < f32::EPSILON);
+        let winlevel = volume.minmax_winlevel();
+        assert!((winlevel.center() - (8_338_608_f32 - 1024_f32)).abs() < f32::EPSILON);
+        // The sample is not representable as `f32`, but its rescaled value is.
+        let pixel = volume.get_pixel(IndexVec { x: 1, y: 1, z: 0 }).unwrap();
+        assert!((pixel.r - 16_776_193_f32).abs() < f32::EPSILON);
+        assert!((volume.rescale_f64(16_777_217.0) - 16_776_193.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    pub fn test_load_float_pixel_data() {
+        let float_slice = |pd_tag: &Tag, val: RawValue| {
+            let mut dcmroot = multi_frame(1, Vec::new());
+            for tag in [
+                &tags::PixelData,
+                &tags::BitsAllocated,
+                &tags::BitsStored,
+                &tags::PixelRepresentation,
+                &tags::SharedFunctionalGroupsSequence,
+            ] {
+                dcmroot.remove_child_by_tag(tag);
+            }
+            dcmroot.add_child_with_val(&tags::PixelSpacing, RawValue::Doubles(vec![0.5, 0.5]));
+            dcmroot.add_child_with_val(&tags::SliceThickness, RawValue::of_double(2.0));
+            dcmroot.add_child_with_val(&tags::FloatPixelPaddingValue, RawValue::Floats(vec![1e6]));
+            dcmroot.add_child_with_val(pd_tag, val);
+            let mut volume = ImageVolume::default();
+            volume.load_slice(dcmroot).unwrap();
+            volume
+        };
+
+        let floats = vec![-1.5_f32, 0.25, 1e6, 3.0];
+        let volume = float_slice(&tags::FloatPixelData, RawValue::Floats(floats.clone()));
+        assert_eq!(SliceBuffer::F32(floats), volume.slices()[0]);
+        // The padding value is excluded from the min/max used for window/level.
+        assert!((volume.min_val() + 1.5).abs() < f32::EPSILON);
+        assert!((volume.max_val() - 3.0).abs() < f32::EPSILON);
+        let winlevel = volume.minmax_winlevel();
+        assert!((winlevel.center() - 0.75).abs() < f32::EPSILON);
+        assert!((winlevel.width() - 4.5).abs() < f32::EPSILON);
+        let pixel = volume.get_pixel(IndexVec { x: 1, y: 0, z: 0 }).unwrap();
+        assert!((pixel.r - 0.25).abs() < f32::EPSILON);
+
+        let doubles = vec![-1.5_f64, 0.25, 1e6, 3.000_000_001];
+        let volume = float_slice(
+            &tags::DoubleFloatPixelData,
+            RawValue::Doubles(doubles.clone()),
+        );
+        assert!(volume.infos()[0].bits_alloc() == &BitsAlloc::SixtyFour);
+        assert_eq!(SliceBuffer::F64(doubles), volume.slices()[0]);
+    }
+
+    #[test]
+    pub fn test_load_palette_color() {
+        let mut dcmroot = multi_frame(1, Vec::new());
+        for tag in [
+            &tags::PhotometricInterpretation,
+            &tags::BitsAllocated,
+            &tags::BitsStored,
+            &tags::PixelData,
+        ] {
+            dcmroot.remove_child_by_tag(tag);
+        }
+        dcmroot.add_child_with_val(
+            &tags::PhotometricInterpretation,
+            RawValue::of_string("PALETTE COLOR"),
+        );
+        dcmroot.add_child_with_val(&tags::BitsAllocated, RawValue::of_ushort(8));
+        dcmroot.add_child_with_val(&tags::BitsStored, RawValue::of_ushort(8));
+        for tag in [
+            &tags::RedPaletteColorLookupTableDescriptor,
+            &tags::GreenPaletteColorLookupTableDescriptor,
+            &tags::BluePaletteColorLookupTableDescriptor,
+        ] {
+            dcmroot.add_child_with_val(tag, RawValue::UShorts(vec![4, 0, 8]));
+        }
+        dcmroot.add_child_with_val(
+            &tags::RedPaletteColorLookupTableData,
+            RawValue::Bytes(vec![0, 85, 170, 255]),
+        );
+        // Discrete 0, then linear to 255 over 3 entries.
+        dcmroot.add_child_with_val(
+            &tags::SegmentedGreenPaletteColorLookupTableData,
+            RawValue::Words(vec![0, 1, 0, 1, 3, 255]),
+        );
+        dcmroot.add_child_with_val(
+            &tags::BluePaletteColorLookupTableData,
+            RawValue::Bytes(vec![255, 170, 85, 0]),
+        );
+        dcmroot.add_child_with_val(&tags::PixelData, RawValue::Bytes(vec![0, 1, 2, 3]));
+
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+        assert!(volume.is_rgb());
+        assert_eq!(
+            SliceBuffer::U8(vec![0, 0, 255, 85, 85, 170, 170, 170, 85, 255, 255, 0]),
+            volume.slices()[0]
+        );
+        let pixel = volume.get_pixel(IndexVec { x: 1, y: 0, z: 0 }).unwrap();
+        assert_eq!((85.0, 85.0, 170.0), (pixel.r, pixel.g, pixel.b));
+    }
+
+    #[test]
+    pub fn test_grayscale_pipeline() {
+        let mut dcmroot = multi_frame(3, Vec::new());
+        dcmroot.remove_child_by_tag(&tags::PhotometricInterpretation);
+        dcmroot.add_child_with_val(
+            &tags::PhotometricInterpretation,
+            RawValue::of_string("MONOCHROME1"),
+        );
+        // A non-linear VOI LUT over the modality values -1024..=-1022.
+        let first_mapped = u16::from_ne_bytes((-1024_i16).to_ne_bytes());
+        *dcmroot.add_child(&tags::VOILUTSequence) = seq(
+            &tags::VOILUTSequence,
+            vec![item(vec![
+                elem(
+                    &tags::LUTDescriptor,
+                    RawValue::UShorts(vec![3, first_mapped, 8]),
+                ),
+                elem(&tags::LUTData, RawValue::UShorts(vec![0, 100, 255])),
+            ])],
+        );
+        dcmroot.add_child_with_val(&tags::WindowCenter, RawValue::of_double(-1023.0));
+        dcmroot.add_child_with_val(&tags::WindowWidth, RawValue::of_double(2.0));
+        dcmroot.add_child_with_val(&tags::VOILUTFunction, RawValue::of_string("SIGMOID"));
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot.clone()).unwrap();
+
+        // The VOI LUT takes precedence over the window, and MONOCHROME1 is inverted.
+        let pipeline = volume.grayscale_pipeline();
+        assert!(pipeline.is_inverse());
+        assert!(matches!(pipeline.voi_transform(), VoiTransform::Lut(_)));
+        assert!((pipeline.display(-1024_f32, 0_f32, 255_f32) - 255_f32).abs() < f32::EPSILON);
+        assert!((pipeline.display(-1023_f32, 0_f32, 255_f32) - 155_f32).abs() < 1e-3);
+        assert!((pipeline.apply(2_f32, 0_f32, 255_f32) - 0_f32).abs() < f32::EPSILON);
+
+        // Without the VOI LUT the window is used with its VOI LUT Function, and the Presentation
+        // LUT Shape overrides the inversion of MONOCHROME1.
+        dcmroot.remove_child_by_tag(&tags::VOILUTSequence);
+        dcmroot.add_child_with_val(&tags::PresentationLUTShape, RawValue::of_string("IDENTITY"));
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot.clone()).unwrap();
+        let pipeline = volume.grayscale_pipeline();
+        assert!(!pipeline.is_inverse());
+        let VoiTransform::Window(winlevel) = pipeline.voi_transform() else {
+            panic!("expected a window");
+        };
+        assert_eq!(VoiLutFunction::Sigmoid, winlevel.function());
+        assert!((pipeline.display(-1023_f32, 0_f32, 1_f32) - 0.5).abs() < f32::EPSILON);
+
+        // A Modality LUT replaces the rescale intercept.
+        *dcmroot.add_child(&tags::ModalityLUTSequence) = seq(
+            &tags::ModalityLUTSequence,
+            vec![item(vec![
+                elem(&tags::LUTDescriptor, RawValue::UShorts(vec![3, 0, 16])),
+                elem(&tags::LUTData, RawValue::UShorts(vec![10, 20, 40])),
+            ])],
+        );
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+        assert!(volume.modality_lut().is_some());
+        let pixel = volume.get_pixel(IndexVec { x: 0, y: 0, z: 0 }).unwrap();
+        assert!((pixel.r - 40_f32).abs() < f32::EPSILON);
+        let winlevel = volume.minmax_winlevel();
+        assert!((winlevel.center() - 25_f32).abs() < f32::EPSILON);
+        assert!((winlevel.width() - 30_f32).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    pub fn test_overlays_per_frame() {
+        let mut dcmroot = multi_frame(2, vec![frame_groups(0.0), frame_groups(5.0)]);
+        dcmroot.add_child_with_val(&tags::OverlayRows, RawValue::of_ushort(2));
+        dcmroot.add_child_with_val(&tags::OverlayColumns, RawValue::of_ushort(2));
+        dcmroot.add_child_with_val(&tags::NumberofFramesinOverlay, RawValue::of_string("2"));
+        dcmroot.add_child_with_val(&tags::OverlayBitsAllocated, RawValue::of_ushort(1));
+        // The first pixel of the first frame and the last pixel of the second frame.
+        dcmroot.add_child_with_val(&tags::OverlayData, RawValue::Bytes(vec![0b1000_0001]));
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+
+        // Slices are ordered by descending z, so the second frame is first.
+        assert_eq!(1, volume.overlays(0).len());
+        assert_eq!(0x6000, volume.overlays(0)[0].group());
+        assert!(volume.overlay_at(&IndexVec { x: 1, y: 1, z: 0 }));
+        assert!(!volume.overlay_at(&IndexVec { x: 0, y: 0, z: 0 }));
+        assert!(volume.overlay_at(&IndexVec { x: 0, y: 0, z: 1 }));
+        assert!(!volume.overlay_at(&IndexVec { x: 1, y: 1, z: 1 }));
+        assert!(volume.overlays(2).is_empty());
+    }
+
+    #[test]
+    pub fn test_invalid_overlay_skipped() {
+        let mut dcmroot = multi_frame(2, vec![frame_groups(0.0), frame_groups(5.0)]);
+        dcmroot.add_child_with_val(&tags::OverlayRows, RawValue::of_ushort(8));
+        dcmroot.add_child_with_val(&tags::OverlayColumns, RawValue::of_ushort(8));
+        dcmroot.add_child_with_val(&tags::OverlayBitsAllocated, RawValue::of_ushort(1));
+        // Overlay Data of an 8x8 overlay requires 8 bytes.
+        dcmroot.add_child_with_val(&tags::OverlayData, RawValue::Bytes(vec![0xFF, 0xFF]));
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+
+        assert_eq!(2, volume.dims().counts().z);
+        assert!(volume.overlays(0).is_empty());
+        assert!(volume.overlays(1).is_empty());
+        assert_eq!(
+            vec![SliceBuffer::U16(vec![1; 4]), SliceBuffer::U16(vec![0; 4])],
+            *volume.slices()
+        );
+    }
+}
@@ -15,7 +15,7 @@
 */
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     io::{BufReader, Read},
     marker::PhantomData,
     sync::RwLock,
@@ -26,12 +26,36 @@ use pixeldata::LoadError;
 use workspace::Workspace;
 
 use crate::{
-    core::{dcmobject::DicomRoot, read::ParserBuilder},
-    dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+    core::{
+        charset::CSRef,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{
+            constants::tags::ITEM,
+            tag::Tag,
+            ts::TSRef,
+            vl::ValueLength,
+            vr::{self, UN},
+        },
+        read::{ParseError, ParserBuilder},
+        values::RawValue,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
 };
 
+pub mod encapdoc;
+pub mod geometry;
 pub mod imgvol;
 pub mod pixeldata;
+pub mod projection;
+pub mod resample;
+pub mod rtdose;
+pub mod rtstruct;
+pub mod seg;
+pub mod sr;
+pub mod stats;
+#[cfg(test)]
+mod testsupport;
 pub mod workspace;
 
 /// General epsilon when comparing f32s which should be valid for most units within DICOM.
@@ -248,7 +272,64 @@ pub struct DicomVec {
     pub z: f32,
 }
 
-#[derive(Debug, Default)]
+impl DicomVec {
+    #[must_use]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    #[must_use]
+    pub fn dot(&self, other: &DicomVec) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[must_use]
+    pub fn cross(&self, other: &DicomVec) -> DicomVec {
+        DicomVec {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// This vector scaled to unit length, or `None` if its length is zero.
+    #[must_use]
+    pub fn normalized(&self) -> Option<DicomVec> {
+        let len = self.length();
+        (len > f32::EPSILON).then(|| *self * (1_f32 / len))
+    }
+}
+
+impl std::ops::Add for DicomVec {
+    type Output = DicomVec;
+
+    fn add(self, rhs: DicomVec) -> DicomVec {
+        DicomVec::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl std::ops::Sub for DicomVec {
+    type Output = DicomVec;
+
+    fn sub(self, rhs: DicomVec) -> DicomVec {
+        DicomVec::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl std::ops::Mul<f32> for DicomVec {
+    type Output = DicomVec;
+
+    fn mul(self, rhs: f32) -> DicomVec {
+        DicomVec::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+#[derive(Debug)]
 pub struct VolDims {
     /// The coordinate in DICOM space of the volume's origin (top-left of first slice in z-axis).
     origin: DicomVec,
@@ -256,18 +337,54 @@ pub struct VolDims {
     counts: IndexVec,
     /// The distance in mm between voxels.
     voxel_dims: DicomVec,
+    /// The unit direction of increasing x, along a row, from Image Orientation (Patient).
+    row_dir: DicomVec,
+    /// The unit direction of increasing y, down a column, from Image Orientation (Patient).
+    col_dir: DicomVec,
+    /// The unit normal of the image plane, the cross product of `row_dir` and `col_dir`.
+    normal: DicomVec,
+}
+
+impl Default for VolDims {
+    fn default() -> Self {
+        Self {
+            origin: DicomVec::default(),
+            counts: IndexVec::default(),
+            voxel_dims: DicomVec::default(),
+            row_dir: DicomVec::new(1_f32, 0_f32, 0_f32),
+            col_dir: DicomVec::new(0_f32, 1_f32, 0_f32),
+            normal: DicomVec::new(0_f32, 0_f32, 1_f32),
+        }
+    }
 }
 
 impl VolDims {
+    /// Creates dimensions whose rows, columns and slices are along the x, y and z axes of the
+    /// patient coordinate system. See `with_orientation`.
     #[must_use]
     pub fn new(origin: DicomVec, counts: IndexVec, voxel_dims: DicomVec) -> Self {
         Self {
             origin,
             counts,
             voxel_dims,
+            ..Self::default()
         }
     }
 
+    /// Orients the rows and columns of the volume by the directions of Image Orientation
+    /// (Patient). The orientation is unchanged if the directions are zero or parallel.
+    #[must_use]
+    pub fn with_orientation(mut self, row_dir: DicomVec, col_dir: DicomVec) -> Self {
+        if let (Some(row), Some(col)) = (row_dir.normalized(), col_dir.normalized()) {
+            if let Some(normal) = row.cross(&col).normalized() {
+                self.row_dir = row;
+                self.col_dir = col;
+                self.normal = normal;
+            }
+        }
+        self
+    }
+
     /// Checks that a dimension value is valid. A dimension value should be a positive value
     /// greater than zero.
     #[must_use]
@@ -293,6 +410,25 @@ impl VolDims {
         self.voxel_dims
     }
 
+    /// The unit direction in patient space of increasing x, along a row.
+    #[must_use]
+    pub fn row_dir(&self) -> DicomVec {
+        self.row_dir
+    }
+
+    /// The unit direction in patient space of increasing y, down a column.
+    #[must_use]
+    pub fn col_dir(&self) -> DicomVec {
+        self.col_dir
+    }
+
+    /// The unit normal of the image plane. Slices are ordered by descending position along the
+    /// normal, so increasing z is in the opposite direction.
+    #[must_use]
+    pub fn normal(&self) -> DicomVec {
+        self.normal
+    }
+
     pub(crate) fn inc_z_count(&mut self) {
         self.counts.z += 1;
     }
@@ -313,14 +449,24 @@ impl VolDims {
             && (self.voxel_dims.z - other.voxel_dims.z).abs() < EPSILON_F32
     }
 
-    /// Converts indices for a pixel in the loaded volume into DICOM coordinate space.
+    /// Whether the image planes of one `VolDims` and another have the same orientation.
+    #[must_use]
+    pub fn orientation_matches(&self, other: &VolDims) -> bool {
+        (self.row_dir - other.row_dir).length() < EPSILON_F32
+            && (self.col_dir - other.col_dir).length() < EPSILON_F32
+    }
+
+    /// Converts indices for a pixel in the loaded volume into DICOM coordinate space, using the
+    /// orientation of the image plane and stepping between slices by the slice spacing. See
+    /// `ImageVolume::voxel_to_patient` for the transform using the slices' actual positions.
     #[must_use]
     pub fn coordinate(&self, pos: IndexVec) -> DicomVec {
-        let mut coordinate = self.origin();
-        coordinate.x += f32::from(pos.x as u16) * self.voxel_dims.x;
-        coordinate.y += f32::from(pos.y as u16) * self.voxel_dims.y;
-        coordinate.z += f32::from(pos.z as u16) * self.voxel_dims.z;
-        coordinate
+        #[allow(clippy::cast_precision_loss)]
+        let (x, y, z) = (pos.x as f32, pos.y as f32, pos.z as f32);
+        self.origin
+            + self.row_dir * (x * self.voxel_dims.x)
+            + self.col_dir * (y * self.voxel_dims.y)
+            - self.normal * (z * self.voxel_dims.z)
     }
 }
 
@@ -350,6 +496,46 @@ pub enum VolAxis {
     Z,
 }
 
+impl VolAxis {
+    /// The coordinate within a volume of the given counts of the pixel at `index` in a slice
+    /// oriented to this axis, where pixels are ordered by row then column. Returns `None` if the
+    /// index is beyond the slice.
+    #[must_use]
+    pub fn slice_coord(
+        &self,
+        counts: &IndexVec,
+        axis_index: usize,
+        index: usize,
+    ) -> Option<IndexVec> {
+        let (cols, rows) = match self {
+            VolAxis::X => (counts.y, counts.z),
+            VolAxis::Y => (counts.x, counts.z),
+            VolAxis::Z => (counts.x, counts.y),
+        };
+        if index >= rows * cols {
+            return None;
+        }
+        let (col, row) = (index % cols, index / cols);
+        Some(match self {
+            VolAxis::X => IndexVec {
+                x: axis_index,
+                y: col,
+                z: row,
+            },
+            VolAxis::Y => IndexVec {
+                x: col,
+                y: axis_index,
+                z: row,
+            },
+            VolAxis::Z => IndexVec {
+                x: col,
+                y: row,
+                z: axis_index,
+            },
+        })
+    }
+}
+
 impl std::fmt::Display for VolAxis {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -368,3 +554,90 @@ pub struct VolPixel {
     pub g: f32,
     pub b: f32,
 }
+
+/// A coded concept from an item of a Code Sequence, such as the property or anatomic region of a
+/// segment.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Code {
+    /// The Code Value, or the Long Code Value or URN Code Value if it is not present.
+    pub value: String,
+    pub scheme: String,
+    pub meaning: String,
+}
+
+impl Code {
+    /// Reads the code from an item of a Code Sequence. Returns `None` if the item has no code
+    /// value.
+    #[must_use]
+    pub fn from_item(item: &DicomObject) -> Option<Self> {
+        let string = |tag: &Tag| {
+            item.get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+        };
+        let value = [&tags::CodeValue, &tags::LongCodeValue, &tags::URNCodeValue]
+            .into_iter()
+            .find_map(string)?;
+        Some(Self {
+            value,
+            scheme: string(&tags::CodingSchemeDesignator).unwrap_or_default(),
+            meaning: string(&tags::CodeMeaning).unwrap_or_default(),
+        })
+    }
+
+    /// Reads the code from the first item of the given Code Sequence of an object.
+    #[must_use]
+    pub fn from_sequence(obj: &DicomObject, tag: &Tag) -> Option<Self> {
+        obj.get_child_by_tag(tag)
+            .and_then(|sq| sq.get_item_by_index(1))
+            .and_then(Self::from_item)
+    }
+
+    /// Creates a Code Sequence holding this code as its single item, encoded with the given
+    /// transfer syntax. The sequence and item are given explicit lengths.
+    ///
+    /// # Errors
+    /// - `ParseError` if the code's values cannot be encoded.
+    pub fn to_sequence(&self, tag: &Tag, ts: TSRef) -> Result<DicomObject, ParseError> {
+        let mut children: BTreeMap<u32, DicomObject> = BTreeMap::new();
+        for (child_tag, value) in [
+            (&tags::CodeValue, &self.value),
+            (&tags::CodingSchemeDesignator, &self.scheme),
+            (&tags::CodeMeaning, &self.meaning),
+        ] {
+            let vr = child_tag.implicit_vr().unwrap_or(&UN);
+            let mut elem = DicomElement::new_empty(child_tag, vr, ts);
+            elem.encode_val(RawValue::of_string(value))?;
+            children.insert(elem.tag(), DicomObject::new(elem));
+        }
+        let item_len = children
+            .values()
+            .map(|c| c.element().byte_size())
+            .sum::<usize>();
+        // The Item tag and its length are always 8 bytes, regardless of transfer syntax.
+        let sq_len = 8 + item_len;
+        let new_elem = |tag: u32, vr, len: usize| {
+            DicomElement::new(
+                tag,
+                vr,
+                ValueLength::Explicit(u32::try_from(len).unwrap_or_default()),
+                ts,
+                CSRef::default(),
+                Vec::with_capacity(0),
+                Vec::with_capacity(0),
+            )
+        };
+        let item = new_elem(ITEM, &UN, item_len);
+        let sq = new_elem(tag.num(), &vr::SQ, sq_len);
+        Ok(DicomObject::new_with_children(
+            sq,
+            BTreeMap::new(),
+            vec![DicomObject::new_with_children(item, children, Vec::new())],
+        ))
+    }
+}
+
+impl std::fmt::Display for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}, {})", self.meaning, self.scheme, self.value)
+    }
+}
@@ -0,0 +1,189 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The grayscale standard display pipeline of Part 3 Section C.11: the Modality LUT or rescale,
+//! then the VOI LUT or window, then the Presentation LUT Shape.
+
+use crate::load::pixeldata::{lut::Lut, winlevel::WindowLevel};
+
+/// Converts stored pixel values into modality values, such as Hounsfield Units, Part 3 Section
+/// C.11.1.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModalityTransform {
+    /// Rescale Slope and Rescale Intercept.
+    Rescale { slope: f32, intercept: f32 },
+    /// The first item of the Modality LUT Sequence.
+    Lut(Lut),
+}
+
+impl Default for ModalityTransform {
+    fn default() -> Self {
+        Self::Rescale {
+            slope: 1_f32,
+            intercept: 0_f32,
+        }
+    }
+}
+
+impl ModalityTransform {
+    /// Converts a stored value into a modality value.
+    #[must_use]
+    pub fn apply(&self, stored: f32) -> f32 {
+        match self {
+            Self::Rescale { slope, intercept } => stored * slope + intercept,
+            #[allow(clippy::cast_possible_truncation)]
+            Self::Lut(lut) => f32::from(lut.lookup(stored.round() as i64)),
+        }
+    }
+}
+
+/// Selects the range of modality values of interest, Part 3 Section C.11.2.
+#[derive(Debug, Clone)]
+pub enum VoiTransform {
+    /// Window Center and Window Width, applied using their VOI LUT Function.
+    Window(WindowLevel),
+    /// An item of the VOI LUT Sequence.
+    Lut(Lut),
+}
+
+impl VoiTransform {
+    /// Converts a modality value into the range `0.0..=1.0`.
+    #[must_use]
+    pub fn apply(&self, val: f32) -> f32 {
+        match self {
+            Self::Window(winlevel) => winlevel.with_out(0_f32, 1_f32).apply(val),
+            #[allow(clippy::cast_possible_truncation)]
+            Self::Lut(lut) => {
+                f32::from(lut.lookup(val.round() as i64)) / f32::from(lut.max_output().max(1))
+            }
+        }
+    }
+}
+
+/// The transforms applied to grayscale pixel values for display.
+#[derive(Debug, Clone)]
+pub struct GrayscalePipeline {
+    modality: ModalityTransform,
+    voi: VoiTransform,
+    inverse: bool,
+}
+
+impl GrayscalePipeline {
+    /// Creates a pipeline from its transforms.
+    ///
+    /// `inverse` - Whether the output is inverted, which is the case for a Presentation LUT Shape
+    ///             of `INVERSE`, or for `MONOCHROME1` images without a Presentation LUT Shape.
+    #[must_use]
+    pub fn new(modality: ModalityTransform, voi: VoiTransform, inverse: bool) -> Self {
+        Self {
+            modality,
+            voi,
+            inverse,
+        }
+    }
+
+    #[must_use]
+    pub fn modality_transform(&self) -> &ModalityTransform {
+        &self.modality
+    }
+
+    #[must_use]
+    pub fn voi_transform(&self) -> &VoiTransform {
+        &self.voi
+    }
+
+    #[must_use]
+    pub fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+
+    /// Converts a stored value into a modality value.
+    #[must_use]
+    pub fn modality(&self, stored: f32) -> f32 {
+        self.modality.apply(stored)
+    }
+
+    /// Converts a modality value into a display value in the range `out_min..=out_max`, by applying
+    /// the VOI transform and then the Presentation LUT Shape.
+    #[must_use]
+    pub fn display(&self, modality_val: f32, out_min: f32, out_max: f32) -> f32 {
+        let val = self.voi.apply(modality_val).clamp(0_f32, 1_f32);
+        let val = if self.inverse { 1_f32 - val } else { val };
+        val * (out_max - out_min) + out_min
+    }
+
+    /// Converts a stored value into a display value in the range `out_min..=out_max`, applying
+    /// every stage of the pipeline.
+    #[must_use]
+    pub fn apply(&self, stored: f32, out_min: f32, out_max: f32) -> f32 {
+        self.display(self.modality(stored), out_min, out_max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::load::pixeldata::{
+        display::{GrayscalePipeline, ModalityTransform, VoiTransform},
+        lut::{Lut, LutDescriptor},
+        winlevel::WindowLevel,
+    };
+
+    #[test]
+    pub fn test_window_pipeline() {
+        let modality = ModalityTransform::Rescale {
+            slope: 2_f32,
+            intercept: -100_f32,
+        };
+        let window = WindowLevel::new(String::new(), 0_f32, 100_f32, 0_f32, 0_f32);
+        let pipeline = GrayscalePipeline::new(modality, VoiTransform::Window(window), false);
+        assert!((pipeline.modality(50_f32) - 0_f32).abs() < f32::EPSILON);
+        assert!((pipeline.apply(0_f32, 0_f32, 255_f32) - 0_f32).abs() < f32::EPSILON);
+        assert!((pipeline.apply(100_f32, 0_f32, 255_f32) - 255_f32).abs() < f32::EPSILON);
+
+        // MONOCHROME1 inverts the output.
+        let pipeline = GrayscalePipeline::new(
+            pipeline.modality_transform().clone(),
+            pipeline.voi_transform().clone(),
+            true,
+        );
+        assert!((pipeline.apply(0_f32, 0_f32, 255_f32) - 255_f32).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    pub fn test_lut_pipeline() {
+        // A modality LUT doubling stored values 10..=13, then a non-linear 8-bit VOI LUT.
+        let modality = Lut::new(
+            LutDescriptor::from_values(&[4, 10, 16], false).unwrap(),
+            vec![20, 22, 24, 26],
+        )
+        .unwrap();
+        let voi = Lut::new(
+            LutDescriptor::from_values(&[3, 22, 8], false).unwrap(),
+            vec![0, 200, 255],
+        )
+        .unwrap();
+        let pipeline = GrayscalePipeline::new(
+            ModalityTransform::Lut(modality),
+            VoiTransform::Lut(voi),
+            false,
+        );
+        assert!((pipeline.modality(11_f32) - 22_f32).abs() < f32::EPSILON);
+        assert!((pipeline.apply(10_f32, 0_f32, 255_f32) - 0_f32).abs() < f32::EPSILON);
+        assert!((pipeline.apply(12_f32, 0_f32, 255_f32) - 255_f32).abs() < f32::EPSILON);
+        let display = pipeline.display(23_f32, 0_f32, 255_f32);
+        assert!((display - 200_f32).abs() < f32::EPSILON);
+    }
+}
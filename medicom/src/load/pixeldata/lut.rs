@@ -0,0 +1,343 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Lookup tables which map stored pixel values to output values, such as the Palette Color Lookup
+//! Tables of Part 3 Section C.7.6.3.1.5 and their segmented form in Section C.7.9.2.
+
+use crate::load::pixeldata::LoadError;
+
+/// Segment types of Segmented Palette Color Lookup Table Data.
+const DISCRETE_SEGMENT: u16 = 0;
+const LINEAR_SEGMENT: u16 = 1;
+const INDIRECT_SEGMENT: u16 = 2;
+
+/// The three values of a LUT Descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LutDescriptor {
+    /// The number of entries in the table.
+    pub num_entries: usize,
+    /// The first input value mapped by the table. Input values below this map to the first entry.
+    pub first_mapped: i32,
+    /// The number of bits of each entry.
+    pub bits: u16,
+}
+
+impl LutDescriptor {
+    /// Parses a LUT Descriptor from its three values. A number of entries of zero means 65536.
+    ///
+    /// `signed` - Whether the first mapped value is signed, which follows the Pixel Representation
+    ///            of the values being mapped.
+    ///
+    /// # Errors
+    /// - `InvalidLut` if there are not three values or the bits per entry is not 1 to 16.
+    pub fn from_values(vals: &[u16], signed: bool) -> Result<Self, LoadError> {
+        let [num_entries, first_mapped, bits] = vals else {
+            return Err(LoadError::InvalidLut(format!(
+                "LUT Descriptor has {} values, expected 3",
+                vals.len()
+            )));
+        };
+        if *bits == 0 || *bits > 16 {
+            return Err(LoadError::InvalidLut(format!(
+                "LUT Descriptor bits per entry of {bits} is not supported"
+            )));
+        }
+        let num_entries = if *num_entries == 0 {
+            65536
+        } else {
+            usize::from(*num_entries)
+        };
+        let first_mapped = if signed {
+            i32::from(i16::from_ne_bytes(first_mapped.to_ne_bytes()))
+        } else {
+            i32::from(*first_mapped)
+        };
+        Ok(Self {
+            num_entries,
+            first_mapped,
+            bits: *bits,
+        })
+    }
+}
+
+/// A lookup table of up to 16-bit entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lut {
+    descriptor: LutDescriptor,
+    entries: Vec<u16>,
+}
+
+impl Lut {
+    /// Creates a lookup table from its expanded entries.
+    ///
+    /// # Errors
+    /// - `InvalidLut` if the number of entries does not match the descriptor.
+    pub fn new(descriptor: LutDescriptor, entries: Vec<u16>) -> Result<Self, LoadError> {
+        if entries.len() != descriptor.num_entries {
+            return Err(LoadError::InvalidLut(format!(
+                "LUT has {} entries but its descriptor specifies {}",
+                entries.len(),
+                descriptor.num_entries
+            )));
+        }
+        Ok(Self {
+            descriptor,
+            entries,
+        })
+    }
+
+    /// Parses a lookup table from the bytes of its LUT Data. Tables with 8-bit entries are
+    /// expected to have one entry per byte, but tables which instead use one 16-bit word per entry
+    /// are also accepted.
+    ///
+    /// # Errors
+    /// - `InvalidLut` if the number of entries does not match the descriptor.
+    pub fn from_bytes(
+        descriptor: LutDescriptor,
+        bytes: &[u8],
+        big_endian: bool,
+    ) -> Result<Self, LoadError> {
+        if descriptor.bits <= 8 && bytes.len() < descriptor.num_entries * 2 {
+            let entries = bytes
+                .iter()
+                .take(descriptor.num_entries)
+                .map(|b| u16::from(*b))
+                .collect();
+            return Self::new(descriptor, entries);
+        }
+
+        let mut entries = Self::read_words(bytes, big_endian);
+        entries.truncate(descriptor.num_entries);
+        // 8-bit entries in 16-bit words are sometimes stored in the high byte.
+        if descriptor.bits <= 8 && entries.iter().any(|e| *e > 0xFF) {
+            for entry in &mut entries {
+                *entry >>= 8;
+            }
+        }
+        Self::new(descriptor, entries)
+    }
+
+    /// Parses a lookup table from the bytes of Segmented Palette Color Lookup Table Data, expanding
+    /// its discrete, linear and indirect segments.
+    ///
+    /// # Errors
+    /// - `InvalidLut` if a segment is malformed, or if the expanded number of entries does not
+    ///   match the descriptor. Expansion stops as soon as a segment would exceed the descriptor's
+    ///   number of entries.
+    pub fn from_segmented(
+        descriptor: LutDescriptor,
+        bytes: &[u8],
+        big_endian: bool,
+    ) -> Result<Self, LoadError> {
+        let data = Self::read_words(bytes, big_endian);
+        let mut entries = Vec::with_capacity(descriptor.num_entries);
+        Self::expand_segments(&data, 0, None, descriptor.num_entries, &mut entries)?;
+        Self::new(descriptor, entries)
+    }
+
+    #[must_use]
+    pub fn descriptor(&self) -> &LutDescriptor {
+        &self.descriptor
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> &[u16] {
+        &self.entries
+    }
+
+    /// The largest value an entry may have, based on the bits per entry.
+    #[must_use]
+    pub fn max_output(&self) -> u16 {
+        u16::MAX >> (16 - self.descriptor.bits)
+    }
+
+    /// Maps an input value through the table. Values below the first mapped value map to the first
+    /// entry, and values beyond the last mapped value map to the last entry.
+    #[must_use]
+    pub fn lookup(&self, val: i64) -> u16 {
+        let index = val - i64::from(self.descriptor.first_mapped);
+        let last = self.entries.len().saturating_sub(1);
+        let index = usize::try_from(index).unwrap_or(0).min(last);
+        self.entries.get(index).copied().unwrap_or_default()
+    }
+
+    fn read_words(bytes: &[u8], big_endian: bool) -> Vec<u16> {
+        bytes
+            .chunks_exact(2)
+            .map(|w| {
+                if big_endian {
+                    u16::from_be_bytes([w[0], w[1]])
+                } else {
+                    u16::from_le_bytes([w[0], w[1]])
+                }
+            })
+            .collect()
+    }
+
+    /// Expands the segments of segmented LUT data starting at word `pos`, appending to `entries`.
+    /// If `max_segments` is specified only that many segments are expanded, which is used for the
+    /// segments referenced by an indirect segment. No more than `max_entries` entries are expanded,
+    /// as indirect and linear segments can otherwise expand a few words into billions of entries.
+    fn expand_segments(
+        data: &[u16],
+        mut pos: usize,
+        max_segments: Option<usize>,
+        max_entries: usize,
+        entries: &mut Vec<u16>,
+    ) -> Result<(), LoadError> {
+        let mut num_segments = 0usize;
+        while pos < data.len() && max_segments.is_none_or(|max| num_segments < max) {
+            let (Some(opcode), Some(length)) = (data.get(pos), data.get(pos + 1)) else {
+                return Err(LoadError::InvalidLut("truncated segment header".to_owned()));
+            };
+            let length = usize::from(*length);
+            if matches!(*opcode, DISCRETE_SEGMENT | LINEAR_SEGMENT)
+                && entries.len() + length > max_entries
+            {
+                return Err(LoadError::InvalidLut(format!(
+                    "segments expand to more than the {max_entries} entries of the LUT Descriptor"
+                )));
+            }
+            match *opcode {
+                DISCRETE_SEGMENT => {
+                    let Some(values) = data.get(pos + 2..pos + 2 + length) else {
+                        return Err(LoadError::InvalidLut(
+                            "truncated discrete segment".to_owned(),
+                        ));
+                    };
+                    entries.extend_from_slice(values);
+                    pos += 2 + length;
+                }
+                LINEAR_SEGMENT => {
+                    let (Some(start), Some(end)) = (entries.last(), data.get(pos + 2)) else {
+                        return Err(LoadError::InvalidLut(
+                            "linear segment without a preceding value".to_owned(),
+                        ));
+                    };
+                    let (start, end) = (f64::from(*start), f64::from(*end));
+                    #[allow(clippy::cast_precision_loss)]
+                    let step = (end - start) / length as f64;
+                    for i in 1..=length {
+                        #[allow(
+                            clippy::cast_possible_truncation,
+                            clippy::cast_sign_loss,
+                            clippy::cast_precision_loss
+                        )]
+                        entries.push((start + step * i as f64).round() as u16);
+                    }
+                    pos += 3;
+                }
+                INDIRECT_SEGMENT => {
+                    if max_segments.is_some() {
+                        return Err(LoadError::InvalidLut(
+                            "indirect segment references another indirect segment".to_owned(),
+                        ));
+                    }
+                    let (Some(low), Some(high)) = (data.get(pos + 2), data.get(pos + 3)) else {
+                        return Err(LoadError::InvalidLut(
+                            "truncated indirect segment".to_owned(),
+                        ));
+                    };
+                    // The offset is in bytes, least significant word first.
+                    let offset = (u32::from(*high) << 16) | u32::from(*low);
+                    let offset = usize::try_from(offset / 2).unwrap_or(usize::MAX);
+                    if offset >= pos {
+                        return Err(LoadError::InvalidLut(
+                            "indirect segment does not reference a preceding segment".to_owned(),
+                        ));
+                    }
+                    Self::expand_segments(data, offset, Some(length), max_entries, entries)?;
+                    pos += 4;
+                }
+                other => {
+                    return Err(LoadError::InvalidLut(format!(
+                        "unknown segment type {other}"
+                    )));
+                }
+            }
+            num_segments += 1;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::load::pixeldata::{
+        lut::{Lut, LutDescriptor},
+        LoadError,
+    };
+
+    fn to_bytes(words: &[u16]) -> Vec<u8> {
+        words.iter().flat_map(|w| w.to_le_bytes()).collect()
+    }
+
+    #[test]
+    pub fn test_lut_lookup() {
+        let descriptor = LutDescriptor::from_values(&[4, 0xFFFE, 8], true).unwrap();
+        assert_eq!(-2, descriptor.first_mapped);
+        let lut = Lut::from_bytes(descriptor, &[10, 20, 30, 40], false).unwrap();
+        assert_eq!(10, lut.lookup(-100));
+        assert_eq!(20, lut.lookup(-1));
+        assert_eq!(40, lut.lookup(1));
+        assert_eq!(40, lut.lookup(100));
+        assert_eq!(255, lut.max_output());
+
+        // 8-bit entries stored one per word.
+        let words = Lut::from_bytes(descriptor, &to_bytes(&[10, 20, 30, 40]), false).unwrap();
+        assert_eq!(lut, words);
+
+        let descriptor = LutDescriptor::from_values(&[0, 0, 16], false).unwrap();
+        assert_eq!(65536, descriptor.num_entries);
+        assert!(Lut::from_bytes(descriptor, &to_bytes(&[1, 2]), false).is_err());
+    }
+
+    #[test]
+    pub fn test_segmented_lut() {
+        let data = [
+            // Discrete: 0, 10
+            0, 2, 0, 10, //
+            // Linear: 10 -> 40 over 3 entries
+            1, 3, 40, //
+            // Indirect: copy the 2 segments at byte offset 0
+            2, 2, 0, 0,
+        ];
+        let descriptor = LutDescriptor::from_values(&[10, 0, 16], false).unwrap();
+        let lut = Lut::from_segmented(descriptor, &to_bytes(&data), false).unwrap();
+        assert_eq!(&[0, 10, 20, 30, 40, 0, 10, 20, 30, 40], lut.entries());
+
+        let linear_first = [1, 2, 100];
+        let descriptor = LutDescriptor::from_values(&[2, 0, 16], false).unwrap();
+        assert!(Lut::from_segmented(descriptor, &to_bytes(&linear_first), false).is_err());
+    }
+
+    #[test]
+    pub fn test_segmented_lut_exceeds_entries() {
+        let descriptor = LutDescriptor::from_values(&[4, 0, 16], false).unwrap();
+        let discrete = [0, 5, 1, 2, 3, 4, 5];
+        assert!(matches!(
+            Lut::from_segmented(descriptor, &to_bytes(&discrete), false),
+            Err(LoadError::InvalidLut(_))
+        ));
+
+        // A linear segment of 65535 entries, replayed by indirect segments of 65535 segments.
+        let data = [0, 1, 0, 1, 0xFFFF, 0xFFFF, 2, 0xFFFF, 0, 0, 2, 0xFFFF, 0, 0];
+        assert!(matches!(
+            Lut::from_segmented(descriptor, &to_bytes(&data), false),
+            Err(LoadError::InvalidLut(_))
+        ));
+    }
+}
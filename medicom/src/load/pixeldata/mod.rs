@@ -15,6 +15,8 @@
 */
 
 use pdinfo::PixelDataSliceInfo;
+use pixel_f32::PixelDataSliceF32;
+use pixel_f64::PixelDataSliceF64;
 use pixel_i16::PixelDataSliceI16;
 use pixel_i32::PixelDataSliceI32;
 use pixel_i8::PixelDataSliceI8;
@@ -23,9 +25,15 @@ use pixel_u32::PixelDataSliceU32;
 use pixel_u8::PixelDataSliceU8;
 use thiserror::Error;
 
-use crate::core::{defn::vr::VRRef, read::ParseError};
+use crate::core::{codec::CodecError, defn::vr::VRRef, read::ParseError};
 
+pub mod display;
+pub mod lut;
+pub mod overlay;
+pub mod palette;
 pub mod pdinfo;
+pub mod pixel_f32;
+pub mod pixel_f64;
 pub mod pixel_i16;
 pub mod pixel_i32;
 pub mod pixel_i8;
@@ -33,6 +41,7 @@ pub mod pixel_u16;
 pub mod pixel_u32;
 pub mod pixel_u8;
 pub mod winlevel;
+pub mod ybr;
 
 #[derive(Error, Debug)]
 pub enum LoadError {
@@ -57,12 +66,45 @@ pub enum LoadError {
     #[error("Invalid Photometric Interpretation and Samples per Pixel combo: {0:?}, {1}")]
     InvalidPhotoInterpSamples(PhotoInterp, u16),
 
+    #[error("Invalid lookup table: {0}")]
+    InvalidLut(String),
+
+    #[error("Invalid overlay: {0}")]
+    InvalidOverlay(String),
+
+    #[error("Invalid projection: {0}")]
+    InvalidProjection(String),
+
+    #[error("Invalid region: {0}")]
+    InvalidRegion(String),
+
+    #[error("Invalid segmentation: {0}")]
+    InvalidSegmentation(String),
+
+    #[error("Invalid structure set: {0}")]
+    InvalidStructureSet(String),
+
+    #[error("Invalid structured report: {0}")]
+    InvalidStructuredReport(String),
+
+    #[error("Invalid encapsulated document: {0}")]
+    InvalidEncapsulatedDocument(String),
+
     #[error("Invalid source location to interpret pixel data: {0}")]
     InvalidPixelSource(usize),
 
     #[error("Slice format does not match others in volume. SOP: {0}, error: {1}")]
     InconsistentSliceFormat(String, String),
 
+    #[error("Unsupported Transfer Syntax for Pixel Data: {0}")]
+    UnsupportedTransferSyntax(String),
+
+    #[error("Error decoding encapsulated Pixel Data")]
+    CodecError {
+        #[from]
+        source: CodecError,
+    },
+
     #[error("Error parsing DICOM")]
     ParseError {
         #[from]
@@ -98,6 +140,13 @@ pub enum PhotoInterp {
     Rgb,
     Monochrome1,
     Monochrome2,
+    PaletteColor,
+    YbrFull,
+    YbrFull422,
+    YbrPartial422,
+    YbrPartial420,
+    YbrIct,
+    YbrRct,
 }
 
 impl PhotoInterp {
@@ -112,19 +161,38 @@ impl PhotoInterp {
     pub fn is_monochrome(&self) -> bool {
         *self == PhotoInterp::Monochrome1 || *self == PhotoInterp::Monochrome2
     }
+
+    /// Whether this `PhotoInterp` is one of the YBR color spaces, which are converted to RGB when
+    /// loaded.
+    #[must_use]
+    pub fn is_ybr(&self) -> bool {
+        matches!(
+            self,
+            PhotoInterp::YbrFull
+                | PhotoInterp::YbrFull422
+                | PhotoInterp::YbrPartial422
+                | PhotoInterp::YbrPartial420
+                | PhotoInterp::YbrIct
+                | PhotoInterp::YbrRct
+        )
+    }
 }
 
 impl From<&str> for PhotoInterp {
     /// Parse Photometric Interpretation from its DICOM element value.
     fn from(value: &str) -> Self {
-        if value == "RGB" {
-            Self::Rgb
-        } else if value == "MONOCHROME1" {
-            Self::Monochrome1
-        } else if value == "MONOCHROME2" {
-            Self::Monochrome2
-        } else {
-            Self::Unsupported(value.to_owned())
+        match value {
+            "RGB" => Self::Rgb,
+            "MONOCHROME1" => Self::Monochrome1,
+            "MONOCHROME2" => Self::Monochrome2,
+            "PALETTE COLOR" => Self::PaletteColor,
+            "YBR_FULL" => Self::YbrFull,
+            "YBR_FULL_422" => Self::YbrFull422,
+            "YBR_PARTIAL_422" => Self::YbrPartial422,
+            "YBR_PARTIAL_420" => Self::YbrPartial420,
+            "YBR_ICT" => Self::YbrIct,
+            "YBR_RCT" => Self::YbrRct,
+            _ => Self::Unsupported(value.to_owned()),
         }
     }
 }
@@ -133,9 +201,13 @@ impl From<&str> for PhotoInterp {
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub enum BitsAlloc {
     Unsupported(u16),
+    /// Only valid for BINARY Segmentations, which are unpacked to `Eight` when loaded.
+    One,
     Eight,
     Sixteen,
     ThirtyTwo,
+    /// Only valid for Double Float Pixel Data.
+    SixtyFour,
 }
 
 impl std::fmt::Display for BitsAlloc {
@@ -148,9 +220,11 @@ impl std::fmt::Debug for BitsAlloc {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Unsupported(other) => write!(f, "BitsAlloc(Unsupported:{other})"),
+            Self::One => write!(f, "BitsAlloc(1)"),
             Self::Eight => write!(f, "BitsAlloc(8)"),
             Self::Sixteen => write!(f, "BitsAlloc(16)"),
             Self::ThirtyTwo => write!(f, "BitsAlloc(32)"),
+            Self::SixtyFour => write!(f, "BitsAlloc(64)"),
         }
     }
 }
@@ -160,9 +234,11 @@ impl BitsAlloc {
     #[must_use]
     pub fn from_val(val: u16) -> Self {
         match val {
+            1 => BitsAlloc::One,
             8 => BitsAlloc::Eight,
             16 => BitsAlloc::Sixteen,
             32 => BitsAlloc::ThirtyTwo,
+            64 => BitsAlloc::SixtyFour,
             other => BitsAlloc::Unsupported(other),
         }
     }
@@ -171,9 +247,11 @@ impl BitsAlloc {
     pub fn val(&self) -> u16 {
         match self {
             Self::Unsupported(val) => *val,
+            Self::One => 1,
             Self::Eight => 8,
             Self::Sixteen => 16,
             Self::ThirtyTwo => 32,
+            Self::SixtyFour => 64,
         }
     }
 }
@@ -187,6 +265,8 @@ pub enum PixelDataSlice {
     U16(PixelDataSliceU16),
     I32(PixelDataSliceI32),
     U32(PixelDataSliceU32),
+    F32(PixelDataSliceF32),
+    F64(PixelDataSliceF64),
 }
 
 impl PixelDataSlice {
@@ -199,6 +279,8 @@ impl PixelDataSlice {
             PixelDataSlice::U16(pds) => pds.info(),
             PixelDataSlice::I32(pds) => pds.info(),
             PixelDataSlice::U32(pds) => pds.info(),
+            PixelDataSlice::F32(pds) => pds.info(),
+            PixelDataSlice::F64(pds) => pds.info(),
         }
     }
 
@@ -224,9 +306,125 @@ impl PixelDataSlice {
     }
 }
 
+/// The pixel values of a single slice, kept in the native sample type of its `PixelData` so that
+/// values are not truncated, such as unsigned 16-bit values above `i16::MAX`, 32-bit dose values, or
+/// the real values of Float and Double Float Pixel Data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SliceBuffer {
+    U8(Vec<u8>),
+    I16(Vec<i16>),
+    U16(Vec<u16>),
+    I32(Vec<i32>),
+    U32(Vec<u32>),
+    F32(Vec<f32>),
+    F64(Vec<f64>),
+}
+
+impl SliceBuffer {
+    /// The number of samples in the buffer.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::U8(buf) => buf.len(),
+            Self::I16(buf) => buf.len(),
+            Self::U16(buf) => buf.len(),
+            Self::I32(buf) => buf.len(),
+            Self::U32(buf) => buf.len(),
+            Self::F32(buf) => buf.len(),
+            Self::F64(buf) => buf.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The size in bytes of a single sample.
+    #[must_use]
+    pub fn sample_size(&self) -> usize {
+        match self {
+            Self::U8(_) => std::mem::size_of::<u8>(),
+            Self::I16(_) => std::mem::size_of::<i16>(),
+            Self::U16(_) => std::mem::size_of::<u16>(),
+            Self::I32(_) => std::mem::size_of::<i32>(),
+            Self::U32(_) => std::mem::size_of::<u32>(),
+            Self::F32(_) => std::mem::size_of::<f32>(),
+            Self::F64(_) => std::mem::size_of::<f64>(),
+        }
+    }
+
+    /// The size in bytes of all samples in the buffer.
+    #[must_use]
+    pub fn byte_size(&self) -> usize {
+        self.len() * self.sample_size()
+    }
+
+    /// The sample at the given index. All sample types are exactly representable as `f64`.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<f64> {
+        match self {
+            Self::U8(buf) => buf.get(index).copied().map(f64::from),
+            Self::I16(buf) => buf.get(index).copied().map(f64::from),
+            Self::U16(buf) => buf.get(index).copied().map(f64::from),
+            Self::I32(buf) => buf.get(index).copied().map(f64::from),
+            Self::U32(buf) => buf.get(index).copied().map(f64::from),
+            Self::F32(buf) => buf.get(index).copied().map(f64::from),
+            Self::F64(buf) => buf.get(index).copied(),
+        }
+    }
+
+    /// Iterates over the samples in the buffer.
+    pub fn iter(&self) -> impl Iterator<Item = f64> + '_ {
+        (0..self.len()).filter_map(|i| self.get(i))
+    }
+}
+
+impl From<Vec<u8>> for SliceBuffer {
+    fn from(value: Vec<u8>) -> Self {
+        Self::U8(value)
+    }
+}
+
+impl From<Vec<i16>> for SliceBuffer {
+    fn from(value: Vec<i16>) -> Self {
+        Self::I16(value)
+    }
+}
+
+impl From<Vec<u16>> for SliceBuffer {
+    fn from(value: Vec<u16>) -> Self {
+        Self::U16(value)
+    }
+}
+
+impl From<Vec<i32>> for SliceBuffer {
+    fn from(value: Vec<i32>) -> Self {
+        Self::I32(value)
+    }
+}
+
+impl From<Vec<u32>> for SliceBuffer {
+    fn from(value: Vec<u32>) -> Self {
+        Self::U32(value)
+    }
+}
+
+impl From<Vec<f32>> for SliceBuffer {
+    fn from(value: Vec<f32>) -> Self {
+        Self::F32(value)
+    }
+}
+
+impl From<Vec<f64>> for SliceBuffer {
+    fn from(value: Vec<f64>) -> Self {
+        Self::F64(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::load::pixeldata::PixelDataSlice;
+    use crate::load::pixeldata::{PixelDataSlice, SliceBuffer};
 
     #[test]
     pub fn test_shift_i8() {
@@ -260,4 +458,19 @@ mod tests {
         assert_eq!(4294967294u32, PixelDataSlice::shift_i32(i32::MAX - 1));
         assert_eq!(4294967295u32, PixelDataSlice::shift_i32(i32::MAX));
     }
+
+    #[test]
+    pub fn test_slice_buffer_values() {
+        let u16s = SliceBuffer::from(vec![0u16, 40_000, u16::MAX]);
+        assert_eq!(Some(40_000_f64), u16s.get(1));
+        assert_eq!(6, u16s.byte_size());
+        assert!(u16s.get(3).is_none());
+
+        let u32s = SliceBuffer::from(vec![u32::MAX]);
+        assert_eq!(Some(f64::from(u32::MAX)), u32s.get(0));
+
+        let f32s = SliceBuffer::from(vec![-0.5_f32, 2.25]);
+        assert_eq!(vec![-0.5, 2.25], f32s.iter().collect::<Vec<f64>>());
+        assert_eq!(8, f32s.byte_size());
+    }
 }
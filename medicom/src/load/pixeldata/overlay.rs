@@ -0,0 +1,496 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Overlay Planes of Part 3 Section C.9.2, bitmaps of graphics or regions of interest stored in the
+//! repeating groups 6000-601E, either in Overlay Data or in the unused high bits of Pixel Data.
+
+use crate::{
+    core::{codec::FrameLayout, dcmobject::DicomRoot, defn::tag::Tag, defn::vr},
+    dict::tags,
+    load::pixeldata::LoadError,
+};
+
+/// The first of the overlay groups, which are the even groups 6000-601E.
+const FIRST_GROUP: u16 = 0x6000;
+/// The number of overlay groups.
+const NUM_GROUPS: u16 = 16;
+
+/// A single Overlay Plane, with a bitmap for each frame it applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overlay {
+    group: u16,
+    rows: u16,
+    cols: u16,
+    origin: (i32, i32),
+    overlay_type: String,
+    label: String,
+    description: String,
+    frame_origin: usize,
+    num_frames: usize,
+    bits: Vec<bool>,
+}
+
+impl Overlay {
+    /// Reads the Overlay Planes of the dataset. Overlays in Overlay Data are unpacked, and overlays
+    /// embedded in the unused high bits of Pixel Data are read from `pd_bytes`. The embedded bits
+    /// are cleared from `pd_bytes` so they are not interpreted as part of the pixel values. Overlay
+    /// groups which are not valid are skipped, so they do not prevent loading the image.
+    ///
+    /// `pd_bytes` - The native Pixel Data of all frames.
+    /// `layout` - The layout of a single frame of `pd_bytes`.
+    /// `high_bit` - The High Bit of the Pixel Data samples.
+    /// `big_endian` - Whether `pd_bytes` and Overlay Data are big-endian.
+    #[must_use]
+    pub fn read_all(
+        dcmroot: &DicomRoot,
+        pd_bytes: &mut [u8],
+        layout: &FrameLayout,
+        high_bit: u16,
+        big_endian: bool,
+    ) -> Vec<Overlay> {
+        (0..NUM_GROUPS)
+            .map(|i| FIRST_GROUP + i * 2)
+            .filter_map(|group| {
+                Self::read(dcmroot, group, pd_bytes, layout, high_bit, big_endian)
+                    .ok()
+                    .flatten()
+            })
+            .collect()
+    }
+
+    /// Reads the Overlay Plane in the given group of the dataset, returning `None` if the group has
+    /// no overlay. See `read_all`.
+    ///
+    /// # Errors
+    /// - `InvalidOverlay` if Overlay Data is shorter than its rows, columns and frames require, or
+    ///   if an embedded overlay does not match the layout of the Pixel Data or is not above its
+    ///   High Bit. `pd_bytes` is not modified in this case.
+    pub fn read(
+        dcmroot: &DicomRoot,
+        group: u16,
+        pd_bytes: &mut [u8],
+        layout: &FrameLayout,
+        high_bit: u16,
+        big_endian: bool,
+    ) -> Result<Option<Overlay>, LoadError> {
+        let tag = |tag: &Tag| (u32::from(group) << 16) | (tag.num() & 0x0000_FFFF);
+        let (Some(rows), Some(cols)) = (
+            dcmroot
+                .get_value_by_tag(tag(&tags::OverlayRows))
+                .and_then(|v| v.ushort()),
+            dcmroot
+                .get_value_by_tag(tag(&tags::OverlayColumns))
+                .and_then(|v| v.ushort()),
+        ) else {
+            return Ok(None);
+        };
+        let origin = dcmroot
+            .get_value_by_tag(tag(&tags::OverlayOrigin))
+            .map(|v| v.shorts())
+            .filter(|vals| vals.len() == 2)
+            .map_or((1, 1), |vals| (i32::from(vals[0]), i32::from(vals[1])));
+        let string = |t: &Tag| {
+            dcmroot
+                .get_value_by_tag(tag(t))
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .unwrap_or_default()
+        };
+        let num_frames = dcmroot
+            .get_value_by_tag(tag(&tags::NumberofFramesinOverlay))
+            .and_then(|v| v.int())
+            .and_then(|v| usize::try_from(v).ok())
+            .unwrap_or(1)
+            .max(1);
+        let frame_origin = dcmroot
+            .get_value_by_tag(tag(&tags::ImageFrameOrigin))
+            .and_then(|v| v.ushort())
+            .map_or(1, usize::from)
+            .max(1);
+        let mut overlay = Overlay {
+            group,
+            rows,
+            cols,
+            origin,
+            overlay_type: string(&tags::OverlayType),
+            label: string(&tags::OverlayLabel),
+            description: string(&tags::OverlayDescription),
+            frame_origin,
+            num_frames,
+            bits: Vec::with_capacity(0),
+        };
+
+        if let Some(obj) = dcmroot.get_child_by_tag(tag(&tags::OverlayData)) {
+            let elem = obj.element();
+            let swap = big_endian && elem.vr() == &vr::OW;
+            overlay.bits = overlay.unpack(elem.data(), swap)?;
+        } else {
+            let bits_alloc = dcmroot
+                .get_value_by_tag(tag(&tags::OverlayBitsAllocated))
+                .and_then(|v| v.ushort())
+                .unwrap_or(1);
+            let bit_pos = dcmroot
+                .get_value_by_tag(tag(&tags::OverlayBitPosition))
+                .and_then(|v| v.ushort())
+                .unwrap_or(0);
+            if bits_alloc <= 1 {
+                return Ok(None);
+            }
+            overlay
+                .extract_embedded(pd_bytes, layout, bits_alloc, bit_pos, high_bit, big_endian)?;
+        }
+        Ok(Some(overlay))
+    }
+
+    /// The overlay group, e.g. `0x6000`.
+    #[must_use]
+    pub fn group(&self) -> u16 {
+        self.group
+    }
+
+    #[must_use]
+    pub fn rows(&self) -> u16 {
+        self.rows
+    }
+
+    #[must_use]
+    pub fn cols(&self) -> u16 {
+        self.cols
+    }
+
+    /// The 1-based row and column of the image at which the overlay's first pixel is located.
+    #[must_use]
+    pub fn origin(&self) -> (i32, i32) {
+        self.origin
+    }
+
+    /// The Overlay Type, `G` for graphics or `R` for a region of interest.
+    #[must_use]
+    pub fn overlay_type(&self) -> &str {
+        &self.overlay_type
+    }
+
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// The 1-based index of the first image frame this overlay applies to.
+    #[must_use]
+    pub fn frame_origin(&self) -> usize {
+        self.frame_origin
+    }
+
+    #[must_use]
+    pub fn num_frames(&self) -> usize {
+        self.num_frames
+    }
+
+    /// The overlay's bitmap for the given 0-based image frame, in the overlay's rows and columns.
+    #[must_use]
+    pub fn frame_bits(&self, frame: usize) -> Option<&[bool]> {
+        let index = (frame + 1).checked_sub(self.frame_origin)?;
+        if index >= self.num_frames {
+            return None;
+        }
+        let len = self.pixel_count();
+        self.bits.get(index * len..(index + 1) * len)
+    }
+
+    /// Whether the overlay is set at the given 0-based row and column of the given image frame.
+    #[must_use]
+    pub fn is_set(&self, frame: usize, row: usize, col: usize) -> bool {
+        let Some(bits) = self.frame_bits(frame) else {
+            return false;
+        };
+        let offset = |pos: usize, origin: i32| {
+            i64::try_from(pos)
+                .ok()
+                .and_then(|pos| usize::try_from(pos - i64::from(origin) + 1).ok())
+        };
+        match (offset(row, self.origin.0), offset(col, self.origin.1)) {
+            (Some(row), Some(col))
+                if row < usize::from(self.rows) && col < usize::from(self.cols) =>
+            {
+                bits[row * usize::from(self.cols) + col]
+            }
+            _ => false,
+        }
+    }
+
+    /// The overlay's bitmap for the given 0-based image frame, positioned by its origin within an
+    /// image of the given rows and columns. Overlay pixels outside the image are dropped.
+    #[must_use]
+    pub fn frame_bitmap(&self, frame: usize, rows: u16, cols: u16) -> Option<Vec<bool>> {
+        self.frame_bits(frame)?;
+        let (rows, cols) = (usize::from(rows), usize::from(cols));
+        let mut bitmap = vec![false; rows * cols];
+        for (index, set) in bitmap.iter_mut().enumerate() {
+            *set = self.is_set(frame, index / cols, index % cols);
+        }
+        Some(bitmap)
+    }
+
+    /// A copy of this overlay holding only the bitmap of the given 0-based image frame, for the
+    /// slice of that frame split from a multi-frame dataset.
+    #[must_use]
+    pub fn for_frame(&self, frame: usize) -> Option<Overlay> {
+        let bits = self.frame_bits(frame)?.to_vec();
+        Some(Overlay {
+            group: self.group,
+            rows: self.rows,
+            cols: self.cols,
+            origin: self.origin,
+            overlay_type: self.overlay_type.clone(),
+            label: self.label.clone(),
+            description: self.description.clone(),
+            frame_origin: 1,
+            num_frames: 1,
+            bits,
+        })
+    }
+
+    fn pixel_count(&self) -> usize {
+        usize::from(self.rows) * usize::from(self.cols)
+    }
+
+    /// Unpacks Overlay Data, where each bit is a pixel starting from the least significant bit of
+    /// the first byte. If `swap` the data is big-endian words which are first swapped.
+    fn unpack(&self, data: &[u8], swap: bool) -> Result<Vec<bool>, LoadError> {
+        let num_bits = self.pixel_count() * self.num_frames;
+        if data.len() * 8 < num_bits {
+            return Err(LoadError::InvalidOverlay(format!(
+                "group {:04X} has {} bytes of Overlay Data for {num_bits} pixels",
+                self.group,
+                data.len()
+            )));
+        }
+        Ok((0..num_bits)
+            .map(|bit| {
+                let byte = if swap { (bit / 8) ^ 1 } else { bit / 8 };
+                data.get(byte).is_some_and(|b| (b >> (bit % 8)) & 1 == 1)
+            })
+            .collect())
+    }
+
+    /// Extracts an overlay embedded in bit `bit_pos` of each Pixel Data sample, clearing the bit.
+    fn extract_embedded(
+        &mut self,
+        pd_bytes: &mut [u8],
+        layout: &FrameLayout,
+        bits_alloc: u16,
+        bit_pos: u16,
+        high_bit: u16,
+        big_endian: bool,
+    ) -> Result<(), LoadError> {
+        let sample_size = layout.bytes_per_sample();
+        // A bit at or below the High Bit holds part of the pixel value, which must not be cleared.
+        if bits_alloc != layout.bits_alloc
+            || bit_pos >= bits_alloc
+            || bit_pos <= high_bit
+            || layout.samples_per_pixel != 1
+            || (self.rows, self.cols) != (layout.rows, layout.cols)
+            || !(1..=4).contains(&sample_size)
+        {
+            return Err(LoadError::InvalidOverlay(format!(
+                "group {:04X} is embedded in bit {bit_pos} of {bits_alloc} but Pixel Data is {}x{} with {} bits allocated and high bit {high_bit}",
+                self.group, layout.cols, layout.rows, layout.bits_alloc
+            )));
+        }
+
+        let mask = 1u32 << bit_pos;
+        let mut bits = Vec::with_capacity(pd_bytes.len() / sample_size);
+        for sample in pd_bytes.chunks_exact_mut(sample_size) {
+            let mut word = [0u8; 4];
+            if big_endian {
+                word[4 - sample_size..].copy_from_slice(sample);
+            } else {
+                word[..sample_size].copy_from_slice(sample);
+            }
+            let val = if big_endian {
+                u32::from_be_bytes(word)
+            } else {
+                u32::from_le_bytes(word)
+            };
+            bits.push(val & mask != 0);
+            let cleared = val & !mask;
+            if big_endian {
+                sample.copy_from_slice(&cleared.to_be_bytes()[4 - sample_size..]);
+            } else {
+                sample.copy_from_slice(&cleared.to_le_bytes()[..sample_size]);
+            }
+        }
+
+        // Embedded overlays apply to every frame of the Pixel Data.
+        self.frame_origin = 1;
+        self.num_frames = bits.len() / self.pixel_count().max(1);
+        self.bits = bits;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            charset::CSRef, codec::FrameLayout, dcmobject::DicomRoot,
+            defn::constants::ts::ExplicitVRLittleEndian, defn::tag::Tag, values::RawValue,
+        },
+        dict::tags,
+        load::pixeldata::{overlay::Overlay, LoadError},
+    };
+
+    /// The tag of the given overlay element in the given overlay group.
+    fn group_tag(group: u32, tag: &Tag) -> Tag {
+        Tag::new(
+            tag.ident(),
+            (group << 16) | (tag.num() & 0xFFFF),
+            tag.implicit_vr(),
+            tag.vm(),
+            tag.desc(),
+        )
+    }
+
+    const LAYOUT: FrameLayout = FrameLayout {
+        rows: 2,
+        cols: 4,
+        samples_per_pixel: 1,
+        bits_alloc: 16,
+        bits_stored: 12,
+        pixel_rep: 0,
+    };
+
+    #[test]
+    pub fn test_overlay_data() {
+        let mut dcmroot = DicomRoot::new_empty(&ExplicitVRLittleEndian, CSRef::default());
+        let add = |dcmroot: &mut DicomRoot, tag: &Tag, val: RawValue| {
+            dcmroot.add_child_with_val(&group_tag(0x6002, tag), val);
+        };
+        add(&mut dcmroot, &tags::OverlayRows, RawValue::of_ushort(2));
+        add(&mut dcmroot, &tags::OverlayColumns, RawValue::of_ushort(3));
+        add(
+            &mut dcmroot,
+            &tags::OverlayOrigin,
+            RawValue::Shorts(vec![1, 2]),
+        );
+        add(&mut dcmroot, &tags::OverlayType, RawValue::of_string("G"));
+        add(
+            &mut dcmroot,
+            &tags::OverlayBitsAllocated,
+            RawValue::of_ushort(1),
+        );
+        // Pixels 0 and 4 of the 2x3 overlay, LSB first.
+        add(
+            &mut dcmroot,
+            &tags::OverlayData,
+            RawValue::Bytes(vec![0b0001_0001, 0]),
+        );
+
+        let mut pd_bytes = vec![0u8; LAYOUT.frame_len()];
+        let overlays = Overlay::read_all(&dcmroot, &mut pd_bytes, &LAYOUT, 11, false);
+        assert_eq!(1, overlays.len());
+        let overlay = &overlays[0];
+        assert_eq!(0x6002, overlay.group());
+        assert_eq!("G", overlay.overlay_type());
+        assert_eq!(
+            Some(&[true, false, false, false, true, false][..]),
+            overlay.frame_bits(0)
+        );
+        assert!(overlay.frame_bits(1).is_none());
+
+        // The origin shifts the overlay one column right within the image.
+        let bitmap = overlay.frame_bitmap(0, LAYOUT.rows, LAYOUT.cols).unwrap();
+        assert_eq!(
+            vec![false, true, false, false, false, false, true, false],
+            bitmap
+        );
+        assert!(overlay.is_set(0, 1, 2));
+        assert!(!overlay.is_set(0, 0, 0));
+    }
+
+    #[test]
+    pub fn test_embedded_overlay() {
+        let mut dcmroot = DicomRoot::new_empty(&ExplicitVRLittleEndian, CSRef::default());
+        let add = |dcmroot: &mut DicomRoot, tag: &Tag, val: RawValue| {
+            dcmroot.add_child_with_val(&group_tag(0x6000, tag), val);
+        };
+        add(&mut dcmroot, &tags::OverlayRows, RawValue::of_ushort(2));
+        add(&mut dcmroot, &tags::OverlayColumns, RawValue::of_ushort(4));
+        add(
+            &mut dcmroot,
+            &tags::OverlayBitsAllocated,
+            RawValue::of_ushort(16),
+        );
+        add(
+            &mut dcmroot,
+            &tags::OverlayBitPosition,
+            RawValue::of_ushort(15),
+        );
+
+        let vals = [0x8001u16, 2, 3, 0x8004, 5, 6, 7, 8];
+        let mut pd_bytes = vals
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let overlays = Overlay::read_all(&dcmroot, &mut pd_bytes, &LAYOUT, 11, false);
+        assert_eq!(1, overlays.len());
+        assert_eq!(
+            Some(&[true, false, false, true, false, false, false, false][..]),
+            overlays[0].frame_bits(0)
+        );
+        // The overlay bits are cleared from the pixel values.
+        assert_eq!([1, 0], pd_bytes[..2]);
+        assert_eq!([4, 0], pd_bytes[6..8]);
+    }
+
+    #[test]
+    pub fn test_embedded_overlay_within_high_bit() {
+        let mut dcmroot = DicomRoot::new_empty(&ExplicitVRLittleEndian, CSRef::default());
+        let add = |dcmroot: &mut DicomRoot, tag: &Tag, val: RawValue| {
+            dcmroot.add_child_with_val(&group_tag(0x6000, tag), val);
+        };
+        add(&mut dcmroot, &tags::OverlayRows, RawValue::of_ushort(2));
+        add(&mut dcmroot, &tags::OverlayColumns, RawValue::of_ushort(4));
+        add(
+            &mut dcmroot,
+            &tags::OverlayBitsAllocated,
+            RawValue::of_ushort(16),
+        );
+        // Bit 11 is the High Bit of the 12-bit samples, so it is part of the pixel values.
+        add(
+            &mut dcmroot,
+            &tags::OverlayBitPosition,
+            RawValue::of_ushort(11),
+        );
+
+        let vals = [0x0801u16, 2, 3, 0x0FFF, 5, 6, 7, 8];
+        let mut pd_bytes = vals
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let expected = pd_bytes.clone();
+        assert!(matches!(
+            Overlay::read(&dcmroot, 0x6000, &mut pd_bytes, &LAYOUT, 11, false),
+            Err(LoadError::InvalidOverlay(_))
+        ));
+        assert!(Overlay::read_all(&dcmroot, &mut pd_bytes, &LAYOUT, 11, false).is_empty());
+        assert_eq!(expected, pd_bytes);
+    }
+}
@@ -0,0 +1,196 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Palette Color Lookup Tables, Part 3 Section C.7.6.3.1.5, which map the stored values of
+//! `PALETTE COLOR` images to RGB.
+
+use crate::{
+    core::{dcmobject::DicomRoot, defn::tag::Tag},
+    dict::tags,
+    load::pixeldata::{
+        lut::{Lut, LutDescriptor},
+        LoadError,
+    },
+};
+
+/// The red, green and blue lookup tables of a `PALETTE COLOR` image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteColorLut {
+    red: Lut,
+    green: Lut,
+    blue: Lut,
+}
+
+impl PaletteColorLut {
+    #[must_use]
+    pub fn new(red: Lut, green: Lut, blue: Lut) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Reads the palette from the Red, Green and Blue Palette Color Lookup Table Descriptors, and
+    /// either their Palette Color Lookup Table Data or Segmented Palette Color Lookup Table Data.
+    ///
+    /// `signed` - Whether the stored pixel values are signed, per Pixel Representation.
+    ///
+    /// # Errors
+    /// - `InvalidLut` if a descriptor or its data is missing or malformed.
+    pub fn from_dcmroot(dcmroot: &DicomRoot, signed: bool) -> Result<Self, LoadError> {
+        let red = Self::read_lut(
+            dcmroot,
+            signed,
+            &tags::RedPaletteColorLookupTableDescriptor,
+            &tags::RedPaletteColorLookupTableData,
+            &tags::SegmentedRedPaletteColorLookupTableData,
+        )?;
+        let green = Self::read_lut(
+            dcmroot,
+            signed,
+            &tags::GreenPaletteColorLookupTableDescriptor,
+            &tags::GreenPaletteColorLookupTableData,
+            &tags::SegmentedGreenPaletteColorLookupTableData,
+        )?;
+        let blue = Self::read_lut(
+            dcmroot,
+            signed,
+            &tags::BluePaletteColorLookupTableDescriptor,
+            &tags::BluePaletteColorLookupTableData,
+            &tags::SegmentedBluePaletteColorLookupTableData,
+        )?;
+        Ok(Self::new(red, green, blue))
+    }
+
+    #[must_use]
+    pub fn red(&self) -> &Lut {
+        &self.red
+    }
+
+    #[must_use]
+    pub fn green(&self) -> &Lut {
+        &self.green
+    }
+
+    #[must_use]
+    pub fn blue(&self) -> &Lut {
+        &self.blue
+    }
+
+    /// The bits of each output sample, 8 if all tables have 8-bit entries and otherwise 16.
+    #[must_use]
+    pub fn bits(&self) -> u16 {
+        if [&self.red, &self.green, &self.blue]
+            .iter()
+            .all(|lut| lut.descriptor().bits <= 8)
+        {
+            8
+        } else {
+            16
+        }
+    }
+
+    /// Maps a stored pixel value to its red, green and blue values, scaled to `Self::bits`.
+    #[must_use]
+    pub fn lookup(&self, val: i64) -> [u16; 3] {
+        let bits = self.bits();
+        [&self.red, &self.green, &self.blue].map(|lut| {
+            let entry = lut.lookup(val);
+            if bits > lut.descriptor().bits {
+                // Scale to the full range of the output, so 0xFF becomes 0xFFFF.
+                let max = u32::from(lut.max_output()).max(1);
+                u16::try_from(u32::from(entry) * u32::from(u16::MAX) / max).unwrap_or(u16::MAX)
+            } else {
+                entry
+            }
+        })
+    }
+
+    /// Maps the stored values of native pixel data to RGB samples interleaved by pixel, where each
+    /// sample is `Self::bits`.
+    ///
+    /// # Errors
+    /// - `InvalidBitsAlloc` if the stored values are not 8-bit or 16-bit.
+    pub fn apply(
+        &self,
+        bytes: &[u8],
+        bits_alloc: u16,
+        signed: bool,
+        big_endian: bool,
+    ) -> Result<Vec<u8>, LoadError> {
+        let vals = match bits_alloc {
+            8 => bytes
+                .iter()
+                .map(|b| {
+                    if signed {
+                        i64::from(i8::from_ne_bytes([*b]))
+                    } else {
+                        i64::from(*b)
+                    }
+                })
+                .collect::<Vec<i64>>(),
+            16 => bytes
+                .chunks_exact(2)
+                .map(|w| {
+                    let w = [w[0], w[1]];
+                    match (signed, big_endian) {
+                        (true, true) => i64::from(i16::from_be_bytes(w)),
+                        (true, false) => i64::from(i16::from_le_bytes(w)),
+                        (false, true) => i64::from(u16::from_be_bytes(w)),
+                        (false, false) => i64::from(u16::from_le_bytes(w)),
+                    }
+                })
+                .collect::<Vec<i64>>(),
+            other => return Err(LoadError::InvalidBitsAlloc(other)),
+        };
+
+        let wide = self.bits() > 8;
+        let mut rgb = Vec::with_capacity(vals.len() * if wide { 6 } else { 3 });
+        for val in vals {
+            for sample in self.lookup(val) {
+                if !wide {
+                    rgb.push(u8::try_from(sample).unwrap_or(u8::MAX));
+                } else if big_endian {
+                    rgb.extend_from_slice(&sample.to_be_bytes());
+                } else {
+                    rgb.extend_from_slice(&sample.to_le_bytes());
+                }
+            }
+        }
+        Ok(rgb)
+    }
+
+    fn read_lut(
+        dcmroot: &DicomRoot,
+        signed: bool,
+        descriptor: &Tag,
+        data: &Tag,
+        segmented: &Tag,
+    ) -> Result<Lut, LoadError> {
+        let Some(vals) = dcmroot.get_value_by_tag(descriptor).map(|v| v.ushorts()) else {
+            return Err(LoadError::InvalidLut(format!(
+                "missing {}",
+                descriptor.ident()
+            )));
+        };
+        let descriptor = LutDescriptor::from_values(&vals, signed)?;
+        let big_endian = dcmroot.ts().big_endian();
+        if let Some(obj) = dcmroot.get_child_by_tag(data) {
+            Lut::from_bytes(descriptor, obj.element().data(), big_endian)
+        } else if let Some(obj) = dcmroot.get_child_by_tag(segmented) {
+            Lut::from_segmented(descriptor, obj.element().data(), big_endian)
+        } else {
+            Err(LoadError::InvalidLut(format!("missing {}", data.ident())))
+        }
+    }
+}
@@ -16,13 +16,25 @@
 
 use crate::{
     core::{
-        dcmobject::DicomRoot,
-        defn::vr::{self, VRRef},
+        codec::{registry::CodecRegistry, CodecError, FrameLayout},
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{
+            tag::Tag,
+            ts::TSRef,
+            vr::{self, VRRef},
+        },
         values::RawValue,
     },
     dict::tags,
     load::{
-        pixeldata::{winlevel::WindowLevel, BitsAlloc, LoadError, PhotoInterp},
+        pixeldata::{
+            display::ModalityTransform,
+            lut::{Lut, LutDescriptor},
+            overlay::Overlay,
+            palette::PaletteColorLut,
+            winlevel::{VoiLutFunction, WindowLevel},
+            ybr, BitsAlloc, LoadError, PhotoInterp,
+        },
         DicomVec, IndexVec, VolDims,
     },
 };
@@ -33,6 +45,8 @@ pub const I32_SIZE: usize = size_of::<i32>();
 pub const U8_SIZE: usize = size_of::<u8>();
 pub const U16_SIZE: usize = size_of::<u16>();
 pub const U32_SIZE: usize = size_of::<u32>();
+pub const F32_SIZE: usize = size_of::<f32>();
+pub const F64_SIZE: usize = size_of::<f64>();
 
 /// Parsed tag values relevant to interpreting Pixel Data, including the raw `PixelData` bytes.
 pub struct PixelDataSliceInfo {
@@ -49,12 +63,17 @@ pub struct PixelDataSliceInfo {
     rows: u16,
     pixel_spacing: (f32, f32),
     pixel_pad: Option<u16>,
+    float_pixel_pad: Option<f64>,
     bits_alloc: BitsAlloc,
     bits_stored: u16,
     high_bit: u16,
     pixel_rep: u16,
     slope: Option<f32>,
     intercept: Option<f32>,
+    modality_lut: Option<Lut>,
+    voi_luts: Vec<Lut>,
+    presentation_shape: Option<String>,
+    overlays: Vec<Overlay>,
     unit: String,
     patient_pos: String,
     image_pos: [f32; 3],
@@ -62,6 +81,7 @@ pub struct PixelDataSliceInfo {
     min_val: f32,
     max_val: f32,
     win_levels: Vec<WindowLevel>,
+    frame_index: Option<usize>,
     pd_bytes: Vec<u8>,
 }
 
@@ -85,11 +105,13 @@ impl PixelDataSliceInfo {
     }
 
     /// Process a DICOM object to extract and validate in preparation for loading the image data.
+    /// Encapsulated Pixel Data is decoded using the codec registered for the dataset's transfer
+    /// syntax.
     ///
     /// # Errors
     /// - `LoadError` if validation of image data dicom elements fails. See `Self::validate`.
     #[allow(clippy::too_many_lines)] // No great way to shrink this down.
-    pub(crate) fn process(dcmroot: DicomRoot) -> Result<Self, LoadError> {
+    pub(crate) fn process(dcmroot: DicomRoot, codecs: &CodecRegistry) -> Result<Self, LoadError> {
         let big_endian = dcmroot.ts().big_endian();
         let mut pdinfo = Self {
             dcmroot,
@@ -105,12 +127,17 @@ impl PixelDataSliceInfo {
             rows: 0,
             pixel_spacing: (0f32, 0f32),
             pixel_pad: None,
+            float_pixel_pad: None,
             bits_alloc: BitsAlloc::Unsupported(0),
             bits_stored: 0,
             high_bit: 0,
             pixel_rep: 0,
             slope: None,
             intercept: None,
+            modality_lut: None,
+            voi_luts: Vec::with_capacity(0),
+            presentation_shape: None,
+            overlays: Vec::with_capacity(0),
             unit: String::new(),
             patient_pos: String::new(),
             image_pos: [0_f32; 3],
@@ -118,6 +145,7 @@ impl PixelDataSliceInfo {
             min_val: 0_f32,
             max_val: 0_f32,
             win_levels: Vec::with_capacity(0),
+            frame_index: None,
             pd_bytes: Vec::with_capacity(0),
         };
 
@@ -137,6 +165,17 @@ impl PixelDataSliceInfo {
         {
             pdinfo.spacing_between_slices = val;
         }
+        // RT Dose frames are spaced by the Grid Frame Offset Vector rather than either of these.
+        if let Some(offsets) = pdinfo
+            .dcmroot()
+            .get_value_by_tag(&tags::GridFrameOffsetVector)
+            .map(|v| v.floats())
+            .filter(|offsets| offsets.len() > 1)
+        {
+            if !VolDims::is_valid_dim(pdinfo.spacing_between_slices) {
+                pdinfo.spacing_between_slices = (offsets[1] - offsets[0]).abs();
+            }
+        }
         if let Some(val) = pdinfo
             .dcmroot()
             .get_value_by_tag(&tags::SamplesperPixel)
@@ -220,6 +259,17 @@ impl PixelDataSliceInfo {
             .dcmroot()
             .get_value_by_tag(&tags::PixelPaddingValue)
             .and_then(|v| v.ushort());
+        pdinfo.float_pixel_pad = pdinfo
+            .dcmroot()
+            .get_value_by_tag(&tags::FloatPixelPaddingValue)
+            .and_then(|v| v.float())
+            .map(f64::from)
+            .or_else(|| {
+                pdinfo
+                    .dcmroot()
+                    .get_value_by_tag(&tags::DoubleFloatPixelPaddingValue)
+                    .and_then(|v| v.double())
+            });
         if let Some(vals) = pdinfo
             .dcmroot()
             .get_value_by_tag(&tags::WindowCenter)
@@ -262,20 +312,29 @@ impl PixelDataSliceInfo {
             .dcmroot()
             .get_value_by_tag(&tags::RescaleIntercept)
             .and_then(|v| v.float());
-        pdinfo.slope = pdinfo
-            .dcmroot()
-            .get_value_by_tag(&tags::RescaleSlope)
-            .and_then(|v| v.float());
+        // RT Dose values are scaled into the Dose Units by the Dose Grid Scaling.
+        pdinfo.slope = [&tags::RescaleSlope, &tags::DoseGridScaling]
+            .into_iter()
+            .find_map(|tag| {
+                pdinfo
+                    .dcmroot()
+                    .get_value_by_tag(tag)
+                    .and_then(|v| v.float())
+            });
         if let Some(val) = pdinfo
             .dcmroot()
             .get_value_by_tag(&tags::RescaleType)
             .and_then(|v| v.string().cloned())
         {
             pdinfo.unit = val;
-        } else if let Some(val) = pdinfo
-            .dcmroot()
-            .get_value_by_tag(&tags::Units)
-            .and_then(|v| v.string().cloned())
+        } else if let Some(val) = [&tags::Units, &tags::DoseUnits]
+            .into_iter()
+            .find_map(|tag| {
+                pdinfo
+                    .dcmroot()
+                    .get_value_by_tag(tag)
+                    .and_then(|v| v.string().cloned())
+            })
         {
             pdinfo.unit = val;
         }
@@ -299,6 +358,21 @@ impl PixelDataSliceInfo {
             }
         }
 
+        if let Some(val) = pdinfo
+            .dcmroot()
+            .get_value_by_tag(&tags::VOILUTFunction)
+            .and_then(|v| v.string().cloned())
+        {
+            let function = VoiLutFunction::from(val.trim());
+            for winlevel in &mut pdinfo.win_levels {
+                winlevel.set_function(function);
+            }
+        }
+        pdinfo.presentation_shape = pdinfo
+            .dcmroot()
+            .get_value_by_tag(&tags::PresentationLUTShape)
+            .and_then(|v| v.string().map(|s| s.trim().to_owned()));
+
         if let Some(val) = pdinfo
             .dcmroot()
             .get_value_by_tag(&tags::PatientPosition)
@@ -325,18 +399,25 @@ impl PixelDataSliceInfo {
             }
         }
 
+        let ts = pdinfo.dcmroot().ts();
         let mut pd_bytes = Vec::with_capacity(0);
+        let mut encapsulated = false;
         let mut vr = &vr::OB;
-        if let Some(obj) = pdinfo.dcmroot_mut().get_child_by_tag_mut(&tags::PixelData) {
+        // Parametric maps and similar use Float or Double Float Pixel Data in place of Pixel Data.
+        let pd_tag = [
+            &tags::PixelData,
+            &tags::FloatPixelData,
+            &tags::DoubleFloatPixelData,
+        ]
+        .into_iter()
+        .find(|tag| pdinfo.dcmroot().get_child_by_tag(*tag).is_some())
+        .unwrap_or(&tags::PixelData);
+        if let Some(obj) = pdinfo.dcmroot_mut().get_child_by_tag_mut(pd_tag) {
             let elem = obj.element_mut();
             vr = elem.vr();
             if elem.has_fragments() {
-                // Otherwise the additional fragments have to be appended. Shrink the element's data
-                // buffer so it's not hanging on to an empty vec with a large capacity.
-                for ch in obj.iter_items_mut() {
-                    pd_bytes.append(ch.element_mut().data_mut());
-                    ch.element_mut().data_mut().shrink_to(0);
-                }
+                // Compressed frames are decoded from the fragments below.
+                encapsulated = true;
             } else {
                 // The common case of a single-frame dataset, or the first frame of a multi-frame
                 // datset, swapping results in more efficient memory usage since the bytes do not need
@@ -346,13 +427,449 @@ impl PixelDataSliceInfo {
         }
         pdinfo.vr = vr;
         pdinfo.pd_bytes = pd_bytes;
+        if pdinfo.is_float() && pdinfo.bits_alloc == BitsAlloc::Unsupported(0) {
+            // Bits Allocated is implied by the VR of Float and Double Float Pixel Data.
+            pdinfo.bits_alloc = if vr == &vr::OD {
+                BitsAlloc::SixtyFour
+            } else {
+                BitsAlloc::ThirtyTwo
+            };
+        }
+
+        if encapsulated && !matches!(pdinfo.bits_alloc, BitsAlloc::Unsupported(_)) {
+            pdinfo.decode_frames(ts, codecs)?;
+        }
+
+        // Enhanced multi-frame datasets specify geometry and rescaling in the Functional Groups,
+        // which take precedence over the top-level values. The first frame's values are used here
+        // and the values of other frames are applied by `into_frames`.
+        let groups = [
+            &tags::SharedFunctionalGroupsSequence,
+            &tags::PerFrameFunctionalGroupsSequence,
+        ]
+        .into_iter()
+        .filter_map(|tag| {
+            pdinfo
+                .dcmroot()
+                .get_child_by_tag(tag)
+                .and_then(|sq| sq.get_item_by_index(1))
+                .cloned()
+        })
+        .collect::<Vec<DicomObject>>();
+        for group in &groups {
+            pdinfo.apply_functional_groups(group);
+        }
+
+        // The Modality LUT input is the stored value, whose signedness is that of the Pixel
+        // Representation. The VOI LUT input is the output of the Modality LUT or rescale, which may
+        // be negative even for unsigned stored values.
+        let big_endian = pdinfo.big_endian;
+        let signed = pdinfo.is_signed();
+        if let Some(item) = pdinfo
+            .dcmroot()
+            .get_child_by_tag(&tags::ModalityLUTSequence)
+            .and_then(|sq| sq.get_item_by_index(1))
+        {
+            pdinfo.modality_lut = Some(Self::read_lut_item(item, signed, big_endian)?);
+        }
+        let voi_signed = signed || pdinfo.intercept.is_some_and(|v| v < 0_f32);
+        if let Some(sq) = pdinfo.dcmroot().get_child_by_tag(&tags::VOILUTSequence) {
+            pdinfo.voi_luts = sq
+                .iter_items()
+                .map(|item| Self::read_lut_item(item, voi_signed, big_endian))
+                .collect::<Result<Vec<Lut>, LoadError>>()?;
+        }
 
         pdinfo.validate()?;
+        if pdinfo.bits_alloc == BitsAlloc::One {
+            pdinfo.unpack_bits();
+        }
+        if !pdinfo.is_float() {
+            let layout = pdinfo.frame_layout();
+            pdinfo.overlays = Overlay::read_all(
+                &pdinfo.dcmroot,
+                &mut pdinfo.pd_bytes,
+                &layout,
+                pdinfo.high_bit,
+                big_endian,
+            );
+        }
+        if pdinfo
+            .photo_interp
+            .as_ref()
+            .is_some_and(PhotoInterp::is_ybr)
+        {
+            pdinfo.convert_ybr_to_rgb()?;
+        } else if pdinfo.photo_interp == Some(PhotoInterp::PaletteColor) {
+            pdinfo.apply_palette()?;
+        }
 
         Ok(pdinfo)
     }
 }
 
+impl PixelDataSliceInfo {
+    /// The layout of a single frame of native pixel data described by this slice.
+    #[must_use]
+    pub fn frame_layout(&self) -> FrameLayout {
+        FrameLayout {
+            rows: self.rows,
+            cols: self.cols,
+            samples_per_pixel: self.samples_per_pixel,
+            bits_alloc: self.bits_alloc.val(),
+            bits_stored: self.bits_stored,
+            pixel_rep: self.pixel_rep,
+        }
+    }
+
+    /// Decodes the fragments of encapsulated Pixel Data into native pixel data, using the codec
+    /// registered for the given transfer syntax.
+    ///
+    /// The decoded frames are interleaved by pixel so Planar Configuration is updated to reflect
+    /// that, regardless of its value in the dataset. Codecs which convert color images to another
+    /// color space while decoding, such as JPEG Baseline and Extended to RGB, also result in
+    /// Photometric Interpretation being updated.
+    fn decode_frames(&mut self, ts: TSRef, codecs: &CodecRegistry) -> Result<(), LoadError> {
+        let Some(codec) = codecs.get(ts) else {
+            return Err(LoadError::UnsupportedTransferSyntax(
+                ts.uid().name().to_owned(),
+            ));
+        };
+
+        let table = self.dcmroot.pixel_data_frames()?.unwrap_or_default();
+        let layout = self.frame_layout();
+        let mut pd_bytes = Vec::with_capacity(layout.frame_len() * table.len());
+        let frames = self
+            .dcmroot
+            .pixel_data_frames_iter(&table)
+            .into_iter()
+            .flatten();
+        for (index, frame) in frames.enumerate() {
+            let Some(frame) = frame else {
+                return Err(
+                    CodecError::InvalidData(format!("missing fragments of frame {index}")).into(),
+                );
+            };
+            pd_bytes.append(&mut codec.decode_frame(&frame, &layout)?);
+        }
+        self.pd_bytes = pd_bytes;
+        self.planar_config = 0;
+        let photo_interp = self
+            .dcmroot
+            .get_value_by_tag(&tags::PhotometricInterpretation)
+            .and_then(|v| v.string().cloned())
+            .unwrap_or_default();
+        if let Some(decoded) = codec.decoded_photo_interp(&photo_interp, &layout) {
+            self.photo_interp = Some(PhotoInterp::from(decoded));
+        }
+
+        // The compressed bytes are no longer needed. Taking the bytes leaves the fragments' data
+        // buffers empty without hanging on to a large capacity.
+        if let Some(obj) = self.dcmroot.get_child_by_tag_mut(&tags::PixelData) {
+            for item in obj.iter_items_mut() {
+                std::mem::take(item.element_mut().data_mut());
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the values of the Functional Group Macros describing the geometry and rescaling of
+    /// a frame: Pixel Measures, Plane Position (Patient), Plane Orientation (Patient), and Pixel
+    /// Value Transformation. `groups` is an item of the Shared or Per-frame Functional Groups
+    /// Sequence. Returns whether the item specified the position of the frame.
+    fn apply_functional_groups(&mut self, groups: &DicomObject) -> bool {
+        let macro_item = |tag: &Tag| {
+            groups
+                .get_child_by_tag(tag)
+                .and_then(|sq| sq.get_item_by_index(1))
+        };
+
+        if let Some(item) = macro_item(&tags::PixelMeasuresSequence) {
+            if let Some(vals) = item
+                .get_value_by_tag(&tags::PixelSpacing)
+                .map(|v| v.floats())
+            {
+                if vals.len() == 2 {
+                    self.pixel_spacing = (vals[0], vals[1]);
+                }
+            }
+            if let Some(val) = item
+                .get_value_by_tag(&tags::SliceThickness)
+                .and_then(|v| v.float())
+            {
+                self.slice_thickness = val;
+            }
+            if let Some(val) = item
+                .get_value_by_tag(&tags::SpacingBetweenSlices)
+                .and_then(|v| v.float())
+            {
+                self.spacing_between_slices = val;
+            }
+        }
+        if let Some(vals) = macro_item(&tags::PlaneOrientationSequence)
+            .and_then(|item| item.get_value_by_tag(&tags::ImageOrientationPatient))
+            .map(|v| v.floats())
+        {
+            if vals.len() == self.iop.len() {
+                self.iop.copy_from_slice(vals.as_slice());
+            }
+        }
+        if let Some(item) = macro_item(&tags::PixelValueTransformationSequence) {
+            if let Some(val) = item
+                .get_value_by_tag(&tags::RescaleIntercept)
+                .and_then(|v| v.float())
+            {
+                self.intercept = Some(val);
+            }
+            if let Some(val) = item
+                .get_value_by_tag(&tags::RescaleSlope)
+                .and_then(|v| v.float())
+            {
+                self.slope = Some(val);
+            }
+            if let Some(val) = item
+                .get_value_by_tag(&tags::RescaleType)
+                .and_then(|v| v.string().cloned())
+            {
+                self.unit = val;
+            }
+        }
+        if let Some(item) = macro_item(&tags::FrameVOILUTSequence) {
+            let centers = item
+                .get_value_by_tag(&tags::WindowCenter)
+                .map(|v| v.floats())
+                .unwrap_or_default();
+            let widths = item
+                .get_value_by_tag(&tags::WindowWidth)
+                .map(|v| v.floats())
+                .unwrap_or_default();
+            let function = item
+                .get_value_by_tag(&tags::VOILUTFunction)
+                .and_then(|v| v.string().map(|s| VoiLutFunction::from(s.trim())))
+                .unwrap_or_default();
+            if !centers.is_empty() && centers.len() == widths.len() {
+                self.win_levels = centers
+                    .into_iter()
+                    .zip(widths)
+                    .enumerate()
+                    .map(|(i, (center, width))| {
+                        let mut winlevel = WindowLevel::new(
+                            format!("winlevel_{i}"),
+                            center,
+                            width,
+                            f32::MIN,
+                            f32::MAX,
+                        );
+                        winlevel.set_function(function);
+                        winlevel
+                    })
+                    .collect();
+            }
+        }
+        if let Some(vals) = macro_item(&tags::PlanePositionSequence)
+            .and_then(|item| item.get_value_by_tag(&tags::ImagePositionPatient))
+            .map(|v| v.floats())
+        {
+            if vals.len() == self.image_pos.len() {
+                self.image_pos.copy_from_slice(vals.as_slice());
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Splits a multi-frame dataset into a slice per frame, each positioned and oriented by the
+    /// Per-frame Functional Groups. Frames without their own Plane Position are stacked in frame
+    /// order along the normal of the image plane, starting at the position of the dataset, and
+    /// offset by the Grid Frame Offset Vector of RT Dose if present.
+    ///
+    /// Each frame's `DicomRoot` is a copy of this slice's without the Per-frame Functional Groups
+    /// Sequence, to avoid duplicating the values of every frame for each frame.
+    ///
+    /// # Errors
+    /// - `LoadError::InvalidDims` if the Pixel Data does not hold all frames.
+    pub(crate) fn into_frames(mut self) -> Result<Vec<PixelDataSliceInfo>, LoadError> {
+        let num_frames = usize::try_from(self.num_frames).unwrap_or(1).max(1);
+        if num_frames == 1 {
+            return Ok(vec![self]);
+        }
+
+        let frame_len = self.frame_layout().frame_len();
+        let bytes = self.take_bytes();
+        if frame_len == 0 || bytes.len() < frame_len * num_frames {
+            return Err(LoadError::InvalidDims(format!(
+                "expected {} bytes of Pixel Data for {num_frames} frames but got {}",
+                frame_len * num_frames,
+                bytes.len()
+            )));
+        }
+
+        let per_frame = self
+            .dcmroot
+            .remove_child_by_tag(&tags::PerFrameFunctionalGroupsSequence);
+        let dims = self.vol_dims();
+        let normal = dims.normal();
+        let spacing = dims.voxel_dims().z;
+        let spacing = if VolDims::is_valid_dim(spacing) {
+            spacing
+        } else {
+            1_f32
+        };
+
+        // The offsets are relative to the position of the dataset when the first offset is zero,
+        // otherwise the first offset is the position of the dataset along the normal.
+        let grid_offsets = self
+            .dcmroot
+            .get_value_by_tag(&tags::GridFrameOffsetVector)
+            .map(|v| v.floats())
+            .filter(|offsets| offsets.len() >= num_frames);
+
+        let mut frames = Vec::with_capacity(num_frames);
+        for (index, frame) in bytes.chunks_exact(frame_len).take(num_frames).enumerate() {
+            let mut info = self.frame_info(index, frame.to_vec());
+            let positioned = per_frame
+                .as_ref()
+                .and_then(|sq| sq.get_item_by_index(index + 1))
+                .is_some_and(|groups| info.apply_functional_groups(groups));
+            if !positioned {
+                #[allow(clippy::cast_precision_loss)]
+                let offset = grid_offsets
+                    .as_ref()
+                    .map_or(index as f32 * spacing, |offsets| {
+                        offsets[index] - offsets[0]
+                    });
+                for (pos, dir) in info
+                    .image_pos
+                    .iter_mut()
+                    .zip([normal.x, normal.y, normal.z])
+                {
+                    *pos += dir * offset;
+                }
+            }
+            frames.push(info);
+        }
+        Ok(frames)
+    }
+
+    /// Creates the slice for a single frame of this multi-frame slice.
+    fn frame_info(&self, index: usize, pd_bytes: Vec<u8>) -> Self {
+        Self {
+            dcmroot: self.dcmroot.clone(),
+            big_endian: self.big_endian,
+            vr: self.vr,
+            slice_thickness: self.slice_thickness,
+            spacing_between_slices: self.spacing_between_slices,
+            samples_per_pixel: self.samples_per_pixel,
+            photo_interp: self.photo_interp.clone(),
+            planar_config: self.planar_config,
+            num_frames: 1,
+            cols: self.cols,
+            rows: self.rows,
+            pixel_spacing: self.pixel_spacing,
+            pixel_pad: self.pixel_pad,
+            float_pixel_pad: self.float_pixel_pad,
+            bits_alloc: self.bits_alloc,
+            bits_stored: self.bits_stored,
+            high_bit: self.high_bit,
+            pixel_rep: self.pixel_rep,
+            slope: self.slope,
+            intercept: self.intercept,
+            modality_lut: self.modality_lut.clone(),
+            voi_luts: self.voi_luts.clone(),
+            presentation_shape: self.presentation_shape.clone(),
+            overlays: self
+                .overlays
+                .iter()
+                .filter_map(|overlay| overlay.for_frame(index))
+                .collect(),
+            unit: self.unit.clone(),
+            patient_pos: self.patient_pos.clone(),
+            image_pos: self.image_pos,
+            iop: self.iop,
+            min_val: self.min_val,
+            max_val: self.max_val,
+            win_levels: self.win_levels.clone(),
+            frame_index: Some(index),
+            pd_bytes,
+        }
+    }
+
+    /// Unpacks 1-bit Pixel Data into a byte per pixel, holding 0 or 1. The bits of all frames are
+    /// packed contiguously, least significant bit first, so frames need not start on a byte
+    /// boundary.
+    fn unpack_bits(&mut self) {
+        let num_frames = usize::try_from(self.num_frames).unwrap_or(1).max(1);
+        let len = usize::from(self.rows) * usize::from(self.cols) * num_frames;
+        let packed = self.take_bytes();
+        self.pd_bytes = (0..len)
+            .map(|i| packed.get(i / 8).map_or(0, |byte| (byte >> (i % 8)) & 1))
+            .collect();
+        self.bits_alloc = BitsAlloc::Eight;
+        self.bits_stored = 1;
+        self.high_bit = 0;
+    }
+
+    /// Reads an item of the Modality LUT Sequence or VOI LUT Sequence.
+    ///
+    /// `signed` - Whether the first value mapped by the LUT Descriptor is signed.
+    fn read_lut_item(item: &DicomObject, signed: bool, big_endian: bool) -> Result<Lut, LoadError> {
+        let Some(vals) = item
+            .get_value_by_tag(&tags::LUTDescriptor)
+            .map(|v| v.ushorts())
+        else {
+            return Err(LoadError::InvalidLut("missing LUTDescriptor".to_owned()));
+        };
+        let descriptor = LutDescriptor::from_values(&vals, signed)?;
+        let Some(obj) = item.get_child_by_tag(&tags::LUTData) else {
+            return Err(LoadError::InvalidLut("missing LUTData".to_owned()));
+        };
+        Lut::from_bytes(descriptor, obj.element().data(), big_endian)
+    }
+
+    /// Converts YBR pixel data to RGB, upsampling subsampled chroma. Photometric Interpretation and
+    /// Planar Configuration are updated to reflect the converted pixel data.
+    fn convert_ybr_to_rgb(&mut self) -> Result<(), LoadError> {
+        let Some(photo_interp) = &self.photo_interp else {
+            return Ok(());
+        };
+        let num_frames = usize::try_from(self.num_frames).unwrap_or(1).max(1);
+        self.pd_bytes = ybr::ybr_to_rgb(
+            photo_interp,
+            &self.pd_bytes,
+            &self.frame_layout(),
+            num_frames,
+            self.planar_config != 0,
+            self.big_endian,
+        )?;
+        self.photo_interp = Some(PhotoInterp::Rgb);
+        self.planar_config = 0;
+        Ok(())
+    }
+
+    /// Maps the stored values of a `PALETTE COLOR` image through its Palette Color Lookup Tables,
+    /// resulting in RGB pixel data with 8-bit or 16-bit samples depending on the tables' entries.
+    fn apply_palette(&mut self) -> Result<(), LoadError> {
+        let palette = PaletteColorLut::from_dcmroot(&self.dcmroot, self.is_signed())?;
+        self.pd_bytes = palette.apply(
+            &self.pd_bytes,
+            self.bits_alloc.val(),
+            self.is_signed(),
+            self.big_endian,
+        )?;
+        let bits = palette.bits();
+        self.photo_interp = Some(PhotoInterp::Rgb);
+        self.samples_per_pixel = 3;
+        self.planar_config = 0;
+        self.bits_alloc = BitsAlloc::from_val(bits);
+        self.bits_stored = bits;
+        self.high_bit = bits - 1;
+        self.pixel_rep = 0;
+        // Padding refers to stored values, which no longer exist after mapping.
+        self.pixel_pad = None;
+        Ok(())
+    }
+}
+
 impl std::fmt::Debug for PixelDataSliceInfo {
     // Default Debug implementation but don't print all bytes, just the length.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -379,6 +896,12 @@ impl std::fmt::Debug for PixelDataSliceInfo {
                 "pixel_pad",
                 &self.pixel_pad.map_or("None".to_string(), |v| v.to_string()),
             )
+            .field(
+                "float_pixel_pad",
+                &self
+                    .float_pixel_pad
+                    .map_or("None".to_string(), |v| v.to_string()),
+            )
             .field("bits_alloc", &self.bits_alloc)
             .field("bits_stored", &self.bits_stored)
             .field("high_bit", &self.high_bit)
@@ -391,6 +914,10 @@ impl std::fmt::Debug for PixelDataSliceInfo {
                 "intercept",
                 &self.intercept.map_or("None".to_string(), |v| v.to_string()),
             )
+            .field("modality_lut", &self.modality_lut.is_some())
+            .field("voi_luts", &self.voi_luts.len())
+            .field("presentation_shape", &self.presentation_shape)
+            .field("overlays", &self.overlays.len())
             .field("unit", &self.unit)
             .field("patient_pos", &self.patient_pos)
             .field("image_pos", &self.image_pos)
@@ -398,6 +925,7 @@ impl std::fmt::Debug for PixelDataSliceInfo {
             .field("min_val", &self.min_val)
             .field("max_val", &self.max_val)
             .field("win_levels", &self.win_levels)
+            .field("frame_index", &self.frame_index)
             .field("pd_bytes", &self.pd_bytes.len())
             .finish()
     }
@@ -474,6 +1002,12 @@ impl PixelDataSliceInfo {
         self.pixel_pad
     }
 
+    /// The Float or Double Float Pixel Padding Value, for Float and Double Float Pixel Data.
+    #[must_use]
+    pub fn float_pixel_pad(&self) -> Option<f64> {
+        self.float_pixel_pad
+    }
+
     #[must_use]
     pub fn bits_alloc(&self) -> &BitsAlloc {
         &self.bits_alloc
@@ -504,6 +1038,58 @@ impl PixelDataSliceInfo {
         self.intercept
     }
 
+    /// The first item of the Modality LUT Sequence, which replaces Rescale Slope and Rescale
+    /// Intercept when present.
+    #[must_use]
+    pub fn modality_lut(&self) -> Option<&Lut> {
+        self.modality_lut.as_ref()
+    }
+
+    /// The items of the VOI LUT Sequence.
+    #[must_use]
+    pub fn voi_luts(&self) -> &[Lut] {
+        &self.voi_luts
+    }
+
+    /// The Presentation LUT Shape, `IDENTITY` or `INVERSE`, if specified.
+    #[must_use]
+    pub fn presentation_shape(&self) -> Option<&str> {
+        self.presentation_shape.as_deref()
+    }
+
+    /// The Overlay Planes of this slice. For a slice split from a multi-frame dataset only the
+    /// overlays applying to its frame are included.
+    #[must_use]
+    pub fn overlays(&self) -> &[Overlay] {
+        &self.overlays
+    }
+
+    /// The transform from stored values to modality values, the Modality LUT if present and
+    /// otherwise Rescale Slope and Rescale Intercept.
+    #[must_use]
+    pub fn modality_transform(&self) -> ModalityTransform {
+        if let Some(lut) = &self.modality_lut {
+            ModalityTransform::Lut(lut.clone())
+        } else {
+            ModalityTransform::Rescale {
+                slope: self.slope.unwrap_or(1_f32),
+                intercept: self.intercept.unwrap_or(0_f32),
+            }
+        }
+    }
+
+    /// Whether display values are inverted so that the minimum value is white. This is specified
+    /// by a Presentation LUT Shape of `INVERSE`, or implied by `MONOCHROME1` if the shape is not
+    /// specified.
+    #[must_use]
+    pub fn is_inverse(&self) -> bool {
+        match self.presentation_shape.as_deref() {
+            Some("INVERSE") => true,
+            Some("IDENTITY") => false,
+            _ => self.photo_interp == Some(PhotoInterp::Monochrome1),
+        }
+    }
+
     #[must_use]
     pub fn unit(&self) -> &str {
         &self.unit
@@ -557,6 +1143,13 @@ impl PixelDataSliceInfo {
         self.pixel_rep != 0
     }
 
+    /// Whether the pixel values are from Float Pixel Data or Double Float Pixel Data, which hold
+    /// 32-bit or 64-bit IEEE floating point values rather than integers.
+    #[must_use]
+    pub fn is_float(&self) -> bool {
+        self.vr == &vr::OF || self.vr == &vr::OD
+    }
+
     /// Creates a `VolDims` populated with the dimensions of the volume based on only what this SOP
     /// Instance has information on. It will not have correct information for details which require
     /// all slices to be loaded. Thus, `z_count` will always be 1, the DICOM origin will be the
@@ -593,7 +1186,16 @@ impl PixelDataSliceInfo {
             z: z_mm,
         };
 
+        let [rx, ry, rz, cx, cy, cz] = self.iop;
         VolDims::new(origin, count, mm)
+            .with_orientation(DicomVec::new(rx, ry, rz), DicomVec::new(cx, cy, cz))
+    }
+
+    /// The index of the frame of a multi-frame dataset which this slice holds, if this slice was
+    /// split from a multi-frame dataset by `into_frames`.
+    #[must_use]
+    pub fn frame_index(&self) -> Option<usize> {
+        self.frame_index
     }
 
     #[must_use]
@@ -616,13 +1218,29 @@ impl PixelDataSliceInfo {
             return Err(LoadError::InvalidSize(self.cols, self.rows));
         }
 
-        if self.vr != &vr::OB && self.vr != &vr::OW {
+        if self.vr != &vr::OB && self.vr != &vr::OW && !self.is_float() {
             return Err(LoadError::InvalidVR(self.vr));
         }
 
         if let BitsAlloc::Unsupported(val) = self.bits_alloc {
             return Err(LoadError::InvalidBitsAlloc(val));
         }
+        // Float Pixel Data is 32-bit, Double Float Pixel Data is 64-bit, and 64-bit is only valid
+        // for Double Float Pixel Data.
+        let float_bits = match self.vr {
+            vr if vr == &vr::OF => Some(BitsAlloc::ThirtyTwo),
+            vr if vr == &vr::OD => Some(BitsAlloc::SixtyFour),
+            _ => None,
+        };
+        if float_bits.is_some_and(|bits| bits != self.bits_alloc)
+            || (float_bits.is_none() && self.bits_alloc == BitsAlloc::SixtyFour)
+        {
+            return Err(LoadError::InvalidBitsAlloc(self.bits_alloc.val()));
+        }
+
+        if self.bits_alloc == BitsAlloc::One && self.samples_per_pixel != 1 {
+            return Err(LoadError::InvalidBitsAlloc(1));
+        }
 
         // BitsStored will generally be the same value as BitsAllocated.
         if self.bits_stored > self.bits_alloc.val() || self.bits_stored == 0 {
@@ -634,11 +1252,12 @@ impl PixelDataSliceInfo {
         }
 
         if let Some(pi) = &self.photo_interp {
-            if (pi.is_rgb() && self.samples_per_pixel != 3)
-                || (pi.is_monochrome() && self.samples_per_pixel != 1)
+            if ((pi.is_rgb() || pi.is_ybr()) && self.samples_per_pixel != 3)
+                || ((pi.is_monochrome() || *pi == PhotoInterp::PaletteColor)
+                    && self.samples_per_pixel != 1)
             {
-                // RGB must use 3 Samples Per Pixel.
-                // MONOCHROME1/2 must use 1 Sample Per Pixel.
+                // RGB and YBR must use 3 Samples Per Pixel.
+                // MONOCHROME1/2 and PALETTE COLOR must use 1 Sample Per Pixel.
                 return Err(LoadError::InvalidPhotoInterpSamples(
                     pi.clone(),
                     self.samples_per_pixel,
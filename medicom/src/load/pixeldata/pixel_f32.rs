@@ -0,0 +1,152 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use crate::load::{
+    pixeldata::{
+        pdinfo::{PixelDataSliceInfo, F32_SIZE},
+        winlevel::WindowLevel,
+        LoadError,
+    },
+    EPSILON_F32,
+};
+
+pub struct PixelDataSliceF32 {
+    info: PixelDataSliceInfo,
+    buffer: Vec<f32>,
+}
+
+impl std::fmt::Debug for PixelDataSliceF32 {
+    // Default Debug implementation but don't print all bytes, just the length.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PixelDataSliceF32")
+            .field("info", &self.info)
+            .field("buffer.len", &self.buffer.len())
+            .finish()
+    }
+}
+
+impl PixelDataSliceF32 {
+    /// Create `PixelDataSliceF32` from Float Pixel Data. Values which are NaN or the Float Pixel
+    /// Padding Value are excluded from the minimum and maximum values.
+    ///
+    /// # Errors
+    /// - Any errors interpreting little/big -endian bytes as 32bit floats.
+    pub fn from_float_32bit(mut pdinfo: PixelDataSliceInfo) -> Result<Self, LoadError> {
+        let num_frames = usize::try_from(pdinfo.num_frames()).unwrap_or(1);
+        let samples = usize::from(pdinfo.samples_per_pixel());
+        let len = usize::from(pdinfo.cols()) * usize::from(pdinfo.rows()) * num_frames;
+        let pixel_pad = pdinfo.float_pixel_pad();
+
+        let mut buffer: Vec<f32> = Vec::with_capacity(len * samples);
+        let mut in_pos: usize = 0;
+        let mut min: f32 = f32::MAX;
+        let mut max: f32 = f32::MIN;
+        let bytes = pdinfo.take_bytes();
+        for _i in 0..len * samples {
+            let val_bytes = bytes[in_pos..in_pos + F32_SIZE].try_into()?;
+            let val = if pdinfo.big_endian() {
+                f32::from_be_bytes(val_bytes)
+            } else {
+                f32::from_le_bytes(val_bytes)
+            };
+            in_pos += F32_SIZE;
+
+            buffer.push(val);
+            if !val.is_nan() && pixel_pad.is_none_or(|pad_val| f64::from(val) != pad_val) {
+                min = min.min(val);
+                max = max.max(val);
+            }
+        }
+
+        pdinfo.set_min_val(min);
+        pdinfo.set_max_val(max);
+
+        let minmax_width = max - min;
+        let minmax_center = min + minmax_width / 2_f32;
+        let mut already_has_minmax = false;
+        for winlevel in pdinfo.win_levels_mut() {
+            let same_width = (winlevel.width() - minmax_width).abs() < EPSILON_F32;
+            let same_center = (winlevel.center() - minmax_center).abs() < EPSILON_F32;
+            if same_width && same_center {
+                already_has_minmax = true;
+            }
+        }
+        if !already_has_minmax {
+            pdinfo.win_levels_mut().push(WindowLevel::new(
+                "Min/Max".to_string(),
+                minmax_center,
+                minmax_width,
+                f32::MIN,
+                f32::MAX,
+            ));
+        }
+        Ok(Self::new(pdinfo, buffer))
+    }
+
+    #[must_use]
+    pub fn new(info: PixelDataSliceInfo, buffer: Vec<f32>) -> Self {
+        Self { info, buffer }
+    }
+
+    #[must_use]
+    pub fn info(&self) -> &PixelDataSliceInfo {
+        &self.info
+    }
+
+    #[must_use]
+    pub fn buffer(&self) -> &[f32] {
+        &self.buffer
+    }
+
+    #[must_use]
+    pub fn into_buffer(self) -> (PixelDataSliceInfo, Vec<f32>) {
+        (self.info, self.buffer)
+    }
+
+    #[must_use]
+    pub fn rescale(&self, val: f32) -> f32 {
+        if let Some(slope) = self.info().slope() {
+            if let Some(intercept) = self.info().intercept() {
+                return val * slope + intercept;
+            }
+        }
+        val
+    }
+
+    #[must_use]
+    pub fn best_winlevel(&self) -> WindowLevel {
+        self.info.win_levels().last().map_or_else(
+            || {
+                WindowLevel::new(
+                    "Default".to_string(),
+                    self.info.min_val() + (self.info.max_val() - self.info.min_val()) / 2_f32,
+                    self.info.max_val() - self.info.min_val(),
+                    f32::MIN,
+                    f32::MAX,
+                )
+            },
+            |winlevel| {
+                WindowLevel::new(
+                    winlevel.name().to_string(),
+                    self.rescale(winlevel.center()),
+                    self.rescale(winlevel.width()),
+                    winlevel.out_min(),
+                    winlevel.out_max(),
+                )
+            },
+        )
+    }
+}
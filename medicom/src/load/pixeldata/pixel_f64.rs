@@ -0,0 +1,155 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use crate::load::{
+    pixeldata::{
+        pdinfo::{PixelDataSliceInfo, F64_SIZE},
+        winlevel::WindowLevel,
+        LoadError,
+    },
+    EPSILON_F32,
+};
+
+pub struct PixelDataSliceF64 {
+    info: PixelDataSliceInfo,
+    buffer: Vec<f64>,
+}
+
+impl std::fmt::Debug for PixelDataSliceF64 {
+    // Default Debug implementation but don't print all bytes, just the length.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PixelDataSliceF64")
+            .field("info", &self.info)
+            .field("buffer.len", &self.buffer.len())
+            .finish()
+    }
+}
+
+impl PixelDataSliceF64 {
+    /// Create `PixelDataSliceF64` from Double Float Pixel Data. Values which are NaN or the Double
+    /// Float Pixel Padding Value are excluded from the minimum and maximum values.
+    ///
+    /// # Errors
+    /// - Any errors interpreting little/big -endian bytes as 64bit floats.
+    pub fn from_float_64bit(mut pdinfo: PixelDataSliceInfo) -> Result<Self, LoadError> {
+        let num_frames = usize::try_from(pdinfo.num_frames()).unwrap_or(1);
+        let samples = usize::from(pdinfo.samples_per_pixel());
+        let len = usize::from(pdinfo.cols()) * usize::from(pdinfo.rows()) * num_frames;
+        let pixel_pad = pdinfo.float_pixel_pad();
+
+        let mut buffer: Vec<f64> = Vec::with_capacity(len * samples);
+        let mut in_pos: usize = 0;
+        let mut min: f64 = f64::MAX;
+        let mut max: f64 = f64::MIN;
+        let bytes = pdinfo.take_bytes();
+        for _i in 0..len * samples {
+            let val_bytes = bytes[in_pos..in_pos + F64_SIZE].try_into()?;
+            let val = if pdinfo.big_endian() {
+                f64::from_be_bytes(val_bytes)
+            } else {
+                f64::from_le_bytes(val_bytes)
+            };
+            in_pos += F64_SIZE;
+
+            buffer.push(val);
+            if !val.is_nan() && pixel_pad.is_none_or(|pad_val| val != pad_val) {
+                min = min.min(val);
+                max = max.max(val);
+            }
+        }
+
+        // The volume's window/level and statistics are computed in `f32`.
+        #[allow(clippy::cast_possible_truncation)]
+        let (min, max) = (min as f32, max as f32);
+        pdinfo.set_min_val(min);
+        pdinfo.set_max_val(max);
+
+        let minmax_width = max - min;
+        let minmax_center = min + minmax_width / 2_f32;
+        let mut already_has_minmax = false;
+        for winlevel in pdinfo.win_levels_mut() {
+            let same_width = (winlevel.width() - minmax_width).abs() < EPSILON_F32;
+            let same_center = (winlevel.center() - minmax_center).abs() < EPSILON_F32;
+            if same_width && same_center {
+                already_has_minmax = true;
+            }
+        }
+        if !already_has_minmax {
+            pdinfo.win_levels_mut().push(WindowLevel::new(
+                "Min/Max".to_string(),
+                minmax_center,
+                minmax_width,
+                f32::MIN,
+                f32::MAX,
+            ));
+        }
+        Ok(Self::new(pdinfo, buffer))
+    }
+
+    #[must_use]
+    pub fn new(info: PixelDataSliceInfo, buffer: Vec<f64>) -> Self {
+        Self { info, buffer }
+    }
+
+    #[must_use]
+    pub fn info(&self) -> &PixelDataSliceInfo {
+        &self.info
+    }
+
+    #[must_use]
+    pub fn buffer(&self) -> &[f64] {
+        &self.buffer
+    }
+
+    #[must_use]
+    pub fn into_buffer(self) -> (PixelDataSliceInfo, Vec<f64>) {
+        (self.info, self.buffer)
+    }
+
+    #[must_use]
+    pub fn rescale(&self, val: f32) -> f32 {
+        if let Some(slope) = self.info().slope() {
+            if let Some(intercept) = self.info().intercept() {
+                return val * slope + intercept;
+            }
+        }
+        val
+    }
+
+    #[must_use]
+    pub fn best_winlevel(&self) -> WindowLevel {
+        self.info.win_levels().last().map_or_else(
+            || {
+                WindowLevel::new(
+                    "Default".to_string(),
+                    self.info.min_val() + (self.info.max_val() - self.info.min_val()) / 2_f32,
+                    self.info.max_val() - self.info.min_val(),
+                    f32::MIN,
+                    f32::MAX,
+                )
+            },
+            |winlevel| {
+                WindowLevel::new(
+                    winlevel.name().to_string(),
+                    self.rescale(winlevel.center()),
+                    self.rescale(winlevel.width()),
+                    winlevel.out_min(),
+                    winlevel.out_max(),
+                )
+            },
+        )
+    }
+}
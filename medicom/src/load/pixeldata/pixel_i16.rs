@@ -60,7 +60,11 @@ impl PixelDataSliceI16 {
         let bytes = pdinfo.take_bytes();
         for _i in 0..len {
             for _j in 0..samples {
-                let val = i16::from(bytes[in_pos]);
+                let val = if pdinfo.is_signed() {
+                    i16::from(i8::from_ne_bytes([bytes[in_pos]]))
+                } else {
+                    i16::from(bytes[in_pos])
+                };
                 in_pos += I8_SIZE;
 
                 buffer.push(val);
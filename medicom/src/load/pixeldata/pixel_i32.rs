@@ -139,18 +139,6 @@ impl PixelDataSliceI32 {
         }
     }
 
-    /// Consume this slice and convert into `Vec<i16>`, also returning the `PixelDataSliceInfo`.
-    ///
-    /// # Errors
-    /// - `PixelValueError` if unable to convert the `i32` into `i16`.
-    pub fn into_i16(self) -> Result<(PixelDataSliceInfo, Vec<i16>), LoadError> {
-        let mut buffer: Vec<i16> = Vec::with_capacity(self.buffer.len());
-        for v in &self.buffer {
-            buffer.push(i16::try_from(*v).map_err(|e| LoadError::PixelValueError { source: e })?);
-        }
-        Ok((self.info, buffer))
-    }
-
     #[must_use]
     pub fn info(&self) -> &PixelDataSliceInfo {
         &self.info
@@ -162,8 +150,8 @@ impl PixelDataSliceI32 {
     }
 
     #[must_use]
-    pub fn into_buffer(self) -> Vec<i32> {
-        self.buffer
+    pub fn into_buffer(self) -> (PixelDataSliceInfo, Vec<i32>) {
+        (self.info, self.buffer)
     }
 
     #[must_use]
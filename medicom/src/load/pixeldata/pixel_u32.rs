@@ -14,10 +14,13 @@
    limitations under the License.
 */
 
-use crate::load::pixeldata::{
-    pdinfo::{PixelDataSliceInfo, I32_SIZE, U32_SIZE},
-    winlevel::WindowLevel,
-    LoadError, PhotoInterp, PixelDataSlice,
+use crate::load::{
+    pixeldata::{
+        pdinfo::{PixelDataSliceInfo, I32_SIZE, U32_SIZE},
+        winlevel::WindowLevel,
+        LoadError, PhotoInterp, PixelDataSlice,
+    },
+    EPSILON_F32,
 };
 
 pub struct PixelDataSliceU32 {
@@ -45,7 +48,48 @@ impl PixelDataSliceU32 {
     ///
     /// # Errors
     /// - I/O errors reading the data.
-    pub fn from_rgb_32bit(mut pdinfo: PixelDataSliceInfo) -> Result<Self, LoadError> {
+    pub fn from_rgb_32bit(pdinfo: PixelDataSliceInfo) -> Result<Self, LoadError> {
+        Self::read_32bit(pdinfo)
+    }
+
+    /// Create `PixelDataSliceU32` from unsigned 32-bit monochrome slice data, retaining values
+    /// above `i32::MAX`.
+    ///
+    /// # Errors
+    /// - Any errors interpreting little/big -endian bytes as 32bit numbers.
+    pub fn from_mono_32bit(pdinfo: PixelDataSliceInfo) -> Result<Self, LoadError> {
+        let mut slice = Self::read_32bit(pdinfo)?;
+        let pdinfo = &mut slice.info;
+
+        let min = pdinfo.min_val();
+        let max = pdinfo.max_val();
+        let minmax_width = max - min;
+        let minmax_center = min + minmax_width / 2_f32;
+        let mut already_has_minmax = false;
+        for winlevel in pdinfo.win_levels_mut() {
+            winlevel.set_out_min(u32::MIN as f32);
+            winlevel.set_out_max(u32::MAX as f32);
+
+            let same_width = (winlevel.width() - minmax_width).abs() < EPSILON_F32;
+            let same_center = (winlevel.center() - minmax_center).abs() < EPSILON_F32;
+            if same_width && same_center {
+                already_has_minmax = true;
+            }
+        }
+        if !already_has_minmax {
+            pdinfo.win_levels_mut().push(WindowLevel::new(
+                "Min/Max".to_string(),
+                minmax_center,
+                minmax_width,
+                u32::MIN as f32,
+                u32::MAX as f32,
+            ));
+        }
+        Ok(slice)
+    }
+
+    /// Reads the 32-bit samples, tracking the minimum and maximum values excluding padding.
+    fn read_32bit(mut pdinfo: PixelDataSliceInfo) -> Result<Self, LoadError> {
         let num_frames = usize::try_from(pdinfo.num_frames()).unwrap_or(1);
         let samples = usize::from(pdinfo.samples_per_pixel());
         let len = usize::from(pdinfo.cols()) * usize::from(pdinfo.rows()) * num_frames;
@@ -116,18 +160,6 @@ impl PixelDataSliceU32 {
         }
     }
 
-    /// Consume this slice and convert into `Vec<i16>`, also returning the `PixelDataSliceInfo`.
-    ///
-    /// # Errors
-    /// - `PixelValueError` if unable to convert the `u32` into `i16`.
-    pub fn into_i16(self) -> Result<(PixelDataSliceInfo, Vec<i16>), LoadError> {
-        let mut buffer: Vec<i16> = Vec::with_capacity(self.buffer.len());
-        for v in &self.buffer {
-            buffer.push(i16::try_from(*v).map_err(|e| LoadError::PixelValueError { source: e })?);
-        }
-        Ok((self.info, buffer))
-    }
-
     #[must_use]
     pub fn info(&self) -> &PixelDataSliceInfo {
         &self.info
@@ -139,8 +171,8 @@ impl PixelDataSliceU32 {
     }
 
     #[must_use]
-    pub fn into_buffer(self) -> Vec<u32> {
-        self.buffer
+    pub fn into_buffer(self) -> (PixelDataSliceInfo, Vec<u32>) {
+        (self.info, self.buffer)
     }
 
     #[must_use]
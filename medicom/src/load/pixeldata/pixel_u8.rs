@@ -43,6 +43,28 @@ impl PixelDataSliceU8 {
         PixelDataSliceU8::new(pdinfo, buffer)
     }
 
+    /// Create `PixelDataSliceU8` from unsigned 8-bit monochrome slice data. The minimum and
+    /// maximum values exclude the Pixel Padding Value.
+    #[must_use]
+    pub fn from_mono_8bit(mut pdinfo: PixelDataSliceInfo) -> Self {
+        let pixel_pad = pdinfo
+            .pixel_pad()
+            .and_then(|pad_val| TryInto::<u8>::try_into(pad_val).ok());
+        let buffer = pdinfo.take_bytes();
+        let unpadded = buffer
+            .iter()
+            .copied()
+            .filter(|val| pixel_pad.is_none_or(|pad_val| *val != pad_val));
+        let (min, max) = unpadded.fold((u8::MAX, u8::MIN), |(min, max), val| {
+            (min.min(val), max.max(val))
+        });
+
+        let mut slice = PixelDataSliceU8::new(pdinfo, buffer);
+        slice.info.set_min_val(f32::from(min));
+        slice.info.set_max_val(f32::from(max));
+        slice
+    }
+
     #[must_use]
     pub fn new(mut info: PixelDataSliceInfo, buffer: Vec<u8>) -> Self {
         let stride = if info.planar_config() == 0 {
@@ -64,15 +86,6 @@ impl PixelDataSliceU8 {
         }
     }
 
-    #[must_use]
-    pub fn into_i16(self) -> (PixelDataSliceInfo, Vec<i16>) {
-        let mut buffer: Vec<i16> = Vec::with_capacity(self.buffer().len());
-        for b in &self.buffer {
-            buffer.push(i16::from(*b));
-        }
-        (self.info, buffer)
-    }
-
     #[must_use]
     pub fn info(&self) -> &PixelDataSliceInfo {
         &self.info
@@ -84,8 +97,8 @@ impl PixelDataSliceU8 {
     }
 
     #[must_use]
-    pub fn into_buffer(self) -> Vec<u8> {
-        self.buffer
+    pub fn into_buffer(self) -> (PixelDataSliceInfo, Vec<u8>) {
+        (self.info, self.buffer)
     }
 
     #[must_use]
@@ -14,15 +14,38 @@
    limitations under the License.
 */
 
+/// The VOI LUT Function, which determines how Window Center and Window Width are applied. Refer
+/// to Part 3, Section C.11.2.1.3.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VoiLutFunction {
+    #[default]
+    Linear,
+    LinearExact,
+    Sigmoid,
+}
+
+impl From<&str> for VoiLutFunction {
+    /// Parse VOI LUT Function from its DICOM element value. Unrecognized values are `Linear`,
+    /// which is the default when not specified.
+    fn from(value: &str) -> Self {
+        match value.trim() {
+            "LINEAR_EXACT" => Self::LinearExact,
+            "SIGMOID" => Self::Sigmoid,
+            _ => Self::Linear,
+        }
+    }
+}
+
 /// Represents a Window/Level that can be applied to adjust values from one scale to another.
 /// Referto Part 3, Section C.11.2, specifically C.11.2.1.2 Window Center and Window Width.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct WindowLevel {
     name: String,
     center: f32,
     width: f32,
     out_min: f32,
     out_max: f32,
+    function: VoiLutFunction,
 }
 
 impl WindowLevel {
@@ -34,6 +57,7 @@ impl WindowLevel {
             width,
             out_min,
             out_max,
+            function: VoiLutFunction::default(),
         }
     }
 
@@ -82,20 +106,36 @@ impl WindowLevel {
         self.out_max = out_max;
     }
 
+    #[must_use]
+    pub fn function(&self) -> VoiLutFunction {
+        self.function
+    }
+
+    pub fn set_function(&mut self, function: VoiLutFunction) {
+        self.function = function;
+    }
+
     #[must_use]
     pub fn with_out(&self, out_min: f32, out_max: f32) -> Self {
-        Self::new(
-            self.name().to_string(),
-            self.center(),
-            self.width(),
+        Self {
             out_min,
             out_max,
-        )
+            ..self.clone()
+        }
     }
 
-    /// Converts the given value to this window/level, per Part 3, Section C.11.2.1.2.1.
+    /// Converts the given value to this window/level using its VOI LUT Function, per Part 3,
+    /// Section C.11.2.1.2.1 for `LINEAR` and Section C.11.2.1.3 for `LINEAR_EXACT` and `SIGMOID`.
     #[must_use]
     pub fn apply(&self, value: f32) -> f32 {
+        match self.function {
+            VoiLutFunction::Linear => self.apply_linear(value),
+            VoiLutFunction::LinearExact => self.apply_linear_exact(value),
+            VoiLutFunction::Sigmoid => self.apply_sigmoid(value),
+        }
+    }
+
+    fn apply_linear(&self, value: f32) -> f32 {
         let center = self.center - 0.5_f32;
         let width = self.width - 1_f32;
         let half_width = width / 2_f32;
@@ -107,11 +147,29 @@ impl WindowLevel {
             ((value - center) / width + 0.5_f32) * (self.out_max - self.out_min) + self.out_min
         }
     }
+
+    fn apply_linear_exact(&self, value: f32) -> f32 {
+        let half_width = self.width / 2_f32;
+        if value <= self.center - half_width {
+            self.out_min
+        } else if value > self.center + half_width {
+            self.out_max
+        } else {
+            ((value - self.center) / self.width + 0.5_f32) * (self.out_max - self.out_min)
+                + self.out_min
+        }
+    }
+
+    fn apply_sigmoid(&self, value: f32) -> f32 {
+        (self.out_max - self.out_min)
+            / (1_f32 + (-4_f32 * (value - self.center) / self.width).exp())
+            + self.out_min
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::WindowLevel;
+    use super::{VoiLutFunction, WindowLevel};
 
     #[test]
     pub fn test_winlevel() {
@@ -130,4 +188,28 @@ mod tests {
         let v = wl.apply(100_f32) as u8;
         assert_eq!(u8::MAX / 2 + 1, v);
     }
+
+    #[test]
+    pub fn test_voi_lut_functions() {
+        let mut wl = WindowLevel::new(String::new(), 100_f32, 200_f32, 0_f32, 1_f32);
+        assert_eq!(VoiLutFunction::Sigmoid, VoiLutFunction::from("SIGMOID"));
+        assert_eq!(VoiLutFunction::Linear, VoiLutFunction::from("OTHER"));
+
+        wl.set_function(VoiLutFunction::LinearExact);
+        assert!((wl.apply(0_f32) - 0_f32).abs() < f32::EPSILON);
+        assert!((wl.apply(50_f32) - 0.25).abs() < f32::EPSILON);
+        assert!((wl.apply(100_f32) - 0.5).abs() < f32::EPSILON);
+        assert!((wl.apply(300_f32) - 1_f32).abs() < f32::EPSILON);
+
+        wl.set_function(VoiLutFunction::Sigmoid);
+        assert!((wl.apply(100_f32) - 0.5).abs() < f32::EPSILON);
+        // 1 / (1 + e^2) at one half-width below the center.
+        assert!((wl.apply(0_f32) - 0.1192).abs() < 0.001);
+        assert!((wl.apply(200_f32) - 0.8808).abs() < 0.001);
+        // The function is kept when changing the output range.
+        assert_eq!(
+            VoiLutFunction::Sigmoid,
+            wl.with_out(0_f32, 255_f32).function()
+        );
+    }
 }
@@ -0,0 +1,244 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Conversion of the YBR Photometric Interpretations to RGB, Part 3 Section C.7.6.3.1.2.
+
+use crate::{
+    core::codec::FrameLayout,
+    load::pixeldata::{LoadError, PhotoInterp},
+};
+
+/// Converts YBR pixel data to RGB, interleaved by pixel.
+///
+/// `photo_interp` - The YBR Photometric Interpretation of the pixel data.
+/// `bytes` - The native pixel data of all frames.
+/// `layout` - The layout of a single frame, with the chroma at full resolution.
+/// `num_frames` - The number of frames in `bytes`.
+/// `planar` - Whether the samples are separated by plane, Planar Configuration of 1.
+/// `big_endian` - Whether 16-bit samples are big-endian.
+///
+/// The chroma of `YBR_FULL_422` and `YBR_PARTIAL_422` is upsampled when it is horizontally
+/// subsampled as described for native pixel data, where each pair of pixels is stored as
+/// `Y1 Y2 Cb Cr`. Pixel data already at full resolution, such as from codecs which upsample while
+/// decoding, is converted as-is.
+///
+/// # Errors
+/// - `InvalidBitsAlloc` if the samples are not 8-bit or 16-bit.
+/// - `InvalidDims` if the number of samples does not match the dimensions.
+pub fn ybr_to_rgb(
+    photo_interp: &PhotoInterp,
+    bytes: &[u8],
+    layout: &FrameLayout,
+    num_frames: usize,
+    planar: bool,
+    big_endian: bool,
+) -> Result<Vec<u8>, LoadError> {
+    let bytes_per_sample = layout.bytes_per_sample();
+    if bytes_per_sample != 1 && bytes_per_sample != 2 {
+        return Err(LoadError::InvalidBitsAlloc(layout.bits_alloc));
+    }
+    let samples = bytes
+        .chunks_exact(bytes_per_sample)
+        .map(|s| match s {
+            [val] => i64::from(*val),
+            [a, b] if big_endian => i64::from(u16::from_be_bytes([*a, *b])),
+            [a, b] => i64::from(u16::from_le_bytes([*a, *b])),
+            _ => 0,
+        })
+        .collect::<Vec<i64>>();
+
+    let pixel_count = layout.pixel_count();
+    let full_len = pixel_count * 3;
+    let subsampled_len = pixel_count * 2;
+    let subsampled = matches!(
+        photo_interp,
+        PhotoInterp::YbrFull422 | PhotoInterp::YbrPartial422
+    );
+    let ybr = if full_len > 0 && samples.len() == full_len * num_frames {
+        if planar {
+            samples
+                .chunks_exact(full_len)
+                .flat_map(|frame| {
+                    (0..pixel_count).map(move |i| {
+                        [frame[i], frame[i + pixel_count], frame[i + pixel_count * 2]]
+                    })
+                })
+                .collect::<Vec<[i64; 3]>>()
+        } else {
+            samples
+                .chunks_exact(3)
+                .map(|p| [p[0], p[1], p[2]])
+                .collect::<Vec<[i64; 3]>>()
+        }
+    } else if subsampled && layout.cols % 2 == 0 && samples.len() == subsampled_len * num_frames {
+        samples
+            .chunks_exact(4)
+            .flat_map(|p| [[p[0], p[2], p[3]], [p[1], p[2], p[3]]])
+            .collect::<Vec<[i64; 3]>>()
+    } else {
+        return Err(LoadError::InvalidDims(format!(
+            "{} samples do not fit {}x{} {photo_interp:?} frames",
+            samples.len(),
+            layout.cols,
+            layout.rows
+        )));
+    };
+
+    let bits = u32::from(layout.bits_stored.clamp(1, layout.bits_alloc));
+    let max = (1i64 << bits) - 1;
+    let half = 1i64 << (bits - 1);
+    let mut rgb = Vec::with_capacity(ybr.len() * 3 * bytes_per_sample);
+    for [y, cb, cr] in ybr {
+        for val in ybr_pixel_to_rgb(photo_interp, [y, cb - half, cr - half], bits) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let val = val.clamp(0, max) as u16;
+            if bytes_per_sample == 1 {
+                #[allow(clippy::cast_possible_truncation)]
+                rgb.push(val as u8);
+            } else if big_endian {
+                rgb.extend_from_slice(&val.to_be_bytes());
+            } else {
+                rgb.extend_from_slice(&val.to_le_bytes());
+            }
+        }
+    }
+    Ok(rgb)
+}
+
+/// Converts a single pixel to RGB, where the chroma is centered on zero.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn ybr_pixel_to_rgb(photo_interp: &PhotoInterp, [y, cb, cr]: [i64; 3], bits: u32) -> [i64; 3] {
+    let round = |val: f64| val.round() as i64;
+    match photo_interp {
+        PhotoInterp::YbrRct => {
+            // The inverse of the reversible color transform is exact in integers.
+            let g = y - (cb + cr).div_euclid(4);
+            [cr + g, g, cb + g]
+        }
+        PhotoInterp::YbrPartial422 | PhotoInterp::YbrPartial420 => {
+            // Luminance has a black level of 16 and chroma a reduced range, scaled for the bits.
+            let black = (16i64 << bits.saturating_sub(8)) as f64;
+            let (y, cb, cr) = (1.1644 * (y as f64 - black), cb as f64, cr as f64);
+            [
+                round(y + 1.5960 * cr),
+                round(y - 0.3918 * cb - 0.8130 * cr),
+                round(y + 2.0172 * cb),
+            ]
+        }
+        _ => {
+            let (y, cb, cr) = (y as f64, cb as f64, cr as f64);
+            [
+                round(y + 1.402 * cr),
+                round(y - 0.344_136 * cb - 0.714_136 * cr),
+                round(y + 1.772 * cb),
+            ]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::codec::FrameLayout,
+        load::pixeldata::{ybr::ybr_to_rgb, PhotoInterp},
+    };
+
+    const fn layout(cols: u16, bits: u16) -> FrameLayout {
+        FrameLayout {
+            rows: 1,
+            cols,
+            samples_per_pixel: 3,
+            bits_alloc: bits,
+            bits_stored: bits,
+            pixel_rep: 0,
+        }
+    }
+
+    #[test]
+    pub fn test_ybr_full() {
+        // Neutral chroma is gray, and the YBR_FULL encoding of pure red.
+        let ybr = [100, 128, 128, 76, 85, 255];
+        let rgb = ybr_to_rgb(&PhotoInterp::YbrFull, &ybr, &layout(2, 8), 1, false, false).unwrap();
+        assert_eq!(vec![100, 100, 100, 254, 0, 0], rgb);
+
+        // The same pixels separated into planes.
+        let planar = [100, 76, 128, 85, 128, 255];
+        let rgb = ybr_to_rgb(
+            &PhotoInterp::YbrFull,
+            &planar,
+            &layout(2, 8),
+            1,
+            true,
+            false,
+        )
+        .unwrap();
+        assert_eq!(vec![100, 100, 100, 254, 0, 0], rgb);
+
+        // 16-bit samples center chroma on the 16-bit midpoint.
+        let ybr = [1000u16, 32768, 32768]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let rgb = ybr_to_rgb(&PhotoInterp::YbrFull, &ybr, &layout(1, 16), 1, false, false).unwrap();
+        assert_eq!(
+            [1000u16; 3]
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect::<Vec<u8>>(),
+            rgb
+        );
+    }
+
+    #[test]
+    pub fn test_ybr_subsampled() {
+        // Each pair of pixels shares its chroma.
+        let ybr = [10, 20, 128, 128, 30, 40, 128, 128];
+        let rgb = ybr_to_rgb(
+            &PhotoInterp::YbrFull422,
+            &ybr,
+            &layout(4, 8),
+            1,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(vec![10, 10, 10, 20, 20, 20, 30, 30, 30, 40, 40, 40], rgb);
+
+        // Partial range maps the luminance black and white levels to the full range.
+        let ybr = [16, 235, 128, 128];
+        let rgb = ybr_to_rgb(
+            &PhotoInterp::YbrPartial422,
+            &ybr,
+            &layout(2, 8),
+            1,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(vec![0, 0, 0, 255, 255, 255], rgb);
+
+        // YBR_FULL cannot be subsampled.
+        assert!(ybr_to_rgb(&PhotoInterp::YbrFull, &ybr, &layout(2, 8), 1, false, false).is_err());
+    }
+
+    #[test]
+    pub fn test_ybr_rct() {
+        // R=200, G=100, B=50 with the reversible color transform, chroma offset by 128.
+        let ybr = [112, 78, 228];
+        let rgb = ybr_to_rgb(&PhotoInterp::YbrRct, &ybr, &layout(1, 8), 1, false, false).unwrap();
+        assert_eq!(vec![200, 100, 50], rgb);
+    }
+}
@@ -0,0 +1,186 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Intensity projections, which collapse a slab of an `ImageVolume` into a single image by taking
+//! the maximum, minimum or average of the values along each ray through the slab.
+
+use std::str::FromStr;
+
+use crate::load::pixeldata::LoadError;
+
+/// How the values along a ray through a slab are combined into a pixel of the projection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ProjectionMode {
+    /// Maximum Intensity Projection (MIP).
+    #[default]
+    Maximum,
+    /// Minimum Intensity Projection (MinIP).
+    Minimum,
+    /// Average Intensity Projection (AIP).
+    Average,
+}
+
+impl ProjectionMode {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectionMode::Maximum => "MIP",
+            ProjectionMode::Minimum => "MinIP",
+            ProjectionMode::Average => "AIP",
+        }
+    }
+
+    /// Combines the red, green and blue values along a ray, each independently. Returns `None` if
+    /// there are no values.
+    pub fn combine<I>(&self, values: I) -> Option<[f32; 3]>
+    where
+        I: IntoIterator<Item = [f32; 3]>,
+    {
+        let mut count = 0_u32;
+        let mut acc = match self {
+            ProjectionMode::Maximum => [f32::MIN; 3],
+            ProjectionMode::Minimum => [f32::MAX; 3],
+            ProjectionMode::Average => [0_f32; 3],
+        };
+        for vals in values {
+            count += 1;
+            for (a, v) in acc.iter_mut().zip(vals) {
+                *a = match self {
+                    ProjectionMode::Maximum => a.max(v),
+                    ProjectionMode::Minimum => a.min(v),
+                    ProjectionMode::Average => *a + v,
+                };
+            }
+        }
+        if count == 0 {
+            return None;
+        }
+        if *self == ProjectionMode::Average {
+            #[allow(clippy::cast_precision_loss)]
+            let count = count as f32;
+            acc = acc.map(|a| a / count);
+        }
+        Some(acc)
+    }
+}
+
+impl std::fmt::Display for ProjectionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl FromStr for ProjectionMode {
+    type Err = LoadError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "mip" | "max" | "maximum" => Ok(Self::Maximum),
+            "minip" | "min" | "minimum" => Ok(Self::Minimum),
+            "aip" | "avg" | "average" => Ok(Self::Average),
+            other => Err(LoadError::InvalidProjection(format!(
+                "unknown projection mode: {other}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::load::{
+        imgvol::ImageVolume,
+        projection::ProjectionMode,
+        resample::{Interpolation, ObliquePlane, ResampledVolume},
+        testsupport::{frame_groups, multi_frame},
+        DicomVec, IndexVec, VolAxis,
+    };
+
+    #[test]
+    pub fn test_combine() {
+        let values = [[1.0, 5.0, 0.0], [3.0, 2.0, 0.0], [2.0, -1.0, 0.0]];
+        assert_eq!(
+            Some([3.0, 5.0, 0.0]),
+            ProjectionMode::Maximum.combine(values)
+        );
+        assert_eq!(
+            Some([1.0, -1.0, 0.0]),
+            ProjectionMode::Minimum.combine(values)
+        );
+        assert_eq!(
+            Some([2.0, 2.0, 0.0]),
+            ProjectionMode::Average.combine(values)
+        );
+        assert_eq!(None, ProjectionMode::Average.combine([]));
+
+        assert_eq!(ProjectionMode::Minimum, "MinIP".parse().unwrap());
+        assert_eq!(ProjectionMode::Average, "avg".parse().unwrap());
+        assert!("sum".parse::<ProjectionMode>().is_err());
+    }
+
+    #[test]
+    pub fn test_projections() {
+        let dcmroot = multi_frame(
+            4,
+            vec![
+                frame_groups(15.0),
+                frame_groups(10.0),
+                frame_groups(5.0),
+                frame_groups(0.0),
+            ],
+        );
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+
+        let value = |vol: &ResampledVolume| vol.get_pixel(IndexVec { x: 1, y: 1, z: 0 }).unwrap().r;
+        let mip = volume
+            .project(&VolAxis::Z, 0..4, ProjectionMode::Maximum)
+            .unwrap();
+        assert!((value(&mip) - -1021.0).abs() < f32::EPSILON);
+        let slab = volume.axis_slab(&VolAxis::Z, 1, 10.0);
+        assert_eq!(0..3, slab);
+        let minip = volume
+            .project(&VolAxis::Z, 1..3, ProjectionMode::Minimum)
+            .unwrap();
+        assert!((value(&minip) - -1023.0).abs() < f32::EPSILON);
+        assert!((minip.voxel_to_patient().apply_index(IndexVec::default()).z - 10.0).abs() < 1e-4);
+        let aip = volume
+            .project(&VolAxis::X, 0..2, ProjectionMode::Average)
+            .unwrap();
+        assert_eq!((2, 4), (aip.counts().x, aip.counts().y));
+        assert!((value(&aip) - -1023.0).abs() < f32::EPSILON);
+        assert!(volume
+            .project(&VolAxis::Z, 2..5, ProjectionMode::Maximum)
+            .is_err());
+
+        // A 10mm slab centered between the second and third slices.
+        let plane = ObliquePlane {
+            origin: DicomVec::new(-10.0, -10.0, 7.5),
+            row_dir: DicomVec::new(1.0, 0.0, 0.0),
+            col_dir: DicomVec::new(0.0, 1.0, 0.0),
+            cols: 2,
+            rows: 2,
+            spacing: (0.5, 0.5),
+        };
+        let oblique = |mode| {
+            volume
+                .project_oblique(&plane, 10.0, mode, Interpolation::Trilinear)
+                .unwrap()
+        };
+        assert!((value(&oblique(ProjectionMode::Maximum)) - -1021.5).abs() < 1e-3);
+        assert!((value(&oblique(ProjectionMode::Minimum)) - -1023.5).abs() < 1e-3);
+        assert!((value(&oblique(ProjectionMode::Average)) - -1022.5).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,358 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Resampling an `ImageVolume` onto another grid of voxels, such as a grid of isotropic voxels or
+//! an oblique plane for multi-planar reformatting.
+
+use crate::load::{geometry::Affine, DicomVec, IndexVec, VolAxis, VolPixel};
+
+/// The method of interpolating between voxels when sampling a volume between voxel centers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// The value of the nearest voxel.
+    Nearest,
+    /// Linear interpolation between the 8 surrounding voxels.
+    #[default]
+    Trilinear,
+    /// Catmull-Rom cubic interpolation between the 64 surrounding voxels.
+    Cubic,
+}
+
+impl Interpolation {
+    /// Samples a volume of the given counts at a continuous voxel index, using `fetch` to get the
+    /// samples of a voxel. Positions within half a voxel of the volume's edge are sampled by
+    /// repeating the edge voxels. Returns `None` if the position is outside the volume.
+    pub fn sample<F>(&self, counts: &IndexVec, pos: DicomVec, fetch: F) -> Option<[f32; 3]>
+    where
+        F: Fn(IndexVec) -> Option<[f32; 3]>,
+    {
+        let inside = |p: f32, count: usize| {
+            #[allow(clippy::cast_precision_loss)]
+            let max = count as f32 - 0.5;
+            count > 0 && p >= -0.5 && p <= max
+        };
+        if !inside(pos.x, counts.x) || !inside(pos.y, counts.y) || !inside(pos.z, counts.z) {
+            return None;
+        }
+
+        match self {
+            Interpolation::Nearest => fetch(IndexVec {
+                x: Self::clamp(pos.x.round(), counts.x),
+                y: Self::clamp(pos.y.round(), counts.y),
+                z: Self::clamp(pos.z.round(), counts.z),
+            }),
+            Interpolation::Trilinear => {
+                let weights = |p: f32| [1_f32 - (p - p.floor()), p - p.floor()];
+                Self::convolve(counts, pos, 0, weights, fetch)
+            }
+            Interpolation::Cubic => Self::convolve(counts, pos, 1, Self::catmull_rom, fetch),
+        }
+    }
+
+    /// Sums the voxels around `pos` scaled by the product of their weights along each axis. The
+    /// voxels start `before` voxels before the voxel at or below `pos`, and there is one for each
+    /// weight.
+    fn convolve<F, W, const N: usize>(
+        counts: &IndexVec,
+        pos: DicomVec,
+        before: usize,
+        weights: W,
+        fetch: F,
+    ) -> Option<[f32; 3]>
+    where
+        F: Fn(IndexVec) -> Option<[f32; 3]>,
+        W: Fn(f32) -> [f32; N],
+    {
+        #[allow(clippy::cast_precision_loss)]
+        let indices = |p: f32, count: usize| {
+            let start = p.floor() - before as f32;
+            std::array::from_fn::<usize, N, _>(|i| {
+                #[allow(clippy::cast_precision_loss)]
+                Self::clamp(start + i as f32, count)
+            })
+        };
+        let (xs, ys, zs) = (
+            indices(pos.x, counts.x),
+            indices(pos.y, counts.y),
+            indices(pos.z, counts.z),
+        );
+        let (wx, wy, wz) = (weights(pos.x), weights(pos.y), weights(pos.z));
+
+        let mut sum = [0_f32; 3];
+        for (z, wz) in zs.iter().zip(wz) {
+            for (y, wy) in ys.iter().zip(wy) {
+                for (x, wx) in xs.iter().zip(wx) {
+                    let weight = wx * wy * wz;
+                    let vals = fetch(IndexVec {
+                        x: *x,
+                        y: *y,
+                        z: *z,
+                    })?;
+                    for (s, v) in sum.iter_mut().zip(vals) {
+                        *s += v * weight;
+                    }
+                }
+            }
+        }
+        Some(sum)
+    }
+
+    /// The Catmull-Rom weights of the 4 voxels around `p`, from the voxel before the one at or
+    /// below `p`.
+    fn catmull_rom(p: f32) -> [f32; 4] {
+        let t = p - p.floor();
+        let (t2, t3) = (t * t, t * t * t);
+        [
+            (-t3 + 2_f32 * t2 - t) / 2_f32,
+            (3_f32 * t3 - 5_f32 * t2 + 2_f32) / 2_f32,
+            (-3_f32 * t3 + 4_f32 * t2 + t) / 2_f32,
+            (t3 - t2) / 2_f32,
+        ]
+    }
+
+    /// Clamps a whole number index to the voxels of an axis with the given count.
+    fn clamp(index: f32, count: usize) -> usize {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index = index.max(0_f32) as usize;
+        index.min(count.saturating_sub(1))
+    }
+}
+
+/// A plane in patient space to sample from a volume, for multi-planar reformatting.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ObliquePlane {
+    /// The position in patient space of the center of the top-left pixel.
+    pub origin: DicomVec,
+    /// The direction of increasing column, along each row.
+    pub row_dir: DicomVec,
+    /// The direction of increasing row, down each column.
+    pub col_dir: DicomVec,
+    /// The number of columns of the plane.
+    pub cols: usize,
+    /// The number of rows of the plane.
+    pub rows: usize,
+    /// The distance in mm between pixels along the row direction and along the column direction.
+    pub spacing: (f32, f32),
+}
+
+impl ObliquePlane {
+    /// The transform from pixels of the plane (column, row, 0) to patient space. The z-axis is
+    /// the unit normal of the plane.
+    #[must_use]
+    pub fn voxel_to_patient(&self) -> Affine {
+        let row = self.row_dir.normalized().unwrap_or_default();
+        let col = self.col_dir.normalized().unwrap_or_default();
+        let normal = row.cross(&col).normalized().unwrap_or_default();
+        Affine::from_columns(
+            row * self.spacing.0,
+            col * self.spacing.1,
+            normal,
+            self.origin,
+        )
+    }
+}
+
+/// Values sampled from an `ImageVolume` onto a new grid of voxels. Values are those returned by
+/// `ImageVolume::get_pixel`, with the modality transform applied.
+#[derive(Clone, Debug)]
+pub struct ResampledVolume {
+    counts: IndexVec,
+    voxel_to_patient: Affine,
+    is_rgb: bool,
+    /// The samples of each voxel ordered by x, then y, then z. RGB volumes have three samples per
+    /// voxel.
+    samples: Vec<f32>,
+}
+
+impl ResampledVolume {
+    pub(crate) fn new(
+        counts: IndexVec,
+        voxel_to_patient: Affine,
+        is_rgb: bool,
+        samples: Vec<f32>,
+    ) -> Self {
+        Self {
+            counts,
+            voxel_to_patient,
+            is_rgb,
+            samples,
+        }
+    }
+
+    /// The number of voxels along each axis.
+    #[must_use]
+    pub fn counts(&self) -> IndexVec {
+        self.counts
+    }
+
+    /// The affine transform from voxel indices to patient coordinates (LPS) in mm.
+    #[must_use]
+    pub fn voxel_to_patient(&self) -> &Affine {
+        &self.voxel_to_patient
+    }
+
+    /// The distance in mm between voxels along each axis.
+    #[must_use]
+    pub fn voxel_dims(&self) -> DicomVec {
+        let [x, y, z, _] = self.voxel_to_patient.columns();
+        DicomVec::new(x.length(), y.length(), z.length())
+    }
+
+    #[must_use]
+    pub fn is_rgb(&self) -> bool {
+        self.is_rgb
+    }
+
+    #[must_use]
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    /// Gets the pixel at the given coordinate (x, y, z), or `None` if it is outside the volume.
+    #[must_use]
+    pub fn get_pixel(&self, coord: IndexVec) -> Option<VolPixel> {
+        if coord.x >= self.counts.x || coord.y >= self.counts.y || coord.z >= self.counts.z {
+            return None;
+        }
+        let index = coord.x + (coord.y + coord.z * self.counts.y) * self.counts.x;
+        let (r, g, b) = if self.is_rgb {
+            let rgb = self.samples.get(index * 3..index * 3 + 3)?;
+            (rgb[0], rgb[1], rgb[2])
+        } else {
+            let val = *self.samples.get(index)?;
+            (val, val, val)
+        };
+        Some(VolPixel { coord, r, g, b })
+    }
+
+    /// Iterates over the pixels of a slice oriented to the given axis, by row then column.
+    pub fn slice_iter(
+        &self,
+        axis: &VolAxis,
+        axis_index: usize,
+    ) -> impl Iterator<Item = VolPixel> + '_ {
+        let axis = axis.clone();
+        (0..)
+            .map_while(move |index| axis.slice_coord(&self.counts, axis_index, index))
+            .filter_map(|coord| self.get_pixel(coord))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::load::{
+        imgvol::ImageVolume,
+        resample::{Interpolation, ObliquePlane, ResampledVolume},
+        testsupport::{frame_groups, multi_frame},
+        DicomVec, IndexVec, VolAxis,
+    };
+
+    /// A 4x1x1 volume whose values are the square of the x index.
+    fn fetch(coord: IndexVec) -> Option<[f32; 3]> {
+        #[allow(clippy::cast_precision_loss)]
+        let val = (coord.x * coord.x) as f32;
+        Some([val; 3])
+    }
+
+    const COUNTS: IndexVec = IndexVec { x: 4, y: 1, z: 1 };
+
+    fn sample(interp: Interpolation, x: f32) -> Option<f32> {
+        interp
+            .sample(&COUNTS, DicomVec::new(x, 0.0, 0.0), fetch)
+            .map(|v| v[0])
+    }
+
+    #[test]
+    pub fn test_interpolation() {
+        assert_eq!(Some(1.0), sample(Interpolation::Nearest, 1.4));
+        assert_eq!(Some(4.0), sample(Interpolation::Nearest, 1.6));
+        assert_eq!(Some(2.5), sample(Interpolation::Trilinear, 1.5));
+        // Catmull-Rom reproduces the quadratic between interior voxels.
+        assert!((sample(Interpolation::Cubic, 1.5).unwrap() - 2.25).abs() < 1e-5);
+
+        // Exact voxel positions are unchanged by each method.
+        for interp in [
+            Interpolation::Nearest,
+            Interpolation::Trilinear,
+            Interpolation::Cubic,
+        ] {
+            assert!((sample(interp, 2.0).unwrap() - 4.0).abs() < 1e-5);
+            assert!(sample(interp, -0.5).is_some());
+            assert!(sample(interp, -0.6).is_none());
+            assert!(sample(interp, 3.6).is_none());
+        }
+    }
+
+    #[test]
+    pub fn test_resample() {
+        // Frames 5mm apart whose values step by 1 from -1024, with 0.5mm pixels.
+        let dcmroot = multi_frame(
+            4,
+            vec![
+                frame_groups(15.0),
+                frame_groups(10.0),
+                frame_groups(5.0),
+                frame_groups(0.0),
+            ],
+        );
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+
+        let iso = volume.resample_isotropic(Interpolation::Trilinear).unwrap();
+        let counts = iso.counts();
+        assert_eq!((2, 2, 31), (counts.x, counts.y, counts.z));
+        assert!((iso.voxel_dims().z - 0.5).abs() < f32::EPSILON);
+        // 7mm below the first slice is 1.4 slices.
+        let value =
+            |vol: &ResampledVolume| vol.get_pixel(IndexVec { x: 1, y: 0, z: 14 }).unwrap().r;
+        assert!((value(&iso) - -1022.6).abs() < 1e-3);
+        let nearest = volume.resample_isotropic(Interpolation::Nearest).unwrap();
+        assert!((value(&nearest) - -1023.0).abs() < f32::EPSILON);
+        let cubic = volume.resample_isotropic(Interpolation::Cubic).unwrap();
+        assert!((value(&cubic) - -1022.6).abs() < 1e-3);
+        let pos = iso
+            .voxel_to_patient()
+            .apply_index(IndexVec { x: 1, y: 0, z: 14 });
+        assert!((pos.z - 8.0).abs() < 1e-4);
+
+        assert!(volume
+            .resample(DicomVec::new(0.0, 1.0, 1.0), Interpolation::Nearest)
+            .is_err());
+
+        // A coronal plane through the first row, 2.5mm between rows. Of the rows beyond the last
+        // slice, the first is within half a slice so it repeats the last slice.
+        let plane = ObliquePlane {
+            origin: DicomVec::new(-10.0, -10.0, 15.0),
+            row_dir: DicomVec::new(1.0, 0.0, 0.0),
+            col_dir: DicomVec::new(0.0, 0.0, -1.0),
+            cols: 2,
+            rows: 9,
+            spacing: (0.5, 2.5),
+        };
+        let mpr = volume.reformat(&plane, Interpolation::Trilinear).unwrap();
+        let column = mpr
+            .slice_iter(&VolAxis::Z, 0)
+            .filter(|p| p.coord.x == 0)
+            .map(|p| p.r)
+            .collect::<Vec<f32>>();
+        assert_eq!(
+            vec![-1024.0, -1023.5, -1023.0, -1022.5, -1022.0, -1021.5, -1021.0, -1021.0, -1024.0],
+            column
+        );
+        let axis_plane = volume.axis_plane(&VolAxis::X, 1);
+        assert_eq!((2, 31), (axis_plane.cols, axis_plane.rows));
+    }
+}
@@ -0,0 +1,215 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! RT Dose grids, multi-frame instances whose frames are positioned by the Grid Frame Offset
+//! Vector and whose values are scaled into Gy by the Dose Grid Scaling.
+
+use crate::{
+    core::{codec::registry::CodecRegistry, dcmobject::DicomRoot},
+    dict::tags,
+    load::{
+        imgvol::ImageVolume, pixeldata::LoadError, resample::Interpolation,
+        resample::ResampledVolume, DicomVec, IndexVec,
+    },
+};
+
+/// An RT Dose instance, loaded as a volume of dose values.
+pub struct RtDose {
+    units: String,
+    dose_type: String,
+    summation_type: String,
+    volume: ImageVolume,
+}
+
+impl RtDose {
+    /// Loads the dose grid of an RT Dose instance. Encapsulated Pixel Data is decoded using the
+    /// given codecs.
+    ///
+    /// # Errors
+    /// - `LoadError` if the dose grid cannot be loaded as a volume.
+    pub fn load(dcmroot: DicomRoot, codecs: &CodecRegistry) -> Result<Self, LoadError> {
+        let string = |tag| {
+            dcmroot
+                .get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .unwrap_or_default()
+        };
+        let units = string(&tags::DoseUnits);
+        let dose_type = string(&tags::DoseType);
+        let summation_type = string(&tags::DoseSummationType);
+
+        let mut volume = ImageVolume::with_codecs(codecs.clone());
+        volume.load_slice(dcmroot)?;
+        Ok(Self {
+            units,
+            dose_type,
+            summation_type,
+            volume,
+        })
+    }
+
+    /// The Dose Units, GY or RELATIVE.
+    #[must_use]
+    pub fn units(&self) -> &str {
+        &self.units
+    }
+
+    /// The Dose Type, such as PHYSICAL or EFFECTIVE.
+    #[must_use]
+    pub fn dose_type(&self) -> &str {
+        &self.dose_type
+    }
+
+    /// The Dose Summation Type, such as PLAN, FRACTION or BEAM.
+    #[must_use]
+    pub fn summation_type(&self) -> &str {
+        &self.summation_type
+    }
+
+    /// The dose grid as a volume, whose values after the modality transform are the dose.
+    #[must_use]
+    pub fn volume(&self) -> &ImageVolume {
+        &self.volume
+    }
+
+    /// The maximum dose of the grid.
+    #[must_use]
+    pub fn max_dose(&self) -> f32 {
+        self.volume.rescale(self.volume.max_val())
+    }
+
+    /// The dose at a position in patient coordinates (LPS) in mm, or `None` if outside the grid.
+    #[must_use]
+    pub fn dose_at(&self, pos: DicomVec, interp: Interpolation) -> Option<f32> {
+        self.volume.sample_patient(pos, interp).map(|vals| vals[0])
+    }
+
+    /// The dose of each voxel of the grid, as floating-point values positioned in patient space.
+    ///
+    /// # Errors
+    /// - `InvalidDims` if the grid has no extent.
+    pub fn dose_grid(&self) -> Result<ResampledVolume, LoadError> {
+        self.volume.resample_to(
+            self.volume.voxel_to_patient(),
+            self.volume.dims().counts(),
+            Interpolation::Nearest,
+        )
+    }
+
+    /// Resamples the dose onto the voxels of another volume, such as the CT it was planned on, for
+    /// display as an overlay. Voxels outside the dose grid have no dose.
+    ///
+    /// # Errors
+    /// - `InvalidDims` if the dose grid has no extent.
+    pub fn resample_onto(
+        &self,
+        target: &ImageVolume,
+        interp: Interpolation,
+    ) -> Result<ResampledVolume, LoadError> {
+        let Some(patient_to_dose) = self.volume.patient_to_voxel() else {
+            return Err(LoadError::InvalidDims(format!(
+                "Unable to resample dose grid without extent: {}",
+                self.volume.dims()
+            )));
+        };
+        let voxel_to_patient = target.voxel_to_patient();
+        let counts = target.dims().counts();
+        let mut samples = Vec::with_capacity(counts.x * counts.y * counts.z);
+        for z in 0..counts.z {
+            for y in 0..counts.y {
+                for x in 0..counts.x {
+                    let pos = voxel_to_patient.apply_index(IndexVec { x, y, z });
+                    let dose = self
+                        .volume
+                        .sample(patient_to_dose.apply(pos), interp)
+                        .map_or(0_f32, |vals| vals[0]);
+                    samples.push(dose);
+                }
+            }
+        }
+        Ok(ResampledVolume::new(
+            counts,
+            voxel_to_patient,
+            false,
+            samples,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{codec::registry::CodecRegistry, values::RawValue},
+        dict::tags,
+        load::{
+            imgvol::ImageVolume, resample::Interpolation, rtdose::RtDose, testsupport::multi_frame,
+            DicomVec,
+        },
+    };
+
+    #[test]
+    pub fn test_rt_dose() {
+        let mut dcmroot = multi_frame(3, Vec::new());
+        // Each frame's values exceed 16 bits.
+        let pixels = (1..=3u32)
+            .flat_map(|frame| [frame * 70_000; 4])
+            .collect::<Vec<u32>>();
+        for (tag, val) in [
+            (&tags::BitsAllocated, RawValue::of_ushort(32)),
+            (&tags::BitsStored, RawValue::of_ushort(32)),
+            (&tags::PixelData, RawValue::DWords(pixels)),
+        ] {
+            dcmroot.remove_child_by_tag(tag);
+            dcmroot.add_child_with_val(tag, val);
+        }
+        // The values are scaled by the Dose Grid Scaling rather than the functional groups.
+        dcmroot
+            .get_child_by_tag_mut(&tags::SharedFunctionalGroupsSequence)
+            .and_then(|sq| sq.get_item_by_index_mut(1))
+            .and_then(|item| item.remove_child_by_tag(&tags::PixelValueTransformationSequence));
+        dcmroot.add_child_with_val(
+            &tags::GridFrameOffsetVector,
+            RawValue::Doubles(vec![0.0, 5.0, 10.0]),
+        );
+        dcmroot.add_child_with_val(&tags::DoseGridScaling, RawValue::of_double(0.001));
+        dcmroot.add_child_with_val(&tags::DoseUnits, RawValue::of_string("GY"));
+        dcmroot.add_child_with_val(&tags::DoseSummationType, RawValue::of_string("PLAN"));
+
+        let dose = RtDose::load(dcmroot, &CodecRegistry::default()).unwrap();
+        assert_eq!("GY", dose.units());
+        assert_eq!("PLAN", dose.summation_type());
+        assert_eq!(3, dose.volume().dims().counts().z);
+        assert!((dose.max_dose() - 210.0).abs() < 1e-3);
+        let at = |z: f32| dose.dose_at(DicomVec::new(0.0, 0.0, z), Interpolation::Trilinear);
+        assert!((at(7.5).unwrap() - 175.0).abs() < 1e-3);
+        assert!((at(0.0).unwrap() - 70.0).abs() < 1e-3);
+        assert_eq!(None, at(15.0));
+
+        // Slices are ordered with the highest z-position first.
+        let grid = dose.dose_grid().unwrap();
+        for (i, expected) in [210.0, 140.0, 70.0].into_iter().enumerate() {
+            assert!((grid.samples()[i * 4] - expected).abs() < 1e-3);
+        }
+
+        // Slices at 2mm and 0mm, within the first 5mm of the grid.
+        let mut ct = ImageVolume::default();
+        ct.load_slice(multi_frame(2, Vec::new())).unwrap();
+        let overlay = dose.resample_onto(&ct, Interpolation::Trilinear).unwrap();
+        assert_eq!(8, overlay.samples().len());
+        assert!((overlay.samples()[3] - 98.0).abs() < 1e-3);
+        assert!((overlay.samples()[4] - 70.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,473 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! RT Structure Sets (RTSTRUCT), whose ROIs are outlined by planar contours in patient space, and
+//! the rasterization of those contours into masks over the voxels of an `ImageVolume`.
+
+use crate::{
+    core::dcmobject::{DicomObject, DicomRoot},
+    dict::tags,
+    load::{imgvol::ImageVolume, pixeldata::LoadError, stats::Region, DicomVec, IndexVec},
+};
+
+/// The Contour Geometric Type of a contour.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContourType {
+    Point,
+    OpenPlanar,
+    OpenNonplanar,
+    ClosedPlanar,
+    Unsupported(String),
+}
+
+impl ContourType {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Point => "POINT",
+            Self::OpenPlanar => "OPEN_PLANAR",
+            Self::OpenNonplanar => "OPEN_NONPLANAR",
+            Self::ClosedPlanar => "CLOSED_PLANAR",
+            Self::Unsupported(value) => value,
+        }
+    }
+}
+
+impl From<&str> for ContourType {
+    fn from(value: &str) -> Self {
+        match value {
+            "POINT" => Self::Point,
+            "OPEN_PLANAR" => Self::OpenPlanar,
+            "OPEN_NONPLANAR" => Self::OpenNonplanar,
+            "CLOSED_PLANAR" => Self::ClosedPlanar,
+            _ => Self::Unsupported(value.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for ContourType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An item of the Contour Sequence of an ROI.
+#[derive(Clone, Debug)]
+pub struct Contour {
+    pub contour_type: ContourType,
+    /// The points of the contour in patient coordinates (LPS) in mm. Closed contours are implicitly
+    /// closed from the last point to the first.
+    pub points: Vec<DicomVec>,
+    /// The SOP Instance UIDs of the images the contour was drawn on.
+    pub image_uids: Vec<String>,
+}
+
+/// An ROI of a structure set, combining its items of the Structure Set ROI Sequence, ROI Contour
+/// Sequence and RT ROI Observations Sequence.
+#[derive(Clone, Debug, Default)]
+pub struct Roi {
+    pub number: i32,
+    pub name: String,
+    pub description: Option<String>,
+    /// AUTOMATIC, SEMIAUTOMATIC or MANUAL.
+    pub generation_algorithm: Option<String>,
+    /// The RT ROI Interpreted Type, such as ORGAN or PTV.
+    pub interpreted_type: Option<String>,
+    pub color: Option<[u8; 3]>,
+    pub contours: Vec<Contour>,
+}
+
+impl Roi {
+    /// The closed planar contours of this ROI, which outline its area on each plane.
+    pub fn closed_contours(&self) -> impl Iterator<Item = &Contour> {
+        self.contours
+            .iter()
+            .filter(|c| c.contour_type == ContourType::ClosedPlanar && c.points.len() >= 3)
+    }
+}
+
+/// An RT Structure Set instance.
+#[derive(Clone, Debug, Default)]
+pub struct StructureSet {
+    label: String,
+    name: Option<String>,
+    frame_of_ref_uid: Option<String>,
+    rois: Vec<Roi>,
+}
+
+impl StructureSet {
+    /// Reads the ROIs of an RT Structure Set instance.
+    ///
+    /// # Errors
+    /// - `InvalidStructureSet` if there is no Structure Set ROI Sequence, or contour data is not
+    ///   made of (x, y, z) triplets.
+    pub fn from_dcmroot(dcmroot: &DicomRoot) -> Result<Self, LoadError> {
+        let string = |obj: &DicomObject, tag| {
+            obj.get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .filter(|s| !s.is_empty())
+        };
+        let roi_number = |obj: &DicomObject, tag| obj.get_value_by_tag(tag).and_then(|v| v.int());
+
+        let Some(roi_sq) = dcmroot.get_child_by_tag(&tags::StructureSetROISequence) else {
+            return Err(LoadError::InvalidStructureSet(
+                "missing Structure Set ROI Sequence".to_owned(),
+            ));
+        };
+        let mut rois = roi_sq
+            .iter_items()
+            .map(|item| Roi {
+                number: roi_number(item, &tags::ROINumber).unwrap_or_default(),
+                name: string(item, &tags::ROIName).unwrap_or_default(),
+                description: string(item, &tags::ROIDescription),
+                generation_algorithm: string(item, &tags::ROIGenerationAlgorithm),
+                ..Roi::default()
+            })
+            .collect::<Vec<Roi>>();
+        let frame_of_ref_uid = roi_sq
+            .get_item_by_index(1)
+            .and_then(|item| string(item, &tags::ReferencedFrameofReferenceUID));
+
+        if let Some(sq) = dcmroot.get_child_by_tag(&tags::RTROIObservationsSequence) {
+            for item in sq.iter_items() {
+                let number = roi_number(item, &tags::ReferencedROINumber);
+                if let Some(roi) = rois.iter_mut().find(|r| Some(r.number) == number) {
+                    roi.interpreted_type = string(item, &tags::RTROIInterpretedType);
+                }
+            }
+        }
+
+        if let Some(sq) = dcmroot.get_child_by_tag(&tags::ROIContourSequence) {
+            for item in sq.iter_items() {
+                let number = roi_number(item, &tags::ReferencedROINumber);
+                let Some(roi) = rois.iter_mut().find(|r| Some(r.number) == number) else {
+                    continue;
+                };
+                roi.color = item
+                    .get_value_by_tag(&tags::ROIDisplayColor)
+                    .map(|v| v.ints())
+                    .filter(|rgb| rgb.len() == 3)
+                    .map(|rgb| {
+                        [rgb[0], rgb[1], rgb[2]]
+                            .map(|c| u8::try_from(c.clamp(0, 255)).unwrap_or_default())
+                    });
+                if let Some(contour_sq) = item.get_child_by_tag(&tags::ContourSequence) {
+                    for contour in contour_sq.iter_items() {
+                        if let Some(contour) = Self::read_contour(contour)? {
+                            roi.contours.push(contour);
+                        }
+                    }
+                }
+            }
+        }
+
+        let root_string = |tag| {
+            dcmroot
+                .get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .filter(|s| !s.is_empty())
+        };
+        Ok(Self {
+            label: root_string(&tags::StructureSetLabel).unwrap_or_default(),
+            name: root_string(&tags::StructureSetName),
+            frame_of_ref_uid,
+            rois,
+        })
+    }
+
+    /// Reads an item of the Contour Sequence, or `None` if it has no Contour Geometric Type.
+    fn read_contour(item: &DicomObject) -> Result<Option<Contour>, LoadError> {
+        let Some(contour_type) = item
+            .get_value_by_tag(&tags::ContourGeometricType)
+            .and_then(|v| v.string().map(|s| ContourType::from(s.trim())))
+        else {
+            return Ok(None);
+        };
+        let coords = item
+            .get_value_by_tag(&tags::ContourData)
+            .map(|v| v.floats())
+            .unwrap_or_default();
+        if coords.len() % 3 != 0 {
+            return Err(LoadError::InvalidStructureSet(format!(
+                "contour data has {} values, which is not a multiple of 3",
+                coords.len()
+            )));
+        }
+        let points = coords
+            .chunks_exact(3)
+            .map(|p| DicomVec::new(p[0], p[1], p[2]))
+            .collect();
+        let image_uids = item
+            .get_child_by_tag(&tags::ContourImageSequence)
+            .map(|sq| {
+                sq.iter_items()
+                    .filter_map(|image| {
+                        image
+                            .get_value_by_tag(&tags::ReferencedSOPInstanceUID)
+                            .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Some(Contour {
+            contour_type,
+            points,
+            image_uids,
+        }))
+    }
+
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The Frame of Reference UID which the contours are positioned in.
+    #[must_use]
+    pub fn frame_of_ref_uid(&self) -> Option<&str> {
+        self.frame_of_ref_uid.as_deref()
+    }
+
+    #[must_use]
+    pub fn rois(&self) -> &[Roi] {
+        &self.rois
+    }
+
+    /// The ROI with the given ROI Number.
+    #[must_use]
+    pub fn roi(&self, number: i32) -> Option<&Roi> {
+        self.rois.iter().find(|r| r.number == number)
+    }
+
+    /// The ROI with the given ROI Name, ignoring case.
+    #[must_use]
+    pub fn roi_by_name(&self, name: &str) -> Option<&Roi> {
+        self.rois.iter().find(|r| r.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Rasterizes the closed planar contours of an ROI into a mask over the voxels of the given
+    /// volume. Each contour is placed on the slice nearest its points and fills the pixels whose
+    /// centers are within it. Contours on the same slice are combined by the even-odd rule, so a
+    /// contour within another is a hole. Contours outside the volume are ignored.
+    ///
+    /// # Errors
+    /// - `InvalidStructureSet` if there is no ROI with the given number, the structure set and
+    ///   volume are in different Frames of Reference, or the volume has no voxel geometry.
+    pub fn mask(&self, number: i32, volume: &ImageVolume) -> Result<Region, LoadError> {
+        let Some(roi) = self.roi(number) else {
+            return Err(LoadError::InvalidStructureSet(format!(
+                "no ROI with number {number}"
+            )));
+        };
+        if let (Some(uid), Some(vol_uid)) = (self.frame_of_ref_uid(), volume.frame_of_ref_uid()) {
+            if uid != vol_uid {
+                return Err(LoadError::InvalidStructureSet(format!(
+                    "Frame of Reference mismatch, this: {uid}, volume: {vol_uid}"
+                )));
+            }
+        }
+        let counts = volume.dims().counts();
+        let patient_to_voxel = volume.patient_to_voxel().ok_or_else(|| {
+            LoadError::InvalidStructureSet("volume has no voxel geometry".to_owned())
+        })?;
+        let mut mask = vec![false; counts.x * counts.y * counts.z];
+
+        for contour in roi.closed_contours() {
+            let voxels = contour
+                .points
+                .iter()
+                .map(|p| patient_to_voxel.apply(*p))
+                .collect::<Vec<DicomVec>>();
+            #[allow(clippy::cast_precision_loss)]
+            let z = (voxels.iter().map(|v| v.z).sum::<f32>() / voxels.len() as f32).round();
+            #[allow(clippy::cast_precision_loss)]
+            if z < 0_f32 || z >= counts.z as f32 {
+                continue;
+            }
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let z = z as usize;
+
+            let polygon = Region::Polygon {
+                z,
+                points: voxels.iter().map(|v| (v.x, v.y)).collect(),
+            };
+            let (xs, ys) = (
+                Self::pixel_range(voxels.iter().map(|v| v.x), counts.x),
+                Self::pixel_range(voxels.iter().map(|v| v.y), counts.y),
+            );
+            for y in ys {
+                for x in xs.clone() {
+                    if polygon.contains(&counts, &IndexVec { x, y, z }) {
+                        let index = x + (y + z * counts.y) * counts.x;
+                        mask[index] = !mask[index];
+                    }
+                }
+            }
+        }
+
+        Ok(Region::Mask(mask))
+    }
+
+    /// The range of pixels whose centers may be within the given continuous indices.
+    fn pixel_range<I>(vals: I, count: usize) -> std::ops::Range<usize>
+    where
+        I: Iterator<Item = f32>,
+    {
+        let (min, max) = vals.fold((f32::MAX, f32::MIN), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let start = min.ceil().max(0_f32) as usize;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let end = (max.floor().max(-1_f32) + 1_f32) as usize;
+        start.min(count)..end.min(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            charset::CSRef,
+            dcmobject::{DicomObject, DicomRoot},
+            defn::constants::ts::ExplicitVRLittleEndian,
+            values::RawValue,
+        },
+        dict::tags,
+        load::{
+            imgvol::ImageVolume,
+            rtstruct::{ContourType, StructureSet},
+            stats::Region,
+            testsupport::{elem, frame_groups, item, multi_frame, seq},
+        },
+    };
+
+    #[test]
+    pub fn test_structure_set_masks() {
+        let load_volume = |frame_of_ref_uid: &str| {
+            let mut dcmroot = multi_frame(2, vec![frame_groups(5.0), frame_groups(0.0)]);
+            dcmroot.add_child_with_val(
+                &tags::FrameofReferenceUID,
+                RawValue::of_uid(frame_of_ref_uid),
+            );
+            let mut volume = ImageVolume::default();
+            volume.load_slice(dcmroot).unwrap();
+            volume
+        };
+        let volume = load_volume("1.2.3.7");
+
+        // A closed square in patient coordinates, from voxel indices of the 2x2 slices.
+        let square = |x0: f64, y0: f64, x1: f64, y1: f64, z: f64| {
+            let pos = |x: f64, y: f64| [-10.0 + x * 0.5, -10.0 + y * 0.5, z];
+            let points = [pos(x0, y0), pos(x1, y0), pos(x1, y1), pos(x0, y1)];
+            item(vec![
+                elem(
+                    &tags::ContourGeometricType,
+                    RawValue::of_string("CLOSED_PLANAR"),
+                ),
+                elem(&tags::NumberofContourPoints, RawValue::of_string("4")),
+                elem(
+                    &tags::ContourData,
+                    RawValue::Doubles(points.into_iter().flatten().collect()),
+                ),
+            ])
+        };
+        let roi = |number: &str, name: &str| {
+            item(vec![
+                elem(&tags::ROINumber, RawValue::of_string(number)),
+                elem(&tags::ROIName, RawValue::of_string(name)),
+                elem(
+                    &tags::ReferencedFrameofReferenceUID,
+                    RawValue::of_uid("1.2.3.7"),
+                ),
+            ])
+        };
+        let roi_contour = |number: &str, contours: Vec<DicomObject>| {
+            item(vec![
+                elem(&tags::ReferencedROINumber, RawValue::of_string(number)),
+                elem(
+                    &tags::ROIDisplayColor,
+                    RawValue::Strings(vec!["255".into(), "0".into(), "0".into()]),
+                ),
+                seq(&tags::ContourSequence, contours),
+            ])
+        };
+
+        let mut dcmroot = DicomRoot::new_empty(&ExplicitVRLittleEndian, CSRef::default());
+        dcmroot.add_child_with_val(&tags::StructureSetLabel, RawValue::of_string("RS"));
+        *dcmroot.add_child(&tags::StructureSetROISequence) = seq(
+            &tags::StructureSetROISequence,
+            vec![roi("1", "Cord"), roi("2", "PTV")],
+        );
+        *dcmroot.add_child(&tags::RTROIObservationsSequence) = seq(
+            &tags::RTROIObservationsSequence,
+            vec![item(vec![
+                elem(&tags::ReferencedROINumber, RawValue::of_string("2")),
+                elem(&tags::RTROIInterpretedType, RawValue::of_string("PTV")),
+            ])],
+        );
+        *dcmroot.add_child(&tags::ROIContourSequence) = seq(
+            &tags::ROIContourSequence,
+            vec![
+                roi_contour("1", vec![square(-0.25, -0.25, 0.25, 1.25, 5.0)]),
+                // The second contour is a hole within the first.
+                roi_contour(
+                    "2",
+                    vec![
+                        square(-0.5, -0.5, 1.5, 1.5, 0.0),
+                        square(0.75, 0.75, 1.25, 1.25, 0.0),
+                    ],
+                ),
+            ],
+        );
+
+        let rtstruct = StructureSet::from_dcmroot(&dcmroot).unwrap();
+        assert_eq!("RS", rtstruct.label());
+        assert_eq!(2, rtstruct.rois().len());
+        let ptv = rtstruct.roi_by_name("ptv").unwrap();
+        assert_eq!(2, ptv.number);
+        assert_eq!(Some("PTV"), ptv.interpreted_type.as_deref());
+        assert_eq!(Some([255, 0, 0]), ptv.color);
+        assert_eq!(ContourType::ClosedPlanar, ptv.contours[0].contour_type);
+        assert_eq!(
+            ContourType::Unsupported("CLOSED".to_owned()),
+            ContourType::from("CLOSED")
+        );
+        assert!((ptv.contours[0].points[1].x - -9.25).abs() < f32::EPSILON);
+
+        // Slices are ordered with the highest z-position first.
+        let Region::Mask(cord) = rtstruct.mask(1, &volume).unwrap() else {
+            panic!("expected a mask");
+        };
+        assert_eq!(
+            vec![true, false, true, false, false, false, false, false],
+            cord
+        );
+        let ptv_mask = rtstruct.mask(2, &volume).unwrap();
+        let stats = volume.statistics(&ptv_mask).unwrap();
+        assert_eq!(3, stats.count());
+        assert!((stats.area() - 0.75).abs() < f32::EPSILON);
+        assert!(rtstruct.mask(3, &volume).is_err());
+        // The contours are not positioned in the Frame of Reference of another volume.
+        assert_eq!(Some("1.2.3.7"), rtstruct.frame_of_ref_uid());
+        assert_eq!(Some("1.2.3.7"), volume.frame_of_ref_uid());
+        assert!(rtstruct.mask(1, &load_volume("1.2.3.8")).is_err());
+    }
+}
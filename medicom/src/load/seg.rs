@@ -0,0 +1,546 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Segmentation (SEG) instances, whose frames each hold the pixels of one segment within a plane,
+//! decoded into a 3D mask per segment aligned to a referenced `ImageVolume`.
+
+use crate::{
+    core::{
+        codec::registry::CodecRegistry,
+        dcmobject::{DicomObject, DicomRoot},
+    },
+    dict::tags,
+    load::{
+        imgvol::ImageVolume,
+        pixeldata::{pdinfo::PixelDataSliceInfo, LoadError},
+        stats::Region,
+        Code, IndexVec, VolDims,
+    },
+};
+
+/// The Segmentation Type, and for FRACTIONAL segmentations the Segmentation Fractional Type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SegmentationType {
+    /// Each pixel is either in or out of the segment.
+    Binary,
+    /// Each pixel is the probability that it is in the segment.
+    Probability,
+    /// Each pixel is the fraction of the pixel occupied by the segment.
+    Occupancy,
+}
+
+/// An item of the Segment Sequence, describing one segment of a segmentation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Segment {
+    pub number: u16,
+    pub label: String,
+    pub description: Option<String>,
+    /// AUTOMATIC, SEMIAUTOMATIC or MANUAL.
+    pub algorithm_type: String,
+    pub algorithm_name: Option<String>,
+    /// The Recommended Display CIELab Value converted to sRGB.
+    pub color: Option<[u8; 3]>,
+    pub category: Option<Code>,
+    pub property_type: Option<Code>,
+    pub anatomic_region: Option<Code>,
+}
+
+impl Segment {
+    fn from_item(item: &DicomObject) -> Self {
+        let string = |tag| {
+            item.get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .filter(|s| !s.is_empty())
+        };
+        let color = item
+            .get_value_by_tag(&tags::RecommendedDisplayCIELabValue)
+            .map(|v| v.ushorts())
+            .filter(|lab| lab.len() == 3)
+            .map(|lab| lab_to_rgb([lab[0], lab[1], lab[2]]));
+        Self {
+            number: item
+                .get_value_by_tag(&tags::SegmentNumber)
+                .and_then(|v| v.ushort())
+                .unwrap_or_default(),
+            label: string(&tags::SegmentLabel).unwrap_or_default(),
+            description: string(&tags::SegmentDescription),
+            algorithm_type: string(&tags::SegmentAlgorithmType).unwrap_or_default(),
+            algorithm_name: string(&tags::SegmentAlgorithmName),
+            color,
+            category: Code::from_sequence(item, &tags::SegmentedPropertyCategoryCodeSequence),
+            property_type: Code::from_sequence(item, &tags::SegmentedPropertyTypeCodeSequence),
+            anatomic_region: Code::from_sequence(item, &tags::AnatomicRegionSequence),
+        }
+    }
+}
+
+/// Converts a DICOM scaled CIELab value, where each component is scaled to 0-65535, to sRGB using
+/// the D65 white point.
+fn lab_to_rgb(lab: [u16; 3]) -> [u8; 3] {
+    let l = f32::from(lab[0]) * 100_f32 / 65535_f32;
+    let a = f32::from(lab[1]) * 255_f32 / 65535_f32 - 128_f32;
+    let b = f32::from(lab[2]) * 255_f32 / 65535_f32 - 128_f32;
+
+    let finv = |t: f32| {
+        const DELTA: f32 = 6_f32 / 29_f32;
+        if t > DELTA {
+            t * t * t
+        } else {
+            3_f32 * DELTA * DELTA * (t - 4_f32 / 29_f32)
+        }
+    };
+    let fy = (l + 16_f32) / 116_f32;
+    let x = 0.950_47 * finv(fy + a / 500_f32);
+    let y = finv(fy);
+    let z = 1.088_83 * finv(fy - b / 200_f32);
+
+    let gamma = |c: f32| {
+        let c = if c <= 0.003_130_8 {
+            12.92 * c
+        } else {
+            1.055 * c.powf(1_f32 / 2.4) - 0.055
+        };
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let c = (c.clamp(0_f32, 1_f32) * 255_f32).round() as u8;
+        c
+    };
+    [
+        gamma(3.240_97 * x - 1.537_383 * y - 0.498_611 * z),
+        gamma(-0.969_244 * x + 1.875_968 * y + 0.041_555 * z),
+        gamma(0.055_63 * x - 0.203_977 * y + 1.056_972 * z),
+    ]
+}
+
+/// A frame of a segmentation, holding the pixels of one segment.
+#[derive(Debug)]
+struct SegmentFrame {
+    segment: u16,
+    /// The geometry of the frame, if its position was specified.
+    dims: Option<VolDims>,
+    /// The SOP Instance UID of the source image the frame was derived from.
+    source_uid: Option<String>,
+    /// A byte per pixel, by row then column.
+    pixels: Vec<u8>,
+}
+
+/// A Segmentation instance.
+#[derive(Debug)]
+pub struct Segmentation {
+    seg_type: SegmentationType,
+    max_fractional: u8,
+    segments: Vec<Segment>,
+    frames: Vec<SegmentFrame>,
+}
+
+impl Segmentation {
+    /// Reads the segments and frames of a Segmentation instance. 1-bit BINARY frames are unpacked
+    /// and encapsulated frames are decoded using the given codecs.
+    ///
+    /// # Errors
+    /// - `InvalidSegmentation` if the instance is not a segmentation or its frames do not
+    ///   reference a segment.
+    /// - `LoadError` if the Pixel Data cannot be loaded.
+    pub fn load(dcmroot: DicomRoot, codecs: &CodecRegistry) -> Result<Self, LoadError> {
+        let seg_type = match dcmroot
+            .get_value_by_tag(&tags::SegmentationType)
+            .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+            .as_deref()
+        {
+            Some("BINARY") => SegmentationType::Binary,
+            Some("FRACTIONAL") => match dcmroot
+                .get_value_by_tag(&tags::SegmentationFractionalType)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .as_deref()
+            {
+                Some("OCCUPANCY") => SegmentationType::Occupancy,
+                _ => SegmentationType::Probability,
+            },
+            other => {
+                return Err(LoadError::InvalidSegmentation(format!(
+                    "unsupported Segmentation Type: {other:?}"
+                )))
+            }
+        };
+        let max_fractional = if seg_type == SegmentationType::Binary {
+            1
+        } else {
+            dcmroot
+                .get_value_by_tag(&tags::MaximumFractionalValue)
+                .and_then(|v| v.ushort())
+                .and_then(|v| u8::try_from(v).ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(u8::MAX)
+        };
+        let segments = dcmroot
+            .get_child_by_tag(&tags::SegmentSequence)
+            .map(|sq| sq.iter_items().map(Segment::from_item).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        // The segment and source image of each frame are in the Per-frame Functional Groups,
+        // which are removed from each frame by `into_frames`. A segmentation of a single segment
+        // may identify it in the Shared Functional Groups.
+        let per_frame = dcmroot
+            .get_child_by_tag(&tags::PerFrameFunctionalGroupsSequence)
+            .map(|sq| sq.iter_items().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let shared = dcmroot
+            .get_child_by_tag(&tags::SharedFunctionalGroupsSequence)
+            .and_then(|sq| sq.get_item_by_index(1).cloned());
+
+        let pdinfo = PixelDataSliceInfo::process(dcmroot, codecs)?;
+        let mut frames = Vec::new();
+        for (index, mut info) in pdinfo.into_frames()?.into_iter().enumerate() {
+            let groups = per_frame.get(index);
+            let segment = [groups, shared.as_ref()]
+                .into_iter()
+                .flatten()
+                .find_map(Self::frame_segment)
+                .ok_or_else(|| {
+                    LoadError::InvalidSegmentation(format!("frame {} has no segment", index + 1))
+                })?;
+            let positioned =
+                groups.is_some_and(|g| g.get_child_by_tag(&tags::PlanePositionSequence).is_some());
+            let source_uid = groups.and_then(Self::frame_source);
+            frames.push(SegmentFrame {
+                segment,
+                dims: positioned.then(|| info.vol_dims()),
+                source_uid,
+                pixels: info.take_bytes(),
+            });
+        }
+
+        Ok(Self {
+            seg_type,
+            max_fractional,
+            segments,
+            frames,
+        })
+    }
+
+    /// The Referenced Segment Number of the Segment Identification of a Functional Groups item.
+    fn frame_segment(groups: &DicomObject) -> Option<u16> {
+        groups
+            .get_child_by_tag(&tags::SegmentIdentificationSequence)
+            .and_then(|sq| sq.get_item_by_index(1))
+            .and_then(|item| item.get_value_by_tag(&tags::ReferencedSegmentNumber))
+            .and_then(|v| v.ushort())
+    }
+
+    /// The Referenced SOP Instance UID of the first Source Image of the Derivation Image of a
+    /// Functional Groups item.
+    fn frame_source(groups: &DicomObject) -> Option<String> {
+        groups
+            .get_child_by_tag(&tags::DerivationImageSequence)
+            .and_then(|sq| sq.get_item_by_index(1))
+            .and_then(|item| item.get_child_by_tag(&tags::SourceImageSequence))
+            .and_then(|sq| sq.get_item_by_index(1))
+            .and_then(|item| item.get_value_by_tag(&tags::ReferencedSOPInstanceUID))
+            .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+    }
+
+    #[must_use]
+    pub fn seg_type(&self) -> SegmentationType {
+        self.seg_type
+    }
+
+    /// The stored value of a pixel fully within a segment, 1 for BINARY segmentations.
+    #[must_use]
+    pub fn max_fractional(&self) -> u8 {
+        self.max_fractional
+    }
+
+    #[must_use]
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// The segment with the given Segment Number.
+    #[must_use]
+    pub fn segment(&self, number: u16) -> Option<&Segment> {
+        self.segments.iter().find(|s| s.number == number)
+    }
+
+    /// The number of frames.
+    #[must_use]
+    pub fn num_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Creates the mask of each segment aligned to the given volume, in Segment Sequence order.
+    ///
+    /// # Errors
+    /// - See `mask`.
+    pub fn masks(&self, volume: &ImageVolume) -> Result<Vec<SegmentMask>, LoadError> {
+        self.segments
+            .iter()
+            .map(|segment| self.mask(segment.number, volume))
+            .collect()
+    }
+
+    /// Creates the mask of a segment aligned to the voxels of the given volume. Each pixel of the
+    /// segment's frames is placed in the nearest voxel by its position in patient space, or for
+    /// frames without a position, in the slice of the source image with the same dimensions.
+    /// Pixels outside the volume are ignored.
+    ///
+    /// # Errors
+    /// - `InvalidSegmentation` if there is no segment with the given number, or a frame can not be
+    ///   aligned to the volume.
+    pub fn mask(&self, number: u16, volume: &ImageVolume) -> Result<SegmentMask, LoadError> {
+        if self.segment(number).is_none() {
+            return Err(LoadError::InvalidSegmentation(format!(
+                "no segment with number {number}"
+            )));
+        }
+        let counts = volume.dims().counts();
+        let patient_to_voxel = volume.patient_to_voxel().ok_or_else(|| {
+            LoadError::InvalidSegmentation("volume has no voxel geometry".to_owned())
+        })?;
+        let mut values = vec![0_f32; counts.x * counts.y * counts.z];
+        let max = f32::from(self.max_fractional);
+
+        for frame in self.frames.iter().filter(|f| f.segment == number) {
+            if let Some(dims) = &frame.dims {
+                let frame_counts = dims.counts();
+                for (index, pixel) in frame.pixels.iter().enumerate() {
+                    if *pixel == 0 {
+                        continue;
+                    }
+                    let (x, y) = (index % frame_counts.x, index / frame_counts.x);
+                    let pos = dims.coordinate(IndexVec { x, y, z: 0 });
+                    let voxel = patient_to_voxel.apply(pos);
+                    let Some(coord) = Self::nearest_voxel(&counts, [voxel.x, voxel.y, voxel.z])
+                    else {
+                        continue;
+                    };
+                    let value = &mut values[coord.x + (coord.y + coord.z * counts.y) * counts.x];
+                    *value = value.max(f32::from(*pixel).min(max) / max);
+                }
+                continue;
+            }
+
+            let z = frame
+                .source_uid
+                .as_ref()
+                .and_then(|uid| {
+                    volume
+                        .infos()
+                        .iter()
+                        .position(|info| info.sop_instance_id() == *uid)
+                })
+                .filter(|_| frame.pixels.len() == counts.x * counts.y)
+                .ok_or_else(|| {
+                    LoadError::InvalidSegmentation(format!(
+                        "a frame of segment {number} has no position or source image in the volume"
+                    ))
+                })?;
+            let slice = &mut values[z * counts.x * counts.y..(z + 1) * counts.x * counts.y];
+            for (value, pixel) in slice.iter_mut().zip(&frame.pixels) {
+                *value = value.max(f32::from(*pixel).min(max) / max);
+            }
+        }
+
+        Ok(SegmentMask {
+            segment: number,
+            counts,
+            values,
+        })
+    }
+
+    /// The voxel nearest to a continuous voxel index, if within the volume.
+    fn nearest_voxel(counts: &IndexVec, pos: [f32; 3]) -> Option<IndexVec> {
+        let mut index = [0usize; 3];
+        for ((i, p), count) in index
+            .iter_mut()
+            .zip(pos)
+            .zip([counts.x, counts.y, counts.z])
+        {
+            let p = p.round();
+            #[allow(clippy::cast_precision_loss)]
+            if p < 0_f32 || p >= count as f32 {
+                return None;
+            }
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            {
+                *i = p as usize;
+            }
+        }
+        Some(IndexVec {
+            x: index[0],
+            y: index[1],
+            z: index[2],
+        })
+    }
+}
+
+/// The mask of a segment over the voxels of an `ImageVolume`.
+#[derive(Clone, Debug)]
+pub struct SegmentMask {
+    segment: u16,
+    counts: IndexVec,
+    /// The fraction of each voxel in the segment, from 0 to 1, ordered by x, then y, then z.
+    /// BINARY segmentations are either 0 or 1.
+    values: Vec<f32>,
+}
+
+impl SegmentMask {
+    /// The Segment Number of the segment.
+    #[must_use]
+    pub fn segment(&self) -> u16 {
+        self.segment
+    }
+
+    #[must_use]
+    pub fn counts(&self) -> IndexVec {
+        self.counts
+    }
+
+    /// The fraction of each voxel in the segment, from 0 to 1, ordered by x, then y, then z.
+    #[must_use]
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// The fraction of the voxel at the given coordinate in the segment, 0 outside the volume.
+    #[must_use]
+    pub fn get(&self, coord: IndexVec) -> f32 {
+        if coord.x >= self.counts.x || coord.y >= self.counts.y || coord.z >= self.counts.z {
+            return 0_f32;
+        }
+        self.values[coord.x + (coord.y + coord.z * self.counts.y) * self.counts.x]
+    }
+
+    /// The number of voxels whose fraction in the segment is at least the given threshold.
+    #[must_use]
+    pub fn voxel_count(&self, threshold: f32) -> usize {
+        self.values.iter().filter(|v| **v >= threshold).count()
+    }
+
+    /// The region of voxels whose fraction in the segment is at least the given threshold, for
+    /// computing statistics of the segment.
+    #[must_use]
+    pub fn region(&self, threshold: f32) -> Region {
+        Region::Mask(self.values.iter().map(|v| *v >= threshold).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{codec::registry::CodecRegistry, values::RawValue},
+        dict::tags,
+        load::{
+            imgvol::ImageVolume,
+            seg::{lab_to_rgb, Segmentation, SegmentationType},
+            testsupport::{elem, frame_groups, item, multi_frame, seq},
+        },
+    };
+
+    #[test]
+    pub fn test_lab_to_rgb() {
+        assert_eq!([255, 255, 255], lab_to_rgb([65535, 32896, 32896]));
+        assert_eq!([0, 0, 0], lab_to_rgb([0, 32896, 32896]));
+        // Pure sRGB red is approximately L=53.24, a=80.09, b=67.20.
+        let red = lab_to_rgb([34891, 53486, 50612]);
+        assert!(red[0] > 250 && red[1] < 10 && red[2] < 10, "{red:?}");
+    }
+
+    #[test]
+    pub fn test_segmentation_masks() {
+        let mut volume = ImageVolume::default();
+        volume
+            .load_slice(multi_frame(2, vec![frame_groups(5.0), frame_groups(0.0)]))
+            .unwrap();
+
+        let seg_groups = |z: f64, segment: u16| {
+            item(vec![
+                seq(
+                    &tags::PlanePositionSequence,
+                    vec![item(vec![elem(
+                        &tags::ImagePositionPatient,
+                        RawValue::Doubles(vec![-10.0, -10.0, z]),
+                    )])],
+                ),
+                seq(
+                    &tags::SegmentIdentificationSequence,
+                    vec![item(vec![elem(
+                        &tags::ReferencedSegmentNumber,
+                        RawValue::of_ushort(segment),
+                    )])],
+                ),
+            ])
+        };
+        let segment = |number: u16, label: &str| {
+            item(vec![
+                elem(&tags::SegmentNumber, RawValue::of_ushort(number)),
+                elem(&tags::SegmentLabel, RawValue::of_string(label)),
+                elem(
+                    &tags::RecommendedDisplayCIELabValue,
+                    RawValue::UShorts(vec![65535, 32896, 32896]),
+                ),
+                seq(
+                    &tags::SegmentedPropertyTypeCodeSequence,
+                    vec![item(vec![
+                        elem(&tags::CodeValue, RawValue::of_string("10200004")),
+                        elem(&tags::CodingSchemeDesignator, RawValue::of_string("SCT")),
+                        elem(&tags::CodeMeaning, RawValue::of_string("Liver")),
+                    ])],
+                ),
+            ])
+        };
+
+        let mut dcmroot = multi_frame(
+            3,
+            vec![seg_groups(5.0, 1), seg_groups(0.0, 1), seg_groups(0.0, 2)],
+        );
+        dcmroot.add_child_with_val(&tags::SegmentationType, RawValue::of_string("BINARY"));
+        dcmroot.remove_child_by_tag(&tags::BitsAllocated);
+        dcmroot.remove_child_by_tag(&tags::BitsStored);
+        dcmroot.add_child_with_val(&tags::BitsAllocated, RawValue::of_ushort(1));
+        dcmroot.add_child_with_val(&tags::BitsStored, RawValue::of_ushort(1));
+        *dcmroot.add_child(&tags::SegmentSequence) = seq(
+            &tags::SegmentSequence,
+            vec![segment(1, "Liver"), segment(2, "Lesion")],
+        );
+        // The 12 bits of the three frames are packed contiguously: 1001, 0100, 1111.
+        dcmroot.remove_child_by_tag(&tags::PixelData);
+        dcmroot.add_child_with_val(&tags::PixelData, RawValue::Bytes(vec![0x29, 0x0F]));
+
+        let seg = Segmentation::load(dcmroot, &CodecRegistry::default()).unwrap();
+        assert_eq!(SegmentationType::Binary, seg.seg_type());
+        assert_eq!(3, seg.num_frames());
+        let liver = seg.segment(1).unwrap();
+        assert_eq!("Liver", liver.label);
+        assert_eq!(Some([255, 255, 255]), liver.color);
+        assert_eq!(
+            "Liver",
+            liver
+                .property_type
+                .as_ref()
+                .map_or("", |c| c.meaning.as_str())
+        );
+
+        // Frames are aligned to the volume's slices by position, highest z-position first.
+        let masks = seg.masks(&volume).unwrap();
+        assert_eq!(2, masks.len());
+        assert_eq!(&[1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0], masks[0].values());
+        assert_eq!(&[0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0], masks[1].values());
+        assert_eq!(3, masks[0].voxel_count(0.5));
+
+        let stats = volume.statistics(&masks[0].region(0.5)).unwrap();
+        assert_eq!(3, stats.count());
+        assert!(seg.mask(3, &volume).is_err());
+    }
+}
@@ -0,0 +1,677 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Structured Reports (SR), whose content is a tree of content items nested through the Content
+//! Sequence, such as Comprehensive SR, Key Object Selection and radiation dose reports.
+
+use crate::{
+    core::{
+        dcmobject::{DicomObject, DicomRoot},
+        defn::tag::Tag,
+        values::RawValue,
+    },
+    dict::tags,
+    load::{pixeldata::LoadError, Code, DicomVec},
+};
+
+/// The Relationship Type of a content item to its parent.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RelationshipType {
+    Contains,
+    HasProperties,
+    HasObsContext,
+    HasAcqContext,
+    HasConceptMod,
+    InferredFrom,
+    SelectedFrom,
+    Unsupported(String),
+}
+
+impl RelationshipType {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Contains => "CONTAINS",
+            Self::HasProperties => "HAS PROPERTIES",
+            Self::HasObsContext => "HAS OBS CONTEXT",
+            Self::HasAcqContext => "HAS ACQ CONTEXT",
+            Self::HasConceptMod => "HAS CONCEPT MOD",
+            Self::InferredFrom => "INFERRED FROM",
+            Self::SelectedFrom => "SELECTED FROM",
+            Self::Unsupported(value) => value,
+        }
+    }
+}
+
+impl From<&str> for RelationshipType {
+    fn from(value: &str) -> Self {
+        match value {
+            "CONTAINS" => Self::Contains,
+            "HAS PROPERTIES" => Self::HasProperties,
+            "HAS OBS CONTEXT" => Self::HasObsContext,
+            "HAS ACQ CONTEXT" => Self::HasAcqContext,
+            "HAS CONCEPT MOD" => Self::HasConceptMod,
+            "INFERRED FROM" => Self::InferredFrom,
+            "SELECTED FROM" => Self::SelectedFrom,
+            _ => Self::Unsupported(value.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for RelationshipType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// The value of a content item, by its Value Type.
+#[derive(Clone, Debug)]
+pub enum ContentValue {
+    Container {
+        /// Whether the children are CONTINUOUS text or SEPARATE items.
+        continuous: bool,
+        /// The Template Identifier of the template the content follows, such as 10001.
+        template: Option<String>,
+    },
+    Text(String),
+    Code(Code),
+    Num {
+        /// The Floating Point Value if present, otherwise the Numeric Value. `None` if the
+        /// measurement has no value.
+        value: Option<f64>,
+        units: Option<Code>,
+        qualifier: Option<Code>,
+    },
+    UidRef(String),
+    DateTime(String),
+    Date(String),
+    Time(String),
+    PName(String),
+    Composite {
+        sop_class_uid: String,
+        sop_instance_uid: String,
+    },
+    Image {
+        sop_class_uid: String,
+        sop_instance_uid: String,
+        frames: Vec<i32>,
+    },
+    /// Spatial coordinates in an image, as (column, row) pixel positions.
+    SCoord {
+        graphic_type: String,
+        points: Vec<(f32, f32)>,
+    },
+    /// Spatial coordinates in patient space.
+    SCoord3D {
+        graphic_type: String,
+        frame_of_ref_uid: String,
+        points: Vec<DicomVec>,
+    },
+    /// Temporal coordinates, by one of sample positions, time offsets or date times.
+    TCoord {
+        range_type: String,
+        sample_positions: Vec<u32>,
+        time_offsets: Vec<f64>,
+        datetimes: Vec<String>,
+    },
+    /// A by-reference relationship to another content item, by the position of each item along
+    /// the path from the root, starting with 1 for the root.
+    Reference(Vec<u32>),
+    /// A content item of a Value Type which is not supported.
+    Unsupported(String),
+}
+
+impl ContentValue {
+    /// Reads the value of a content item of the given Value Type.
+    fn read(value_type: &str, item: &DicomObject) -> Self {
+        let string = |tag| {
+            item.get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .unwrap_or_default()
+        };
+        let referenced_sop = || {
+            item.get_child_by_tag(&tags::ReferencedSOPSequence)
+                .and_then(|sq| sq.get_item_by_index(1))
+        };
+        let sop_uid = |tag| {
+            referenced_sop()
+                .and_then(|sop| sop.get_value_by_tag(tag))
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .unwrap_or_default()
+        };
+
+        match value_type {
+            "CONTAINER" => Self::Container {
+                continuous: string(&tags::ContinuityOfContent) == "CONTINUOUS",
+                template: item
+                    .get_child_by_tag(&tags::ContentTemplateSequence)
+                    .and_then(|sq| sq.get_item_by_index(1))
+                    .and_then(|t| t.get_value_by_tag(&tags::TemplateIdentifier))
+                    .and_then(|v| v.string().map(|s| s.trim().to_owned())),
+            },
+            "TEXT" => Self::Text(string(&tags::TextValue)),
+            "CODE" => Code::from_sequence(item, &tags::ConceptCodeSequence)
+                .map_or_else(|| Self::Unsupported(value_type.to_owned()), Self::Code),
+            "NUM" => {
+                let measured = item
+                    .get_child_by_tag(&tags::MeasuredValueSequence)
+                    .and_then(|sq| sq.get_item_by_index(1));
+                let value = measured.and_then(|m| {
+                    [&tags::FloatingPointValue, &tags::NumericValue]
+                        .into_iter()
+                        .find_map(|tag| m.get_value_by_tag(tag).and_then(|v| v.double()))
+                });
+                Self::Num {
+                    value,
+                    units: measured
+                        .and_then(|m| Code::from_sequence(m, &tags::MeasurementUnitsCodeSequence)),
+                    qualifier: Code::from_sequence(item, &tags::NumericValueQualifierCodeSequence),
+                }
+            }
+            "UIDREF" => Self::UidRef(string(&tags::UID)),
+            "DATETIME" => Self::DateTime(string(&tags::DateTime)),
+            "DATE" => Self::Date(string(&tags::Date)),
+            "TIME" => Self::Time(string(&tags::Time)),
+            "PNAME" => Self::PName(string(&tags::PersonName)),
+            "COMPOSITE" => Self::Composite {
+                sop_class_uid: sop_uid(&tags::ReferencedSOPClassUID),
+                sop_instance_uid: sop_uid(&tags::ReferencedSOPInstanceUID),
+            },
+            "IMAGE" => Self::Image {
+                sop_class_uid: sop_uid(&tags::ReferencedSOPClassUID),
+                sop_instance_uid: sop_uid(&tags::ReferencedSOPInstanceUID),
+                frames: referenced_sop()
+                    .and_then(|sop| sop.get_value_by_tag(&tags::ReferencedFrameNumber))
+                    .map(|v| v.ints())
+                    .unwrap_or_default(),
+            },
+            "SCOORD" => Self::SCoord {
+                graphic_type: string(&tags::GraphicType),
+                points: Self::floats(item, &tags::GraphicData)
+                    .chunks_exact(2)
+                    .map(|p| (p[0], p[1]))
+                    .collect(),
+            },
+            "SCOORD3D" => Self::SCoord3D {
+                graphic_type: string(&tags::GraphicType),
+                frame_of_ref_uid: string(&tags::ReferencedFrameofReferenceUID),
+                points: Self::floats(item, &tags::GraphicData)
+                    .chunks_exact(3)
+                    .map(|p| DicomVec::new(p[0], p[1], p[2]))
+                    .collect(),
+            },
+            "TCOORD" => Self::TCoord {
+                range_type: string(&tags::TemporalRangeType),
+                sample_positions: item
+                    .get_value_by_tag(&tags::ReferencedSamplePositions)
+                    .map(|v| v.uints())
+                    .unwrap_or_default(),
+                time_offsets: item
+                    .get_value_by_tag(&tags::ReferencedTimeOffsets)
+                    .map(|v| v.doubles())
+                    .unwrap_or_default(),
+                datetimes: match item.get_value_by_tag(&tags::ReferencedDateTime) {
+                    Some(RawValue::Strings(vals)) => vals,
+                    _ => Vec::new(),
+                },
+            },
+            other => Self::Unsupported(other.to_owned()),
+        }
+    }
+
+    fn floats(item: &DicomObject, tag: &Tag) -> Vec<f32> {
+        item.get_value_by_tag(tag)
+            .map(|v| v.floats())
+            .unwrap_or_default()
+    }
+}
+
+impl std::fmt::Display for ContentValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Container { .. } => Ok(()),
+            Self::Text(text)
+            | Self::UidRef(text)
+            | Self::DateTime(text)
+            | Self::Date(text)
+            | Self::Time(text)
+            | Self::PName(text) => write!(f, "{text}"),
+            Self::Code(code) => write!(f, "{}", code.meaning),
+            Self::Num {
+                value,
+                units,
+                qualifier,
+            } => {
+                match (value, qualifier) {
+                    (Some(value), _) => write!(f, "{value}")?,
+                    (None, Some(qualifier)) => write!(f, "{}", qualifier.meaning)?,
+                    (None, None) => write!(f, "(no value)")?,
+                }
+                match units {
+                    Some(units) if value.is_some() && units.value != "1" => {
+                        write!(f, " {}", units.value)
+                    }
+                    _ => Ok(()),
+                }
+            }
+            Self::Composite {
+                sop_instance_uid, ..
+            } => write!(f, "{sop_instance_uid}"),
+            Self::Image {
+                sop_instance_uid,
+                frames,
+                ..
+            } => {
+                write!(f, "{sop_instance_uid}")?;
+                if !frames.is_empty() {
+                    let frames = frames.iter().map(ToString::to_string).collect::<Vec<_>>();
+                    write!(f, " frames {}", frames.join(","))?;
+                }
+                Ok(())
+            }
+            Self::SCoord {
+                graphic_type,
+                points,
+            } => {
+                write!(f, "{graphic_type}")?;
+                for (x, y) in points {
+                    write!(f, " ({x}, {y})")?;
+                }
+                Ok(())
+            }
+            Self::SCoord3D {
+                graphic_type,
+                points,
+                ..
+            } => {
+                write!(f, "{graphic_type}")?;
+                for p in points {
+                    write!(f, " ({}, {}, {})", p.x, p.y, p.z)?;
+                }
+                Ok(())
+            }
+            Self::TCoord {
+                range_type,
+                sample_positions,
+                time_offsets,
+                datetimes,
+            } => {
+                write!(f, "{range_type}")?;
+                let vals = if !sample_positions.is_empty() {
+                    sample_positions.iter().map(ToString::to_string).collect()
+                } else if !time_offsets.is_empty() {
+                    time_offsets.iter().map(ToString::to_string).collect()
+                } else {
+                    datetimes.clone()
+                };
+                write!(f, " {}", vals.join(","))
+            }
+            Self::Reference(ids) => {
+                let ids = ids.iter().map(ToString::to_string).collect::<Vec<_>>();
+                write!(f, "(see content item {})", ids.join("."))
+            }
+            Self::Unsupported(value_type) => write!(f, "(unsupported {value_type})"),
+        }
+    }
+}
+
+/// A content item of a structured report and the content items it has relationships with.
+#[derive(Clone, Debug)]
+pub struct ContentItem {
+    /// The relationship of this item to its parent, `None` for the root.
+    pub relationship: Option<RelationshipType>,
+    pub concept_name: Option<Code>,
+    pub value: ContentValue,
+    pub children: Vec<ContentItem>,
+}
+
+impl ContentItem {
+    /// Reads a content item and its children in the Content Sequence.
+    fn read(item: &DicomObject) -> Self {
+        let string = |tag| {
+            item.get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+        };
+        let relationship =
+            string(&tags::RelationshipType).map(|r| RelationshipType::from(r.as_str()));
+        let value = match string(&tags::ValueType) {
+            Some(value_type) => ContentValue::read(&value_type, item),
+            None => match item.get_value_by_tag(&tags::ReferencedContentItemIdentifier) {
+                Some(ids) => ContentValue::Reference(ids.uints()),
+                None => ContentValue::Unsupported(String::new()),
+            },
+        };
+        let children = item
+            .get_child_by_tag(&tags::ContentSequence)
+            .map(|sq| sq.iter_items().map(Self::read).collect())
+            .unwrap_or_default();
+        Self {
+            relationship,
+            concept_name: Code::from_sequence(item, &tags::ConceptNameCodeSequence),
+            value,
+            children,
+        }
+    }
+
+    /// The Code Meaning of the concept name, or an empty string if there is none.
+    #[must_use]
+    pub fn concept(&self) -> &str {
+        self.concept_name
+            .as_ref()
+            .map_or("", |c| c.meaning.as_str())
+    }
+
+    /// The content items within this item's subtree, including itself, whose concept name has the
+    /// given code value, in depth-first order.
+    #[must_use]
+    pub fn find_all(&self, code_value: &str) -> Vec<&ContentItem> {
+        let mut found = Vec::new();
+        if self
+            .concept_name
+            .as_ref()
+            .is_some_and(|c| c.value == code_value)
+        {
+            found.push(self);
+        }
+        for child in &self.children {
+            found.extend(child.find_all(code_value));
+        }
+        found
+    }
+
+    /// Writes this item and its children as indented lines of text, one line per item.
+    fn write_tree(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        write!(f, "{:indent$}", "", indent = depth * 2)?;
+        if let Some(relationship) = &self.relationship {
+            write!(f, "{relationship}: ")?;
+        }
+        write!(f, "{}", self.concept())?;
+        if !matches!(self.value, ContentValue::Container { .. }) {
+            if self.concept_name.is_some() {
+                write!(f, " = ")?;
+            }
+            write!(f, "{}", self.value)?;
+        }
+        writeln!(f)?;
+        for child in &self.children {
+            child.write_tree(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for ContentItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_tree(f, 0)
+    }
+}
+
+/// A Structured Report instance.
+#[derive(Clone, Debug)]
+pub struct StructuredReport {
+    sop_class_uid: String,
+    completion_flag: String,
+    verification_flag: String,
+    root: ContentItem,
+}
+
+impl StructuredReport {
+    /// Reads the content tree of a Structured Report instance, whose dataset is the root content
+    /// item.
+    ///
+    /// # Errors
+    /// - `InvalidStructuredReport` if the root content item is not a CONTAINER.
+    pub fn from_dcmroot(dcmroot: &DicomRoot) -> Result<Self, LoadError> {
+        let root = ContentItem::read(dcmroot.as_obj());
+        if !matches!(root.value, ContentValue::Container { .. }) {
+            return Err(LoadError::InvalidStructuredReport(
+                "root content item is not a CONTAINER".to_owned(),
+            ));
+        }
+        let string = |tag| {
+            dcmroot
+                .get_value_by_tag(tag)
+                .and_then(|v| v.string().map(|s| s.trim().to_owned()))
+                .unwrap_or_default()
+        };
+        Ok(Self {
+            sop_class_uid: string(&tags::SOPClassUID),
+            completion_flag: string(&tags::CompletionFlag),
+            verification_flag: string(&tags::VerificationFlag),
+            root,
+        })
+    }
+
+    #[must_use]
+    pub fn sop_class_uid(&self) -> &str {
+        &self.sop_class_uid
+    }
+
+    /// PARTIAL or COMPLETE.
+    #[must_use]
+    pub fn completion_flag(&self) -> &str {
+        &self.completion_flag
+    }
+
+    /// UNVERIFIED or VERIFIED.
+    #[must_use]
+    pub fn verification_flag(&self) -> &str {
+        &self.verification_flag
+    }
+
+    /// The root CONTAINER, whose concept name is the document title.
+    #[must_use]
+    pub fn root(&self) -> &ContentItem {
+        &self.root
+    }
+
+    /// The content item referenced by a by-reference relationship, by the position of each item
+    /// along the path from the root, starting with 1 for the root.
+    #[must_use]
+    pub fn resolve(&self, ids: &[u32]) -> Option<&ContentItem> {
+        let (first, path) = ids.split_first()?;
+        if *first != 1 {
+            return None;
+        }
+        path.iter().try_fold(&self.root, |item, id| {
+            let index = usize::try_from(*id).ok()?.checked_sub(1)?;
+            item.children.get(index)
+        })
+    }
+
+    /// The content items whose concept name has the given code value, in depth-first order.
+    #[must_use]
+    pub fn find_all(&self, code_value: &str) -> Vec<&ContentItem> {
+        self.root.find_all(code_value)
+    }
+}
+
+impl std::fmt::Display for StructuredReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.root.write_tree(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            charset::CSRef,
+            dcmobject::{DicomObject, DicomRoot},
+            defn::{constants::ts::ExplicitVRLittleEndian, tag::Tag},
+            values::RawValue,
+        },
+        dict::tags,
+        load::{
+            sr::{ContentValue, RelationshipType, StructuredReport},
+            testsupport::{elem, item, seq},
+        },
+    };
+
+    fn code(tag: &Tag, value: &str, meaning: &str) -> DicomObject {
+        seq(
+            tag,
+            vec![item(vec![
+                elem(&tags::CodeValue, RawValue::of_string(value)),
+                elem(&tags::CodingSchemeDesignator, RawValue::of_string("DCM")),
+                elem(&tags::CodeMeaning, RawValue::of_string(meaning)),
+            ])],
+        )
+    }
+
+    fn content(
+        relationship: &str,
+        value_type: &str,
+        mut children: Vec<DicomObject>,
+    ) -> DicomObject {
+        children.push(elem(
+            &tags::RelationshipType,
+            RawValue::of_string(relationship),
+        ));
+        if !value_type.is_empty() {
+            children.push(elem(&tags::ValueType, RawValue::of_string(value_type)));
+        }
+        item(children)
+    }
+
+    #[test]
+    pub fn test_dose_report() {
+        let dap = content(
+            "CONTAINS",
+            "NUM",
+            vec![
+                code(
+                    &tags::ConceptNameCodeSequence,
+                    "113722",
+                    "Dose Area Product Total",
+                ),
+                seq(
+                    &tags::MeasuredValueSequence,
+                    vec![item(vec![
+                        elem(&tags::NumericValue, RawValue::of_double(1.5)),
+                        code(&tags::MeasurementUnitsCodeSequence, "Gy.m2", "Gy.m2"),
+                    ])],
+                ),
+            ],
+        );
+        let items = vec![
+            content(
+                "HAS CONCEPT MOD",
+                "CODE",
+                vec![
+                    code(
+                        &tags::ConceptNameCodeSequence,
+                        "121058",
+                        "Procedure reported",
+                    ),
+                    code(&tags::ConceptCodeSequence, "113704", "Projection X-Ray"),
+                ],
+            ),
+            content(
+                "CONTAINS",
+                "CONTAINER",
+                vec![
+                    code(
+                        &tags::ConceptNameCodeSequence,
+                        "113702",
+                        "Accumulated X-Ray Dose Data",
+                    ),
+                    seq(&tags::ContentSequence, vec![dap]),
+                ],
+            ),
+            content(
+                "CONTAINS",
+                "IMAGE",
+                vec![
+                    code(&tags::ConceptNameCodeSequence, "121200", "Image"),
+                    seq(
+                        &tags::ReferencedSOPSequence,
+                        vec![item(vec![
+                            elem(&tags::ReferencedSOPInstanceUID, RawValue::of_uid("1.2.3.4")),
+                            elem(&tags::ReferencedFrameNumber, RawValue::of_string("2")),
+                        ])],
+                    ),
+                ],
+            ),
+            content(
+                "INFERRED FROM",
+                "",
+                vec![elem(
+                    &tags::ReferencedContentItemIdentifier,
+                    RawValue::UInts(vec![1, 2, 1]),
+                )],
+            ),
+            content(
+                "CONTAINS",
+                "SCOORD3D",
+                vec![
+                    code(&tags::ConceptNameCodeSequence, "111030", "Point"),
+                    elem(&tags::GraphicType, RawValue::of_string("POINT")),
+                    elem(&tags::GraphicData, RawValue::Floats(vec![1.0, 2.0, 3.0])),
+                ],
+            ),
+        ];
+
+        let mut dcmroot = DicomRoot::new_empty(&ExplicitVRLittleEndian, CSRef::default());
+        dcmroot.add_child_with_val(&tags::ValueType, RawValue::of_string("CONTAINER"));
+        dcmroot.add_child_with_val(&tags::CompletionFlag, RawValue::of_string("COMPLETE"));
+        dcmroot.add_child_with_val(&tags::ContinuityOfContent, RawValue::of_string("SEPARATE"));
+        *dcmroot.add_child(&tags::ConceptNameCodeSequence) = code(
+            &tags::ConceptNameCodeSequence,
+            "113701",
+            "X-Ray Radiation Dose Report",
+        );
+        *dcmroot.add_child(&tags::ContentSequence) = seq(&tags::ContentSequence, items);
+
+        let report = StructuredReport::from_dcmroot(&dcmroot).unwrap();
+        assert_eq!("COMPLETE", report.completion_flag());
+        assert_eq!("X-Ray Radiation Dose Report", report.root().concept());
+        assert_eq!(5, report.root().children.len());
+        assert_eq!(
+            Some(RelationshipType::HasConceptMod),
+            report.root().children[0].relationship
+        );
+
+        let dap = report.find_all("113722");
+        assert_eq!(1, dap.len());
+        assert!(matches!(
+            dap[0].value,
+            ContentValue::Num { value: Some(v), .. } if (v - 1.5).abs() < f64::EPSILON
+        ));
+        let ContentValue::Reference(ids) = &report.root().children[3].value else {
+            panic!("expected a by-reference relationship");
+        };
+        assert_eq!(
+            "Dose Area Product Total",
+            report.resolve(ids).map_or("", |item| item.concept())
+        );
+        assert!(report.resolve(&[2]).is_none());
+
+        let expected = "\
+X-Ray Radiation Dose Report
+  HAS CONCEPT MOD: Procedure reported = Projection X-Ray
+  CONTAINS: Accumulated X-Ray Dose Data
+    CONTAINS: Dose Area Product Total = 1.5 Gy.m2
+  CONTAINS: Image = 1.2.3.4 frames 2
+  INFERRED FROM: (see content item 1.2.1)
+  CONTAINS: Point = POINT (1, 2, 3)
+";
+        assert_eq!(expected, report.to_string());
+
+        dcmroot.remove_child_by_tag(&tags::ValueType);
+        assert!(StructuredReport::from_dcmroot(&dcmroot).is_err());
+    }
+}
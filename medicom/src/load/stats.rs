@@ -0,0 +1,447 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Statistics and histograms of the values of an `ImageVolume` within a region of interest, in
+//! modality units such as Hounsfield Units.
+
+use std::ops::Range;
+
+use crate::load::{pixeldata::LoadError, IndexVec};
+
+/// A region of voxels of an `ImageVolume`, in the native plane orientation, `VolAxis::Z`. Regions
+/// within a slice contain the pixels whose centers are within the shape, where the center of the
+/// pixel at column `x` and row `y` is `(x, y)`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Region {
+    /// Every voxel of the volume.
+    Volume,
+    /// Every pixel of the slice at the given z-index.
+    Slice(usize),
+    /// The pixels of a slice within a rectangle of whole pixels.
+    Rect {
+        z: usize,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    },
+    /// The pixels of a slice within an axis-aligned ellipse.
+    Ellipse {
+        z: usize,
+        center: (f32, f32),
+        radii: (f32, f32),
+    },
+    /// The pixels of a slice within a polygon of (x, y) vertices, by the even-odd rule.
+    Polygon { z: usize, points: Vec<(f32, f32)> },
+    /// The voxels set in a mask of the whole volume, ordered by x, then y, then z.
+    Mask(Vec<bool>),
+}
+
+impl Region {
+    /// The range of slices which may contain voxels of this region, within a volume of the given
+    /// counts.
+    ///
+    /// # Errors
+    /// - `InvalidRegion` if the region's slice is beyond the volume, a mask is not the size of the
+    ///   volume, or a polygon has fewer than three points.
+    pub fn slices(&self, counts: &IndexVec) -> Result<Range<usize>, LoadError> {
+        let z = match self {
+            Region::Volume => return Ok(0..counts.z),
+            Region::Mask(mask) => {
+                let len = counts.x * counts.y * counts.z;
+                if mask.len() != len {
+                    return Err(LoadError::InvalidRegion(format!(
+                        "mask has {} voxels but the volume has {len}",
+                        mask.len()
+                    )));
+                }
+                return Ok(0..counts.z);
+            }
+            Region::Polygon { points, .. } if points.len() < 3 => {
+                return Err(LoadError::InvalidRegion(format!(
+                    "polygon has {} points",
+                    points.len()
+                )));
+            }
+            Region::Slice(z)
+            | Region::Rect { z, .. }
+            | Region::Ellipse { z, .. }
+            | Region::Polygon { z, .. } => *z,
+        };
+        if z >= counts.z {
+            return Err(LoadError::InvalidRegion(format!(
+                "slice {z} is beyond the {} slices of the volume",
+                counts.z
+            )));
+        }
+        Ok(z..z + 1)
+    }
+
+    /// Whether the voxel at the given coordinate of a volume of the given counts is within this
+    /// region.
+    #[must_use]
+    pub fn contains(&self, counts: &IndexVec, coord: &IndexVec) -> bool {
+        #[allow(clippy::cast_precision_loss)]
+        let (px, py) = (coord.x as f32, coord.y as f32);
+        match self {
+            Region::Volume => true,
+            Region::Slice(z) => coord.z == *z,
+            Region::Rect {
+                z,
+                x,
+                y,
+                width,
+                height,
+            } => {
+                coord.z == *z
+                    && (*x..x + width).contains(&coord.x)
+                    && (*y..y + height).contains(&coord.y)
+            }
+            Region::Ellipse { z, center, radii } => {
+                let dx = (px - center.0) / radii.0;
+                let dy = (py - center.1) / radii.1;
+                coord.z == *z && dx * dx + dy * dy <= 1_f32
+            }
+            Region::Polygon { z, points } => {
+                // Count the crossings of a ray from the point towards +x with each edge.
+                let mut inside = false;
+                for (i, &(x0, y0)) in points.iter().enumerate() {
+                    let (x1, y1) = points[(i + 1) % points.len()];
+                    if (y0 > py) != (y1 > py) && px < x0 + (py - y0) / (y1 - y0) * (x1 - x0) {
+                        inside = !inside;
+                    }
+                }
+                coord.z == *z && inside
+            }
+            Region::Mask(mask) => {
+                let index = coord.x + (coord.y + coord.z * counts.y) * counts.x;
+                mask.get(index).copied().unwrap_or(false)
+            }
+        }
+    }
+}
+
+/// Statistics of the values within a region of a volume.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Statistics {
+    /// The values of the region in ascending order.
+    sorted: Vec<f32>,
+    mean: f32,
+    std_dev: f32,
+    /// The area in mm² of a single pixel.
+    pixel_area: f32,
+    /// The distance in mm between slices.
+    slice_spacing: f32,
+}
+
+impl Statistics {
+    /// Computes the statistics of the given values, or `None` if there are none.
+    ///
+    /// `pixel_area` - The area in mm² of a single pixel, from Pixel Spacing.
+    /// `slice_spacing` - The distance in mm between slices.
+    #[must_use]
+    pub fn new(mut values: Vec<f32>, pixel_area: f32, slice_spacing: f32) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f32::total_cmp);
+        #[allow(clippy::cast_precision_loss)]
+        let count = values.len() as f64;
+        let mean = values.iter().map(|v| f64::from(*v)).sum::<f64>() / count;
+        let variance = values
+            .iter()
+            .map(|v| (f64::from(*v) - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        #[allow(clippy::cast_possible_truncation)]
+        Some(Self {
+            sorted: values,
+            mean: mean as f32,
+            std_dev: variance.sqrt() as f32,
+            pixel_area,
+            slice_spacing,
+        })
+    }
+
+    /// The number of voxels.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.sorted.len()
+    }
+
+    #[must_use]
+    pub fn min(&self) -> f32 {
+        self.sorted[0]
+    }
+
+    #[must_use]
+    pub fn max(&self) -> f32 {
+        self.sorted[self.sorted.len() - 1]
+    }
+
+    #[must_use]
+    pub fn mean(&self) -> f32 {
+        self.mean
+    }
+
+    /// The population standard deviation.
+    #[must_use]
+    pub fn std_dev(&self) -> f32 {
+        self.std_dev
+    }
+
+    #[must_use]
+    pub fn median(&self) -> f32 {
+        self.percentile(50_f32)
+    }
+
+    /// The value below which the given percent of values fall, from 0 to 100, interpolating
+    /// linearly between the closest values.
+    #[must_use]
+    pub fn percentile(&self, percent: f32) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        let rank = percent.clamp(0_f32, 100_f32) / 100_f32 * (self.sorted.len() - 1) as f32;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        let frac = rank - rank.floor();
+        self.sorted[lower] + (self.sorted[upper] - self.sorted[lower]) * frac
+    }
+
+    /// The values of the region in ascending order.
+    #[must_use]
+    pub fn values(&self) -> &[f32] {
+        &self.sorted
+    }
+
+    /// The area in mm² of the voxels, the number of voxels times the area of a pixel. This is the
+    /// area of regions within a single slice.
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        let count = self.count() as f32;
+        count * self.pixel_area
+    }
+
+    /// The volume in mm³ of the voxels, the area times the spacing between slices.
+    #[must_use]
+    pub fn volume(&self) -> f32 {
+        self.area() * self.slice_spacing
+    }
+
+    /// A histogram of the values with the given number of bins, spanning the minimum to the
+    /// maximum value.
+    #[must_use]
+    pub fn histogram(&self, bins: usize) -> Histogram {
+        Histogram::new(&self.sorted, self.min(), self.max(), bins)
+    }
+}
+
+impl std::fmt::Display for Statistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "n={}, mean {:.1}, std {:.1}, min {:.1}, median {:.1}, max {:.1}, area {:.1}mm²",
+            self.count(),
+            self.mean,
+            self.std_dev,
+            self.min(),
+            self.median(),
+            self.max(),
+            self.area()
+        )
+    }
+}
+
+/// The number of values within each of a number of equal-width bins.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    min: f32,
+    max: f32,
+    counts: Vec<usize>,
+}
+
+impl Histogram {
+    /// Counts the values within `min..=max` into the given number of bins. The last bin includes
+    /// `max`. Values outside the range are not counted.
+    #[must_use]
+    pub fn new(values: &[f32], min: f32, max: f32, bins: usize) -> Self {
+        let mut counts = vec![0usize; bins.max(1)];
+        #[allow(clippy::cast_precision_loss)]
+        let width = (max - min) / counts.len() as f32;
+        for val in values.iter().filter(|v| (min..=max).contains(*v)) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let bin = if width > 0_f32 {
+                ((val - min) / width) as usize
+            } else {
+                0
+            };
+            let last = counts.len() - 1;
+            counts[bin.min(last)] += 1;
+        }
+        Self { min, max, counts }
+    }
+
+    /// The number of values within each bin.
+    #[must_use]
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// The width of each bin.
+    #[must_use]
+    pub fn bin_width(&self) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        let bins = self.counts.len() as f32;
+        (self.max - self.min) / bins
+    }
+
+    /// The range of values of the bin at the given index.
+    #[must_use]
+    pub fn bin_range(&self, index: usize) -> Range<f32> {
+        #[allow(clippy::cast_precision_loss)]
+        let start = self.min + self.bin_width() * index as f32;
+        start..start + self.bin_width()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::values::RawValue,
+        dict::tags,
+        load::{
+            imgvol::ImageVolume,
+            stats::{Histogram, Region, Statistics},
+            testsupport::{frame_groups, multi_frame},
+            IndexVec,
+        },
+    };
+
+    const COUNTS: IndexVec = IndexVec { x: 5, y: 5, z: 2 };
+
+    fn members(region: &Region) -> Vec<(usize, usize)> {
+        let mut members = Vec::new();
+        for z in region.slices(&COUNTS).unwrap() {
+            for y in 0..COUNTS.y {
+                for x in 0..COUNTS.x {
+                    if region.contains(&COUNTS, &IndexVec { x, y, z }) {
+                        members.push((x, y));
+                    }
+                }
+            }
+        }
+        members
+    }
+
+    #[test]
+    pub fn test_regions() {
+        let rect = Region::Rect {
+            z: 1,
+            x: 1,
+            y: 2,
+            width: 2,
+            height: 1,
+        };
+        assert_eq!(vec![(1, 2), (2, 2)], members(&rect));
+
+        let ellipse = Region::Ellipse {
+            z: 0,
+            center: (2.0, 2.0),
+            radii: (1.0, 1.0),
+        };
+        assert_eq!(
+            vec![(2, 1), (1, 2), (2, 2), (3, 2), (2, 3)],
+            members(&ellipse)
+        );
+
+        let triangle = Region::Polygon {
+            z: 0,
+            points: vec![(-0.5, -0.5), (3.5, -0.5), (-0.5, 3.5)],
+        };
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (0, 2)],
+            members(&triangle)
+        );
+
+        let mut mask = vec![false; 50];
+        mask[25 + 6] = true;
+        assert_eq!(vec![(1, 1)], members(&Region::Mask(mask)));
+
+        assert!(Region::Slice(2).slices(&COUNTS).is_err());
+        assert!(Region::Mask(vec![true; 3]).slices(&COUNTS).is_err());
+    }
+
+    #[test]
+    pub fn test_statistics() {
+        let stats = Statistics::new(vec![4.0, 1.0, 3.0, 2.0], 0.25, 2.0).unwrap();
+        assert_eq!(4, stats.count());
+        assert!((stats.mean() - 2.5).abs() < f32::EPSILON);
+        assert!((stats.std_dev() - 1.25_f32.sqrt()).abs() < 1e-6);
+        assert!((stats.median() - 2.5).abs() < f32::EPSILON);
+        assert!((stats.percentile(100.0) - 4.0).abs() < f32::EPSILON);
+        assert!((stats.percentile(25.0) - 1.75).abs() < f32::EPSILON);
+        assert!((stats.area() - 1.0).abs() < f32::EPSILON);
+        assert!((stats.volume() - 2.0).abs() < f32::EPSILON);
+        assert!(Statistics::new(Vec::new(), 1.0, 1.0).is_none());
+
+        let histogram = stats.histogram(3);
+        assert_eq!(&[1, 1, 2], histogram.counts());
+        assert!((histogram.bin_width() - 1.0).abs() < f32::EPSILON);
+        assert_eq!(2.0..3.0, histogram.bin_range(1));
+        assert_eq!(
+            &[0, 1],
+            Histogram::new(&[0.0, 5.0, 9.0], 4.0, 6.0, 2).counts()
+        );
+    }
+
+    #[test]
+    pub fn test_volume_statistics() {
+        let mut dcmroot = multi_frame(2, vec![frame_groups(5.0), frame_groups(0.0)]);
+        dcmroot.remove_child_by_tag(&tags::PixelData);
+        dcmroot.add_child_with_val(
+            &tags::PixelData,
+            RawValue::Words(vec![0, 10, 20, 30, 40, 50, 60, 70]),
+        );
+        dcmroot.add_child_with_val(&tags::PixelPaddingValue, RawValue::of_ushort(70));
+        let mut volume = ImageVolume::default();
+        volume.load_slice(dcmroot).unwrap();
+
+        // The padding voxel is excluded.
+        let stats = volume.statistics(&Region::Volume).unwrap();
+        assert_eq!(7, stats.count());
+        assert!((stats.mean() - -994.0).abs() < f32::EPSILON);
+        assert!((stats.max() - -964.0).abs() < f32::EPSILON);
+        assert!((stats.area() - 1.75).abs() < f32::EPSILON);
+        assert!((stats.volume() - 8.75).abs() < f32::EPSILON);
+
+        let slice = volume.statistics(&Region::Slice(1)).unwrap();
+        assert!((slice.median() - -974.0).abs() < f32::EPSILON);
+        assert!((slice.area() - 0.75).abs() < f32::EPSILON);
+        let histogram = volume.histogram(&Region::Slice(0), 2).unwrap();
+        assert_eq!(&[2, 2], histogram.counts());
+
+        let rect = Region::Rect {
+            z: 0,
+            x: 1,
+            y: 0,
+            width: 1,
+            height: 2,
+        };
+        let stats = volume.statistics(&rect).unwrap();
+        assert!((stats.mean() - -1004.0).abs() < f32::EPSILON);
+        assert!(volume.statistics(&Region::Slice(2)).is_err());
+    }
+}
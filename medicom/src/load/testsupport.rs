@@ -0,0 +1,133 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Builders of elements, items, sequences and datasets for constructing test volumes.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    core::{
+        charset::CSRef,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{
+            constants::{tags::ITEM, ts::ExplicitVRLittleEndian},
+            tag::Tag,
+            vr::{self, UN},
+        },
+        values::RawValue,
+    },
+    dict::tags,
+};
+
+/// An element with the given value, using the tag's implicit VR.
+pub(crate) fn elem(tag: &Tag, val: RawValue) -> DicomObject {
+    let mut elem = DicomElement::new_empty(
+        tag,
+        tag.implicit_vr().unwrap_or(&UN),
+        &ExplicitVRLittleEndian,
+    );
+    elem.encode_val(val).unwrap();
+    DicomObject::new(elem)
+}
+
+/// A sequence item with the given child elements.
+pub(crate) fn item(children: Vec<DicomObject>) -> DicomObject {
+    let children = children
+        .into_iter()
+        .map(|c| (c.element().tag(), c))
+        .collect::<BTreeMap<u32, DicomObject>>();
+    DicomObject::new_with_children(
+        DicomElement::new_empty(ITEM, &UN, &ExplicitVRLittleEndian),
+        children,
+        Vec::new(),
+    )
+}
+
+/// A sequence with the given items.
+pub(crate) fn seq(tag: &Tag, items: Vec<DicomObject>) -> DicomObject {
+    DicomObject::new_with_children(
+        DicomElement::new_empty(tag, &vr::SQ, &ExplicitVRLittleEndian),
+        BTreeMap::new(),
+        items,
+    )
+}
+
+/// A 2x2 monochrome multi-frame dataset where each pixel of a frame is the frame index.
+pub(crate) fn multi_frame(num_frames: u16, per_frame: Vec<DicomObject>) -> DicomRoot {
+    let mut dcmroot = DicomRoot::new_empty(&ExplicitVRLittleEndian, CSRef::default());
+    dcmroot.add_child_with_val(&tags::SOPInstanceUID, RawValue::of_uid("1.2.3.4.5"));
+    dcmroot.add_child_with_val(&tags::SeriesInstanceUID, RawValue::of_uid("1.2.3.4"));
+    dcmroot.add_child_with_val(&tags::SamplesperPixel, RawValue::of_ushort(1));
+    dcmroot.add_child_with_val(
+        &tags::PhotometricInterpretation,
+        RawValue::of_string("MONOCHROME2"),
+    );
+    dcmroot.add_child_with_val(
+        &tags::NumberofFrames,
+        RawValue::of_string(num_frames.to_string()),
+    );
+    dcmroot.add_child_with_val(&tags::Rows, RawValue::of_ushort(2));
+    dcmroot.add_child_with_val(&tags::Columns, RawValue::of_ushort(2));
+    dcmroot.add_child_with_val(&tags::BitsAllocated, RawValue::of_ushort(16));
+    dcmroot.add_child_with_val(&tags::BitsStored, RawValue::of_ushort(16));
+    dcmroot.add_child_with_val(&tags::PixelRepresentation, RawValue::of_ushort(0));
+    let shared = item(vec![
+        seq(
+            &tags::PixelMeasuresSequence,
+            vec![item(vec![
+                elem(&tags::PixelSpacing, RawValue::Doubles(vec![0.5, 0.5])),
+                elem(&tags::SliceThickness, RawValue::of_double(2.0)),
+            ])],
+        ),
+        seq(
+            &tags::PlaneOrientationSequence,
+            vec![item(vec![elem(
+                &tags::ImageOrientationPatient,
+                RawValue::Doubles(vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]),
+            )])],
+        ),
+        seq(
+            &tags::PixelValueTransformationSequence,
+            vec![item(vec![
+                elem(&tags::RescaleIntercept, RawValue::of_double(-1024.0)),
+                elem(&tags::RescaleSlope, RawValue::of_double(1.0)),
+            ])],
+        ),
+    ]);
+    *dcmroot.add_child(&tags::SharedFunctionalGroupsSequence) =
+        seq(&tags::SharedFunctionalGroupsSequence, vec![shared]);
+    if !per_frame.is_empty() {
+        *dcmroot.add_child(&tags::PerFrameFunctionalGroupsSequence) =
+            seq(&tags::PerFrameFunctionalGroupsSequence, per_frame);
+    }
+    let pixels = (0..num_frames)
+        .flat_map(|frame| [frame; 4])
+        .collect::<Vec<u16>>();
+    dcmroot.add_child_with_val(&tags::PixelData, RawValue::Words(pixels));
+    dcmroot
+}
+
+/// Per-frame Functional Groups positioning a frame of `multi_frame` at the given z-position.
+pub(crate) fn frame_groups(z: f64) -> DicomObject {
+    item(vec![seq(
+        &tags::PlanePositionSequence,
+        vec![item(vec![elem(
+            &tags::ImagePositionPatient,
+            RawValue::Doubles(vec![-10.0, -10.0, z]),
+        )])],
+    )])
+}
@@ -82,3 +82,10 @@ pub const NULL_ELEMENT: &[u8] = &[
     // INVALID              VR: INVALID VL: 0
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
 ];
+
+pub const ZERO_LENGTH_SQ_ELEMENTS: &[u8] = &[
+    // ReferencedStudySequence  VR: SQ      VL: 0
+    0x08, 0x00, 0x10, 0x11, 0x53, 0x51, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    // PatientsName             VR: PN      VL: 8
+    0x10, 0x00, 0x10, 0x00, 0x50, 0x4E, 0x08, 0x00, 0x44, 0x4F, 0x45, 0x5E, 0x4A, 0x4F, 0x48, 0x4E,
+];
@@ -18,12 +18,16 @@
 mod dict_tests {
     use medicom::{
         self,
-        core::defn::{tag::Tag, ts::TransferSyntax, uid::UIDRef},
+        core::defn::{dcmdict::DicomDictionary, tag::Tag, ts::TransferSyntax, uid::UIDRef},
         dict::{
             lookup::{
                 TAG_BY_IDENT, TAG_BY_VALUE, TS_BY_IDENT, TS_BY_UID, UID_BY_IDENT, UID_BY_UID,
             },
-            tags::{FilesetID, PixelData, TransferSyntaxUID},
+            stdlookup::STANDARD_DICOM_DICTIONARY,
+            tags::{
+                CurveData, FilesetID, OverlayData, OverlayRows, PixelData, SourceImageIDs,
+                TransferSyntaxUID, TransformLabel,
+            },
             transfer_syntaxes::{ExplicitVRLittleEndian, ImplicitVRLittleEndian},
             uids::{CTImageStorage, MRImageStorage},
         },
@@ -78,6 +82,28 @@ mod dict_tests {
         assert_eq!(&TransferSyntaxUID, tsuid_by_tag);
     }
 
+    #[test]
+    pub fn test_repeating_group_tags_lookup() {
+        assert!(OverlayData.is_repeating());
+        assert_eq!(0xFF00_FFFF, OverlayData.mask());
+        assert!(OverlayData.matches(0x6002_3000u32));
+        assert!(!PixelData.is_repeating());
+
+        let dict = &STANDARD_DICOM_DICTIONARY;
+        for group in [0x6000_0000u32, 0x6002_0000, 0x601E_0000] {
+            assert_eq!(Some(&OverlayData), dict.get_tag_by_number(group | 0x3000));
+            assert_eq!(Some(&OverlayRows), dict.get_tag_by_number(group | 0x0010));
+        }
+        assert_eq!(Some(&CurveData), dict.get_tag_by_number(0x5004_3000));
+        assert_eq!(Some(&SourceImageIDs), dict.get_tag_by_number(0x0020_3105));
+        assert_eq!(Some(&OverlayData), dict.get_tag_by_name("OverlayData"));
+
+        // Exact definitions take precedence over repeating groups, and odd groups are private.
+        assert_eq!(Some(&TransformLabel), dict.get_tag_by_number(0x0028_0400));
+        assert!(dict.get_tag_by_number(0x6001_3000).is_none());
+        assert!(dict.get_tag_by_number(0x6000_9999).is_none());
+    }
+
     #[test]
     pub fn test_transfer_syntaxes_lookup() {
         let ivrle_by_ident: &TransferSyntax = TS_BY_IDENT
@@ -60,7 +60,7 @@ mod parsing_tests {
             parse_file,
         },
         mock::MockDicomDataset,
-        mockdata::{INVALID_VR_ELEMENT, NULL_ELEMENT, STANDARD_HEADER},
+        mockdata::{INVALID_VR_ELEMENT, NULL_ELEMENT, STANDARD_HEADER, ZERO_LENGTH_SQ_ELEMENTS},
     };
 
     #[test]
@@ -509,6 +509,33 @@ mod parsing_tests {
         Ok(())
     }
 
+    /// A sequence with an explicit length of zero has no items, and the element which follows it is
+    /// a sibling of the sequence rather than its child.
+    #[test]
+    fn test_empty_seq_explicit_length() -> ParseResult<()> {
+        let mut parser: Parser<'_, MockDicomDataset> =
+            MockDicomDataset::build_mock_parser(&[STANDARD_HEADER, ZERO_LENGTH_SQ_ELEMENTS]);
+        let dcmroot: DicomRoot =
+            DicomRoot::parse(&mut parser)?.expect("Failed to parse DICOM elements");
+
+        let rss_obj: &DicomObject = dcmroot
+            .get_child_by_tag(ReferencedStudySequence.num())
+            .expect("Should be able to parse ReferencedStudySequence");
+        assert_eq!(ValueLength::Explicit(0), rss_obj.element().vl());
+        assert_eq!(0, rss_obj.child_count());
+        assert_eq!(0, rss_obj.item_count());
+
+        let name_obj: &DicomObject = dcmroot
+            .get_child_by_tag(PatientsName.num())
+            .expect("PatientsName should be a top-level element");
+        assert_eq!(
+            "DOE^JOHN",
+            String::try_from(&ElementWithVr(name_obj.element(), &vr::PN))?
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_private_tag_un_sq_with_std() -> ParseResult<()> {
         test_private_tag_un_sq(true)
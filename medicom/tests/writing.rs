@@ -28,6 +28,7 @@ mod writing_tests {
     use medicom::{
         core::{
             charset::CSRef,
+            codec::{rle, FrameLayout},
             dcmelement::DicomElement,
             dcmobject::DicomRoot,
             defn::{
@@ -35,7 +36,7 @@ mod writing_tests {
                 tag::TagPath,
                 vl::ValueLength,
                 vr::{
-                    AE, AT, CS, CS_SEPARATOR_BYTE, DS, FD, IS, LO, NULL_PADDING, OB, SH,
+                    AE, AT, CS, CS_SEPARATOR_BYTE, DS, FD, IS, LO, NULL_PADDING, OB, OW, SH,
                     SPACE_PADDING, UI, US, UV,
                 },
             },
@@ -50,14 +51,22 @@ mod writing_tests {
         dict::{
             stdlookup::STANDARD_DICOM_DICTIONARY,
             tags::{
-                FileMetaInformationVersion, FrameIncrementPointer, ImplementationClassUID,
-                ImplementationVersionName, MediaStorageSOPClassUID, MediaStorageSOPInstanceUID,
-                ReferencedWaveformChannels, SourceApplicationEntityTitle, SpecificCharacterSet,
-                StudyComments, TransferSyntaxUID,
+                BitsAllocated, BitsStored, Columns, FileMetaInformationVersion,
+                FrameIncrementPointer, HighBit, ImplementationClassUID, ImplementationVersionName,
+                MediaStorageSOPClassUID, MediaStorageSOPInstanceUID, NumberofFrames,
+                PhotometricInterpretation, PixelData, PixelRepresentation, PixelSpacing,
+                ReferencedWaveformChannels, Rows, SOPInstanceUID, SamplesperPixel,
+                SeriesInstanceUID, SliceThickness, SourceApplicationEntityTitle,
+                SpecificCharacterSet, StudyComments, TransferSyntaxUID,
+            },
+            transfer_syntaxes::{
+                JPEGBaselineProcess1,
+                JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1,
+                RLELossless,
             },
-            transfer_syntaxes::{JPEGBaselineProcess1, RLELossless},
             uids::CTImageStorage,
         },
+        load::{imgvol::ImageVolume, pixeldata::SliceBuffer},
     };
 
     use crate::common::{
@@ -351,6 +360,151 @@ mod writing_tests {
         Ok(())
     }
 
+    /// Write a `DicomRoot` holding native multi-frame `PixelData` using a `Writer` configured for
+    /// `RLELossless`. Parse the written dataset and verify the `TransferSyntaxUID` was updated and
+    /// that each frame's fragment decodes to the original pixel data.
+    #[test]
+    pub fn test_write_rle_lossless() -> Result<(), WriteError> {
+        let layout = FrameLayout {
+            rows: 4,
+            cols: 5,
+            samples_per_pixel: 1,
+            bits_alloc: 16,
+            bits_stored: 16,
+            pixel_rep: 0,
+        };
+        let num_frames = 2usize;
+        let native = (0..layout.pixel_count() * num_frames)
+            .map(|i| u16::try_from(i / 3 * 300).unwrap())
+            .collect::<Vec<u16>>();
+
+        let mut dcmroot = DicomRoot::new_empty(&ExplicitVRLittleEndian, CSRef::default());
+        dcmroot.add_child_with_val(
+            &TransferSyntaxUID,
+            RawValue::of_uid(ExplicitVRLittleEndian.uid().uid()),
+        );
+        dcmroot.add_child_with_val(&SamplesperPixel, RawValue::of_ushort(1));
+        dcmroot.add_child_with_val(&NumberofFrames, RawValue::of_string("2"));
+        dcmroot.add_child_with_val(&Rows, RawValue::of_ushort(layout.rows));
+        dcmroot.add_child_with_val(&Columns, RawValue::of_ushort(layout.cols));
+        dcmroot.add_child_with_val(&BitsAllocated, RawValue::of_ushort(16));
+        dcmroot.add_child_with_val(&BitsStored, RawValue::of_ushort(16));
+        dcmroot.add_child_with_val(&PixelRepresentation, RawValue::of_ushort(0));
+        let mut pixel_data = DicomElement::new_empty(&PixelData, &OW, &ExplicitVRLittleEndian);
+        pixel_data.encode_val(RawValue::Words(native.clone()))?;
+        let native_bytes = pixel_data.data().clone();
+        dcmroot.add_element(pixel_data);
+
+        let mut writer = WriterBuilder::for_file().ts(&RLELossless).build(Vec::new());
+        writer.write_dcmroot(&dcmroot)?;
+        let written_bytes = writer.into_dataset();
+
+        let mut parser =
+            ParserBuilder::default().build(written_bytes.as_slice(), &STANDARD_DICOM_DICTIONARY);
+        let written = DicomRoot::parse(&mut parser)?.unwrap();
+        assert_eq!(&RLELossless, written.ts());
+        assert_eq!(
+            Some(RLELossless.uid().uid()),
+            written
+                .get_value_by_tag(&TransferSyntaxUID)
+                .and_then(|v| v.string().cloned())
+                .as_deref()
+        );
+
+        let pixel_data = written.get_child_by_tag(&PixelData).unwrap();
+        assert!(pixel_data.element().has_fragments());
+        // The Basic Offset Table followed by one fragment per frame.
+        assert_eq!(num_frames + 1, pixel_data.item_count());
+        let bot = pixel_data.get_item_by_index(1).unwrap().element().data();
+        assert_eq!(num_frames * 4, bot.len());
+
+        // Frames are located through the Basic Offset Table, and can be read in any order.
+        let table = written.pixel_data_frames().unwrap().unwrap();
+        assert_eq!(num_frames, table.len());
+        for frame_num in (0..num_frames).rev() {
+            let compressed = written.pixel_data_frame(&table, frame_num).unwrap();
+            let frame = rle::decode_frame(&compressed, &layout).unwrap();
+            let frame_len = layout.frame_len();
+            assert_eq!(
+                &native_bytes[frame_num * frame_len..(frame_num + 1) * frame_len],
+                frame.as_slice()
+            );
+        }
+        // Reading every frame in order gathers the fragments once.
+        let frames = written
+            .pixel_data_frames_iter(&table)
+            .unwrap()
+            .collect::<Option<Vec<_>>>()
+            .unwrap();
+        assert_eq!(num_frames, frames.len());
+        for (frame_num, compressed) in frames.iter().enumerate() {
+            assert_eq!(
+                written.pixel_data_frame(&table, frame_num).as_deref(),
+                Some(compressed.as_ref())
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_write_jpeg_lossless_signed() -> Result<(), WriteError> {
+        let rows = 6u16;
+        let cols = 7u16;
+        // CT values stored in 12 bits, including values at both extremes.
+        let native = (0..usize::from(rows) * usize::from(cols))
+            .map(|i| match i % 9 {
+                0 => -2048,
+                1 => 2047,
+                _ => i16::try_from(i * 41).unwrap() - 1024,
+            })
+            .collect::<Vec<i16>>();
+
+        let mut dcmroot = DicomRoot::new_empty(&ExplicitVRLittleEndian, CSRef::default());
+        dcmroot.add_child_with_val(
+            &TransferSyntaxUID,
+            RawValue::of_uid(ExplicitVRLittleEndian.uid().uid()),
+        );
+        dcmroot.add_child_with_val(&SOPInstanceUID, RawValue::of_uid("1.2.3.4.5"));
+        dcmroot.add_child_with_val(&SeriesInstanceUID, RawValue::of_uid("1.2.3.4"));
+        dcmroot.add_child_with_val(&SliceThickness, RawValue::of_double(1.0));
+        dcmroot.add_child_with_val(&SamplesperPixel, RawValue::of_ushort(1));
+        dcmroot.add_child_with_val(
+            &PhotometricInterpretation,
+            RawValue::of_string("MONOCHROME2"),
+        );
+        dcmroot.add_child_with_val(&Rows, RawValue::of_ushort(rows));
+        dcmroot.add_child_with_val(&Columns, RawValue::of_ushort(cols));
+        dcmroot.add_child_with_val(&PixelSpacing, RawValue::Doubles(vec![0.5, 0.5]));
+        dcmroot.add_child_with_val(&BitsAllocated, RawValue::of_ushort(16));
+        dcmroot.add_child_with_val(&BitsStored, RawValue::of_ushort(12));
+        dcmroot.add_child_with_val(&HighBit, RawValue::of_ushort(11));
+        dcmroot.add_child_with_val(&PixelRepresentation, RawValue::of_ushort(1));
+        let mut pixel_data = DicomElement::new_empty(&PixelData, &OW, &ExplicitVRLittleEndian);
+        pixel_data.encode_val(RawValue::Shorts(native.clone()))?;
+        dcmroot.add_element(pixel_data);
+
+        let ts = &JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1;
+        let mut writer = WriterBuilder::for_file().ts(ts).build(Vec::new());
+        writer.write_dcmroot(&dcmroot)?;
+        let written_bytes = writer.into_dataset();
+
+        let mut parser =
+            ParserBuilder::default().build(written_bytes.as_slice(), &STANDARD_DICOM_DICTIONARY);
+        let written = DicomRoot::parse(&mut parser)?.unwrap();
+        assert_eq!(ts, written.ts());
+
+        let mut volume = ImageVolume::default();
+        volume
+            .load_slice(written)
+            .expect("load JPEG Lossless slice");
+        assert_eq!(SliceBuffer::I16(native), volume.slices()[0]);
+        assert!((volume.min_val() + 2048_f32).abs() < f32::EPSILON);
+        assert!((volume.max_val() - 2047_f32).abs() < f32::EPSILON);
+
+        Ok(())
+    }
+
     #[test]
     #[ignore]
     fn test_reencoded_values_all_files() -> Result<(), WriteError> {
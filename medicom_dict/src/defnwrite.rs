@@ -49,6 +49,9 @@ static LOOKUP_PREAMBLE: &str = "/*
 //! on the name, with spaces removed and all letters lower-cased, to allow for case-insensitive
 //! look-ups. The associated value is the common value for the item, such as tag number for DICOM
 //! Elements, and the stringified UID for Transfer Syntaxes and DICOM UIDs.
+//!
+//! DICOM Elements defined in repeating groups, such as Overlay Data (60xx,3000), are looked up by
+//! their tag number with one of `TAG_MASKS` applied, in `TAG_BY_MASKED_VALUE`.
 
 #![allow(clippy::unreadable_literal, clippy::doc_markdown)]
 
@@ -190,6 +193,27 @@ pub static {}: Tag = Tag::new(
     };
 }
 
+macro_rules! masked_dicom_element_definition {
+    ($($args:tt)*) => {
+        format!(
+"/// {}
+///
+/// - **Tag:** {}
+/// - **VR:** {}
+/// - **VM:** {}
+pub static {}: Tag = Tag::new_masked(
+    \"{}\",
+    0x{:04X}_{:04X},
+    0x{:04X}_{:04X},
+    {},
+    {},
+    \"{}\",
+);
+
+", $($args)*)
+    };
+}
+
 /// Parse the given input XML files based on the DICOM standard structure and output the resulting
 /// definition files to the given folder.
 ///
@@ -229,6 +253,7 @@ pub fn process_xml_files(files: Vec<File>, folder: &Path) -> Result<(), Error> {
 fn process_entries(xml_definitions: Vec<XmlDicomDefinition>, folder: &Path) -> Result<(), Error> {
     let mut tag_ident_lookup_phf: phf_codegen::Map<String> = phf_codegen::Map::new();
     let mut tag_tag_lookup_phf: phf_codegen::Map<u32> = phf_codegen::Map::new();
+    let mut tag_masked_lookup_phf: phf_codegen::Map<u32> = phf_codegen::Map::new();
 
     let mut uid_ident_lookup_phf: phf_codegen::Map<String> = phf_codegen::Map::new();
     let mut uid_id_lookup_phf: phf_codegen::Map<String> = phf_codegen::Map::new();
@@ -258,15 +283,26 @@ fn process_entries(xml_definitions: Vec<XmlDicomDefinition>, folder: &Path) -> R
     let mut dicom_elements = xml_dicom_elements
         .iter()
         .filter_map(|e| {
-            process_element(
-                e,
-                "tags::",
-                &mut tag_ident_lookup_phf,
-                &mut tag_tag_lookup_phf,
-            )
+            // Elements in repeating groups are looked up by their masked tag number separately, as
+            // their masked number may be the same as an element's tag number.
+            let tag_lookup = if e.tag_mask == u32::MAX {
+                &mut tag_tag_lookup_phf
+            } else {
+                &mut tag_masked_lookup_phf
+            };
+            process_element(e, "tags::", &mut tag_ident_lookup_phf, tag_lookup)
         })
         .collect::<String>();
 
+    // The masks of repeating groups, with the most specific masks first.
+    let mut tag_masks = xml_dicom_elements
+        .iter()
+        .map(|e| e.tag_mask)
+        .filter(|mask| *mask != u32::MAX)
+        .collect::<Vec<u32>>();
+    tag_masks.sort_by(|a, b| b.count_ones().cmp(&a.count_ones()).then(b.cmp(a)));
+    tag_masks.dedup();
+
     let mut uids = xml_uids
         .iter()
         .filter_map(|uid| process_uid(uid, &mut uid_ident_lookup_phf, &mut uid_id_lookup_phf))
@@ -344,6 +380,19 @@ fn process_entries(xml_definitions: Vec<XmlDicomDefinition>, folder: &Path) -> R
         "pub static TAG_BY_VALUE: phf::Map<u32, TagRef> = ",
         tag_tag_lookup_phf,
     )?;
+    write_uintkey_map(
+        &mut lookup_file,
+        "pub static TAG_BY_MASKED_VALUE: phf::Map<u32, TagRef> = ",
+        tag_masked_lookup_phf,
+    )?;
+    let tag_masks = tag_masks
+        .iter()
+        .map(|mask| format!("    0x{:04X}_{:04X},\n", mask >> 16, mask & 0x0000_FFFF))
+        .collect::<String>();
+    write!(
+        &mut lookup_file,
+        "pub static TAG_MASKS: &[u32] = &[\n{tag_masks}];\n\n"
+    )?;
     write_stringkey_map(
         &mut lookup_file,
         "pub static TS_BY_IDENT: phf::Map<&'static str, TSRef> = ",
@@ -490,7 +539,19 @@ fn process_element(
     let tag_group: u32 = (element.tag >> 16) & 0x0000_FFFF;
     let tag_element: u32 = element.tag & 0x0000_FFFF;
 
-    let tag_display: String = format!("({tag_group:04X},{tag_element:04X})");
+    // Tags in repeating groups display each repeating digit as an 'x', e.g. "(60xx,3000)".
+    let tag_display: String = format!("({tag_group:04X},{tag_element:04X})")
+        .chars()
+        .zip(
+            format!(
+                "({:04X},{:04X})",
+                element.tag_mask >> 16,
+                element.tag_mask & 0xFFFF
+            )
+            .chars(),
+        )
+        .map(|(c, m)| if m == '0' { 'x' } else { c })
+        .collect();
 
     // VR will either be listed as a valid VR, a list of possible VR a la "US or SS or DS", or have
     // the text "See Note ..."
@@ -521,19 +582,37 @@ fn process_element(
         }
     };
 
-    let code: String = dicom_element_definition!(
-        element.name,
-        tag_display,
-        vr,
-        element.vm, // comment placeholders
-        var_name,
-        var_name,
-        tag_group,
-        tag_element,
-        vr_value,
-        vm,
-        element.name
-    ); // field placeholders
+    let code: String = if element.tag_mask == u32::MAX {
+        dicom_element_definition!(
+            element.name,
+            tag_display,
+            vr,
+            element.vm, // comment placeholders
+            var_name,
+            var_name,
+            tag_group,
+            tag_element,
+            vr_value,
+            vm,
+            element.name
+        ) // field placeholders
+    } else {
+        masked_dicom_element_definition!(
+            element.name,
+            tag_display,
+            vr,
+            element.vm, // comment placeholders
+            var_name,
+            var_name,
+            tag_group,
+            tag_element,
+            element.tag_mask >> 16,
+            element.tag_mask & 0x0000_FFFF,
+            vr_value,
+            vm,
+            element.name
+        ) // field placeholders
+    };
 
     let var_name_key: String = var_name.to_lowercase();
     ident_lookup.entry(var_name_key, format!("&{dict}{var_name}"));
@@ -47,6 +47,9 @@ pub enum XmlDicomDefinition {
 #[derive(Debug, PartialEq, Eq, PartialOrd, Clone)]
 pub struct XmlDicomElement {
     pub tag: u32,
+    /// The bits of the tag which identify the element. Elements defined in a repeating group, such
+    /// as `(60xx,3000)`, have the bits of each `x` digit cleared in both the tag and the mask.
+    pub tag_mask: u32,
     pub name: String,
     pub keyword: String,
     pub vr: String,
@@ -119,7 +122,7 @@ pub struct XmlDicomDefinitionIterator<R: BufRead> {
 
     table: XmlDicomDefinitionTable,
 
-    element_tag: Option<u32>,
+    element_tag: Option<(u32, u32)>,
     element_name: Option<String>,
     element_keyword: Option<String>,
     element_vr: Option<String>,
@@ -165,8 +168,18 @@ fn parse_text_bytes(data: &BytesText<'_>) -> String {
         .replace('\u{200b}', "")
 }
 
-fn parse_text_bytes_as_u32(data: &BytesText<'_>) -> Option<u32> {
-    u32::from_str_radix(&parse_text_bytes(data).replace(['(', ')', ','], ""), 16).ok()
+/// Parses a tag such as `(0010,0010)` into its number and mask. Each `x` digit of a tag in a
+/// repeating group, such as `(60xx,3000)`, is cleared in both the number and the mask.
+fn parse_text_bytes_as_tag(data: &BytesText<'_>) -> Option<(u32, u32)> {
+    let text = parse_text_bytes(data).replace(['(', ')', ','], "");
+    if text.len() != 8 {
+        return None;
+    }
+    let tag = u32::from_str_radix(&text.replace(['x', 'X'], "0"), 16).ok()?;
+    let mask = text.chars().fold(0u32, |mask, c| {
+        (mask << 4) | if c == 'x' || c == 'X' { 0 } else { 0xF }
+    });
+    Some((tag, mask))
 }
 
 impl<R: BufRead> XmlDicomDefinitionIterator<R> {
@@ -421,8 +434,10 @@ impl<R: BufRead> Iterator for XmlDicomDefinitionIterator<R> {
                             // DICOM Element or UID were extracted.
                             if local_name == QName(b"tr").into() {
                                 if self.is_next_element_fully_read() {
+                                    let (tag, tag_mask) = self.element_tag.take().unwrap();
                                     let out = XmlDicomElement {
-                                        tag: self.element_tag.take().unwrap(),
+                                        tag,
+                                        tag_mask,
                                         name: self.element_name.take().unwrap(),
                                         keyword: self.element_keyword.take().unwrap(),
                                         vr: self.element_vr.take().unwrap(),
@@ -564,7 +579,7 @@ impl<R: BufRead> Iterator for XmlDicomDefinitionIterator<R> {
                         XmlDicomElementCell::RowStart => {}
                         XmlDicomElementCell::Tag => {
                             if self.element_tag.is_none() {
-                                self.element_tag = parse_text_bytes_as_u32(&data);
+                                self.element_tag = parse_text_bytes_as_tag(&data);
                             }
                         }
                         XmlDicomElementCell::Name => {
@@ -0,0 +1,114 @@
+/*
+   Copyright 2024-2025 Christopher Speck
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! The document command extracts the document of an Encapsulated Document instance, such as a PDF
+//! report or an STL model, or creates an Encapsulated Document instance from a document.
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use anyhow::{anyhow, Result};
+
+use medicom::{
+    core::{dcmobject::DicomRoot, write::builder::WriterBuilder},
+    load::encapdoc::{DocumentContext, DocumentType, EncapsulatedDocument},
+};
+
+use crate::{
+    app::{parse_file, CommandApplication},
+    args::{DocumentArgs, DocumentCommand, DocumentCreateArgs},
+};
+
+pub struct DocumentApp {
+    args: DocumentArgs,
+}
+
+impl DocumentApp {
+    pub fn new(args: DocumentArgs) -> DocumentApp {
+        DocumentApp { args }
+    }
+
+    fn parse_dcmroot(path: &Path) -> Result<DicomRoot> {
+        let mut parser = parse_file(path, false)?;
+        DicomRoot::parse(&mut parser)?.ok_or_else(|| anyhow!("file is empty: {}", path.display()))
+    }
+
+    fn extract(file: &Path, output: &Path) -> Result<()> {
+        let dcmroot = Self::parse_dcmroot(file)?;
+        let doc = EncapsulatedDocument::from_dcmroot(&dcmroot)?;
+        std::fs::write(output, doc.data())?;
+        println!(
+            "Extracted {} bytes of {} to {}",
+            doc.data().len(),
+            doc.mime_type(),
+            output.display()
+        );
+        Ok(())
+    }
+
+    fn create(args: &DocumentCreateArgs) -> Result<()> {
+        let (input, output) = (args.input.as_path(), args.output.as_path());
+        let extension = input
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default();
+        let Some(doc_type) = DocumentType::from_extension(extension) else {
+            return Err(anyhow!("unsupported document type: {}", input.display()));
+        };
+
+        let mut ctx = match &args.study {
+            Some(study) => DocumentContext::from_dcmroot(&Self::parse_dcmroot(study)?),
+            None => DocumentContext::default(),
+        };
+        if let Some(patient_name) = &args.patient_name {
+            ctx.patient_name.clone_from(patient_name);
+        }
+        if let Some(patient_id) = &args.patient_id {
+            ctx.patient_id.clone_from(patient_id);
+        }
+        if let Some(accession_number) = &args.accession_number {
+            ctx.accession_number.clone_from(accession_number);
+        }
+        let title = args.title.clone().unwrap_or_else(|| {
+            input
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        });
+
+        let doc = EncapsulatedDocument::new(doc_type, std::fs::read(input)?).with_title(&title);
+        let dcmroot = doc.to_dcmroot(&ctx)?;
+
+        let file = BufWriter::new(File::create(output)?);
+        let mut writer = WriterBuilder::for_file().ts(dcmroot.ts()).build(file);
+        writer.write_dcmroot(&dcmroot)?;
+        writer.into_dataset().flush()?;
+        println!("Created {doc_type} instance {}", output.display());
+        Ok(())
+    }
+}
+
+impl CommandApplication for DocumentApp {
+    fn run(&mut self) -> Result<()> {
+        match &self.args.cmd {
+            DocumentCommand::Extract { file, output } => Self::extract(file, output),
+            DocumentCommand::Create(args) => Self::create(args),
+        }
+    }
+}
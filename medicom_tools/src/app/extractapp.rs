@@ -19,8 +19,8 @@
 use anyhow::{anyhow, Result};
 use image::{ImageBuffer, Rgb};
 use medicom::{
-    core::{dcmobject::DicomRoot, defn::ts::TSRef},
-    load::{imgvol::ImageVolume, VolAxis},
+    core::dcmobject::DicomRoot,
+    load::{imgvol::ImageVolume, VolAxis, VolPixel},
 };
 
 use crate::{app::parse_file, args::ExtractArgs, CommandApplication};
@@ -34,10 +34,6 @@ impl ExtractApp {
         ExtractApp { args }
     }
 
-    pub(crate) fn is_jpeg(ts: TSRef) -> bool {
-        ts.uid().name().contains("JPEG")
-    }
-
     fn extract_image(&self) -> Result<()> {
         let mut output = self.args.output.clone();
         let extension = output
@@ -51,13 +47,6 @@ impl ExtractApp {
             .unwrap_or("image".to_string());
 
         let mut parser = parse_file(&self.args.file, true)?;
-        if ExtractApp::is_jpeg(parser.ts()) {
-            return Err(anyhow!(
-                "Unsupported TransferSyntax: {}",
-                parser.ts().uid().name()
-            ));
-        }
-
         let Some(dcmroot) = DicomRoot::parse(&mut parser)? else {
             return Err(anyhow!("DICOM SOP is missing PixelData"));
         };
@@ -66,19 +55,53 @@ impl ExtractApp {
         let win = imgvol
             .minmax_winlevel()
             .with_out(f32::from(u8::MIN), f32::from(u8::MAX));
+        let pipeline = imgvol.grayscale_pipeline();
 
         let axis = VolAxis::Z;
-        let axis_dims = imgvol.axis_dims(&axis);
+        let projection = self
+            .args
+            .projection
+            .map(|mode| {
+                let count = imgvol.axis_dims(&axis).z;
+                let slices = match self.args.slab {
+                    Some(thickness) => imgvol.axis_slab(&axis, count / 2, thickness),
+                    None => 0..count,
+                };
+                imgvol.project(&axis, slices, mode)
+            })
+            .transpose()?;
+        let (width, height, pixels) = if let Some(projection) = &projection {
+            let counts = projection.counts();
+            let pixels = projection.slice_iter(&axis, 0).collect::<Vec<VolPixel>>();
+            (counts.x, counts.y, pixels)
+        } else {
+            let axis_dims = imgvol.axis_dims(&axis);
+            let pixels = imgvol.slice_iter(&axis, 0).collect::<Vec<VolPixel>>();
+            (axis_dims.x, axis_dims.y, pixels)
+        };
+        // Overlays are drawn over the first slice, not projections.
+        let overlays = self.args.overlays && projection.is_none();
 
         let mut image: ImageBuffer<Rgb<u8>, Vec<u8>> =
-            ImageBuffer::new(u32::try_from(axis_dims.x)?, u32::try_from(axis_dims.y)?);
-        for pix in imgvol.slice_iter(&axis, 0) {
+            ImageBuffer::new(u32::try_from(width)?, u32::try_from(height)?);
+        for pix in pixels {
             #[allow(clippy::cast_possible_truncation)]
-            let val = win.apply(pix.r) as u8;
+            let val = if overlays && imgvol.overlay_at(&pix.coord) {
+                [u8::MAX; 3]
+            } else if imgvol.is_rgb() {
+                [
+                    win.apply(pix.r) as u8,
+                    win.apply(pix.g) as u8,
+                    win.apply(pix.b) as u8,
+                ]
+            } else {
+                let val = pipeline.display(pix.r, win.out_min(), win.out_max()) as u8;
+                [val, val, val]
+            };
             image.put_pixel(
                 u32::try_from(pix.coord.x)?,
                 u32::try_from(pix.coord.y)?,
-                Rgb([val, val, val]),
+                Rgb(val),
             );
         }
         image.save(format!("{filename}.{extension}"))?;
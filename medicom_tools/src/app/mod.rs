@@ -37,6 +37,7 @@ use medicom::{
 };
 
 pub(crate) mod archiveapp;
+pub(crate) mod documentapp;
 #[cfg(feature = "image")]
 pub(crate) mod extractapp;
 #[cfg(feature = "index")]
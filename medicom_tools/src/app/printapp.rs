@@ -15,17 +15,21 @@
 */
 
 //! The print command renders the contents of a DICOM dataset to stdout, in a format similar to the
-//! dcmdump tool.
+//! dcmdump tool, or the content tree of a Structured Report as an indented text report.
 
 use std::{
     io::{self, Write},
     path::{Path, PathBuf},
 };
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
-use medicom::core::{
-    dcmelement::DicomElement, defn::constants::tags::FILE_META_GROUP_END, inspect::FormattedElement,
+use medicom::{
+    core::{
+        dcmelement::DicomElement, dcmobject::DicomRoot, defn::constants::tags::FILE_META_GROUP_END,
+        inspect::FormattedElement,
+    },
+    load::sr::StructuredReport,
 };
 
 use crate::{
@@ -50,6 +54,15 @@ impl CommandApplication for PrintApp {
         let mut parser = parse_file(path, true)?;
 
         let mut stdout = io::stdout().lock();
+        if self.args.sr {
+            let Some(dcmroot) = DicomRoot::parse(&mut parser)? else {
+                return Err(anyhow!("DICOM dataset is empty"));
+            };
+            let report = StructuredReport::from_dcmroot(&dcmroot)?;
+            stdout.write_all(report.to_string().as_ref())?;
+            return Ok(());
+        }
+
         stdout.write_all(format!(
             "\n# Dicom-File-Format File: {:#}\n\n# Dicom-Meta-Information-Header\n# Used TransferSyntax: {}\n",
             path.display(),
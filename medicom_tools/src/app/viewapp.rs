@@ -23,8 +23,14 @@ use egui::{
     ColorImage, Margin, SizeHint,
 };
 use medicom::load::{
-    imgvol::ImageVolume, pixeldata::LoadError, workspace::Workspace, IndexVec, LoadableChunkKey,
-    LoadableKey, Loader, SeriesSource, SeriesSourceLoadResult, VolAxis,
+    imgvol::ImageVolume,
+    pixeldata::LoadError,
+    projection::ProjectionMode,
+    resample::Interpolation,
+    stats::{Region, Statistics},
+    workspace::Workspace,
+    IndexVec, LoadableChunkKey, LoadableKey, Loader, SeriesSource, SeriesSourceLoadResult, VolAxis,
+    VolPixel,
 };
 use std::{
     fs::File,
@@ -47,7 +53,7 @@ impl ViewApp {
 
 impl CommandApplication for ViewApp {
     fn run(&mut self) -> Result<()> {
-        ImageViewer::open_viewer(&self.args.input)
+        ImageViewer::open_viewer(&self.args)
     }
 }
 
@@ -181,37 +187,86 @@ impl SeriesSource<File> for FlatFolderSeriesSource {
 #[derive(Default)]
 struct DicomFileImageLoader {
     workspace: RwLock<Workspace>,
+    /// Whether to draw the Overlay Planes of each slice over its image.
+    overlays: bool,
+    /// The intensity projection to show of a slab around each slice, if any.
+    projection: Option<ProjectionMode>,
+    /// The thickness in mm of the slab to project.
+    slab: f32,
 }
 
 impl DicomFileImageLoader {
-    fn to_image(imgvol: &ImageVolume, axis: &VolAxis, slice_index: usize) -> ColorImage {
+    fn to_image(&self, imgvol: &ImageVolume, axis: &VolAxis, slice_index: usize) -> ColorImage {
         let win = imgvol
             .minmax_winlevel()
             .with_out(f32::from(u8::MIN), f32::from(u8::MAX));
 
-        // TODO: Create a uniform volume and use interpolation.
-
-        // Size the image in the maximum dimension, using filler before and after so it will
-        // vertically center.
-        let axis_dims = imgvol.axis_dims(axis);
-        let max = axis_dims.x.max(axis_dims.y.max(axis_dims.z));
-        let fill = (max * max) - (axis_dims.x * axis_dims.y);
-        // Ensure that the fill content on either side is evenly divisible by the image dimension
-        // otherwise it will cause the image to be shifted.
-        let half_fill = fill / 2;
-        let over = half_fill % max;
-        let (prefill, postfill) = (half_fill + over, half_fill - over);
-
-        #[allow(clippy::cast_possible_truncation)]
-        let iter = std::iter::repeat_n(0, prefill)
-            .chain(
-                imgvol
-                    .slice_iter(axis, slice_index)
-                    .map(|p| win.apply(p.r) as u8),
-            )
-            .chain(std::iter::repeat_n(0, postfill));
-
-        ColorImage::from_gray_iter([max, max], iter)
+        // The native plane is shown as stored. Other axes and projections are reformatted onto
+        // square pixels so that they are not stretched by the spacing between slices. Overlays are
+        // only drawn on the native plane, where pixel coordinates are those of the volume.
+        let native = *axis == VolAxis::Z && self.projection.is_none();
+        let (width, height, pixels) = match axis {
+            _ if native => {
+                let dims = imgvol.axis_dims(axis);
+                let pixels = imgvol.slice_iter(axis, slice_index).collect::<Vec<_>>();
+                (dims.x, dims.y, pixels)
+            }
+            _ => {
+                let plane = imgvol.axis_plane(axis, slice_index);
+                let mpr = match self.projection {
+                    Some(mode) => {
+                        imgvol.project_oblique(&plane, self.slab, mode, Interpolation::Trilinear)
+                    }
+                    None => imgvol.reformat(&plane, Interpolation::Trilinear),
+                };
+                let pixels = mpr
+                    .map(|mpr| mpr.slice_iter(&VolAxis::Z, 0).collect::<Vec<_>>())
+                    .unwrap_or_default();
+                (plane.cols, plane.rows, pixels)
+            }
+        };
+        let overlay_at = |p: &VolPixel| self.overlays && native && imgvol.overlay_at(&p.coord);
+
+        let rgb = if imgvol.is_rgb() {
+            #[allow(clippy::cast_possible_truncation)]
+            pixels
+                .iter()
+                .map(|p| {
+                    if overlay_at(p) {
+                        return [u8::MAX; 3];
+                    }
+                    [
+                        win.apply(p.r) as u8,
+                        win.apply(p.g) as u8,
+                        win.apply(p.b) as u8,
+                    ]
+                })
+                .collect::<Vec<[u8; 3]>>()
+        } else {
+            let pipeline = imgvol.grayscale_pipeline();
+            let (out_min, out_max) = (f32::from(u8::MIN), f32::from(u8::MAX));
+            #[allow(clippy::cast_possible_truncation)]
+            pixels
+                .iter()
+                .map(|p| {
+                    if overlay_at(p) {
+                        [u8::MAX; 3]
+                    } else {
+                        [pipeline.display(p.r, out_min, out_max) as u8; 3]
+                    }
+                })
+                .collect::<Vec<[u8; 3]>>()
+        };
+
+        // Size the image in the maximum dimension, centering the slice within it.
+        let size = width.max(height);
+        let (left, top) = ((size - width) / 2, (size - height) / 2);
+        let mut square = vec![[0u8; 3]; size * size];
+        for (row, line) in rgb.chunks(width.max(1)).enumerate().take(height) {
+            let start = (top + row) * size + left;
+            square[start..start + line.len()].copy_from_slice(line);
+        }
+        ColorImage::from_rgb([size, size], square.as_flattened())
     }
 }
 
@@ -226,7 +281,7 @@ impl ImageLoader for DicomFileImageLoader {
             if let Some(imgvol) = workspace.volume(&slice_key.series) {
                 let axis_dims = imgvol.axis_dims(&slice_key.axis);
                 if slice_key.slice_index < axis_dims.z {
-                    let image = Self::to_image(imgvol, &slice_key.axis, slice_key.slice_index);
+                    let image = self.to_image(imgvol, &slice_key.axis, slice_key.slice_index);
                     let image = Arc::new(image);
                     return Ok(ImagePoll::Ready { image });
                 }
@@ -262,15 +317,23 @@ struct ImageViewer {
     current_slice: usize,
     image_loader: Arc<DicomFileImageLoader>,
     view_axis: VolAxis,
+    /// The statistics of `current_slice` along the z-axis, computed when the slice changes rather
+    /// than on every repaint. The volume does not change once it has finished loading.
+    slice_stats: Option<(usize, Option<Statistics>)>,
 }
 
 impl ImageViewer {
-    fn new(input: &Path, cc: &eframe::CreationContext<'_>) -> Result<Self> {
+    fn new(args: &ViewArgs, cc: &eframe::CreationContext<'_>) -> Result<Self> {
         // Start the current image as the middle index. Note that at this point the files list is
         // not sorted at all.
-        let loader = Arc::new(DicomFileImageLoader::default());
+        let loader = Arc::new(DicomFileImageLoader {
+            overlays: args.overlays,
+            projection: args.projection,
+            slab: args.slab,
+            ..Default::default()
+        });
 
-        let source = Arc::new(FlatFolderSeriesSource::new(input.to_path_buf())?);
+        let source = Arc::new(FlatFolderSeriesSource::new(args.input.clone())?);
 
         // Create one list of the files, shared to the thread which will load all the images in the
         // background. After loading it modifies the input list of files to be sorted based on the
@@ -295,10 +358,11 @@ impl ImageViewer {
             current_slice: NO_CURRENT_SLICE_SENTINEL,
             image_loader: loader_for_self,
             view_axis: VolAxis::Z,
+            slice_stats: None,
         })
     }
 
-    fn open_viewer(input: &Path) -> Result<()> {
+    fn open_viewer(args: &ViewArgs) -> Result<()> {
         let native_options = eframe::NativeOptions {
             viewport: egui::ViewportBuilder::default()
                 .with_inner_size([1024.0, 768.0])
@@ -309,7 +373,7 @@ impl ImageViewer {
         eframe::run_native(
             "Medicom Image Viewer",
             native_options,
-            Box::new(|cc| Ok(Box::new(ImageViewer::new(input, cc)?))),
+            Box::new(|cc| Ok(Box::new(ImageViewer::new(args, cc)?))),
         )?;
 
         Ok(())
@@ -422,7 +486,7 @@ impl eframe::App for ImageViewer {
                 VolAxis::Y => index_coord.y = self.current_slice,
                 VolAxis::Z => index_coord.z = self.current_slice,
             }
-            let dcm_pos = imgvol.dims().coordinate(index_coord);
+            let dcm_pos = imgvol.voxel_to_patient().apply_index(index_coord);
             ui.label(format!(
                 "Top-left Loc: {:.2}, {:.2}, {:.2}",
                 dcm_pos.x, dcm_pos.y, dcm_pos.z
@@ -432,6 +496,21 @@ impl eframe::App for ImageViewer {
                 vox_dims.x, vox_dims.y, vox_dims.z
             ));
             ui.label(format!("Slice Dims: {}x{}", axis_dims.x, axis_dims.y));
+            ui.label(format!("Slice Geometry: {}", imgvol.slice_geometry()));
+            if axis == VolAxis::Z {
+                let current_slice = self.current_slice;
+                if self
+                    .slice_stats
+                    .as_ref()
+                    .is_none_or(|(slice, _)| *slice != current_slice)
+                {
+                    let stats = imgvol.statistics(&Region::Slice(current_slice)).ok();
+                    self.slice_stats = Some((current_slice, stats));
+                }
+                if let Some((_, Some(stats))) = &self.slice_stats {
+                    ui.label(format!("Slice Stats: {stats}"));
+                }
+            }
             ui.label(imgvol.series_desc());
 
             ui.label(format!("Slice No: {}/{num_slices}", self.current_slice + 1));
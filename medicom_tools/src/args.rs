@@ -18,6 +18,8 @@ use std::{error::Error, path::PathBuf};
 
 use clap::{Args, Parser, Subcommand};
 use medicom::dimse::assoc::QueryLevel;
+#[cfg(feature = "image")]
+use medicom::load::projection::ProjectionMode;
 
 #[derive(Parser, Debug)]
 /// Explore DICOM
@@ -42,6 +44,11 @@ pub enum Command {
     #[cfg(feature = "image")]
     View(ViewArgs),
 
+    /// Extract or create Encapsulated Documents, such as PDF reports and STL models.
+    ///
+    /// Supports documents of PDF, HL7 CDA, STL and OBJ formats.
+    Document(DocumentArgs),
+
     /// Manage a database index of DICOM on disk.
     ///
     /// Recursively scans a folder for DICOM datasets, indexing them into a database.
@@ -68,6 +75,11 @@ pub enum Command {
 pub struct PrintArgs {
     /// The file to process as a DICOM dataset.
     pub file: PathBuf,
+
+    #[arg(long)]
+    /// Render the content tree of a Structured Report as an indented text report, instead of the
+    /// dataset's elements.
+    pub sr: bool,
 }
 
 #[derive(Args, Debug)]
@@ -84,6 +96,21 @@ pub struct ExtractArgs {
 
     /// The output file to save the encoded image.
     pub output: PathBuf,
+
+    #[arg(long)]
+    /// Draw the dataset's Overlay Planes over the image.
+    pub overlays: bool,
+
+    #[arg(long)]
+    /// Extract an intensity projection of the dataset's slices instead of the first slice: MIP,
+    /// MinIP or AIP.
+    pub projection: Option<ProjectionMode>,
+
+    #[arg(long)]
+    /// The thickness in mm of the slab to project, centered on the middle slice.
+    ///
+    /// If not specified then all slices are projected.
+    pub slab: Option<f32>,
 }
 
 #[cfg(feature = "image")]
@@ -91,6 +118,71 @@ pub struct ExtractArgs {
 pub struct ViewArgs {
     /// A folder containing DICOM files to view.
     pub input: PathBuf,
+
+    #[arg(long)]
+    /// Draw the Overlay Planes of each image over it.
+    pub overlays: bool,
+
+    #[arg(long)]
+    /// Show an intensity projection of a slab around each slice: MIP, MinIP or AIP.
+    pub projection: Option<ProjectionMode>,
+
+    #[arg(long, default_value_t = 10.0)]
+    /// The thickness in mm of the slab to project around each slice.
+    pub slab: f32,
+}
+
+#[derive(Args, Debug)]
+pub struct DocumentArgs {
+    #[clap(subcommand)]
+    /// Document sub-command
+    pub cmd: DocumentCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum DocumentCommand {
+    /// Writes the document of an Encapsulated Document instance to a file.
+    Extract {
+        /// The Encapsulated Document instance to extract the document from.
+        file: PathBuf,
+
+        /// The file to write the document to.
+        output: PathBuf,
+    },
+    /// Creates an Encapsulated Document instance from a document.
+    Create(DocumentCreateArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct DocumentCreateArgs {
+    /// The document to encapsulate. Its type is determined by its extension: pdf, xml, stl or
+    /// obj.
+    pub input: PathBuf,
+
+    /// The file to write the Encapsulated Document instance to.
+    pub output: PathBuf,
+
+    #[arg(long)]
+    /// The Document Title. If not specified then the name of the document's file is used.
+    pub title: Option<String>,
+
+    #[arg(long)]
+    /// A DICOM file from the study to add the document to, whose patient and study are copied.
+    ///
+    /// If not specified then the document is created in a new study.
+    pub study: Option<PathBuf>,
+
+    #[arg(long)]
+    /// The Patient's Name, in the format `Family^Given`.
+    pub patient_name: Option<String>,
+
+    #[arg(long)]
+    /// The Patient ID.
+    pub patient_id: Option<String>,
+
+    #[arg(long)]
+    /// The Accession Number.
+    pub accession_number: Option<String>,
 }
 
 #[cfg(feature = "index")]
@@ -28,8 +28,8 @@ use crate::app::{indexapp::IndexApp, scpapp::SvcProviderApp};
 
 use crate::{
     app::{
-        archiveapp::ArchiveApp, inspectapp::InspectApp, printapp::PrintApp, scuapp::SvcUserApp,
-        CommandApplication,
+        archiveapp::ArchiveApp, documentapp::DocumentApp, inspectapp::InspectApp,
+        printapp::PrintApp, scuapp::SvcUserApp, CommandApplication,
     },
     args::{Arguments, Command},
 };
@@ -61,6 +61,7 @@ fn make_app() -> Box<dyn CommandApplication> {
         #[cfg(feature = "image")]
         Command::Extract(args) => Box::new(ExtractApp::new(args)),
         Command::Inspect(args) => Box::new(InspectApp::new(args)),
+        Command::Document(args) => Box::new(DocumentApp::new(args)),
         #[cfg(feature = "index")]
         Command::Index(args) => Box::new(IndexApp::new(args)),
         Command::Archive(args) => Box::new(ArchiveApp::new(args)),